@@ -10,6 +10,8 @@ use std::collections::HashMap;
 
 use linefeed::{Reader, ReadResult};
 
+use crate::utils::output;
+
 #[derive(Debug)]
 pub struct ParamMetadata {
     name: &'static str,
@@ -400,6 +402,13 @@ impl CommandExecutor {
     }
 
     pub fn execute(&self, line: &str) -> Result<(), ()> {
+        output::begin(line);
+        let res = self._execute(line);
+        output::finish(&res);
+        res
+    }
+
+    fn _execute(&self, line: &str) -> Result<(), ()> {
         let (cmd, params) = CommandExecutor::_split_first_word(line);
 
         if cmd == "help" {
@@ -416,7 +425,7 @@ impl CommandExecutor {
         }
 
         println_err!("Unknown group or command \"{}\"", cmd);
-        println!("Type \"help\" to display the help");
+        println_msg!("Type \"help\" to display the help");
         Err(())
     }
 
@@ -654,7 +663,7 @@ impl CommandExecutor {
         }
 
         println_err!("Unknown command \"{} {}\"", group.metadata().name(), cmd);
-        println!("Type \"{} help\" to display the help for \"{}\" group", group.metadata().name(), group.metadata().name());
+        println_msg!("Type \"{} help\" to display the help for \"{}\" group", group.metadata().name(), group.metadata().name());
         Err(())
     }
 
@@ -671,11 +680,11 @@ impl CommandExecutor {
             Err(ref err) => {
                 println_err!("{}", err);
                 if group.is_some() {
-                    println!("Type \"{} {} help\" to display the help for \"{} {}\" command",
+                    println_msg!("Type \"{} {} help\" to display the help for \"{} {}\" command",
                              group.unwrap().metadata().name(), command.metadata().name(),
                              group.unwrap().metadata().name(), command.metadata().name());
                 } else {
-                    println!("Type \"{} help\" to display the help for \"{}\" command",
+                    println_msg!("Type \"{} help\" to display the help for \"{}\" command",
                              command.metadata().name(),
                              command.metadata().name());
                 }
@@ -686,60 +695,60 @@ impl CommandExecutor {
 
     fn _print_help(&self) {
         println_acc!("Hyperledger Indy CLI");
-        println!();
+        println_msg!();
         println_acc!("Usage:");
-        println!("\t[<command-group>] <command> [[<main-param-name>=]<main-param-value>] [<param_name-1>=<param_value-1>]...[<param_name-n>=<param_value-n>]");
-        println!();
+        println_msg!("\t[<command-group>] <command> [[<main-param-name>=]<main-param-value>] [<param_name-1>=<param_value-1>]...[<param_name-n>=<param_value-n>]");
+        println_msg!();
         println_acc!("Getting help:");
-        println!("\thelp - Display this help");
-        println!("\t<command-group> help - Display the help for the specific command group");
-        println!("\t[<command-group>] <command> help - Display the help for the specific command");
-        println!();
+        println_msg!("\thelp - Display this help");
+        println_msg!("\t<command-group> help - Display the help for the specific command group");
+        println_msg!("\t[<command-group>] <command> help - Display the help for the specific command");
+        println_msg!();
         println_acc!("Command groups are:");
 
         for &(ref group, _) in self.grouped_commands.values() {
-            println!("\t{} - {}", group.metadata().name(), group.metadata().help())
+            println_msg!("\t{} - {}", group.metadata().name(), group.metadata().help())
         }
 
-        println!();
+        println_msg!();
         println_acc!("Top level commands are:");
 
         for command in self.commands.values() {
-            println!("\t{} - {}", command.metadata().name(), command.metadata().help())
+            println_msg!("\t{} - {}", command.metadata().name(), command.metadata().help())
         }
 
-        println!();
+        println_msg!();
     }
 
     fn _print_group_help(&self, group: &CommandGroup, commands: &HashMap<&'static str, Command>) {
         println_acc!("Group:");
-        println!("\t{} - {}", group.metadata().name(), group.metadata().help());
-        println!();
+        println_msg!("\t{} - {}", group.metadata().name(), group.metadata().help());
+        println_msg!();
         println_acc!("Usage:");
-        println!("\t{} <command> [[<main-param-name>=]<main-param-value>] [<param_name-1>=<param_value-1>]...[<param_name-n>=<param_value-n>]", group.metadata().name());
-        println!();
+        println_msg!("\t{} <command> [[<main-param-name>=]<main-param-value>] [<param_name-1>=<param_value-1>]...[<param_name-n>=<param_value-n>]", group.metadata().name());
+        println_msg!();
         println_acc!("Getting help:");
-        println!("\t{} <command> help - Display the help for the specific command", group.metadata().name());
-        println!();
+        println_msg!("\t{} <command> help - Display the help for the specific command", group.metadata().name());
+        println_msg!();
         println_acc!("Group commands are:");
 
         for command in commands.values() {
-            println!("\t{} - {}", command.metadata().name(), command.metadata().help())
+            println_msg!("\t{} - {}", command.metadata().name(), command.metadata().help())
         }
 
-        println!();
+        println_msg!();
     }
 
     fn _print_command_help(&self, group: Option<&CommandGroup>, command: &Command) {
         println_acc!("Command:");
 
         if let Some(group) = group {
-            println!("\t{} {} - {}", group.metadata().name(), command.metadata().name(), command.metadata().help());
+            println_msg!("\t{} {} - {}", group.metadata().name(), command.metadata().name(), command.metadata().help());
         } else {
-            println!("\t{} - {}", command.metadata().name(), command.metadata().help());
+            println_msg!("\t{} - {}", command.metadata().name(), command.metadata().help());
         }
 
-        println!();
+        println_msg!();
         println_acc!("Usage:");
 
         if let Some(group) = group {
@@ -761,14 +770,14 @@ impl CommandExecutor {
             }
         }
 
-        println!();
+        println_msg!();
 
         if command.metadata().main_param().is_some() || !command.metadata().params().is_empty() {
-            println!();
+            println_msg!();
             println_acc!("Parameters are:");
 
             if let Some(ref main_param) = command.metadata().main_param() {
-                println!("\t{} - {}", main_param.name(), main_param.help())
+                println_msg!("\t{} - {}", main_param.name(), main_param.help())
             }
 
             for param in command.metadata().params() {
@@ -782,20 +791,20 @@ impl CommandExecutor {
                     print!("(leave empty for deferred input) ")
                 }
 
-                println!("{}", param.help());
+                println_msg!("{}", param.help());
             }
         }
 
         if !command.metadata().examples().is_empty() {
-            println!();
+            println_msg!();
             println_acc!("Examples:");
 
             for example in command.metadata().examples() {
-                println!("\t{}", example);
+                println_msg!("\t{}", example);
             }
         }
 
-        println!();
+        println_msg!();
     }
 
     fn _parse_params(command: &CommandMetadata, params: &str) -> Result<CommandParams, String> {
@@ -856,13 +865,13 @@ impl CommandExecutor {
         }
 
         for param in deferred_params {
-            println!("Enter value for {}:", param);
+            println_msg!("Enter value for {}:", param);
             let val;
             loop {
                 match rpassword::read_password() {
                     Ok(v) => {
                         if v.is_empty() {
-                            println!("Please enter value for {}:", param);
+                            println_msg!("Please enter value for {}:", param);
                         } else {
                             val = v;
                             break;
@@ -870,7 +879,7 @@ impl CommandExecutor {
                     }
                     Err(err) => {
                         println_err!("{}", err.to_string());
-                        println!("Please enter value for {}:", param);
+                        println_msg!("Please enter value for {}:", param);
                     }
                 }
             }
@@ -0,0 +1,928 @@
+use crate::command_executor::{Command, CommandContext, CommandMetadata, CommandParams, CommandGroup, CommandGroupMetadata};
+use crate::commands::*;
+use crate::commands::ledger::send_cred_def;
+
+use indy::WalletHandle;
+
+use crate::libindy::anoncreds::{Anoncreds, Issuer, Prover, Verifier};
+use crate::libindy::ledger::Ledger;
+
+use serde_json::Value as JSONValue;
+use serde_json::Map as JSONMap;
+
+use crate::utils::file::{read_file, write_file};
+use crate::utils::output;
+use crate::utils::table::print_list_table;
+
+pub const DEFAULT_TAG: &str = "default";
+
+pub mod group {
+    use super::*;
+
+    command_group!(CommandGroupMetadata::new("anoncreds", "Anonymous credentials management commands"));
+}
+
+pub mod schema_create_command {
+    use super::*;
+
+    command!(CommandMetadata::build("schema-create", "Create Schema issued by the active DID. Use `ledger schema` command to publish it.")
+                .add_required_param("name", "Schema name")
+                .add_required_param("version", "Schema version")
+                .add_required_param("attr_names", "Schema attributes split by comma")
+                .add_optional_param("file", "Path to the file to store Schema json")
+                .add_example("anoncreds schema-create name=gvt version=1.0 attr_names=name,age")
+                .add_example("anoncreds schema-create name=gvt version=1.0 attr_names=name,age file=/home/schema.json")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let issuer_did = ensure_active_did(&ctx)?;
+
+        let name = get_str_param("name", params).map_err(error_err!())?;
+        let version = get_str_param("version", params).map_err(error_err!())?;
+        let attr_names = get_str_array_param("attr_names", params).map_err(error_err!())?;
+        let file = get_opt_str_param("file", params).map_err(error_err!())?;
+
+        let attr_names = JSONValue::from(attr_names).to_string();
+
+        let (schema_id, schema_json) = Issuer::create_schema(&issuer_did, name, version, &attr_names)
+            .map_err(|err| handle_indy_error(err, Some(&issuer_did), None, None))?;
+
+        println_succ!("Schema \"{}\" has been created", schema_id);
+
+        let res = output_json(&schema_json, file);
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod cred_def_create_command {
+    use super::*;
+
+    command!(CommandMetadata::build("cred-def-create", "Create Credential Definition issued by the active DID and store its private part into the wallet.
+                Schema is read from `schema` parameter or received from the Ledger by `schema_id`.")
+                .add_optional_param("schema", "Schema json or path to the file containing it")
+                .add_optional_param("schema_id", "Id of Schema to get from the Ledger")
+                .add_optional_param("tag", "Allows to distinct between credential definitions for the same issuer and schema (\"default\" by default)")
+                .add_optional_param("signature_type", "Signature type (only CL supported now)")
+                .add_optional_param("support_revocation", "Whether revocation registries can be created for the Credential Definition (false by default)")
+                .add_optional_param("file", "Path to the file to store Credential Definition json")
+                .add_optional_param("publish", "Send Credential Definition to the Ledger the same way as `ledger cred-def` command does (false by default)")
+                .add_optional_param("send", "Send the request to the Ledger (True by default). If false then created request will be printed and stored into CLI context.")
+                .add_optional_param("endorser", "DID of the Endorser that will submit the transaction to the ledger later.")
+                .add_example("anoncreds cred-def-create schema_id=V4SGRU86Z58d6TV7PBUe6f:2:gvt:1.0 tag=1 publish=true")
+                .add_example("anoncreds cred-def-create schema=/home/schema.json support_revocation=true file=/home/cred_def.json")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+        let issuer_did = ensure_active_did(&ctx)?;
+
+        let schema = get_opt_json_param("schema", params).map_err(error_err!())?;
+        let schema_id = get_opt_str_param("schema_id", params).map_err(error_err!())?;
+        let tag = get_opt_str_param("tag", params).map_err(error_err!())?.unwrap_or(DEFAULT_TAG);
+        let signature_type = get_opt_str_param("signature_type", params).map_err(error_err!())?;
+        let support_revocation = get_opt_bool_param("support_revocation", params).map_err(error_err!())?.unwrap_or(false);
+        let file = get_opt_str_param("file", params).map_err(error_err!())?;
+        let publish = get_opt_bool_param("publish", params).map_err(error_err!())?.unwrap_or(false);
+
+        let schema = match (schema, schema_id) {
+            (Some(schema), None) => schema,
+            (None, Some(schema_id)) => get_schema_from_ledger(ctx, schema_id)?,
+            _ => {
+                println_err!("Either \"schema\" or \"schema_id\" parameter must be specified");
+                return Err(());
+            }
+        };
+
+        let config = json!({"support_revocation": support_revocation}).to_string();
+
+        let (cred_def_id, cred_def_json) = Issuer::create_and_store_credential_def(wallet_handle, &issuer_did, &schema, tag, signature_type, &config)
+            .map_err(|err| handle_indy_error(err, Some(&issuer_did), None, Some(&wallet_name)))?;
+
+        println_succ!("Credential Definition \"{}\" has been created", cred_def_id);
+
+        output_json(&cred_def_json, file)?;
+
+        let res = if publish {
+            send_cred_def(ctx, params, wallet_handle, &wallet_name, &issuer_did, &cred_def_json)
+        } else {
+            Ok(())
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod rev_reg_create_command {
+    use super::*;
+
+    command!(CommandMetadata::build("rev-reg-create", "Create Revocation Registry for the Credential Definition issued by the active DID and write its tails into the directory.")
+                .add_required_param("cred_def_id", "Id of Credential Definition stored in the wallet")
+                .add_required_param("tails_dir", "Path to the directory to write tails into")
+                .add_optional_param("tag", "Allows to distinct between revocation registries for the same issuer and credential definition (\"default\" by default)")
+                .add_optional_param("max_cred_num", "Maximum number of credentials the registry can process")
+                .add_optional_param("issuance_type", "Type of issuance: ISSUANCE_BY_DEFAULT or ISSUANCE_ON_DEMAND")
+                .add_optional_param("file", "Path to the file to store Revocation Registry Definition json")
+                .add_example("anoncreds rev-reg-create cred_def_id=V4SGRU86Z58d6TV7PBUe6f:3:CL:1:default tails_dir=/home/tails max_cred_num=100")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+        let issuer_did = ensure_active_did(&ctx)?;
+
+        let cred_def_id = get_str_param("cred_def_id", params).map_err(error_err!())?;
+        let tails_dir = get_str_param("tails_dir", params).map_err(error_err!())?;
+        let tag = get_opt_str_param("tag", params).map_err(error_err!())?.unwrap_or(DEFAULT_TAG);
+        let max_cred_num = get_opt_number_param::<u32>("max_cred_num", params).map_err(error_err!())?;
+        let issuance_type = get_opt_str_param("issuance_type", params).map_err(error_err!())?;
+        let file = get_opt_str_param("file", params).map_err(error_err!())?;
+
+        let config = {
+            let mut json = JSONMap::new();
+            update_json_map_opt_key!(json, "max_cred_num", max_cred_num);
+            update_json_map_opt_key!(json, "issuance_type", issuance_type);
+            JSONValue::from(json).to_string()
+        };
+
+        let (rev_reg_id, rev_reg_def_json, rev_reg_entry_json) =
+            Issuer::create_and_store_revoc_reg(wallet_handle, &issuer_did, None, tag, cred_def_id, &config, tails_dir)
+                .map_err(|err| handle_indy_error(err, Some(&issuer_did), None, Some(&wallet_name)))?;
+
+        println_succ!("Revocation Registry \"{}\" has been created", rev_reg_id);
+
+        output_json(&rev_reg_def_json, file)?;
+
+        println_succ!("Initial Revocation Registry entry:");
+        output::print_json(&rev_reg_entry_json, "     ");
+
+        let res = Ok(());
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod offer_command {
+    use super::*;
+
+    command!(CommandMetadata::build("offer", "Create Credential Offer for the Credential Definition stored in the wallet.")
+                .add_required_param("cred_def_id", "Id of Credential Definition stored in the wallet")
+                .add_optional_param("file", "Path to the file to store Credential Offer json")
+                .add_example("anoncreds offer cred_def_id=V4SGRU86Z58d6TV7PBUe6f:3:CL:1:default file=/home/offer.json")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let cred_def_id = get_str_param("cred_def_id", params).map_err(error_err!())?;
+        let file = get_opt_str_param("file", params).map_err(error_err!())?;
+
+        let cred_offer_json = Issuer::create_credential_offer(wallet_handle, cred_def_id)
+            .map_err(|err| handle_indy_error(err, None, None, Some(&wallet_name)))?;
+
+        println_succ!("Credential Offer has been created");
+
+        let res = output_json(&cred_offer_json, file);
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod request_command {
+    use super::*;
+
+    command!(CommandMetadata::build("request", "Create Credential Request for the Credential Offer on behalf of the active DID.
+                Credential Definition is read from `cred_def` parameter or received from the Ledger.
+                Default master secret of the wallet is used if `master_secret` is not specified. It is created if the wallet has none.")
+                .add_required_param("offer", "Credential Offer json or path to the file containing it")
+                .add_optional_param("cred_def", "Credential Definition json or path to the file containing it")
+                .add_optional_param("master_secret", "Id of master secret stored in the wallet")
+                .add_optional_param("file", "Path to the file to store Credential Request json")
+                .add_optional_param("metadata_file", "Path to the file to store Credential Request metadata json")
+                .add_example("anoncreds request offer=/home/offer.json file=/home/request.json metadata_file=/home/request_metadata.json")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+        let prover_did = ensure_active_did(&ctx)?;
+
+        let offer = get_json_param("offer", params).map_err(error_err!())?;
+        let cred_def = get_opt_json_param("cred_def", params).map_err(error_err!())?;
+        let master_secret = get_opt_str_param("master_secret", params).map_err(error_err!())?;
+        let file = get_opt_str_param("file", params).map_err(error_err!())?;
+        let metadata_file = get_opt_str_param("metadata_file", params).map_err(error_err!())?;
+
+        let cred_def = match cred_def {
+            Some(cred_def) => cred_def,
+            None => get_cred_def_from_ledger(ctx, &get_id_field(&offer, "cred_def_id")?)?
+        };
+
+        let master_secret = match master_secret {
+            Some(master_secret) => master_secret.to_string(),
+            None => get_default_master_secret(wallet_handle)?
+        };
+
+        let (cred_req_json, cred_req_metadata_json) = Prover::create_credential_req(wallet_handle, &prover_did, &offer, &cred_def, &master_secret)
+            .map_err(|err| handle_indy_error(err, Some(&prover_did), None, Some(&wallet_name)))?;
+
+        println_succ!("Credential Request has been created");
+        output_json(&cred_req_json, file)?;
+
+        println_succ!("Credential Request metadata (keep it to store the Credential):");
+        let res = output_json(&cred_req_metadata_json, metadata_file);
+
+        trace!("execute << {:?}", res);
+        res
+    }
+
+    fn get_default_master_secret(wallet_handle: WalletHandle) -> Result<String, ()> {
+        let master_secrets = Prover::list_master_secrets(wallet_handle)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let master_secrets: Vec<JSONValue> = serde_json::from_str(&master_secrets)
+            .map_err(|_| println_err!("Wrong data has been received"))?;
+
+        let default = master_secrets.iter()
+            .find(|master_secret| master_secret["default"].as_bool().unwrap_or(false))
+            .and_then(|master_secret| master_secret["id"].as_str());
+
+        match default {
+            Some(master_secret) => Ok(master_secret.to_string()),
+            None => {
+                let master_secret = Prover::create_master_secret(wallet_handle, None)
+                    .map_err(|err| handle_indy_error(err, None, None, None))?;
+                println_succ!("Master secret \"{}\" has been created", master_secret);
+                Ok(master_secret)
+            }
+        }
+    }
+}
+
+pub mod issue_command {
+    use super::*;
+
+    command!(CommandMetadata::build("issue", "Issue Credential for the Credential Request.")
+                .add_required_param("offer", "Credential Offer json or path to the file containing it")
+                .add_required_param("request", "Credential Request json or path to the file containing it")
+                .add_required_param("values", "Raw credential attribute values json {\"attr\": \"value\"} or path to the file containing it")
+                .add_optional_param("rev_reg_id", "Id of Revocation Registry stored in the wallet")
+                .add_optional_param("tails_dir", "Path to the directory containing tails of the Revocation Registry")
+                .add_optional_param("file", "Path to the file to store Credential json")
+                .add_example(r#"anoncreds issue offer=/home/offer.json request=/home/request.json values={"name":"Alex","age":"28"} file=/home/credential.json"#)
+                .add_example("anoncreds issue offer=/home/offer.json request=/home/request.json values=/home/values.json rev_reg_id=V4SGRU86Z58d6TV7PBUe6f:4:V4SGRU86Z58d6TV7PBUe6f:3:CL:1:default:CL_ACCUM:default tails_dir=/home/tails")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let offer = get_json_param("offer", params).map_err(error_err!())?;
+        let request = get_json_param("request", params).map_err(error_err!())?;
+        let values = get_json_param("values", params).map_err(error_err!())?;
+        let rev_reg_id = get_opt_str_param("rev_reg_id", params).map_err(error_err!())?;
+        let tails_dir = get_opt_str_param("tails_dir", params).map_err(error_err!())?;
+        let file = get_opt_str_param("file", params).map_err(error_err!())?;
+
+        if rev_reg_id.is_some() && tails_dir.is_none() {
+            println_err!("\"tails_dir\" parameter must be specified for Revocation Registry");
+            return Err(());
+        }
+
+        let values = Anoncreds::encode_credential_values(&values)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let (cred_json, cred_rev_id, rev_reg_delta_json) =
+            Issuer::create_credential(wallet_handle, &offer, &request, &values, rev_reg_id, tails_dir)
+                .map_err(|err| handle_indy_error(err, None, None, Some(&wallet_name)))?;
+
+        match cred_rev_id {
+            Some(cred_rev_id) => println_succ!("Credential has been issued with revocation id \"{}\"", cred_rev_id),
+            None => println_succ!("Credential has been issued")
+        }
+
+        output_json(&cred_json, file)?;
+
+        if let Some(rev_reg_delta_json) = rev_reg_delta_json {
+            println_succ!("Revocation Registry delta:");
+            output::print_json(&rev_reg_delta_json, "     ");
+        }
+
+        let res = Ok(());
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod store_command {
+    use super::*;
+
+    command!(CommandMetadata::build("store", "Store received Credential into the wallet.
+                Credential Definition is read from `cred_def` parameter or received from the Ledger.")
+                .add_required_param("credential", "Credential json or path to the file containing it")
+                .add_required_param("metadata", "Credential Request metadata json or path to the file containing it")
+                .add_optional_param("cred_def", "Credential Definition json or path to the file containing it")
+                .add_optional_param("rev_reg_def", "Revocation Registry Definition json or path to the file containing it")
+                .add_optional_param("id", "Identifier by which Credential will be stored in the wallet")
+                .add_example("anoncreds store credential=/home/credential.json metadata=/home/request_metadata.json")
+                .add_example("anoncreds store credential=/home/credential.json metadata=/home/request_metadata.json cred_def=/home/cred_def.json id=my-credential")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let credential = get_json_param("credential", params).map_err(error_err!())?;
+        let metadata = get_json_param("metadata", params).map_err(error_err!())?;
+        let cred_def = get_opt_json_param("cred_def", params).map_err(error_err!())?;
+        let rev_reg_def = get_opt_json_param("rev_reg_def", params).map_err(error_err!())?;
+        let id = get_opt_str_param("id", params).map_err(error_err!())?;
+
+        let cred_def = match cred_def {
+            Some(cred_def) => cred_def,
+            None => get_cred_def_from_ledger(ctx, &get_id_field(&credential, "cred_def_id")?)?
+        };
+
+        let res = match Prover::store_credential(wallet_handle, id, &metadata, &credential, &cred_def, rev_reg_def.as_ref().map(String::as_str)) {
+            Ok(cred_id) => Ok(println_succ!("Credential has been stored with id \"{}\"", cred_id)),
+            Err(err) => Err(handle_indy_error(err, None, None, Some(&wallet_name)))
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod revoke_command {
+    use super::*;
+
+    command!(CommandMetadata::build("revoke", "Revoke Credential issued with the Revocation Registry.")
+                .add_required_param("rev_reg_id", "Id of Revocation Registry stored in the wallet")
+                .add_required_param("cred_rev_id", "Revocation id of the Credential")
+                .add_required_param("tails_dir", "Path to the directory containing tails of the Revocation Registry")
+                .add_optional_param("file", "Path to the file to store Revocation Registry delta json")
+                .add_example("anoncreds revoke rev_reg_id=V4SGRU86Z58d6TV7PBUe6f:4:V4SGRU86Z58d6TV7PBUe6f:3:CL:1:default:CL_ACCUM:default cred_rev_id=1 tails_dir=/home/tails")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let rev_reg_id = get_str_param("rev_reg_id", params).map_err(error_err!())?;
+        let cred_rev_id = get_str_param("cred_rev_id", params).map_err(error_err!())?;
+        let tails_dir = get_str_param("tails_dir", params).map_err(error_err!())?;
+        let file = get_opt_str_param("file", params).map_err(error_err!())?;
+
+        let rev_reg_delta_json = Issuer::revoke_credential(wallet_handle, tails_dir, rev_reg_id, cred_rev_id)
+            .map_err(|err| handle_indy_error(err, None, None, Some(&wallet_name)))?;
+
+        println_succ!("Credential \"{}\" has been revoked. Revocation Registry delta:", cred_rev_id);
+
+        let res = output_json(&rev_reg_delta_json, file);
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod proof_create_command {
+    use super::*;
+
+    command!(CommandMetadata::build("proof-create", "Create Proof for the Proof Request using credentials stored in the wallet.
+                Default master secret of the wallet is used if `master_secret` is not specified.")
+                .add_required_param("proof_request", "Proof Request json or path to the file containing it")
+                .add_required_param("requested_credentials", "Requested credentials json or path to the file containing it")
+                .add_required_param("schemas", "Json map of all schemas participating in the Proof Request by their ids or path to the file containing it")
+                .add_required_param("cred_defs", "Json map of all credential definitions participating in the Proof Request by their ids or path to the file containing it")
+                .add_optional_param("rev_states", "Json map of all revocation states participating in the Proof Request or path to the file containing it")
+                .add_optional_param("master_secret", "Id of master secret stored in the wallet")
+                .add_optional_param("file", "Path to the file to store Proof json")
+                .add_example("anoncreds proof-create proof_request=/home/proof_request.json requested_credentials=/home/requested_credentials.json schemas=/home/schemas.json cred_defs=/home/cred_defs.json file=/home/proof.json")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let proof_request = get_json_param("proof_request", params).map_err(error_err!())?;
+        let requested_credentials = get_json_param("requested_credentials", params).map_err(error_err!())?;
+        let schemas = get_json_param("schemas", params).map_err(error_err!())?;
+        let cred_defs = get_json_param("cred_defs", params).map_err(error_err!())?;
+        let rev_states = get_opt_json_param("rev_states", params).map_err(error_err!())?.unwrap_or_else(|| "{}".to_string());
+        let master_secret = get_opt_str_param("master_secret", params).map_err(error_err!())?;
+        let file = get_opt_str_param("file", params).map_err(error_err!())?;
+
+        let proof_json = Prover::create_proof(wallet_handle, &proof_request, &requested_credentials, master_secret, &schemas, &cred_defs, &rev_states)
+            .map_err(|err| handle_indy_error(err, None, None, Some(&wallet_name)))?;
+
+        println_succ!("Proof has been created");
+
+        let res = output_json(&proof_json, file);
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod proof_verify_command {
+    use super::*;
+
+    command!(CommandMetadata::build("proof-verify", "Verify Proof for the Proof Request.")
+                .add_required_param("proof_request", "Proof Request json or path to the file containing it")
+                .add_required_param("proof", "Proof json or path to the file containing it")
+                .add_required_param("schemas", "Json map of all schemas participating in the Proof by their ids or path to the file containing it")
+                .add_required_param("cred_defs", "Json map of all credential definitions participating in the Proof by their ids or path to the file containing it")
+                .add_optional_param("rev_reg_defs", "Json map of all revocation registry definitions participating in the Proof or path to the file containing it")
+                .add_optional_param("rev_regs", "Json map of all revocation registries participating in the Proof or path to the file containing it")
+                .add_example("anoncreds proof-verify proof_request=/home/proof_request.json proof=/home/proof.json schemas=/home/schemas.json cred_defs=/home/cred_defs.json")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let proof_request = get_json_param("proof_request", params).map_err(error_err!())?;
+        let proof = get_json_param("proof", params).map_err(error_err!())?;
+        let schemas = get_json_param("schemas", params).map_err(error_err!())?;
+        let cred_defs = get_json_param("cred_defs", params).map_err(error_err!())?;
+        let rev_reg_defs = get_opt_json_param("rev_reg_defs", params).map_err(error_err!())?.unwrap_or_else(|| "{}".to_string());
+        let rev_regs = get_opt_json_param("rev_regs", params).map_err(error_err!())?.unwrap_or_else(|| "{}".to_string());
+
+        let valid = Verifier::verify_proof(&proof_request, &proof, &schemas, &cred_defs, &rev_reg_defs, &rev_regs)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let res = if valid {
+            Ok(println_succ!("Proof is valid"))
+        } else {
+            println_err!("Proof is invalid");
+            Err(())
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod credentials_list_command {
+    use super::*;
+
+    command!(CommandMetadata::build("credentials-list", "List credentials stored in the wallet.")
+                .add_optional_param("filter", r#"Filter json {"schema_id", "schema_issuer_did", "schema_name", "schema_version", "issuer_did", "cred_def_id"}"#)
+                .add_example("anoncreds credentials-list")
+                .add_example(r#"anoncreds credentials-list filter={"schema_name":"gvt"}"#)
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let wallet_handle = ensure_opened_wallet_handle(&ctx)?;
+
+        let filter = get_opt_object_param("filter", params).map_err(error_err!())?;
+
+        let credentials = Prover::get_credentials(wallet_handle, filter.map(|filter| filter.to_string()).as_ref().map(String::as_str))
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let credentials: Vec<JSONValue> = serde_json::from_str(&credentials)
+            .map_err(|_| println_err!("Wrong data has been received"))?;
+
+        print_list_table(&credentials,
+                         &[("referent", "Id"),
+                             ("cred_def_id", "Credential Definition"),
+                             ("rev_reg_id", "Revocation Registry"),
+                             ("cred_rev_id", "Revocation Id"),
+                             ("attrs", "Attributes")],
+                         "There are no credentials");
+
+        let res = Ok(());
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+// Artifacts are large, so json parameters can be passed either inline or as a path to a file.
+fn get_json_param(name: &str, params: &CommandParams) -> Result<String, ()> {
+    let value = get_str_param(name, params)?;
+
+    let json = if value.starts_with('{') || value.starts_with('[') {
+        value.to_string()
+    } else {
+        read_file(value).map_err(|err| println_err!("Can't read \"{}\" parameter from file \"{}\": {}", name, value, err))?
+    };
+
+    serde_json::from_str::<JSONValue>(&json)
+        .map_err(|err| println_err!("Can't parse json parameter \"{}\": err {}", name, err))?;
+
+    Ok(json)
+}
+
+fn get_opt_json_param(name: &str, params: &CommandParams) -> Result<Option<String>, ()> {
+    match params.get(name) {
+        Some(_) => Ok(Some(get_json_param(name, params)?)),
+        None => Ok(None)
+    }
+}
+
+fn output_json(json: &str, file: Option<&str>) -> Result<(), ()> {
+    match file {
+        Some(file) => {
+            write_file(file, json).map_err(|err| println_err!("{}", err))?;
+            println_succ!("It has been saved to \"{}\"", file);
+        }
+        None => output::print_json(json, "     ")
+    }
+    Ok(())
+}
+
+fn get_id_field(json: &str, field: &str) -> Result<String, ()> {
+    serde_json::from_str::<JSONValue>(json).ok()
+        .and_then(|value| value[field].as_str().map(String::from))
+        .ok_or_else(|| println_err!("\"{}\" not found", field))
+}
+
+fn get_schema_from_ledger(ctx: &CommandContext, schema_id: &str) -> Result<String, ()> {
+    let pool_handle = ensure_connected_pool_handle(ctx)?;
+    let submitter_did = get_active_did(ctx);
+
+    let request = Ledger::build_get_schema_request(submitter_did.as_ref().map(String::as_str), schema_id)
+        .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+    let response = Ledger::submit_request(pool_handle, &request)
+        .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+    Ledger::parse_get_schema_response(&response)
+        .map(|(_, schema_json)| schema_json)
+        .map_err(|_| println_err!("Schema \"{}\" not found", schema_id))
+}
+
+fn get_cred_def_from_ledger(ctx: &CommandContext, cred_def_id: &str) -> Result<String, ()> {
+    let pool_handle = ensure_connected_pool_handle(ctx)?;
+    let submitter_did = get_active_did(ctx);
+
+    let request = Ledger::build_get_cred_def_request(submitter_did.as_ref().map(String::as_str), cred_def_id)
+        .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+    let response = Ledger::submit_request(pool_handle, &request)
+        .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+    Ledger::parse_get_cred_def_response(&response)
+        .map(|(_, cred_def_json)| cred_def_json)
+        .map_err(|_| println_err!("Credential Definition \"{}\" not found", cred_def_id))
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::commands::did::tests::{new_did, use_did, SEED_MY1, DID_MY1};
+    use crate::utils::environment::EnvironmentUtils;
+
+    const SCHEMA_NAME: &str = "gvt";
+    const SCHEMA_VERSION: &str = "1.0";
+    const CRED_VALUES: &str = r#"{"name":"Alex","age":"28"}"#;
+
+    mod schema_create {
+        use super::*;
+
+        #[test]
+        pub fn schema_create_works() {
+            let ctx = setup_with_wallet_and_did();
+            let schema_path = tmp_file("schema.json");
+            {
+                let cmd = schema_create_command::new();
+                let mut params = CommandParams::new();
+                params.insert("name", SCHEMA_NAME.to_string());
+                params.insert("version", SCHEMA_VERSION.to_string());
+                params.insert("attr_names", "name,age".to_string());
+                params.insert("file", schema_path.clone());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            let schema: JSONValue = serde_json::from_str(&read_file(&schema_path).unwrap()).unwrap();
+            assert_eq!(format!("{}:2:{}:{}", DID_MY1, SCHEMA_NAME, SCHEMA_VERSION), schema["id"].as_str().unwrap());
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn schema_create_works_for_no_active_did() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = schema_create_command::new();
+                let mut params = CommandParams::new();
+                params.insert("name", SCHEMA_NAME.to_string());
+                params.insert("version", SCHEMA_VERSION.to_string());
+                params.insert("attr_names", "name,age".to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+    }
+
+    mod cred_def_create {
+        use super::*;
+
+        #[test]
+        pub fn cred_def_create_works() {
+            let ctx = setup_with_wallet_and_did();
+            let schema_path = create_schema(&ctx);
+            let cred_def_path = tmp_file("cred_def.json");
+            {
+                let cmd = cred_def_create_command::new();
+                let mut params = CommandParams::new();
+                params.insert("schema", schema_path);
+                params.insert("file", cred_def_path.clone());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            let cred_def: JSONValue = serde_json::from_str(&read_file(&cred_def_path).unwrap()).unwrap();
+            assert_eq!(DEFAULT_TAG, cred_def["tag"].as_str().unwrap());
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn cred_def_create_works_for_missed_schema() {
+            let ctx = setup_with_wallet_and_did();
+            {
+                let cmd = cred_def_create_command::new();
+                let params = CommandParams::new();
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn cred_def_create_works_for_invalid_schema() {
+            let ctx = setup_with_wallet_and_did();
+            {
+                let cmd = cred_def_create_command::new();
+                let mut params = CommandParams::new();
+                params.insert("schema", r#"{"id":"invalid"}"#.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+    }
+
+    mod issuance {
+        use super::*;
+
+        #[test]
+        pub fn issue_and_store_works() {
+            let ctx = setup_with_wallet_and_did();
+            let cred_def_path = create_cred_def(&ctx, false);
+
+            issue_and_store_credential(&ctx, &cred_def_path, None);
+
+            let credentials = get_credentials(&ctx);
+            assert_eq!(1, credentials.len());
+            assert_eq!("Alex", credentials[0]["attrs"]["name"].as_str().unwrap());
+            {
+                let cmd = credentials_list_command::new();
+                let params = CommandParams::new();
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn issue_works_for_revocation_registry_without_tails_dir() {
+            let ctx = setup_with_wallet_and_did();
+            let cred_def_path = create_cred_def(&ctx, false);
+            let offer_path = create_offer(&ctx, &cred_def_path);
+            let (request_path, _) = create_request(&ctx, &offer_path, &cred_def_path);
+            {
+                let cmd = issue_command::new();
+                let mut params = CommandParams::new();
+                params.insert("offer", offer_path);
+                params.insert("request", request_path);
+                params.insert("values", CRED_VALUES.to_string());
+                params.insert("rev_reg_id", "rev_reg_id".to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn revoke_works() {
+            let ctx = setup_with_wallet_and_did();
+            let cred_def_path = create_cred_def(&ctx, true);
+            let tails_dir = tmp_file("tails");
+            let rev_reg_def_path = tmp_file("rev_reg_def.json");
+            {
+                let cmd = rev_reg_create_command::new();
+                let mut params = CommandParams::new();
+                params.insert("cred_def_id", get_field(&cred_def_path, "id"));
+                params.insert("tails_dir", tails_dir.clone());
+                params.insert("max_cred_num", "5".to_string());
+                params.insert("file", rev_reg_def_path.clone());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            let rev_reg_id = get_field(&rev_reg_def_path, "id");
+
+            issue_and_store_credential(&ctx, &cred_def_path, Some((&rev_reg_def_path, &tails_dir)));
+
+            let credentials = get_credentials(&ctx);
+            assert_eq!("1", credentials[0]["cred_rev_id"].as_str().unwrap());
+            {
+                let cmd = revoke_command::new();
+                let mut params = CommandParams::new();
+                params.insert("rev_reg_id", rev_reg_id);
+                params.insert("cred_rev_id", "1".to_string());
+                params.insert("tails_dir", tails_dir);
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+    }
+
+    mod proof {
+        use super::*;
+
+        #[test]
+        pub fn proof_create_and_verify_works() {
+            let ctx = setup_with_wallet_and_did();
+            let cred_def_path = create_cred_def(&ctx, false);
+            issue_and_store_credential(&ctx, &cred_def_path, None);
+
+            let cred_id = get_credentials(&ctx)[0]["referent"].as_str().unwrap().to_string();
+            let schema: JSONValue = serde_json::from_str(&read_file(tmp_file("schema.json")).unwrap()).unwrap();
+            let cred_def: JSONValue = serde_json::from_str(&read_file(&cred_def_path).unwrap()).unwrap();
+
+            let proof_request = json!({
+                "nonce": "123432421212",
+                "name": "proof_req_1",
+                "version": "0.1",
+                "requested_attributes": {"attr1_referent": {"name": "name"}},
+                "requested_predicates": {"predicate1_referent": {"name": "age", "p_type": ">=", "p_value": 18}}
+            }).to_string();
+            let requested_credentials = json!({
+                "self_attested_attributes": {},
+                "requested_attributes": {"attr1_referent": {"cred_id": cred_id, "revealed": true}},
+                "requested_predicates": {"predicate1_referent": {"cred_id": cred_id}}
+            }).to_string();
+            let schemas = id_map(schema);
+            let cred_defs = id_map(cred_def);
+            let proof_path = tmp_file("proof.json");
+            {
+                let cmd = proof_create_command::new();
+                let mut params = CommandParams::new();
+                params.insert("proof_request", proof_request.clone());
+                params.insert("requested_credentials", requested_credentials);
+                params.insert("schemas", schemas.clone());
+                params.insert("cred_defs", cred_defs.clone());
+                params.insert("file", proof_path.clone());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            {
+                let cmd = proof_verify_command::new();
+                let mut params = CommandParams::new();
+                params.insert("proof_request", proof_request.clone());
+                params.insert("proof", proof_path.clone());
+                params.insert("schemas", schemas.clone());
+                params.insert("cred_defs", cred_defs.clone());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            let other_proof_request = proof_request.replace("123432421212", "123432421213");
+            {
+                let cmd = proof_verify_command::new();
+                let mut params = CommandParams::new();
+                params.insert("proof_request", other_proof_request);
+                params.insert("proof", proof_path);
+                params.insert("schemas", schemas);
+                params.insert("cred_defs", cred_defs);
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+    }
+
+    fn setup_with_wallet_and_did() -> CommandContext {
+        let ctx = setup_with_wallet();
+        new_did(&ctx, SEED_MY1);
+        use_did(&ctx, DID_MY1);
+        ctx
+    }
+
+    fn tmp_file(name: &str) -> String {
+        EnvironmentUtils::tmp_file_path(name).to_str().unwrap().to_string()
+    }
+
+    fn get_field(path: &str, field: &str) -> String {
+        get_id_field(&read_file(path).unwrap(), field).unwrap()
+    }
+
+    fn id_map(value: JSONValue) -> String {
+        let mut json = JSONMap::new();
+        json.insert(value["id"].as_str().unwrap().to_string(), value);
+        JSONValue::from(json).to_string()
+    }
+
+    fn get_credentials(ctx: &CommandContext) -> Vec<JSONValue> {
+        let wallet_handle = ensure_opened_wallet_handle(ctx).unwrap();
+        let credentials = Prover::get_credentials(wallet_handle, None).unwrap();
+        serde_json::from_str(&credentials).unwrap()
+    }
+
+    pub fn create_schema(ctx: &CommandContext) -> String {
+        let schema_path = tmp_file("schema.json");
+        let cmd = schema_create_command::new();
+        let mut params = CommandParams::new();
+        params.insert("name", SCHEMA_NAME.to_string());
+        params.insert("version", SCHEMA_VERSION.to_string());
+        params.insert("attr_names", "name,age".to_string());
+        params.insert("file", schema_path.clone());
+        cmd.execute(&ctx, &params).unwrap();
+        schema_path
+    }
+
+    pub fn create_cred_def(ctx: &CommandContext, support_revocation: bool) -> String {
+        let schema_path = create_schema(ctx);
+        let cred_def_path = tmp_file("cred_def.json");
+        let cmd = cred_def_create_command::new();
+        let mut params = CommandParams::new();
+        params.insert("schema", schema_path);
+        params.insert("support_revocation", support_revocation.to_string());
+        params.insert("file", cred_def_path.clone());
+        cmd.execute(&ctx, &params).unwrap();
+        cred_def_path
+    }
+
+    fn create_offer(ctx: &CommandContext, cred_def_path: &str) -> String {
+        let offer_path = tmp_file("offer.json");
+        let cmd = offer_command::new();
+        let mut params = CommandParams::new();
+        params.insert("cred_def_id", get_field(cred_def_path, "id"));
+        params.insert("file", offer_path.clone());
+        cmd.execute(&ctx, &params).unwrap();
+        offer_path
+    }
+
+    fn create_request(ctx: &CommandContext, offer_path: &str, cred_def_path: &str) -> (String, String) {
+        let request_path = tmp_file("request.json");
+        let metadata_path = tmp_file("request_metadata.json");
+        let cmd = request_command::new();
+        let mut params = CommandParams::new();
+        params.insert("offer", offer_path.to_string());
+        params.insert("cred_def", cred_def_path.to_string());
+        params.insert("file", request_path.clone());
+        params.insert("metadata_file", metadata_path.clone());
+        cmd.execute(&ctx, &params).unwrap();
+        (request_path, metadata_path)
+    }
+
+    fn issue_and_store_credential(ctx: &CommandContext, cred_def_path: &str, rev_reg: Option<(&str, &str)>) {
+        let offer_path = create_offer(ctx, cred_def_path);
+        let (request_path, metadata_path) = create_request(ctx, &offer_path, cred_def_path);
+        let credential_path = tmp_file("credential.json");
+        {
+            let cmd = issue_command::new();
+            let mut params = CommandParams::new();
+            params.insert("offer", offer_path);
+            params.insert("request", request_path);
+            params.insert("values", CRED_VALUES.to_string());
+            if let Some((rev_reg_def_path, tails_dir)) = rev_reg {
+                params.insert("rev_reg_id", get_field(rev_reg_def_path, "id"));
+                params.insert("tails_dir", tails_dir.to_string());
+            }
+            params.insert("file", credential_path.clone());
+            cmd.execute(&ctx, &params).unwrap();
+        }
+        {
+            let cmd = store_command::new();
+            let mut params = CommandParams::new();
+            params.insert("credential", credential_path);
+            params.insert("metadata", metadata_path);
+            params.insert("cred_def", cred_def_path.to_string());
+            if let Some((rev_reg_def_path, _)) = rev_reg {
+                params.insert("rev_reg_def", rev_reg_def_path.to_string());
+            }
+            cmd.execute(&ctx, &params).unwrap();
+        }
+    }
+}
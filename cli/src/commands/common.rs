@@ -7,6 +7,9 @@ use crate::commands::get_str_param;
 
 use crate::utils::logger;
 use crate::utils::file::read_file;
+use crate::utils::output::{self, OutputFormat};
+
+use std::str::FromStr;
 
 pub mod about_command {
     use super::*;
@@ -17,15 +20,15 @@ pub mod about_command {
         trace!("execute >> _ctx: params: {:?}", _params);
 
         println_succ!("Hyperledger Indy CLI (https://github.com/hyperledger/indy-sdk)");
-        println!();
+        println_msg!();
         println_succ!("This is the official CLI tool for Hyperledger Indy (https://www.hyperledger.org/projects),");
         println_succ!("which provides a distributed-ledger-based foundation for");
         println_succ!("self-sovereign identity (https://sovrin.org/).");
-        println!();
+        println_msg!();
         println_succ!("Version: {}", env!("CARGO_PKG_VERSION"));
         println_succ!("Apache License Version 2.0");
         println_succ!("Copyright 2017 Sovrin Foundation");
-        println!();
+        println_msg!();
 
         let res = Ok(());
 
@@ -50,7 +53,7 @@ pub mod show_command {
         let content = read_file(file)
             .map_err(|err| println_err!("{}", err))?;
 
-        println!("{}", content);
+        println_msg!("{}", content);
         let res = Ok(());
 
         trace!("execute << {:?}", res);
@@ -80,6 +83,33 @@ pub mod prompt_command {
     }
 }
 
+pub mod output_format_command {
+    use super::*;
+
+    command!(CommandMetadata::build("output-format", "Change format of commands output")
+                            .add_main_param("format", "Output format: \"text\" (default) or \"json\". \
+                                In \"json\" format every command prints one JSON object with the status, error code, messages and data")
+                            .add_example("output-format json")
+                            .add_example("output-format text")
+                            .finalize());
+
+    fn execute(_ctx: &CommandContext, params: &CommandParams) -> CommandResult {
+        trace!("execute >> params: {:?}", params);
+
+        let format = get_str_param("format", params).map_err(error_err!())?;
+
+        let format = OutputFormat::from_str(format)
+            .map_err(|err| println_err!("{}", err))?;
+
+        output::set_output_format(format);
+        println_succ!("Output format has been set to \"{:?}\"", format);
+        let res = Ok(());
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
 pub mod load_plugin_command {
     use super::*;
 
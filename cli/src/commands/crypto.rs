@@ -0,0 +1,549 @@
+use crate::command_executor::{Command, CommandContext, CommandMetadata, CommandParams, CommandGroup, CommandGroupMetadata};
+use crate::commands::*;
+
+use indy::{ErrorCode, IndyError, WalletHandle};
+use crate::libindy::crypto::Crypto;
+use crate::libindy::did::Did;
+
+use serde_json::Value as JSONValue;
+
+use crate::utils::file::{read_file_bytes, write_file_bytes};
+use crate::utils::output;
+
+pub mod group {
+    use super::*;
+
+    command_group!(CommandGroupMetadata::new("crypto", "Cryptographic operations with keys of the opened wallet"));
+}
+
+pub mod sign_command {
+    use super::*;
+
+    command!(CommandMetadata::build("sign", "Sign message with the key of the opened wallet. Signature is printed in hex")
+                .add_required_param("signer", "Verkey or DID to sign with. It must be stored in the opened wallet")
+                .add_optional_param("message", "Message to sign")
+                .add_optional_param("file", "Path to the file with message to sign")
+                .add_optional_param("out", "Path to the file to store raw signature")
+                .add_example("crypto sign signer=VsKV7grR1BUE29mG2Fm2kX message=hello")
+                .add_example("crypto sign signer=GjZWsBLgZCR18aL468JAT7w9CZRiBnpxUPPgyQxh4voa file=/home/message.txt out=/home/message.sig")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let signer = get_str_param("signer", params).map_err(error_err!())?;
+        let out = get_opt_str_param("out", params).map_err(error_err!())?;
+        let message = get_payload_param("message", "file", params)?;
+
+        let signer_vk = resolve_verkey(wallet_handle, &wallet_name, signer)?;
+
+        let signature = Crypto::sign(wallet_handle, &signer_vk, &message)
+            .map_err(|err| handle_crypto_error(err, &signer_vk, &wallet_name))?;
+
+        println_succ!("Message has been signed by \"{}\"", signer_vk);
+
+        let res = output_bytes(&signature, out, false);
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod verify_command {
+    use super::*;
+
+    command!(CommandMetadata::build("verify", "Verify signature of the message")
+                .add_required_param("signer", "Verkey of the signer or DID stored in the opened wallet")
+                .add_optional_param("message", "Signed message")
+                .add_optional_param("file", "Path to the file with signed message")
+                .add_optional_param("signature", "Signature in hex")
+                .add_optional_param("signature_file", "Path to the file with raw signature")
+                .add_example("crypto verify signer=GjZWsBLgZCR18aL468JAT7w9CZRiBnpxUPPgyQxh4voa message=hello signature=3a5c...")
+                .add_example("crypto verify signer=VsKV7grR1BUE29mG2Fm2kX file=/home/message.txt signature_file=/home/message.sig")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let signer = get_str_param("signer", params).map_err(error_err!())?;
+        let message = get_payload_param("message", "file", params)?;
+        let signature = get_binary_payload_param("signature", "signature_file", params)?;
+
+        let signer_vk = match get_opened_wallet(&ctx) {
+            Some((wallet_handle, wallet_name)) => resolve_verkey(wallet_handle, &wallet_name, signer)?,
+            None => signer.to_string()
+        };
+
+        let valid = Crypto::verify(&signer_vk, &message, &signature)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        output::data(json!({"valid": valid}));
+
+        let res = if valid {
+            println_succ!("Signature is valid");
+            Ok(())
+        } else {
+            println_err!("Signature is invalid");
+            Err(())
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod pack_command {
+    use super::*;
+
+    command!(CommandMetadata::build("pack", "Pack message for the recipients (DIDComm JWE). \
+                                             Message is authcrypted if the sender is specified and anoncrypted otherwise")
+                .add_required_param("recipients", "Verkeys of the recipients or DIDs stored in the opened wallet split by comma")
+                .add_optional_param("sender", "Verkey or DID of the sender. It must be stored in the opened wallet")
+                .add_optional_param("message", "Message to pack")
+                .add_optional_param("file", "Path to the file with message to pack")
+                .add_optional_param("out", "Path to the file to store packed message")
+                .add_example(r#"crypto pack recipients=GjZWsBLgZCR18aL468JAT7w9CZRiBnpxUPPgyQxh4voa message={"@type":"ping"}"#)
+                .add_example("crypto pack recipients=VsKV7grR1BUE29mG2Fm2kX,5Uu7YveFSGcT3dSzjpvPab sender=VsKV7grR1BUE29mG2Fm2kX file=/home/message.json out=/home/packed.json")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let recipients = get_str_array_param("recipients", params).map_err(error_err!())?;
+        let sender = get_opt_str_param("sender", params).map_err(error_err!())?;
+        let out = get_opt_str_param("out", params).map_err(error_err!())?;
+        let message = get_payload_param("message", "file", params)?;
+
+        let recipients = recipients.into_iter()
+            .map(|recipient| resolve_verkey(wallet_handle, &wallet_name, recipient))
+            .collect::<Result<Vec<String>, ()>>()?;
+
+        let sender_vk = match sender {
+            Some(sender) => Some(resolve_verkey(wallet_handle, &wallet_name, sender)?),
+            None => None
+        };
+
+        let packed = Crypto::pack_message(wallet_handle, &message, &JSONValue::from(recipients).to_string(), sender_vk.as_ref().map(String::as_str))
+            .map_err(|err| handle_crypto_error(err, sender_vk.as_ref().map(String::as_str).unwrap_or_default(), &wallet_name))?;
+
+        println_succ!("Message has been packed");
+
+        let res = match out {
+            Some(_) => output_bytes(&packed, out, true),
+            None => Ok(output::print_json(&String::from_utf8_lossy(&packed), "     "))
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod unpack_command {
+    use super::*;
+
+    command!(CommandMetadata::build("unpack", "Unpack message packed for a key of the opened wallet")
+                .add_optional_param("message", "Packed message")
+                .add_optional_param("file", "Path to the file with packed message")
+                .add_optional_param("out", "Path to the file to store unpacked message content")
+                .add_example("crypto unpack file=/home/packed.json")
+                .add_example("crypto unpack file=/home/packed.json out=/home/message.json")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let out = get_opt_str_param("out", params).map_err(error_err!())?;
+        let jwe = get_payload_param("message", "file", params)?;
+
+        let unpacked = Crypto::unpack_message(wallet_handle, &jwe)
+            .map_err(|err| match err.error_code {
+                ErrorCode::WalletItemNotFound => {
+                    output::set_error_code(err.error_code as i32);
+                    println_err!("No recipient key of the message found in the wallet \"{}\"", wallet_name)
+                }
+                _ => handle_indy_error(err, None, None, Some(&wallet_name))
+            })?;
+
+        let unpacked = serde_json::from_slice::<JSONValue>(&unpacked)
+            .map_err(|_| println_err!("Wrong data has been received"))?;
+
+        println_succ!("Message has been unpacked");
+
+        let res = match out {
+            Some(_) => {
+                println_succ!("Recipient: \"{}\"", unpacked["recipient_verkey"].as_str().unwrap_or_default());
+                println_succ!("Sender: \"{}\"", unpacked["sender_verkey"].as_str().unwrap_or("-"));
+                output_bytes(unpacked["message"].as_str().unwrap_or_default().as_bytes(), out, true)
+            }
+            None => {
+                output::print_json(&unpacked.to_string(), "     ");
+                Ok(())
+            }
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod anon_crypt_command {
+    use super::*;
+
+    command!(CommandMetadata::build("anon-crypt", "Encrypt message for the recipient anonymously. Encrypted message is printed in hex")
+                .add_required_param("recipient", "Verkey of the recipient or DID stored in the opened wallet")
+                .add_optional_param("message", "Message to encrypt")
+                .add_optional_param("file", "Path to the file with message to encrypt")
+                .add_optional_param("out", "Path to the file to store raw encrypted message")
+                .add_example("crypto anon-crypt recipient=GjZWsBLgZCR18aL468JAT7w9CZRiBnpxUPPgyQxh4voa message=hello")
+                .add_example("crypto anon-crypt recipient=VsKV7grR1BUE29mG2Fm2kX file=/home/message.txt out=/home/message.enc")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let recipient = get_str_param("recipient", params).map_err(error_err!())?;
+        let out = get_opt_str_param("out", params).map_err(error_err!())?;
+        let message = get_payload_param("message", "file", params)?;
+
+        let recipient_vk = match get_opened_wallet(&ctx) {
+            Some((wallet_handle, wallet_name)) => resolve_verkey(wallet_handle, &wallet_name, recipient)?,
+            None => recipient.to_string()
+        };
+
+        let encrypted = Crypto::anon_crypt(&recipient_vk, &message)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        println_succ!("Message has been encrypted for \"{}\"", recipient_vk);
+
+        let res = output_bytes(&encrypted, out, false);
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod anon_decrypt_command {
+    use super::*;
+
+    command!(CommandMetadata::build("anon-decrypt", "Decrypt anonymously encrypted message with the key of the opened wallet")
+                .add_required_param("recipient", "Verkey or DID of the recipient. It must be stored in the opened wallet")
+                .add_optional_param("message", "Encrypted message in hex")
+                .add_optional_param("file", "Path to the file with raw encrypted message")
+                .add_optional_param("out", "Path to the file to store decrypted message")
+                .add_example("crypto anon-decrypt recipient=VsKV7grR1BUE29mG2Fm2kX message=9ef3...")
+                .add_example("crypto anon-decrypt recipient=VsKV7grR1BUE29mG2Fm2kX file=/home/message.enc out=/home/message.txt")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let recipient = get_str_param("recipient", params).map_err(error_err!())?;
+        let out = get_opt_str_param("out", params).map_err(error_err!())?;
+        let encrypted = get_binary_payload_param("message", "file", params)?;
+
+        let recipient_vk = resolve_verkey(wallet_handle, &wallet_name, recipient)?;
+
+        let decrypted = Crypto::anon_decrypt(wallet_handle, &recipient_vk, &encrypted)
+            .map_err(|err| handle_crypto_error(err, &recipient_vk, &wallet_name))?;
+
+        println_succ!("Message has been decrypted");
+
+        let res = output_bytes(&decrypted, out, true);
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+// Accepts either DID stored in the wallet or verkey itself.
+fn resolve_verkey(wallet_handle: WalletHandle, wallet_name: &str, key: &str) -> Result<String, ()> {
+    if is_verkey(key) {
+        return Ok(key.to_string());
+    }
+
+    match Did::key_for_local_did(wallet_handle, key) {
+        Ok(verkey) => Ok(verkey),
+        Err(ref err) if err.error_code == ErrorCode::WalletItemNotFound => {
+            output::set_error_code(err.error_code as i32);
+            println_err!("DID \"{}\" not found in the wallet \"{}\"", key, wallet_name);
+            Err(())
+        }
+        Err(err) => Err(handle_indy_error(err, None, None, Some(wallet_name)))
+    }
+}
+
+// Full verkeys are 32 bytes in base58 (43-44 chars) with optional ":<crypto type>" suffix, abbreviated ones start with "~".
+// Unqualified DIDs are 16 bytes in base58, so they are shorter, and qualified ones start with "did:".
+fn is_verkey(key: &str) -> bool {
+    let key = key.split(':').next().unwrap_or(key);
+    key.starts_with('~') || key.len() >= 43
+}
+
+fn get_payload_param(name: &str, file_name: &str, params: &CommandParams) -> Result<Vec<u8>, ()> {
+    match (get_opt_str_param(name, params).map_err(error_err!())?,
+           get_opt_str_param(file_name, params).map_err(error_err!())?) {
+        (Some(message), None) => Ok(message.as_bytes().to_vec()),
+        (None, Some(file)) => read_file_bytes(file).map_err(|err| println_err!("{}", err)),
+        _ => {
+            println_err!("Either \"{}\" or \"{}\" parameter must be specified", name, file_name);
+            Err(())
+        }
+    }
+}
+
+// Binary payloads are passed inline in hex and as is in files.
+fn get_binary_payload_param(name: &str, file_name: &str, params: &CommandParams) -> Result<Vec<u8>, ()> {
+    match get_opt_str_param(name, params).map_err(error_err!())? {
+        Some(hex) if !params.contains_key(file_name) =>
+            from_hex(hex).map_err(|err| println_err!("Can't parse \"{}\" parameter: {}", name, err)),
+        _ => get_payload_param(name, file_name, params)
+    }
+}
+
+fn output_bytes(bytes: &[u8], out: Option<&str>, text: bool) -> Result<(), ()> {
+    match out {
+        Some(out) => {
+            write_file_bytes(out, bytes).map_err(|err| println_err!("{}", err))?;
+            println_succ!("It has been saved to \"{}\"", out);
+        }
+        None if text => output::print_value(&String::from_utf8_lossy(bytes), "     "),
+        None => output::print_value(&to_hex(bytes), "     ")
+    }
+    Ok(())
+}
+
+fn handle_crypto_error(err: IndyError, verkey: &str, wallet_name: &str) {
+    match err.error_code {
+        ErrorCode::WalletItemNotFound => {
+            output::set_error_code(err.error_code as i32);
+            println_err!("Key \"{}\" not found in the wallet \"{}\"", verkey, wallet_name)
+        }
+        _ => handle_indy_error(err, None, None, Some(wallet_name))
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn from_hex(hex: &str) -> Result<Vec<u8>, String> {
+    if hex.len() % 2 != 0 {
+        return Err("odd number of hex digits".to_string());
+    }
+
+    (0..hex.len()).step_by(2)
+        .map(|i| hex.get(i..i + 2)
+            .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+            .ok_or_else(|| format!("invalid hex digits at position {}", i)))
+        .collect()
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::commands::did::tests::{new_did, SEED_MY1, DID_MY1, VERKEY_MY1, SEED_MY3, DID_MY3, VERKEY_MY3};
+    use crate::utils::environment::EnvironmentUtils;
+    use crate::utils::file::read_file;
+
+    const MESSAGE: &str = r#"{"@type":"ping"}"#;
+
+    mod sign {
+        use super::*;
+
+        #[test]
+        pub fn sign_and_verify_works() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_MY1);
+            let signature_path = tmp_file("message.sig");
+            {
+                let cmd = sign_command::new();
+                let mut params = CommandParams::new();
+                params.insert("signer", DID_MY1.to_string());
+                params.insert("message", MESSAGE.to_string());
+                params.insert("out", signature_path.clone());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            {
+                let cmd = verify_command::new();
+                let mut params = CommandParams::new();
+                params.insert("signer", VERKEY_MY1.to_string());
+                params.insert("message", MESSAGE.to_string());
+                params.insert("signature_file", signature_path.clone());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            {
+                let cmd = verify_command::new();
+                let mut params = CommandParams::new();
+                params.insert("signer", DID_MY1.to_string());
+                params.insert("message", MESSAGE.to_string());
+                params.insert("signature", to_hex(&read_file_bytes(&signature_path).unwrap()));
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn sign_works_for_unknown_did() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = sign_command::new();
+                let mut params = CommandParams::new();
+                params.insert("signer", DID_MY1.to_string());
+                params.insert("message", MESSAGE.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn sign_works_for_missed_message() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_MY1);
+            {
+                let cmd = sign_command::new();
+                let mut params = CommandParams::new();
+                params.insert("signer", DID_MY1.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn verify_works_for_invalid_signature() {
+            let ctx = setup();
+            {
+                let cmd = verify_command::new();
+                let mut params = CommandParams::new();
+                params.insert("signer", VERKEY_MY1.to_string());
+                params.insert("message", MESSAGE.to_string());
+                params.insert("signature", to_hex(&[0; 64]));
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down();
+        }
+    }
+
+    mod pack {
+        use super::*;
+
+        #[test]
+        pub fn pack_and_unpack_works() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_MY1);
+            new_did(&ctx, SEED_MY3);
+            let packed_path = tmp_file("packed.json");
+            let message_path = tmp_file("message.json");
+            {
+                let cmd = pack_command::new();
+                let mut params = CommandParams::new();
+                params.insert("recipients", VERKEY_MY3.to_string());
+                params.insert("sender", DID_MY1.to_string());
+                params.insert("message", MESSAGE.to_string());
+                params.insert("out", packed_path.clone());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            {
+                let cmd = unpack_command::new();
+                let mut params = CommandParams::new();
+                params.insert("file", packed_path);
+                params.insert("out", message_path.clone());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            assert_eq!(MESSAGE, read_file(&message_path).unwrap());
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn pack_works_for_unknown_recipient_did() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = pack_command::new();
+                let mut params = CommandParams::new();
+                params.insert("recipients", DID_MY3.to_string());
+                params.insert("message", MESSAGE.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+    }
+
+    mod anon_crypt {
+        use super::*;
+
+        #[test]
+        pub fn anon_crypt_and_decrypt_works() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_MY1);
+            let encrypted_path = tmp_file("message.enc");
+            let message_path = tmp_file("message.txt");
+            {
+                let cmd = anon_crypt_command::new();
+                let mut params = CommandParams::new();
+                params.insert("recipient", DID_MY1.to_string());
+                params.insert("message", MESSAGE.to_string());
+                params.insert("out", encrypted_path.clone());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            {
+                let cmd = anon_decrypt_command::new();
+                let mut params = CommandParams::new();
+                params.insert("recipient", VERKEY_MY1.to_string());
+                params.insert("message", to_hex(&read_file_bytes(&encrypted_path).unwrap()));
+                params.insert("out", message_path.clone());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            assert_eq!(MESSAGE, read_file(&message_path).unwrap());
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn anon_decrypt_works_for_invalid_hex() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_MY1);
+            {
+                let cmd = anon_decrypt_command::new();
+                let mut params = CommandParams::new();
+                params.insert("recipient", DID_MY1.to_string());
+                params.insert("message", "0xZZ".to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+    }
+
+    #[test]
+    pub fn is_verkey_works() {
+        assert!(is_verkey(VERKEY_MY1));
+        assert!(is_verkey(&format!("{}:ed25519", VERKEY_MY1)));
+        assert!(is_verkey("~NcYxiDXkpYi6ov5FcYDi1e"));
+        assert!(!is_verkey(DID_MY1));
+        assert!(!is_verkey(&format!("did:sov:{}", DID_MY1)));
+    }
+
+    #[test]
+    pub fn hex_works() {
+        assert_eq!("00ff10", to_hex(&[0, 255, 16]));
+        assert_eq!(vec![0, 255, 16], from_hex("00ff10").unwrap());
+        assert!(from_hex("0f1").is_err());
+    }
+
+    fn tmp_file(name: &str) -> String {
+        EnvironmentUtils::tmp_file_path(name).to_str().unwrap().to_string()
+    }
+}
@@ -1,8 +1,9 @@
 use crate::command_executor::{Command, CommandContext, CommandMetadata, CommandParams, CommandGroup, CommandGroupMetadata, DynamicCompletionType};
 use crate::commands::*;
-use crate::utils::table::print_list_table;
+use crate::utils::table::{print_table, print_list_table};
+use crate::utils::output;
 
-use indy::{WalletHandle, PoolHandle, ErrorCode};
+use indy::{WalletHandle, PoolHandle, ErrorCode, INVALID_POOL_HANDLE};
 
 use crate::libindy::did::Did;
 use crate::libindy::ledger::Ledger;
@@ -18,6 +19,7 @@ use crate::commands::ledger::{
     print_response_receipts,
     Response
 };
+use crate::commands::taa::check_author_agreement_rejection;
 
 pub mod group {
     use super::*;
@@ -74,6 +76,7 @@ pub mod new_command {
         let res = match res {
             Ok((did, vk)) => {
                 println_succ!("Did \"{}\" has been created with \"{}\" verkey", did, vk);
+                output::data(json!({"did": did, "verkey": vk}));
                 Ok(did)
             }
             Err(err) => {
@@ -153,8 +156,10 @@ pub mod import_command {
                                         Ok(vk) => Ok((did, vk)),
                                         Err(err) => Err(err)
                                     }) {
-                                Ok((did, vk)) =>
-                                    println_succ!("Did \"{}\" has been created with \"{}\" verkey", did, vk),
+                                Ok((did, vk)) => {
+                                    println_succ!("Did \"{}\" has been created with \"{}\" verkey", did, vk);
+                                    output::data(json!({"did": did, "verkey": vk}));
+                                }
                                 Err(err) =>
                                     println_warn!("Indy SDK error occured {} while importing DID {}", err.message, did)
                             }
@@ -353,6 +358,8 @@ pub mod rotate_key_command {
             let response: Response<serde_json::Value> = serde_json::from_str::<Response<serde_json::Value>>(&response_json)
                 .map_err(|err| println_err!("Invalid data has been received: {:?}", err))?;
 
+            check_author_agreement_rejection(ctx, &response);
+
             handle_transaction_response(response)?;
 
             parse_response_with_fees(&response_json, payment_method)?
@@ -363,6 +370,7 @@ pub mod rotate_key_command {
             Ok(vk) => {
                 println_succ!("Verkey for did \"{}\" has been updated", did);
                 println_succ!("New verkey is \"{}\"", vk);
+                output::data(json!({"did": did, "verkey": vk}));
                 Ok(())
             },
             Err(err) => {
@@ -468,6 +476,7 @@ pub mod qualify_command {
         let res = match Did::qualify_did(wallet_handle, &did, &method) {
             Ok(full_qualified_did) => {
                 println_succ!("Fully qualified DID \"{}\"", full_qualified_did);
+                output::data(json!({"did": full_qualified_did}));
 
                 if let Some(active_did) = get_active_did(&ctx) {
                     if active_did == did {
@@ -497,6 +506,119 @@ pub mod qualify_command {
     }
 }
 
+pub mod store_their_command {
+    use super::*;
+
+    command!(CommandMetadata::build("store-their", "Store their DID in the opened wallet to use it for pairwise relationships and encryption.")
+                .add_main_param("did", "Their DID")
+                .add_optional_param("verkey", "Verkey of their DID. Can be omitted if DID is self-certifying (the default verkey is derived from the DID)")
+                .add_example("did store-their 5Uu7YveFSGcT3dSzjpvPab")
+                .add_example("did store-their 5Uu7YveFSGcT3dSzjpvPab verkey=3SeuRm3uYuQDYmHeuMLu1xNHozNTtzS3kbZRFMMCWrX4")
+                .finalize());
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?}, params {:?}", ctx, params);
+
+        let did = get_str_param("did", params).map_err(error_err!())?;
+        let verkey = get_opt_str_param("verkey", params).map_err(error_err!())?;
+
+        let wallet_handle = ensure_opened_wallet_handle(ctx)?;
+
+        let identity = {
+            let mut json = JSONMap::new();
+            json.insert("did".to_string(), JSONValue::from(did));
+            update_json_map_opt_key!(json, "verkey", verkey);
+            JSONValue::from(json).to_string()
+        };
+
+        let res = match Did::store_their_did(wallet_handle, &identity) {
+            Ok(()) => {
+                println_succ!("Their DID \"{}\" has been stored", did);
+                Ok(())
+            }
+            Err(err) => {
+                handle_indy_error(err, None, None, None);
+                Err(())
+            }
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod endpoint_command {
+    use super::*;
+
+    command!(CommandMetadata::build("endpoint", "Set or show endpoint of the DID. \
+                                                 If address is not specified, endpoint stored in the opened wallet is shown. \
+                                                 If it is missed in the wallet it is requested from the connected pool.")
+                .add_main_param_with_dynamic_completion("did", "DID to set or show endpoint for", DynamicCompletionType::Did)
+                .add_optional_param("address", "Endpoint address in format <ip_address>:<port>")
+                .add_optional_param("transport_key", "Verkey of the endpoint. Required if address is specified")
+                .add_example("did endpoint 5Uu7YveFSGcT3dSzjpvPab")
+                .add_example("did endpoint 5Uu7YveFSGcT3dSzjpvPab address=127.0.0.1:5555 transport_key=3SeuRm3uYuQDYmHeuMLu1xNHozNTtzS3kbZRFMMCWrX4")
+                .finalize());
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?}, params {:?}", ctx, params);
+
+        let did = get_str_param("did", params).map_err(error_err!())?;
+        let address = get_opt_str_param("address", params).map_err(error_err!())?;
+
+        let wallet_handle = ensure_opened_wallet_handle(ctx)?;
+
+        let res = match address {
+            Some(address) => {
+                let transport_key = get_str_param("transport_key", params).map_err(error_err!())?;
+
+                match Did::set_endpoint(wallet_handle, did, address, transport_key) {
+                    Ok(()) => {
+                        println_succ!("Endpoint \"{}\" has been set for DID \"{}\"", address, did);
+                        Ok(())
+                    }
+                    Err(err) => {
+                        handle_indy_error(err, None, None, None);
+                        Err(())
+                    }
+                }
+            }
+            None => {
+                let pool_handle = get_connected_pool(ctx).map(|(handle, _)| handle).unwrap_or(INVALID_POOL_HANDLE);
+
+                match Did::get_endpoint(wallet_handle, pool_handle, did) {
+                    Ok((address, transport_key)) => {
+                        print_table(&json!({
+                                        "did": did,
+                                        "address": address,
+                                        "transport_key": transport_key
+                                    }),
+                                    &[("did", "Did"),
+                                        ("address", "Address"),
+                                        ("transport_key", "Transport Key")]);
+                        Ok(())
+                    }
+                    Err(ref err) if err.error_code == ErrorCode::PoolLedgerInvalidPoolHandle => {
+                        println_err!("Endpoint for DID \"{}\" not found in the wallet. Connect to pool to look it up on the Ledger", did);
+                        Err(())
+                    }
+                    Err(ref err) if err.error_code == ErrorCode::CommonInvalidState || err.error_code == ErrorCode::WalletItemNotFound => {
+                        println_err!("Endpoint for DID \"{}\" not found", did);
+                        Err(())
+                    }
+                    Err(err) => {
+                        handle_indy_error(err, None, None, None);
+                        Err(())
+                    }
+                }
+            }
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
 fn _list_dids(ctx: &CommandContext) -> Vec<serde_json::Value> {
     get_opened_wallet(ctx)
         .and_then(|(wallet_handle, _)|
@@ -1027,6 +1149,94 @@ pub mod tests {
         }
     }
 
+    mod did_store_their {
+        use super::*;
+
+        #[test]
+        pub fn store_their_works() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = store_their_command::new();
+                let mut params = CommandParams::new();
+                params.insert("did", DID_MY3.to_string());
+                params.insert("verkey", VERKEY_MY3.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            let wallet_handle = ensure_opened_wallet_handle(&ctx).unwrap();
+            assert_eq!(VERKEY_MY3, Did::key_for_local_did(wallet_handle, DID_MY3).unwrap());
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn store_their_works_for_invalid_did() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = store_their_command::new();
+                let mut params = CommandParams::new();
+                params.insert("did", "invalid_base58_did".to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+    }
+
+    mod did_endpoint {
+        use super::*;
+
+        const ADDRESS: &str = "127.0.0.1:5555";
+
+        #[test]
+        pub fn endpoint_works() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = endpoint_command::new();
+                let mut params = CommandParams::new();
+                params.insert("did", DID_MY3.to_string());
+                params.insert("address", ADDRESS.to_string());
+                params.insert("transport_key", VERKEY_MY3.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            {
+                let cmd = endpoint_command::new();
+                let mut params = CommandParams::new();
+                params.insert("did", DID_MY3.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            let wallet_handle = ensure_opened_wallet_handle(&ctx).unwrap();
+            let (address, transport_key) = Did::get_endpoint(wallet_handle, INVALID_POOL_HANDLE, DID_MY3).unwrap();
+            assert_eq!(ADDRESS, address);
+            assert_eq!(Some(VERKEY_MY3.to_string()), transport_key);
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn endpoint_works_for_missed_transport_key() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = endpoint_command::new();
+                let mut params = CommandParams::new();
+                params.insert("did", DID_MY3.to_string());
+                params.insert("address", ADDRESS.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn endpoint_works_for_unknown_endpoint_and_no_pool() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = endpoint_command::new();
+                let mut params = CommandParams::new();
+                params.insert("did", DID_MY3.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+    }
+
     fn get_did_info(ctx: &CommandContext, did: &str) -> serde_json::Value {
         let wallet_handle = ensure_opened_wallet_handle(ctx).unwrap();
         let did_info = Did::get_did_with_meta(wallet_handle, did).unwrap();
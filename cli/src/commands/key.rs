@@ -0,0 +1,247 @@
+use crate::command_executor::{Command, CommandContext, CommandMetadata, CommandParams, CommandGroup, CommandGroupMetadata};
+use crate::commands::*;
+
+use indy::ErrorCode;
+use crate::libindy::crypto::Crypto;
+
+use serde_json::Value as JSONValue;
+use serde_json::Map as JSONMap;
+
+use crate::utils::table::print_table;
+
+pub mod group {
+    use super::*;
+
+    command_group!(CommandGroupMetadata::new("key", "Keys management commands"));
+}
+
+pub mod new_command {
+    use super::*;
+
+    command!(CommandMetadata::build("new", "Create new key pair in the opened wallet")
+                .add_optional_deferred_param("seed", "Seed for creating key pair (UTF-8, base64 or hex)")
+                .add_optional_param("crypto_type", "Crypto type of the key (ed25519 by default)")
+                .add_optional_param("metadata", "Key metadata")
+                .add_example("key new")
+                .add_example("key new seed=00000000000000000000000000000My1")
+                .add_example("key new crypto_type=ed25519 metadata=transport")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, secret!(params));
+
+        let wallet_handle = ensure_opened_wallet_handle(&ctx)?;
+
+        let seed = get_opt_str_param("seed", params).map_err(error_err!())?;
+        let crypto_type = get_opt_str_param("crypto_type", params).map_err(error_err!())?;
+        let metadata = get_opt_str_param("metadata", params).map_err(error_err!())?;
+
+        let config = {
+            let mut json = JSONMap::new();
+            update_json_map_opt_key!(json, "seed", seed);
+            update_json_map_opt_key!(json, "crypto_type", crypto_type);
+            JSONValue::from(json).to_string()
+        };
+
+        let verkey = Crypto::create_key(wallet_handle, Some(&config))
+            .map_err(|err| match err.error_code {
+                ErrorCode::WalletItemAlreadyExists => println_err!("Key already exists"),
+                _ => handle_indy_error(err, None, None, None),
+            })?;
+
+        println_succ!("Key \"{}\" has been created", verkey);
+
+        let res = match metadata {
+            Some(metadata) =>
+                Crypto::set_key_metadata(wallet_handle, &verkey, metadata)
+                    .map(|_| println_succ!("Metadata has been saved for key \"{}\"", verkey))
+                    .map_err(|err| handle_indy_error(err, None, None, None)),
+            None => Ok(())
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod set_metadata_command {
+    use super::*;
+
+    command!(CommandMetadata::build("set-metadata", "Set metadata of the key stored in the opened wallet")
+                .add_main_param("verkey", "Verkey of the key")
+                .add_required_param("metadata", "Key metadata")
+                .add_example("key set-metadata GjZWsBLgZCR18aL468JAT7w9CZRiBnpxUPPgyQxh4voa metadata=transport")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let wallet_handle = ensure_opened_wallet_handle(&ctx)?;
+
+        let verkey = get_str_param("verkey", params).map_err(error_err!())?;
+        let metadata = get_str_param("metadata", params).map_err(error_err!())?;
+
+        let res = match Crypto::set_key_metadata(wallet_handle, verkey, metadata) {
+            Ok(()) => {
+                println_succ!("Metadata has been saved for key \"{}\"", verkey);
+                Ok(())
+            }
+            Err(err) => {
+                handle_indy_error(err, None, None, None);
+                Err(())
+            }
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod get_metadata_command {
+    use super::*;
+
+    command!(CommandMetadata::build("get-metadata", "Get metadata of the key stored in the opened wallet")
+                .add_main_param("verkey", "Verkey of the key")
+                .add_example("key get-metadata GjZWsBLgZCR18aL468JAT7w9CZRiBnpxUPPgyQxh4voa")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let wallet_handle = ensure_opened_wallet_handle(&ctx)?;
+
+        let verkey = get_str_param("verkey", params).map_err(error_err!())?;
+
+        let res = match Crypto::get_key_metadata(wallet_handle, verkey) {
+            Ok(metadata) => {
+                print_table(&json!({"verkey": verkey, "metadata": metadata}),
+                            &[("verkey", "Verkey"),
+                                ("metadata", "Metadata")]);
+                Ok(())
+            }
+            Err(err) => {
+                match err.error_code {
+                    ErrorCode::WalletItemNotFound => println_err!("Metadata for key \"{}\" not found", verkey),
+                    _ => handle_indy_error(err, None, None, None),
+                };
+                Err(())
+            }
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::commands::did::tests::{SEED_MY1, VERKEY_MY1};
+
+    const METADATA: &str = "transport";
+
+    mod key_new {
+        use super::*;
+
+        #[test]
+        pub fn new_works() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = new_command::new();
+                let params = CommandParams::new();
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn new_works_for_seed_and_metadata() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = new_command::new();
+                let mut params = CommandParams::new();
+                params.insert("seed", SEED_MY1.to_string());
+                params.insert("metadata", METADATA.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            assert_eq!(METADATA, get_metadata(&ctx));
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn new_works_for_wrong_seed() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = new_command::new();
+                let mut params = CommandParams::new();
+                params.insert("seed", "invalid_base58_string".to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn new_works_for_no_opened_wallet() {
+            let ctx = setup();
+            {
+                let cmd = new_command::new();
+                let params = CommandParams::new();
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down();
+        }
+    }
+
+    mod key_metadata {
+        use super::*;
+
+        #[test]
+        pub fn set_and_get_metadata_works() {
+            let ctx = setup_with_wallet();
+            create_key(&ctx);
+            {
+                let cmd = set_metadata_command::new();
+                let mut params = CommandParams::new();
+                params.insert("verkey", VERKEY_MY1.to_string());
+                params.insert("metadata", METADATA.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            {
+                let cmd = get_metadata_command::new();
+                let mut params = CommandParams::new();
+                params.insert("verkey", VERKEY_MY1.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            assert_eq!(METADATA, get_metadata(&ctx));
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn get_metadata_works_for_no_metadata() {
+            let ctx = setup_with_wallet();
+            create_key(&ctx);
+            {
+                let cmd = get_metadata_command::new();
+                let mut params = CommandParams::new();
+                params.insert("verkey", VERKEY_MY1.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+    }
+
+    fn create_key(ctx: &CommandContext) {
+        let wallet_handle = ensure_opened_wallet_handle(&ctx).unwrap();
+        Crypto::create_key(wallet_handle, Some(&json!({"seed": SEED_MY1}).to_string())).unwrap();
+    }
+
+    fn get_metadata(ctx: &CommandContext) -> String {
+        let wallet_handle = ensure_opened_wallet_handle(&ctx).unwrap();
+        Crypto::get_key_metadata(wallet_handle, VERKEY_MY1).unwrap()
+    }
+}
@@ -4,6 +4,7 @@ extern crate chrono;
 use crate::command_executor::{Command, CommandContext, CommandMetadata, CommandParams, CommandGroup, CommandGroupMetadata, DynamicCompletionType};
 use crate::commands::*;
 use crate::commands::payment_address::handle_payment_error;
+use crate::commands::taa::{ensure_author_agreement, check_author_agreement_rejection};
 
 use indy::{ErrorCode, IndyError, WalletHandle, PoolHandle, INVALID_WALLET_HANDLE};
 use crate::libindy::ledger::Ledger;
@@ -16,6 +17,8 @@ use std::collections::{HashMap, BTreeMap};
 
 use crate::utils::table::{print_table, print_list_table};
 use crate::utils::file::{read_file, write_file};
+use crate::utils::output;
+use crate::utils::merkle;
 
 use self::regex::Regex;
 use self::chrono::prelude::*;
@@ -81,10 +84,12 @@ macro_rules! send_request {
             let response = serde_json::from_str::<Response<serde_json::Value>>(&response_json)
                 .map_err(|err| println_err!("Invalid data has been received: {:?}", err))?;
 
+            check_author_agreement_rejection($ctx, &response);
+
             (response_json, response)
         } else {
             println_succ!("Transaction has been created:");
-            println!("     {}", $request);
+            output::print_json(&$request, "     ");
             set_transaction($ctx, Some($request.to_string()));
             return Ok(());
         }
@@ -96,20 +101,20 @@ macro_rules! get_transaction_to_use {
         if let Some(txn_) = $param_txn {
             txn_.to_string()
         } else if let Some(txn_) = get_transaction($ctx) {
-            println!("Transaction stored into context: {:?}.", txn_);
-            println!("Would you like to use it? (y/n)");
+            println_msg!("Transaction stored into context: {:?}.", txn_);
+            println_msg!("Would you like to use it? (y/n)");
 
             let use_transaction = crate::command_executor::wait_for_user_reply($ctx);
 
             if !use_transaction {
-                println!("No transaction has been used.");
+                println_msg!("No transaction has been used.");
                 return Ok(());
             }
 
             txn_.to_string()
         } else {
             println_err!("There is not a transaction to use.");
-            println!("You either need to explicitly pass transaction as a parameter, or \
+            println_msg!("You either need to explicitly pass transaction as a parameter, or \
                     load transaction using `ledger load-transaction`, or \
                     build a transaction (with passing either `send=false` or `endorser` parameter).");
             return Err(());
@@ -167,7 +172,7 @@ pub mod nym_command {
                     let change_nym = crate::command_executor::wait_for_user_reply(ctx);
 
                     if !change_nym {
-                        println!("The transaction has not been sent.");
+                        println_msg!("The transaction has not been sent.");
                         return Ok(());
                     }
                 }
@@ -497,34 +502,37 @@ pub mod get_validator_info_command {
             Err(_) => {
                 let response = serde_json::from_str::<Response<serde_json::Value>>(&response)
                     .map_err(|err| println_err!("Invalid data has been received: {:?}", err))?;
-                return handle_transaction_response(response).map(|result| println_succ!("{}", result));
+                return handle_transaction_response(response).map(|result| {
+                    println_succ!("Validator Info:");
+                    output::print_json(&result.to_string(), "");
+                });
             }
         };
 
         println_succ!("Validator Info:");
 
-        let mut lines: Vec<String> = Vec::new();
+        let mut info = JSONMap::new();
 
         for (node, response) in responses {
             if response.eq("timeout") {
-                lines.push(format!("\t{:?}: {:?}", node, "Timeout"));
+                info.insert(node, JSONValue::from("Timeout"));
                 continue
             }
             let response = match serde_json::from_str::<Response<serde_json::Value>>(&response) {
                 Ok(resp) => resp,
                 Err(err) => {
-                    lines.push(format!("\t{:?}: \"Invalid data has been received: {:?}\"", node, err));
+                    info.insert(node, JSONValue::from(format!("Invalid data has been received: {:?}", err)));
                     continue
                 }
             };
 
             match handle_transaction_response(response) {
-                Ok(result) => lines.push(format!("\t{:?}: {}", node, result)),
+                Ok(result) => { info.insert(node, result); }
                 Err(_) => {}
             };
         }
 
-        println!("{{\n{}\n}}", lines.join(",\n"));
+        output::print_json(&serde_json::to_string_pretty(&info).unwrap_or_default(), "");
 
         let res = Ok(());
 
@@ -639,27 +647,7 @@ pub mod cred_def_command {
             JSONValue::from(json).to_string()
         };
 
-        let mut request = Ledger::build_cred_def_request(&submitter_did, &cred_def_data)
-            .map_err(|err| handle_indy_error(err, None, None, None))?;
-
-        set_author_agreement(ctx, &mut request)?;
-
-        let payment_method = set_request_fees(ctx, params, &mut request, wallet_handle, Some(&submitter_did))?;
-
-        let (response_json, response): (String, Response<serde_json::Value>) =
-            send_write_request!(ctx, params, &request, wallet_handle, &wallet_name, &submitter_did);
-
-        handle_transaction_response(response)
-            .map(|result| print_transaction_response(result,
-                                                     "NodeConfig request has been sent to Ledger.",
-                                                     Some("data"),
-                                                     &[("primary", "Primary Key"),
-                                                         ("revocation", "Revocation Key")],
-                                                     true))?;
-
-        let receipts = parse_response_with_fees(&response_json, payment_method)?;
-
-        let res = print_response_receipts(receipts);
+        let res = send_cred_def(ctx, params, wallet_handle, &wallet_name, &submitter_did, &cred_def_data);
 
         trace!("execute << {:?}", res);
         res
@@ -991,11 +979,11 @@ pub mod pool_upgrade_command {
                                                      true));
         if let Some(h) = hash {
             println_succ!("Hash:");
-            println!("{}", h);
+            println_msg!("{}", h);
         }
         if let Some(s) = schedule {
             println_succ!("Schedule:");
-            println!("{}", s);
+            println_msg!("{}", s);
         }
         trace!("execute << {:?}", res);
         res
@@ -1029,13 +1017,13 @@ pub mod custom_command {
 
             match context_txn {
                 Some(txn_) => {
-                    println!("Transaction stored into context: {:?}.", txn_);
-                    println!("Would you like to send it? (y/n)");
+                    println_msg!("Transaction stored into context: {:?}.", txn_);
+                    println_msg!("Would you like to send it? (y/n)");
 
                     let use_transaction = crate::command_executor::wait_for_user_reply(ctx);
 
                     if !use_transaction {
-                        println!("No transaction has been send.");
+                        println_msg!("No transaction has been send.");
                         return Ok(());
                     }
 
@@ -1043,7 +1031,7 @@ pub mod custom_command {
                 }
                 None => {
                     println_err!("There is not a transaction stored into CLI context.");
-                    println!("You either need to load transaction using `ledger load-transaction`, or \
+                    println_msg!("You either need to load transaction using `ledger load-transaction`, or \
                         build a transaction (with passing a `send=false`) to store it into CLI context.");
                 }
             }
@@ -1069,10 +1057,13 @@ pub mod custom_command {
         let response = serde_json::from_str::<Response<serde_json::Value>>(&response_json)
             .map_err(|err| println_err!("Invalid data has been received: {:?}", err))?;
 
+        check_author_agreement_rejection(ctx, &response);
+
         let res = match response {
             Response { op: ResponseType::REPLY, result: Some(_), reason: None } =>
                 {
-                    println!("Response: \n{}", response_json);
+                    println_msg!("Response: ");
+                    output::print_json(&response_json, "");
                     Ok(())
                 },
             Response { op: ResponseType::REQNACK, result: None, reason: Some(reason) } |
@@ -1182,8 +1173,10 @@ pub mod payment_command {
 
         let (inputs, outputs) = prepare_sources_for_payment_cmd(&ctx, source_payment_address, target_payment_address, amount, fee, inputs, outputs)?;
 
-        if let Some((text, version, acc_mech_type, time_of_acceptance)) = get_transaction_author_info(&ctx) {
-            extra = Some(Payment::prepare_payment_extra_with_acceptance_data(extra.as_ref().map(String::as_str), Some(&text), Some(&version), None, &acc_mech_type, time_of_acceptance)
+        if let Some(acceptance) = ensure_author_agreement(ctx, submitter_did.as_ref().map(String::as_str))? {
+            let (text, version, digest) = acceptance.agreement();
+
+            extra = Some(Payment::prepare_payment_extra_with_acceptance_data(extra.as_ref().map(String::as_str), text, version, digest, acceptance.mechanism(), acceptance.time)
                 .map_err(|err| handle_payment_error(err, None))?);
         }
 
@@ -1304,7 +1297,7 @@ pub mod mint_prepare_command {
         set_author_agreement(ctx, &mut request)?;
 
         println_succ!("MINT transaction has been created:");
-        println!("     {}", request);
+        output::print_json(&request, "     ");
         set_transaction(&ctx, Some(request));
 
         let res = Ok(());
@@ -1338,7 +1331,7 @@ pub mod set_fees_prepare_command {
             .map_err(|err| handle_payment_error(err, None))?;
 
         println_succ!("SET_FEES transaction has been created:");
-        println!("     {}", request);
+        output::print_json(&request, "     ");
         set_transaction(&ctx, Some(request));
 
         let res = Ok(());
@@ -1373,7 +1366,7 @@ pub mod verify_payment_receipt_command {
         let res = match Payment::parse_verify_payment_response(&payment_method, &response) {
             Ok(info_json) => {
                 println_succ!("Following Payment Receipt Verification Info has been received.");
-                println!("{}", info_json);
+                output::print_json(&info_json, "");
                 Ok(())
             }
             Err(err) => {
@@ -1532,7 +1525,7 @@ pub mod auth_rules_command {
             send_write_request!(ctx, params, &request, wallet_handle, &wallet_name, &submitter_did);
 
         let result = handle_transaction_response(response)?;
-        println!("result {:?}", result);
+        println_msg!("result {:?}", result);
 
         let rules: AuthRulesData = serde_json::from_value(result["txn"]["data"]["rules"].clone())
             .map_err(|_| println_err!("Wrong data has been received"))?;
@@ -1647,13 +1640,13 @@ pub mod save_transaction_command {
 
         let transaction = ensure_set_transaction(ctx)?;
 
-        println!("Transaction: {:?}.", transaction);
-        println!("Would you like to save it? (y/n)");
+        println_msg!("Transaction: {:?}.", transaction);
+        println_msg!("Would you like to save it? (y/n)");
 
         let save_transaction = crate::command_executor::wait_for_user_reply(ctx);
 
         if !save_transaction {
-            println!("The transaction has not been saved.");
+            println_msg!("The transaction has not been saved.");
             return Ok(());
         }
 
@@ -1696,7 +1689,7 @@ pub mod load_transaction_command {
         serde_json::from_str::<Request>(&transaction)
             .map_err(|err| println_err!("File contains invalid transaction: {:?}", err))?;
 
-        println!("Transaction has been loaded: {}", transaction);
+        println_msg!("Transaction has been loaded: {}", transaction);
 
         set_transaction(ctx, Some(transaction));
 
@@ -1798,7 +1791,7 @@ pub mod taa_command {
                                                    ("ratification_ts", "Ratification Time"),
                                                    ("retirement_ts", "Retirement Time")],
                                                true);
-                    crate::commands::pool::accept_transaction_author_agreement(ctx, &text, &version);
+                    crate::commands::pool::accept_transaction_author_agreement(ctx, &text, &version, None);
                 }
             })?;
 
@@ -2019,7 +2012,7 @@ pub mod get_acceptance_mechanisms_command {
                         .collect::<Vec<serde_json::Value>>();
 
                 if !aml.is_empty() {
-                    println!("Following Acceptance Mechanisms are set on the Ledger");
+                    println_msg!("Following Acceptance Mechanisms are set on the Ledger");
                 }
 
                 print_list_table(&aml,
@@ -2027,12 +2020,13 @@ pub mod get_acceptance_mechanisms_command {
                                      ("description", "Description")],
                                  "There are no acceptance mechanisms");
 
-                println!("Version: {}", result["data"]["version"].as_str().unwrap_or_default());
+                println_msg!("Version: {}", result["data"]["version"].as_str().unwrap_or_default());
+                output::data(json!({"version": result["data"]["version"], "amlContext": result["data"]["amlContext"]}));
 
                 if let Some(context) = result["data"]["amlContext"].as_str() {
-                    println!("Context: {}", context);
+                    println_msg!("Context: {}", context);
                 }
-                println!();
+                println_msg!();
             }
             Err(_) => {}
         }
@@ -2064,7 +2058,8 @@ pub mod ledgers_freeze_command {
 
         let result = handle_transaction_response(response)?;
 
-        println!("result {:?}", result);
+        println_succ!("Ledgers freeze request has been sent to Ledger.");
+        output::print_json(&result.to_string(), "");
 
         trace!("execute <<");
         Ok(())
@@ -2125,14 +2120,191 @@ pub mod get_frozen_ledgers_command {
     }
 }
 
-pub fn set_author_agreement(ctx: &CommandContext, request: &mut String) -> Result<(), ()> {
-    if let Some((text, version, acc_mech_type, time_of_acceptance)) = get_transaction_author_info(&ctx) {
-        if acc_mech_type.is_empty() {
-            println_err!("Transaction author agreement Acceptance Mechanism isn't set.");
+pub mod txn_range_command {
+    use super::*;
+
+    command!(CommandMetadata::build("txn-range", "Get a range of transactions from the Ledger and show them decoded per transaction type.")
+                .add_main_param("ledger", "Type of the ledger. One of: DOMAIN, POOL, CONFIG")
+                .add_required_param("from", "Sequence number of the first transaction")
+                .add_required_param("to", "Sequence number of the last transaction")
+                .add_example("ledger txn-range DOMAIN from=1 to=10")
+                .add_example("ledger txn-range POOL from=1 to=4")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (pool_handle, pool_name) = ensure_connected_pool(&ctx)?;
+        let submitter_did = get_active_did(&ctx);
+
+        let (ledger_type, from, to) = get_txn_range_params(params)?;
+
+        let txns = fetch_txn_range(pool_handle, &pool_name, submitter_did.as_ref().map(String::as_str), ledger_type, from, to)?;
+
+        print_ledger_txns(&txns.iter().map(|txn| &txn.txn).collect::<Vec<&JSONValue>>(),
+                          &format!("There are no transactions in the range {}-{} of {} ledger", from, to, ledger_type));
+
+        let res = Ok(());
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod search_command {
+    use super::*;
+
+    command!(CommandMetadata::build("search", "Get a range of transactions from the Ledger and show the ones matching the filters. \
+                                               Transactions are filtered locally so only the given range is searched.")
+                .add_main_param("ledger", "Type of the ledger. One of: DOMAIN, POOL, CONFIG")
+                .add_required_param("from", "Sequence number of the first transaction")
+                .add_required_param("to", "Sequence number of the last transaction")
+                .add_optional_param("type", "Transaction type alias or associated value (e.g. NYM or 1)")
+                .add_optional_param_with_dynamic_completion("submitter", "DID of the transaction submitter", DynamicCompletionType::Did)
+                .add_optional_param("target", "Target DID of the transaction (`dest` field)")
+                .add_example("ledger search DOMAIN from=1 to=100 type=NYM")
+                .add_example("ledger search DOMAIN from=1 to=100 submitter=V4SGRU86Z58d6TV7PBUe6f")
+                .add_example("ledger search DOMAIN from=1 to=100 type=ATTRIB target=VsKV7grR1BUE29mG2Fm2kX")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (pool_handle, pool_name) = ensure_connected_pool(&ctx)?;
+        let submitter_did = get_active_did(&ctx);
+
+        let (ledger_type, from, to) = get_txn_range_params(params)?;
+        let txn_type = get_opt_str_param("type", params).map_err(error_err!())?;
+        let submitter = get_opt_str_param("submitter", params).map_err(error_err!())?;
+        let target = get_opt_str_param("target", params).map_err(error_err!())?;
+
+        let txns = fetch_txn_range(pool_handle, &pool_name, submitter_did.as_ref().map(String::as_str), ledger_type, from, to)?;
+
+        let found = txns.iter()
+            .map(|txn| &txn.txn)
+            .filter(|txn| ledger_txn_matches(txn, txn_type, submitter, target))
+            .collect::<Vec<&JSONValue>>();
+
+        println_succ!("{} of {} transaction(s) match the filters", found.len(), txns.len());
+        print_ledger_txns(&found, "There are no transactions matching the filters");
+
+        let res = Ok(());
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod export_command {
+    use super::*;
+
+    command!(CommandMetadata::build("export", "Export a range of transactions from the Ledger into a file (one JSON transaction per line). \
+                                               Merkle audit path of every transaction is checked against the ledger root hash multi-signed by the pool nodes.")
+                .add_main_param("ledger", "Type of the ledger. One of: DOMAIN, POOL, CONFIG")
+                .add_required_param("from", "Sequence number of the first transaction")
+                .add_required_param("to", "Sequence number of the last transaction")
+                .add_required_param("file", "The path to the file to export transactions")
+                .add_example("ledger export DOMAIN from=1 to=1000 file=/home/domain.ndjson")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (pool_handle, pool_name) = ensure_connected_pool(&ctx)?;
+        let submitter_did = get_active_did(&ctx);
+
+        let (ledger_type, from, to) = get_txn_range_params(params)?;
+        let file = get_str_param("file", params).map_err(error_err!())?;
+
+        let txns = fetch_txn_range(pool_handle, &pool_name, submitter_did.as_ref().map(String::as_str), ledger_type, from, to)?;
+
+        let (root_hash, ledger_size) = match txns.last() {
+            Some(txn) => (txn.root_hash.clone(), txn.ledger_size),
+            None => {
+                println_err!("There are no transactions in the range {}-{} of {} ledger", from, to, ledger_type);
+                return Err(());
+            }
+        };
+
+        let unverified = txns.iter()
+            .filter(|txn| !verify_ledger_txn(txn))
+            .map(|txn| txn.seq_no.to_string())
+            .collect::<Vec<String>>();
+
+        if !unverified.is_empty() {
+            println_err!("Merkle audit path does not match the multi-signed ledger root hash for transaction(s): {}", unverified.join(","));
             return Err(());
         }
 
-        *request = Ledger::append_txn_author_agreement_acceptance_to_request(&request, Some(&text), Some(&version), None, &acc_mech_type, time_of_acceptance)
+        if txns.iter().any(|txn| txn.root_hash != root_hash) {
+            println_warn!("Ledger has grown while exporting. Transactions have been checked against the root hash at the time they were received");
+        }
+
+        let content = txns.iter()
+            .map(|txn| txn.txn.to_string())
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        write_file(file, &format!("{}\n", content))
+            .map_err(|err| println_err!("Cannot store transactions into the file: {:?}", err))?;
+
+        println_succ!("{} transaction(s) have been exported to \"{}\"", txns.len(), file);
+        print_table(&json!({
+                        "ledger": ledger_type,
+                        "from": from,
+                        "to": txns.last().map(|txn| txn.seq_no),
+                        "root_hash": root_hash,
+                        "ledger_size": ledger_size,
+                    }),
+                    &[("ledger", "Ledger"),
+                        ("from", "From"),
+                        ("to", "To"),
+                        ("root_hash", "Root Hash"),
+                        ("ledger_size", "Ledger Size")]);
+
+        let res = Ok(());
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub fn send_cred_def(ctx: &CommandContext, params: &CommandParams, wallet_handle: WalletHandle, wallet_name: &str, submitter_did: &str, cred_def_data: &str) -> Result<(), ()> {
+    let mut request = Ledger::build_cred_def_request(submitter_did, cred_def_data)
+        .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+    set_author_agreement(ctx, &mut request)?;
+
+    let payment_method = set_request_fees(ctx, params, &mut request, wallet_handle, Some(submitter_did))?;
+
+    let (response_json, response): (String, Response<serde_json::Value>) =
+        send_write_request!(ctx, params, &request, wallet_handle, wallet_name, submitter_did);
+
+    handle_transaction_response(response)
+        .map(|result| print_transaction_response(result,
+                                                 "NodeConfig request has been sent to Ledger.",
+                                                 Some("data"),
+                                                 &[("primary", "Primary Key"),
+                                                     ("revocation", "Revocation Key")],
+                                                 true))?;
+
+    let receipts = parse_response_with_fees(&response_json, payment_method)?;
+
+    print_response_receipts(receipts)
+}
+
+pub fn set_author_agreement(ctx: &CommandContext, request: &mut String) -> Result<(), ()> {
+    let submitter_did = serde_json::from_str::<JSONValue>(&request).ok()
+        .and_then(|request| request["identifier"].as_str().map(String::from))
+        .or_else(|| get_active_did(ctx));
+
+    if let Some(acceptance) = ensure_author_agreement(ctx, submitter_did.as_ref().map(String::as_str))? {
+        let (text, version, digest) = acceptance.agreement();
+
+        *request = Ledger::append_txn_author_agreement_acceptance_to_request(&request, text, version, digest, acceptance.mechanism(), acceptance.time)
             .map_err(|err| handle_indy_error(err, None, None, None))?;
     };
     Ok(())
@@ -2218,7 +2390,7 @@ fn parse_payment_fees(fees: &[&str]) -> Result<String, ()> {
     serialize(&fees_map)
 }
 
-fn parse_transaction_response(mut result: serde_json::Value) -> Result<(Vec<(&'static str, &'static str)>, serde_json::Value, serde_json::Value), ()> {
+pub fn parse_transaction_response(mut result: serde_json::Value) -> Result<(Vec<(&'static str, &'static str)>, serde_json::Value, serde_json::Value), ()> {
     match result["ver"].clone().as_str() {
         None => Ok(parse_transaction_response_v0(&mut result)),
         Some("1") => Ok(parse_transaction_response_v1(&mut result)),
@@ -2315,7 +2487,7 @@ fn extract_error_message(error: &str) -> String {
     }
 }
 
-fn get_role_title(role: &serde_json::Value) -> serde_json::Value {
+pub fn get_role_title(role: &serde_json::Value) -> serde_json::Value {
     serde_json::Value::String(match role.as_str() {
         Some("0") => "TRUSTEE",
         Some("2") => "STEWARD",
@@ -2325,7 +2497,7 @@ fn get_role_title(role: &serde_json::Value) -> serde_json::Value {
     }.to_string())
 }
 
-fn get_txn_title(role: &serde_json::Value) -> serde_json::Value {
+pub fn get_txn_title(role: &serde_json::Value) -> serde_json::Value {
     serde_json::Value::String(match role.as_str() {
         Some("0") => "NODE",
         Some("1") => "NYM",
@@ -2364,6 +2536,195 @@ fn timestamp_to_datetime(_time: i64) -> String {
     NaiveDateTime::from_timestamp(_time, 0).to_string()
 }
 
+/// Ledger transaction received by GET_TXN with the merkle info of the reply.
+/// `signed_root_hash` is the ledger root hash covered by BLS multi-signature of the pool nodes.
+/// Libindy checks the multi-signature when it accepts the reply, so only this root is authenticated.
+#[derive(Debug)]
+pub struct LedgerTxn {
+    pub seq_no: u64,
+    pub txn: JSONValue,
+    pub audit_path: Vec<String>,
+    pub root_hash: Option<String>,
+    pub ledger_size: Option<u64>,
+    pub signed_root_hash: Option<String>,
+}
+
+fn get_txn_range_params(params: &CommandParams) -> Result<(&str, i32, i32), ()> {
+    let ledger_type = get_str_param("ledger", params).map_err(error_err!())?;
+    let from = get_number_param::<i32>("from", params).map_err(error_err!())?;
+    let to = get_number_param::<i32>("to", params).map_err(error_err!())?;
+
+    if from < 1 || to < from {
+        println_err!("Invalid range of transactions: {}-{}. Sequence numbers start from 1", from, to);
+        return Err(());
+    }
+
+    Ok((ledger_type, from, to))
+}
+
+fn fetch_txn(pool_handle: PoolHandle, pool_name: &str, submitter_did: Option<&str>, ledger_type: &str, seq_no: i32) -> Result<Option<LedgerTxn>, ()> {
+    let request = Ledger::build_get_txn_request(submitter_did, Some(ledger_type), seq_no)
+        .map_err(|err| handle_indy_error(err, submitter_did, Some(pool_name), None))?;
+
+    let response_json = Ledger::submit_request(pool_handle, &request)
+        .map_err(|err| handle_indy_error(err, submitter_did, Some(pool_name), None))?;
+
+    let response = serde_json::from_str::<Response<JSONValue>>(&response_json)
+        .map_err(|err| println_err!("Invalid data has been received: {:?}", err))?;
+
+    let mut result = handle_transaction_response(response)?;
+    let mut data = result["data"].take();
+
+    if data.is_null() {
+        return Ok(None);
+    }
+
+    let signed_root_hash = result["state_proof"]["multi_signature"]["value"]["txn_root_hash"].as_str().map(String::from);
+
+    let audit_path = data["auditPath"].as_array()
+        .map(|path| path.iter().filter_map(|hash| hash.as_str().map(String::from)).collect())
+        .unwrap_or_default();
+    let root_hash = data["rootHash"].as_str().map(String::from);
+    let ledger_size = data["ledgerSize"].as_u64();
+
+    if let Some(data) = data.as_object_mut() {
+        data.remove("auditPath");
+        data.remove("rootHash");
+        data.remove("ledgerSize");
+    }
+
+    Ok(Some(LedgerTxn { seq_no: seq_no as u64, txn: data, audit_path, root_hash, ledger_size, signed_root_hash }))
+}
+
+/// Fetches transactions one by one. Stops at the end of the ledger if the range exceeds it.
+fn fetch_txn_range(pool_handle: PoolHandle, pool_name: &str, submitter_did: Option<&str>, ledger_type: &str, from: i32, to: i32) -> Result<Vec<LedgerTxn>, ()> {
+    let mut txns = Vec::new();
+
+    for seq_no in from..=to {
+        match fetch_txn(pool_handle, pool_name, submitter_did, ledger_type, seq_no)? {
+            Some(txn) => txns.push(txn),
+            None => break
+        }
+    }
+
+    Ok(txns)
+}
+
+// Root hash and ledger size of the reply alone are not authenticated,
+// so the audit path is checked only against the root hash multi-signed by the pool.
+fn verify_ledger_txn(txn: &LedgerTxn) -> bool {
+    match (txn.root_hash.as_ref(), txn.ledger_size) {
+        (Some(root_hash), Some(ledger_size)) if txn.signed_root_hash.as_ref() == Some(root_hash) => {
+            let audit_path = txn.audit_path.iter().map(String::as_str).collect::<Vec<&str>>();
+            merkle::verify_audit_path(&txn.txn, txn.seq_no, ledger_size, &audit_path, root_hash)
+                .unwrap_or(false)
+        }
+        _ => false
+    }
+}
+
+fn ledger_txn_matches(txn: &JSONValue, txn_type: Option<&str>, submitter: Option<&str>, target: Option<&str>) -> bool {
+    let type_matches = txn_type
+        .map(|txn_type| {
+            let type_ = &txn["txn"]["type"];
+            type_.as_str() == Some(txn_type) || get_txn_title(type_).as_str().map(|title| title.eq_ignore_ascii_case(txn_type)).unwrap_or(false)
+        })
+        .unwrap_or(true);
+
+    let submitter_matches = submitter.map(|did| txn["txn"]["metadata"]["from"].as_str() == Some(did)).unwrap_or(true);
+    let target_matches = target.map(|did| txn["txn"]["data"]["dest"].as_str() == Some(did)).unwrap_or(true);
+
+    type_matches && submitter_matches && target_matches
+}
+
+/// Decodes ledger transaction into a table row with columns specific for the transaction type.
+fn decode_ledger_txn(txn: &JSONValue) -> (Vec<(&'static str, &'static str)>, JSONValue) {
+    let data = &txn["txn"]["data"];
+
+    let mut headers = vec![("seqNo", "Seq No"),
+                           ("time", "Time"),
+                           ("from", "Submitter"),
+                           ("endorser", "Endorser")];
+
+    let mut row = json!({
+        "seqNo": txn["txnMetadata"]["seqNo"],
+        "time": txn["txnMetadata"]["txnTime"].as_i64().map(timestamp_to_datetime),
+        "from": txn["txn"]["metadata"]["from"],
+        "endorser": txn["txn"]["metadata"]["endorser"],
+    });
+
+    let specific: Vec<(&'static str, &'static str, &JSONValue)> = match txn["txn"]["type"].as_str() {
+        Some("1") => vec![("dest", "Did", &data["dest"]),
+            ("verkey", "Verkey", &data["verkey"]),
+            ("role", "Role", &data["role"]),
+            ("alias", "Alias", &data["alias"])],
+        Some("100") => vec![("dest", "Did", &data["dest"]),
+            ("raw", "Raw value", &data["raw"]),
+            ("hash", "Hashed value", &data["hash"]),
+            ("enc", "Encrypted value", &data["enc"])],
+        Some("101") => vec![("name", "Name", &data["data"]["name"]),
+            ("version", "Version", &data["data"]["version"]),
+            ("attr_names", "Attributes", &data["data"]["attr_names"])],
+        Some("102") => vec![("ref", "Schema Seq No", &data["ref"]),
+            ("signature_type", "Signature Type", &data["signature_type"]),
+            ("tag", "Tag", &data["tag"])],
+        Some("0") => vec![("dest", "Dest", &data["dest"]),
+            ("alias", "Alias", &data["data"]["alias"]),
+            ("node_ip", "Node Ip", &data["data"]["node_ip"]),
+            ("client_ip", "Client Ip", &data["data"]["client_ip"]),
+            ("services", "Services", &data["data"]["services"])],
+        Some("113") => vec![("id", "Id", &data["id"]),
+            ("credDefId", "Cred Def Id", &data["credDefId"]),
+            ("revocDefType", "Type", &data["revocDefType"]),
+            ("tag", "Tag", &data["tag"])],
+        Some("4") => vec![("version", "Version", &data["version"]),
+            ("text", "Text", &data["text"])],
+        Some("5") => vec![("version", "Version", &data["version"]),
+            ("aml", "Mechanisms", &data["aml"])],
+        _ => vec![]
+    };
+
+    if specific.is_empty() {
+        headers.push(("data", "Data"));
+        row["data"] = JSONValue::String(data.to_string());
+    }
+
+    for (key, title, value) in specific {
+        headers.push((key, title));
+        row[key] = value.clone();
+    }
+
+    if !row["role"].is_null() {
+        row["role"] = get_role_title(&row["role"]);
+    }
+
+    (headers, row)
+}
+
+fn print_ledger_txns(txns: &[&JSONValue], empty_msg: &str) {
+    if txns.is_empty() {
+        return println_succ!("{}", empty_msg);
+    }
+
+    // Transactions are grouped by type keeping the order of the first occurrence
+    let mut groups: Vec<(JSONValue, Vec<(&'static str, &'static str)>, Vec<JSONValue>)> = Vec::new();
+
+    for txn in txns {
+        let txn_type = get_txn_title(&txn["txn"]["type"]);
+        let (headers, row) = decode_ledger_txn(txn);
+
+        match groups.iter_mut().find(|(type_, _, _)| *type_ == txn_type) {
+            Some((_, _, rows)) => rows.push(row),
+            None => groups.push((txn_type, headers, vec![row]))
+        }
+    }
+
+    for (txn_type, headers, rows) in groups {
+        println_succ!("{} transactions:", txn_type.as_str().unwrap_or("-"));
+        print_list_table(&rows, &headers, "");
+    }
+}
+
 pub fn get_active_transaction_author_agreement(_pool_handle: PoolHandle) -> Result<Option<(String, String, Option<String>)>, ()> {
     let response = Ledger::build_get_txn_author_agreement_request(None, None)
         .and_then(|request| Ledger::submit_request(_pool_handle, &request))
@@ -5195,6 +5556,150 @@ pub mod tests {
         }
     }
 
+    mod txn_range {
+        use super::*;
+
+        #[test]
+        pub fn txn_range_works() {
+            let ctx = setup_with_wallet_and_pool();
+            {
+                let cmd = txn_range_command::new();
+                let mut params = CommandParams::new();
+                params.insert("ledger", "DOMAIN".to_string());
+                params.insert("from", "1".to_string());
+                params.insert("to", "5".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
+        #[test]
+        pub fn txn_range_works_for_range_beyond_ledger() {
+            let ctx = setup_with_wallet_and_pool();
+            {
+                let cmd = txn_range_command::new();
+                let mut params = CommandParams::new();
+                params.insert("ledger", "POOL".to_string());
+                params.insert("from", "1000000".to_string());
+                params.insert("to", "1000001".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
+        #[test]
+        pub fn txn_range_works_for_invalid_range() {
+            let ctx = setup_with_wallet_and_pool();
+            {
+                let cmd = txn_range_command::new();
+                let mut params = CommandParams::new();
+                params.insert("ledger", "DOMAIN".to_string());
+                params.insert("from", "5".to_string());
+                params.insert("to", "1".to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
+        #[test]
+        pub fn txn_range_works_for_no_connected_pool() {
+            let ctx = setup();
+            {
+                let cmd = txn_range_command::new();
+                let mut params = CommandParams::new();
+                params.insert("ledger", "DOMAIN".to_string());
+                params.insert("from", "1".to_string());
+                params.insert("to", "5".to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down();
+        }
+    }
+
+    mod search {
+        use super::*;
+
+        #[test]
+        pub fn search_works() {
+            let ctx = setup_with_wallet_and_pool();
+            {
+                let cmd = search_command::new();
+                let mut params = CommandParams::new();
+                params.insert("ledger", "DOMAIN".to_string());
+                params.insert("from", "1".to_string());
+                params.insert("to", "10".to_string());
+                params.insert("type", "NYM".to_string());
+                params.insert("target", DID_TRUSTEE.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
+        #[test]
+        pub fn ledger_txn_matches_works() {
+            let txn = json!({
+                "txn": {
+                    "type": "1",
+                    "data": {"dest": DID_MY1, "verkey": VERKEY_MY1},
+                    "metadata": {"from": DID_TRUSTEE}
+                },
+                "txnMetadata": {"seqNo": 10, "txnTime": 1577836800}
+            });
+
+            assert!(ledger_txn_matches(&txn, None, None, None));
+            assert!(ledger_txn_matches(&txn, Some("NYM"), Some(DID_TRUSTEE), Some(DID_MY1)));
+            assert!(ledger_txn_matches(&txn, Some("1"), None, None));
+            assert!(!ledger_txn_matches(&txn, Some("ATTRIB"), None, None));
+            assert!(!ledger_txn_matches(&txn, None, Some(DID_MY1), None));
+            assert!(!ledger_txn_matches(&txn, None, None, Some(DID_TRUSTEE)));
+        }
+
+        #[test]
+        pub fn decode_ledger_txn_works() {
+            let txn = json!({
+                "txn": {
+                    "type": "1",
+                    "data": {"dest": DID_MY1, "verkey": VERKEY_MY1, "role": "0"},
+                    "metadata": {"from": DID_TRUSTEE}
+                },
+                "txnMetadata": {"seqNo": 10, "txnTime": 1577836800}
+            });
+
+            let (headers, row) = decode_ledger_txn(&txn);
+            assert!(headers.contains(&("verkey", "Verkey")));
+            assert_eq!(DID_MY1, row["dest"].as_str().unwrap());
+            assert_eq!("TRUSTEE", row["role"].as_str().unwrap());
+            assert_eq!("2020-01-01 00:00:00", row["time"].as_str().unwrap());
+        }
+    }
+
+    mod export {
+        use super::*;
+
+        #[test]
+        pub fn export_works() {
+            let ctx = setup_with_wallet_and_pool();
+            let file = crate::utils::environment::EnvironmentUtils::tmp_file_path("domain.ndjson");
+            {
+                let cmd = export_command::new();
+                let mut params = CommandParams::new();
+                params.insert("ledger", "DOMAIN".to_string());
+                params.insert("from", "1".to_string());
+                params.insert("to", "3".to_string());
+                params.insert("file", file.to_str().unwrap().to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            let content = read_file(&file).unwrap();
+            let txns = content.lines()
+                .map(|line| serde_json::from_str::<JSONValue>(line).unwrap())
+                .collect::<Vec<JSONValue>>();
+            assert_eq!(3, txns.len());
+            assert_eq!(1, txns[0]["txnMetadata"]["seqNo"].as_u64().unwrap());
+
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+    }
+
     fn _path() -> (::std::path::PathBuf, String) {
         let mut path = crate::utils::environment::EnvironmentUtils::indy_home_path();
         path.push("transaction");
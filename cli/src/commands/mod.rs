@@ -2,15 +2,24 @@ extern crate serde_json;
 extern crate regex;
 
 pub mod common;
+pub mod anoncreds;
 pub mod did;
 pub mod pool;
 pub mod wallet;
 pub mod ledger;
 pub mod payment_address;
+pub mod record;
+pub mod crypto;
+pub mod pairwise;
+pub mod key;
+pub mod txn;
+pub mod taa;
 
 use self::regex::Regex;
 
 use crate::command_executor::{CommandContext, CommandParams};
+use crate::commands::taa::{TaaAcceptance, LedgerAuthorAgreement};
+use crate::utils::output;
 use indy::{ErrorCode, IndyError, WalletHandle, PoolHandle};
 
 use std;
@@ -176,7 +185,6 @@ pub fn get_number_tuple_array_param<'a>(name: &'a str, params: &'a CommandParams
             } else {
                 let mut result: Vec<u64> = Vec::new();
                 for item in tuples {
-                    println!("{:?}",item);
                     result.push(item.parse::<u64>().map_err(|err|
                         println_err!("Can't parse number parameter \"{}\": value: \"{}\", err \"{}\"", name, item, err))?);
                 }
@@ -312,6 +320,16 @@ pub fn set_connected_pool(ctx: &CommandContext, value: Option<(PoolHandle, Strin
     ctx.set_int_value("CONNECTED_POOL_HANDLE", value.as_ref().map(|value| value.0.to_owned()));
     ctx.set_string_value("CONNECTED_POOL_NAME", value.as_ref().map(|value| value.1.to_owned()));
     ctx.set_sub_prompt(1, value.map(|value| format!("pool({})", value.1)));
+    set_cached_author_agreement(ctx, None);
+}
+
+pub fn set_cached_author_agreement(ctx: &CommandContext, value: Option<Option<LedgerAuthorAgreement>>) {
+    ctx.set_string_value("LEDGER_AUTHOR_AGREEMENT", value.map(|value| json!(value).to_string()));
+}
+
+pub fn get_cached_author_agreement(ctx: &CommandContext) -> Option<Option<LedgerAuthorAgreement>> {
+    ctx.get_string_value("LEDGER_AUTHOR_AGREEMENT")
+        .and_then(|value| serde_json::from_str(&value).ok())
 }
 
 
@@ -333,20 +351,27 @@ pub fn ensure_set_transaction(ctx: &CommandContext) -> Result<String, ()> {
     }
 }
 
-pub fn set_transaction_author_info(ctx: &CommandContext, value: Option<(String, String, u64)>) {
-    ctx.set_string_value("AGREEMENT_TEXT", value.as_ref().map(|value| value.0.to_owned()));
-    ctx.set_string_value("AGREEMENT_VERSION", value.as_ref().map(|value| value.1.to_owned()));
-    ctx.set_uint_value("AGREEMENT_TIME_OF_ACCEPTANCE", value.as_ref().map(|value| value.2));
+pub fn set_transaction_author_info(ctx: &CommandContext, value: Option<TaaAcceptance>) {
+    ctx.set_string_value("AGREEMENT_TEXT", value.as_ref().map(|value| value.text.to_owned()));
+    ctx.set_string_value("AGREEMENT_VERSION", value.as_ref().map(|value| value.version.to_owned()));
+    ctx.set_string_value("AGREEMENT_DIGEST", value.as_ref().and_then(|value| value.digest.to_owned()));
+    ctx.set_string_value("AGREEMENT_MECHANISM", value.as_ref().and_then(|value| value.mechanism.to_owned()));
+    ctx.set_uint_value("AGREEMENT_TIME_OF_ACCEPTANCE", value.as_ref().map(|value| value.time));
 }
 
-pub fn get_transaction_author_info(ctx: &CommandContext) -> Option<(String, String, String, u64)> {
+pub fn get_transaction_author_info(ctx: &CommandContext) -> Option<TaaAcceptance> {
     let text = ctx.get_string_value("AGREEMENT_TEXT");
     let version = ctx.get_string_value("AGREEMENT_VERSION");
-    let acc_mech_type = ctx.get_taa_acceptance_mechanism();
     let time_of_acceptance = ctx.get_uint_value("AGREEMENT_TIME_OF_ACCEPTANCE");
 
     if let (Some(text), Some(version), Some(time_of_acceptance)) = (text, version, time_of_acceptance) {
-        Some((text, version, acc_mech_type, time_of_acceptance))
+        Some(TaaAcceptance {
+            text,
+            version,
+            digest: ctx.get_string_value("AGREEMENT_DIGEST"),
+            mechanism: ctx.get_string_value("AGREEMENT_MECHANISM"),
+            time: time_of_acceptance,
+        })
     } else {
         None
     }
@@ -366,6 +391,7 @@ pub fn get_pool_protocol_version(ctx: &CommandContext) -> usize {
 }
 
 pub fn handle_indy_error(err: IndyError, submitter_did: Option<&str>, pool_name: Option<&str>, wallet_name: Option<&str>) {
+    output::set_error_code(err.error_code as i32);
     match err.error_code {
         ErrorCode::WalletAlreadyExistsError => println_err!("Wallet \"{}\" already exists", wallet_name.unwrap_or("")),
         ErrorCode::WalletInvalidHandle => println_err!("Wallet: \"{}\" not found", wallet_name.unwrap_or("")),
@@ -0,0 +1,345 @@
+use crate::command_executor::{Command, CommandContext, CommandMetadata, CommandParams, CommandGroup, CommandGroupMetadata, DynamicCompletionType};
+use crate::commands::*;
+
+use indy::ErrorCode;
+use crate::libindy::pairwise::Pairwise;
+
+use serde_json::Value as JSONValue;
+
+use crate::utils::table::{print_table, print_list_table};
+
+pub mod group {
+    use super::*;
+
+    command_group!(CommandGroupMetadata::new("pairwise", "Pairwise relationships management commands"));
+}
+
+pub mod create_command {
+    use super::*;
+
+    command!(CommandMetadata::build("create", "Create pairwise relationship between their DID and my DID. \
+                                               Their DID must be stored in the opened wallet by `did store-their` command.")
+                .add_main_param("their_did", "Their DID")
+                .add_optional_param_with_dynamic_completion("my_did", "My DID (the active DID by default)", DynamicCompletionType::Did)
+                .add_optional_param("metadata", "Pairwise metadata")
+                .add_example("pairwise create 5Uu7YveFSGcT3dSzjpvPab")
+                .add_example("pairwise create 5Uu7YveFSGcT3dSzjpvPab my_did=VsKV7grR1BUE29mG2Fm2kX metadata=alice")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let wallet_handle = ensure_opened_wallet_handle(&ctx)?;
+
+        let their_did = get_str_param("their_did", params).map_err(error_err!())?;
+        let my_did = match get_opt_str_param("my_did", params).map_err(error_err!())? {
+            Some(my_did) => my_did.to_string(),
+            None => ensure_active_did(&ctx)?
+        };
+        let metadata = get_opt_str_param("metadata", params).map_err(error_err!())?;
+
+        let res = match Pairwise::create(wallet_handle, their_did, &my_did, metadata) {
+            Ok(()) => {
+                println_succ!("Pairwise between their DID \"{}\" and my DID \"{}\" has been created", their_did, my_did);
+                Ok(())
+            }
+            Err(err) => {
+                match err.error_code {
+                    ErrorCode::WalletItemNotFound => println_err!("Their DID \"{}\" or my DID \"{}\" not found in the wallet", their_did, my_did),
+                    ErrorCode::WalletItemAlreadyExists => println_err!("Pairwise for their DID \"{}\" already exists", their_did),
+                    _ => handle_indy_error(err, None, None, None),
+                };
+                Err(())
+            }
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod list_command {
+    use super::*;
+
+    command!(CommandMetadata::build("list", "List pairwise relationships stored in the opened wallet.")
+                .finalize());
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let wallet_handle = ensure_opened_wallet_handle(&ctx)?;
+
+        let res = match Pairwise::list(wallet_handle) {
+            Ok(pairwise_list) => {
+                // Every pairwise is returned as json string
+                let pairwise_list: Vec<String> = serde_json::from_str(&pairwise_list)
+                    .map_err(|_| println_err!("Wrong data has been received"))?;
+
+                let pairwise_list = pairwise_list.iter()
+                    .map(|pairwise| serde_json::from_str::<JSONValue>(pairwise))
+                    .collect::<Result<Vec<JSONValue>, _>>()
+                    .map_err(|_| println_err!("Wrong data has been received"))?;
+
+                print_list_table(&pairwise_list,
+                                 &[("their_did", "Their Did"),
+                                     ("my_did", "My Did"),
+                                     ("metadata", "Metadata")],
+                                 "There are no pairwise");
+                Ok(())
+            }
+            Err(err) => {
+                handle_indy_error(err, None, None, None);
+                Err(())
+            }
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod get_command {
+    use super::*;
+
+    command!(CommandMetadata::build("get", "Get pairwise relationship for their DID.")
+                .add_main_param("their_did", "Their DID")
+                .add_example("pairwise get 5Uu7YveFSGcT3dSzjpvPab")
+                .finalize());
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let wallet_handle = ensure_opened_wallet_handle(&ctx)?;
+
+        let their_did = get_str_param("their_did", params).map_err(error_err!())?;
+
+        let res = match Pairwise::get(wallet_handle, their_did) {
+            Ok(pairwise) => {
+                let mut pairwise: JSONValue = serde_json::from_str(&pairwise)
+                    .map_err(|_| println_err!("Wrong data has been received"))?;
+                pairwise["their_did"] = JSONValue::from(their_did);
+
+                print_table(&pairwise,
+                            &[("their_did", "Their Did"),
+                                ("my_did", "My Did"),
+                                ("metadata", "Metadata")]);
+                Ok(())
+            }
+            Err(err) => {
+                match err.error_code {
+                    ErrorCode::WalletItemNotFound => println_err!("Pairwise for their DID \"{}\" not found", their_did),
+                    _ => handle_indy_error(err, None, None, None),
+                };
+                Err(())
+            }
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod set_metadata_command {
+    use super::*;
+
+    command!(CommandMetadata::build("set-metadata", "Set metadata of pairwise relationship for their DID.")
+                .add_main_param("their_did", "Their DID")
+                .add_optional_param("metadata", "New metadata. The current metadata is removed if it is omitted")
+                .add_example("pairwise set-metadata 5Uu7YveFSGcT3dSzjpvPab metadata=alice")
+                .add_example("pairwise set-metadata 5Uu7YveFSGcT3dSzjpvPab")
+                .finalize());
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let wallet_handle = ensure_opened_wallet_handle(&ctx)?;
+
+        let their_did = get_str_param("their_did", params).map_err(error_err!())?;
+        let metadata = get_opt_str_param("metadata", params).map_err(error_err!())?;
+
+        let res = match Pairwise::set_metadata(wallet_handle, their_did, metadata) {
+            Ok(()) => {
+                println_succ!("Metadata has been saved for pairwise \"{}\"", their_did);
+                Ok(())
+            }
+            Err(err) => {
+                match err.error_code {
+                    ErrorCode::WalletItemNotFound => println_err!("Pairwise for their DID \"{}\" not found", their_did),
+                    _ => handle_indy_error(err, None, None, None),
+                };
+                Err(())
+            }
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::commands::did::tests::{new_did, use_did, SEED_MY1, DID_MY1, DID_MY3, VERKEY_MY3};
+    use crate::libindy::did::Did;
+
+    const METADATA: &str = "alice";
+
+    mod create {
+        use super::*;
+
+        #[test]
+        pub fn create_works() {
+            let ctx = setup_with_wallet_and_their_did();
+            {
+                let cmd = create_command::new();
+                let mut params = CommandParams::new();
+                params.insert("their_did", DID_MY3.to_string());
+                params.insert("metadata", METADATA.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            let pairwise = get_pairwise(&ctx);
+            assert_eq!(DID_MY1, pairwise["my_did"].as_str().unwrap());
+            assert_eq!(METADATA, pairwise["metadata"].as_str().unwrap());
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn create_works_for_unknown_their_did() {
+            let ctx = setup_with_wallet();
+            new_did(&ctx, SEED_MY1);
+            {
+                let cmd = create_command::new();
+                let mut params = CommandParams::new();
+                params.insert("their_did", DID_MY3.to_string());
+                params.insert("my_did", DID_MY1.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn create_works_for_no_active_did() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = create_command::new();
+                let mut params = CommandParams::new();
+                params.insert("their_did", DID_MY3.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+    }
+
+    mod list {
+        use super::*;
+
+        #[test]
+        pub fn list_works() {
+            let ctx = setup_with_wallet_and_their_did();
+            create_pairwise(&ctx);
+            {
+                let cmd = list_command::new();
+                let params = CommandParams::new();
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn list_works_for_empty_result() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = list_command::new();
+                let params = CommandParams::new();
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+    }
+
+    mod get {
+        use super::*;
+
+        #[test]
+        pub fn get_works() {
+            let ctx = setup_with_wallet_and_their_did();
+            create_pairwise(&ctx);
+            {
+                let cmd = get_command::new();
+                let mut params = CommandParams::new();
+                params.insert("their_did", DID_MY3.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn get_works_for_unknown_pairwise() {
+            let ctx = setup_with_wallet_and_their_did();
+            {
+                let cmd = get_command::new();
+                let mut params = CommandParams::new();
+                params.insert("their_did", DID_MY3.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+    }
+
+    mod set_metadata {
+        use super::*;
+
+        #[test]
+        pub fn set_metadata_works() {
+            let ctx = setup_with_wallet_and_their_did();
+            create_pairwise(&ctx);
+            {
+                let cmd = set_metadata_command::new();
+                let mut params = CommandParams::new();
+                params.insert("their_did", DID_MY3.to_string());
+                params.insert("metadata", "bob".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            assert_eq!("bob", get_pairwise(&ctx)["metadata"].as_str().unwrap());
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn set_metadata_works_for_reset() {
+            let ctx = setup_with_wallet_and_their_did();
+            create_pairwise(&ctx);
+            {
+                let cmd = set_metadata_command::new();
+                let mut params = CommandParams::new();
+                params.insert("their_did", DID_MY3.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            assert!(get_pairwise(&ctx)["metadata"].is_null());
+
+            tear_down_with_wallet(&ctx);
+        }
+    }
+
+    fn setup_with_wallet_and_their_did() -> CommandContext {
+        let ctx = setup_with_wallet();
+        new_did(&ctx, SEED_MY1);
+        use_did(&ctx, DID_MY1);
+        let wallet_handle = ensure_opened_wallet_handle(&ctx).unwrap();
+        Did::store_their_did(wallet_handle, &json!({"did": DID_MY3, "verkey": VERKEY_MY3}).to_string()).unwrap();
+        ctx
+    }
+
+    fn create_pairwise(ctx: &CommandContext) {
+        let wallet_handle = ensure_opened_wallet_handle(&ctx).unwrap();
+        Pairwise::create(wallet_handle, DID_MY3, DID_MY1, Some(METADATA)).unwrap();
+    }
+
+    fn get_pairwise(ctx: &CommandContext) -> JSONValue {
+        let wallet_handle = ensure_opened_wallet_handle(&ctx).unwrap();
+        let pairwise = Pairwise::get(wallet_handle, DID_MY3).unwrap();
+        serde_json::from_str(&pairwise).unwrap()
+    }
+}
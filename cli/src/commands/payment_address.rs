@@ -11,6 +11,7 @@ use serde_json::Value as JSONValue;
 use serde_json::Map as JSONMap;
 
 use crate::utils::table::print_list_table;
+use crate::utils::output;
 
 
 pub mod group {
@@ -220,6 +221,7 @@ pub mod verify_command {
 }
 
 pub fn handle_payment_error(err: IndyError, payment_method: Option<&str>) {
+    output::set_error_code(err.error_code as i32);
     match err.error_code {
         ErrorCode::UnknownPaymentMethod => println_err!("Unknown payment method {}", payment_method.unwrap_or("")),
         ErrorCode::IncompatiblePaymentError => println_err!("No methods were scraped or more than one was scraped"),
@@ -4,14 +4,19 @@ extern crate chrono;
 use crate::command_executor::{Command, CommandContext, CommandMetadata, CommandParams, CommandGroup, CommandGroupMetadata, wait_for_user_reply, DynamicCompletionType};
 use crate::commands::*;
 
-use indy::{ErrorCode, IndyError};
+use indy::{ErrorCode, IndyError, PoolHandle, WalletHandle};
+use crate::libindy::ledger::Ledger;
 use crate::libindy::pool::Pool;
-use crate::utils::table::print_list_table;
+use crate::commands::ledger::{Response, ResponseType};
+use crate::commands::taa::{TaaAcceptance, LedgerAuthorAgreement, get_accepted_author_agreement, get_ledger_author_agreement};
+use crate::utils::table::{print_table, print_list_table};
 
 use self::chrono::prelude::*;
 use serde_json::Value as JSONValue;
 use serde_json::Map as JSONMap;
 
+use std::collections::BTreeMap;
+
 pub mod group {
     use super::*;
 
@@ -235,7 +240,7 @@ pub mod show_taa_command {
             Err(_) => Err(()),
             Ok(Some(_)) => Ok(()),
             Ok(None) => {
-                println!("There is no transaction agreement set on the Pool.");
+                println_msg!("There is no transaction agreement set on the Pool.");
                 Ok(())
             }
         };
@@ -400,6 +405,289 @@ pub mod delete_command {
     }
 }
 
+pub mod diagnose_command {
+    use super::*;
+
+    command!(CommandMetadata::build("diagnose", "Diagnose connectivity and consensus health of the pool nodes. \
+                 The pool is opened (or refreshed if it is connected) and the reply of every node to the catchup is reported. \
+                 Validator info is requested from the nodes if a trustee DID is active.")
+                .add_main_param_with_dynamic_completion("name", "The name of pool", DynamicCompletionType::Pool)
+                .add_optional_param("timeout", "Timeout for opening the pool and waiting a reply from a node (in sec, 5 by default)")
+                .add_example("pool diagnose pool1")
+                .add_example("pool diagnose pool1 timeout=20")
+                .finalize());
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let name = get_str_param("name", params).map_err(error_err!())?;
+        let timeout = get_opt_number_param::<u64>("timeout", params).map_err(error_err!())?.unwrap_or(DIAGNOSE_TIMEOUT);
+
+        get_pool_status(name)?;
+
+        let (pool_handle, opened) = match get_connected_pool(ctx) {
+            Some((handle, connected_name)) if connected_name == name => {
+                refresh_pool(name, handle);
+                (Some(handle), false)
+            }
+            _ => (open_pool_timed(name, timeout), true)
+        };
+
+        let status = get_pool_status(name)?;
+
+        if status.nodes.is_empty() {
+            println_err!("There are no validator nodes in the transactions of pool \"{}\"", name);
+            return Err(());
+        }
+
+        let mut validator_info = BTreeMap::new();
+
+        if let Some(pool_handle) = pool_handle {
+            match (get_opened_wallet_handle(ctx), get_active_did(ctx)) {
+                (Some(wallet_handle), Some(did)) => {
+                    match query_validator_info(pool_handle, wallet_handle, &did, timeout) {
+                        Ok(info) => validator_info = info,
+                        Err(err) => println_warn!("Validator info has not been received: {}", err)
+                    }
+                }
+                _ => println_warn!("Activate a trustee DID to get validator info of the nodes")
+            }
+
+            if opened {
+                Pool::close(pool_handle)
+                    .map_err(|err| handle_indy_error(err, None, Some(name), None))?;
+            }
+        }
+
+        let roots = merkle_root_agreement(&status.nodes);
+
+        println_succ!("Nodes:");
+        print_list_table(&status.nodes.iter()
+                             .map(|(alias, node)| node_to_row(alias, node, roots.get(alias), validator_info.get(alias)))
+                             .collect::<Vec<JSONValue>>(),
+                         &[("alias", "Alias"),
+                             ("address", "Client Address"),
+                             ("reply", "Catchup Reply"),
+                             ("rtt", "Round Trip"),
+                             ("txn_count", "Pool Ledger Size"),
+                             ("roots", "Merkle Root"),
+                             ("bls", "BLS Key"),
+                             ("validator_info", "Validator Info")],
+                         "There are no nodes");
+
+        print_consensus_health(&status.nodes);
+
+        let res = Ok(());
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+const DIAGNOSE_TIMEOUT: u64 = 5;
+
+/// Status of the last open or refresh of the pool reported by libindy.
+#[derive(Debug, Deserialize)]
+struct PoolStatus {
+    state: String,
+    error: Option<String>,
+    txn_count: Option<u64>,
+    elapsed_ms: Option<u64>,
+    nodes: BTreeMap<String, PoolNodeStatus>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PoolNodeStatus {
+    address: String,
+    bls_key: bool,
+    reply: String,
+    txn_count: Option<u64>,
+    merkle_root: Option<String>,
+    rtt_ms: Option<u64>,
+}
+
+impl PoolNodeStatus {
+    fn is_responsive(&self) -> bool {
+        self.reply == "ledger_status" || self.reply == "consistency_proof"
+    }
+}
+
+fn node_to_row(alias: &str, node: &PoolNodeStatus, roots: Option<&String>, validator_info: Option<&Result<JSONValue, String>>) -> JSONValue {
+    json!({
+        "alias": alias,
+        "address": node.address,
+        "reply": node.reply.replace('_', " "),
+        "rtt": node.rtt_ms.map(|rtt| format!("{} ms", rtt)),
+        "txn_count": node.txn_count,
+        "roots": roots,
+        "bls": if node.bls_key { "yes" } else { "no" },
+        "validator_info": match validator_info {
+            Some(Ok(info)) => info["Node_info"]["Mode"].as_str().unwrap_or("received").to_string(),
+            Some(Err(err)) => err.to_string(),
+            None => "-".to_string()
+        },
+    })
+}
+
+fn get_pool_status(name: &str) -> Result<PoolStatus, ()> {
+    let status = Pool::get_status(name)
+        .map_err(|err| match err.error_code {
+            ErrorCode::PoolLedgerNotCreatedError => println_err!("Pool \"{}\" does not exist.", name),
+            _ => handle_indy_error(err, None, Some(name), None)
+        })?;
+
+    serde_json::from_str::<PoolStatus>(&status)
+        .map_err(|err| println_err!("Invalid status of pool \"{}\": {}", name, err))
+}
+
+fn open_pool_timed(name: &str, timeout: u64) -> Option<PoolHandle> {
+    let config = json!({"timeout": timeout}).to_string();
+
+    match Pool::open_pool_ledger(name, Some(&config)) {
+        Ok(handle) => {
+            print_open_result(name, "opened", None);
+            Some(handle)
+        }
+        Err(err) => {
+            print_open_result(name, "opened", Some(err));
+            None
+        }
+    }
+}
+
+fn refresh_pool(name: &str, pool_handle: PoolHandle) {
+    let res = Pool::refresh(pool_handle);
+    print_open_result(name, "refreshed", res.err());
+}
+
+fn print_open_result(name: &str, action: &str, err: Option<IndyError>) {
+    let status = Pool::get_status(name).ok()
+        .and_then(|status| serde_json::from_str::<PoolStatus>(&status).ok());
+
+    let elapsed = status.as_ref().and_then(|status| status.elapsed_ms)
+        .map(|elapsed| format!(" in {} ms", elapsed))
+        .unwrap_or_default();
+
+    match err {
+        None => {
+            let size = status.as_ref().and_then(|status| status.txn_count)
+                .map(|size| format!(", pool ledger size is {}", size))
+                .unwrap_or_default();
+            println_succ!("Pool \"{}\" has been {}{}{}", name, action, elapsed, size)
+        }
+        Some(err) => {
+            let state = status.map(|status| format!(" ({}: {})", status.state, status.error.unwrap_or_default()))
+                .unwrap_or_default();
+            println_warn!("Pool \"{}\" has not been {}{}: {}{}", name, action, elapsed, err.message, state)
+        }
+    }
+}
+
+/// Sends GET_VALIDATOR_INFO signed by the active DID to all the nodes at once.
+fn query_validator_info(pool_handle: PoolHandle, wallet_handle: WalletHandle, did: &str, timeout: u64) -> Result<BTreeMap<String, Result<JSONValue, String>>, String> {
+    let request = Ledger::build_get_validator_info_request(did)
+        .and_then(|request| Ledger::sign_request(wallet_handle, did, &request))
+        .map_err(|err| err.message)?;
+
+    let response = Ledger::submit_action(pool_handle, &request, None, Some(timeout as i32))
+        .map_err(|err| err.message)?;
+
+    let replies = serde_json::from_str::<BTreeMap<String, String>>(&response)
+        .map_err(|_| "invalid reply".to_string())?;
+
+    Ok(replies.into_iter()
+        .map(|(alias, reply)| (alias, parse_validator_info(&reply)))
+        .collect())
+}
+
+fn parse_validator_info(reply: &str) -> Result<JSONValue, String> {
+    if reply == "timeout" {
+        return Err("timeout".to_string());
+    }
+
+    let reply = serde_json::from_str::<Response<JSONValue>>(reply)
+        .map_err(|_| "invalid reply".to_string())?;
+
+    match reply {
+        Response { op: ResponseType::REPLY, result: Some(result), .. } => {
+            match result["data"] {
+                JSONValue::String(ref data) => serde_json::from_str(data).map_err(|_| "invalid reply".to_string()),
+                ref data => Ok(data.clone())
+            }
+        }
+        Response { reason, .. } => Err(reason.unwrap_or_else(|| "rejected".to_string()))
+    }
+}
+
+/// Compares the pool ledger size and merkle root of every replied node with the ones reported by the majority of nodes.
+fn merkle_root_agreement(nodes: &BTreeMap<String, PoolNodeStatus>) -> BTreeMap<String, String> {
+    let mut votes: BTreeMap<(u64, &str), usize> = BTreeMap::new();
+    for node in nodes.values() {
+        if let (Some(size), Some(root)) = (node.txn_count, node.merkle_root.as_ref()) {
+            *votes.entry((size, root.as_str())).or_insert(0) += 1;
+        }
+    }
+
+    let majority = votes.iter()
+        .max_by_key(|(_, count)| **count)
+        .map(|(key, _)| *key);
+
+    nodes.iter()
+        .filter_map(|(alias, node)| match (node.txn_count, node.merkle_root.as_ref()) {
+            (Some(size), Some(root)) => {
+                let agreement = if majority == Some((size, root.as_str())) { "agree" } else { "differ" };
+                Some((alias.to_string(), agreement.to_string()))
+            }
+            _ => None
+        })
+        .collect()
+}
+
+fn print_consensus_health(nodes: &BTreeMap<String, PoolNodeStatus>) {
+    let total = nodes.len();
+    let f = total.saturating_sub(1) / 3;
+    let responsive = nodes.values().filter(|node| node.is_responsive()).count();
+    let with_bls = nodes.values().filter(|node| node.bls_key).count();
+    let write_quorum = total - f;
+    let read_quorum = f + 1;
+
+    let status = if responsive >= write_quorum {
+        "healthy"
+    } else if responsive >= read_quorum {
+        "degraded"
+    } else {
+        "no consensus"
+    };
+
+    println_succ!("Consensus health:");
+    print_table(&json!({
+                    "validators": total,
+                    "f": f,
+                    "responsive": responsive,
+                    "write_quorum": write_quorum,
+                    "read_quorum": read_quorum,
+                    "bls": with_bls,
+                    "status": status,
+                }),
+                &[("validators", "Validators"),
+                    ("f", "f"),
+                    ("responsive", "Responsive"),
+                    ("write_quorum", "Write Quorum (N-f)"),
+                    ("read_quorum", "Read Quorum (f+1)"),
+                    ("bls", "BLS Keys"),
+                    ("status", "Status")]);
+
+    match status {
+        "healthy" => println_succ!("Pool can order transactions and tolerate {} more failed node(s)", responsive - write_quorum),
+        "degraded" => println_warn!("Pool can serve reads but cannot order transactions: {} of {} validator(s) required are responsive", responsive, write_quorum),
+        _ => println_err!("Pool cannot reach consensus: {} of {} validator(s) required are responsive", responsive, read_quorum),
+    }
+
+    if with_bls < write_quorum {
+        println_warn!("Only {} validator(s) have BLS keys but {} are required for state proofs multi-signature", with_bls, write_quorum);
+    }
+}
+
 pub fn pool_list() -> Vec<String> {
     Pool::list().ok()
         .and_then(|pools|
@@ -413,52 +701,72 @@ pub fn pool_list() -> Vec<String> {
         .collect()
 }
 
-pub fn accept_transaction_author_agreement(ctx: &CommandContext, text: &str, version: &str) {
-    println!("Would you like to accept it? (y/n)");
+pub fn show_transaction_author_agreement(text: &str, version: &str, digest: Option<&str>) {
+    println_msg!("Transaction Author Agreement");
+    println_msg!("Version: {:?}", version);
+    if let Some(digest_) = digest {
+        println_msg!("Digest: {:?}", digest_);
+    }
+    println_msg!("Content: \n {:?}", text);
+}
+
+pub fn accept_transaction_author_agreement(ctx: &CommandContext, text: &str, version: &str, digest: Option<&str>) -> Option<TaaAcceptance> {
+    println_msg!("Would you like to accept it? (y/n)");
 
     let accept_agreement = wait_for_user_reply(ctx);
 
     if !accept_agreement {
         println_warn!("The Transaction Author Agreement has NOT been Accepted.");
-        println!("Use `pool show-taa` command to accept the Agreement.");
-        println!();
-        return;
+        println_msg!("Use `pool show-taa` command to accept the Agreement.");
+        println_msg!();
+        return None;
     }
 
     println_succ!("Transaction Author Agreement has been accepted.");
 
     let time_of_acceptance = Utc::now().timestamp() as u64;
 
-    set_transaction_author_info(ctx, Some((text.to_string(), version.to_string(), time_of_acceptance)));
+    let acceptance = TaaAcceptance::new(text, version, digest, time_of_acceptance);
+
+    set_transaction_author_info(ctx, Some(acceptance.clone()));
+
+    Some(acceptance)
 }
 
 pub fn set_transaction_author_agreement(ctx: &CommandContext, pool_handle: i32, ask_for_showing: bool) -> Result<Option<()>, ()> {
-    if let Some((text, version, digest)) = ledger::get_active_transaction_author_agreement(pool_handle)? {
+    if let Some(LedgerAuthorAgreement { text, version, digest, .. }) = get_ledger_author_agreement(ctx, pool_handle)? {
+        let digest = digest.as_ref().map(String::as_str);
+
         if ask_for_showing {
-            println!();
-            println!("There is a Transaction Author Agreement set on the connected Pool.");
-            println!("You should read and accept it to be able to send transactions to the Pool.");
-            println!("You can postpone accepting the Agreement. Accept it later by calling `pool show-taa` command");
-            println!("Would you like to read it? (y/n)");
+            if let Some((_, pool_name)) = get_connected_pool(ctx) {
+                let did = get_active_did(ctx);
+
+                if let Some(acceptance) = get_accepted_author_agreement(ctx, &pool_name, did.as_ref().map(String::as_str), &text, &version, digest)? {
+                    println_succ!("Transaction Author Agreement version \"{}\" has already been accepted.", acceptance.version);
+                    set_transaction_author_info(ctx, Some(acceptance));
+                    return Ok(Some(()));
+                }
+            }
+
+            println_msg!();
+            println_msg!("There is a Transaction Author Agreement set on the connected Pool.");
+            println_msg!("You should read and accept it to be able to send transactions to the Pool.");
+            println_msg!("You can postpone accepting the Agreement. Accept it later by calling `pool show-taa` command");
+            println_msg!("Would you like to read it? (y/n)");
 
             let read_agreement = wait_for_user_reply(ctx);
 
             if !read_agreement {
                 println_warn!("The Transaction Author Agreement has NOT been Accepted.");
-                println!("Use `pool show-taa` command to accept the Agreement.");
-                println!();
+                println_msg!("Use `pool show-taa` command to accept the Agreement.");
+                println_msg!();
                 return Ok(Some(()));
             }
         }
 
-        println!("Transaction Author Agreement");
-        println!("Version: {:?}", version);
-        if let Some(digest_) = digest {
-            println!("Digest: {:?}", digest_);
-        }
-        println!("Content: \n {:?}", text);
+        show_transaction_author_agreement(&text, &version, digest);
 
-        accept_transaction_author_agreement(ctx, &text, &version);
+        accept_transaction_author_agreement(ctx, &text, &version, digest);
 
         Ok(Some(()))
     } else {
@@ -813,6 +1121,78 @@ pub mod tests {
         }
     }
 
+    mod diagnose {
+        use super::*;
+
+        #[test]
+        pub fn diagnose_works() {
+            let ctx = setup();
+            create_pool(&ctx);
+            {
+                let cmd = diagnose_command::new();
+                let mut params = CommandParams::new();
+                params.insert("name", POOL.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            delete_pool(&ctx);
+            tear_down();
+        }
+
+        #[test]
+        pub fn diagnose_works_for_connected() {
+            let ctx = setup();
+            create_and_connect_pool(&ctx);
+            {
+                let cmd = diagnose_command::new();
+                let mut params = CommandParams::new();
+                params.insert("name", POOL.to_string());
+                params.insert("timeout", "10".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            disconnect_and_delete_pool(&ctx);
+            tear_down();
+        }
+
+        #[test]
+        pub fn diagnose_works_for_not_created() {
+            let ctx = setup();
+
+            let cmd = diagnose_command::new();
+            let mut params = CommandParams::new();
+            params.insert("name", POOL.to_string());
+            cmd.execute(&ctx, &params).unwrap_err();
+
+            tear_down();
+        }
+
+        #[test]
+        pub fn merkle_root_agreement_works() {
+            let node = |reply: &str, status: Option<(u64, &str)>| PoolNodeStatus {
+                address: "tcp://127.0.0.1:9702".to_string(),
+                bls_key: true,
+                reply: reply.to_string(),
+                txn_count: status.map(|(size, _)| size),
+                merkle_root: status.map(|(_, root)| root.to_string()),
+                rtt_ms: status.map(|_| 1),
+            };
+
+            let nodes = vec![
+                ("Node1".to_string(), node("ledger_status", Some((4, "root")))),
+                ("Node2".to_string(), node("ledger_status", Some((4, "root")))),
+                ("Node3".to_string(), node("consistency_proof", Some((3, "behind")))),
+                ("Node4".to_string(), node("timeout", None)),
+            ].into_iter().collect::<BTreeMap<String, PoolNodeStatus>>();
+
+            let roots = merkle_root_agreement(&nodes);
+
+            assert_eq!(3, roots.len());
+            assert_eq!("agree", roots["Node1"]);
+            assert_eq!("agree", roots["Node2"]);
+            assert_eq!("differ", roots["Node3"]);
+            assert!(!nodes["Node4"].is_responsive());
+        }
+    }
+
     mod set_protocol_version {
         use super::*;
 
@@ -0,0 +1,550 @@
+use crate::command_executor::{Command, CommandContext, CommandMetadata, CommandParams, CommandGroup, CommandGroupMetadata};
+use crate::commands::*;
+
+use indy::{ErrorCode, IndyError, WalletHandle, SearchHandle};
+use crate::libindy::wallet::Wallet;
+
+use serde_json::Value as JSONValue;
+
+use crate::utils::table::{print_table, print_list_table};
+use crate::utils::output;
+
+const SEARCH_BATCH_SIZE: usize = 100;
+const RECORD_OPTIONS: &str = r#"{"retrieveType":true,"retrieveValue":true,"retrieveTags":true}"#;
+
+pub mod group {
+    use super::*;
+
+    command_group!(CommandGroupMetadata::new("record", "Non-secret wallet records management commands"));
+}
+
+pub mod add_command {
+    use super::*;
+
+    command!(CommandMetadata::build("add", "Add non-secret record to the opened wallet")
+                .add_required_param("type", "Record type")
+                .add_required_param("id", "Record id")
+                .add_required_param("value", "Record value")
+                .add_optional_param("tags", "Record tags json. Tags with names starting with `~` are stored unencrypted")
+                .add_example(r#"record add type=connection id=alice value=active"#)
+                .add_example(r#"record add type=connection id=alice value=active tags={"~state":"active","did":"VsKV7grR1BUE29mG2Fm2kX"}"#)
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let type_ = get_str_param("type", params).map_err(error_err!())?;
+        let id = get_str_param("id", params).map_err(error_err!())?;
+        let value = get_str_param("value", params).map_err(error_err!())?;
+        let tags = get_opt_tags_param("tags", params)?;
+
+        let res = match Wallet::add_record(wallet_handle, type_, id, value, tags.as_ref().map(String::as_str)) {
+            Ok(()) => Ok(println_succ!("Record \"{}\" of type \"{}\" has been added", id, type_)),
+            Err(err) => Err(handle_record_error(err, type_, id, &wallet_name)),
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod get_command {
+    use super::*;
+
+    command!(CommandMetadata::build("get", "Get non-secret record from the opened wallet")
+                .add_required_param("type", "Record type")
+                .add_required_param("id", "Record id")
+                .add_example("record get type=connection id=alice")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let type_ = get_str_param("type", params).map_err(error_err!())?;
+        let id = get_str_param("id", params).map_err(error_err!())?;
+
+        let record = Wallet::get_record(wallet_handle, type_, id, RECORD_OPTIONS)
+            .map_err(|err| handle_record_error(err, type_, id, &wallet_name))?;
+
+        let record = serde_json::from_str::<JSONValue>(&record)
+            .map_err(|_| println_err!("Wrong data has been received"))?;
+
+        print_table(&record, &[("id", "Id"),
+                               ("type", "Type"),
+                               ("value", "Value"),
+                               ("tags", "Tags")]);
+
+        let res = Ok(());
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod update_command {
+    use super::*;
+
+    command!(CommandMetadata::build("update", "Update value and tags of non-secret record in the opened wallet")
+                .add_required_param("type", "Record type")
+                .add_required_param("id", "Record id")
+                .add_optional_param("value", "New record value")
+                .add_optional_param("tags", "Tags json replacing all current tags of the record")
+                .add_optional_param("add_tags", "Tags json to add to the record. Existing tags with the same names are overwritten")
+                .add_optional_param("delete_tags", "Names of tags to delete split by comma")
+                .add_example("record update type=connection id=alice value=inactive")
+                .add_example(r#"record update type=connection id=alice tags={"~state":"inactive"}"#)
+                .add_example(r#"record update type=connection id=alice add_tags={"~label":"Alice"} delete_tags=did"#)
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let type_ = get_str_param("type", params).map_err(error_err!())?;
+        let id = get_str_param("id", params).map_err(error_err!())?;
+        let value = get_opt_str_param("value", params).map_err(error_err!())?;
+        let tags = get_opt_tags_param("tags", params)?;
+        let add_tags = get_opt_tags_param("add_tags", params)?;
+        let delete_tags = get_opt_str_array_param("delete_tags", params).map_err(error_err!())?;
+
+        if value.is_none() && tags.is_none() && add_tags.is_none() && delete_tags.is_none() {
+            println_err!("Nothing to update. Specify either \"value\", \"tags\", \"add_tags\" or \"delete_tags\" parameter");
+            return Err(());
+        }
+
+        _update_record(wallet_handle, type_, id, value, tags, add_tags, delete_tags)
+            .map_err(|err| handle_record_error(err, type_, id, &wallet_name))?;
+
+        println_succ!("Record \"{}\" of type \"{}\" has been updated", id, type_);
+
+        let res = Ok(());
+
+        trace!("execute << {:?}", res);
+        res
+    }
+
+    fn _update_record(wallet_handle: WalletHandle, type_: &str, id: &str, value: Option<&str>, tags: Option<String>,
+                      add_tags: Option<String>, delete_tags: Option<Vec<&str>>) -> Result<(), IndyError> {
+        if let Some(value) = value {
+            Wallet::update_record_value(wallet_handle, type_, id, value)?;
+        }
+        if let Some(tags) = tags {
+            Wallet::update_record_tags(wallet_handle, type_, id, &tags)?;
+        }
+        if let Some(add_tags) = add_tags {
+            Wallet::add_record_tags(wallet_handle, type_, id, &add_tags)?;
+        }
+        if let Some(delete_tags) = delete_tags {
+            Wallet::delete_record_tags(wallet_handle, type_, id, &JSONValue::from(delete_tags).to_string())?;
+        }
+        Ok(())
+    }
+}
+
+pub mod delete_command {
+    use super::*;
+
+    command!(CommandMetadata::build("delete", "Delete non-secret record from the opened wallet")
+                .add_required_param("type", "Record type")
+                .add_required_param("id", "Record id")
+                .add_example("record delete type=connection id=alice")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let type_ = get_str_param("type", params).map_err(error_err!())?;
+        let id = get_str_param("id", params).map_err(error_err!())?;
+
+        let res = match Wallet::delete_record(wallet_handle, type_, id) {
+            Ok(()) => Ok(println_succ!("Record \"{}\" of type \"{}\" has been deleted", id, type_)),
+            Err(err) => Err(handle_record_error(err, type_, id, &wallet_name)),
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod search_command {
+    use super::*;
+
+    command!(CommandMetadata::build("search", "Search non-secret records of the type in the opened wallet")
+                .add_required_param("type", "Record type")
+                .add_optional_param("query", "WQL query over record tags (all records of the type by default)")
+                .add_optional_param("count", "Maximum number of records to return (all by default)")
+                .add_example("record search type=connection")
+                .add_example(r#"record search type=connection query={"~state":"active"} count=10"#)
+                .add_example(r#"record search type=connection query={"$or":[{"~state":"active"},{"~label":{"$like":"Al%"}}]}"#)
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let type_ = get_str_param("type", params).map_err(error_err!())?;
+        let query = get_opt_object_param("query", params).map_err(error_err!())?
+            .unwrap_or_else(|| json!({}));
+        let count = get_opt_number_param::<usize>("count", params).map_err(error_err!())?;
+
+        let search_handle = Wallet::open_search(wallet_handle, type_, &query.to_string(), RECORD_OPTIONS)
+            .map_err(|err| match err.error_code {
+                ErrorCode::WalletQueryError => {
+                    output::set_error_code(err.error_code as i32);
+                    println_err!("Invalid WQL query: {}", err.message)
+                }
+                _ => handle_indy_error(err, None, None, Some(&wallet_name))
+            })?;
+
+        let records = fetch_records(wallet_handle, search_handle, count, &wallet_name);
+
+        Wallet::close_search(search_handle)
+            .map_err(|err| handle_indy_error(err, None, None, Some(&wallet_name)))?;
+
+        print_list_table(&records?,
+                         &[("id", "Id"),
+                             ("value", "Value"),
+                             ("tags", "Tags")],
+                         "There are no records");
+
+        let res = Ok(());
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+fn fetch_records(wallet_handle: WalletHandle, search_handle: SearchHandle, count: Option<usize>, wallet_name: &str) -> Result<Vec<JSONValue>, ()> {
+    let mut records: Vec<JSONValue> = Vec::new();
+
+    loop {
+        let batch_size = match count {
+            Some(count) if count - records.len() < SEARCH_BATCH_SIZE => count - records.len(),
+            _ => SEARCH_BATCH_SIZE
+        };
+
+        if batch_size == 0 {
+            break;
+        }
+
+        let batch = match Wallet::fetch_search_next_records(wallet_handle, search_handle, batch_size) {
+            Ok(batch) => batch,
+            Err(ref err) if err.error_code == ErrorCode::WalletItemNotFound => break,
+            Err(err) => return Err(handle_indy_error(err, None, None, Some(wallet_name)))
+        };
+
+        let batch = serde_json::from_str::<JSONValue>(&batch)
+            .map_err(|_| println_err!("Wrong data has been received"))?;
+
+        match batch["records"].as_array() {
+            Some(batch) if !batch.is_empty() => records.extend(batch.iter().cloned()),
+            _ => break
+        }
+    }
+
+    Ok(records)
+}
+
+fn get_opt_tags_param(name: &str, params: &CommandParams) -> Result<Option<String>, ()> {
+    match get_opt_object_param(name, params).map_err(error_err!())? {
+        Some(ref tags) if !tags.is_object() => {
+            println_err!("Parameter \"{}\" must be a json object", name);
+            Err(())
+        }
+        tags => Ok(tags.map(|tags| tags.to_string()))
+    }
+}
+
+fn handle_record_error(err: IndyError, type_: &str, id: &str, wallet_name: &str) {
+    match err.error_code {
+        ErrorCode::WalletItemNotFound => {
+            output::set_error_code(err.error_code as i32);
+            println_err!("Record \"{}\" of type \"{}\" not found", id, type_)
+        }
+        ErrorCode::WalletItemAlreadyExists => {
+            output::set_error_code(err.error_code as i32);
+            println_err!("Record \"{}\" of type \"{}\" already exists", id, type_)
+        }
+        _ => handle_indy_error(err, None, None, Some(wallet_name))
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    const TYPE: &str = "connection";
+    const ID: &str = "alice";
+    const VALUE: &str = "active";
+    const TAGS: &str = r#"{"~state":"active","did":"VsKV7grR1BUE29mG2Fm2kX"}"#;
+
+    mod add {
+        use super::*;
+
+        #[test]
+        pub fn add_works() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = add_command::new();
+                let mut params = CommandParams::new();
+                params.insert("type", TYPE.to_string());
+                params.insert("id", ID.to_string());
+                params.insert("value", VALUE.to_string());
+                params.insert("tags", TAGS.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            let record = get_record(&ctx);
+            assert_eq!(VALUE, record["value"].as_str().unwrap());
+            assert_eq!(serde_json::from_str::<JSONValue>(TAGS).unwrap(), record["tags"]);
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn add_works_for_duplicate() {
+            let ctx = setup_with_wallet();
+            add_record(&ctx);
+            {
+                let cmd = add_command::new();
+                let mut params = CommandParams::new();
+                params.insert("type", TYPE.to_string());
+                params.insert("id", ID.to_string());
+                params.insert("value", VALUE.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn add_works_for_invalid_tags() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = add_command::new();
+                let mut params = CommandParams::new();
+                params.insert("type", TYPE.to_string());
+                params.insert("id", ID.to_string());
+                params.insert("value", VALUE.to_string());
+                params.insert("tags", r#"["active"]"#.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn add_works_for_no_opened_wallet() {
+            let ctx = setup();
+            {
+                let cmd = add_command::new();
+                let mut params = CommandParams::new();
+                params.insert("type", TYPE.to_string());
+                params.insert("id", ID.to_string());
+                params.insert("value", VALUE.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down();
+        }
+    }
+
+    mod get {
+        use super::*;
+
+        #[test]
+        pub fn get_works() {
+            let ctx = setup_with_wallet();
+            add_record(&ctx);
+            {
+                let cmd = get_command::new();
+                let mut params = CommandParams::new();
+                params.insert("type", TYPE.to_string());
+                params.insert("id", ID.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn get_works_for_unknown_record() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = get_command::new();
+                let mut params = CommandParams::new();
+                params.insert("type", TYPE.to_string());
+                params.insert("id", ID.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+    }
+
+    mod update {
+        use super::*;
+
+        #[test]
+        pub fn update_works_for_value() {
+            let ctx = setup_with_wallet();
+            add_record(&ctx);
+            {
+                let cmd = update_command::new();
+                let mut params = CommandParams::new();
+                params.insert("type", TYPE.to_string());
+                params.insert("id", ID.to_string());
+                params.insert("value", "inactive".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            assert_eq!("inactive", get_record(&ctx)["value"].as_str().unwrap());
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn update_works_for_tags() {
+            let ctx = setup_with_wallet();
+            add_record(&ctx);
+            {
+                let cmd = update_command::new();
+                let mut params = CommandParams::new();
+                params.insert("type", TYPE.to_string());
+                params.insert("id", ID.to_string());
+                params.insert("add_tags", r#"{"~label":"Alice"}"#.to_string());
+                params.insert("delete_tags", "did".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            assert_eq!(json!({"~state": "active", "~label": "Alice"}), get_record(&ctx)["tags"]);
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn update_works_for_nothing_to_update() {
+            let ctx = setup_with_wallet();
+            add_record(&ctx);
+            {
+                let cmd = update_command::new();
+                let mut params = CommandParams::new();
+                params.insert("type", TYPE.to_string());
+                params.insert("id", ID.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn update_works_for_unknown_record() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = update_command::new();
+                let mut params = CommandParams::new();
+                params.insert("type", TYPE.to_string());
+                params.insert("id", ID.to_string());
+                params.insert("value", "inactive".to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+    }
+
+    mod delete {
+        use super::*;
+
+        #[test]
+        pub fn delete_works() {
+            let ctx = setup_with_wallet();
+            add_record(&ctx);
+            {
+                let cmd = delete_command::new();
+                let mut params = CommandParams::new();
+                params.insert("type", TYPE.to_string());
+                params.insert("id", ID.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            let wallet_handle = ensure_opened_wallet_handle(&ctx).unwrap();
+            Wallet::get_record(wallet_handle, TYPE, ID, RECORD_OPTIONS).unwrap_err();
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn delete_works_for_unknown_record() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = delete_command::new();
+                let mut params = CommandParams::new();
+                params.insert("type", TYPE.to_string());
+                params.insert("id", ID.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+    }
+
+    mod search {
+        use super::*;
+
+        #[test]
+        pub fn search_works() {
+            let ctx = setup_with_wallet();
+            add_record(&ctx);
+            {
+                let cmd = search_command::new();
+                let mut params = CommandParams::new();
+                params.insert("type", TYPE.to_string());
+                params.insert("query", r#"{"~state":"active"}"#.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn search_works_for_count() {
+            let ctx = setup_with_wallet();
+            let wallet_handle = ensure_opened_wallet_handle(&ctx).unwrap();
+            for id in 0..3 {
+                Wallet::add_record(wallet_handle, TYPE, &id.to_string(), VALUE, None).unwrap();
+            }
+
+            let search_handle = Wallet::open_search(wallet_handle, TYPE, "{}", RECORD_OPTIONS).unwrap();
+            let records = fetch_records(wallet_handle, search_handle, Some(2), "wallet").unwrap();
+            Wallet::close_search(search_handle).unwrap();
+            assert_eq!(2, records.len());
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn search_works_for_invalid_query() {
+            let ctx = setup_with_wallet();
+            {
+                let cmd = search_command::new();
+                let mut params = CommandParams::new();
+                params.insert("type", TYPE.to_string());
+                params.insert("query", r#"{"$unknown":"active"}"#.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+    }
+
+    fn add_record(ctx: &CommandContext) {
+        let wallet_handle = ensure_opened_wallet_handle(&ctx).unwrap();
+        Wallet::add_record(wallet_handle, TYPE, ID, VALUE, Some(TAGS)).unwrap();
+    }
+
+    fn get_record(ctx: &CommandContext) -> JSONValue {
+        let wallet_handle = ensure_opened_wallet_handle(&ctx).unwrap();
+        let record = Wallet::get_record(wallet_handle, TYPE, ID, RECORD_OPTIONS).unwrap();
+        serde_json::from_str(&record).unwrap()
+    }
+}
@@ -0,0 +1,534 @@
+extern crate chrono;
+
+use crate::command_executor::{Command, CommandContext, CommandMetadata, CommandParams, CommandGroup, CommandGroupMetadata};
+use crate::commands::*;
+use crate::commands::ledger::{Response, ResponseType, get_active_transaction_author_agreement};
+use crate::commands::pool::{accept_transaction_author_agreement, show_transaction_author_agreement};
+
+use indy::{ErrorCode, PoolHandle};
+use crate::libindy::ledger::Ledger;
+use crate::libindy::wallet::Wallet;
+
+use serde_json::Value as JSONValue;
+
+use crate::utils::table::print_table;
+
+use self::chrono::prelude::*;
+
+const ACCEPTANCE_RECORD_TYPE: &str = "indy_cli_taa_acceptance";
+const RECORD_OPTIONS: &str = r#"{"retrieveType":false,"retrieveValue":true,"retrieveTags":false}"#;
+const SEC_IN_DAY: u64 = 86400;
+const TAA_REJECTION_REASON: &str = "Txn Author Agreement";
+
+/// Acceptance of the transaction author agreement.
+/// Stored in the opened wallet for every pool and DID that have sent transactions with it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TaaAcceptance {
+    pub text: String,
+    pub version: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub digest: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mechanism: Option<String>,
+    pub time: u64,
+}
+
+impl TaaAcceptance {
+    pub fn new(text: &str, version: &str, digest: Option<&str>, time: u64) -> TaaAcceptance {
+        TaaAcceptance {
+            text: text.to_string(),
+            version: version.to_string(),
+            digest: digest.map(String::from),
+            mechanism: None,
+            time: round_to_day(time),
+        }
+    }
+
+    /// Checks that the acceptance relates to the given agreement.
+    /// Digests are compared if both known, otherwise text and version.
+    pub fn is_for(&self, text: &str, version: &str, digest: Option<&str>) -> bool {
+        match (self.digest.as_ref(), digest) {
+            (Some(accepted_digest), Some(digest)) => accepted_digest == digest,
+            _ => self.text == text && self.version == version
+        }
+    }
+
+    /// Text, version and digest params to be passed to Libindy for appending of the acceptance.
+    pub fn agreement(&self) -> (Option<&str>, Option<&str>, Option<&str>) {
+        match self.digest {
+            Some(ref digest) => (None, None, Some(digest.as_str())),
+            None => (Some(self.text.as_str()), Some(self.version.as_str()), None)
+        }
+    }
+
+    pub fn mechanism(&self) -> &str {
+        self.mechanism.as_ref().map(String::as_str).unwrap_or("")
+    }
+}
+
+/// Transaction author agreement and acceptance mechanisms set on the Ledger.
+/// Requested once per pool connection and cached in the CLI context.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct LedgerAuthorAgreement {
+    pub text: String,
+    pub version: String,
+    pub digest: Option<String>,
+    pub aml: Vec<String>,
+}
+
+/// The Ledger accepts only the date part of the time of acceptance.
+pub fn round_to_day(time: u64) -> u64 {
+    time / SEC_IN_DAY * SEC_IN_DAY
+}
+
+pub mod group {
+    use super::*;
+
+    command_group!(CommandGroupMetadata::new("taa", "Transaction author agreement commands"));
+}
+
+pub mod status_command {
+    use super::*;
+
+    command!(CommandMetadata::build("status", "Show status of transaction author agreement acceptance for connected pool and DID.")
+                .add_optional_param("did", "DID that sends transactions (active DID is used by default)")
+                .add_example("taa status")
+                .add_example("taa status did=VsKV7grR1BUE29mG2Fm2kX")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (pool_handle, pool_name) = ensure_connected_pool(&ctx)?;
+
+        let did = get_opt_str_param("did", params).map_err(error_err!())?
+            .map(String::from)
+            .or_else(|| get_active_did(&ctx));
+
+        let LedgerAuthorAgreement { text, version, digest, aml } = match get_ledger_author_agreement(ctx, pool_handle)? {
+            Some(agreement) => agreement,
+            None => {
+                println_msg!("There is no transaction agreement set on the Pool.");
+                return Ok(());
+            }
+        };
+
+        let acceptances = get_acceptances(ctx, &pool_name, did.as_ref().map(String::as_str))?;
+
+        let accepted = acceptances.iter()
+            .find(|acceptance| acceptance.is_for(&text, &version, digest.as_ref().map(String::as_str)));
+
+        let (status, acceptance) = match (accepted, acceptances.first()) {
+            (Some(acceptance), _) => ("accepted", Some(acceptance)),
+            (None, Some(acceptance)) => ("outdated", Some(acceptance)),
+            (None, None) => ("not accepted", None)
+        };
+
+        let mechanism = get_mechanism(ctx, acceptance);
+
+        print_table(&json!({
+                        "pool": pool_name,
+                        "did": did.as_ref().map(String::as_str).unwrap_or("-"),
+                        "version": version,
+                        "digest": digest.as_ref().map(String::as_str).unwrap_or("-"),
+                        "accepted_version": acceptance.map(|acceptance| acceptance.version.as_str()).unwrap_or("-"),
+                        "time": acceptance.map(|acceptance| format_time(acceptance.time)).unwrap_or_else(|| "-".to_string()),
+                        "mechanism": mechanism.as_ref().map(String::as_str).unwrap_or("-"),
+                        "status": status,
+                    }),
+                    &[("pool", "Pool"),
+                        ("did", "Did"),
+                        ("version", "Ledger Version"),
+                        ("digest", "Ledger Digest"),
+                        ("accepted_version", "Accepted Version"),
+                        ("time", "Time of Acceptance"),
+                        ("mechanism", "Acceptance Mechanism"),
+                        ("status", "Status")]);
+
+        match mechanism {
+            Some(ref mechanism) if !aml.is_empty() && !aml.contains(mechanism) =>
+                println_warn!("Acceptance Mechanism \"{}\" is not in the list set on the Ledger: {}", mechanism, aml.join(", ")),
+            Some(_) => {}
+            None => println_warn!("Transaction author agreement Acceptance Mechanism isn't set."),
+        }
+
+        if status != "accepted" {
+            println_msg!("Use `pool show-taa` command to accept the Agreement.");
+        }
+
+        let res = Ok(());
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+/// Returns the acceptance to be appended to a write transaction sent by `submitter_did`.
+///
+/// If a pool is connected, the active agreement set on the Ledger is used (see `get_ledger_author_agreement`).
+/// An acceptance made in the current session or stored in the wallet is reused if it relates to this agreement,
+/// otherwise the agreement is shown and the user is asked to accept it again.
+/// The acceptance mechanism is checked against the list set on the Ledger and the acceptance is stored
+/// in the opened wallet for the pool and `submitter_did`.
+pub fn ensure_author_agreement(ctx: &CommandContext, submitter_did: Option<&str>) -> Result<Option<TaaAcceptance>, ()> {
+    let (pool_handle, pool_name) = match get_connected_pool(ctx) {
+        Some(pool) => pool,
+        None => {
+            // Transaction will be sent later so use the agreement accepted in the current session
+            return match get_transaction_author_info(ctx) {
+                Some(mut acceptance) => {
+                    acceptance.mechanism = Some(ensure_mechanism(ctx, Some(&acceptance))?);
+                    Ok(Some(acceptance))
+                }
+                None => Ok(None)
+            };
+        }
+    };
+
+    let LedgerAuthorAgreement { text, version, digest, aml } = match get_ledger_author_agreement(ctx, pool_handle)? {
+        Some(agreement) => agreement,
+        None => {
+            set_transaction_author_info(ctx, None);
+            return Ok(None);
+        }
+    };
+    let digest = digest.as_ref().map(String::as_str);
+
+    let acceptances = get_acceptances(ctx, &pool_name, submitter_did)?;
+
+    let mut acceptance = match acceptances.iter().find(|acceptance| acceptance.is_for(&text, &version, digest)) {
+        Some(acceptance) => acceptance.clone(),
+        None => {
+            if let Some(outdated) = acceptances.first() {
+                println_warn!("Transaction Author Agreement has been changed on the Ledger: version \"{}\" was accepted but version \"{}\" is active now.", outdated.version, version);
+            }
+
+            show_transaction_author_agreement(&text, &version, digest);
+
+            match accept_transaction_author_agreement(ctx, &text, &version, digest) {
+                Some(acceptance) => acceptance,
+                None => {
+                    println_err!("Transaction can not be sent without accepting of the Transaction Author Agreement.");
+                    return Err(());
+                }
+            }
+        }
+    };
+
+    let mechanism = ensure_mechanism(ctx, Some(&acceptance))?;
+
+    if !aml.is_empty() && !aml.contains(&mechanism) {
+        println_err!("Acceptance Mechanism \"{}\" is not in the list set on the Ledger: {}", mechanism, aml.join(", "));
+        return Err(());
+    }
+
+    acceptance.mechanism = Some(mechanism);
+
+    set_transaction_author_info(ctx, Some(acceptance.clone()));
+
+    if let Some(did) = submitter_did {
+        if read_acceptance(ctx, &pool_name, did)?.as_ref() != Some(&acceptance) {
+            store_acceptance(ctx, &pool_name, did, &acceptance)?;
+        }
+    }
+
+    Ok(Some(acceptance))
+}
+
+/// Returns the acceptance of the given agreement made in the current session or stored in the wallet.
+pub fn get_accepted_author_agreement(ctx: &CommandContext, pool_name: &str, did: Option<&str>,
+                                     text: &str, version: &str, digest: Option<&str>) -> Result<Option<TaaAcceptance>, ()> {
+    Ok(get_acceptances(ctx, pool_name, did)?
+        .into_iter()
+        .find(|acceptance| acceptance.is_for(text, version, digest)))
+}
+
+fn get_acceptances(ctx: &CommandContext, pool_name: &str, did: Option<&str>) -> Result<Vec<TaaAcceptance>, ()> {
+    let mut acceptances = Vec::new();
+
+    if let Some(acceptance) = get_transaction_author_info(ctx) {
+        acceptances.push(acceptance);
+    }
+
+    if let Some(did) = did {
+        if let Some(acceptance) = read_acceptance(ctx, pool_name, did)? {
+            acceptances.push(acceptance);
+        }
+    }
+
+    Ok(acceptances)
+}
+
+fn get_mechanism(ctx: &CommandContext, acceptance: Option<&TaaAcceptance>) -> Option<String> {
+    let mechanism = ctx.get_taa_acceptance_mechanism();
+
+    if !mechanism.is_empty() {
+        return Some(mechanism);
+    }
+
+    acceptance.and_then(|acceptance| acceptance.mechanism.clone())
+}
+
+fn ensure_mechanism(ctx: &CommandContext, acceptance: Option<&TaaAcceptance>) -> Result<String, ()> {
+    get_mechanism(ctx, acceptance)
+        .ok_or_else(|| println_err!("Transaction author agreement Acceptance Mechanism isn't set."))
+}
+
+/// Returns the agreement set on the Ledger of the connected pool.
+/// It is requested from the Ledger only once per pool connection or after a transaction
+/// has been rejected because of the agreement (see `check_author_agreement_rejection`).
+pub fn get_ledger_author_agreement(ctx: &CommandContext, pool_handle: PoolHandle) -> Result<Option<LedgerAuthorAgreement>, ()> {
+    if let Some(agreement) = get_cached_author_agreement(ctx) {
+        return Ok(agreement);
+    }
+
+    let agreement = match get_active_transaction_author_agreement(pool_handle)? {
+        Some((text, version, digest)) => Some(LedgerAuthorAgreement {
+            text,
+            version,
+            digest,
+            aml: get_acceptance_mechanisms(pool_handle)?,
+        }),
+        None => None
+    };
+
+    set_cached_author_agreement(ctx, Some(agreement.clone()));
+
+    Ok(agreement)
+}
+
+/// Drops the cached agreement if the Ledger rejected the transaction because of the agreement acceptance
+/// so the next transaction requests the agreement set on the Ledger again.
+pub fn check_author_agreement_rejection(ctx: &CommandContext, response: &Response<JSONValue>) {
+    match response {
+        Response { op: ResponseType::REQNACK, reason: Some(reason), .. } |
+        Response { op: ResponseType::REJECT, reason: Some(reason), .. } if reason.contains(TAA_REJECTION_REASON) =>
+            set_cached_author_agreement(ctx, None),
+        _ => {}
+    }
+}
+
+fn get_acceptance_mechanisms(pool_handle: PoolHandle) -> Result<Vec<String>, ()> {
+    let response = Ledger::build_get_acceptance_mechanisms_request(None, None, None)
+        .and_then(|request| Ledger::submit_request(pool_handle, &request))
+        .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+    let response = serde_json::from_str::<JSONValue>(&response)
+        .map_err(|err| println_err!("Invalid transaction response: {:?}", err))?;
+
+    Ok(response["result"]["data"]["aml"].as_object()
+        .map(|aml| aml.keys().cloned().collect())
+        .unwrap_or_default())
+}
+
+fn acceptance_id(pool_name: &str, did: &str) -> String {
+    format!("{}:{}", pool_name, did)
+}
+
+fn read_acceptance(ctx: &CommandContext, pool_name: &str, did: &str) -> Result<Option<TaaAcceptance>, ()> {
+    let (wallet_handle, wallet_name) = match get_opened_wallet(ctx) {
+        Some(wallet) => wallet,
+        None => return Ok(None)
+    };
+
+    let record = match Wallet::get_record(wallet_handle, ACCEPTANCE_RECORD_TYPE, &acceptance_id(pool_name, did), RECORD_OPTIONS) {
+        Ok(record) => record,
+        Err(ref err) if err.error_code == ErrorCode::WalletItemNotFound => return Ok(None),
+        Err(err) => {
+            handle_indy_error(err, Some(did), Some(pool_name), Some(&wallet_name));
+            return Err(());
+        }
+    };
+
+    let record = serde_json::from_str::<JSONValue>(&record)
+        .map_err(|err| println_err!("Invalid wallet record: {:?}", err))?;
+
+    // Records of unknown format are ignored and will be overwritten with the next acceptance
+    Ok(record["value"].as_str()
+        .and_then(|value| serde_json::from_str::<TaaAcceptance>(value).ok()))
+}
+
+fn store_acceptance(ctx: &CommandContext, pool_name: &str, did: &str, acceptance: &TaaAcceptance) -> Result<(), ()> {
+    let (wallet_handle, wallet_name) = match get_opened_wallet(ctx) {
+        Some(wallet) => wallet,
+        None => return Ok(())
+    };
+
+    let id = acceptance_id(pool_name, did);
+    let value = json!(acceptance).to_string();
+    let tags = json!({"pool": pool_name, "did": did, "version": acceptance.version}).to_string();
+
+    let res = match Wallet::add_record(wallet_handle, ACCEPTANCE_RECORD_TYPE, &id, &value, Some(tags.as_str())) {
+        Err(ref err) if err.error_code == ErrorCode::WalletItemAlreadyExists =>
+            Wallet::update_record_value(wallet_handle, ACCEPTANCE_RECORD_TYPE, &id, &value)
+                .and_then(|_| Wallet::update_record_tags(wallet_handle, ACCEPTANCE_RECORD_TYPE, &id, &tags)),
+        res => res
+    };
+
+    res.map_err(|err| handle_indy_error(err, Some(did), Some(pool_name), Some(&wallet_name)))
+}
+
+fn format_time(time: u64) -> String {
+    NaiveDateTime::from_timestamp(time as i64, 0).format("%Y-%m-%d").to_string()
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::commands::did::tests::DID_MY1;
+
+    const POOL: &str = "pool";
+    const TEXT: &str = "test taa";
+    const VERSION: &str = "1.0";
+    const DIGEST: &str = "4b5e9bb8d0a6e4a5f1c2b9d3a7e8f60c1d2e3f405162738495a6b7c8d9e0f1a2";
+    const MECHANISM: &str = "Acceptance Mechanism 1";
+
+    fn acceptance() -> TaaAcceptance {
+        let mut acceptance = TaaAcceptance::new(TEXT, VERSION, Some(DIGEST), 1571097600 + 3600);
+        acceptance.mechanism = Some(MECHANISM.to_string());
+        acceptance
+    }
+
+    mod acceptance {
+        use super::*;
+
+        #[test]
+        pub fn new_works_for_rounding_time() {
+            assert_eq!(1571097600, acceptance().time);
+            assert_eq!(1571097600, round_to_day(1571097600));
+            assert_eq!(1571097600, round_to_day(1571097600 + SEC_IN_DAY - 1));
+        }
+
+        #[test]
+        pub fn is_for_works() {
+            let acceptance = acceptance();
+            assert!(acceptance.is_for(TEXT, VERSION, Some(DIGEST)));
+            assert!(acceptance.is_for(TEXT, VERSION, None));
+            assert!(!acceptance.is_for("other taa", "2.0", Some("other digest")));
+            assert!(!acceptance.is_for(TEXT, "2.0", None));
+        }
+
+        #[test]
+        pub fn is_for_works_for_unknown_digest() {
+            let acceptance = TaaAcceptance::new(TEXT, VERSION, None, 0);
+            assert!(acceptance.is_for(TEXT, VERSION, Some(DIGEST)));
+            assert!(!acceptance.is_for(TEXT, "2.0", Some(DIGEST)));
+        }
+
+        #[test]
+        pub fn agreement_works() {
+            assert_eq!((None, None, Some(DIGEST)), acceptance().agreement());
+            assert_eq!((Some(TEXT), Some(VERSION), None), TaaAcceptance::new(TEXT, VERSION, None, 0).agreement());
+        }
+    }
+
+    mod store {
+        use super::*;
+
+        #[test]
+        pub fn store_acceptance_works() {
+            let ctx = setup_with_wallet();
+
+            assert_eq!(None, read_acceptance(&ctx, POOL, DID_MY1).unwrap());
+
+            store_acceptance(&ctx, POOL, DID_MY1, &acceptance()).unwrap();
+            assert_eq!(Some(acceptance()), read_acceptance(&ctx, POOL, DID_MY1).unwrap());
+            assert_eq!(None, read_acceptance(&ctx, "other_pool", DID_MY1).unwrap());
+
+            let mut updated = TaaAcceptance::new("other taa", "2.0", None, 1571097600);
+            updated.mechanism = Some(MECHANISM.to_string());
+            store_acceptance(&ctx, POOL, DID_MY1, &updated).unwrap();
+            assert_eq!(Some(updated), read_acceptance(&ctx, POOL, DID_MY1).unwrap());
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn get_accepted_author_agreement_works() {
+            let ctx = setup_with_wallet();
+
+            store_acceptance(&ctx, POOL, DID_MY1, &acceptance()).unwrap();
+
+            assert_eq!(Some(acceptance()), get_accepted_author_agreement(&ctx, POOL, Some(DID_MY1), TEXT, VERSION, Some(DIGEST)).unwrap());
+            assert_eq!(None, get_accepted_author_agreement(&ctx, POOL, Some(DID_MY1), TEXT, "2.0", Some("other digest")).unwrap());
+            assert_eq!(None, get_accepted_author_agreement(&ctx, POOL, None, TEXT, VERSION, Some(DIGEST)).unwrap());
+
+            set_transaction_author_info(&ctx, Some(acceptance()));
+            assert_eq!(Some(acceptance()), get_accepted_author_agreement(&ctx, POOL, None, TEXT, VERSION, Some(DIGEST)).unwrap());
+
+            tear_down_with_wallet(&ctx);
+        }
+    }
+
+    mod ledger_agreement {
+        use super::*;
+
+        fn ledger_agreement() -> LedgerAuthorAgreement {
+            LedgerAuthorAgreement {
+                text: TEXT.to_string(),
+                version: VERSION.to_string(),
+                digest: Some(DIGEST.to_string()),
+                aml: vec![MECHANISM.to_string()],
+            }
+        }
+
+        fn response(op: &str, reason: &str) -> Response<JSONValue> {
+            serde_json::from_value(json!({"op": op, "reason": reason})).unwrap()
+        }
+
+        #[test]
+        pub fn check_author_agreement_rejection_works() {
+            let ctx = setup();
+
+            set_cached_author_agreement(&ctx, Some(Some(ledger_agreement())));
+
+            check_author_agreement_rejection(&ctx, &response("REJECT", "client request invalid: InvalidClientRequest('Insufficient signatures',)"));
+            assert_eq!(Some(Some(ledger_agreement())), get_cached_author_agreement(&ctx));
+
+            check_author_agreement_rejection(&ctx, &response("REJECT", "client request invalid: InvalidClientTaaAcceptanceError('Txn Author Agreement acceptance is required for ledger with id 1',)"));
+            assert_eq!(None, get_cached_author_agreement(&ctx));
+
+            tear_down();
+        }
+
+        #[test]
+        pub fn set_connected_pool_resets_cached_agreement() {
+            let ctx = setup();
+
+            set_cached_author_agreement(&ctx, Some(None));
+            assert_eq!(Some(None), get_cached_author_agreement(&ctx));
+
+            set_connected_pool(&ctx, None);
+            assert_eq!(None, get_cached_author_agreement(&ctx));
+
+            tear_down();
+        }
+    }
+
+    mod status {
+        use super::*;
+
+        #[test]
+        pub fn status_works_for_no_taa() {
+            let ctx = setup_with_wallet_and_pool();
+            {
+                let cmd = status_command::new();
+                let params = CommandParams::new();
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
+        #[test]
+        pub fn status_works_for_no_connected_pool() {
+            let ctx = setup();
+            {
+                let cmd = status_command::new();
+                let params = CommandParams::new();
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down();
+        }
+    }
+}
@@ -0,0 +1,1096 @@
+use crate::command_executor::{Command, CommandContext, CommandMetadata, CommandParams, CommandGroup, CommandGroupMetadata};
+use crate::commands::*;
+use crate::commands::ledger::{AuthRuleData, AuthRulesData, Response, handle_transaction_response, parse_transaction_response,
+                              set_author_agreement, get_role_title, get_txn_title};
+use crate::commands::taa::check_author_agreement_rejection;
+
+use indy::PoolHandle;
+use crate::libindy::crypto::Crypto;
+use crate::libindy::ledger::Ledger;
+
+use rust_base58::{FromBase58, ToBase58};
+use serde_json::Value as JSONValue;
+use sha2::{Digest, Sha256};
+
+use std::collections::BTreeMap;
+
+use crate::utils::table::{print_table, print_list_table};
+use crate::utils::file::{read_file, write_file};
+use crate::utils::output;
+
+pub const ENVELOPE_VERSION: u32 = 1;
+
+const NYM: &str = "1";
+const ATTRIB: &str = "100";
+const GET_ATTR: &str = "104";
+
+/// Transaction passed between signers in the offline multi-signature workflow.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TransactionEnvelope {
+    pub version: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub transaction: JSONValue,
+}
+
+pub mod group {
+    use super::*;
+
+    command_group!(CommandGroupMetadata::new("txn", "Offline multi-signature transaction workflow commands"));
+}
+
+pub mod create_command {
+    use super::*;
+
+    command!(CommandMetadata::build("create", "Create transaction envelope file to collect signatures of several parties.")
+                .add_main_param("file", "The path to the envelope file to create")
+                .add_optional_param("txn", "Transaction to put into the envelope. Skip to use a transaction stored into CLI context.")
+                .add_optional_param("description", "Human readable description of the transaction shown to signers")
+                .add_example("txn create /home/nym.txn")
+                .add_example(r#"txn create /home/nym.txn txn={"reqId":123456789,"identifier":"V4SGRU86Z58d6TV7PBUe6f","operation":{"type":"1","dest":"VsKV7grR1BUE29mG2Fm2kX"},"protocolVersion":2}"#)
+                .add_example(r#"txn create /home/nym.txn description="Promote VsKV7grR1BUE29mG2Fm2kX to Steward""#)
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let file = get_str_param("file", params).map_err(error_err!())?;
+        let description = get_opt_str_param("description", params).map_err(error_err!())?;
+
+        let mut request = match get_opt_str_param("txn", params).map_err(error_err!())? {
+            Some(txn) => txn.to_string(),
+            None => ensure_set_transaction(ctx)?
+        };
+
+        let transaction = parse_transaction(&request)?;
+
+        // Transaction author agreement is a part of signed data so it can be appended only before the first signature
+        if collect_signatures(&transaction)?.is_empty() && transaction["taaAcceptance"].is_null() {
+            set_author_agreement(ctx, &mut request)?;
+        }
+
+        let envelope = TransactionEnvelope {
+            version: ENVELOPE_VERSION,
+            description: description.map(String::from),
+            transaction: parse_transaction(&request)?,
+        };
+
+        write_envelope(file, &envelope)?;
+
+        println_succ!("Transaction envelope has been saved to \"{}\"", file);
+        let res = Ok(());
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod inspect_command {
+    use super::*;
+
+    command!(CommandMetadata::build("inspect", "Decode transaction envelope and list collected signatures. \
+                                                If a pool is connected signatures are verified with signer verkeys received by GET_NYM \
+                                                and checked against the auth rule received by GET_AUTH_RULE.")
+                .add_main_param("file", "The path to the envelope file")
+                .add_optional_param("action", "Auth rule action to check. One of: ADD, EDIT (ADD by default)")
+                .add_optional_param("field", "Auth rule field to check. Skip to use all rules matching the operation")
+                .add_optional_param("old_value", "Auth rule old value to check (for EDIT action)")
+                .add_optional_param("new_value", "Auth rule new value to check. Skip to take it from the operation")
+                .add_example("txn inspect /home/nym.txn")
+                .add_example("txn inspect /home/nym.txn action=EDIT field=role old_value=101 new_value=2")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let file = get_str_param("file", params).map_err(error_err!())?;
+        let filter = get_rule_filter(params)?;
+
+        let envelope = read_envelope(file)?;
+        let transaction = &envelope.transaction;
+        let signatures = collect_signatures(transaction)?;
+
+        let taa = if transaction["taaAcceptance"].is_null() { "-" } else { "accepted" };
+
+        println_succ!("Transaction:");
+        print_table(&json!({
+                        "description": envelope.description,
+                        "type": get_txn_title(&transaction["operation"]["type"]),
+                        "identifier": transaction["identifier"],
+                        "endorser": transaction["endorser"],
+                        "reqId": transaction["reqId"],
+                        "taa": taa,
+                    }),
+                    &[("description", "Description"),
+                        ("type", "Type"),
+                        ("identifier", "Identifier"),
+                        ("endorser", "Endorser"),
+                        ("reqId", "Request Id"),
+                        ("taa", "Author Agreement")]);
+
+        println_succ!("Operation:");
+        output::print_json(&serde_json::to_string_pretty(&transaction["operation"]).unwrap(), "");
+
+        let pool_handle = match get_connected_pool(ctx) {
+            Some((pool_handle, _)) => pool_handle,
+            None => {
+                println_succ!("Signatures:");
+                print_list_table(&signatures.keys().map(|did| json!({"did": did})).collect::<Vec<JSONValue>>(),
+                                 &[("did", "Did")],
+                                 "There are no signatures");
+                println_warn!("Connect to a pool to check collected signatures against the auth rule");
+                return Ok(());
+            }
+        };
+
+        let signers = get_signers(pool_handle, transaction, &signatures)?;
+
+        println_succ!("Signatures:");
+        print_list_table(&signers.iter()
+                             .map(|signer| {
+                                 let role = if signer.on_ledger { get_role_title(&json!(signer.role)) } else { json!("not on ledger") };
+                                 let signature = if signer.verified { "valid" } else { "invalid" };
+                                 json!({"did": signer.did, "role": role, "signature": signature})
+                             })
+                             .collect::<Vec<JSONValue>>(),
+                         &[("did", "Did"),
+                             ("role", "Role"),
+                             ("signature", "Signature")],
+                         "There are no signatures");
+
+        warn_invalid_signatures(&signers);
+
+        let checks = check_auth_rules(pool_handle, transaction, &filter, &signers)?;
+
+        println_succ!("Auth rules:");
+        print_list_table(&checks.iter()
+                             .map(|(rule, met)| {
+                                 let met = if *met { "yes" } else { "no" };
+                                 json!({
+                                     "auth_action": rule.auth_action,
+                                     "field": rule.field,
+                                     "old_value": rule.old_value,
+                                     "new_value": rule.new_value,
+                                     "constraint": serde_json::to_string_pretty(&rule.constraint).unwrap(),
+                                     "met": met,
+                                 })
+                             })
+                             .collect::<Vec<JSONValue>>(),
+                         &[("auth_action", "Action"),
+                             ("field", "Field"),
+                             ("old_value", "Old Value"),
+                             ("new_value", "New Value"),
+                             ("constraint", "Constraint"),
+                             ("met", "Met")],
+                         "There are no auth rules matching the transaction");
+
+        match constraint_status(&checks) {
+            Some(true) => println_succ!("Auth rule constraint is met"),
+            Some(false) => println_warn!("Auth rule constraint is not met"),
+            None => println_warn!("Auth rule constraint cannot be determined. Specify `action`, `field`, `old_value` and `new_value` to select the rule"),
+        }
+
+        let res = Ok(());
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod sign_command {
+    use super::*;
+
+    command!(CommandMetadata::build("sign", "Add multi signature of the active DID to the transaction envelope.")
+                .add_main_param("file", "The path to the envelope file")
+                .add_optional_param("out", "The path to save the signed envelope. Skip to update the source file")
+                .add_example("txn sign /home/nym.txn")
+                .add_example("txn sign /home/nym.txn out=/home/nym_signed.txn")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+        let submitter_did = ensure_active_did(&ctx)?;
+
+        let file = get_str_param("file", params).map_err(error_err!())?;
+        let out = get_opt_str_param("out", params).map_err(error_err!())?.unwrap_or(file);
+
+        let mut envelope = read_envelope(file)?;
+
+        let request = Ledger::multi_sign_request(wallet_handle, &submitter_did, &envelope.transaction.to_string())
+            .map_err(|err| handle_indy_error(err, Some(&submitter_did), None, Some(&wallet_name)))?;
+
+        envelope.transaction = parse_transaction(&request)?;
+
+        write_envelope(out, &envelope)?;
+
+        println_succ!("Transaction has been signed by \"{}\" and saved to \"{}\"", submitter_did, out);
+        let res = Ok(());
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod merge_command {
+    use super::*;
+
+    command!(CommandMetadata::build("merge", "Combine signatures collected in several envelope files of the same transaction.")
+                .add_main_param("file", "The path to save the merged envelope")
+                .add_required_param("files", "The list of envelope files to merge separated by comma")
+                .add_example("txn merge /home/nym.txn files=/home/nym_trustee1.txn,/home/nym_trustee2.txn")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let file = get_str_param("file", params).map_err(error_err!())?;
+        let files = get_str_array_param("files", params).map_err(error_err!())?;
+
+        let envelopes = files.iter()
+            .map(|file| read_envelope(file))
+            .collect::<Result<Vec<TransactionEnvelope>, ()>>()?;
+
+        let envelope = merge_envelopes(&files, envelopes)?;
+        let signatures = collect_signatures(&envelope.transaction)?;
+
+        write_envelope(file, &envelope)?;
+
+        println_succ!("{} signature(s) have been merged into \"{}\"", signatures.len(), file);
+        let res = Ok(());
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod submit_command {
+    use super::*;
+
+    command!(CommandMetadata::build("submit", "Send the transaction from the envelope to the Ledger once the auth rule constraint is met.")
+                .add_main_param("file", "The path to the envelope file")
+                .add_optional_param("action", "Auth rule action to check. One of: ADD, EDIT (ADD by default)")
+                .add_optional_param("field", "Auth rule field to check. Skip to use all rules matching the operation")
+                .add_optional_param("old_value", "Auth rule old value to check (for EDIT action)")
+                .add_optional_param("new_value", "Auth rule new value to check. Skip to take it from the operation")
+                .add_optional_param("force", "Send the transaction even if collected signatures do not meet the auth rule constraint or it cannot be determined (false by default)")
+                .add_example("txn submit /home/nym.txn")
+                .add_example("txn submit /home/nym.txn force=true")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (pool_handle, pool_name) = ensure_connected_pool(&ctx)?;
+
+        let file = get_str_param("file", params).map_err(error_err!())?;
+        let force = get_opt_bool_param("force", params).map_err(error_err!())?.unwrap_or(false);
+        let filter = get_rule_filter(params)?;
+
+        let envelope = read_envelope(file)?;
+        let transaction = &envelope.transaction;
+
+        let signatures = collect_signatures(transaction)?;
+        let signers = get_signers(pool_handle, transaction, &signatures)?;
+        warn_invalid_signatures(&signers);
+
+        let checks = check_auth_rules(pool_handle, transaction, &filter, &signers)?;
+
+        match constraint_status(&checks) {
+            Some(true) => {}
+            Some(false) if force => println_warn!("Auth rule constraint is not met. Sending the transaction anyway"),
+            Some(false) => {
+                println_err!("Auth rule constraint is not met. Collect more signatures or use `force=true` to send the transaction anyway");
+                return Err(());
+            }
+            None if force => println_warn!("Auth rule constraint cannot be determined. Sending the transaction anyway"),
+            None => {
+                println_err!("Auth rule constraint cannot be determined: no auth rule matches the transaction or matching rules disagree. \
+                              Specify the rule by `action` and `field` params or use `force=true` to send the transaction anyway");
+                return Err(());
+            }
+        }
+
+        let response_json = Ledger::submit_request(pool_handle, &transaction.to_string())
+            .map_err(|err| handle_indy_error(err, None, Some(&pool_name), None))?;
+
+        let response = serde_json::from_str::<Response<JSONValue>>(&response_json)
+            .map_err(|err| println_err!("Invalid data has been received: {:?}", err))?;
+
+        check_author_agreement_rejection(ctx, &response);
+
+        handle_transaction_response(response)
+            .and_then(|result| parse_transaction_response(result))
+            .map(|(metadata_headers, metadata, data)| {
+                println_succ!("Transaction has been sent to Ledger.");
+
+                println_succ!("Metadata:");
+                print_table(&metadata, &metadata_headers);
+
+                println_succ!("Data:");
+                print_table(&json!({"data": data}), &[("data", "Data")]);
+            })?;
+
+        trace!("execute <<");
+        Ok(())
+    }
+}
+
+fn read_envelope(file: &str) -> Result<TransactionEnvelope, ()> {
+    let content = read_file(file)
+        .map_err(|err| println_err!("{}", err))?;
+
+    let envelope = serde_json::from_str::<TransactionEnvelope>(&content)
+        .map_err(|err| println_err!("File \"{}\" contains invalid transaction envelope: {:?}", file, err))?;
+
+    if envelope.version != ENVELOPE_VERSION {
+        println_err!("Unsupported transaction envelope version {} in \"{}\"", envelope.version, file);
+        return Err(());
+    }
+
+    parse_transaction(&envelope.transaction.to_string())?;
+
+    Ok(envelope)
+}
+
+fn write_envelope(file: &str, envelope: &TransactionEnvelope) -> Result<(), ()> {
+    let content = serde_json::to_string_pretty(envelope)
+        .map_err(|err| println_err!("Invalid data: {:?}", err))?;
+
+    write_file(file, &content)
+        .map_err(|err| println_err!("Cannot store transaction envelope into the file: {:?}", err))
+}
+
+fn parse_transaction(transaction: &str) -> Result<JSONValue, ()> {
+    serde_json::from_str::<ledger::load_transaction_command::Request>(transaction)
+        .map_err(|err| println_err!("Invalid transaction: {:?}", err))?;
+
+    serde_json::from_str::<JSONValue>(transaction)
+        .map_err(|err| println_err!("Invalid transaction: {:?}", err))
+}
+
+/// Signatures of the transaction by signer DID. A single signature belongs to the transaction identifier.
+/// Signatures are not checked here, see `get_signers`.
+fn collect_signatures(transaction: &JSONValue) -> Result<BTreeMap<String, String>, ()> {
+    let mut signatures: BTreeMap<String, String> = match transaction.get("signatures") {
+        Some(signatures) => serde_json::from_value(signatures.clone())
+            .map_err(|_| println_err!("Transaction contains invalid signatures"))?,
+        None => BTreeMap::new()
+    };
+
+    if let (Some(signature), Some(identifier)) = (transaction["signature"].as_str(), transaction["identifier"].as_str()) {
+        signatures.insert(identifier.to_string(), signature.to_string());
+    }
+
+    Ok(signatures)
+}
+
+fn unsigned_transaction(transaction: &JSONValue) -> JSONValue {
+    let mut transaction = transaction.clone();
+    if let Some(transaction) = transaction.as_object_mut() {
+        transaction.remove("signature");
+        transaction.remove("signatures");
+    }
+    transaction
+}
+
+fn merge_envelopes(files: &[&str], envelopes: Vec<TransactionEnvelope>) -> Result<TransactionEnvelope, ()> {
+    let mut envelopes = envelopes.into_iter().zip(files.iter());
+
+    let (first, first_file) = match envelopes.next() {
+        Some(envelope) => envelope,
+        None => {
+            println_err!("List of envelope files to merge is empty");
+            return Err(());
+        }
+    };
+
+    let mut transaction = unsigned_transaction(&first.transaction);
+    let mut description = first.description;
+    let mut signatures = collect_signatures(&first.transaction)?;
+
+    for (envelope, file) in envelopes {
+        if unsigned_transaction(&envelope.transaction) != transaction {
+            println_err!("Transaction in \"{}\" differs from transaction in \"{}\"", file, first_file);
+            return Err(());
+        }
+
+        if description.is_none() {
+            description = envelope.description;
+        }
+
+        for (did, other) in collect_signatures(&envelope.transaction)? {
+            match signatures.get(&did) {
+                Some(signature) if *signature != other => {
+                    println_err!("Transaction in \"{}\" contains conflicting signature of \"{}\"", file, did);
+                    return Err(());
+                }
+                _ => { signatures.insert(did, other); }
+            }
+        }
+    }
+
+    // The signature of the identifier is moved to `signatures` as libindy multi-sign does,
+    // because the Ledger rejects a transaction containing both.
+    if !signatures.is_empty() {
+        transaction["signatures"] = json!(signatures);
+    }
+
+    Ok(TransactionEnvelope { version: ENVELOPE_VERSION, description, transaction })
+}
+
+#[derive(Debug)]
+pub struct RuleFilter<'a> {
+    pub action: &'a str,
+    pub field: Option<&'a str>,
+    pub old_value: Option<&'a str>,
+    pub new_value: Option<&'a str>,
+}
+
+fn get_rule_filter(params: &CommandParams) -> Result<RuleFilter, ()> {
+    Ok(RuleFilter {
+        action: get_opt_str_param("action", params).map_err(error_err!())?.unwrap_or("ADD"),
+        field: get_opt_str_param("field", params).map_err(error_err!())?,
+        old_value: get_opt_str_param("old_value", params).map_err(error_err!())?,
+        new_value: get_opt_str_param("new_value", params).map_err(error_err!())?,
+    })
+}
+
+fn value_to_string(value: &JSONValue) -> String {
+    match value {
+        JSONValue::Null => String::new(),
+        JSONValue::String(value) => value.to_string(),
+        value => value.to_string()
+    }
+}
+
+fn rule_matches(rule: &AuthRuleData, operation: &JSONValue, filter: &RuleFilter) -> bool {
+    if rule.auth_type != value_to_string(&operation["type"]) || !rule.auth_action.eq_ignore_ascii_case(filter.action) {
+        return false;
+    }
+
+    if filter.field.map(|field| field != rule.field).unwrap_or(false) {
+        return false;
+    }
+
+    if filter.old_value.is_some() && rule.old_value.as_ref().map(String::as_str) != filter.old_value {
+        return false;
+    }
+
+    let new_value = match filter.new_value {
+        Some(new_value) => new_value.to_string(),
+        None if rule.field == "*" => return true,
+        None => value_to_string(&operation[&rule.field])
+    };
+
+    match rule.new_value {
+        Some(ref value) => value == "*" || *value == new_value,
+        None => new_value.is_empty()
+    }
+}
+
+#[derive(Debug)]
+pub struct Signer {
+    pub did: String,
+    pub role: Option<String>,
+    pub on_ledger: bool,
+    // the signature is checked against the verkey of the DID, only verified signers count toward auth rules
+    pub verified: bool,
+}
+
+fn get_signers(pool_handle: PoolHandle, transaction: &JSONValue, signatures: &BTreeMap<String, String>) -> Result<Vec<Signer>, ()> {
+    let signature_input = signature_input(transaction);
+
+    signatures.iter()
+        .map(|(did, signature)| {
+            let request = Ledger::build_get_nym_request(None, did)
+                .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+            let response_json = Ledger::submit_request(pool_handle, &request)
+                .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+            let response = serde_json::from_str::<Response<JSONValue>>(&response_json)
+                .map_err(|err| println_err!("Invalid data has been received: {:?}", err))?;
+
+            let result = handle_transaction_response(response)?;
+
+            let data = match result["data"].as_str() {
+                Some(data) => serde_json::from_str::<JSONValue>(data)
+                    .map_err(|_| println_err!("Wrong data has been received"))?,
+                None => JSONValue::Null
+            };
+
+            let verified = signer_verkey(did, &data, &transaction["operation"])
+                .map(|verkey| verify_signature(&verkey, &signature_input, signature))
+                .unwrap_or(false);
+
+            Ok(Signer {
+                did: did.to_string(),
+                role: data["role"].as_str().map(String::from),
+                on_ledger: !data.is_null(),
+                verified,
+            })
+        })
+        .collect()
+}
+
+fn warn_invalid_signatures(signers: &[Signer]) {
+    for signer in signers.iter().filter(|signer| !signer.verified) {
+        println_warn!("Signature of \"{}\" is invalid and is not taken into account", signer.did);
+    }
+}
+
+/// Verkey to check the signature of the DID: the verkey from the Ledger NYM or,
+/// for a DID that is not on the Ledger yet, the verkey set by the NYM transaction itself.
+fn signer_verkey(did: &str, nym_data: &JSONValue, operation: &JSONValue) -> Option<String> {
+    let verkey = if !nym_data.is_null() {
+        nym_data["verkey"].as_str()
+    } else if operation["type"].as_str() == Some(NYM) && operation["dest"].as_str() == Some(did) {
+        operation["verkey"].as_str()
+    } else {
+        None
+    };
+
+    match verkey {
+        // abbreviated verkey is the rest of the key following the DID bytes
+        Some(verkey) if verkey.starts_with('~') => {
+            let mut full_verkey = did.from_base58().ok()?;
+            full_verkey.extend(verkey[1..].from_base58().ok()?);
+            Some(full_verkey.to_base58())
+        }
+        Some(verkey) => Some(verkey.to_string()),
+        // cryptonym
+        None => Some(did.to_string())
+    }
+}
+
+fn verify_signature(verkey: &str, signature_input: &str, signature: &str) -> bool {
+    let signature = match signature.from_base58() {
+        Ok(signature) => signature,
+        Err(_) => return false
+    };
+
+    Crypto::verify(verkey, signature_input.as_bytes(), &signature).unwrap_or(false)
+}
+
+/// Data signed by libindy request signing: the transaction without signatures serialized as the Ledger does it.
+/// Keys are sorted, pairs are joined by `|`, arrays by `,` and ATTRIB values are replaced by their sha256 hash.
+fn signature_input(transaction: &JSONValue) -> String {
+    let txn_type = transaction["operation"]["type"].as_str();
+    _serialize_signature_input(transaction, true, txn_type)
+}
+
+fn _serialize_signature_input(value: &JSONValue, is_top_level: bool, txn_type: Option<&str>) -> String {
+    match value {
+        JSONValue::Bool(value) => if *value { "True".to_string() } else { "False".to_string() },
+        JSONValue::Number(value) => value.to_string(),
+        JSONValue::String(value) => value.to_string(),
+        JSONValue::Array(array) => array.iter()
+            .map(|element| _serialize_signature_input(element, false, txn_type))
+            .collect::<Vec<String>>()
+            .join(","),
+        JSONValue::Object(map) => map.iter()
+            .filter(|(key, _)| !is_top_level || !["signature", "fees", "signatures"].contains(&key.as_str()))
+            .map(|(key, value)| {
+                let value = match value.as_str() {
+                    Some(raw) if (txn_type == Some(ATTRIB) || txn_type == Some(GET_ATTR)) && ["raw", "hash", "enc"].contains(&key.as_str()) =>
+                        Sha256::digest(raw.as_bytes()).iter().map(|byte| format!("{:02x}", byte)).collect::<String>(),
+                    _ => _serialize_signature_input(value, false, txn_type)
+                };
+                format!("{}:{}", key, value)
+            })
+            .collect::<Vec<String>>()
+            .join("|"),
+        JSONValue::Null => String::new()
+    }
+}
+
+fn get_auth_rules(pool_handle: PoolHandle) -> Result<AuthRulesData, ()> {
+    let request = Ledger::build_get_auth_rule_request(None, None, None, None, None, None)
+        .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+    let response_json = Ledger::submit_request(pool_handle, &request)
+        .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+    let response = serde_json::from_str::<Response<JSONValue>>(&response_json)
+        .map_err(|err| println_err!("Invalid data has been received: {:?}", err))?;
+
+    let result = handle_transaction_response(response)?;
+
+    serde_json::from_value(result["data"].clone())
+        .map_err(|_| println_err!("Wrong data has been received"))
+}
+
+fn check_auth_rules(pool_handle: PoolHandle, transaction: &JSONValue, filter: &RuleFilter, signers: &[Signer]) -> Result<Vec<(AuthRuleData, bool)>, ()> {
+    let checks = get_auth_rules(pool_handle)?
+        .into_iter()
+        .filter(|rule| rule_matches(rule, &transaction["operation"], filter))
+        .map(|rule| {
+            let met = evaluate_constraint(&rule.constraint, signers);
+            (rule, met)
+        })
+        .collect::<Vec<(AuthRuleData, bool)>>();
+
+    if checks.iter().any(|(rule, _)| requires_owner(&rule.constraint)) {
+        println_warn!("Ownership requirement (need_to_be_owner) of the auth rule is not checked");
+    }
+
+    Ok(checks)
+}
+
+/// Checks whether verified signatures meet the auth rule constraint. Ownership is not taken into account.
+fn evaluate_constraint(constraint: &JSONValue, signers: &[Signer]) -> bool {
+    match constraint["constraint_id"].as_str() {
+        Some("ROLE") => {
+            let role = constraint["role"].as_str().unwrap_or("*");
+            let off_ledger_signature = constraint["off_ledger_signature"].as_bool().unwrap_or(false);
+            let sig_count = constraint["sig_count"].as_u64().unwrap_or(1);
+
+            let count = signers.iter()
+                .filter(|signer| signer.verified)
+                .filter(|signer| match role {
+                    "*" => signer.on_ledger || off_ledger_signature,
+                    role => signer.on_ledger && signer.role.as_ref().map(String::as_str).unwrap_or("") == role
+                })
+                .count() as u64;
+
+            count >= sig_count
+        }
+        Some("AND") => constraint["auth_constraints"].as_array()
+            .map(|constraints| constraints.iter().all(|constraint| evaluate_constraint(constraint, signers)))
+            .unwrap_or(false),
+        Some("OR") => constraint["auth_constraints"].as_array()
+            .map(|constraints| constraints.iter().any(|constraint| evaluate_constraint(constraint, signers)))
+            .unwrap_or(false),
+        _ => false
+    }
+}
+
+fn requires_owner(constraint: &JSONValue) -> bool {
+    constraint["need_to_be_owner"].as_bool().unwrap_or(false) ||
+        constraint["auth_constraints"].as_array()
+            .map(|constraints| constraints.iter().any(requires_owner))
+            .unwrap_or(false)
+}
+
+/// Returns None if the rules matching the transaction disagree or no rule matches.
+fn constraint_status(checks: &[(AuthRuleData, bool)]) -> Option<bool> {
+    let mut statuses = checks.iter().map(|(_, met)| *met);
+    let first = statuses.next()?;
+    if statuses.all(|met| met == first) { Some(first) } else { None }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::commands::did::tests::{new_did, use_did, SEED_TRUSTEE, DID_TRUSTEE, VERKEY_TRUSTEE, SEED_MY1, DID_MY1, VERKEY_MY1, DID_MY3};
+    use crate::utils::environment::EnvironmentUtils;
+
+    const TRANSACTION: &str = r#"{"reqId":1,"identifier":"V4SGRU86Z58d6TV7PBUe6f","operation":{"type":"1","dest":"VsKV7grR1BUE29mG2Fm2kX","role":"2"},"protocolVersion":2}"#;
+
+    mod create {
+        use super::*;
+
+        #[test]
+        pub fn create_works() {
+            let ctx = setup();
+            let file = envelope_path("create_works");
+            {
+                let cmd = create_command::new();
+                let mut params = CommandParams::new();
+                params.insert("file", file.clone());
+                params.insert("txn", TRANSACTION.to_string());
+                params.insert("description", "Promote to Steward".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            let envelope = read_envelope(&file).unwrap();
+            assert_eq!(Some("Promote to Steward".to_string()), envelope.description);
+            assert_eq!(serde_json::from_str::<JSONValue>(TRANSACTION).unwrap(), envelope.transaction);
+
+            tear_down();
+        }
+
+        #[test]
+        pub fn create_works_for_transaction_from_context() {
+            let ctx = setup();
+            let file = envelope_path("create_works_for_transaction_from_context");
+            set_transaction(&ctx, Some(TRANSACTION.to_string()));
+            {
+                let cmd = create_command::new();
+                let mut params = CommandParams::new();
+                params.insert("file", file.clone());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            read_envelope(&file).unwrap();
+
+            tear_down();
+        }
+
+        #[test]
+        pub fn create_works_for_invalid_transaction() {
+            let ctx = setup();
+            {
+                let cmd = create_command::new();
+                let mut params = CommandParams::new();
+                params.insert("file", envelope_path("create_works_for_invalid_transaction"));
+                params.insert("txn", r#"{"reqId":1}"#.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down();
+        }
+    }
+
+    mod inspect {
+        use super::*;
+
+        #[test]
+        pub fn inspect_works_for_no_connected_pool() {
+            let ctx = setup_with_wallet();
+            let file = create_envelope("inspect_works_for_no_connected_pool");
+            sign_envelope(&ctx, SEED_TRUSTEE, DID_TRUSTEE, &file, None);
+            {
+                let cmd = inspect_command::new();
+                let mut params = CommandParams::new();
+                params.insert("file", file);
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn inspect_works_for_unknown_file() {
+            let ctx = setup();
+            {
+                let cmd = inspect_command::new();
+                let mut params = CommandParams::new();
+                params.insert("file", envelope_path("inspect_works_for_unknown_file"));
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down();
+        }
+    }
+
+    mod sign {
+        use super::*;
+
+        #[test]
+        pub fn sign_works() {
+            let ctx = setup_with_wallet();
+            let file = create_envelope("sign_works");
+            sign_envelope(&ctx, SEED_TRUSTEE, DID_TRUSTEE, &file, None);
+            sign_envelope(&ctx, SEED_MY1, DID_MY1, &file, None);
+
+            let signatures = collect_signatures(&read_envelope(&file).unwrap().transaction).unwrap();
+            assert_eq!(vec![DID_MY1, DID_TRUSTEE], signatures.keys().map(String::as_str).collect::<Vec<&str>>());
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn sign_works_for_out_file() {
+            let ctx = setup_with_wallet();
+            let file = create_envelope("sign_works_for_out_file");
+            let out = envelope_path("sign_works_for_out_file_signed");
+            sign_envelope(&ctx, SEED_TRUSTEE, DID_TRUSTEE, &file, Some(&out));
+
+            assert!(collect_signatures(&read_envelope(&file).unwrap().transaction).unwrap().is_empty());
+            assert_eq!(1, collect_signatures(&read_envelope(&out).unwrap().transaction).unwrap().len());
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn sign_works_for_no_active_did() {
+            let ctx = setup_with_wallet();
+            let file = create_envelope("sign_works_for_no_active_did");
+            {
+                let cmd = sign_command::new();
+                let mut params = CommandParams::new();
+                params.insert("file", file);
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet(&ctx);
+        }
+    }
+
+    mod merge {
+        use super::*;
+
+        #[test]
+        pub fn merge_works() {
+            let ctx = setup_with_wallet();
+            let file = create_envelope("merge_works");
+            let file_1 = envelope_path("merge_works_1");
+            let file_2 = envelope_path("merge_works_2");
+            let merged = envelope_path("merge_works_merged");
+            sign_envelope(&ctx, SEED_TRUSTEE, DID_TRUSTEE, &file, Some(&file_1));
+            sign_envelope(&ctx, SEED_MY1, DID_MY1, &file, Some(&file_2));
+            {
+                let cmd = merge_command::new();
+                let mut params = CommandParams::new();
+                params.insert("file", merged.clone());
+                params.insert("files", format!("{},{}", file_1, file_2));
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            let signatures = collect_signatures(&read_envelope(&merged).unwrap().transaction).unwrap();
+            assert_eq!(vec![DID_MY1, DID_TRUSTEE], signatures.keys().map(String::as_str).collect::<Vec<&str>>());
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn merge_works_for_different_transactions() {
+            let ctx = setup();
+            let file_1 = create_envelope("merge_works_for_different_transactions_1");
+            let file_2 = envelope_path("merge_works_for_different_transactions_2");
+            write_envelope(&file_2, &TransactionEnvelope {
+                version: ENVELOPE_VERSION,
+                description: None,
+                transaction: json!({"reqId": 2, "identifier": DID_TRUSTEE, "operation": {"type": "1", "dest": DID_MY3}}),
+            }).unwrap();
+            {
+                let cmd = merge_command::new();
+                let mut params = CommandParams::new();
+                params.insert("file", envelope_path("merge_works_for_different_transactions"));
+                params.insert("files", format!("{},{}", file_1, file_2));
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down();
+        }
+
+        #[test]
+        pub fn merge_works_for_conflicting_signatures() {
+            let mut transaction_1 = serde_json::from_str::<JSONValue>(TRANSACTION).unwrap();
+            let mut transaction_2 = transaction_1.clone();
+            transaction_1["signatures"] = json!({DID_MY1: "signature1"});
+            transaction_2["signatures"] = json!({DID_MY1: "signature2"});
+
+            let envelopes = vec![
+                TransactionEnvelope { version: ENVELOPE_VERSION, description: None, transaction: transaction_1 },
+                TransactionEnvelope { version: ENVELOPE_VERSION, description: None, transaction: transaction_2 },
+            ];
+            merge_envelopes(&["file1", "file2"], envelopes).unwrap_err();
+        }
+
+        #[test]
+        pub fn merge_works_for_single_signed_and_multi_signed() {
+            let mut transaction_1 = serde_json::from_str::<JSONValue>(TRANSACTION).unwrap();
+            let mut transaction_2 = transaction_1.clone();
+            transaction_1["signature"] = json!("signature1");
+            transaction_2["signatures"] = json!({DID_MY1: "signature2"});
+
+            let envelopes = vec![
+                TransactionEnvelope { version: ENVELOPE_VERSION, description: None, transaction: transaction_1 },
+                TransactionEnvelope { version: ENVELOPE_VERSION, description: None, transaction: transaction_2 },
+            ];
+            let envelope = merge_envelopes(&["file1", "file2"], envelopes).unwrap();
+
+            assert!(envelope.transaction["signature"].is_null());
+            assert_eq!(json!({DID_MY1: "signature2", "V4SGRU86Z58d6TV7PBUe6f": "signature1"}), envelope.transaction["signatures"]);
+        }
+    }
+
+    mod submit {
+        use super::*;
+
+        #[test]
+        pub fn submit_works_for_no_connected_pool() {
+            let ctx = setup();
+            let file = create_envelope("submit_works_for_no_connected_pool");
+            {
+                let cmd = submit_command::new();
+                let mut params = CommandParams::new();
+                params.insert("file", file);
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down();
+        }
+    }
+
+    mod signatures {
+        use super::*;
+
+        #[test]
+        pub fn signature_input_works() {
+            let transaction = json!({
+                "reqId": 1,
+                "identifier": DID_TRUSTEE,
+                "operation": {"type": "100", "dest": DID_MY1, "raw": "{\"endpoint\":\"127.0.0.1\"}"},
+                "signature": "signature",
+                "signatures": {DID_MY1: "signature"},
+                "taaAcceptance": {"mechanism": "click", "time": 123, "accepted": true}
+            });
+
+            assert_eq!(format!("identifier:{}|operation:dest:{}|raw:{}|type:100|reqId:1|taaAcceptance:accepted:True|mechanism:click|time:123",
+                               DID_TRUSTEE, DID_MY1, "ba660a1862f6599867a633b8eaa36e090a10d81280bea8ba3390c9c948c7242f"),
+                       signature_input(&transaction));
+        }
+
+        #[test]
+        pub fn verify_signature_works() {
+            let ctx = setup_with_wallet();
+            let file = create_envelope("verify_signature_works");
+            sign_envelope(&ctx, SEED_TRUSTEE, DID_TRUSTEE, &file, None);
+
+            let transaction = read_envelope(&file).unwrap().transaction;
+            let signature = collect_signatures(&transaction).unwrap().remove(DID_TRUSTEE).unwrap();
+
+            assert!(verify_signature(VERKEY_TRUSTEE, &signature_input(&transaction), &signature));
+            assert!(!verify_signature(VERKEY_MY1, &signature_input(&transaction), &signature));
+            assert!(!verify_signature(VERKEY_TRUSTEE, &signature_input(&transaction), "garbage"));
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn verify_signature_works_for_tampered_signature() {
+            let ctx = setup_with_wallet();
+            let file = create_envelope("verify_signature_works_for_tampered_signature");
+            sign_envelope(&ctx, SEED_TRUSTEE, DID_TRUSTEE, &file, None);
+
+            let mut transaction = read_envelope(&file).unwrap().transaction;
+            let signature = collect_signatures(&transaction).unwrap().remove(DID_TRUSTEE).unwrap();
+
+            // signature of other transaction
+            transaction["operation"]["role"] = json!("0");
+            assert!(!verify_signature(VERKEY_TRUSTEE, &signature_input(&transaction), &signature));
+
+            // changed signature
+            let mut tampered = signature.from_base58().unwrap();
+            tampered[0] ^= 1;
+            transaction["operation"]["role"] = json!("2");
+            assert!(!verify_signature(VERKEY_TRUSTEE, &signature_input(&transaction), &tampered.to_base58()));
+
+            tear_down_with_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn signer_verkey_works() {
+            let operation = json!({"type": "1", "dest": DID_MY1, "verkey": VERKEY_MY1});
+
+            assert_eq!(Some(VERKEY_TRUSTEE.to_string()), signer_verkey(DID_TRUSTEE, &json!({"verkey": VERKEY_TRUSTEE}), &operation));
+            assert_eq!(Some(VERKEY_MY1.to_string()), signer_verkey(DID_MY1, &JSONValue::Null, &operation));
+            assert_eq!(Some(VERKEY_TRUSTEE.to_string()), signer_verkey(DID_TRUSTEE, &json!({"verkey": "~CoRER63DVYnWZtK8uAzNbx"}), &operation));
+        }
+    }
+
+    mod auth_rules {
+        use super::*;
+
+        fn rule(field: &str, old_value: Option<&str>, new_value: Option<&str>) -> AuthRuleData {
+            AuthRuleData {
+                auth_type: "1".to_string(),
+                auth_action: "ADD".to_string(),
+                field: field.to_string(),
+                old_value: old_value.map(String::from),
+                new_value: new_value.map(String::from),
+                constraint: JSONValue::Null,
+            }
+        }
+
+        fn signer(did: &str, role: Option<&str>, on_ledger: bool) -> Signer {
+            Signer { did: did.to_string(), role: role.map(String::from), on_ledger, verified: true }
+        }
+
+        const NO_FILTER: RuleFilter<'static> = RuleFilter { action: "ADD", field: None, old_value: None, new_value: None };
+
+        #[test]
+        pub fn rule_matches_works() {
+            let operation = json!({"type": "1", "dest": DID_MY1, "role": "2"});
+
+            assert!(rule_matches(&rule("role", None, Some("2")), &operation, &NO_FILTER));
+            assert!(rule_matches(&rule("role", None, Some("*")), &operation, &NO_FILTER));
+            assert!(!rule_matches(&rule("role", None, Some("0")), &operation, &NO_FILTER));
+            assert!(!rule_matches(&rule("role", None, Some("2")), &operation,
+                                  &RuleFilter { action: "EDIT", field: None, old_value: None, new_value: None }));
+            assert!(rule_matches(&rule("role", None, Some("0")), &operation,
+                                 &RuleFilter { action: "ADD", field: Some("role"), old_value: None, new_value: Some("0") }));
+        }
+
+        #[test]
+        pub fn rule_matches_works_for_missed_field() {
+            let operation = json!({"type": "1", "dest": DID_MY1});
+
+            assert!(rule_matches(&rule("role", None, Some("")), &operation, &NO_FILTER));
+            assert!(!rule_matches(&rule("role", None, Some("2")), &operation, &NO_FILTER));
+        }
+
+        #[test]
+        pub fn evaluate_constraint_works_for_role() {
+            let constraint = json!({"constraint_id": "ROLE", "role": "0", "sig_count": 2, "need_to_be_owner": false});
+
+            assert!(!evaluate_constraint(&constraint, &[signer(DID_TRUSTEE, Some("0"), true)]));
+            assert!(evaluate_constraint(&constraint, &[signer(DID_TRUSTEE, Some("0"), true), signer(DID_MY1, Some("0"), true)]));
+            assert!(!evaluate_constraint(&constraint, &[signer(DID_TRUSTEE, Some("0"), true), signer(DID_MY1, Some("2"), true)]));
+        }
+
+        #[test]
+        pub fn evaluate_constraint_works_for_not_verified_signature() {
+            let constraint = json!({"constraint_id": "ROLE", "role": "0", "sig_count": 1});
+            let mut trustee = signer(DID_TRUSTEE, Some("0"), true);
+            trustee.verified = false;
+
+            assert!(!evaluate_constraint(&constraint, &[trustee]));
+        }
+
+        #[test]
+        pub fn evaluate_constraint_works_for_any_role() {
+            let constraint = json!({"constraint_id": "ROLE", "role": "*", "sig_count": 1});
+            assert!(evaluate_constraint(&constraint, &[signer(DID_MY1, None, true)]));
+            assert!(!evaluate_constraint(&constraint, &[signer(DID_MY1, None, false)]));
+
+            let constraint = json!({"constraint_id": "ROLE", "role": "*", "sig_count": 1, "off_ledger_signature": true});
+            assert!(evaluate_constraint(&constraint, &[signer(DID_MY1, None, false)]));
+        }
+
+        #[test]
+        pub fn evaluate_constraint_works_for_combinations() {
+            let trustee = json!({"constraint_id": "ROLE", "role": "0", "sig_count": 1});
+            let steward = json!({"constraint_id": "ROLE", "role": "2", "sig_count": 1});
+            let signers = [signer(DID_TRUSTEE, Some("0"), true)];
+
+            assert!(evaluate_constraint(&json!({"constraint_id": "OR", "auth_constraints": [trustee, steward]}), &signers));
+            assert!(!evaluate_constraint(&json!({"constraint_id": "AND", "auth_constraints": [trustee, steward]}), &signers));
+            assert!(!evaluate_constraint(&json!({"constraint_id": "FORBIDDEN"}), &signers));
+        }
+
+        #[test]
+        pub fn constraint_status_works() {
+            assert_eq!(None, constraint_status(&[]));
+            assert_eq!(Some(true), constraint_status(&[(rule("role", None, Some("2")), true)]));
+            assert_eq!(None, constraint_status(&[(rule("role", None, Some("2")), true), (rule("role", None, Some("*")), false)]));
+        }
+    }
+
+    fn envelope_path(name: &str) -> String {
+        EnvironmentUtils::tmp_file_path(&format!("{}.txn", name)).to_str().unwrap().to_string()
+    }
+
+    fn create_envelope(name: &str) -> String {
+        let file = envelope_path(name);
+        write_envelope(&file, &TransactionEnvelope {
+            version: ENVELOPE_VERSION,
+            description: None,
+            transaction: serde_json::from_str(TRANSACTION).unwrap(),
+        }).unwrap();
+        file
+    }
+
+    fn sign_envelope(ctx: &CommandContext, seed: &str, did: &str, file: &str, out: Option<&str>) {
+        new_did(&ctx, seed);
+        use_did(&ctx, did);
+        let cmd = sign_command::new();
+        let mut params = CommandParams::new();
+        params.insert("file", file.to_string());
+        if let Some(out) = out {
+            params.insert("out", out.to_string());
+        }
+        cmd.execute(&ctx, &params).unwrap();
+    }
+}
@@ -550,6 +550,99 @@ pub mod import_command {
     }
 }
 
+pub mod migrate_command {
+    use super::*;
+
+    command!(CommandMetadata::build("migrate", "Create new wallet, attach to Indy CLI and then copy all records of opened wallet into it.
+                                              Allows to move wallet content to another storage type.")
+                .add_main_param("name", "The name of new wallet")
+                .add_required_deferred_param("key", "Key or passphrase used for new wallet key derivation.
+                                               Look to key_derivation_method param for information about supported key derivation methods.")
+                .add_optional_param("key_derivation_method", "Algorithm to use for new wallet key derivation. One of:
+                                    argon2m - derive secured wallet key (used by default)
+                                    argon2i - derive secured wallet key (less secured but faster)
+                                    raw - raw key provided (skip derivation)")
+                .add_optional_param("storage_type", "Type of the new wallet storage.")
+                .add_optional_param("storage_config", "The list of key:value pairs defined by storage type.")
+                .add_optional_param("storage_credentials", "The list of key:value pairs defined by storage type.")
+                .add_example("wallet migrate wallet2 key")
+                .add_example(r#"wallet migrate wallet2 key storage_type=postgres_storage storage_config={"url":"localhost:5432"} storage_credentials={"account":"postgres","password":"pass"}"#)
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, secret!(params));
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let id = get_str_param("name", params).map_err(error_err!())?;
+        let key = get_str_param("key", params).map_err(error_err!())?;
+        let key_derivation_method = get_opt_str_param("key_derivation_method", params).map_err(error_err!())?;
+        let storage_type = get_opt_str_param("storage_type", params).map_err(error_err!())?.unwrap_or("default");
+        let storage_config = get_opt_object_param("storage_config", params).map_err(error_err!())?;
+        let storage_credentials = get_opt_object_param("storage_credentials", params).map_err(error_err!())?;
+
+        let config: String = json!({ "id": id, "storage_type": storage_type, "storage_config": storage_config }).to_string();
+
+        let credentials = build_credentials(
+            key,
+            &map_key_derivation_method(key_derivation_method)?.to_string(),
+            storage_credentials);
+
+        if _wallet_config_path(id).exists() {
+            println_err!("Wallet \"{}\" is already attached to CLI", id);
+            return Err(());
+        }
+
+        trace!("Wallet::migrate_wallet try: wallet_name {}, config {}", wallet_name, config);
+
+        let res = Wallet::migrate_wallet(wallet_handle,
+                                         config.as_str(),
+                                         credentials.as_str());
+
+        trace!("Wallet::migrate_wallet return: {:?}", res);
+
+        let res = match res {
+            Ok(result) => {
+                _store_wallet_config(id, &config)
+                    .map_err(|err| println_err!("Cannot store \"{}\" config file: {:?}", id, err))?;
+
+                let result: JSONValue = serde_json::from_str(&result)
+                    .map_err(|_| println_err!("Wrong data has been received"))?;
+
+                let records: Vec<JSONValue> = result["records"].as_object()
+                    .map(|records| records.iter()
+                        .map(|(type_, count)| json!({ "type": type_, "count": count }))
+                        .collect())
+                    .unwrap_or_default();
+
+                print_list_table(&records,
+                                 &[("type", "Record type"),
+                                     ("count", "Records")],
+                                 "There are no records");
+
+                println_succ!("Wallet \"{}\" has been migrated to the wallet \"{}\" ({} records)", wallet_name, id, result["total"]);
+                Ok(())
+            }
+            Err(err) => {
+                match err.error_code {
+                    ErrorCode::WalletAlreadyExistsError => {
+                        println_err!("Wallet \"{}\" already exists", id);
+                        Err(())
+                    },
+                    _ => {
+                        handle_indy_error(err, None, None, Some(id));
+                        Err(())
+                    }
+                }
+            }
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
 fn _wallets_path() -> PathBuf {
     let mut path = EnvironmentUtils::indy_home_path();
     path.push("wallets");
@@ -1412,6 +1505,111 @@ pub mod tests {
         }
     }
 
+    mod migrate {
+        use super::*;
+        use super::did::tests::{new_did, use_did, SEED_MY1, DID_MY1};
+
+        const MIGRATED_WALLET: &str = "migrated_wallet";
+
+        #[test]
+        pub fn migrate_works() {
+            let ctx = setup();
+
+            create_and_open_wallet(&ctx);
+            new_did(&ctx, SEED_MY1);
+            use_did(&ctx, DID_MY1);
+
+            {
+                let cmd = migrate_command::new();
+                let mut params = CommandParams::new();
+                params.insert("name", MIGRATED_WALLET.to_string());
+                params.insert("key", WALLET_KEY_RAW.to_string());
+                params.insert("key_derivation_method", "raw".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+
+            close_and_delete_wallet(&ctx);
+
+            // open migrated wallet
+            {
+                let cmd = open_command::new();
+                let mut params = CommandParams::new();
+                params.insert("name", MIGRATED_WALLET.to_string());
+                params.insert("key", WALLET_KEY_RAW.to_string());
+                params.insert("key_derivation_method", "raw".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+
+            use_did(&ctx, DID_MY1);
+
+            close_wallet(&ctx);
+            delete_migrated_wallet();
+            tear_down();
+        }
+
+        #[test]
+        pub fn migrate_works_for_not_opened() {
+            let ctx = setup();
+            {
+                let cmd = migrate_command::new();
+                let mut params = CommandParams::new();
+                params.insert("name", MIGRATED_WALLET.to_string());
+                params.insert("key", WALLET_KEY_RAW.to_string());
+                params.insert("key_derivation_method", "raw".to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down();
+        }
+
+        #[test]
+        pub fn migrate_works_for_duplicate_name() {
+            let ctx = setup();
+
+            create_and_open_wallet(&ctx);
+            {
+                let cmd = migrate_command::new();
+                let mut params = CommandParams::new();
+                params.insert("name", WALLET.to_string());
+                params.insert("key", WALLET_KEY_RAW.to_string());
+                params.insert("key_derivation_method", "raw".to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+
+            close_and_delete_wallet(&ctx);
+            tear_down();
+        }
+
+        #[test]
+        pub fn migrate_works_for_unknown_storage_type() {
+            let ctx = setup();
+
+            create_and_open_wallet(&ctx);
+            {
+                let cmd = migrate_command::new();
+                let mut params = CommandParams::new();
+                params.insert("name", MIGRATED_WALLET.to_string());
+                params.insert("key", WALLET_KEY_RAW.to_string());
+                params.insert("key_derivation_method", "raw".to_string());
+                params.insert("storage_type", "unknown_storage".to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+
+            assert!(!_wallet_config_path(MIGRATED_WALLET).exists());
+
+            close_and_delete_wallet(&ctx);
+            tear_down();
+        }
+
+        fn delete_migrated_wallet() {
+            let cmd = delete_command::new();
+            let mut params = CommandParams::new();
+            params.insert("name", MIGRATED_WALLET.to_string());
+            params.insert("key", WALLET_KEY_RAW.to_string());
+            params.insert("key_derivation_method", "raw".to_string());
+            cmd.execute(&CommandContext::new(), &params).unwrap();
+        }
+    }
+
     pub fn create_wallet(ctx: &CommandContext) {
         let create_cmd = create_command::new();
         let mut params = CommandParams::new();
@@ -0,0 +1,87 @@
+use indy::IndyError;
+use indy::anoncreds;
+use indy::blob_storage;
+use indy::future::Future;
+use indy::WalletHandle;
+
+pub struct Issuer {}
+
+impl Issuer {
+    pub fn create_schema(issuer_did: &str, name: &str, version: &str, attrs: &str) -> Result<(String, String), IndyError> {
+        anoncreds::issuer_create_schema(issuer_did, name, version, attrs).wait()
+    }
+
+    pub fn create_and_store_credential_def(wallet_handle: WalletHandle, issuer_did: &str, schema_json: &str, tag: &str, signature_type: Option<&str>, config_json: &str) -> Result<(String, String), IndyError> {
+        anoncreds::issuer_create_and_store_credential_def(wallet_handle, issuer_did, schema_json, tag, signature_type, config_json).wait()
+    }
+
+    pub fn create_and_store_revoc_reg(wallet_handle: WalletHandle, issuer_did: &str, revoc_def_type: Option<&str>, tag: &str, cred_def_id: &str, config_json: &str, tails_dir: &str) -> Result<(String, String, String), IndyError> {
+        let tails_writer_handle = blob_storage::open_writer("default", &Issuer::_tails_config(tails_dir)).wait()?;
+        anoncreds::issuer_create_and_store_revoc_reg(wallet_handle, issuer_did, revoc_def_type, tag, cred_def_id, config_json, tails_writer_handle).wait()
+    }
+
+    pub fn create_credential_offer(wallet_handle: WalletHandle, cred_def_id: &str) -> Result<String, IndyError> {
+        anoncreds::issuer_create_credential_offer(wallet_handle, cred_def_id).wait()
+    }
+
+    pub fn create_credential(wallet_handle: WalletHandle, cred_offer_json: &str, cred_req_json: &str, cred_values_json: &str, rev_reg_id: Option<&str>, tails_dir: Option<&str>) -> Result<(String, Option<String>, Option<String>), IndyError> {
+        let blob_storage_reader_handle = match tails_dir {
+            Some(tails_dir) => blob_storage::open_reader("default", &Issuer::_tails_config(tails_dir)).wait()?,
+            None => -1
+        };
+        anoncreds::issuer_create_credential(wallet_handle, cred_offer_json, cred_req_json, cred_values_json, rev_reg_id, blob_storage_reader_handle).wait()
+    }
+
+    pub fn revoke_credential(wallet_handle: WalletHandle, tails_dir: &str, rev_reg_id: &str, cred_revoc_id: &str) -> Result<String, IndyError> {
+        let blob_storage_reader_handle = blob_storage::open_reader("default", &Issuer::_tails_config(tails_dir)).wait()?;
+        anoncreds::issuer_revoke_credential(wallet_handle, blob_storage_reader_handle, rev_reg_id, cred_revoc_id).wait()
+    }
+
+    fn _tails_config(tails_dir: &str) -> String {
+        json!({"base_dir": tails_dir, "uri_pattern": ""}).to_string()
+    }
+}
+
+pub struct Prover {}
+
+impl Prover {
+    pub fn create_master_secret(wallet_handle: WalletHandle, master_secret_id: Option<&str>) -> Result<String, IndyError> {
+        anoncreds::prover_create_master_secret(wallet_handle, master_secret_id).wait()
+    }
+
+    pub fn list_master_secrets(wallet_handle: WalletHandle) -> Result<String, IndyError> {
+        anoncreds::prover_list_master_secrets(wallet_handle).wait()
+    }
+
+    pub fn create_credential_req(wallet_handle: WalletHandle, prover_did: &str, cred_offer_json: &str, cred_def_json: &str, master_secret_id: &str) -> Result<(String, String), IndyError> {
+        anoncreds::prover_create_credential_req(wallet_handle, prover_did, cred_offer_json, cred_def_json, master_secret_id).wait()
+    }
+
+    pub fn store_credential(wallet_handle: WalletHandle, cred_id: Option<&str>, cred_req_metadata_json: &str, cred_json: &str, cred_def_json: &str, rev_reg_def_json: Option<&str>) -> Result<String, IndyError> {
+        anoncreds::prover_store_credential(wallet_handle, cred_id, cred_req_metadata_json, cred_json, cred_def_json, rev_reg_def_json).wait()
+    }
+
+    pub fn get_credentials(wallet_handle: WalletHandle, filter_json: Option<&str>) -> Result<String, IndyError> {
+        anoncreds::prover_get_credentials(wallet_handle, filter_json).wait()
+    }
+
+    pub fn create_proof(wallet_handle: WalletHandle, proof_req_json: &str, requested_credentials_json: &str, master_secret_id: Option<&str>, schemas_json: &str, credential_defs_json: &str, rev_states_json: &str) -> Result<String, IndyError> {
+        anoncreds::prover_create_proof_with_options(wallet_handle, proof_req_json, requested_credentials_json, master_secret_id, schemas_json, credential_defs_json, rev_states_json, "{}").wait()
+    }
+}
+
+pub struct Verifier {}
+
+impl Verifier {
+    pub fn verify_proof(proof_request_json: &str, proof_json: &str, schemas_json: &str, credential_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str) -> Result<bool, IndyError> {
+        anoncreds::verifier_verify_proof(proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json).wait()
+    }
+}
+
+pub struct Anoncreds {}
+
+impl Anoncreds {
+    pub fn encode_credential_values(values_json: &str) -> Result<String, IndyError> {
+        anoncreds::encode_credential_values(values_json).wait()
+    }
+}
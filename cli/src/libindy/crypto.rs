@@ -0,0 +1,44 @@
+use indy::crypto;
+use indy::IndyError;
+use indy::future::Future;
+use indy::WalletHandle;
+
+pub struct Crypto {}
+
+impl Crypto {
+    pub fn create_key(wallet_handle: WalletHandle, key_json: Option<&str>) -> Result<String, IndyError> {
+        crypto::create_key(wallet_handle, key_json).wait()
+    }
+
+    pub fn set_key_metadata(wallet_handle: WalletHandle, verkey: &str, metadata: &str) -> Result<(), IndyError> {
+        crypto::set_key_metadata(wallet_handle, verkey, metadata).wait()
+    }
+
+    pub fn get_key_metadata(wallet_handle: WalletHandle, verkey: &str) -> Result<String, IndyError> {
+        crypto::get_key_metadata(wallet_handle, verkey).wait()
+    }
+
+    pub fn sign(wallet_handle: WalletHandle, signer_vk: &str, message: &[u8]) -> Result<Vec<u8>, IndyError> {
+        crypto::sign(wallet_handle, signer_vk, message).wait()
+    }
+
+    pub fn verify(signer_vk: &str, message: &[u8], signature: &[u8]) -> Result<bool, IndyError> {
+        crypto::verify(signer_vk, message, signature).wait()
+    }
+
+    pub fn anon_crypt(recipient_vk: &str, message: &[u8]) -> Result<Vec<u8>, IndyError> {
+        crypto::anon_crypt(recipient_vk, message).wait()
+    }
+
+    pub fn anon_decrypt(wallet_handle: WalletHandle, recipient_vk: &str, encrypted_message: &[u8]) -> Result<Vec<u8>, IndyError> {
+        crypto::anon_decrypt(wallet_handle, recipient_vk, encrypted_message).wait()
+    }
+
+    pub fn pack_message(wallet_handle: WalletHandle, message: &[u8], receiver_keys: &str, sender: Option<&str>) -> Result<Vec<u8>, IndyError> {
+        crypto::pack_message(wallet_handle, message, receiver_keys, sender).wait()
+    }
+
+    pub fn unpack_message(wallet_handle: WalletHandle, jwe: &[u8]) -> Result<Vec<u8>, IndyError> {
+        crypto::unpack_message(wallet_handle, jwe).wait()
+    }
+}
@@ -1,7 +1,7 @@
 use indy::did;
 use indy::IndyError;
 use indy::future::Future;
-use indy::WalletHandle;
+use indy::{WalletHandle, PoolHandle};
 
 pub struct Did {}
 
@@ -30,6 +30,22 @@ impl Did {
         did::list_my_dids_with_metadata(wallet_handle).wait()
     }
 
+    pub fn store_their_did(wallet_handle: WalletHandle, identity_json: &str) -> Result<(), IndyError> {
+        did::store_their_did(wallet_handle, identity_json).wait()
+    }
+
+    pub fn set_endpoint(wallet_handle: WalletHandle, did: &str, address: &str, transport_key: &str) -> Result<(), IndyError> {
+        did::set_endpoint_for_did(wallet_handle, did, address, transport_key).wait()
+    }
+
+    pub fn get_endpoint(wallet_handle: WalletHandle, pool_handle: PoolHandle, did: &str) -> Result<(String, Option<String>), IndyError> {
+        did::get_endpoint_for_did(wallet_handle, pool_handle, did).wait()
+    }
+
+    pub fn key_for_local_did(wallet_handle: WalletHandle, did: &str) -> Result<String, IndyError> {
+        did::key_for_local_did(wallet_handle, did).wait()
+    }
+
     pub fn abbreviate_verkey(did: &str, verkey: &str) -> Result<String, IndyError> {
         did::abbreviate_verkey(did, verkey).wait()
     }
@@ -35,6 +35,10 @@ impl Ledger {
         ledger::build_get_nym_request(submitter_did, target_did).wait()
     }
 
+    pub fn build_get_txn_request(submitter_did: Option<&str>, ledger_type: Option<&str>, seq_no: i32) -> Result<String, IndyError> {
+        ledger::build_get_txn_request(submitter_did, ledger_type, seq_no).wait()
+    }
+
     pub fn build_attrib_request(submitter_did: &str, target_did: &str, hash: Option<&str>, raw: Option<&str>, enc: Option<&str>) -> Result<String, IndyError> {
         ledger::build_attrib_request(submitter_did, target_did, hash, raw, enc).wait()
     }
@@ -51,6 +55,10 @@ impl Ledger {
         ledger::build_get_schema_request(submitter_did, id).wait()
     }
 
+    pub fn parse_get_schema_response(get_schema_response: &str) -> Result<(String, String), IndyError> {
+        ledger::parse_get_schema_response(get_schema_response).wait()
+    }
+
     pub fn build_cred_def_request(submitter_did: &str, data: &str) -> Result<String, IndyError> {
         ledger::build_cred_def_request(submitter_did, data).wait()
     }
@@ -63,6 +71,10 @@ impl Ledger {
         ledger::build_get_cred_def_request(submitter_did, id).wait()
     }
 
+    pub fn parse_get_cred_def_response(get_cred_def_response: &str) -> Result<(String, String), IndyError> {
+        ledger::parse_get_cred_def_response(get_cred_def_response).wait()
+    }
+
     pub fn build_node_request(submitter_did: &str, target_did: &str, data: &str) -> Result<String, IndyError> {
         ledger::build_node_request(submitter_did, target_did, data).wait()
     }
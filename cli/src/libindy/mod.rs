@@ -3,3 +3,6 @@ pub mod pool;
 pub mod wallet;
 pub mod ledger;
 pub mod payment;
+pub mod anoncreds;
+pub mod crypto;
+pub mod pairwise;
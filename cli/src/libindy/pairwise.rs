@@ -0,0 +1,24 @@
+use indy::pairwise;
+use indy::IndyError;
+use indy::future::Future;
+use indy::WalletHandle;
+
+pub struct Pairwise {}
+
+impl Pairwise {
+    pub fn create(wallet_handle: WalletHandle, their_did: &str, my_did: &str, metadata: Option<&str>) -> Result<(), IndyError> {
+        pairwise::create_pairwise(wallet_handle, their_did, my_did, metadata).wait()
+    }
+
+    pub fn list(wallet_handle: WalletHandle) -> Result<String, IndyError> {
+        pairwise::list_pairwise(wallet_handle).wait()
+    }
+
+    pub fn get(wallet_handle: WalletHandle, their_did: &str) -> Result<String, IndyError> {
+        pairwise::get_pairwise(wallet_handle, their_did).wait()
+    }
+
+    pub fn set_metadata(wallet_handle: WalletHandle, their_did: &str, metadata: Option<&str>) -> Result<(), IndyError> {
+        pairwise::set_pairwise_metadata(wallet_handle, their_did, metadata).wait()
+    }
+}
@@ -1,7 +1,7 @@
 use indy::IndyError;
 use indy::wallet;
 use indy::future::Future;
-use indy::WalletHandle;
+use indy::{WalletHandle, SearchHandle};
 
 pub struct Wallet {}
 
@@ -29,4 +29,48 @@ impl Wallet {
     pub fn import_wallet(config: &str, credentials: &str, import_config_json: &str) -> Result<(), IndyError> {
         wallet::import_wallet(config, credentials, import_config_json).wait()
     }
-}
\ No newline at end of file
+
+    pub fn migrate_wallet(wallet_handle: WalletHandle, config: &str, credentials: &str) -> Result<String, IndyError> {
+        wallet::migrate_wallet(wallet_handle, config, credentials).wait()
+    }
+
+    pub fn add_record(wallet_handle: WalletHandle, type_: &str, id: &str, value: &str, tags_json: Option<&str>) -> Result<(), IndyError> {
+        wallet::add_wallet_record(wallet_handle, type_, id, value, tags_json).wait()
+    }
+
+    pub fn get_record(wallet_handle: WalletHandle, type_: &str, id: &str, options_json: &str) -> Result<String, IndyError> {
+        wallet::get_wallet_record(wallet_handle, type_, id, options_json).wait()
+    }
+
+    pub fn update_record_value(wallet_handle: WalletHandle, type_: &str, id: &str, value: &str) -> Result<(), IndyError> {
+        wallet::update_wallet_record_value(wallet_handle, type_, id, value).wait()
+    }
+
+    pub fn update_record_tags(wallet_handle: WalletHandle, type_: &str, id: &str, tags_json: &str) -> Result<(), IndyError> {
+        wallet::update_wallet_record_tags(wallet_handle, type_, id, tags_json).wait()
+    }
+
+    pub fn add_record_tags(wallet_handle: WalletHandle, type_: &str, id: &str, tags_json: &str) -> Result<(), IndyError> {
+        wallet::add_wallet_record_tags(wallet_handle, type_, id, tags_json).wait()
+    }
+
+    pub fn delete_record_tags(wallet_handle: WalletHandle, type_: &str, id: &str, tag_names_json: &str) -> Result<(), IndyError> {
+        wallet::delete_wallet_record_tags(wallet_handle, type_, id, tag_names_json).wait()
+    }
+
+    pub fn delete_record(wallet_handle: WalletHandle, type_: &str, id: &str) -> Result<(), IndyError> {
+        wallet::delete_wallet_record(wallet_handle, type_, id).wait()
+    }
+
+    pub fn open_search(wallet_handle: WalletHandle, type_: &str, query_json: &str, options_json: &str) -> Result<SearchHandle, IndyError> {
+        wallet::open_wallet_search(wallet_handle, type_, query_json, options_json).wait()
+    }
+
+    pub fn fetch_search_next_records(wallet_handle: WalletHandle, search_handle: SearchHandle, count: usize) -> Result<String, IndyError> {
+        wallet::fetch_wallet_search_next_records(wallet_handle, search_handle, count).wait()
+    }
+
+    pub fn close_search(search_handle: SearchHandle) -> Result<(), IndyError> {
+        wallet::close_wallet_search(search_handle).wait()
+    }
+}
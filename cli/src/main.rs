@@ -24,11 +24,14 @@ mod command_executor;
 #[macro_use]
 mod commands;
 mod libindy;
+mod script;
 
 use crate::command_executor::CommandExecutor;
 
-use crate::commands::{common, did, ledger, pool, wallet, payment_address};
+use crate::commands::{common, did, ledger, pool, wallet, payment_address, anoncreds, record, crypto, pairwise, key, txn, taa};
 use crate::utils::history;
+use crate::utils::output::{self, OutputFormat};
+use crate::script::Script;
 
 use linefeed::{Reader, ReadResult, Terminal, Signal};
 use linefeed::complete::{Completer, Completion};
@@ -36,7 +39,9 @@ use linefeed::complete::{Completer, Completion};
 use std::env;
 use std::fs::File;
 use std::io::BufReader;
+use std::path::Path;
 use std::rc::Rc;
+use std::str::FromStr;
 
 fn main() {
     #[cfg(target_os = "windows")]
@@ -66,12 +71,23 @@ fn main() {
                     Err(err) => return println_err!("{}", err)
                 }
             }
+            "--output" => {
+                let format = unwrap_or_return!(args.next(), println_err!("Output format is not specified"));
+                match OutputFormat::from_str(&format) {
+                    Ok(format) => output::set_output_format(format),
+                    Err(err) => return println_err!("{}", err)
+                }
+            }
             "--plugins" => {
                 let plugins = unwrap_or_return!(args.next(), println_err!("Plugins are not specified"));
                 _load_plugins(&command_executor, &plugins)
             }
             _ if args.len() == 0 => {
-                execute_batch(&command_executor, Some(&arg));
+                let res = execute_batch(&command_executor, Some(&arg));
+
+                if res.is_err() && output::is_json() {
+                    _exit_with_failure(command_executor);
+                }
 
                 if command_executor.ctx().is_exit() {
                     return;
@@ -86,6 +102,12 @@ fn main() {
     execute_stdin(command_executor);
 }
 
+fn _exit_with_failure(command_executor: CommandExecutor) -> ! {
+    // Executor has to be dropped before exit to close opened wallets and pools
+    drop(command_executor);
+    std::process::exit(1);
+}
+
 #[derive(Deserialize, Debug)]
 #[serde(deny_unknown_fields)]
 #[serde(rename_all = "camelCase")]
@@ -125,6 +147,7 @@ fn build_executor() -> CommandExecutor {
         .add_command(common::exit_command::new())
         .add_command(common::prompt_command::new())
         .add_command(common::show_command::new())
+        .add_command(common::output_format_command::new())
         .add_command(common::load_plugin_command::new())
         .add_command(common::init_logger_command::new())
         .add_group(did::group::new())
@@ -134,6 +157,8 @@ fn build_executor() -> CommandExecutor {
         .add_command(did::rotate_key_command::new())
         .add_command(did::list_command::new())
         .add_command(did::qualify_command::new())
+        .add_command(did::store_their_command::new())
+        .add_command(did::endpoint_command::new())
         .finalize_group()
         .add_group(pool::group::new())
         .add_command(pool::create_command::new())
@@ -142,6 +167,7 @@ fn build_executor() -> CommandExecutor {
         .add_command(pool::list_command::new())
         .add_command(pool::disconnect_command::new())
         .add_command(pool::delete_command::new())
+        .add_command(pool::diagnose_command::new())
         .add_command(pool::show_taa_command::new())
         .add_command(pool::set_protocol_version_command::new())
         .finalize_group()
@@ -155,6 +181,7 @@ fn build_executor() -> CommandExecutor {
         .add_command(wallet::detach_command::new())
         .add_command(wallet::export_command::new())
         .add_command(wallet::import_command::new())
+        .add_command(wallet::migrate_command::new())
         .finalize_group()
         .add_group(ledger::group::new())
         .add_command(ledger::nym_command::new())
@@ -190,6 +217,9 @@ fn build_executor() -> CommandExecutor {
         .add_command(ledger::taa_disable_all_command::new())
         .add_command(ledger::ledgers_freeze_command::new())
         .add_command(ledger::get_frozen_ledgers_command::new())
+        .add_command(ledger::txn_range_command::new())
+        .add_command(ledger::search_command::new())
+        .add_command(ledger::export_command::new())
         .finalize_group()
         .add_group(payment_address::group::new())
         .add_command(payment_address::new_command::new())
@@ -198,13 +228,66 @@ fn build_executor() -> CommandExecutor {
         .add_command(payment_address::sign_command::new())
         .add_command(payment_address::verify_command::new())
         .finalize_group()
+        .add_group(anoncreds::group::new())
+        .add_command(anoncreds::schema_create_command::new())
+        .add_command(anoncreds::cred_def_create_command::new())
+        .add_command(anoncreds::rev_reg_create_command::new())
+        .add_command(anoncreds::offer_command::new())
+        .add_command(anoncreds::request_command::new())
+        .add_command(anoncreds::issue_command::new())
+        .add_command(anoncreds::store_command::new())
+        .add_command(anoncreds::revoke_command::new())
+        .add_command(anoncreds::proof_create_command::new())
+        .add_command(anoncreds::proof_verify_command::new())
+        .add_command(anoncreds::credentials_list_command::new())
+        .finalize_group()
+        .add_group(record::group::new())
+        .add_command(record::add_command::new())
+        .add_command(record::get_command::new())
+        .add_command(record::update_command::new())
+        .add_command(record::delete_command::new())
+        .add_command(record::search_command::new())
+        .finalize_group()
+        .add_group(crypto::group::new())
+        .add_command(crypto::sign_command::new())
+        .add_command(crypto::verify_command::new())
+        .add_command(crypto::pack_command::new())
+        .add_command(crypto::unpack_command::new())
+        .add_command(crypto::anon_crypt_command::new())
+        .add_command(crypto::anon_decrypt_command::new())
+        .finalize_group()
+        .add_group(pairwise::group::new())
+        .add_command(pairwise::create_command::new())
+        .add_command(pairwise::list_command::new())
+        .add_command(pairwise::get_command::new())
+        .add_command(pairwise::set_metadata_command::new())
+        .finalize_group()
+        .add_group(key::group::new())
+        .add_command(key::new_command::new())
+        .add_command(key::set_metadata_command::new())
+        .add_command(key::get_metadata_command::new())
+        .finalize_group()
+        .add_group(txn::group::new())
+        .add_command(txn::create_command::new())
+        .add_command(txn::inspect_command::new())
+        .add_command(txn::sign_command::new())
+        .add_command(txn::merge_command::new())
+        .add_command(txn::submit_command::new())
+        .finalize_group()
+        .add_group(taa::group::new())
+        .add_command(taa::status_command::new())
+        .finalize_group()
         .finalize()
 }
 
 fn execute_stdin(command_executor: CommandExecutor) {
     match Reader::new("indy-cli") {
         Ok(reader) => execute_interactive(command_executor, reader),
-        Err(_) => execute_batch(&command_executor, None),
+        Err(_) => {
+            if execute_batch(&command_executor, None).is_err() && output::is_json() {
+                _exit_with_failure(command_executor);
+            }
+        }
     }
 }
 
@@ -241,19 +324,30 @@ fn execute_interactive<T>(command_executor: CommandExecutor, mut reader: Reader<
     }
 }
 
-fn execute_batch(command_executor: &CommandExecutor, script_path: Option<&str>) {
+fn execute_batch(command_executor: &CommandExecutor, script_path: Option<&str>) -> Result<(), ()> {
+    let file = match script_path.map(File::open) {
+        Some(Ok(file)) => Some(file),
+        Some(Err(err)) => {
+            println_err!("Can't open script file {}\nError: {}", script_path.unwrap_or_default(), err);
+            return Err(());
+        }
+        None => None
+    };
+
     command_executor.ctx().set_batch_mode();
-    if let Some(script_path) = script_path {
-        let file = match File::open(script_path) {
-            Ok(file) => file,
-            Err(err) => return println_err!("Can't open script file {}\nError: {}", script_path, err),
-        };
-        _iter_batch(command_executor, BufReader::new(file));
+    output::set_capture(true);
+
+    let mut script = Script::new(script_path.map(Path::new));
+    let res = if let Some(file) = file {
+        script.execute(command_executor, BufReader::new(file))
     } else {
         let stdin = std::io::stdin();
-        _iter_batch(command_executor, stdin.lock());
+        script.execute(command_executor, stdin.lock())
     };
+
+    output::set_capture(false);
     command_executor.ctx().set_not_batch_mode();
+    res
 }
 
 fn _load_plugins(command_executor: &CommandExecutor, plugins_str: &str) {
@@ -276,6 +370,12 @@ fn _print_help() {
     println!();
     println_acc!("\tBatch - all commands will be read from text file or pipe and executed in series.");
     println_acc!("\tUsage: indy-cli <path-to-text-file>");
+    println_acc!("\tBesides commands a script can contain statements:");
+    println_acc!("\t\tset <name>=<value> - define a variable. Use it in the following lines as $name or ${{name}}.");
+    println_acc!("\t\t${{last.<field>}} - a field of the result of the previous command, e.g. ${{last.did}}, ${{last.seqNo}} or ${{last.error_code}}.");
+    println_acc!("\t\tif <condition> ... else ... end - execute lines conditionally, e.g. if ${{last.error_code}} == 0.");
+    println_acc!("\t\tassert <condition> - stop execution if the condition does not hold.");
+    println_acc!("\t\tinclude <path> [<arg> ...] [<name>=<value> ...] - execute another script. Arguments are available there as $1, $2, ... and variables.");
     println!();
     println_acc!("Options:");
     println_acc!("\tLoad plugins in Libindy.");
@@ -290,36 +390,11 @@ fn _print_help() {
     println_acc!("\t\ttaaAcceptanceMechanism - transaction author agreement acceptance mechanism to use for sending write transactions to the Ledger.");
     println_acc!("\tUsage: indy-cli --config <path-to-config-json-file>");
     println!();
-}
-
-fn _iter_batch<T>(command_executor: &CommandExecutor, reader: T) where T: std::io::BufRead {
-    let mut line_num = 1;
-    for line in reader.lines() {
-        let line = if let Ok(line) = line { line } else {
-            return println_err!("Can't parse line #{}", line_num);
-        };
-
-        if line.starts_with('#') || line.is_empty() {
-            // Skip blank lines and lines starting with #
-            continue;
-        }
-
-        println!("{}", line);
-        let (line, force) = if line.starts_with('-') {
-            (line[1..].as_ref(), true)
-        } else {
-            (line[0..].as_ref(), false)
-        };
-        if command_executor.execute(line).is_err() && !force {
-            return println_err!("Batch execution failed at line #{}", line_num);
-        }
-        println!();
-        line_num += 1;
-
-        if command_executor.ctx().is_exit() {
-            break;
-        }
-    }
+    println_acc!("\tSet format of commands output: \"text\" (default) or \"json\".");
+    println_acc!("\tIn \"json\" format every command prints one JSON object per line with the status, error code, messages and data.");
+    println_acc!("\tBatch execution stops with non-zero exit code on the first failed command.");
+    println_acc!("\tUsage: indy-cli --output json");
+    println!();
 }
 
 impl<Term: Terminal> Completer<Term> for CommandExecutor {
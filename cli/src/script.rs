@@ -0,0 +1,485 @@
+//! Interpreter of batch scripts.
+//!
+//! Besides CLI commands a script can contain the following statements:
+//! * `set <name>=<value>` - defines a script variable.
+//! * `if <condition>`, `else`, `end` - executes lines conditionally.
+//! * `assert <condition>` - stops the script if the condition does not hold.
+//! * `include <path> [<arg> ...] [<name>=<value> ...]` - executes another script. Positional arguments are
+//!   available there as `$1`, `$2`, ... and named arguments as variables.
+//!
+//! `$name` and `${name}` are replaced by the value of the variable in all lines. `$name` of undefined variable
+//! is kept as is, so `${name}` is reported as an error instead. `${last.<path>}` is replaced by the field of
+//! the result of the previous command: `status`, `error_code`, `error`, `data` or any field of its data.
+//! `$$` is replaced by `$`.
+
+use crate::command_executor::CommandExecutor;
+use crate::utils::output;
+
+use serde_json::Value as JSONValue;
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+const MAX_INCLUDE_DEPTH: usize = 16;
+const LAST_RESULT: &str = "last";
+
+struct Block {
+    parent_active: bool,
+    condition: bool,
+    is_else: bool,
+}
+
+impl Block {
+    fn is_active(&self) -> bool {
+        self.parent_active && self.condition != self.is_else
+    }
+}
+
+pub struct Script {
+    variables: HashMap<String, String>,
+    dir: PathBuf,
+    depth: usize,
+}
+
+impl Script {
+    /// Creates script executed from the file at `path` or from stdin if there is no path.
+    pub fn new(path: Option<&Path>) -> Script {
+        Script {
+            variables: HashMap::new(),
+            dir: Script::_dir(path),
+            depth: 0,
+        }
+    }
+
+    pub fn execute<T>(&mut self, command_executor: &CommandExecutor, reader: T) -> Result<(), ()> where T: BufRead {
+        let mut blocks: Vec<Block> = Vec::new();
+
+        for (line_num, line) in reader.lines().enumerate() {
+            let line_num = line_num + 1;
+
+            let line = if let Ok(line) = line { line } else {
+                println_err!("Can't parse line #{}", line_num);
+                return Err(());
+            };
+
+            let line = line.trim();
+
+            if line.starts_with('#') || line.is_empty() {
+                // Skip blank lines and lines starting with #
+                continue;
+            }
+
+            let is_active = blocks.last().map(Block::is_active).unwrap_or(true);
+            let (statement, rest) = split_first_word(line);
+
+            let res = match statement {
+                "if" => {
+                    let condition = if is_active { self._condition(rest) } else { Ok(false) };
+                    condition.map(|condition| blocks.push(Block { parent_active: is_active, condition, is_else: false }))
+                }
+                "else" => match blocks.last_mut() {
+                    Some(ref mut block) if !block.is_else => {
+                        block.is_else = true;
+                        Ok(())
+                    }
+                    _ => {
+                        println_err!("\"else\" without \"if\"");
+                        Err(())
+                    }
+                },
+                "end" => match blocks.pop() {
+                    Some(_) => Ok(()),
+                    None => {
+                        println_err!("\"end\" without \"if\"");
+                        Err(())
+                    }
+                },
+                _ if !is_active => continue,
+                _ => {
+                    println_msg!("{}", line);
+
+                    let (line, force) = if line.starts_with('-') {
+                        (line[1..].as_ref(), true)
+                    } else {
+                        (line[0..].as_ref(), false)
+                    };
+
+                    let res = self._execute_line(command_executor, line);
+                    println_msg!();
+
+                    if force { Ok(()) } else { res }
+                }
+            };
+
+            if res.is_err() {
+                println_err!("Batch execution failed at line #{}", line_num);
+                return Err(());
+            }
+
+            if command_executor.ctx().is_exit() {
+                return Ok(());
+            }
+        }
+
+        if !blocks.is_empty() {
+            println_err!("Batch execution failed: \"if\" without \"end\"");
+            return Err(());
+        }
+
+        Ok(())
+    }
+
+    fn _execute_line(&mut self, command_executor: &CommandExecutor, line: &str) -> Result<(), ()> {
+        let (statement, rest) = split_first_word(line);
+
+        match statement {
+            "set" => self._set(rest),
+            "assert" => self._assert(rest),
+            "include" => self._include(command_executor, rest),
+            _ => {
+                let line = self.substitute(line)
+                    .map_err(|err| println_err!("{}", err))?;
+                command_executor.execute(&line)
+            }
+        }
+    }
+
+    fn _set(&mut self, statement: &str) -> Result<(), ()> {
+        let (name, value) = parse_variable(statement)
+            .map_err(|err| println_err!("{}", err))?;
+
+        let value = self.substitute(value)
+            .map_err(|err| println_err!("{}", err))?;
+
+        self.variables.insert(name.to_string(), value);
+        Ok(())
+    }
+
+    fn _assert(&self, condition: &str) -> Result<(), ()> {
+        if self._condition(condition)? {
+            Ok(())
+        } else {
+            println_err!("Assertion \"{}\" failed", condition);
+            Err(())
+        }
+    }
+
+    fn _include(&self, command_executor: &CommandExecutor, statement: &str) -> Result<(), ()> {
+        if self.depth >= MAX_INCLUDE_DEPTH {
+            println_err!("Scripts can not be included deeper than {} levels", MAX_INCLUDE_DEPTH);
+            return Err(());
+        }
+
+        let args = statement.split_whitespace()
+            .map(|arg| self.substitute(arg))
+            .collect::<Result<Vec<String>, String>>()
+            .map_err(|err| println_err!("{}", err))?;
+
+        let (path, args) = match args.split_first() {
+            Some((path, args)) => (self.dir.join(path), args),
+            None => {
+                println_err!("Script to include is not specified");
+                return Err(());
+            }
+        };
+
+        let mut script = Script {
+            variables: self.variables.clone(),
+            dir: Script::_dir(Some(&path)),
+            depth: self.depth + 1,
+        };
+
+        let mut position = 0;
+        for arg in args {
+            if arg.contains('=') {
+                let (name, value) = parse_variable(arg)
+                    .map_err(|err| println_err!("{}", err))?;
+                script.variables.insert(name.to_string(), value.to_string());
+            } else {
+                position += 1;
+                script.variables.insert(position.to_string(), arg.to_string());
+            }
+        }
+
+        let file = File::open(&path)
+            .map_err(|err| println_err!("Can't open script file {}\nError: {}", path.to_string_lossy(), err))?;
+
+        script.execute(command_executor, BufReader::new(file))
+    }
+
+    /// Evaluates `<operand>`, `<operand> == <operand>` or `<operand> != <operand>`.
+    /// A single operand holds if it is not empty, `0` or `false`.
+    fn _condition(&self, condition: &str) -> Result<bool, ()> {
+        let operands = condition.split_whitespace()
+            .map(|operand| self.substitute(operand))
+            .collect::<Result<Vec<String>, String>>()
+            .map_err(|err| println_err!("{}", err))?;
+
+        match operands.iter().map(String::as_str).collect::<Vec<&str>>().as_slice() {
+            [value] => Ok(!["", "0", "false"].contains(value)),
+            [left, "==", right] => Ok(left == right),
+            [left, "!=", right] => Ok(left != right),
+            _ => {
+                println_err!("Invalid condition \"{}\"", condition);
+                Err(())
+            }
+        }
+    }
+
+    /// Replaces variables and fields of the last result in the line by their values.
+    pub fn substitute(&self, line: &str) -> Result<String, String> {
+        let mut res = String::with_capacity(line.len());
+        let mut chars = line.chars().peekable();
+
+        while let Some(ch) = chars.next() {
+            if ch != '$' {
+                res.push(ch);
+                continue;
+            }
+
+            let name = match chars.peek() {
+                Some('$') => {
+                    chars.next();
+                    res.push('$');
+                    continue;
+                }
+                Some('{') => {
+                    chars.next();
+                    let mut name = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(ch) => name.push(ch),
+                            None => return Err(format!("Variable \"{}\" is not closed by \"}}\"", name))
+                        }
+                    }
+                    name
+                }
+                Some(ch) if is_name_char(*ch) => {
+                    let mut name = String::new();
+                    while let Some(ch) = chars.peek().cloned().filter(|ch| is_name_char(*ch)) {
+                        name.push(ch);
+                        chars.next();
+                    }
+
+                    // Keeps WQL operators like $or and $like and other literal text as is
+                    if !self.variables.contains_key(&name) {
+                        res.push('$');
+                        res.push_str(&name);
+                        continue;
+                    }
+
+                    name
+                }
+                _ => {
+                    res.push('$');
+                    continue;
+                }
+            };
+
+            res.push_str(&self.value(&name)?);
+        }
+
+        Ok(res)
+    }
+
+    fn value(&self, name: &str) -> Result<String, String> {
+        if let Some(value) = self.variables.get(name) {
+            return Ok(value.to_string());
+        }
+
+        let mut path = name.split('.');
+
+        if path.next() != Some(LAST_RESULT) {
+            return Err(format!("Variable \"{}\" is not defined", name));
+        }
+
+        let last = output::last_result()
+            .ok_or_else(|| "There is no result of the previous command".to_string())?;
+
+        let path = path.collect::<Vec<&str>>();
+
+        // Fields of the result itself go first, then fields of the data printed by the command
+        let value = std::iter::once(&last)
+            .chain(last["data"].as_array().map(|data| data.iter()).into_iter().flatten())
+            .filter_map(|value| lookup(value, &path))
+            .next()
+            .ok_or_else(|| format!("Field \"{}\" is not found in the result of the previous command", name))?;
+
+        Ok(match value {
+            JSONValue::String(value) => value.to_string(),
+            JSONValue::Null => String::new(),
+            value => value.to_string()
+        })
+    }
+
+    fn _dir(path: Option<&Path>) -> PathBuf {
+        path.and_then(Path::parent)
+            .map(Path::to_path_buf)
+            .unwrap_or_default()
+    }
+}
+
+fn lookup<'a>(value: &'a JSONValue, path: &[&str]) -> Option<&'a JSONValue> {
+    path.iter().fold(Some(value), |value, key| {
+        value.and_then(|value| match value {
+            JSONValue::Array(array) => key.parse::<usize>().ok().and_then(|index| array.get(index)),
+            value => value.get(key)
+        })
+    })
+}
+
+fn parse_variable(statement: &str) -> Result<(&str, &str), String> {
+    let mut parts = statement.splitn(2, '=');
+
+    match (parts.next().map(str::trim), parts.next()) {
+        (Some(name), Some(value)) if !name.is_empty() && name != LAST_RESULT && name.chars().all(is_name_char) =>
+            Ok((name, value.trim())),
+        _ => Err(format!("Invalid variable definition \"{}\". Use <name>=<value>", statement))
+    }
+}
+
+fn is_name_char(ch: char) -> bool {
+    ch.is_ascii_alphanumeric() || ch == '_'
+}
+
+fn split_first_word(line: &str) -> (&str, &str) {
+    match line.find(char::is_whitespace) {
+        Some(pos) => (&line[..pos], line[pos..].trim_start()),
+        None => (line, "")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command_executor::{Command, CommandContext, CommandMetadata, CommandParams};
+    use crate::utils::environment::EnvironmentUtils;
+    use crate::utils::file::write_file;
+
+    pub mod echo_command {
+        use super::*;
+
+        command!(CommandMetadata::build("echo", "Echo command help")
+                    .add_main_param("value", "Value help")
+                    .finalize());
+
+        fn execute(_ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+            output::data(json!({"value": params.get("value")}));
+            Ok(())
+        }
+    }
+
+    pub mod fail_command {
+        use super::*;
+
+        command!(CommandMetadata::build("fail", "Fail command help").finalize());
+
+        fn execute(_ctx: &CommandContext, _params: &CommandParams) -> Result<(), ()> {
+            output::set_error_code(309);
+            Err(())
+        }
+    }
+
+    fn executor() -> CommandExecutor {
+        output::set_capture(true);
+        CommandExecutor::build()
+            .add_command(echo_command::new())
+            .add_command(fail_command::new())
+            .finalize()
+    }
+
+    fn execute(script: &mut Script, lines: &str) -> Result<(), ()> {
+        script.execute(&executor(), lines.as_bytes())
+    }
+
+    #[test]
+    pub fn substitute_works() {
+        let mut script = Script::new(None);
+        script.variables.insert("name".to_string(), "value".to_string());
+
+        assert_eq!("value value-1 ${name} $ text", script.substitute("$name ${name}-1 $${name} $ text").unwrap());
+        assert_eq!("$unknown", script.substitute("$unknown").unwrap());
+        assert!(script.substitute("${unknown}").is_err());
+        assert!(script.substitute("${name").is_err());
+    }
+
+    #[test]
+    pub fn substitute_works_for_wql() {
+        let mut script = Script::new(None);
+        script.variables.insert("name".to_string(), "value".to_string());
+
+        let line = r#"wallet search type=type query={"$or": [{"name": "$name"}, {"~age": {"$gt": "20"}}, {"tag": {"$like": "%a$b%"}}]}"#;
+        assert_eq!(r#"wallet search type=type query={"$or": [{"name": "value"}, {"~age": {"$gt": "20"}}, {"tag": {"$like": "%a$b%"}}]}"#,
+                   script.substitute(line).unwrap());
+    }
+
+    #[test]
+    pub fn execute_works_for_variables() {
+        let mut script = Script::new(None);
+        execute(&mut script, r#"
+            set name=abc
+            echo $name
+            set value=${last.value}
+            set status=${last.status}
+            set code=${last.error_code}
+            assert $value == abc
+        "#).unwrap();
+
+        assert_eq!("abc", script.variables["value"]);
+        assert_eq!("success", script.variables["status"]);
+        assert_eq!("0", script.variables["code"]);
+    }
+
+    #[test]
+    pub fn execute_works_for_conditions() {
+        let mut script = Script::new(None);
+        execute(&mut script, r#"
+            -fail
+            if ${last.error_code} == 309
+                set branch=if
+                if ${last.status} != error
+                    set nested=if
+                else
+                    set nested=else
+                end
+            else
+                set branch=else
+            end
+        "#).unwrap();
+
+        assert_eq!("if", script.variables["branch"]);
+        assert_eq!("else", script.variables["nested"]);
+    }
+
+    #[test]
+    pub fn execute_works_for_failed_assert() {
+        execute(&mut Script::new(None), "echo 1\nassert ${last.value} == 2").unwrap_err();
+    }
+
+    #[test]
+    pub fn execute_works_for_failed_command() {
+        execute(&mut Script::new(None), "fail\necho 1").unwrap_err();
+    }
+
+    #[test]
+    pub fn execute_works_for_invalid_blocks() {
+        execute(&mut Script::new(None), "if 1\necho 1").unwrap_err();
+        execute(&mut Script::new(None), "echo 1\nend").unwrap_err();
+        execute(&mut Script::new(None), "else").unwrap_err();
+        execute(&mut Script::new(None), "if 1 2").unwrap_err();
+    }
+
+    #[test]
+    pub fn execute_works_for_include() {
+        let path = EnvironmentUtils::tmp_file_path("script_include.txt");
+        write_file(&path, "assert $1 == first\nassert $name == abc\nassert $outer == outer\necho included").unwrap();
+
+        let mut script = Script::new(Some(&EnvironmentUtils::tmp_file_path("script.txt")));
+        execute(&mut script, "set outer=outer\ninclude script_include.txt first name=abc\nassert ${last.value} == included").unwrap();
+
+        execute(&mut Script::new(None), "include unknown_script.txt").unwrap_err();
+    }
+}
@@ -20,6 +20,19 @@ pub fn read_file<P: AsRef<Path>>(file: P) -> Result<String, String> {
     Ok(content)
 }
 
+pub fn read_file_bytes<P: AsRef<Path>>(file: P) -> Result<Vec<u8>, String> {
+    let mut file = File::open(file)
+        .map_err(error_err!())
+        .map_err(|_| "Can't read the file".to_string())?;
+
+    let mut content = Vec::new();
+    file.read_to_end(&mut content)
+        .map_err(error_err!())
+        .map_err(|err| format!("Can't read the file: {}", err))?;
+
+    Ok(content)
+}
+
 pub fn read_lines_from_file<P: AsRef<Path>>(file: P) -> Result<impl Iterator<Item=Result<String, ::std::io::Error>>, String> {
     let file = File::open(file)
         .map_err(error_err!())
@@ -53,4 +66,28 @@ pub fn write_file<P: AsRef<Path>>(file: P, content: &str) -> Result<(), String>
 
     file.flush()
         .map_err(|err| format!("Can't write content: \"{}\" to the file: {}", content, err))
-}
\ No newline at end of file
+}
+
+pub fn write_file_bytes<P: AsRef<Path>>(file: P, content: &[u8]) -> Result<(), String> where P: std::convert::AsRef<std::ffi::OsStr> {
+    let path = PathBuf::from(&file);
+
+    if let Some(parent_path) = path.parent() {
+        DirBuilder::new()
+            .recursive(true)
+            .create(parent_path)
+            .map_err(error_err!())
+            .map_err(|err| format!("Can't create the file: {}", err))?;
+    }
+
+    let mut file = OpenOptions::new()
+        .write(true)
+        .truncate(true)
+        .create(true)
+        .open(path)
+        .map_err(error_err!())
+        .map_err(|err| format!("Can't open the file: {}", err))?;
+
+    file.write_all(content)
+        .and_then(|_| file.flush())
+        .map_err(|err| format!("Can't write content to the file: {}", err))
+}
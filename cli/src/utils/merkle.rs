@@ -0,0 +1,117 @@
+//! Verification of merkle audit paths returned by the ledger for GET_TXN requests.
+//!
+//! Ledger transactions are hashed as in RFC 6962: a leaf is `sha256(0x00 || msgpack(txn))`
+//! with keys of all objects sorted, an inner node is `sha256(0x01 || left || right)`.
+
+use rust_base58::FromBase58;
+use serde_json::Value as JSONValue;
+use sha2::{Digest, Sha256};
+
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+/// Checks that the ledger transaction with `seq_no` is included into the tree of `ledger_size`
+/// transactions with `root_hash`. Hashes of the audit path and root hash are base58 encoded.
+pub fn verify_audit_path(txn: &JSONValue, seq_no: u64, ledger_size: u64, audit_path: &[&str], root_hash: &str) -> Result<bool, String> {
+    if seq_no == 0 || seq_no > ledger_size {
+        return Err(format!("Transaction {} is out of the ledger of size {}", seq_no, ledger_size));
+    }
+
+    let audit_path = audit_path.iter()
+        .map(|hash| from_base58(hash))
+        .collect::<Result<Vec<Vec<u8>>, String>>()?;
+
+    let root_hash = from_base58(root_hash)?;
+
+    let txn = rmp_serde::to_vec(txn)
+        .map_err(|err| format!("Cannot serialize transaction to MessagePack: {}", err))?;
+
+    let leaf_hash = hash_leaf(&txn);
+
+    Ok(root_from_audit_path(leaf_hash, seq_no - 1, ledger_size, &audit_path)
+        .map(|root| root[..] == root_hash[..])
+        .unwrap_or(false))
+}
+
+fn root_from_audit_path(leaf_hash: [u8; 32], index: u64, size: u64, audit_path: &[Vec<u8>]) -> Option<[u8; 32]> {
+    let mut fn_ = index;
+    let mut sn = size - 1;
+    let mut hash = leaf_hash;
+
+    for node in audit_path {
+        if sn == 0 {
+            return None;
+        }
+
+        if fn_ & 1 == 1 || fn_ == sn {
+            hash = hash_children(node, &hash);
+            while fn_ & 1 == 0 && fn_ != 0 {
+                fn_ >>= 1;
+                sn >>= 1;
+            }
+        } else {
+            hash = hash_children(&hash, node);
+        }
+
+        fn_ >>= 1;
+        sn >>= 1;
+    }
+
+    if sn == 0 { Some(hash) } else { None }
+}
+
+fn hash_leaf(data: &[u8]) -> [u8; 32] {
+    let mut buf = Vec::with_capacity(data.len() + 1);
+    buf.push(LEAF_PREFIX);
+    buf.extend_from_slice(data);
+    sha256(&buf)
+}
+
+fn hash_children(left: &[u8], right: &[u8]) -> [u8; 32] {
+    let mut buf = Vec::with_capacity(left.len() + right.len() + 1);
+    buf.push(NODE_PREFIX);
+    buf.extend_from_slice(left);
+    buf.extend_from_slice(right);
+    sha256(&buf)
+}
+
+fn sha256(data: &[u8]) -> [u8; 32] {
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(&Sha256::digest(data));
+    hash
+}
+
+fn from_base58(value: &str) -> Result<Vec<u8>, String> {
+    value.from_base58()
+        .map_err(|err| format!("Invalid base58 string {}: {:?}", value, err))
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    use rust_base58::ToBase58;
+
+    #[test]
+    pub fn verify_audit_path_works() {
+        let txns = (1..=5).map(|seq_no| json!({"txn": {"type": "1"}, "txnMetadata": {"seqNo": seq_no}})).collect::<Vec<JSONValue>>();
+        let leaves = txns.iter().map(|txn| hash_leaf(&rmp_serde::to_vec(txn).unwrap())).collect::<Vec<[u8; 32]>>();
+
+        // Tree of 5 leaves: root = h(h(h(0, 1), h(2, 3)), 4)
+        let h01 = hash_children(&leaves[0], &leaves[1]);
+        let h23 = hash_children(&leaves[2], &leaves[3]);
+        let h0123 = hash_children(&h01, &h23);
+        let root = hash_children(&h0123, &leaves[4])[..].to_base58();
+
+        let path_3 = [leaves[3][..].to_base58(), h01[..].to_base58(), leaves[4][..].to_base58()];
+        let path_3 = path_3.iter().map(String::as_str).collect::<Vec<&str>>();
+        assert!(verify_audit_path(&txns[2], 3, 5, &path_3, &root).unwrap());
+        assert!(!verify_audit_path(&txns[1], 3, 5, &path_3, &root).unwrap());
+
+        let path_5 = [h0123[..].to_base58()];
+        let path_5 = path_5.iter().map(String::as_str).collect::<Vec<&str>>();
+        assert!(verify_audit_path(&txns[4], 5, 5, &path_5, &root).unwrap());
+
+        assert!(verify_audit_path(&txns[4], 6, 5, &path_5, &root).is_err());
+    }
+}
@@ -4,10 +4,12 @@ pub mod environment;
 pub mod logger;
 #[macro_use]
 pub mod term;
+pub mod output;
 #[cfg(test)]
 pub mod test;
 pub mod table;
 pub mod file;
+pub mod merkle;
 pub mod history;
 
 #[macro_export] //TODO move to more relevant place
@@ -0,0 +1,274 @@
+use serde_json::Value as JSONValue;
+
+use std::cell::RefCell;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(format!("Unsupported output format \"{}\". Use \"text\" or \"json\"", s))
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct Message {
+    pub level: &'static str,
+    pub text: String,
+}
+
+/// Result of a single command invocation printed as one JSON line in JSON output mode.
+#[derive(Serialize, Debug)]
+pub struct CommandOutput {
+    pub command: String,
+    pub status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub messages: Vec<Message>,
+    pub data: Vec<JSONValue>,
+}
+
+impl CommandOutput {
+    fn new(command: &str) -> CommandOutput {
+        CommandOutput {
+            command: command.to_string(),
+            status: "success",
+            error_code: None,
+            error: None,
+            messages: Vec::new(),
+            data: Vec::new(),
+        }
+    }
+}
+
+thread_local! {
+    static FORMAT: RefCell<OutputFormat> = RefCell::new(OutputFormat::Text);
+    static CURRENT: RefCell<Option<CommandOutput>> = RefCell::new(None);
+    static CAPTURE: RefCell<bool> = RefCell::new(false);
+    static LAST: RefCell<Option<JSONValue>> = RefCell::new(None);
+}
+
+pub fn set_output_format(format: OutputFormat) {
+    FORMAT.with(|f| *f.borrow_mut() = format);
+}
+
+pub fn output_format() -> OutputFormat {
+    FORMAT.with(|f| *f.borrow())
+}
+
+pub fn is_json() -> bool {
+    output_format() == OutputFormat::Json
+}
+
+/// Enables keeping of the result of the last command regardless of output format. Used by batch scripts.
+pub fn set_capture(capture: bool) {
+    CAPTURE.with(|c| *c.borrow_mut() = capture);
+    if !capture {
+        LAST.with(|l| *l.borrow_mut() = None);
+    }
+}
+
+fn is_capture() -> bool {
+    CAPTURE.with(|c| *c.borrow())
+}
+
+/// Returns whether output of the current command is being collected.
+pub fn is_collecting() -> bool {
+    CURRENT.with(|c| c.borrow().is_some())
+}
+
+/// Returns the result of the last command: status, error code, error and data. Available only if capture is enabled.
+pub fn last_result() -> Option<JSONValue> {
+    LAST.with(|l| l.borrow().clone())
+}
+
+/// Starts collecting output of the command. Does nothing in text mode unless capture is enabled.
+pub fn begin(line: &str) {
+    if !is_json() && !is_capture() {
+        return;
+    }
+    CURRENT.with(|c| *c.borrow_mut() = Some(CommandOutput::new(line.trim())));
+}
+
+/// Prints collected output of the command as one JSON line in JSON mode and keeps it if capture is enabled.
+pub fn finish(res: &Result<(), ()>) {
+    if let Some(output) = take(res) {
+        if is_json() {
+            println!("{}", serde_json::to_string(&output).unwrap_or_default());
+        }
+
+        if is_capture() {
+            let mut result = serde_json::to_value(&output).unwrap_or_default();
+            result["error_code"] = match output.error_code {
+                Some(code) => json!(code),
+                None if res.is_ok() => json!(0),
+                None => JSONValue::Null
+            };
+            LAST.with(|l| *l.borrow_mut() = Some(result));
+        }
+    }
+}
+
+fn take(res: &Result<(), ()>) -> Option<CommandOutput> {
+    CURRENT.with(|c| c.borrow_mut().take())
+        .map(|mut output| {
+            if res.is_err() {
+                output.status = "error";
+            }
+            output
+        })
+}
+
+pub fn message(level: &'static str, text: String) {
+    let text = text.trim().to_string();
+
+    if text.is_empty() {
+        return;
+    }
+
+    let handled = CURRENT.with(|c| {
+        match *c.borrow_mut() {
+            Some(ref mut output) => {
+                if level == "error" && output.error.is_none() {
+                    output.error = Some(text.clone());
+                }
+                output.messages.push(Message { level, text: text.clone() });
+                true
+            }
+            None => false
+        }
+    });
+
+    // Messages printed outside of commands (e.g. while processing CLI options) are reported only if they are errors
+    if !handled && level == "error" {
+        println!("{}", json!({"status": "error", "error": text}));
+    }
+}
+
+pub fn data(value: JSONValue) {
+    CURRENT.with(|c| {
+        if let Some(ref mut output) = *c.borrow_mut() {
+            output.data.push(value);
+        }
+    });
+}
+
+pub fn set_error_code(code: i32) {
+    CURRENT.with(|c| {
+        if let Some(ref mut output) = *c.borrow_mut() {
+            output.error_code.get_or_insert(code);
+        }
+    });
+}
+
+/// Prints JSON returned by Libindy: as is in text mode and as command data in JSON mode.
+pub fn print_json(json: &str, indent: &str) {
+    if is_collecting() {
+        data(serde_json::from_str(json).unwrap_or_else(|_| JSONValue::String(json.to_string())));
+    }
+    if !is_json() {
+        println!("{}{}", indent, json);
+    }
+}
+
+/// Prints string result of a command: as is in text mode and as command data in JSON mode.
+pub fn print_value(value: &str, indent: &str) {
+    data(JSONValue::String(value.to_string()));
+    if !is_json() {
+        println!("{}{}", indent, value);
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    fn run(res: Result<(), ()>, f: fn()) -> CommandOutput {
+        set_output_format(OutputFormat::Json);
+        begin("did new seed=XXX ");
+        f();
+        let output = take(&res).unwrap();
+        set_output_format(OutputFormat::Text);
+        output
+    }
+
+    #[test]
+    pub fn output_format_from_str_works() {
+        assert_eq!(OutputFormat::Json, OutputFormat::from_str("json").unwrap());
+        assert_eq!(OutputFormat::Text, OutputFormat::from_str("text").unwrap());
+        assert!(OutputFormat::from_str("xml").is_err());
+    }
+
+    #[test]
+    pub fn command_output_works() {
+        let output = run(Ok(()), || {
+            println_succ!("Did \"{}\" has been created", "did");
+            data(json!({"did": "did"}));
+        });
+
+        assert_eq!(json!({
+            "command": "did new seed=XXX",
+            "status": "success",
+            "messages": [{"level": "success", "text": "Did \"did\" has been created"}],
+            "data": [{"did": "did"}]
+        }), serde_json::to_value(&output).unwrap());
+    }
+
+    #[test]
+    pub fn command_output_works_for_error() {
+        let output = run(Err(()), || {
+            set_error_code(213);
+            println_err!("Wallet: \"{}\" not found", "wallet");
+            println_err!("Another error");
+        });
+
+        assert_eq!("error", output.status);
+        assert_eq!(Some(213), output.error_code);
+        assert_eq!(Some("Wallet: \"wallet\" not found".to_string()), output.error);
+        assert_eq!(2, output.messages.len());
+    }
+
+    #[test]
+    pub fn output_is_not_collected_in_text_mode() {
+        begin("about");
+        println_msg!("About");
+        assert!(take(&Ok(())).is_none());
+    }
+
+    #[test]
+    pub fn last_result_works_for_capture() {
+        set_capture(true);
+
+        begin("did new");
+        data(json!({"did": "did"}));
+        finish(&Ok(()));
+
+        let last = last_result().unwrap();
+        assert_eq!("success", last["status"]);
+        assert_eq!(0, last["error_code"]);
+        assert_eq!(json!([{"did": "did"}]), last["data"]);
+
+        begin("wallet open");
+        set_error_code(204);
+        finish(&Err(()));
+
+        let last = last_result().unwrap();
+        assert_eq!("error", last["status"]);
+        assert_eq!(204, last["error_code"]);
+
+        set_capture(false);
+        assert!(last_result().is_none());
+    }
+}
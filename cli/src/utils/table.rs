@@ -6,7 +6,15 @@ use prettytable::Table;
 use prettytable::row::Row;
 use prettytable::cell::Cell;
 
+use crate::utils::output;
+
 pub fn print_list_table(rows: &[serde_json::Value], headers: &[(&str, &str)], empty_msg: &str) {
+    output::data(serde_json::Value::Array(rows.to_vec()));
+
+    if output::is_json() {
+        return;
+    }
+
     if rows.is_empty() {
         return println_succ!("{}", empty_msg);
     }
@@ -23,6 +31,12 @@ pub fn print_list_table(rows: &[serde_json::Value], headers: &[(&str, &str)], em
 }
 
 pub fn print_table(row: &serde_json::Value, headers: &[(&str, &str)]) {
+    output::data(row.clone());
+
+    if output::is_json() {
+        return;
+    }
+
     let mut table = Table::new();
 
     print_header(&mut table, headers);
@@ -3,7 +3,9 @@ use atty;
 #[macro_export]
 macro_rules! println_err {
     ($($arg:tt)*) => (
-        if $crate::utils::term::is_term() {
+        if $crate::utils::output::is_json() {
+            $crate::utils::output::message("error", format!($($arg)*))
+        } else if $crate::utils::term::is_term() {
             println!("{}", $crate::ansi_term::Color::Red.bold().paint(format!($($arg)*)))
         } else {
             println!($($arg)*)
@@ -14,7 +16,9 @@ macro_rules! println_err {
 #[macro_export]
 macro_rules! println_succ {
     ($($arg:tt)*) => (
-        if $crate::utils::term::is_term() {
+        if $crate::utils::output::is_json() {
+            $crate::utils::output::message("success", format!($($arg)*))
+        } else if $crate::utils::term::is_term() {
             println!("{}", $crate::ansi_term::Color::Green.bold().paint(format!($($arg)*)))
         } else {
             println!($($arg)*)
@@ -24,7 +28,9 @@ macro_rules! println_succ {
 #[macro_export]
 macro_rules! println_warn {
     ($($arg:tt)*) => (
-        if $crate::utils::term::is_term() {
+        if $crate::utils::output::is_json() {
+            $crate::utils::output::message("warning", format!($($arg)*))
+        } else if $crate::utils::term::is_term() {
             println!("{}", $crate::ansi_term::Color::Blue.bold().paint(format!($($arg)*)))
         } else {
             println!($($arg)*)
@@ -35,7 +41,9 @@ macro_rules! println_warn {
 #[macro_export]
 macro_rules! println_acc {
     ($($arg:tt)*) => (
-       if $crate::utils::term::is_term() {
+       if $crate::utils::output::is_json() {
+           $crate::utils::output::message("info", format!($($arg)*))
+       } else if $crate::utils::term::is_term() {
            println!("{}", $crate::ansi_term::Style::new().bold().paint(format!($($arg)*)))
        } else {
            println!($($arg)*)
@@ -43,6 +51,22 @@ macro_rules! println_acc {
     )
 }
 
+#[macro_export]
+macro_rules! println_msg {
+    () => (
+        if !$crate::utils::output::is_json() {
+            println!()
+        }
+    );
+    ($($arg:tt)*) => (
+        if $crate::utils::output::is_json() {
+            $crate::utils::output::message("info", format!($($arg)*))
+        } else {
+            println!($($arg)*)
+        }
+    )
+}
+
 // TODO: move to more relevant place
 #[macro_export]
 macro_rules! map_println_err {
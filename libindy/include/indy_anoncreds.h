@@ -17,6 +17,18 @@ extern "C" {
                                                                        const char*   schema_json)
                                                   );
 
+    extern indy_error_t indy_issuer_create_typed_schema(indy_handle_t command_handle,
+                                                        const char *  issuer_did,
+                                                        const char *  name,
+                                                        const char *  version,
+                                                        const char *  attr_types,
+
+                                                        void           (*cb)(indy_handle_t command_handle_,
+                                                                             indy_error_t  err,
+                                                                             const char*   id,
+                                                                             const char*   schema_json)
+                                                        );
+
     extern indy_error_t indy_issuer_create_and_store_credential_def(indy_handle_t command_handle,
                                                                     indy_handle_t wallet_handle,
                                                                     const char *  issuer_did,
@@ -65,6 +77,26 @@ extern "C" {
                                                                                     const char*   revoc_reg_entry_json)
                                                                );
 
+    extern indy_error_t indy_issuer_create_and_store_revoc_reg_with_progress(indy_handle_t command_handle,
+                                                                             indy_handle_t wallet_handle,
+                                                                             const char *  issuer_did,
+                                                                             const char *  revoc_def_type,
+                                                                             const char *  tag,
+                                                                             const char *  cred_def_id,
+                                                                             const char *  config_json,
+                                                                             indy_handle_t tails_writer_handle,
+
+                                                                             void           (*progress_cb)(indy_handle_t command_handle_,
+                                                                                                           indy_u32_t    tails_written,
+                                                                                                           indy_u32_t    tails_total),
+
+                                                                             void           (*cb)(indy_handle_t command_handle_,
+                                                                                                  indy_error_t  err,
+                                                                                                  const char*   revoc_reg_id,
+                                                                                                  const char*   revoc_reg_def_json,
+                                                                                                  const char*   revoc_reg_entry_json)
+                                                                             );
+
     extern indy_error_t indy_issuer_create_credential_offer(indy_handle_t command_handle,
                                                             indy_handle_t wallet_handle,
                                                             const char *  cred_def_id,
@@ -100,6 +132,67 @@ extern "C" {
                                                                            const char*   revoc_reg_delta_json)
                                                       );
 
+    extern indy_error_t indy_issuer_create_credential_with_record(indy_handle_t command_handle,
+                                                                  indy_handle_t wallet_handle,
+                                                                  const char *  cred_offer_json,
+                                                                  const char *  cred_req_json,
+                                                                  const char *  cred_values_json,
+                                                                  const char *  rev_reg_id,
+                                                                  indy_handle_t blob_storage_reader_handle,
+                                                                  const char *  record_config_json,
+
+                                                                  void           (*cb)(indy_handle_t command_handle_,
+                                                                                       indy_error_t  err,
+                                                                                       const char*   cred_json,
+                                                                                       const char*   cred_revoc_id,
+                                                                                       const char*   revoc_reg_delta_json)
+                                                                  );
+
+    extern indy_error_t indy_issuer_revoke_credential_by_record(indy_handle_t command_handle,
+                                                                indy_handle_t wallet_handle,
+                                                                indy_handle_t blob_storage_reader_handle,
+                                                                const char *  record_id,
+
+                                                                void           (*cb)(indy_handle_t command_handle_,
+                                                                                     indy_error_t  err,
+                                                                                     const char*   revoc_reg_delta_json)
+                                                                );
+
+    extern indy_error_t indy_issuer_get_issuance_record(indy_handle_t command_handle,
+                                                        indy_handle_t wallet_handle,
+                                                        const char *  record_id,
+
+                                                        void           (*cb)(indy_handle_t command_handle_,
+                                                                             indy_error_t  err,
+                                                                             const char*   record_json)
+                                                        );
+
+    extern indy_error_t indy_issuer_search_issuance_records(indy_handle_t command_handle,
+                                                            indy_handle_t wallet_handle,
+                                                            const char *  query_json,
+
+                                                            void           (*cb)(indy_handle_t command_handle_,
+                                                                                 indy_error_t  err,
+                                                                                 indy_handle_t search_handle,
+                                                                                 indy_u32_t    total_count)
+                                                            );
+
+    extern indy_error_t indy_issuer_fetch_issuance_records(indy_handle_t command_handle,
+                                                           indy_handle_t search_handle,
+                                                           indy_u32_t    count,
+
+                                                           void           (*cb)(indy_handle_t command_handle_,
+                                                                                indy_error_t  err,
+                                                                                const char*   records_json)
+                                                           );
+
+    extern indy_error_t indy_issuer_close_issuance_records_search(indy_handle_t command_handle,
+                                                                  indy_handle_t search_handle,
+
+                                                                  void           (*cb)(indy_handle_t command_handle_,
+                                                                                       indy_error_t  err)
+                                                                  );
+
 /*    extern indy_error_t indy_issuer_recover_credential(indy_handle_t command_handle,
                                                        indy_handle_t wallet_handle,
                                                        indy_handle_t blob_storage_reader_handle,
@@ -129,6 +222,32 @@ extern "C" {
                                                                               indy_error_t  err,
                                                                               const char*   out_master_secret_id)
                                                          );
+
+    extern indy_error_t indy_prover_list_master_secrets(indy_handle_t command_handle,
+                                                        indy_handle_t wallet_handle,
+
+                                                        void           (*cb)(indy_handle_t command_handle_,
+                                                                             indy_error_t  err,
+                                                                             const char*   master_secrets_json)
+                                                        );
+
+    extern indy_error_t indy_prover_set_default_master_secret(indy_handle_t command_handle,
+                                                              indy_handle_t wallet_handle,
+                                                              const char *  master_secret_id,
+
+                                                              void           (*cb)(indy_handle_t command_handle_,
+                                                                                   indy_error_t  err)
+                                                              );
+
+    extern indy_error_t indy_prover_rotate_master_secret(indy_handle_t command_handle,
+                                                         indy_handle_t wallet_handle,
+                                                         const char *  master_secret_id,
+                                                         const char *  new_master_secret_id,
+
+                                                         void           (*cb)(indy_handle_t command_handle_,
+                                                                              indy_error_t  err,
+                                                                              const char*   rotation_json)
+                                                         );
     
     
     extern indy_error_t indy_prover_create_credential_req(indy_handle_t command_handle,
@@ -165,6 +284,26 @@ extern "C" {
                                                                           indy_error_t        err)
                                                       );
 
+    extern indy_error_t indy_prover_export_credential(indy_handle_t command_handle,
+                                                      indy_handle_t wallet_handle,
+                                                      const char *  cred_id,
+                                                      const char *  export_config_json,
+
+                                                      void          (*cb)(indy_handle_t command_handle_,
+                                                                          indy_error_t        err,
+                                                                          const char*         bundle_json)
+                                                      );
+
+    extern indy_error_t indy_prover_import_credential(indy_handle_t command_handle,
+                                                      indy_handle_t wallet_handle,
+                                                      const char *  bundle_json,
+                                                      const char *  import_config_json,
+
+                                                      void          (*cb)(indy_handle_t command_handle_,
+                                                                          indy_error_t        err,
+                                                                          const char*         out_cred_id)
+                                                      );
+
     extern indy_error_t indy_prover_get_credentials(indy_handle_t command_handle,
                                                     indy_handle_t wallet_handle,
                                                     const char *  filter_json,
@@ -183,6 +322,33 @@ extern "C" {
                                                                         const char*   credential_json)
                                                    );
 
+    extern indy_error_t indy_prover_get_credential_status(indy_handle_t command_handle,
+                                                          indy_handle_t wallet_handle,
+                                                          const char *  cred_id,
+
+                                                          void           (*cb)(indy_handle_t command_handle_,
+                                                                               indy_error_t  err,
+                                                                               const char*   status_json)
+                                                          );
+
+    extern indy_error_t indy_prover_set_credential_expiry_attr(indy_handle_t command_handle,
+                                                               indy_handle_t wallet_handle,
+                                                               const char *  cred_def_id,
+                                                               const char *  attr_name,
+
+                                                               void           (*cb)(indy_handle_t command_handle_,
+                                                                                    indy_error_t  err)
+                                                               );
+
+    extern indy_error_t indy_prover_set_credential_identity_attr(indy_handle_t command_handle,
+                                                                 indy_handle_t wallet_handle,
+                                                                 const char *  cred_def_id,
+                                                                 const char *  attr_name,
+
+                                                                 void           (*cb)(indy_handle_t command_handle_,
+                                                                                      indy_error_t  err)
+                                                                 );
+
     extern indy_error_t indy_prover_search_credentials(indy_handle_t command_handle,
                                                        indy_handle_t wallet_handle,
                                                        const char *  query_json,
@@ -246,6 +412,35 @@ extern "C" {
                                                                                                 indy_error_t  err)
                                                                            );
 
+    /// Explains why credentials stored in the wallet can or can not satisfy the given proof request.
+    ///
+    /// For every requested attribute and predicate referent reports stored credentials containing
+    /// the requested attribute(s) together with failed restrictions, predicates and non-revocation interval problems.
+    ///
+    /// #Params
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// proof_request_json: proof request json (same format as for indy_prover_search_credentials_for_proof_req)
+    /// extra_query_json:(Optional) List of extra queries that will be applied to correspondent attribute/predicate
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// diagnostics_json: json with explanation per referent (see indy_prover_explain_proof_req in libindy/src/api/anoncreds.rs)
+    ///
+    /// #Errors
+    /// Anoncreds*
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_prover_explain_proof_req(indy_handle_t command_handle,
+                                                      indy_handle_t wallet_handle,
+                                                      const char *  proof_request_json,
+                                                      const char *  extra_query_json,
+
+                                                      void           (*cb)(indy_handle_t command_handle_,
+                                                                           indy_error_t  err,
+                                                                           const char*   diagnostics_json)
+                                                      );
+
     extern indy_error_t indy_prover_create_proof(indy_handle_t command_handle,
                                                  indy_handle_t wallet_handle,
                                                  const char *  proof_req_json,
@@ -260,6 +455,21 @@ extern "C" {
                                                                       const char*   proof_json)
                                                  );
 
+    extern indy_error_t indy_prover_create_proof_with_options(indy_handle_t command_handle,
+                                                              indy_handle_t wallet_handle,
+                                                              const char *  proof_req_json,
+                                                              const char *  requested_credentials_json,
+                                                              const char *  master_secret_name,
+                                                              const char *  schemas_json,
+                                                              const char *  credential_defs_json,
+                                                              const char *  rev_states_json,
+                                                              const char *  options_json,
+
+                                                              void           (*cb)(indy_handle_t command_handle_,
+                                                                                   indy_error_t  err,
+                                                                                   const char*   proof_json)
+                                                              );
+
 
     extern indy_error_t indy_verifier_verify_proof(indy_handle_t command_handle,
                                                    const char *  proof_request_json,
@@ -275,6 +485,50 @@ extern "C" {
                                                    );
 
 
+    extern indy_error_t indy_verifier_verify_proof_with_options(indy_handle_t command_handle,
+                                                                const char *  proof_request_json,
+                                                                const char *  proof_json,
+                                                                const char *  schemas_json,
+                                                                const char *  credential_defs_jsons,
+                                                                const char *  rev_reg_defs_json,
+                                                                const char *  rev_regs_json,
+                                                                const char *  options_json,
+
+                                                                void           (*cb)(indy_handle_t command_handle_,
+                                                                                     indy_error_t  err,
+                                                                                     indy_bool_t   valid )
+                                                                );
+
+
+    extern indy_error_t indy_verifier_verify_proof_detailed(indy_handle_t command_handle,
+                                                            const char *  proof_request_json,
+                                                            const char *  proof_json,
+                                                            const char *  schemas_json,
+                                                            const char *  credential_defs_jsons,
+                                                            const char *  rev_reg_defs_json,
+                                                            const char *  rev_regs_json,
+                                                            const char *  options_json,
+
+                                                            void           (*cb)(indy_handle_t command_handle_,
+                                                                                 indy_error_t  err,
+                                                                                 const char*   report_json)
+                                                            );
+
+
+    extern indy_error_t indy_verifier_verify_w3c_presentation(indy_handle_t command_handle,
+                                                              const char *  proof_request_json,
+                                                              const char *  w3c_presentation_json,
+                                                              const char *  schemas_json,
+                                                              const char *  credential_defs_jsons,
+                                                              const char *  rev_reg_defs_json,
+                                                              const char *  rev_regs_json,
+
+                                                              void           (*cb)(indy_handle_t command_handle_,
+                                                                                   indy_error_t  err,
+                                                                                   indy_bool_t   valid)
+                                                              );
+
+
     extern indy_error_t indy_create_revocation_state(indy_handle_t command_handle,
                                                      indy_handle_t blob_storage_reader_handle,
                                                      const char *  rev_reg_def_json,
@@ -288,6 +542,20 @@ extern "C" {
                                                      );
 
 
+    extern indy_error_t indy_prover_create_credential_revocation_state(indy_handle_t command_handle,
+                                                                       indy_handle_t wallet_handle,
+                                                                       indy_handle_t blob_storage_reader_handle,
+                                                                       const char *  rev_reg_def_json,
+                                                                       const char *  rev_reg_delta_json,
+                                                                       indy_u64_t    timestamp,
+                                                                       const char *  cred_id,
+
+                                                                       void           (*cb)(indy_handle_t command_handle_,
+                                                                                            indy_error_t  err,
+                                                                                            const char*   rev_state_json)
+                                                                       );
+
+
     extern indy_error_t indy_update_revocation_state(indy_handle_t command_handle,
                                                      indy_handle_t blob_storage_reader_handle,
                                                      const char *  rev_state_json,
@@ -308,6 +576,13 @@ extern "C" {
                                                                  const char*   nonce)
                                             );
 
+    extern indy_error_t indy_verifier_build_proof_request(indy_handle_t command_handle,
+                                                          const char *  template_json,
+                                                          void           (*cb)(indy_handle_t command_handle_,
+                                                                               indy_error_t  err,
+                                                                               const char*   proof_request_json)
+                                                          );
+
     extern indy_error_t indy_to_unqualified(indy_handle_t command_handle,
                                             const char *  entity,
                                             void           (*cb)(indy_handle_t command_handle_,
@@ -315,6 +590,60 @@ extern "C" {
                                                                  const char*   res)
                                             );
 
+    extern indy_error_t indy_encode_credential_values(indy_handle_t command_handle,
+                                                      const char *  values_json,
+                                                      void           (*cb)(indy_handle_t command_handle_,
+                                                                           indy_error_t  err,
+                                                                           const char*   cred_values_json)
+                                                      );
+
+    extern indy_error_t indy_encode_typed_credential_values(indy_handle_t command_handle,
+                                                            const char *  values_json,
+                                                            const char *  schema_json,
+                                                            void           (*cb)(indy_handle_t command_handle_,
+                                                                                 indy_error_t  err,
+                                                                                 const char*   cred_values_json)
+                                                            );
+
+    extern indy_error_t indy_build_date_predicate(indy_handle_t command_handle,
+                                                  const char *  date_predicate_json,
+                                                  void           (*cb)(indy_handle_t command_handle_,
+                                                                       indy_error_t  err,
+                                                                       const char*   predicate_json)
+                                                  );
+
+    extern indy_error_t indy_credential_to_w3c(indy_handle_t command_handle,
+                                               const char *  cred_json,
+                                               const char *  issuance_date,
+                                               void           (*cb)(indy_handle_t command_handle_,
+                                                                    indy_error_t  err,
+                                                                    const char*   w3c_cred_json)
+                                               );
+
+    extern indy_error_t indy_credential_from_w3c(indy_handle_t command_handle,
+                                                 const char *  w3c_cred_json,
+                                                 const char *  schema_json,
+                                                 void           (*cb)(indy_handle_t command_handle_,
+                                                                      indy_error_t  err,
+                                                                      const char*   cred_json)
+                                                 );
+
+    extern indy_error_t indy_proof_to_w3c(indy_handle_t command_handle,
+                                          const char *  proof_request_json,
+                                          const char *  proof_json,
+                                          const char *  issuance_dates_json,
+                                          void           (*cb)(indy_handle_t command_handle_,
+                                                               indy_error_t  err,
+                                                               const char*   w3c_presentation_json)
+                                          );
+
+    extern indy_error_t indy_proof_from_w3c(indy_handle_t command_handle,
+                                            const char *  w3c_presentation_json,
+                                            void           (*cb)(indy_handle_t command_handle_,
+                                                                 indy_error_t  err,
+                                                                 const char*   proof_json)
+                                            );
+
 #ifdef __cplusplus
 }
 #endif
@@ -123,6 +123,223 @@ extern "C" {
                                                   void          (*cb)(indy_handle_t command_handle_,
                                                                       indy_error_t  err)
                                                  );
+
+    /// Gets NYM data for specified DID.
+    /// If data is present inside of cache, cached data is returned.
+    /// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// submitter_did: DID of the submitter stored in secured Wallet.
+    /// target_did: Target DID as base58-encoded string for 16 or 32 bit DID value.
+    /// options_json:
+    ///  {
+    ///    noCache: (bool, optional, false by default) Skip usage of cache,
+    ///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+    ///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+    ///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+    ///  }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// NYM data (see indy_parse_get_nym_response)
+    extern indy_error_t indy_get_nym(indy_handle_t command_handle,
+                                     indy_handle_t pool_handle,
+                                     indy_handle_t wallet_handle,
+                                     const char *  submitter_did,
+                                     const char *  target_did,
+                                     const char *  options_json,
+                                     void          (*cb)(indy_handle_t command_handle_,
+                                                         indy_error_t  err,
+                                                         const char*   nym_data)
+                                    );
+
+    /// Gets revocation registry definition json data for specified revocation registry definition id.
+    /// If data is present inside of cache, cached data is returned.
+    /// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+    ///
+    /// Revocation registry definition is immutable on the ledger, so cached data never expires:
+    /// minFresh option is ignored for it.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// submitter_did: DID of the submitter stored in secured Wallet.
+    /// id: identifier of revocation registry definition.
+    /// options_json:
+    ///  {
+    ///    noCache: (bool, optional, false by default) Skip usage of cache,
+    ///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+    ///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+    ///  }
+    /// cb: Callback that takes command result as parameter.
+    extern indy_error_t indy_get_revoc_reg_def(indy_handle_t command_handle,
+                                               indy_handle_t pool_handle,
+                                               indy_handle_t wallet_handle,
+                                               const char *  submitter_did,
+                                               const char *  id,
+                                               const char *  options_json,
+                                               void          (*cb)(indy_handle_t command_handle_,
+                                                                   indy_error_t  err,
+                                                                   const char*   revoc_reg_def_json)
+                                              );
+
+    /// Gets revocation registry delta for specified revocation registry definition id and time interval.
+    /// If data is present inside of cache, cached data is returned.
+    /// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+    ///
+    /// Deltas are cached per revocation registry and from value. A cached delta is returned for any to value
+    /// between its accumulator timestamp and the to value it has been fetched with, otherwise it is fetched again.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// submitter_did: DID of the submitter stored in secured Wallet.
+    /// revoc_reg_def_id: identifier of revocation registry definition.
+    /// from: Requested time represented as a total number of seconds from Unix Epoch, -1 means no lower bound.
+    /// to: Requested time represented as a total number of seconds from Unix Epoch
+    /// options_json:
+    ///  {
+    ///    noCache: (bool, optional, false by default) Skip usage of cache,
+    ///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+    ///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+    ///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+    ///  }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Revocation Registry Definition Id, Revocation Registry Delta json and Timestamp
+    /// (see indy_parse_get_revoc_reg_delta_response).
+    extern indy_error_t indy_get_revoc_reg_delta(indy_handle_t command_handle,
+                                                 indy_handle_t pool_handle,
+                                                 indy_handle_t wallet_handle,
+                                                 const char *  submitter_did,
+                                                 const char *  revoc_reg_def_id,
+                                                 long long     from,
+                                                 long long     to,
+                                                 const char *  options_json,
+                                                 void          (*cb)(indy_handle_t command_handle_,
+                                                                     indy_error_t  err,
+                                                                     const char*   revoc_reg_def_id,
+                                                                     const char*   revoc_reg_delta_json,
+                                                                     unsigned long long timestamp)
+                                                );
+
+    /// Gets Transaction Author Agreement.
+    /// If data is present inside of cache, cached data is returned.
+    /// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// submitter_did: DID of the submitter stored in secured Wallet.
+    /// data: (Optional) specifies a condition for getting specific TAA (see indy_build_get_txn_author_agreement_request).
+    /// options_json:
+    ///  {
+    ///    noCache: (bool, optional, false by default) Skip usage of cache,
+    ///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+    ///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+    ///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+    ///  }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Transaction Author Agreement data as it stored on the ledger
+    extern indy_error_t indy_get_txn_author_agreement(indy_handle_t command_handle,
+                                                      indy_handle_t pool_handle,
+                                                      indy_handle_t wallet_handle,
+                                                      const char *  submitter_did,
+                                                      const char *  data,
+                                                      const char *  options_json,
+                                                      void          (*cb)(indy_handle_t command_handle_,
+                                                                          indy_error_t  err,
+                                                                          const char*   taa_json)
+                                                     );
+
+    /// Purge NYM cache.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// options_json:
+    ///  {
+    ///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+    ///  }
+    extern indy_error_t indy_purge_nym_cache(indy_handle_t command_handle,
+                                             indy_handle_t wallet_handle,
+                                             const char *  options_json,
+                                             void          (*cb)(indy_handle_t command_handle_,
+                                                                 indy_error_t  err)
+                                            );
+
+    /// Purge revocation registry definition cache.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// options_json:
+    ///  {
+    ///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+    ///  }
+    extern indy_error_t indy_purge_revoc_reg_def_cache(indy_handle_t command_handle,
+                                                       indy_handle_t wallet_handle,
+                                                       const char *  options_json,
+                                                       void          (*cb)(indy_handle_t command_handle_,
+                                                                           indy_error_t  err)
+                                                      );
+
+    /// Purge revocation registry delta cache.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// options_json:
+    ///  {
+    ///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+    ///  }
+    extern indy_error_t indy_purge_revoc_reg_delta_cache(indy_handle_t command_handle,
+                                                         indy_handle_t wallet_handle,
+                                                         const char *  options_json,
+                                                         void          (*cb)(indy_handle_t command_handle_,
+                                                                             indy_error_t  err)
+                                                        );
+
+    /// Purge Transaction Author Agreement cache.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// options_json:
+    ///  {
+    ///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+    ///  }
+    extern indy_error_t indy_purge_txn_author_agreement_cache(indy_handle_t command_handle,
+                                                              indy_handle_t wallet_handle,
+                                                              const char *  options_json,
+                                                              void          (*cb)(indy_handle_t command_handle_,
+                                                                                  indy_error_t  err)
+                                                             );
 #ifdef __cplusplus
 }
 #endif
@@ -15,6 +15,8 @@
 #include "indy_blob_storage.h"
 #include "indy_non_secrets.h"
 #include "indy_logger.h"
+#include "indy_tracer.h"
+#include "indy_audit.h"
 #include "indy_cache.h"
 
 #endif
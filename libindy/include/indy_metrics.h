@@ -19,6 +19,33 @@ extern "C" {
                                                                  const char*   metrics_json)
 		                            );
 
+    /// Collect metrics in the requested format.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// options_json: {
+    ///     format: (optional) "json" (default) - map in the JSON format, the same as `indy_collect_metrics` returns,
+    ///             "openmetrics" - OpenMetrics text exposition (counters, gauges and histograms
+    ///                             with `_bucket`, `_sum` and `_count` series), suitable for Prometheus scraping.
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Collected metrics in the requested format.
+    /// Besides the command queue, threadpool and wallet counters it contains
+    ///     pool node replies per pool, node and reply type,
+    ///     pool requests per pool and consensus outcome,
+    ///     storage operations per opened wallet and operation.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_collect_metrics_with_options(indy_handle_t command_handle,
+                                                          const char *  options_json,
+                                                          void          (*fn)(indy_handle_t command_handle_,
+                                                                              indy_err_t    err,
+                                                                              const char*   metrics)
+                                                         );
+
 #ifdef __cplusplus
 }
 #endif
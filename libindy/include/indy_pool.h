@@ -28,6 +28,11 @@ extern "C" {
     extern indy_error_t indy_list_pools(indy_handle_t command_handle,
                                         void          (*fn)(indy_handle_t command_handle_, indy_error_t err, const char *const pools)
                                         );
+
+    extern indy_error_t indy_get_pool_status(indy_handle_t command_handle,
+                                             const char *  config_name,
+                                             void          (*cb)(indy_handle_t command_handle_, indy_error_t err, const char *const status_json)
+                                             );
     
     extern indy_error_t indy_close_pool_ledger(indy_handle_t command_handle,
                                                indy_handle_t handle,
@@ -330,6 +330,62 @@ extern "C" {
                                            void           (*fn)(indy_handle_t command_handle_, indy_error_t err)
                                            );
 
+    /// Creates a new secure wallet and then copies all records of the opened wallet into it.
+    /// Records are re-encrypted with keys of the new wallet without writing them to an intermediate file,
+    /// so it allows to move a wallet between storage types (e.g. from 'default' to a plugged storage).
+    /// After copying the number of records of every type in the new wallet is checked against the opened one.
+    /// If migration fails the new wallet is deleted.
+    ///
+    /// #Params
+    /// wallet_handle: wallet handle returned by indy_open_wallet
+    /// config: Configuration json of the new wallet.
+    /// {
+    ///   "id": string, Identifier of the wallet.
+    ///         Configured storage uses this identifier to lookup exact wallet data placement.
+    ///   "storage_type": optional<string>, Type of the wallet storage. Defaults to 'default'.
+    ///                  'Default' storage type allows to store wallet data in the local file.
+    ///                  Custom storage types can be registered with indy_register_wallet_storage call.
+    ///   "storage_config": optional<object>, Storage configuration json. Storage type defines set of supported keys.
+    ///                     Can be optional if storage supports default configuration.
+    ///                     For 'default' storage type configuration is:
+    ///   {
+    ///     "path": optional<string>, Path to the directory with wallet files.
+    ///             Defaults to $HOME/.indy_client/wallet.
+    ///             Wallet will be stored in the file {path}/{id}/sqlite.db
+    ///   }
+    /// }
+    /// credentials: Credentials json of the new wallet.
+    /// {
+    ///   "key": string, Key or passphrase used for wallet key derivation.
+    ///                  Look to key_derivation_method param for information about supported key derivation methods.
+    ///   "storage_credentials": optional<object> Credentials for wallet storage. Storage type defines set of supported keys.
+    ///                          Can be optional if storage supports default configuration.
+    ///                          For 'default' storage type should be empty.
+    ///   "key_derivation_method": optional<string> Algorithm to use for wallet key derivation:
+    ///                             ARGON2I_MOD - derive secured wallet master key (used by default)
+    ///                             ARGON2I_INT - derive secured wallet master key (less secured but faster)
+    ///                             RAW - raw wallet key master provided (skip derivation).
+    ///                                RAW keys can be generated with indy_generate_wallet_key call
+    /// }
+    ///
+    /// #Returns
+    /// Error code
+    /// migration_result: Migrated records json
+    /// {
+    ///   "records": {<record type>: <number of records>, ...},
+    ///   "total": <number of records>
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    extern indy_error_t indy_migrate_wallet(indy_handle_t  command_handle,
+                                            indy_handle_t  wallet_handle,
+                                            const char*    config,
+                                            const char*    credentials,
+                                            void           (*fn)(indy_handle_t command_handle_, indy_error_t err, const char* migration_result)
+                                            );
+
     /// Closes opened wallet and frees allocated resources.
     ///
     /// #Params
@@ -231,6 +231,7 @@ macro_rules! boxed_callback_string {
             let (err, result_string) = prepare_result_1!(result, String::new());
             trace!("{}: result: {:?}", $method_name, result_string);
             let result_string = ctypes::string_to_cstring(result_string);
+            $crate::tracer::finish_api_call($command_handle);
             $cb($command_handle, err, result_string.as_ptr())
         })
     }
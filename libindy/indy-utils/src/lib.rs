@@ -37,6 +37,7 @@ pub mod sequence;
 #[macro_use]
 #[allow(unused_macros)]
 pub mod test;
+pub mod tracer;
 pub mod wql;
 
 pub(crate) use indy_api_types::ErrorCode;
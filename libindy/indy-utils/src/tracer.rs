@@ -0,0 +1,310 @@
+//! Lightweight request tracing.
+//!
+//! A span is opened per API call and carries its `CommandHandle`. Work done on behalf of the call
+//! (command execution, wallet storage operations, pool requests, threadpool jobs) is recorded as
+//! child spans. Finished spans are passed to the registered exporter; if no exporter is set all
+//! operations are no-ops.
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Mutex, RwLock};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::thread;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use indy_api_types::CommandHandle;
+use serde_derive::Serialize;
+
+pub type SpanExporter = Box<dyn Fn(&FinishedSpan) + Send + Sync>;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static NEXT_SPAN_ID: AtomicU64 = AtomicU64::new(1);
+
+lazy_static! {
+    static ref EXPORTER: RwLock<Option<SpanExporter>> = RwLock::new(None);
+    // Spans of API calls in progress. A span is finished once the result is passed to the callback of the call.
+    static ref API_CALLS: Mutex<HashMap<CommandHandle, Span>> = Mutex::new(HashMap::new());
+    // Span contexts of requests handed over to another thread by the command handle (e.g. pool requests).
+    static ref HANDED_OVER: Mutex<HashMap<CommandHandle, SpanContext>> = Mutex::new(HashMap::new());
+}
+
+thread_local! {
+    static CURRENT: RefCell<Vec<SpanContext>> = RefCell::new(Vec::new());
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SpanContext {
+    pub trace_id: u64,
+    pub span_id: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FinishedSpan {
+    pub trace_id: u64,
+    pub span_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_span_id: Option<u64>,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command_handle: Option<CommandHandle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread: Option<String>,
+    pub start_us: u64,
+    pub duration_us: u64,
+    pub attributes: BTreeMap<String, String>,
+}
+
+/// Registers the exporter of finished spans. `None` disables tracing.
+pub fn set_exporter(exporter: Option<SpanExporter>) {
+    let enabled = exporter.is_some();
+    *EXPORTER.write().unwrap() = exporter;
+    ENABLED.store(enabled, Ordering::SeqCst);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Returns the context of the innermost entered span of the current thread.
+pub fn current() -> Option<SpanContext> {
+    if !is_enabled() {
+        return None;
+    }
+
+    CURRENT.with(|current| current.borrow().last().cloned())
+}
+
+/// Opens the root span of the API call identified by `command_handle`.
+/// The span is finished by `finish_api_call` from the callback of the call.
+pub fn start_api_call(name: &str, command_handle: CommandHandle) -> Option<SpanContext> {
+    let span = Span::api_call(name, Some(command_handle));
+    let context = span.context();
+
+    if context.is_some() {
+        API_CALLS.lock().unwrap().insert(command_handle, span);
+    }
+
+    context
+}
+
+/// Finishes the span of the API call opened by `start_api_call`.
+pub fn finish_api_call(command_handle: CommandHandle) {
+    // The span is exported on drop so the lock is released first.
+    let span = API_CALLS.lock().unwrap().remove(&command_handle);
+    drop(span);
+}
+
+/// Remembers the current span for the work continued on another thread under `handle`.
+/// The entry must be removed by `take_handed_over` on every completion path of the work.
+pub fn hand_over(handle: CommandHandle) {
+    if let Some(context) = current() {
+        HANDED_OVER.lock().unwrap().insert(handle, context);
+    }
+}
+
+/// Takes the span handed over under `handle` by `hand_over`.
+pub fn take_handed_over(handle: CommandHandle) -> Option<SpanContext> {
+    HANDED_OVER.lock().unwrap().remove(&handle)
+}
+
+pub struct Span {
+    inner: Option<SpanInner>,
+}
+
+struct SpanInner {
+    context: SpanContext,
+    parent_span_id: Option<u64>,
+    name: String,
+    command_handle: Option<CommandHandle>,
+    start_us: u64,
+    start: Instant,
+    attributes: BTreeMap<String, String>,
+}
+
+impl Span {
+    /// Opens the root span of the API call that sends a command to the command thread.
+    pub fn api_call(name: &str, command_handle: Option<CommandHandle>) -> Span {
+        Span::new(None, name, command_handle)
+    }
+
+    /// Opens the child span of the innermost entered span. Does nothing if there is no such span.
+    pub fn child(name: &str) -> Span {
+        Span::child_of(current(), name)
+    }
+
+    /// Opens the child span of `parent`. Does nothing if `parent` is `None`.
+    pub fn child_of(parent: Option<SpanContext>, name: &str) -> Span {
+        match parent {
+            Some(parent) => Span::new(Some(parent), name, None),
+            None => Span { inner: None }
+        }
+    }
+
+    /// Opens the child span of `parent` or starts a new trace if `parent` is `None`.
+    pub fn child_or_root(parent: Option<SpanContext>, name: &str) -> Span {
+        Span::new(parent, name, None)
+    }
+
+    fn new(parent: Option<SpanContext>, name: &str, command_handle: Option<CommandHandle>) -> Span {
+        if !is_enabled() {
+            return Span { inner: None };
+        }
+
+        let span_id = NEXT_SPAN_ID.fetch_add(1, Ordering::SeqCst);
+
+        let start_us = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_micros() as u64)
+            .unwrap_or(0);
+
+        Span {
+            inner: Some(SpanInner {
+                context: SpanContext {
+                    trace_id: parent.map(|parent| parent.trace_id).unwrap_or(span_id),
+                    span_id,
+                },
+                parent_span_id: parent.map(|parent| parent.span_id),
+                name: name.to_string(),
+                command_handle,
+                start_us,
+                start: Instant::now(),
+                attributes: BTreeMap::new(),
+            })
+        }
+    }
+
+    pub fn context(&self) -> Option<SpanContext> {
+        self.inner.as_ref().map(|inner| inner.context)
+    }
+
+    pub fn set_attribute<V: ToString>(&mut self, key: &str, value: V) {
+        if let Some(ref mut inner) = self.inner {
+            inner.attributes.insert(key.to_string(), value.to_string());
+        }
+    }
+
+    pub fn with_attribute<V: ToString>(mut self, key: &str, value: V) -> Span {
+        self.set_attribute(key, value);
+        self
+    }
+
+    /// Makes the span current for the calling thread until the returned guard is dropped.
+    pub fn enter(&self) -> SpanGuard {
+        match self.context() {
+            Some(context) => {
+                CURRENT.with(|current| current.borrow_mut().push(context));
+                SpanGuard { entered: true }
+            }
+            None => SpanGuard { entered: false }
+        }
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        let inner = match self.inner.take() {
+            Some(inner) => inner,
+            None => return
+        };
+
+        let span = FinishedSpan {
+            trace_id: inner.context.trace_id,
+            span_id: inner.context.span_id,
+            parent_span_id: inner.parent_span_id,
+            name: inner.name,
+            command_handle: inner.command_handle,
+            thread: thread::current().name().map(String::from),
+            start_us: inner.start_us,
+            duration_us: inner.start.elapsed().as_micros() as u64,
+            attributes: inner.attributes,
+        };
+
+        if let Ok(exporter) = EXPORTER.read() {
+            if let Some(ref exporter) = *exporter {
+                exporter(&span);
+            }
+        }
+    }
+}
+
+pub struct SpanGuard {
+    entered: bool,
+}
+
+impl Drop for SpanGuard {
+    fn drop(&mut self) {
+        if self.entered {
+            CURRENT.with(|current| { current.borrow_mut().pop(); });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    // Exporter is global, so all checks are kept in a single test.
+    #[test]
+    fn spans_are_exported() {
+        let spans: Arc<Mutex<Vec<(u64, u64, Option<u64>, String, Option<CommandHandle>, BTreeMap<String, String>)>>> = Arc::new(Mutex::new(Vec::new()));
+
+        {
+            assert!(Span::api_call("disabled", Some(1)).context().is_none());
+            assert!(Span::child_or_root(None, "disabled").context().is_none());
+        }
+
+        let exported = spans.clone();
+        set_exporter(Some(Box::new(move |span: &FinishedSpan| {
+            exported.lock().unwrap().push((span.trace_id, span.span_id, span.parent_span_id, span.name.clone(), span.command_handle, span.attributes.clone()));
+        })));
+
+        let root = Span::api_call("api", Some(42));
+        let root_context = root.context().unwrap();
+
+        assert!(Span::child("orphan").context().is_none());
+
+        {
+            let command = Span::child_of(Some(root_context), "command");
+            let _guard = command.enter();
+            assert_eq!(current(), command.context());
+
+            hand_over(7);
+
+            let _storage = Span::child("storage").with_attribute("operation", "add");
+        }
+        assert!(current().is_none());
+
+        let pool = Span::child_of(take_handed_over(7), "pool");
+        assert!(take_handed_over(7).is_none());
+        drop(pool);
+        drop(root);
+
+        let api_call = start_api_call("api_call", 43).unwrap();
+        assert_eq!(spans.lock().unwrap().len(), 4);
+        finish_api_call(43);
+        finish_api_call(43);
+
+        set_exporter(None);
+
+        assert!(start_api_call("disabled", 44).is_none());
+        assert!(API_CALLS.lock().unwrap().is_empty());
+
+        let spans = spans.lock().unwrap();
+        let names: Vec<&str> = spans.iter().map(|span| span.3.as_str()).collect();
+        assert_eq!(names, vec!["storage", "command", "pool", "api", "api_call"]);
+
+        for span in spans.iter().take(4) {
+            assert_eq!(span.0, root_context.trace_id);
+        }
+
+        let (_, command_span_id, command_parent, _, _, _) = spans[1].clone();
+        assert_eq!(command_parent, Some(root_context.span_id));
+        assert_eq!(spans[0].2, Some(command_span_id));
+        assert_eq!(spans[0].5["operation"], "add");
+        assert_eq!(spans[2].2, Some(command_span_id));
+        assert_eq!(spans[3].2, None);
+        assert_eq!(spans[3].4, Some(42));
+        assert_eq!((spans[4].0, spans[4].1, spans[4].4), (api_call.trace_id, api_call.span_id, Some(43)));
+    }
+}
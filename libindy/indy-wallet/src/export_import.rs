@@ -1,20 +1,24 @@
+use std::collections::BTreeMap;
 use std::io;
 use std::io::{BufReader, BufWriter, Read, Write};
+use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use rmp_serde;
 
-use indy_api_types::domain::wallet::Record;
-use indy_api_types::domain::wallet::KeyDerivationMethod;
+use indy_api_types::domain::wallet::{KeyDerivationMethod, Record, Tags};
 use indy_api_types::errors::prelude::*;
 use crate::encryption::KeyDerivationData;
 use indy_utils::crypto::{chacha20poly1305_ietf, pwhash_argon2i13};
 use indy_utils::crypto::hash::{hash, HASHBYTES};
 
 use super::{Wallet, WalletRecord};
+use super::iterator::WalletIterator;
+use super::wallet::{EncryptedRecord, Keys};
 
 const CHUNK_SIZE: usize = 1024;
+const MIGRATION_BATCH_SIZE: usize = 100;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub enum EncryptionMethod {
@@ -230,6 +234,106 @@ pub(super) fn finish_import<T>(wallet: &Wallet, reader: BufReader<T>, key: chach
     Ok(())
 }
 
+/// Copy of all records of a wallet into another one, made batch by batch.
+/// Only reading and writing of a batch need the wallets, so a batch can be encrypted
+/// with keys of the destination wallet on another thread.
+pub(super) struct Migration {
+    records: WalletIterator,
+    keys: Arc<Keys>,
+    counts: BTreeMap<String, usize>,
+}
+
+/// Records of the source wallet to be encrypted with keys of the destination wallet.
+pub struct MigrationBatch {
+    records: Vec<(String, String, String, Tags)>,
+    keys: Arc<Keys>,
+}
+
+/// Records encrypted with keys of the destination wallet.
+pub struct EncryptedMigrationBatch {
+    records: Vec<EncryptedRecord>,
+}
+
+impl Migration {
+    /// `keys` are keys of the destination wallet.
+    pub fn new(source: &Wallet, keys: Keys) -> IndyResult<Migration> {
+        Ok(Migration {
+            records: source.get_all()?,
+            keys: Arc::new(keys),
+            counts: BTreeMap::new(),
+        })
+    }
+
+    /// Returns `None` once all records of the source wallet are read.
+    pub fn next_batch(&mut self) -> IndyResult<Option<MigrationBatch>> {
+        let mut records = Vec::new();
+
+        while records.len() < MIGRATION_BATCH_SIZE {
+            let WalletRecord { type_, id, value, tags } = match self.records.next()? {
+                Some(record) => record,
+                None => break
+            };
+
+            let type_ = type_.ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "No type fetched for migrated record"))?;
+            let value = value.ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "No value fetched for migrated record"))?;
+            let tags = tags.ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "No tags fetched for migrated record"))?;
+
+            *self.counts.entry(type_.clone()).or_insert(0) += 1;
+            records.push((type_, id, value, tags));
+        }
+
+        if records.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(MigrationBatch { records, keys: Arc::clone(&self.keys) }))
+    }
+
+    pub fn add_batch(&self, destination: &Wallet, batch: EncryptedMigrationBatch) -> IndyResult<()> {
+        for record in batch.records.iter() {
+            destination.add_encrypted(record)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number of migrated records per type checked against the content of `destination`.
+    pub fn finish(self, destination: &Wallet) -> IndyResult<BTreeMap<String, usize>> {
+        let migrated = _count_records(destination)?;
+
+        if migrated != self.counts {
+            return Err(err_msg(IndyErrorKind::InvalidState,
+                               format!("Migrated wallet contains {:?} records per type instead of {:?}", migrated, self.counts)));
+        }
+
+        Ok(self.counts)
+    }
+}
+
+impl MigrationBatch {
+    pub fn encrypt(self) -> EncryptedMigrationBatch {
+        let keys = self.keys;
+
+        EncryptedMigrationBatch {
+            records: self.records.iter()
+                .map(|(type_, id, value, tags)| EncryptedRecord::new(type_, id, value, tags, &keys))
+                .collect()
+        }
+    }
+}
+
+fn _count_records(wallet: &Wallet) -> IndyResult<BTreeMap<String, usize>> {
+    let mut counts = BTreeMap::new();
+    let mut records = wallet.get_all()?;
+
+    while let Some(record) = records.next()? {
+        let type_ = record.type_.ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "No type fetched for migrated record"))?;
+        *counts.entry(type_).or_insert(0) += 1;
+    }
+
+    Ok(counts)
+}
+
 fn _map_io_err(e: io::Error) -> IndyError {
     match e {
         ref e if e.kind() == io::ErrorKind::UnexpectedEof
@@ -347,6 +451,66 @@ mod tests {
         _cleanup("export_import_works_for_multiple_items2");
     }
 
+    #[test]
+    fn migrate_records_works() {
+        _cleanup("migrate_records_works1");
+        _cleanup("migrate_records_works2");
+        {
+            let mut source = _add_300_records(_wallet("migrate_records_works1"));
+            let keys = Keys::new();
+            let mut destination = _wallet_with_keys("migrate_records_works2", keys.clone());
+
+            let counts = _migrate(&source, &destination, keys).unwrap();
+            assert_eq!(3, counts.len());
+            assert_eq!(100, counts[&_type(0)]);
+            _assert_has_300_records(&destination);
+
+            source.close().unwrap();
+            destination.close().unwrap();
+        }
+        test::cleanup_wallet("migrate_records_works1");
+        test::cleanup_wallet("migrate_records_works2");
+    }
+
+    #[test]
+    fn migrate_records_works_for_empty_wallet() {
+        _cleanup("migrate_records_works_for_empty_wallet1");
+        _cleanup("migrate_records_works_for_empty_wallet2");
+        {
+            let mut source = _wallet("migrate_records_works_for_empty_wallet1");
+            let keys = Keys::new();
+            let mut destination = _wallet_with_keys("migrate_records_works_for_empty_wallet2", keys.clone());
+
+            assert!(_migrate(&source, &destination, keys).unwrap().is_empty());
+            _assert_is_empty(&destination);
+
+            source.close().unwrap();
+            destination.close().unwrap();
+        }
+        test::cleanup_wallet("migrate_records_works_for_empty_wallet1");
+        test::cleanup_wallet("migrate_records_works_for_empty_wallet2");
+    }
+
+    #[test]
+    fn migrate_records_works_for_not_empty_destination() {
+        _cleanup("migrate_records_works_for_not_empty_destination1");
+        _cleanup("migrate_records_works_for_not_empty_destination2");
+        {
+            let mut source = _add_2_records(_wallet("migrate_records_works_for_not_empty_destination1"));
+            let keys = Keys::new();
+            let mut destination = _wallet_with_keys("migrate_records_works_for_not_empty_destination2", keys.clone());
+            destination.add(&_type(3), &_id(3), &_value(3), &_tags(3)).unwrap();
+
+            let err = _migrate(&source, &destination, keys).unwrap_err();
+            assert_eq!(IndyErrorKind::InvalidState, err.kind());
+
+            source.close().unwrap();
+            destination.close().unwrap();
+        }
+        test::cleanup_wallet("migrate_records_works_for_not_empty_destination1");
+        test::cleanup_wallet("migrate_records_works_for_not_empty_destination2");
+    }
+
     #[test]
     fn import_works_for_empty() {
         _cleanup("import_works_for_empty");
@@ -470,9 +634,12 @@ mod tests {
     }
 
     fn _wallet(id: &str) -> Wallet {
+        _wallet_with_keys(id, Keys::new())
+    }
+
+    fn _wallet_with_keys(id: &str, keys: Keys) -> Wallet {
         let storage_type = SQLiteStorageType::new();
         let master_key = _master_key();
-        let keys = Keys::new();
 
         let metadata = {
             let master_key_salt = encryption::gen_master_key_salt().unwrap();
@@ -496,6 +663,16 @@ mod tests {
         Wallet::new(id.to_string(), storage, Rc::new(keys))
     }
 
+    fn _migrate(source: &Wallet, destination: &Wallet, keys: Keys) -> IndyResult<BTreeMap<String, usize>> {
+        let mut migration = Migration::new(source, keys)?;
+
+        while let Some(batch) = migration.next_batch()? {
+            migration.add_batch(destination, batch.encrypt())?;
+        }
+
+        migration.finish(destination)
+    }
+
     fn _assert_is_empty(wallet: &Wallet) {
         assert!(wallet.get_all().unwrap().next().unwrap().is_none());
     }
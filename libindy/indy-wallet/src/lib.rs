@@ -12,7 +12,7 @@ extern crate serde;
 extern crate serde_derive;
 
 use std::cell::RefCell;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
 use std::io::BufReader;
 use std::path::PathBuf;
@@ -28,11 +28,13 @@ pub use crate::encryption::KeyDerivationData;
 use indy_utils::crypto::chacha20poly1305_ietf;
 use indy_utils::crypto::chacha20poly1305_ietf::Key as MasterKey;
 
-use self::export_import::{export_continue, finish_import, preparse_file_to_import};
+use self::export_import::{export_continue, finish_import, preparse_file_to_import, Migration};
+pub use self::export_import::{EncryptedMigrationBatch, MigrationBatch};
 use self::storage::{WalletStorage, WalletStorageType};
 use self::storage::default::SQLiteStorageType;
 use self::storage::plugged::PluggedStorageType;
 use self::wallet::{Keys, Wallet};
+pub use self::wallet::StorageOpCounters;
 use indy_api_types::{WalletHandle};
 
 mod storage;
@@ -45,18 +47,19 @@ mod export_import;
 mod wallet;
 
 pub struct WalletService {
-    storage_types: RefCell<HashMap<String, Box<dyn WalletStorageType>>>,
+    storage_types: RefCell<HashMap<String, Rc<dyn WalletStorageType>>>,
     wallets: RefCell<HashMap<WalletHandle, Box<Wallet>>>,
     wallet_ids: RefCell<HashSet<String>>,
     pending_for_open: RefCell<HashMap<WalletHandle, (String /* id */, Box<dyn WalletStorage>, Metadata, Option<KeyDerivationData>)>>,
     pending_for_import: RefCell<HashMap<WalletHandle, (BufReader<::std::fs::File>, chacha20poly1305_ietf::Nonce, usize, Vec<u8>, KeyDerivationData)>>,
+    pending_for_migrate: RefCell<HashMap<WalletHandle /* migrated wallet */, PendingMigration>>,
 }
 
 impl WalletService {
     pub fn new() -> WalletService {
         let storage_types = {
-            let mut map: HashMap<String, Box<dyn WalletStorageType>> = HashMap::new();
-            map.insert("default".to_string(), Box::new(SQLiteStorageType::new()));
+            let mut map: HashMap<String, Rc<dyn WalletStorageType>> = HashMap::new();
+            map.insert("default".to_string(), Rc::new(SQLiteStorageType::new()));
             RefCell::new(map)
         };
 
@@ -66,6 +69,7 @@ impl WalletService {
             wallet_ids: RefCell::new(HashSet::new()),
             pending_for_open: RefCell::new(HashMap::new()),
             pending_for_import: RefCell::new(HashMap::new()),
+            pending_for_migrate: RefCell::new(HashMap::new()),
         }
     }
 
@@ -104,7 +108,7 @@ impl WalletService {
         }
 
         storage_types.insert(type_.to_string(),
-                             Box::new(
+                             Rc::new(
                                  PluggedStorageType::new(create, open, close, delete,
                                                          add_record, update_record_value,
                                                          update_record_tags, add_record_tags, delete_record_tags,
@@ -524,6 +528,89 @@ impl WalletService {
         res
     }
 
+    /// Creates the wallet described by `config` and `credentials` to copy all records of the opened wallet into it.
+    /// Records are copied batch by batch: `migrate_wallet_next` reads records that must be encrypted
+    /// by `MigrationBatch::encrypt` and passed to `migrate_wallet_add`, then `migrate_wallet_finish` completes the migration.
+    /// The created wallet is deleted if any step fails. The migrated wallet must stay opened till the migration completes.
+    pub fn migrate_wallet_prepare(&self, wallet_handle: WalletHandle, config: &Config, credentials: &Credentials, key: (&KeyDerivationData, &MasterKey)) -> IndyResult<()> {
+        trace!("migrate_wallet_prepare >>> wallet_handle: {:?}, config: {:?}, credentials: {:?}", wallet_handle, config, secret!(credentials));
+
+        let wallets = self.wallets.borrow();
+        let source = wallets
+            .get(&wallet_handle)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidWalletHandle, "Unknown wallet handle"))?;
+
+        if self.pending_for_migrate.borrow().contains_key(&wallet_handle) {
+            return Err(err_msg(IndyErrorKind::InvalidState, "Wallet is already being migrated"));
+        }
+
+        let keys = self._create_wallet(config, credentials, key)?;
+
+        let created = {
+            let storage_types = self.storage_types.borrow();
+            let (storage_type, storage_config, storage_credentials) =
+                WalletService::_get_config_and_cred_for_storage(config, credentials, &storage_types)?;
+
+            CreatedStorage { storage_type: Rc::clone(storage_type), id: config.id.clone(), storage_config, storage_credentials, completed: false }
+        };
+
+        let storage = self._open_storage(config, credentials)?;
+
+        let destination = MigrationDestination {
+            wallet: Wallet::new(WalletService::_get_wallet_id(&config), storage, Rc::new(keys.clone())),
+            storage: created,
+        };
+
+        let migration = Migration::new(source, keys)?;
+
+        self.pending_for_migrate.borrow_mut().insert(wallet_handle, PendingMigration { migration, destination });
+
+        trace!("migrate_wallet_prepare <<<");
+        Ok(())
+    }
+
+    /// Returns `None` once all records of the migrated wallet are read.
+    pub fn migrate_wallet_next(&self, wallet_handle: WalletHandle) -> IndyResult<Option<MigrationBatch>> {
+        self._migration_step(wallet_handle, |pending| pending.migration.next_batch())
+    }
+
+    pub fn migrate_wallet_add(&self, wallet_handle: WalletHandle, batch: EncryptedMigrationBatch) -> IndyResult<()> {
+        self._migration_step(wallet_handle, |pending| pending.migration.add_batch(&pending.destination.wallet, batch))
+    }
+
+    /// Returns the number of migrated records per type.
+    pub fn migrate_wallet_finish(&self, wallet_handle: WalletHandle) -> IndyResult<BTreeMap<String, usize>> {
+        trace!("migrate_wallet_finish >>> wallet_handle: {:?}", wallet_handle);
+
+        let pending = self.pending_for_migrate.borrow_mut().remove(&wallet_handle)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "No pending migration for the wallet"))?;
+
+        let res = pending.finish();
+
+        trace!("migrate_wallet_finish <<< res: {:?}", res);
+        res
+    }
+
+    fn _migration_step<T, F>(&self, wallet_handle: WalletHandle, step: F) -> IndyResult<T>
+        where F: FnOnce(&mut PendingMigration) -> IndyResult<T> {
+        // Records are read through the storage of the migrated wallet, so it can't be closed in the middle.
+        let res = if self.wallets.borrow().contains_key(&wallet_handle) {
+            match self.pending_for_migrate.borrow_mut().get_mut(&wallet_handle) {
+                Some(pending) => step(pending),
+                None => return Err(err_msg(IndyErrorKind::InvalidState, "No pending migration for the wallet"))
+            }
+        } else {
+            Err(err_msg(IndyErrorKind::InvalidWalletHandle, "Wallet has been closed during migration"))
+        };
+
+        if res.is_err() {
+            // Dropping the migration deletes the created wallet.
+            self.pending_for_migrate.borrow_mut().remove(&wallet_handle);
+        }
+
+        res
+    }
+
     pub fn get_wallets_count(&self) -> usize {
         self.wallets.borrow().len()
     }
@@ -540,7 +627,21 @@ impl WalletService {
         self.pending_for_open.borrow().len()
     }
 
-    fn _get_config_and_cred_for_storage<'a>(config: &Config, credentials: &Credentials, storage_types: &'a HashMap<String, Box<dyn WalletStorageType>>) -> IndyResult<(&'a Box<dyn WalletStorageType>, Option<String>, Option<String>)> {
+    /// Returns storage operation counters of opened wallets as (wallet id, operation, counters).
+    pub fn get_storage_counters(&self) -> Vec<(String, &'static str, StorageOpCounters)> {
+        let mut res = Vec::new();
+
+        for wallet in self.wallets.borrow().values() {
+            for (operation, counters) in wallet.get_storage_counters() {
+                res.push((wallet.get_id().to_string(), operation, counters));
+            }
+        }
+
+        res.sort_by(|a, b| (&a.0, a.1).cmp(&(&b.0, b.1)));
+        res
+    }
+
+    fn _get_config_and_cred_for_storage<'a>(config: &Config, credentials: &Credentials, storage_types: &'a HashMap<String, Rc<dyn WalletStorageType>>) -> IndyResult<(&'a Rc<dyn WalletStorageType>, Option<String>, Option<String>)> {
         let storage_type = {
             let storage_type = config.storage_type
                 .as_ref()
@@ -623,6 +724,52 @@ impl WalletService {
     }
 }
 
+struct PendingMigration {
+    migration: Migration,
+    destination: MigrationDestination,
+}
+
+impl PendingMigration {
+    fn finish(mut self) -> IndyResult<BTreeMap<String, usize>> {
+        let counts = self.migration.finish(&self.destination.wallet)?;
+
+        self.destination.wallet.close()?;
+        self.destination.storage.completed = true;
+
+        Ok(counts)
+    }
+}
+
+// Storage is dropped after the wallet, so it is deleted once closed.
+struct MigrationDestination {
+    wallet: Wallet,
+    storage: CreatedStorage,
+}
+
+/// Storage created by migration. It is deleted on drop unless the migration has completed.
+struct CreatedStorage {
+    storage_type: Rc<dyn WalletStorageType>,
+    id: String,
+    storage_config: Option<String>,
+    storage_credentials: Option<String>,
+    completed: bool,
+}
+
+impl Drop for CreatedStorage {
+    fn drop(&mut self) {
+        if self.completed {
+            return;
+        }
+
+        // The error that has failed the migration is returned, so the failed cleanup is only logged.
+        if let Err(err) = self.storage_type.delete_storage(&self.id,
+                                                           self.storage_config.as_ref().map(String::as_str),
+                                                           self.storage_credentials.as_ref().map(String::as_str)) {
+            warn!("Wallet {} created by migration has not been deleted: {:?}", self.id, err);
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(untagged)]
 pub enum Metadata {
@@ -2030,6 +2177,62 @@ mod tests {
         test::cleanup_wallet("wallet_service_export_wallet_returns_error_if_wrong_handle");
     }
 
+    fn _migrate(wallet_service: &WalletService, wallet_handle: WalletHandle) -> IndyResult<BTreeMap<String, usize>> {
+        while let Some(batch) = wallet_service.migrate_wallet_next(wallet_handle)? {
+            wallet_service.migrate_wallet_add(wallet_handle, batch.encrypt())?;
+        }
+
+        wallet_service.migrate_wallet_finish(wallet_handle)
+    }
+
+    #[test]
+    fn wallet_service_migrate_wallet_works() {
+        test::cleanup_wallet("wallet_service_migrate_wallet_works1");
+        test::cleanup_wallet("wallet_service_migrate_wallet_works2");
+        {
+            let wallet_service = WalletService::new();
+            wallet_service.create_wallet(&_config("wallet_service_migrate_wallet_works1"), &RAW_CREDENTIAL, (&RAW_KDD, &RAW_MASTER_KEY)).unwrap();
+            let wallet_handle = wallet_service.open_wallet(&_config("wallet_service_migrate_wallet_works1"), &RAW_CREDENTIAL).unwrap();
+
+            wallet_service.add_record(wallet_handle, "type", "key1", "value1", &HashMap::new()).unwrap();
+            wallet_service.add_record(wallet_handle, "type", "key2", "value2", &HashMap::new()).unwrap();
+
+            wallet_service.migrate_wallet_prepare(wallet_handle, &_config("wallet_service_migrate_wallet_works2"), &RAW_CREDENTIAL, (&RAW_KDD, &RAW_MASTER_KEY)).unwrap();
+            let counts = _migrate(&wallet_service, wallet_handle).unwrap();
+            assert_eq!(2, counts["type"]);
+
+            let migrated_handle = wallet_service.open_wallet(&_config("wallet_service_migrate_wallet_works2"), &RAW_CREDENTIAL).unwrap();
+            let record = wallet_service.get_record(migrated_handle, "type", "key2", &_fetch_options(false, true, false)).unwrap();
+            assert_eq!("value2", record.get_value().unwrap());
+        }
+        test::cleanup_wallet("wallet_service_migrate_wallet_works1");
+        test::cleanup_wallet("wallet_service_migrate_wallet_works2");
+    }
+
+    #[test]
+    fn wallet_service_migrate_wallet_deletes_created_wallet_if_source_closed() {
+        test::cleanup_wallet("wallet_service_migrate_wallet_deletes_created_wallet_if_source_closed1");
+        test::cleanup_wallet("wallet_service_migrate_wallet_deletes_created_wallet_if_source_closed2");
+        {
+            let wallet_service = WalletService::new();
+            wallet_service.create_wallet(&_config("wallet_service_migrate_wallet_deletes_created_wallet_if_source_closed1"), &RAW_CREDENTIAL, (&RAW_KDD, &RAW_MASTER_KEY)).unwrap();
+            let wallet_handle = wallet_service.open_wallet(&_config("wallet_service_migrate_wallet_deletes_created_wallet_if_source_closed1"), &RAW_CREDENTIAL).unwrap();
+
+            wallet_service.add_record(wallet_handle, "type", "key1", "value1", &HashMap::new()).unwrap();
+
+            wallet_service.migrate_wallet_prepare(wallet_handle, &_config("wallet_service_migrate_wallet_deletes_created_wallet_if_source_closed2"), &RAW_CREDENTIAL, (&RAW_KDD, &RAW_MASTER_KEY)).unwrap();
+            wallet_service.close_wallet(wallet_handle).unwrap();
+
+            let res = _migrate(&wallet_service, wallet_handle);
+            assert_kind!(IndyErrorKind::InvalidWalletHandle, res);
+
+            let res = wallet_service.open_wallet(&_config("wallet_service_migrate_wallet_deletes_created_wallet_if_source_closed2"), &RAW_CREDENTIAL);
+            assert_kind!(IndyErrorKind::WalletNotFound, res);
+        }
+        test::cleanup_wallet("wallet_service_migrate_wallet_deletes_created_wallet_if_source_closed1");
+        test::cleanup_wallet("wallet_service_migrate_wallet_deletes_created_wallet_if_source_closed2");
+    }
+
     #[test]
     fn wallet_service_export_import_wallet_1_item() {
         test::cleanup_wallet("wallet_service_export_import_wallet_1_item");
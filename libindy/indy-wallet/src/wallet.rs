@@ -1,8 +1,11 @@
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
+use std::time::Instant;
 
 use indy_utils::crypto::{hmacsha256, chacha20poly1305_ietf};
 use indy_utils::wql::Query;
+use indy_utils::tracer::Span;
 
 use indy_api_types::errors::prelude::*;
 
@@ -14,7 +17,7 @@ use super::encryption::*;
 use super::query_encryption::encrypt_query;
 use super::WalletRecord;
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub(super) struct Keys {
     pub type_key: chacha20poly1305_ietf::Key,
     pub name_key: chacha20poly1305_ietf::Key,
@@ -112,23 +115,80 @@ impl EncryptedValue {
     }
 }
 
+/// Record encrypted with keys of a wallet, so it can be added to the wallet storage as is.
+pub(super) struct EncryptedRecord {
+    type_: Vec<u8>,
+    name: Vec<u8>,
+    value: EncryptedValue,
+    tags: Vec<storage::Tag>,
+}
+
+impl EncryptedRecord {
+    pub fn new(type_: &str, name: &str, value: &str, tags: &HashMap<String, String>, keys: &Keys) -> EncryptedRecord {
+        EncryptedRecord {
+            type_: encrypt_as_searchable(type_.as_bytes(), &keys.type_key, &keys.item_hmac_key),
+            name: encrypt_as_searchable(name.as_bytes(), &keys.name_key, &keys.item_hmac_key),
+            value: EncryptedValue::encrypt(value, &keys.value_key),
+            tags: encrypt_tags(tags, &keys.tag_name_key, &keys.tag_value_key, &keys.tags_hmac_key),
+        }
+    }
+}
+
+/// Counters of operations performed on the wallet storage.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct StorageOpCounters {
+    pub count: u64,
+    pub errors: u64,
+    pub duration_ms_sum: u128,
+}
+
 pub(super) struct Wallet {
     id: String,
     storage: Box<dyn storage::WalletStorage>,
     keys: Rc<Keys>,
+    storage_counters: RefCell<HashMap<&'static str, StorageOpCounters>>,
 }
 
 impl Wallet {
     pub fn new(id: String, storage: Box<dyn storage::WalletStorage>, keys: Rc<Keys>) -> Wallet {
-        Wallet { id, storage, keys }
+        Wallet { id, storage, keys, storage_counters: RefCell::new(HashMap::new()) }
+    }
+
+    fn _storage_op<T, F>(&self, operation: &'static str, f: F) -> IndyResult<T>
+        where F: FnOnce(&dyn storage::WalletStorage) -> IndyResult<T> {
+        let mut span = Span::child("wallet_storage")
+            .with_attribute("wallet", &self.id)
+            .with_attribute("operation", operation);
+
+        let start = Instant::now();
+        let res = f(self.storage.as_ref());
+
+        if let Err(ref err) = res {
+            span.set_attribute("error", err.kind());
+        }
+
+        let mut storage_counters = self.storage_counters.borrow_mut();
+        let counters = storage_counters.entry(operation).or_default();
+        counters.count += 1;
+        counters.duration_ms_sum += start.elapsed().as_millis();
+        if res.is_err() {
+            counters.errors += 1;
+        }
+
+        res
+    }
+
+    pub fn get_storage_counters(&self) -> HashMap<&'static str, StorageOpCounters> {
+        self.storage_counters.borrow().clone()
     }
 
     pub fn add(&self, type_: &str, name: &str, value: &str, tags: &HashMap<String, String>) -> IndyResult<()> {
-        let etype = encrypt_as_searchable(type_.as_bytes(), &self.keys.type_key, &self.keys.item_hmac_key);
-        let ename = encrypt_as_searchable(name.as_bytes(), &self.keys.name_key, &self.keys.item_hmac_key);
-        let evalue = EncryptedValue::encrypt(value, &self.keys.value_key);
-        let etags = encrypt_tags(tags, &self.keys.tag_name_key, &self.keys.tag_value_key, &self.keys.tags_hmac_key);
-        self.storage.add(&etype, &ename, &evalue, &etags)?;
+        self.add_encrypted(&EncryptedRecord::new(type_, name, value, tags, &self.keys))
+    }
+
+    /// Adds the record encrypted with keys of this wallet.
+    pub fn add_encrypted(&self, record: &EncryptedRecord) -> IndyResult<()> {
+        self._storage_op("add", |storage| storage.add(&record.type_, &record.name, &record.value, &record.tags))?;
         Ok(())
     }
 
@@ -136,7 +196,7 @@ impl Wallet {
         let encrypted_type = encrypt_as_searchable(type_.as_bytes(), &self.keys.type_key, &self.keys.item_hmac_key);
         let encrypted_name = encrypt_as_searchable(name.as_bytes(), &self.keys.name_key, &self.keys.item_hmac_key);
         let encrypted_tags = encrypt_tags(tags, &self.keys.tag_name_key, &self.keys.tag_value_key, &self.keys.tags_hmac_key);
-        self.storage.add_tags(&encrypted_type, &encrypted_name, &encrypted_tags)?;
+        self._storage_op("add_tags", |storage| storage.add_tags(&encrypted_type, &encrypted_name, &encrypted_tags))?;
         Ok(())
     }
 
@@ -144,7 +204,7 @@ impl Wallet {
         let encrypted_type = encrypt_as_searchable(type_.as_bytes(), &self.keys.type_key, &self.keys.item_hmac_key);
         let encrypted_name = encrypt_as_searchable(name.as_bytes(), &self.keys.name_key, &self.keys.item_hmac_key);
         let encrypted_tags = encrypt_tags(tags, &self.keys.tag_name_key, &self.keys.tag_value_key, &self.keys.tags_hmac_key);
-        self.storage.update_tags(&encrypted_type, &encrypted_name, &encrypted_tags)?;
+        self._storage_op("update_tags", |storage| storage.update_tags(&encrypted_type, &encrypted_name, &encrypted_tags))?;
         Ok(())
     }
 
@@ -152,7 +212,7 @@ impl Wallet {
         let encrypted_type = encrypt_as_searchable(type_.as_bytes(), &self.keys.type_key, &self.keys.item_hmac_key);
         let encrypted_name = encrypt_as_searchable(name.as_bytes(), &self.keys.name_key, &self.keys.item_hmac_key);
         let encrypted_tag_names = encrypt_tag_names(tag_names, &self.keys.tag_name_key, &self.keys.tags_hmac_key);
-        self.storage.delete_tags(&encrypted_type, &encrypted_name, &encrypted_tag_names[..])?;
+        self._storage_op("delete_tags", |storage| storage.delete_tags(&encrypted_type, &encrypted_name, &encrypted_tag_names[..]))?;
         Ok(())
     }
 
@@ -160,7 +220,7 @@ impl Wallet {
         let encrypted_type = encrypt_as_searchable(type_.as_bytes(), &self.keys.type_key, &self.keys.item_hmac_key);
         let encrypted_name = encrypt_as_searchable(name.as_bytes(), &self.keys.name_key, &self.keys.item_hmac_key);
         let encrypted_value = EncryptedValue::encrypt(new_value, &self.keys.value_key);
-        self.storage.update(&encrypted_type, &encrypted_name, &encrypted_value)?;
+        self._storage_op("update", |storage| storage.update(&encrypted_type, &encrypted_name, &encrypted_value))?;
         Ok(())
     }
 
@@ -168,7 +228,7 @@ impl Wallet {
         let etype = encrypt_as_searchable(type_.as_bytes(), &self.keys.type_key, &self.keys.item_hmac_key);
         let ename = encrypt_as_searchable(name.as_bytes(), &self.keys.name_key, &self.keys.item_hmac_key);
 
-        let result = self.storage.get(&etype, &ename, options)?;
+        let result = self._storage_op("get", |storage| storage.get(&etype, &ename, options))?;
 
         let value = match result.value {
             None => None,
@@ -184,7 +244,7 @@ impl Wallet {
         let etype = encrypt_as_searchable(type_.as_bytes(), &self.keys.type_key, &self.keys.item_hmac_key);
         let ename = encrypt_as_searchable(name.as_bytes(), &self.keys.name_key, &self.keys.item_hmac_key);
 
-        self.storage.delete(&etype, &ename)?;
+        self._storage_op("delete", |storage| storage.delete(&etype, &ename))?;
         Ok(())
     }
 
@@ -196,7 +256,7 @@ impl Wallet {
 
         let encrypted_query = encrypt_query(parsed_query, &self.keys)?;
         let encrypted_type_ = encrypt_as_searchable(type_.as_bytes(), &self.keys.type_key, &self.keys.item_hmac_key);
-        let storage_iterator = self.storage.search(&encrypted_type_, &encrypted_query, options)?;
+        let storage_iterator = self._storage_op("search", |storage| storage.search(&encrypted_type_, &encrypted_query, options))?;
         let wallet_iterator = WalletIterator::new(storage_iterator, Rc::clone(&self.keys));
         Ok(wallet_iterator)
     }
@@ -207,7 +267,7 @@ impl Wallet {
     }
 
     pub fn get_all(&self) -> IndyResult<WalletIterator> {
-        let all_items = self.storage.get_all()?;
+        let all_items = self._storage_op("get_all", |storage| storage.get_all())?;
         Ok(WalletIterator::new(all_items, Rc::clone(&self.keys)))
     }
 
@@ -276,6 +336,29 @@ mod tests {
         test::cleanup_wallet("wallet_add_get_works");
     }
 
+    #[test]
+    fn wallet_storage_counters_work() {
+        test::cleanup_wallet("wallet_storage_counters_work");
+        {
+            let mut wallet = _wallet("wallet_storage_counters_work");
+            assert!(wallet.get_storage_counters().is_empty());
+
+            wallet.add(_type1(), _id1(), _value1(), &_tags()).unwrap();
+            wallet.get(_type1(), _id1(), &_fetch_options(false, true, true)).unwrap();
+            wallet.get(_type1(), _id2(), &_fetch_options(false, true, true)).unwrap_err();
+
+            let counters = wallet.get_storage_counters();
+            assert_eq!(counters["add"].count, 1);
+            assert_eq!(counters["add"].errors, 0);
+            assert_eq!(counters["get"].count, 2);
+            assert_eq!(counters["get"].errors, 1);
+            assert!(!counters.contains_key("delete"));
+
+            wallet.close().unwrap();
+        }
+        test::cleanup_wallet("wallet_storage_counters_work");
+    }
+
     #[test]
     fn wallet_add_get_works_for_reopen() {
         test::cleanup_wallet("wallet_add_get_works_for_reopen");
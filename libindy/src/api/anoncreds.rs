@@ -5,21 +5,28 @@ use crate::commands::anoncreds::AnoncredsCommand;
 use crate::commands::anoncreds::issuer::IssuerCommand;
 use crate::commands::anoncreds::prover::ProverCommand;
 use crate::commands::anoncreds::verifier::VerifierCommand;
-use crate::domain::anoncreds::schema::{Schema, AttributeNames, Schemas};
+use crate::domain::anoncreds::schema::{Schema, SchemaV1, AttributeNames, AttributeTypes, Schemas};
 use crate::domain::crypto::did::DidValue;
 use crate::domain::anoncreds::credential_definition::{CredentialDefinition, CredentialDefinitionConfig, CredentialDefinitionId, CredentialDefinitions};
 use crate::domain::anoncreds::credential_offer::CredentialOffer;
 use crate::domain::anoncreds::credential_request::{CredentialRequest, CredentialRequestMetadata};
 use crate::domain::anoncreds::credential_attr_tag_policy::CredentialAttrTagPolicy;
-use crate::domain::anoncreds::credential::{Credential, CredentialValues};
+use crate::domain::anoncreds::credential_bundle::{CredentialBundle, CredentialExportConfig, CredentialImportConfig};
+use crate::domain::anoncreds::credential::{Credential, CredentialValues, ShortCredentialValues};
+use crate::domain::anoncreds::issuance_record::IssuanceRecordConfig;
 use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistryConfig, RevocationRegistryDefinition, RevocationRegistryId, RevocationRegistryDefinitions};
 use crate::domain::anoncreds::revocation_registry_delta::RevocationRegistryDelta;
 use crate::domain::anoncreds::proof::Proof;
-use crate::domain::anoncreds::proof_request::{ProofRequest, ProofRequestExtraQuery};
+use crate::domain::anoncreds::proof_request::{DatePredicateInfo, ProofRequest, ProofRequestExtraQuery};
+use crate::domain::anoncreds::proof_request_template::ProofRequestTemplate;
 use crate::domain::anoncreds::requested_credential::RequestedCredentials;
+use crate::domain::anoncreds::master_secret::CreateProofOptions;
 use crate::domain::anoncreds::revocation_registry::RevocationRegistries;
 use crate::domain::anoncreds::revocation_state::{RevocationState, RevocationStates};
+use crate::domain::anoncreds::verification_options::VerifyProofOptions;
+use crate::domain::anoncreds::w3c::{W3CCredential, W3CPresentation};
 use indy_utils::ctypes;
+use indy_utils::tracer;
 
 use libc::c_char;
 use std::ptr;
@@ -87,18 +94,20 @@ pub extern "C" fn indy_issuer_create_schema(command_handle: CommandHandle,
     trace!("indy_issuer_create_schema: entity >>> issuer_did: {:?}, name: {:?}, version: {:?}, attrs: {:?}", issuer_did, name, version, attrs);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+        .send_api_call(command_handle, Command::Anoncreds(
             AnoncredsCommand::Issuer(
                 IssuerCommand::CreateSchema(
                     issuer_did,
                     name,
                     version,
                     attrs,
+                    None,
                     Box::new(move |result| {
                         let (err, id, schema_json) = prepare_result_2!(result, String::new(), String::new());
                         trace!("ursa_cl_credential_public_key_to_json: id: {:?}, schema_json: {:?}", id, schema_json);
                         let id = ctypes::string_to_cstring(id);
                         let schema_json = ctypes::string_to_cstring(schema_json);
+                        tracer::finish_api_call(command_handle);
                         cb(command_handle, err, id.as_ptr(), schema_json.as_ptr())
                     })
                 ))));
@@ -110,6 +119,92 @@ pub extern "C" fn indy_issuer_create_schema(command_handle: CommandHandle,
     res
 }
 
+/// Create credential schema entity with declared types of attributes.
+///
+/// Works like indy_issuer_create_schema, but every attribute has a type that defines how its values are encoded:
+///     integer - 32-bit integer, encoded as itself
+///     date - date in "YYYY-MM-DD" format, encoded as number of days since 1970-01-01 (can be used in predicates)
+///     boolean - "true" or "false", encoded as 1 or 0
+///     string - any value, encoded as SHA-256 hash even if it looks like an integer
+///
+/// Attribute types are not a part of SCHEMA ledger transaction. Schema received from the Ledger has no types,
+/// so typed schema json must be shared with credential holders and verifiers directly.
+/// Issuer checks credential values against the types if typed schema is passed to indy_issuer_create_and_store_credential_def.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context
+/// issuer_did: DID of schema issuer
+/// name: a name the schema
+/// version: a version of the schema
+/// attr_types: schema attributes with their types (the number of attributes should be less or equal than 125)
+///     `{"name": "string", "dob": "date", "age": "integer", "married": "boolean"}`
+/// cb: Callback that takes command result as parameter
+///
+/// #Returns
+/// schema_id: identifier of created schema
+/// schema_json: schema as json:
+/// {
+///     id: identifier of schema
+///     attrNames: array of attribute name strings
+///     attrTypes: map of attribute name to its type
+///     name: schema's name string
+///     version: schema's version string,
+///     ver: version of the Schema json
+/// }
+///
+/// #Errors
+/// Common*
+/// Anoncreds*
+#[no_mangle]
+pub extern "C" fn indy_issuer_create_typed_schema(command_handle: CommandHandle,
+                                              issuer_did: *const c_char,
+                                              name: *const c_char,
+                                              version: *const c_char,
+                                              attr_types: *const c_char,
+                                              cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                       schema_id: *const c_char, schema_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_create_typed_schema: >>> issuer_did: {:?}, name: {:?}, version: {:?}, attr_types: {:?}", issuer_did, name, version, attr_types);
+
+    check_useful_validatable_string!(issuer_did, ErrorCode::CommonInvalidParam2, DidValue);
+    check_useful_c_str!(name, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(version, ErrorCode::CommonInvalidParam4);
+    check_useful_json!(attr_types, ErrorCode::CommonInvalidParam5, AttributeTypes);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_issuer_create_typed_schema: entity >>> issuer_did: {:?}, name: {:?}, version: {:?}, attr_types: {:?}", issuer_did, name, version, attr_types);
+
+    let attrs = AttributeNames(attr_types.keys().cloned().collect());
+
+    if let Err(err) = attrs.validate() {
+        return err_msg(IndyErrorKind::InvalidStructure, err).into();
+    }
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::CreateSchema(
+                    issuer_did,
+                    name,
+                    version,
+                    attrs,
+                    Some(attr_types),
+                    Box::new(move |result| {
+                        let (err, id, schema_json) = prepare_result_2!(result, String::new(), String::new());
+                        trace!("indy_issuer_create_typed_schema: id: {:?}, schema_json: {:?}", id, schema_json);
+                        let id = ctypes::string_to_cstring(id);
+                        let schema_json = ctypes::string_to_cstring(schema_json);
+                        tracer::finish_api_call(command_handle);
+                        cb(command_handle, err, id.as_ptr(), schema_json.as_ptr())
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_create_typed_schema: <<< res: {:?}", res);
+
+    res
+}
+
 /// Create credential definition entity that encapsulates credentials issuer DID, credential schema, secrets used for signing credentials
 /// and secrets used for credentials revocation.
 ///
@@ -132,6 +227,8 @@ pub extern "C" fn indy_issuer_create_schema(command_handle: CommandHandle,
 ///     name: schema's name string
 ///     version: schema's version string,
 ///     seqNo: (Optional) schema's sequence number on the ledger,
+///     attrTypes: (Optional) attribute types as returned by indy_issuer_create_typed_schema.
+///         Issued credential values are checked against these types.
 ///     ver: version of the Schema json
 /// }
 /// tag: any string that allows to distinguish between credential definitions for the same issuer and schema
@@ -195,7 +292,7 @@ pub extern "C" fn indy_issuer_create_and_store_credential_def(command_handle: Co
     signature_type: {:?}, config_json: {:?}", wallet_handle, issuer_did, schema_json, tag, signature_type, config_json);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+        .send_api_call(command_handle, Command::Anoncreds(
             AnoncredsCommand::Issuer(
                 IssuerCommand::CreateAndStoreCredentialDefinition(
                     wallet_handle,
@@ -209,6 +306,7 @@ pub extern "C" fn indy_issuer_create_and_store_credential_def(command_handle: Co
                         trace!("indy_issuer_create_and_store_credential_def: cred_def_id: {:?}, cred_def_json: {:?}", cred_def_id, cred_def_json);
                         let cred_def_id = ctypes::string_to_cstring(cred_def_id);
                         let cred_def_json = ctypes::string_to_cstring(cred_def_json);
+                        tracer::finish_api_call(command_handle);
                         cb(command_handle, err, cred_def_id.as_ptr(), cred_def_json.as_ptr())
                     })
                 ))));
@@ -276,7 +374,7 @@ pub extern "C" fn indy_issuer_rotate_credential_def_start(command_handle: Comman
            wallet_handle, cred_def_id, config_json);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+        .send_api_call(command_handle, Command::Anoncreds(
             AnoncredsCommand::Issuer(
                 IssuerCommand::RotateCredentialDefinitionStart(
                     wallet_handle,
@@ -286,6 +384,7 @@ pub extern "C" fn indy_issuer_rotate_credential_def_start(command_handle: Comman
                         let (err, cred_def_json) = prepare_result_1!(result, String::new());
                         trace!("indy_issuer_rotate_credential_def_start:cred_def_json: {:?}", cred_def_json);
                         let cred_def_json = ctypes::string_to_cstring(cred_def_json);
+                        tracer::finish_api_call(command_handle);
                         cb(command_handle, err, cred_def_json.as_ptr())
                     })
                 ))));
@@ -328,7 +427,7 @@ pub extern "C" fn indy_issuer_rotate_credential_def_apply(command_handle: Comman
            wallet_handle, cred_def_id);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+        .send_api_call(command_handle, Command::Anoncreds(
             AnoncredsCommand::Issuer(
                 IssuerCommand::RotateCredentialDefinitionApply(
                     wallet_handle,
@@ -336,6 +435,7 @@ pub extern "C" fn indy_issuer_rotate_credential_def_apply(command_handle: Comman
                     Box::new(move |result| {
                         let err = prepare_result!(result);
                         trace!("indy_issuer_rotate_credential_def_apply:");
+                        tracer::finish_api_call(command_handle);
                         cb(command_handle, err)
                     })
                 ))));
@@ -450,7 +550,7 @@ pub extern "C" fn indy_issuer_create_and_store_revoc_reg(command_handle: Command
     cred_def_id: {:?}, config_json: {:?}, tails_writer_handle: {:?}", wallet_handle, issuer_did, revoc_def_type, tag, cred_def_id, config_json, tails_writer_handle);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+        .send_api_call(command_handle, Command::Anoncreds(
             AnoncredsCommand::Issuer(
                 IssuerCommand::CreateAndStoreRevocationRegistry(
                     wallet_handle,
@@ -460,6 +560,7 @@ pub extern "C" fn indy_issuer_create_and_store_revoc_reg(command_handle: Command
                     cred_def_id,
                     config_json,
                     tails_writer_handle,
+                    None,
                     Box::new(move |result| {
                         let (err, revoc_reg_id, revoc_reg_def_json, revoc_reg_json) = prepare_result_3!(result, String::new(), String::new(), String::new());
                         trace!("indy_issuer_create_and_store_credential_def: revoc_reg_id: {:?}, revoc_reg_def_json: {:?}, revoc_reg_json: {:?}",
@@ -467,6 +568,7 @@ pub extern "C" fn indy_issuer_create_and_store_revoc_reg(command_handle: Command
                         let revoc_reg_id = ctypes::string_to_cstring(revoc_reg_id);
                         let revoc_reg_def_json = ctypes::string_to_cstring(revoc_reg_def_json);
                         let revoc_reg_json = ctypes::string_to_cstring(revoc_reg_json);
+                        tracer::finish_api_call(command_handle);
                         cb(command_handle, err, revoc_reg_id.as_ptr(), revoc_reg_def_json.as_ptr(), revoc_reg_json.as_ptr())
                     })
                 ))));
@@ -478,6 +580,98 @@ pub extern "C" fn indy_issuer_create_and_store_revoc_reg(command_handle: Command
     res
 }
 
+/// Create a new revocation registry for the given credential definition like indy_issuer_create_and_store_revoc_reg does,
+/// and report the progress of tails generation.
+///
+/// Tails are generated sequentially in the crypto threadpool and written to the blob storage by chunks.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// issuer_did: a DID of the issuer
+/// revoc_def_type: revocation registry type (see indy_issuer_create_and_store_revoc_reg)
+/// tag: any string that allows to distinct between revocation registries for the same issuer and credential definition
+/// cred_def_id: id of stored in ledger credential definition
+/// config_json: type-specific configuration of revocation registry as json (see indy_issuer_create_and_store_revoc_reg)
+/// tails_writer_handle: handle of blob storage to store tails (returned by `indy_open_blob_storage_writer`).
+/// progress_cb: (Optional) Callback that is called every time a chunk of tails is written to blob storage.
+///     tails_written: count of tails written so far
+///     tails_total: total count of tails in the registry
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// revoc_reg_id: identifier of created revocation registry definition
+/// revoc_reg_def_json: public part of revocation registry definition (see indy_issuer_create_and_store_revoc_reg)
+/// revoc_reg_entry_json: revocation registry entry that defines initial state of revocation registry
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Anoncreds*
+#[no_mangle]
+pub extern "C" fn indy_issuer_create_and_store_revoc_reg_with_progress(command_handle: CommandHandle,
+                                                                   wallet_handle: WalletHandle,
+                                                                   issuer_did: *const c_char,
+                                                                   revoc_def_type: *const c_char,
+                                                                   tag: *const c_char,
+                                                                   cred_def_id: *const c_char,
+                                                                   config_json: *const c_char,
+                                                                   tails_writer_handle: IndyHandle,
+                                                                   progress_cb: Option<extern "C" fn(command_handle_: CommandHandle,
+                                                                                                     tails_written: u32,
+                                                                                                     tails_total: u32)>,
+                                                                   cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                                        revoc_reg_id: *const c_char,
+                                                                                        revoc_reg_def_json: *const c_char,
+                                                                                        revoc_reg_entry_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_create_and_store_revoc_reg_with_progress: >>> wallet_handle: {:?}, issuer_did: {:?}, revoc_def_type: {:?}, tag: {:?}, \
+    cred_def_id: {:?}, config_json: {:?}, tails_writer_handle: {:?}", wallet_handle, issuer_did, revoc_def_type, tag, cred_def_id, config_json, tails_writer_handle);
+
+    check_useful_validatable_string!(issuer_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_opt_c_str!(revoc_def_type, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(tag, ErrorCode::CommonInvalidParam5);
+    check_useful_validatable_string!(cred_def_id, ErrorCode::CommonInvalidParam6, CredentialDefinitionId);
+    check_useful_validatable_json!(config_json, ErrorCode::CommonInvalidParam7, RevocationRegistryConfig);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam10);
+
+    trace!("indy_issuer_create_and_store_revoc_reg_with_progress: entities >>> wallet_handle: {:?}, issuer_did: {:?}, revoc_def_type: {:?}, tag: {:?}, \
+    cred_def_id: {:?}, config_json: {:?}, tails_writer_handle: {:?}", wallet_handle, issuer_did, revoc_def_type, tag, cred_def_id, config_json, tails_writer_handle);
+
+    let progress = progress_cb.map(|progress_cb| -> Box<dyn Fn(u32, u32) + Send> {
+        Box::new(move |tails_written, tails_total| progress_cb(command_handle, tails_written, tails_total))
+    });
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::CreateAndStoreRevocationRegistry(
+                    wallet_handle,
+                    issuer_did,
+                    revoc_def_type,
+                    tag,
+                    cred_def_id,
+                    config_json,
+                    tails_writer_handle,
+                    progress,
+                    Box::new(move |result| {
+                        let (err, revoc_reg_id, revoc_reg_def_json, revoc_reg_json) = prepare_result_3!(result, String::new(), String::new(), String::new());
+                        trace!("indy_issuer_create_and_store_revoc_reg_with_progress: revoc_reg_id: {:?}, revoc_reg_def_json: {:?}, revoc_reg_json: {:?}",
+                               revoc_reg_id, revoc_reg_def_json, revoc_reg_json);
+                        let revoc_reg_id = ctypes::string_to_cstring(revoc_reg_id);
+                        let revoc_reg_def_json = ctypes::string_to_cstring(revoc_reg_def_json);
+                        let revoc_reg_json = ctypes::string_to_cstring(revoc_reg_json);
+                        tracer::finish_api_call(command_handle);
+                        cb(command_handle, err, revoc_reg_id.as_ptr(), revoc_reg_def_json.as_ptr(), revoc_reg_json.as_ptr())
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_create_and_store_revoc_reg_with_progress: <<< res: {:?}", res);
+
+    res
+}
+
 /// Create credential offer that will be used by Prover for
 /// credential request creation. Offer includes nonce and key correctness proof
 /// for authentication between protocol steps and integrity checking.
@@ -518,7 +712,7 @@ pub extern "C" fn indy_issuer_create_credential_offer(command_handle: CommandHan
     trace!("indy_issuer_create_credential_offer: entities >>> wallet_handle: {:?}, cred_def_id: {:?}", wallet_handle, cred_def_id);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+        .send_api_call(command_handle, Command::Anoncreds(
             AnoncredsCommand::Issuer(
                 IssuerCommand::CreateCredentialOffer(
                     wallet_handle,
@@ -615,7 +809,7 @@ pub extern "C" fn indy_issuer_create_credential(command_handle: CommandHandle,
     blob_storage_reader_handle: {:?}", wallet_handle, cred_offer_json, secret!(&cred_req_json), secret!(&cred_values_json), secret!(&rev_reg_id), blob_storage_reader_handle);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+        .send_api_call(command_handle, Command::Anoncreds(
             AnoncredsCommand::Issuer(
                 IssuerCommand::CreateCredential(
                     wallet_handle,
@@ -624,6 +818,7 @@ pub extern "C" fn indy_issuer_create_credential(command_handle: CommandHandle,
                     cred_values_json,
                     rev_reg_id,
                     blob_storage_reader_handle,
+                    None,
                     Box::new(move |result| {
                         let (err, cred_json, revoc_id, revoc_reg_delta_json) = prepare_result_3!(result, String::new(), None, None);
                         trace!("indy_issuer_create_credential: cred_json: {:?}, revoc_id: {:?}, revoc_reg_delta_json: {:?}",
@@ -631,6 +826,7 @@ pub extern "C" fn indy_issuer_create_credential(command_handle: CommandHandle,
                         let cred_json = ctypes::string_to_cstring(cred_json);
                         let revoc_id = revoc_id.map(ctypes::string_to_cstring);
                         let revoc_reg_delta_json = revoc_reg_delta_json.map(ctypes::string_to_cstring);
+                        tracer::finish_api_call(command_handle);
                         cb(command_handle, err, cred_json.as_ptr(),
                            revoc_id.as_ref().map(|id| id.as_ptr()).unwrap_or(ptr::null()),
                            revoc_reg_delta_json.as_ref().map(|delta| delta.as_ptr()).unwrap_or(ptr::null()))
@@ -694,7 +890,7 @@ pub extern "C" fn indy_issuer_revoke_credential(command_handle: CommandHandle,
            wallet_handle, blob_storage_reader_cfg_handle, rev_reg_id, secret!(cred_revoc_id.as_str()));
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+        .send_api_call(command_handle, Command::Anoncreds(
             AnoncredsCommand::Issuer(
                 IssuerCommand::RevokeCredential(
                     wallet_handle,
@@ -711,175 +907,685 @@ pub extern "C" fn indy_issuer_revoke_credential(command_handle: CommandHandle,
     res
 }
 
-/*/// Recover a credential identified by a cred_revoc_id (returned by indy_issuer_create_credential).
-///
-/// The corresponding credential definition and revocation registry must be already
-/// created an stored into the wallet.
+/// Check Cred Request for the given Cred Offer and issue Credential for the given Cred Request
+/// like indy_issuer_create_credential does, and store an issuance record for it in the wallet.
 ///
-/// This call returns revoc registry delta as json file intended to be shared as REVOC_REG_ENTRY transaction.
-/// Note that it is possible to accumulate deltas to reduce ledger load.
+/// The issuance record is written together with the revocation registry update,
+/// so it can be used later to find and revoke the credential (see indy_issuer_revoke_credential_by_record).
+/// If any of these wallet writes fails, the already applied ones are undone and an error is returned.
+/// The record keeps only a hash of credential values.
 ///
 /// #Params
 /// command_handle: command handle to map callback to user context.
 /// wallet_handle: wallet handle (created by open_wallet).
-/// blob_storage_reader_cfg_handle: configuration of blob storage reader handle that will allow to read revocation tails (returned by `indy_open_blob_storage_reader`).
-/// rev_reg_id: id of revocation registry stored in wallet
-/// cred_revoc_id: local id for revocation info related to issued credential
+/// cred_offer_json: a cred offer created by indy_issuer_create_credential_offer
+/// cred_req_json: a credential request created by indy_prover_create_credential_req
+/// cred_values_json: a credential containing attribute values for each of requested attribute names.
+///     (see indy_issuer_create_credential)
+/// rev_reg_id: id of revocation registry stored in the wallet
+/// blob_storage_reader_handle: configuration of blob storage reader handle that will allow to read revocation tails (returned by `indy_open_blob_storage_reader`)
+/// record_config_json: issuance record config
+///     {
+///         "id": string, - id of the issuance record. Must be unique in the wallet.
+///         "tags": Optional<object> - caller tags of the issuance record (for example, connection or offer id)
+///             {"tag1": "value1", "~tag2": "value2"}
+///             Tags "cred_def_id", "rev_reg_id", "cred_rev_id" and "revoked" are maintained by libindy and can't be set.
+///     }
 /// cb: Callback that takes command result as parameter.
 ///
 /// #Returns
-/// revoc_reg_delta_json: Revocation registry delta json with a recovered credential
-/// {
-///     value: {
-///         prevAccum: string - previous accumulator value.
-///         accum: string - current accumulator value.
-///         issued: array<number> an array of issued indices.
-///     },
-///     ver: string - version revocation registry delta json
-/// }
+/// cred_json: Credential json containing signed credential values (see indy_issuer_create_credential)
+/// cred_revoc_id: local id for revocation info (Can be used for revocation of this credential)
+/// revoc_reg_delta_json: Revocation registry delta json with a newly issued credential
 ///
 /// #Errors
 /// Anoncreds*
 /// Common*
 /// Wallet*
 #[no_mangle]
-pub extern fn indy_issuer_recover_credential(command_handle: CommandHandle,
-                                             wallet_handle: WalletHandle,
-                                             blob_storage_reader_cfg_handle: IndyHandle,
-                                             rev_reg_id: *const c_char,
-                                             cred_revoc_id: *const c_char,
-                                             cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
-                                                                  revoc_reg_delta_json: *const c_char,
-                                             )>) -> ErrorCode {
-    check_useful_c_str!(rev_reg_id, ErrorCode::CommonInvalidParam4);
-    check_useful_c_str!(cred_revoc_id, ErrorCode::CommonInvalidParam5);
-    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+pub extern "C" fn indy_issuer_create_credential_with_record(command_handle: CommandHandle,
+                                                        wallet_handle: WalletHandle,
+                                                        cred_offer_json: *const c_char,
+                                                        cred_req_json: *const c_char,
+                                                        cred_values_json: *const c_char,
+                                                        rev_reg_id: *const c_char,
+                                                        blob_storage_reader_handle: IndyHandle,
+                                                        record_config_json: *const c_char,
+                                                        cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                             cred_json: *const c_char,
+                                                                             cred_revoc_id: *const c_char,
+                                                                             revoc_reg_delta_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_create_credential_with_record: >>> wallet_handle: {:?}, cred_offer_json: {:?}, cred_req_json: {:?}, cred_values_json: {:?}, rev_reg_id: {:?}, \
+    blob_storage_reader_handle: {:?}, record_config_json: {:?}", wallet_handle, cred_offer_json, cred_req_json, cred_values_json, rev_reg_id, blob_storage_reader_handle, record_config_json);
+
+    check_useful_validatable_json!(cred_offer_json, ErrorCode::CommonInvalidParam3, CredentialOffer);
+    check_useful_validatable_json!(cred_req_json, ErrorCode::CommonInvalidParam4, CredentialRequest);
+    check_useful_validatable_json!(cred_values_json, ErrorCode::CommonInvalidParam5, CredentialValues);
+    check_useful_validatable_opt_string!(rev_reg_id, ErrorCode::CommonInvalidParam6, RevocationRegistryId);
+    check_useful_validatable_json!(record_config_json, ErrorCode::CommonInvalidParam8, IssuanceRecordConfig);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam9);
+
+    let blob_storage_reader_handle = if blob_storage_reader_handle != -1 { Some(blob_storage_reader_handle) } else { None };
+
+    trace!("indy_issuer_create_credential_with_record: entities >>> wallet_handle: {:?}, cred_offer_json: {:?}, cred_req_json: {:?}, cred_values_json: {:?}, rev_reg_id: {:?}, \
+    blob_storage_reader_handle: {:?}, record_config_json: {:?}", wallet_handle, cred_offer_json, secret!(&cred_req_json), secret!(&cred_values_json), secret!(&rev_reg_id),
+           blob_storage_reader_handle, record_config_json);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+        .send_api_call(command_handle, Command::Anoncreds(
             AnoncredsCommand::Issuer(
-                IssuerCommand::RecoverCredential(
+                IssuerCommand::CreateCredential(
                     wallet_handle,
-                    blob_storage_reader_cfg_handle,
+                    cred_offer_json,
+                    cred_req_json,
+                    cred_values_json,
                     rev_reg_id,
-                    cred_revoc_id,
+                    blob_storage_reader_handle,
+                    Some(record_config_json),
                     Box::new(move |result| {
-                        let (err, revoc_reg_update_json) = prepare_result_1!(result, String::new());
-                        let revoc_reg_update_json = ctypes::string_to_cstring(revoc_reg_update_json);
-                        cb(command_handle, err, revoc_reg_update_json.as_ptr())
+                        let (err, cred_json, revoc_id, revoc_reg_delta_json) = prepare_result_3!(result, String::new(), None, None);
+                        trace!("indy_issuer_create_credential_with_record: cred_json: {:?}, revoc_id: {:?}, revoc_reg_delta_json: {:?}",
+                               secret!(cred_json.as_str()), secret!(&revoc_id), revoc_reg_delta_json);
+                        let cred_json = ctypes::string_to_cstring(cred_json);
+                        let revoc_id = revoc_id.map(ctypes::string_to_cstring);
+                        let revoc_reg_delta_json = revoc_reg_delta_json.map(ctypes::string_to_cstring);
+                        tracer::finish_api_call(command_handle);
+                        cb(command_handle, err, cred_json.as_ptr(),
+                           revoc_id.as_ref().map(|id| id.as_ptr()).unwrap_or(ptr::null()),
+                           revoc_reg_delta_json.as_ref().map(|delta| delta.as_ptr()).unwrap_or(ptr::null()))
                     })
                 ))));
 
-    prepare_result!(result)
-}*/
+    let res = prepare_result!(result);
 
-/// Merge two revocation registry deltas (returned by indy_issuer_create_credential or indy_issuer_revoke_credential) to accumulate common delta.
-/// Send common delta to ledger to reduce the load.
+    trace!("indy_issuer_create_credential_with_record: <<< res: {:?}", res);
+
+    res
+}
+
+/// Revoke a credential identified by an issuance record (created by indy_issuer_create_credential_with_record).
+///
+/// Works like indy_issuer_revoke_credential, but revocation registry id and cred_revoc_id
+/// are taken from the issuance record. The record is marked as revoked.
+/// Once the registry is updated, the delta is returned even if the record can't be marked (a warning is logged).
 ///
 /// #Params
 /// command_handle: command handle to map callback to user context.
-/// rev_reg_delta_json: revocation registry delta.
-/// {
-///     value: {
-///         prevAccum: string - previous accumulator value.
-///         accum: string - current accumulator value.
-///         issued: array<number> an array of issued indices.
-///         revoked: array<number> an array of revoked indices.
-///     },
-///     ver: string - version revocation registry delta json
-/// }
-///
-/// other_rev_reg_delta_json: revocation registry delta for which PrevAccum value is equal to value of accum field of rev_reg_delta_json parameter.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// blob_storage_reader_cfg_handle: configuration of blob storage reader handle that will allow to read revocation tails (returned by `indy_open_blob_storage_reader`).
+/// record_id: id of the issuance record
 /// cb: Callback that takes command result as parameter.
 ///
 /// #Returns
-/// merged_rev_reg_delta: Merged revocation registry delta
-/// {
-///     value: {
-///         prevAccum: string - previous accumulator value.
-///         accum: string - current accumulator value.
-///         issued: array<number> an array of issued indices.
-///         revoked: array<number> an array of revoked indices.
-///     },
-///     ver: string - version revocation registry delta json
-/// }
+/// revoc_reg_delta_json: Revocation registry delta json with a revoked credential (see indy_issuer_revoke_credential)
 ///
 /// #Errors
 /// Anoncreds*
 /// Common*
 /// Wallet*
 #[no_mangle]
-pub extern "C" fn indy_issuer_merge_revocation_registry_deltas(command_handle: CommandHandle,
-                                                           rev_reg_delta_json: *const c_char,
-                                                           other_rev_reg_delta_json: *const c_char,
-                                                           cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode,
-                                                                                merged_rev_reg_delta: *const c_char)>) -> ErrorCode {
-    trace!("indy_issuer_merge_revocation_registry_deltas: >>> rev_reg_delta_json: {:?}, other_rev_reg_delta_json: {:?}",
-           rev_reg_delta_json, other_rev_reg_delta_json);
+pub extern "C" fn indy_issuer_revoke_credential_by_record(command_handle: CommandHandle,
+                                                      wallet_handle: WalletHandle,
+                                                      blob_storage_reader_cfg_handle: IndyHandle,
+                                                      record_id: *const c_char,
+                                                      cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                           revoc_reg_delta_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_revoke_credential_by_record: >>> wallet_handle: {:?}, blob_storage_reader_cfg_handle: {:?}, record_id: {:?}",
+           wallet_handle, blob_storage_reader_cfg_handle, record_id);
 
-    check_useful_validatable_json!(rev_reg_delta_json, ErrorCode::CommonInvalidParam2, RevocationRegistryDelta);
-    check_useful_validatable_json!(other_rev_reg_delta_json, ErrorCode::CommonInvalidParam3, RevocationRegistryDelta);
-    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(record_id, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
 
-    trace!("indy_issuer_merge_revocation_registry_deltas: entities >>> rev_reg_delta_json: {:?}, other_rev_reg_delta_json: {:?}",
-           rev_reg_delta_json, other_rev_reg_delta_json);
+    trace!("indy_issuer_revoke_credential_by_record: entities >>> wallet_handle: {:?}, blob_storage_reader_cfg_handle: {:?}, record_id: {:?}",
+           wallet_handle, blob_storage_reader_cfg_handle, record_id);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+        .send_api_call(command_handle, Command::Anoncreds(
             AnoncredsCommand::Issuer(
-                IssuerCommand::MergeRevocationRegistryDeltas(
-                    rev_reg_delta_json,
-                    other_rev_reg_delta_json,
-                    boxed_callback_string!("indy_issuer_merge_revocation_registry_deltas", cb, command_handle)
+                IssuerCommand::RevokeCredentialByRecord(
+                    wallet_handle,
+                    blob_storage_reader_cfg_handle,
+                    record_id,
+                    boxed_callback_string!("indy_issuer_revoke_credential_by_record", cb, command_handle)
                 ))));
 
     let res = prepare_result!(result);
 
-    trace!("indy_issuer_merge_revocation_registry_deltas: <<< res: {:?}", res);
+    trace!("indy_issuer_revoke_credential_by_record: <<< res: {:?}", res);
 
     res
 }
 
-/// Creates a master secret with a given id and stores it in the wallet.
-/// The id must be unique.
+/// Get issuance record stored in the wallet.
 ///
 /// #Params
 /// command_handle: command handle to map callback to user context.
 /// wallet_handle: wallet handle (created by open_wallet).
-/// master_secret_id: (optional, if not present random one will be generated) new master id
+/// record_id: id of the issuance record
+/// cb: Callback that takes command result as parameter.
 ///
 /// #Returns
-/// out_master_secret_id: Id of generated master secret
+/// record_json: issuance record
+///     {
+///         "id": string, - id of the issuance record
+///         "cred_def_id": string, - identifier of credential definition
+///         "rev_reg_id": Optional<string>, - identifier of revocation registry
+///         "cred_rev_id": Optional<string>, - identifier of credential in the revocation registry
+///         "issued_at": number, - issuance time as unix timestamp
+///         "revoked_at": Optional<number>, - revocation time as unix timestamp
+///         "tags": object, - caller tags
+///         "values_hash": string - hex encoded sha256 hash of the salt followed by credential values json with sorted attributes
+///         "values_salt": string - hex encoded random salt of the record
+///     }
 ///
 /// #Errors
 /// Anoncreds*
 /// Common*
 /// Wallet*
 #[no_mangle]
-pub extern "C" fn indy_prover_create_master_secret(command_handle: CommandHandle,
-                                               wallet_handle: WalletHandle,
-                                               master_secret_id: *const c_char,
-                                               cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode,
-                                                                    out_master_secret_id: *const c_char)>) -> ErrorCode {
-    trace!("indy_prover_create_master_secret: >>> wallet_handle: {:?}, master_secret_id: {:?}", wallet_handle, master_secret_id);
-
-    check_useful_opt_c_str!(master_secret_id, ErrorCode::CommonInvalidParam3);
+pub extern "C" fn indy_issuer_get_issuance_record(command_handle: CommandHandle,
+                                              wallet_handle: WalletHandle,
+                                              record_id: *const c_char,
+                                              cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                   record_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_get_issuance_record: >>> wallet_handle: {:?}, record_id: {:?}", wallet_handle, record_id);
+
+    check_useful_c_str!(record_id, ErrorCode::CommonInvalidParam3);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
-    trace!("indy_prover_create_master_secret: entities >>> wallet_handle: {:?}, master_secret_id: {:?}", wallet_handle, master_secret_id);
+    trace!("indy_issuer_get_issuance_record: entities >>> wallet_handle: {:?}, record_id: {:?}", wallet_handle, record_id);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
-            AnoncredsCommand::Prover(
-                ProverCommand::CreateMasterSecret(
+        .send_api_call(command_handle, Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::GetIssuanceRecord(
                     wallet_handle,
-                    master_secret_id,
+                    record_id,
+                    boxed_callback_string!("indy_issuer_get_issuance_record", cb, command_handle)
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_get_issuance_record: <<< res: {:?}", res);
+
+    res
+}
+
+/// Search for issuance records stored in wallet.
+///
+/// Instead of immediately returning of fetched records
+/// this call returns search_handle that can be used later
+/// to fetch records by small batches (with indy_issuer_fetch_issuance_records).
+///
+/// #Params
+/// wallet_handle: wallet handle (created by open_wallet).
+/// query_json: Wql query filter for issuance records searching based on tags.
+///     where query: indy-sdk/docs/design/011-wallet-query-language/README.md
+///     In addition to caller tags, the following tags can be used:
+///         "cred_def_id": <credential definition id>,
+///         "rev_reg_id": <revocation registry id> or "None" for non-revocable credentials,
+///         "cred_rev_id": <credential revocation id>,
+///         "revoked": "1" for revoked credentials, "0" otherwise
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// search_handle: Search handle that can be used later to fetch records by small batches (with indy_issuer_fetch_issuance_records)
+/// total_count: Total count of records
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern "C" fn indy_issuer_search_issuance_records(command_handle: CommandHandle,
+                                                  wallet_handle: WalletHandle,
+                                                  query_json: *const c_char,
+                                                  cb: Option<extern "C" fn(
+                                                      command_handle_: CommandHandle, err: ErrorCode,
+                                                      search_handle: SearchHandle,
+                                                      total_count: usize)>) -> ErrorCode {
+    trace!("indy_issuer_search_issuance_records: >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
+
+    check_useful_opt_c_str!(query_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_issuer_search_issuance_records: entities >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::SearchIssuanceRecords(
+                    wallet_handle,
+                    query_json,
+                    Box::new(move |result| {
+                        let (err, handle, total_count) = prepare_result_2!(result, INVALID_SEARCH_HANDLE, 0);
+                        tracer::finish_api_call(command_handle);
+                        cb(command_handle, err, handle, total_count)
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_search_issuance_records: <<< res: {:?}", res);
+
+    res
+}
+
+/// Fetch next issuance records for search.
+///
+/// #Params
+/// search_handle: Search handle (created by indy_issuer_search_issuance_records)
+/// count: Count of records to fetch
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// records_json: List of issuance records (see indy_issuer_get_issuance_record)
+/// NOTE: The list of length less than the requested count means records search iterator is completed.
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern "C" fn indy_issuer_fetch_issuance_records(command_handle: CommandHandle,
+                                                 search_handle: SearchHandle,
+                                                 count: usize,
+                                                 cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                      records_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_fetch_issuance_records: >>> search_handle: {:?}, count: {:?}", search_handle, count);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_issuer_fetch_issuance_records: entities >>> search_handle: {:?}, count: {:?}", search_handle, count);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::FetchIssuanceRecords(
+                    search_handle,
+                    count,
+                    boxed_callback_string!("indy_issuer_fetch_issuance_records", cb, command_handle)
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_fetch_issuance_records: <<< res: {:?}", res);
+
+    res
+}
+
+/// Close issuance records search (make search handle invalid)
+///
+/// #Params
+/// search_handle: Search handle (created by indy_issuer_search_issuance_records)
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern "C" fn indy_issuer_close_issuance_records_search(command_handle: CommandHandle,
+                                                        search_handle: SearchHandle,
+                                                        cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_issuer_close_issuance_records_search: >>> search_handle: {:?}", search_handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_issuer_close_issuance_records_search: entities >>> search_handle: {:?}", search_handle);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::CloseIssuanceRecordsSearch(
+                    search_handle,
+                    Box::new(move |result| {
+                        let err = prepare_result!(result);
+                        trace!("indy_issuer_close_issuance_records_search:");
+                        tracer::finish_api_call(command_handle);
+                        cb(command_handle, err)
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_close_issuance_records_search: <<< res: {:?}", res);
+
+    res
+}
+
+/*/// Recover a credential identified by a cred_revoc_id (returned by indy_issuer_create_credential).
+///
+/// The corresponding credential definition and revocation registry must be already
+/// created an stored into the wallet.
+///
+/// This call returns revoc registry delta as json file intended to be shared as REVOC_REG_ENTRY transaction.
+/// Note that it is possible to accumulate deltas to reduce ledger load.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// blob_storage_reader_cfg_handle: configuration of blob storage reader handle that will allow to read revocation tails (returned by `indy_open_blob_storage_reader`).
+/// rev_reg_id: id of revocation registry stored in wallet
+/// cred_revoc_id: local id for revocation info related to issued credential
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// revoc_reg_delta_json: Revocation registry delta json with a recovered credential
+/// {
+///     value: {
+///         prevAccum: string - previous accumulator value.
+///         accum: string - current accumulator value.
+///         issued: array<number> an array of issued indices.
+///     },
+///     ver: string - version revocation registry delta json
+/// }
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_recover_credential(command_handle: CommandHandle,
+                                             wallet_handle: WalletHandle,
+                                             blob_storage_reader_cfg_handle: IndyHandle,
+                                             rev_reg_id: *const c_char,
+                                             cred_revoc_id: *const c_char,
+                                             cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                  revoc_reg_delta_json: *const c_char,
+                                             )>) -> ErrorCode {
+    check_useful_c_str!(rev_reg_id, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(cred_revoc_id, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::RecoverCredential(
+                    wallet_handle,
+                    blob_storage_reader_cfg_handle,
+                    rev_reg_id,
+                    cred_revoc_id,
+                    Box::new(move |result| {
+                        let (err, revoc_reg_update_json) = prepare_result_1!(result, String::new());
+                        let revoc_reg_update_json = ctypes::string_to_cstring(revoc_reg_update_json);
+                        tracer::finish_api_call(command_handle);
+                        cb(command_handle, err, revoc_reg_update_json.as_ptr())
+                    })
+                ))));
+
+    prepare_result!(result)
+}*/
+
+/// Merge two revocation registry deltas (returned by indy_issuer_create_credential or indy_issuer_revoke_credential) to accumulate common delta.
+/// Send common delta to ledger to reduce the load.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// rev_reg_delta_json: revocation registry delta.
+/// {
+///     value: {
+///         prevAccum: string - previous accumulator value.
+///         accum: string - current accumulator value.
+///         issued: array<number> an array of issued indices.
+///         revoked: array<number> an array of revoked indices.
+///     },
+///     ver: string - version revocation registry delta json
+/// }
+///
+/// other_rev_reg_delta_json: revocation registry delta for which PrevAccum value is equal to value of accum field of rev_reg_delta_json parameter.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// merged_rev_reg_delta: Merged revocation registry delta
+/// {
+///     value: {
+///         prevAccum: string - previous accumulator value.
+///         accum: string - current accumulator value.
+///         issued: array<number> an array of issued indices.
+///         revoked: array<number> an array of revoked indices.
+///     },
+///     ver: string - version revocation registry delta json
+/// }
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern "C" fn indy_issuer_merge_revocation_registry_deltas(command_handle: CommandHandle,
+                                                           rev_reg_delta_json: *const c_char,
+                                                           other_rev_reg_delta_json: *const c_char,
+                                                           cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                                merged_rev_reg_delta: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_merge_revocation_registry_deltas: >>> rev_reg_delta_json: {:?}, other_rev_reg_delta_json: {:?}",
+           rev_reg_delta_json, other_rev_reg_delta_json);
+
+    check_useful_validatable_json!(rev_reg_delta_json, ErrorCode::CommonInvalidParam2, RevocationRegistryDelta);
+    check_useful_validatable_json!(other_rev_reg_delta_json, ErrorCode::CommonInvalidParam3, RevocationRegistryDelta);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_issuer_merge_revocation_registry_deltas: entities >>> rev_reg_delta_json: {:?}, other_rev_reg_delta_json: {:?}",
+           rev_reg_delta_json, other_rev_reg_delta_json);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::MergeRevocationRegistryDeltas(
+                    rev_reg_delta_json,
+                    other_rev_reg_delta_json,
+                    boxed_callback_string!("indy_issuer_merge_revocation_registry_deltas", cb, command_handle)
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_merge_revocation_registry_deltas: <<< res: {:?}", res);
+
+    res
+}
+
+/// Creates a master secret with a given id and stores it in the wallet.
+/// The id must be unique.
+/// If the wallet has no default master secret yet, the created one becomes the default.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// master_secret_id: (optional, if not present random one will be generated) new master id
+///
+/// #Returns
+/// out_master_secret_id: Id of generated master secret
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern "C" fn indy_prover_create_master_secret(command_handle: CommandHandle,
+                                               wallet_handle: WalletHandle,
+                                               master_secret_id: *const c_char,
+                                               cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                    out_master_secret_id: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_create_master_secret: >>> wallet_handle: {:?}, master_secret_id: {:?}", wallet_handle, master_secret_id);
+
+    check_useful_opt_c_str!(master_secret_id, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_prover_create_master_secret: entities >>> wallet_handle: {:?}, master_secret_id: {:?}", wallet_handle, master_secret_id);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Anoncreds(
+            AnoncredsCommand::Prover(
+                ProverCommand::CreateMasterSecret(
+                    wallet_handle,
+                    master_secret_id,
                     boxed_callback_string!("indy_prover_create_master_secret", cb, command_handle)
                 ))));
 
     let res = prepare_result!(result);
 
-    trace!("indy_prover_create_master_secret: <<< res: {:?}", res);
+    trace!("indy_prover_create_master_secret: <<< res: {:?}", res);
+
+    res
+}
+
+/// Lists master secrets stored in the wallet.
+/// Master secret values are never returned.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// master_secrets_json: list of master secrets sorted by id
+///     [{
+///         "id": string, // master secret id
+///         "default": bool, // whether master secret is the default one
+///         "retired": bool, // whether master secret is retired by indy_prover_rotate_master_secret
+///     }]
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern "C" fn indy_prover_list_master_secrets(command_handle: CommandHandle,
+                                              wallet_handle: WalletHandle,
+                                              cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                   master_secrets_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_list_master_secrets: >>> wallet_handle: {:?}", wallet_handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_prover_list_master_secrets: entities >>> wallet_handle: {:?}", wallet_handle);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Anoncreds(
+            AnoncredsCommand::Prover(
+                ProverCommand::ListMasterSecrets(
+                    wallet_handle,
+                    boxed_callback_string!("indy_prover_list_master_secrets", cb, command_handle)
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_list_master_secrets: <<< res: {:?}", res);
+
+    res
+}
+
+/// Marks a master secret stored in the wallet as the default one.
+/// The default master secret is used by indy_prover_create_proof_with_options if master secret id is not set.
+/// A retired master secret cannot become the default one.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// master_secret_id: the id of the master secret stored in the wallet
+/// cb: Callback that takes command result as parameter.
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern "C" fn indy_prover_set_default_master_secret(command_handle: CommandHandle,
+                                                    wallet_handle: WalletHandle,
+                                                    master_secret_id: *const c_char,
+                                                    cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_prover_set_default_master_secret: >>> wallet_handle: {:?}, master_secret_id: {:?}", wallet_handle, master_secret_id);
+
+    check_useful_c_str!(master_secret_id, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_prover_set_default_master_secret: entities >>> wallet_handle: {:?}, master_secret_id: {:?}", wallet_handle, master_secret_id);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Anoncreds(
+            AnoncredsCommand::Prover(
+                ProverCommand::SetDefaultMasterSecret(
+                    wallet_handle,
+                    master_secret_id,
+                    Box::new(move |result| {
+                        let err = prepare_result!(result);
+                        trace!("indy_prover_set_default_master_secret: ");
+                        tracer::finish_api_call(command_handle);
+                        cb(command_handle, err)
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_set_default_master_secret: <<< res: {:?}", res);
+
+    res
+}
+
+/// Rotates a master secret: creates a new master secret and retires the given one.
+///
+/// A retired master secret cannot be used for new credential requests, but credentials bound to it
+/// stay usable in proofs until they are re-issued to the new master secret.
+/// If the retired master secret was the default one, the new master secret becomes the default.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// master_secret_id: the id of the master secret to retire
+/// new_master_secret_id: (optional, if not present random one will be generated) id of the new master secret
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// rotation_json:
+///     {
+///         "master_secret_id": string, // id of the new master secret
+///         "retired_master_secret_id": string, // id of the retired master secret
+///         "credentials_to_reissue": [<credential_info>], // credentials bound to the retired master secret
+///     }
+/// where credential_info:
+///     {
+///         "referent": string, // cred_id in the wallet
+///         "attrs": {"key1":"raw_value1", "key2":"raw_value2"},
+///         "schema_id": string,
+///         "cred_def_id": string,
+///         "rev_reg_id": Optional<string>,
+///         "cred_rev_id": Optional<string>
+///     }
+/// NOTE: credentials stored before master secret binding was tracked have no `master_secret_id` tag and are not reported.
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern "C" fn indy_prover_rotate_master_secret(command_handle: CommandHandle,
+                                               wallet_handle: WalletHandle,
+                                               master_secret_id: *const c_char,
+                                               new_master_secret_id: *const c_char,
+                                               cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                    rotation_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_rotate_master_secret: >>> wallet_handle: {:?}, master_secret_id: {:?}, new_master_secret_id: {:?}",
+           wallet_handle, master_secret_id, new_master_secret_id);
+
+    check_useful_c_str!(master_secret_id, ErrorCode::CommonInvalidParam3);
+    check_useful_opt_c_str!(new_master_secret_id, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_prover_rotate_master_secret: entities >>> wallet_handle: {:?}, master_secret_id: {:?}, new_master_secret_id: {:?}",
+           wallet_handle, master_secret_id, new_master_secret_id);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Anoncreds(
+            AnoncredsCommand::Prover(
+                ProverCommand::RotateMasterSecret(
+                    wallet_handle,
+                    master_secret_id,
+                    new_master_secret_id,
+                    boxed_callback_string!("indy_prover_rotate_master_secret", cb, command_handle)
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_rotate_master_secret: <<< res: {:?}", res);
 
     res
 }
@@ -951,7 +1657,7 @@ pub extern "C" fn indy_prover_create_credential_req(command_handle: CommandHandl
            wallet_handle, prover_did, cred_offer_json, cred_def_json, master_secret_id);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+        .send_api_call(command_handle, Command::Anoncreds(
             AnoncredsCommand::Prover(
                 ProverCommand::CreateCredentialRequest(
                     wallet_handle,
@@ -964,6 +1670,7 @@ pub extern "C" fn indy_prover_create_credential_req(command_handle: CommandHandl
                         trace!("indy_prover_create_credential_req: cred_req_json: {:?}, cred_req_metadata_json: {:?}", cred_req_json, cred_req_metadata_json);
                         let cred_req_json = ctypes::string_to_cstring(cred_req_json);
                         let cred_req_metadata_json = ctypes::string_to_cstring(cred_req_metadata_json);
+                        tracer::finish_api_call(command_handle);
                         cb(command_handle, err, cred_req_json.as_ptr(), cred_req_metadata_json.as_ptr())
                     })
                 ))));
@@ -999,11 +1706,20 @@ pub extern "C" fn indy_prover_create_credential_req(command_handle: CommandHandl
 ///         "attr::<attribute name>::value": <attribute raw value>,
 ///     }
 ///
+/// Attributes listed as "<attribute name>::<type>" where type is the one the schema declares for the attribute
+/// (integer, date or boolean, see indy_issuer_create_typed_schema) are tagged as well and additionally get an unencrypted tag,
+/// so the wallet can compare their values in typed queries (see indy_prover_search_credentials):
+///     {
+///         "~attr::<attribute name>::int": <encoded value in sortable form>,
+///     }
+/// Such tag is not written for values that are not valid for the type.
+///
 /// #Params
 /// command_handle: command handle to map callback to user context.
 /// wallet_handle: wallet handle (created by open_wallet).
 /// cred_def_id: credential definition id
-/// tag_attrs_json: JSON array with names of attributes to tag by policy, or null for all
+/// tag_attrs_json: JSON array with names of attributes to tag by policy, or null for all.
+///     Names given as "<attribute name>::<type>" opt in for unencrypted typed tags
 /// retroactive: boolean, whether to apply policy to existing credentials on credential definition identifier
 /// cb: Callback that takes command result as parameter.
 ///
@@ -1028,7 +1744,7 @@ pub extern "C" fn indy_prover_set_credential_attr_tag_policy(command_handle: Com
            wallet_handle, cred_def_id, tag_attrs_json, retroactive);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+        .send_api_call(command_handle, Command::Anoncreds(
             AnoncredsCommand::Prover(
                 ProverCommand::SetCredentialAttrTagPolicy(
                     wallet_handle,
@@ -1038,6 +1754,7 @@ pub extern "C" fn indy_prover_set_credential_attr_tag_policy(command_handle: Com
                     Box::new(move |result| {
                         let err = prepare_result!(result);
                         trace!("indy_prover_set_credential_attr_tag_policy: ");
+                        tracer::finish_api_call(command_handle);
                         cb(command_handle, err)
                     })
                 ))));
@@ -1082,7 +1799,7 @@ pub extern "C" fn indy_prover_get_credential_attr_tag_policy(command_handle: Com
     trace!("indy_prover_get_credential_attr_tag_policy: entities >>> wallet_handle: {:?}, cred_def_id: {:?}", wallet_handle, cred_def_id);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+        .send_api_call(command_handle, Command::Anoncreds(
             AnoncredsCommand::Prover(
                 ProverCommand::GetCredentialAttrTagPolicy(
                     wallet_handle,
@@ -1097,6 +1814,114 @@ pub extern "C" fn indy_prover_get_credential_attr_tag_policy(command_handle: Com
     res
 }
 
+/// Designate credential attribute that carries expiry of credentials of the credential definition.
+/// Expiry of subsequent and existing credentials on the credential definition is taken from this attribute.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// cred_def_id: credential definition id
+/// attr_name: (optional) name of the attribute with expiry as a unix timestamp or a date in YYYY-MM-DD format,
+///     null to stop tracking expiry of credentials on the credential definition
+/// cb: Callback that takes command result as parameter.
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern "C" fn indy_prover_set_credential_expiry_attr(command_handle: CommandHandle,
+                                                     wallet_handle: WalletHandle,
+                                                     cred_def_id: *const c_char,
+                                                     attr_name: *const c_char,
+                                                     cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_prover_set_credential_expiry_attr: >>> wallet_handle: {:?}, cred_def_id: {:?}, attr_name: {:?}", wallet_handle, cred_def_id, attr_name);
+
+    check_useful_validatable_string!(cred_def_id, ErrorCode::CommonInvalidParam3, CredentialDefinitionId);
+    check_useful_opt_c_str!(attr_name, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_prover_set_credential_expiry_attr: entities >>> wallet_handle: {:?}, cred_def_id: {:?}, attr_name: {:?}", wallet_handle, cred_def_id, attr_name);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Anoncreds(
+            AnoncredsCommand::Prover(
+                ProverCommand::SetCredentialExpiryAttr(
+                    wallet_handle,
+                    cred_def_id,
+                    attr_name,
+                    Box::new(move |result| {
+                        let err = prepare_result!(result);
+                        trace!("indy_prover_set_credential_expiry_attr: ");
+                        tracer::finish_api_call(command_handle);
+                        cb(command_handle, err)
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_set_credential_expiry_attr: <<< res: {:?}", res);
+
+    res
+}
+
+/// Designate credential attribute that identifies the subject of credentials of the credential definition.
+/// Subsequently stored or imported credential replaces the other credentials of the credential definition
+/// with the same value of this attribute (see indy_prover_get_credential_status).
+/// Credentials are never replaced while no identity attribute is designated.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// cred_def_id: credential definition id
+/// attr_name: (optional) name of the attribute identifying the subject of credentials,
+///     null to stop replacing credentials on the credential definition
+/// cb: Callback that takes command result as parameter.
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern "C" fn indy_prover_set_credential_identity_attr(command_handle: CommandHandle,
+                                                     wallet_handle: WalletHandle,
+                                                     cred_def_id: *const c_char,
+                                                     attr_name: *const c_char,
+                                                     cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_prover_set_credential_identity_attr: >>> wallet_handle: {:?}, cred_def_id: {:?}, attr_name: {:?}", wallet_handle, cred_def_id, attr_name);
+
+    check_useful_validatable_string!(cred_def_id, ErrorCode::CommonInvalidParam3, CredentialDefinitionId);
+    check_useful_opt_c_str!(attr_name, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_prover_set_credential_identity_attr: entities >>> wallet_handle: {:?}, cred_def_id: {:?}, attr_name: {:?}", wallet_handle, cred_def_id, attr_name);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Anoncreds(
+            AnoncredsCommand::Prover(
+                ProverCommand::SetCredentialIdentityAttr(
+                    wallet_handle,
+                    cred_def_id,
+                    attr_name,
+                    Box::new(move |result| {
+                        let err = prepare_result!(result);
+                        trace!("indy_prover_set_credential_identity_attr: ");
+                        tracer::finish_api_call(command_handle);
+                        cb(command_handle, err)
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_set_credential_identity_attr: <<< res: {:?}", res);
+
+    res
+}
+
 /// Check credential provided by Issuer for the given credential request,
 /// updates the credential by a master secret and stores in a secure wallet.
 ///
@@ -1109,16 +1934,29 @@ pub extern "C" fn indy_prover_get_credential_attr_tag_policy(command_handle: Com
 ///         "issuer_did": <credential issuer did>,
 ///         "cred_def_id": <credential definition id>,
 ///         "rev_reg_id": <credential revocation registry id>, // "None" as string if not present
+///         "master_secret_id": <id of master secret credential is bound to>,
 ///         // for every attribute in <credential values> that credential attribute tagging policy marks taggable
 ///         "attr::<attribute name>::marker": "1",
 ///         "attr::<attribute name>::value": <attribute raw value>,
+///         // unencrypted, only for attributes the policy lists with integer, date or boolean types (see indy_prover_set_credential_attr_tag_policy)
+///         "~attr::<attribute name>::int": <encoded value in sortable form>,
+///         // credential status, only when applicable (see indy_prover_get_credential_status)
+///         "status::revoked": "1",
+///         "~status::revoked_as_of": <timestamp in sortable form>,
+///         "~status::expires_at": <timestamp in sortable form>,
+///         "status::replaced": "1",
+///         "status::replaced_by": <id of credential that replaced this one>,
 ///     }
 ///
+/// Stored credential replaces other credentials of the same credential definition with the same value
+/// of the identity attribute (see indy_prover_set_credential_identity_attr).
+///
 /// #Params
 /// command_handle: command handle to map callback to user context.
 /// wallet_handle: wallet handle (created by open_wallet).
 /// cred_id: (optional, default is a random one) identifier by which credential will be stored in the wallet
-/// cred_req_metadata_json: a credential request metadata created by indy_prover_create_credential_req
+/// cred_req_metadata_json: a credential request metadata created by indy_prover_create_credential_req,
+///     kept in the wallet with the credential (see indy_prover_export_credential)
 /// cred_json: credential json received from issuer
 ///     {
 ///         "schema_id": string, - identifier of schema
@@ -1168,7 +2006,7 @@ pub extern "C" fn indy_prover_store_credential(command_handle: CommandHandle,
     rev_reg_def_json: {:?}", wallet_handle, cred_id, cred_req_metadata_json, cred_json, cred_def_json, rev_reg_def_json);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+        .send_api_call(command_handle, Command::Anoncreds(
             AnoncredsCommand::Prover(
                 ProverCommand::StoreCredential(
                     wallet_handle,
@@ -1224,7 +2062,7 @@ pub extern "C" fn indy_prover_get_credential(command_handle: CommandHandle,
     trace!("indy_prover_get_credential: entities >>> wallet_handle: {:?}, cred_id: {:?}", wallet_handle, cred_id);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+        .send_api_call(command_handle, Command::Anoncreds(
             AnoncredsCommand::Prover(
                 ProverCommand::GetCredential(
                     wallet_handle,
@@ -1239,6 +2077,61 @@ pub extern "C" fn indy_prover_get_credential(command_handle: CommandHandle,
     res
 }
 
+/// Gets status of the credential by the given id.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// wallet_handle: wallet handle (created by open_wallet).
+/// cred_id: Identifier by which requested credential is stored in the wallet
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// credential status json:
+///     {
+///         "referent": string, - id of credential in the wallet
+///         "revoked": bool, - whether credential is known to be revoked (see indy_prover_create_credential_revocation_state)
+///         "revoked_as_of": Optional<int>, - earliest timestamp credential is known to be revoked at
+///         "expires_at": Optional<int>, - expiry timestamp (see indy_prover_set_credential_expiry_attr)
+///         "replaced_by": Optional<string>, - id of credential with the same identity attribute value stored after this one
+///                                             (see indy_prover_set_credential_identity_attr)
+///         "active": bool - whether credential is neither revoked, nor replaced, nor expired
+///     }
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern "C" fn indy_prover_get_credential_status(command_handle: CommandHandle,
+                                                wallet_handle: WalletHandle,
+                                                cred_id: *const c_char,
+                                                cb: Option<extern "C" fn(
+                                                    command_handle_: CommandHandle, err: ErrorCode,
+                                                    status_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_get_credential_status: >>> wallet_handle: {:?}, cred_id: {:?}", wallet_handle, cred_id);
+
+    check_useful_c_str!(cred_id, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_prover_get_credential_status: entities >>> wallet_handle: {:?}, cred_id: {:?}", wallet_handle, cred_id);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Anoncreds(
+            AnoncredsCommand::Prover(
+                ProverCommand::GetCredentialStatus(
+                    wallet_handle,
+                    cred_id,
+                    boxed_callback_string!("indy_prover_get_credential_status", cb, command_handle)
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_get_credential_status: <<< res: {:?}", res);
+
+    res
+}
+
 /// Deletes credential by given id.
 ///
 /// #Params
@@ -1263,7 +2156,7 @@ pub extern "C" fn indy_prover_delete_credential(command_handle: CommandHandle,
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+        .send_api_call(command_handle, Command::Anoncreds(
             AnoncredsCommand::Prover(
                 ProverCommand::DeleteCredential(
                     wallet_handle,
@@ -1271,6 +2164,7 @@ pub extern "C" fn indy_prover_delete_credential(command_handle: CommandHandle,
                     Box::new(move |result| {
                         let err = prepare_result!(result);
                         trace!("indy_prover_delete_credential: ");
+                        tracer::finish_api_call(command_handle);
                         cb(command_handle, err)
                     })
                 ))));
@@ -1282,6 +2176,146 @@ pub extern "C" fn indy_prover_delete_credential(command_handle: CommandHandle,
     res
 }
 
+/// Exports a single credential stored in the wallet as an encrypted bundle
+/// that can be imported into another wallet with `indy_prover_import_credential`.
+///
+/// The credential is stored in the wallet already processed with its `CredentialRequestMetadata`,
+/// so it is usable only with the link secret (master secret) it was issued to.
+/// The bundle is bound to this link secret: import succeeds only into a wallet that holds the same link secret,
+/// unless the link secret itself is carried in the bundle (`include_master_secret`).
+/// Revocation information of the credential (revocation registry id, index and the revocation status known to the holder)
+/// is carried in the bundle and restored on import.
+/// The `CredentialRequestMetadata` the credential was stored with is carried in the bundle and restored on import
+/// (credentials stored before the wallet kept the metadata are exported without it).
+/// Credential tags are not exported, they are rebuilt on import according to the target wallet policy.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// cred_id: identifier by which requested credential is stored in the wallet
+/// export_config_json: export configuration
+///     {
+///         "key": string, // passphrase used to derive the bundle encryption key
+///         "key_derivation_method": Optional<string>, // algorithm to use for key derivation:
+///                                  ARGON2I_MOD - derive secured key (used by default)
+///                                  ARGON2I_INT - derive secured key (less secured but faster)
+///         "master_secret_id": Optional<string>, // id of the link secret the credential was issued to.
+///                               // The link secret is taken from the credential, if set it must match.
+///         "include_master_secret": Optional<bool>, // (false by default) carry the link secret in the bundle
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// bundle_json: encrypted credential bundle
+///     {
+///         "ver": "1.0",
+///         "key_derivation_method": string,
+///         "salt": string,
+///         "nonce": string,
+///         "ciphertext": string,
+///     }
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern "C" fn indy_prover_export_credential(command_handle: CommandHandle,
+                                            wallet_handle: WalletHandle,
+                                            cred_id: *const c_char,
+                                            export_config_json: *const c_char,
+                                            cb: Option<extern "C" fn(command_handle_: CommandHandle,
+                                                                     err: ErrorCode,
+                                                                     bundle_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_export_credential: >>> wallet_handle: {:?}, cred_id: {:?}", wallet_handle, cred_id);
+
+    check_useful_c_str!(cred_id, ErrorCode::CommonInvalidParam3);
+    check_useful_validatable_json!(export_config_json, ErrorCode::CommonInvalidParam4, CredentialExportConfig);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_prover_export_credential: entities >>> wallet_handle: {:?}, cred_id: {:?}, export_config_json: {:?}",
+           wallet_handle, cred_id, secret!(&export_config_json));
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Anoncreds(
+            AnoncredsCommand::Prover(
+                ProverCommand::ExportCredential(
+                    wallet_handle,
+                    cred_id,
+                    export_config_json,
+                    boxed_callback_string!("indy_prover_export_credential", cb, command_handle)
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_export_credential: <<< res: {:?}", res);
+
+    res
+}
+
+/// Imports a credential from an encrypted bundle created by `indy_prover_export_credential`.
+///
+/// If the wallet holds a link secret with the target id it must be the link secret the credential was issued to.
+/// If the wallet does not hold it, the bundle must carry the link secret, which is stored in the wallet as well.
+/// Credential tags are built according to the credential attr tag policy of the wallet
+/// (see `indy_prover_set_credential_attr_tag_policy`).
+/// The bundle is validated completely before anything is written, and the link secret, the credential
+/// and its `CredentialRequestMetadata` are stored together: if one of the writes fails, the others are undone.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// bundle_json: encrypted credential bundle (see `indy_prover_export_credential`),
+///     bundles with RAW key derivation method are rejected
+/// import_config_json: import configuration
+///     {
+///         "key": string, // passphrase used on export
+///         "cred_id": Optional<string>, // id to store the credential with (the exported id by default)
+///         "master_secret_id": Optional<string>, // id of the local link secret (the exported id by default)
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// out_cred_id: identifier by which credential is stored in the wallet
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern "C" fn indy_prover_import_credential(command_handle: CommandHandle,
+                                            wallet_handle: WalletHandle,
+                                            bundle_json: *const c_char,
+                                            import_config_json: *const c_char,
+                                            cb: Option<extern "C" fn(command_handle_: CommandHandle,
+                                                                     err: ErrorCode,
+                                                                     out_cred_id: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_import_credential: >>> wallet_handle: {:?}, bundle_json: {:?}", wallet_handle, bundle_json);
+
+    check_useful_validatable_json!(bundle_json, ErrorCode::CommonInvalidParam3, CredentialBundle);
+    check_useful_validatable_json!(import_config_json, ErrorCode::CommonInvalidParam4, CredentialImportConfig);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_prover_import_credential: entities >>> wallet_handle: {:?}, bundle_json: {:?}, import_config_json: {:?}",
+           wallet_handle, bundle_json, secret!(&import_config_json));
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Anoncreds(
+            AnoncredsCommand::Prover(
+                ProverCommand::ImportCredential(
+                    wallet_handle,
+                    bundle_json,
+                    import_config_json,
+                    boxed_callback_string!("indy_prover_import_credential", cb, command_handle)
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_import_credential: <<< res: {:?}", res);
+
+    res
+}
+
 /// Gets human readable credentials according to the filter.
 /// If filter is NULL, then all credentials are returned.
 /// Credentials can be filtered by Issuer, credential_def and/or Schema.
@@ -1299,6 +2333,7 @@ pub extern "C" fn indy_prover_delete_credential(command_handle: CommandHandle,
 ///            "schema_version": string, (Optional)
 ///            "issuer_did": string, (Optional)
 ///            "cred_def_id": string, (Optional)
+///            "master_secret_id": string, (Optional)
 ///        }
 /// cb: Callback that takes command result as parameter.
 ///
@@ -1333,7 +2368,7 @@ pub extern "C" fn indy_prover_get_credentials(command_handle: CommandHandle,
     trace!("indy_prover_get_credentials: entities >>> wallet_handle: {:?}, filter_json: {:?}", wallet_handle, filter_json);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+        .send_api_call(command_handle, Command::Anoncreds(
             AnoncredsCommand::Prover(
                 ProverCommand::GetCredentials(
                     wallet_handle,
@@ -1359,6 +2394,12 @@ pub extern "C" fn indy_prover_get_credentials(command_handle: CommandHandle,
 /// wallet_handle: wallet handle (created by open_wallet).
 /// query_json: Wql query filter for credentials searching based on tags.
 ///     where query: indy-sdk/docs/design/011-wallet-query-language/README.md
+///     In addition to the tags, the following keys can be compared with $gt, $gte, $lt, $lte, $eq, $neq and $in operators:
+///         "attr::<attribute name>::int": <32-bit integer> - for attributes with integer or boolean (1 or 0) values
+///         "attr::<attribute name>::date": <date in YYYY-MM-DD format> - for attributes with date values
+///     Only attributes the credential attribute tag policy lists with types can be compared (see indy_prover_set_credential_attr_tag_policy).
+///     The following key selects credentials by status (see indy_prover_get_credential_status):
+///         "status::active": "1" - for active credentials, "0" - for revoked, replaced or expired ones
 /// cb: Callback that takes command result as parameter.
 ///
 /// #Returns
@@ -1385,13 +2426,14 @@ pub extern "C" fn indy_prover_search_credentials(command_handle: CommandHandle,
     trace!("indy_prover_search_credentials: entities >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+        .send_api_call(command_handle, Command::Anoncreds(
             AnoncredsCommand::Prover(
                 ProverCommand::SearchCredentials(
                     wallet_handle,
                     query_json,
                     Box::new(move |result| {
                         let (err, handle, total_count) = prepare_result_2!(result, INVALID_SEARCH_HANDLE, 0);
+                        tracer::finish_api_call(command_handle);
                         cb(command_handle, err, handle, total_count)
                     })
                 ))));
@@ -1439,7 +2481,7 @@ pub  extern "C" fn indy_prover_fetch_credentials(command_handle: CommandHandle,
     trace!("indy_prover_fetch_credentials: entities >>> search_handle: {:?}, count: {:?}", search_handle, count);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+        .send_api_call(command_handle, Command::Anoncreds(
             AnoncredsCommand::Prover(
                 ProverCommand::FetchCredentials(
                     search_handle,
@@ -1474,13 +2516,14 @@ pub  extern "C" fn indy_prover_close_credentials_search(command_handle: CommandH
     trace!("indy_prover_close_credentials_search: entities >>> search_handle: {:?}", search_handle);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+        .send_api_call(command_handle, Command::Anoncreds(
             AnoncredsCommand::Prover(
                 ProverCommand::CloseCredentialsSearch(
                     search_handle,
                     Box::new(move |result| {
                         let err = prepare_result!(result);
                         trace!("indy_prover_close_credentials_search:");
+                        tracer::finish_api_call(command_handle);
                         cb(command_handle, err)
                     })
                 ))));
@@ -1605,7 +2648,7 @@ pub extern "C" fn indy_prover_get_credentials_for_proof_req(command_handle: Comm
            wallet_handle, proof_request_json);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+        .send_api_call(command_handle, Command::Anoncreds(
             AnoncredsCommand::Prover(
                 ProverCommand::GetCredentialsForProofReq(
                     wallet_handle,
@@ -1695,9 +2738,16 @@ pub extern "C" fn indy_prover_get_credentials_for_proof_req(command_handle: Comm
 ///         "issuer_did": <credential issuer did>,
 ///         "cred_def_id": <credential definition id>,
 ///         "rev_reg_id": <credential revocation registry id>, // "None" as string if not present
+///         "master_secret_id": <id of master secret credential is bound to>,
 ///         // the following keys can be used for every `attribute name` in credential.
 ///         "attr::<attribute name>::marker": "1", - to filter based on existence of a specific attribute
 ///         "attr::<attribute name>::value": <attribute raw value>, - to filter based on value of a specific attribute
+///         // the following keys can be compared with $gt, $gte, $lt, $lte, $eq, $neq and $in operators.
+///         "attr::<attribute name>::int": <32-bit integer>, - for attributes with integer or boolean (1 or 0) values
+///         "attr::<attribute name>::date": <date in YYYY-MM-DD format>, - for attributes with date values
+///         // (only for attributes the credential attribute tag policy lists with types, see indy_prover_set_credential_attr_tag_policy)
+///         // the following key selects credentials by status (see indy_prover_get_credential_status).
+///         "status::active": "1", - for active credentials, "0" - for revoked, replaced or expired ones
 ///
 /// cb: Callback that takes command result as parameter.
 ///
@@ -1726,7 +2776,7 @@ pub extern "C" fn indy_prover_search_credentials_for_proof_req(command_handle: C
            wallet_handle, proof_request_json, extra_query_json);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+        .send_api_call(command_handle, Command::Anoncreds(
             AnoncredsCommand::Prover(
                 ProverCommand::SearchCredentialsForProofReq(
                     wallet_handle,
@@ -1735,6 +2785,7 @@ pub extern "C" fn indy_prover_search_credentials_for_proof_req(command_handle: C
                     Box::new(move |result| {
                         let (err, search_handle) = prepare_result_1!(result, INVALID_SEARCH_HANDLE);
                         trace!("indy_prover_search_credentials_for_proof_req: search_handle: {:?}", search_handle);
+                        tracer::finish_api_call(command_handle);
                         cb(command_handle, err, search_handle)
                     }),
                 ))));
@@ -1798,7 +2849,7 @@ pub  extern "C" fn indy_prover_fetch_credentials_for_proof_req(command_handle: C
     trace!("indy_prover_fetch_credentials_for_proof_req: entities >>> search_handle: {:?}, count: {:?}", search_handle, count);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+        .send_api_call(command_handle, Command::Anoncreds(
             AnoncredsCommand::Prover(
                 ProverCommand::FetchCredentialForProofReq(
                     search_handle,
@@ -1834,20 +2885,111 @@ pub  extern "C" fn indy_prover_close_credentials_search_for_proof_req(command_ha
     trace!("indy_prover_close_credentials_search_for_proof_req: entities >>> search_handle: {:?}", search_handle);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+        .send_api_call(command_handle, Command::Anoncreds(
             AnoncredsCommand::Prover(
                 ProverCommand::CloseCredentialsSearchForProofReq(
                     search_handle,
                     Box::new(move |result| {
                         let err = prepare_result!(result);
                         trace!("indy_prover_close_credentials_search:");
+                        tracer::finish_api_call(command_handle);
                         cb(command_handle, err)
                     }),
                 ))));
 
     let res = prepare_result!(result);
 
-    trace!("indy_prover_close_credentials_search_for_proof_req: <<< res: {:?}", res);
+    trace!("indy_prover_close_credentials_search_for_proof_req: <<< res: {:?}", res);
+
+    res
+}
+
+/// Explains why credentials stored in the wallet can or can not satisfy the given proof request.
+///
+/// For every requested attribute and predicate referent the function looks through all stored credentials
+/// that contain the requested attribute(s) and reports for each of them which restriction, extra query
+/// or predicate is not satisfied. It also reports problems related to non-revocation interval.
+///
+/// NOTE: This function is intended for diagnostics only and explains at most 100 credentials per referent.
+/// Use indy_prover_search_credentials_for_proof_req to get credentials for building a proof.
+///
+/// #Params
+/// wallet_handle: wallet handle (created by open_wallet).
+/// proof_request_json: proof request json (same format as for indy_prover_search_credentials_for_proof_req)
+/// extra_query_json:(Optional) List of extra queries that will be applied to correspondent attribute/predicate
+///     (same format as for indy_prover_search_credentials_for_proof_req)
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// diagnostics_json: json with explanation per referent:
+///     {
+///         "satisfiable": bool, - whether every referent can be satisfied by at least one credential
+///         "requested_attributes": {
+///             "<attr_referent>": <referent_diagnostics>,
+///         },
+///         "requested_predicates": {
+///             "<predicate_referent>": <referent_diagnostics>,
+///         },
+///     }
+/// where
+/// referent_diagnostics:
+///     {
+///         "satisfiable": bool,
+///         "non_revoked": Optional<non_revoc_interval>, - effective non-revocation interval for the referent
+///         "credentials": [{ - stored credentials containing the requested attribute(s)
+///             "cred_info": <credential_info>, - see indy_prover_get_credentials_for_proof_req
+///             "satisfies": bool,
+///             "issues": [<issue>] - why the credential does not satisfy the referent
+///         }],
+///         "issues": [<issue>] - referent level problems
+///         "truncated": bool, - whether there are more stored credentials containing the requested attribute(s)
+///                              than reported in "credentials"
+///     }
+/// issue:
+///     {
+///         "reason": string, - one of "no_credential_with_attribute", "restriction_mismatch",
+///                             "predicate_not_satisfied", "invalid_attribute_value",
+///                             "credential_not_revocable", "invalid_interval"
+///         "message": string, - human readable explanation
+///         "restriction": Optional<wql query>, - failed restriction clause (for "restriction_mismatch")
+///         "tag": Optional<string>, - credential tag checked by the failed clause (f.e. "schema_id", "attr::name::value")
+///         "actual": Optional<string>, - actual value of the tag (absent if credential has no such tag)
+///     }
+/// NOTE: If restrictions contain several alternatives ("$or") the issues of the closest alternative are reported.
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern "C" fn indy_prover_explain_proof_req(command_handle: CommandHandle,
+                                            wallet_handle: WalletHandle,
+                                            proof_request_json: *const c_char,
+                                            extra_query_json: *const c_char,
+                                            cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                     diagnostics_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_explain_proof_req: >>> wallet_handle: {:?}, proof_request_json: {:?}, extra_query_json: {:?}", wallet_handle, proof_request_json, extra_query_json);
+
+    check_useful_validatable_json!(proof_request_json, ErrorCode::CommonInvalidParam3, ProofRequest);
+    check_useful_opt_json!(extra_query_json, ErrorCode::CommonInvalidParam4, ProofRequestExtraQuery);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_prover_explain_proof_req: entities >>> wallet_handle: {:?}, proof_request_json: {:?}, extra_query_json: {:?}",
+           wallet_handle, proof_request_json, extra_query_json);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Anoncreds(
+            AnoncredsCommand::Prover(
+                ProverCommand::ExplainProofReq(
+                    wallet_handle,
+                    proof_request_json,
+                    extra_query_json,
+                    boxed_callback_string!("indy_prover_explain_proof_req", cb, command_handle)
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_explain_proof_req: <<< res: {:?}", res);
 
     res
 }
@@ -2045,14 +3187,15 @@ pub extern "C" fn indy_prover_create_proof(command_handle: CommandHandle,
            wallet_handle, proof_req_json, requested_credentials_json, master_secret_id, schemas_json, credential_defs_json, rev_states_json);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::CreateProof(
+        .send_api_call(command_handle, Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::CreateProof(
             wallet_handle,
             proof_req_json,
             requested_credentials_json,
-            master_secret_id,
+            Some(master_secret_id),
             schemas_json,
             credential_defs_json,
             rev_states_json,
+            CreateProofOptions::default(),
             boxed_callback_string!("indy_prover_create_proof", cb, command_handle)
         ))));
 
@@ -2063,6 +3206,88 @@ pub extern "C" fn indy_prover_create_proof(command_handle: CommandHandle,
     res
 }
 
+/// Creates a proof according to the given proof request with additional proof creation options.
+///
+/// Performs the same as `indy_prover_create_proof`, but master secret can be omitted
+/// and the checks of credentials binding to master secrets can be relaxed in `options_json`.
+///
+/// Every stored credential is bound to the master secret it was requested with (see `master_secret_id` credential tag).
+/// By default proof creation fails if some of requested credentials is bound to other master secret than `master_secret_id`.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// proof_req_json: proof request json (see indy_prover_create_proof)
+/// requested_credentials_json: either a credential or self-attested attribute for each requested attribute (see indy_prover_create_proof)
+/// master_secret_id: (optional, default master secret is used if not set) the id of the master secret stored in the wallet
+/// schemas_json: all schemas participating in the proof request (see indy_prover_create_proof)
+/// credential_defs_json: all credential definitions participating in the proof request (see indy_prover_create_proof)
+/// rev_states_json: all revocation states participating in the proof request (see indy_prover_create_proof)
+/// options_json: proof creation options
+///     {
+///         "allow_mixed_master_secrets": Optional<bool>, // (false by default) allow credentials bound to different master secrets in one proof.
+///                               // Every credential is proved with its own master secret,
+///                               // so the proof no longer shows that all credentials belong to the same prover.
+///                               // indy_verifier_verify_proof doesn't compare master secrets of the credentials and accepts such proof.
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Proof json (see indy_prover_create_proof)
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern "C" fn indy_prover_create_proof_with_options(command_handle: CommandHandle,
+                                                    wallet_handle: WalletHandle,
+                                                    proof_req_json: *const c_char,
+                                                    requested_credentials_json: *const c_char,
+                                                    master_secret_id: *const c_char,
+                                                    schemas_json: *const c_char,
+                                                    credential_defs_json: *const c_char,
+                                                    rev_states_json: *const c_char,
+                                                    options_json: *const c_char,
+                                                    cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                         proof_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_create_proof_with_options: >>> wallet_handle: {:?}, proof_req_json: {:?}, requested_credentials_json: {:?}, master_secret_id: {:?}, \
+    schemas_json: {:?}, credential_defs_json: {:?}, rev_states_json: {:?}, options_json: {:?}",
+           wallet_handle, proof_req_json, requested_credentials_json, master_secret_id, schemas_json, credential_defs_json, rev_states_json, options_json);
+
+    check_useful_validatable_json!(proof_req_json, ErrorCode::CommonInvalidParam3, ProofRequest);
+    check_useful_validatable_json!(requested_credentials_json, ErrorCode::CommonInvalidParam4, RequestedCredentials);
+    check_useful_opt_c_str!(master_secret_id, ErrorCode::CommonInvalidParam5);
+    check_useful_json!(schemas_json, ErrorCode::CommonInvalidParam6, Schemas);
+    check_useful_json!(credential_defs_json, ErrorCode::CommonInvalidParam7, CredentialDefinitions);
+    check_useful_json!(rev_states_json, ErrorCode::CommonInvalidParam8, RevocationStates);
+    check_useful_json!(options_json, ErrorCode::CommonInvalidParam9, CreateProofOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam10);
+
+    trace!("indy_prover_create_proof_with_options: entities >>> wallet_handle: {:?}, proof_req_json: {:?}, requested_credentials_json: {:?}, master_secret_id: {:?}, \
+    schemas_json: {:?}, credential_defs_json: {:?}, rev_states_json: {:?}, options_json: {:?}",
+           wallet_handle, proof_req_json, requested_credentials_json, master_secret_id, schemas_json, credential_defs_json, rev_states_json, options_json);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::CreateProof(
+            wallet_handle,
+            proof_req_json,
+            requested_credentials_json,
+            master_secret_id,
+            schemas_json,
+            credential_defs_json,
+            rev_states_json,
+            options_json,
+            boxed_callback_string!("indy_prover_create_proof_with_options", cb, command_handle)
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_create_proof_with_options: <<< res: {:?}", res);
+
+    res
+}
+
 /// Verifies a proof (of multiple credential).
 /// All required schemas, public keys and revocation registries must be provided.
 ///
@@ -2237,17 +3462,19 @@ pub extern "C" fn indy_verifier_verify_proof(command_handle: CommandHandle,
     rev_reg_defs_json: {:?}, rev_regs_json: {:?}", proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(AnoncredsCommand::Verifier(VerifierCommand::VerifyProof(
+        .send_api_call(command_handle, Command::Anoncreds(AnoncredsCommand::Verifier(VerifierCommand::VerifyProof(
             proof_request_json,
             proof_json,
             schemas_json,
             credential_defs_json,
             rev_reg_defs_json,
             rev_regs_json,
+            VerifyProofOptions::default(),
             Box::new(move |result| {
                 let (err, valid) = prepare_result_1!(result, false);
                 trace!("indy_verifier_verify_proof: valid: {:?}", valid);
 
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err, valid)
             })
         ))));
@@ -2259,6 +3486,267 @@ pub extern "C" fn indy_verifier_verify_proof(command_handle: CommandHandle,
     res
 }
 
+/// Verifies a proof (of multiple credential) with additional verification options.
+///
+/// Performs the same checks as `indy_verifier_verify_proof` plus the optional checks enabled in `options_json`.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// proof_request_json: proof request json (see indy_verifier_verify_proof)
+/// proof_json: created for request proof json (see indy_verifier_verify_proof)
+/// schemas_json: all schemas participating in the proof (see indy_verifier_verify_proof)
+/// credential_defs_json: all credential definitions participating in the proof (see indy_verifier_verify_proof)
+/// rev_reg_defs_json: all revocation registry definitions participating in the proof (see indy_verifier_verify_proof)
+/// rev_regs_json: all revocation registries participating in the proof (see indy_verifier_verify_proof)
+/// options_json: verification options
+///     {
+///         "check_raw_encoding": Optional<bool>, // (false by default) check that every revealed raw value
+///                               // re-encodes to the proven encoded value (see indy_encode_credential_values).
+///                               // Proof is rejected if an issuer made raw and encoded values disagree.
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// valid: true - if signature is valid, false - otherwise
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern "C" fn indy_verifier_verify_proof_with_options(command_handle: CommandHandle,
+                                                      proof_request_json: *const c_char,
+                                                      proof_json: *const c_char,
+                                                      schemas_json: *const c_char,
+                                                      credential_defs_json: *const c_char,
+                                                      rev_reg_defs_json: *const c_char,
+                                                      rev_regs_json: *const c_char,
+                                                      options_json: *const c_char,
+                                                      cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                               valid: bool)>) -> ErrorCode {
+    trace!("indy_verifier_verify_proof_with_options: >>> proof_request_json: {:?}, proof_json: {:?}, schemas_json: {:?}, credential_defs_json: {:?}, \
+    rev_reg_defs_json: {:?}, rev_regs_json: {:?}, options_json: {:?}", proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json, options_json);
+
+    check_useful_validatable_json!(proof_request_json, ErrorCode::CommonInvalidParam2, ProofRequest);
+    check_useful_validatable_json!(proof_json, ErrorCode::CommonInvalidParam3, Proof);
+    check_useful_json!(schemas_json, ErrorCode::CommonInvalidParam4, Schemas);
+    check_useful_json!(credential_defs_json, ErrorCode::CommonInvalidParam5, CredentialDefinitions);
+    check_useful_json!(rev_reg_defs_json, ErrorCode::CommonInvalidParam6, RevocationRegistryDefinitions);
+    check_useful_json!(rev_regs_json, ErrorCode::CommonInvalidParam7, RevocationRegistries);
+    check_useful_json!(options_json, ErrorCode::CommonInvalidParam8, VerifyProofOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam9);
+
+    trace!("indy_verifier_verify_proof_with_options: entities >>> proof_request_json: {:?}, proof_json: {:?}, schemas_json: {:?}, credential_defs_json: {:?}, \
+    rev_reg_defs_json: {:?}, rev_regs_json: {:?}, options_json: {:?}", proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json, options_json);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Anoncreds(AnoncredsCommand::Verifier(VerifierCommand::VerifyProof(
+            proof_request_json,
+            proof_json,
+            schemas_json,
+            credential_defs_json,
+            rev_reg_defs_json,
+            rev_regs_json,
+            options_json,
+            Box::new(move |result| {
+                let (err, valid) = prepare_result_1!(result, false);
+                trace!("indy_verifier_verify_proof_with_options: valid: {:?}", valid);
+
+                tracer::finish_api_call(command_handle);
+                cb(command_handle, err, valid)
+            })
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_verifier_verify_proof_with_options: <<< res: {:?}", res);
+
+    res
+}
+
+/// Verifies a proof (of multiple credential) and returns a detailed verification report
+/// instead of a bare boolean.
+///
+/// The same checks as `indy_verifier_verify_proof` are performed, but they do not stop on the first failure.
+/// Every requested attribute, predicate and sub proof gets its own result with the list of failure reasons,
+/// so an application can tell a restriction mismatch from a revoked credential or a missing ledger object.
+/// In addition to `indy_verifier_verify_proof` checks, timestamps are validated against requested non-revocation intervals
+/// and reported as "timestamp_outside_interval" failures.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context
+/// proof_request_json: proof request json (see `indy_verifier_verify_proof`)
+/// proof_json: created for request proof json (see `indy_verifier_verify_proof`)
+/// schemas_json: all schemas participating in the proof
+/// credential_defs_json: all credential definitions participating in the proof
+/// rev_reg_defs_json: all revocation registry definitions participating in the proof
+/// rev_regs_json: all revocation registries participating in the proof
+/// options_json: verification options (see `indy_verifier_verify_proof_with_options`)
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// report_json: verification report json
+///     {
+///         "valid": bool, // true if there are no failures in the report
+///         "requested_attributes": {
+///             "<attr_referent>": <referent_report>,
+///         },
+///         "requested_predicates": {
+///             "<predicate_referent>": <referent_report>,
+///         },
+///         "sub_proofs": [{
+///             "sub_proof_index": int,
+///             "schema_id": string,
+///             "cred_def_id": string,
+///             "rev_reg_id": Optional<string>,
+///             "timestamp": Optional<int>,
+///             "non_revocation_checked": bool,
+///             "failures": [<failure>]
+///         }],
+///         "failures": [<failure>] // proof level failures: unexpected referents, crypto verification
+///     }
+/// where
+/// referent_report:
+///     {
+///         "valid": bool,
+///         "sub_proof_index": Optional<int>,
+///         "revealed": bool,
+///         "self_attested": bool,
+///         "timestamp": Optional<int>, // timestamp used by prover
+///         "non_revoked": Optional<<non_revoc_interval>>, // effective requested interval
+///         "failures": [<failure>]
+///     }
+/// failure:
+///     {
+///         "reason": one of "missing_referent", "unexpected_referent", "restriction_mismatch",
+///                   "revealed_attribute_mismatch", // revealed attribute does not correspond to the request or the crypto proof
+///                   "encoded_value_mismatch", "raw_value_mismatch", // raw_value_mismatch only if `check_raw_encoding` option is set
+///                   "missing_timestamp", "timestamp_outside_interval",
+///                   "missing_schema", "missing_credential_definition", "missing_revocation_registry_definition",
+///                   "missing_revocation_registry", "crypto_verification_failed",
+///         "message": string
+///     }
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern "C" fn indy_verifier_verify_proof_detailed(command_handle: CommandHandle,
+                                                  proof_request_json: *const c_char,
+                                                  proof_json: *const c_char,
+                                                  schemas_json: *const c_char,
+                                                  credential_defs_json: *const c_char,
+                                                  rev_reg_defs_json: *const c_char,
+                                                  rev_regs_json: *const c_char,
+                                                  options_json: *const c_char,
+                                                  cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                       report_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_verifier_verify_proof_detailed: >>> proof_request_json: {:?}, proof_json: {:?}, schemas_json: {:?}, credential_defs_json: {:?}, \
+    rev_reg_defs_json: {:?}, rev_regs_json: {:?}, options_json: {:?}", proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json, options_json);
+
+    check_useful_validatable_json!(proof_request_json, ErrorCode::CommonInvalidParam2, ProofRequest);
+    check_useful_validatable_json!(proof_json, ErrorCode::CommonInvalidParam3, Proof);
+    check_useful_json!(schemas_json, ErrorCode::CommonInvalidParam4, Schemas);
+    check_useful_json!(credential_defs_json, ErrorCode::CommonInvalidParam5, CredentialDefinitions);
+    check_useful_json!(rev_reg_defs_json, ErrorCode::CommonInvalidParam6, RevocationRegistryDefinitions);
+    check_useful_json!(rev_regs_json, ErrorCode::CommonInvalidParam7, RevocationRegistries);
+    check_useful_json!(options_json, ErrorCode::CommonInvalidParam8, VerifyProofOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam9);
+
+    trace!("indy_verifier_verify_proof_detailed: entities >>> proof_request_json: {:?}, proof_json: {:?}, schemas_json: {:?}, credential_defs_json: {:?}, \
+    rev_reg_defs_json: {:?}, rev_regs_json: {:?}, options_json: {:?}", proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json, options_json);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Anoncreds(AnoncredsCommand::Verifier(VerifierCommand::VerifyProofDetailed(
+            proof_request_json,
+            proof_json,
+            schemas_json,
+            credential_defs_json,
+            rev_reg_defs_json,
+            rev_regs_json,
+            options_json,
+            boxed_callback_string!("indy_verifier_verify_proof_detailed", cb, command_handle)
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_verifier_verify_proof_detailed: <<< res: {:?}", res);
+
+    res
+}
+
+/// Verifies a proof wrapped into a W3C Verifiable Presentation (see `indy_proof_to_w3c`).
+///
+/// The CL proof is restored from the `proof` sections of the presentation and its credentials
+/// and checked in the same way as `indy_verifier_verify_proof` does.
+/// In addition the attribute values shown in `credentialSubject` of every presented credential
+/// must be exactly the values revealed by the proof, otherwise the presentation is rejected.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// proof_request_json: proof request json (see `indy_verifier_verify_proof`)
+/// w3c_presentation_json: W3C Verifiable Presentation json (see `indy_proof_to_w3c`)
+/// schemas_json: all schemas participating in the proof (see `indy_verifier_verify_proof`)
+/// credential_defs_json: all credential definitions participating in the proof (see `indy_verifier_verify_proof`)
+/// rev_reg_defs_json: all revocation registry definitions participating in the proof (see `indy_verifier_verify_proof`)
+/// rev_regs_json: all revocation registries participating in the proof (see `indy_verifier_verify_proof`)
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// valid: true - if signature is valid, false - otherwise
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+#[no_mangle]
+pub extern "C" fn indy_verifier_verify_w3c_presentation(command_handle: CommandHandle,
+                                                     proof_request_json: *const c_char,
+                                                     w3c_presentation_json: *const c_char,
+                                                     schemas_json: *const c_char,
+                                                     credential_defs_json: *const c_char,
+                                                     rev_reg_defs_json: *const c_char,
+                                                     rev_regs_json: *const c_char,
+                                                     cb: Option<extern "C" fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                              valid: bool)>) -> ErrorCode {
+    trace!("indy_verifier_verify_w3c_presentation: >>> proof_request_json: {:?}, w3c_presentation_json: {:?}, schemas_json: {:?}, credential_defs_json: {:?}, \
+    rev_reg_defs_json: {:?}, rev_regs_json: {:?}", proof_request_json, w3c_presentation_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json);
+
+    check_useful_validatable_json!(proof_request_json, ErrorCode::CommonInvalidParam2, ProofRequest);
+    check_useful_validatable_json!(w3c_presentation_json, ErrorCode::CommonInvalidParam3, W3CPresentation);
+    check_useful_json!(schemas_json, ErrorCode::CommonInvalidParam4, Schemas);
+    check_useful_json!(credential_defs_json, ErrorCode::CommonInvalidParam5, CredentialDefinitions);
+    check_useful_json!(rev_reg_defs_json, ErrorCode::CommonInvalidParam6, RevocationRegistryDefinitions);
+    check_useful_json!(rev_regs_json, ErrorCode::CommonInvalidParam7, RevocationRegistries);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+
+    trace!("indy_verifier_verify_w3c_presentation: entities >>> proof_request_json: {:?}, w3c_presentation_json: {:?}, schemas_json: {:?}, credential_defs_json: {:?}, \
+    rev_reg_defs_json: {:?}, rev_regs_json: {:?}", proof_request_json, w3c_presentation_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Anoncreds(AnoncredsCommand::Verifier(VerifierCommand::VerifyW3CPresentation(
+            proof_request_json,
+            w3c_presentation_json,
+            schemas_json,
+            credential_defs_json,
+            rev_reg_defs_json,
+            rev_regs_json,
+            Box::new(move |result| {
+                let (err, valid) = prepare_result_1!(result, false);
+                trace!("indy_verifier_verify_w3c_presentation: valid: {:?}", valid);
+
+                tracer::finish_api_call(command_handle);
+                cb(command_handle, err, valid)
+            })
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_verifier_verify_w3c_presentation: <<< res: {:?}", res);
+
+    res
+}
+
 /// Create revocation state for a credential that corresponds to a particular time.
 ///
 /// Note that revocation delta must cover the whole registry existence time.
@@ -2271,59 +3759,123 @@ pub extern "C" fn indy_verifier_verify_proof(command_handle: CommandHandle,
 /// #Params
 /// command_handle: command handle to map callback to user context
 /// blob_storage_reader_handle: configuration of blob storage reader handle that will allow to read revocation tails (returned by `indy_open_blob_storage_reader`)
-/// rev_reg_def_json: revocation registry definition json related to `rev_reg_id` in a credential
+/// rev_reg_def_json: revocation registry definition json related to `rev_reg_id` in a credential
+/// rev_reg_delta_json: revocation registry delta which covers the whole registry existence time
+/// timestamp: time represented as a total number of seconds from Unix Epoch.
+/// cred_rev_id: user credential revocation id in revocation registry (match to `cred_rev_id` in a credential)
+/// cb: Callback that takes command result as parameter
+///
+/// #Returns
+/// revocation state json:
+///     {
+///         "rev_reg": <revocation registry>,
+///         "witness": <witness>,  (opaque type that contains data structures internal to Ursa.
+///                                 It should not be parsed and are likely to change in future versions).
+///         "timestamp" : integer
+///     }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Anoncreds*
+#[no_mangle]
+pub extern "C" fn indy_create_revocation_state(command_handle: CommandHandle,
+                                           blob_storage_reader_handle: IndyHandle,
+                                           rev_reg_def_json: *const c_char,
+                                           rev_reg_delta_json: *const c_char,
+                                           timestamp: u64,
+                                           cred_rev_id: *const c_char,
+                                           cb: Option<extern "C" fn(
+                                               command_handle_: CommandHandle, err: ErrorCode,
+                                               rev_state_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_create_revocation_state: >>> blob_storage_reader_handle: {:?}, rev_reg_def_json: {:?}, rev_reg_delta_json: {:?}, timestamp: {:?}, \
+    cred_rev_id: {:?}", blob_storage_reader_handle, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_rev_id);
+
+    check_useful_validatable_json!(rev_reg_def_json, ErrorCode::CommonInvalidParam3, RevocationRegistryDefinition);
+    check_useful_validatable_json!(rev_reg_delta_json, ErrorCode::CommonInvalidParam4, RevocationRegistryDelta);
+    check_useful_c_str!(cred_rev_id, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_create_revocation_state: entities >>> blob_storage_reader_handle: {:?}, rev_reg_def_json: {:?}, rev_reg_delta_json: {:?}, timestamp: {:?}, \
+    cred_rev_id: {:?}", blob_storage_reader_handle, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_rev_id);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::CreateRevocationState(
+            blob_storage_reader_handle,
+            rev_reg_def_json,
+            rev_reg_delta_json,
+            timestamp,
+            cred_rev_id,
+            boxed_callback_string!("indy_create_revocation_state", cb, command_handle)
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_create_revocation_state: <<< res: {:?}", res);
+
+    res
+}
+
+/// Create revocation state for a credential stored in the wallet that corresponds to a particular time.
+/// Works as `indy_create_revocation_state`, taking credential revocation id from the credential.
+/// If the credential is revoked according to the revocation delta, the credential is marked as revoked in the wallet.
+/// If the delta issues the credential after the known revocation (the issuer recovered it), the revoked mark is cleared.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to user context
+/// wallet_handle: wallet handle (created by open_wallet).
+/// blob_storage_reader_handle: configuration of blob storage reader handle that will allow to read revocation tails (returned by `indy_open_blob_storage_reader`)
+/// rev_reg_def_json: revocation registry definition json related to `rev_reg_id` in the credential
 /// rev_reg_delta_json: revocation registry delta which covers the whole registry existence time
 /// timestamp: time represented as a total number of seconds from Unix Epoch.
-/// cred_rev_id: user credential revocation id in revocation registry (match to `cred_rev_id` in a credential)
+/// cred_id: Identifier by which the credential is stored in the wallet
 /// cb: Callback that takes command result as parameter
 ///
 /// #Returns
-/// revocation state json:
-///     {
-///         "rev_reg": <revocation registry>,
-///         "witness": <witness>,  (opaque type that contains data structures internal to Ursa.
-///                                 It should not be parsed and are likely to change in future versions).
-///         "timestamp" : integer
-///     }
+/// revocation state json (see `indy_create_revocation_state`)
 ///
 /// #Errors
 /// Common*
 /// Wallet*
 /// Anoncreds*
 #[no_mangle]
-pub extern "C" fn indy_create_revocation_state(command_handle: CommandHandle,
-                                           blob_storage_reader_handle: IndyHandle,
-                                           rev_reg_def_json: *const c_char,
-                                           rev_reg_delta_json: *const c_char,
-                                           timestamp: u64,
-                                           cred_rev_id: *const c_char,
-                                           cb: Option<extern "C" fn(
-                                               command_handle_: CommandHandle, err: ErrorCode,
-                                               rev_state_json: *const c_char)>) -> ErrorCode {
-    trace!("indy_create_revocation_state: >>> blob_storage_reader_handle: {:?}, rev_reg_def_json: {:?}, rev_reg_delta_json: {:?}, timestamp: {:?}, \
-    cred_rev_id: {:?}", blob_storage_reader_handle, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_rev_id);
+pub extern "C" fn indy_prover_create_credential_revocation_state(command_handle: CommandHandle,
+                                                             wallet_handle: WalletHandle,
+                                                             blob_storage_reader_handle: IndyHandle,
+                                                             rev_reg_def_json: *const c_char,
+                                                             rev_reg_delta_json: *const c_char,
+                                                             timestamp: u64,
+                                                             cred_id: *const c_char,
+                                                             cb: Option<extern "C" fn(
+                                                                 command_handle_: CommandHandle, err: ErrorCode,
+                                                                 rev_state_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_create_credential_revocation_state: >>> wallet_handle: {:?}, blob_storage_reader_handle: {:?}, rev_reg_def_json: {:?}, \
+    rev_reg_delta_json: {:?}, timestamp: {:?}, cred_id: {:?}", wallet_handle, blob_storage_reader_handle, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_id);
 
-    check_useful_validatable_json!(rev_reg_def_json, ErrorCode::CommonInvalidParam3, RevocationRegistryDefinition);
-    check_useful_validatable_json!(rev_reg_delta_json, ErrorCode::CommonInvalidParam4, RevocationRegistryDelta);
-    check_useful_c_str!(cred_rev_id, ErrorCode::CommonInvalidParam6);
-    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+    check_useful_validatable_json!(rev_reg_def_json, ErrorCode::CommonInvalidParam4, RevocationRegistryDefinition);
+    check_useful_validatable_json!(rev_reg_delta_json, ErrorCode::CommonInvalidParam5, RevocationRegistryDelta);
+    check_useful_c_str!(cred_id, ErrorCode::CommonInvalidParam7);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
 
-    trace!("indy_create_revocation_state: entities >>> blob_storage_reader_handle: {:?}, rev_reg_def_json: {:?}, rev_reg_delta_json: {:?}, timestamp: {:?}, \
-    cred_rev_id: {:?}", blob_storage_reader_handle, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_rev_id);
+    trace!("indy_prover_create_credential_revocation_state: entities >>> wallet_handle: {:?}, blob_storage_reader_handle: {:?}, rev_reg_def_json: {:?}, \
+    rev_reg_delta_json: {:?}, timestamp: {:?}, cred_id: {:?}", wallet_handle, blob_storage_reader_handle, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_id);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::CreateRevocationState(
+        .send_api_call(command_handle, Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::CreateCredentialRevocationState(
+            wallet_handle,
             blob_storage_reader_handle,
             rev_reg_def_json,
             rev_reg_delta_json,
             timestamp,
-            cred_rev_id,
-            boxed_callback_string!("indy_create_revocation_state", cb, command_handle)
+            cred_id,
+            boxed_callback_string!("indy_prover_create_credential_revocation_state", cb, command_handle)
         ))));
 
     let res = prepare_result!(result);
 
-    trace!("indy_create_revocation_state: <<< res: {:?}", res);
+    trace!("indy_prover_create_credential_revocation_state: <<< res: {:?}", res);
 
     res
 }
@@ -2383,7 +3935,7 @@ pub extern "C" fn indy_update_revocation_state(command_handle: CommandHandle,
     timestamp: {:?}, cred_rev_id: {:?}", blob_storage_reader_handle, rev_state_json, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_rev_id);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::UpdateRevocationState(
+        .send_api_call(command_handle, Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::UpdateRevocationState(
             blob_storage_reader_handle,
             rev_state_json,
             rev_reg_def_json,
@@ -2420,7 +3972,7 @@ pub extern "C" fn indy_generate_nonce(command_handle: CommandHandle,
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam2);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(AnoncredsCommand::Verifier(
+        .send_api_call(command_handle, Command::Anoncreds(AnoncredsCommand::Verifier(
             VerifierCommand::GenerateNonce(
                 boxed_callback_string!("indy_generate_nonce", cb, command_handle)
             ))));
@@ -2432,6 +3984,88 @@ pub extern "C" fn indy_generate_nonce(command_handle: CommandHandle,
     res
 }
 
+/// Builds a proof request from a high level template.
+///
+/// The template is validated with the rules applied to proof requests in `indy_verifier_verify_proof`,
+/// restrictions can only use operators and tags the verifier is able to check.
+/// Nonce is generated the same way as `indy_generate_nonce` does.
+/// The result is proof request of version 2.0 serialized with sorted keys,
+/// so it can be stored or compared as is.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// template_json: proof request template
+///     {
+///         "name": Optional<string>, // "proof_req" by default
+///         "version": Optional<string>, // "1.0" by default
+///         "attributes": [ // list of requested attributes
+///             {
+///                 "referent": Optional<string>, // "attr{N}_referent" by default, N starts with 1
+///                 "name": Optional<string>, // attribute name
+///                 "names": Optional<[string, string]>, // attribute names, can't be used together with `name`
+///                 "restrictions": Optional<filter_json>, // see below
+///                 "non_revoked": Optional<<non_revoc_interval>>,
+///             }
+///         ],
+///         "predicates": [ // list of requested predicates
+///             {
+///                 "referent": Optional<string>, // "predicate{N}_referent" by default, N starts with 1
+///                 "name": attribute name,
+///                 "p_type": predicate type (">=", ">", "<=", "<")
+///                 "p_value": int predicate value
+///                 "restrictions": Optional<filter_json>, // see below
+///                 "non_revoked": Optional<<non_revoc_interval>>,
+///             }
+///         ],
+///         "issuer_dids": Optional<[string]>, // credentials of any listed issuer are accepted
+///         "schema_ids": Optional<[string]>, // credentials of any listed schema are accepted
+///         "cred_def_ids": Optional<[string]>, // credentials of any listed credential definition are accepted
+///         "non_revoked": Optional<<non_revoc_interval>>, // non revocation interval of the whole request
+///         "freshness": Optional<int>, // seconds, sets the interval of the whole request to [now - freshness, now]
+///                                     // can't be used together with `non_revoked`
+///         "identifiers": Optional<"qualified" | "unqualified">, // form of identifiers in restrictions, kept as given by default
+///         "method": Optional<string>, // DID method for "qualified" identifiers, "sov" by default
+///     }
+/// where
+/// filter_json: restrictions in the wql format. Supported operators: "$and", "$or", "$not", "$neq", "$in" and equality.
+///     Supported tags: "schema_id", "schema_issuer_did", "schema_name", "schema_version", "issuer_did", "cred_def_id",
+///     "attr::<attribute name>::value", "attr::<attribute name>::marker".
+///     Allowed identifier lists of the template are added to the restrictions of every attribute and predicate.
+/// non_revoc_interval: see `indy_prover_create_proof`
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// proof_request_json: proof request json (see `indy_prover_create_proof`)
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern "C" fn indy_verifier_build_proof_request(command_handle: CommandHandle,
+                                                template_json: *const c_char,
+                                                cb: Option<extern "C" fn(command_handle_: CommandHandle,
+                                                                         err: ErrorCode,
+                                                                         proof_request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_verifier_build_proof_request: >>> template_json: {:?}", template_json);
+
+    check_useful_validatable_json!(template_json, ErrorCode::CommonInvalidParam2, ProofRequestTemplate);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_verifier_build_proof_request: entities >>> template_json: {:?}", template_json);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Anoncreds(AnoncredsCommand::Verifier(
+            VerifierCommand::BuildProofRequest(
+                template_json,
+                boxed_callback_string!("indy_verifier_build_proof_request", cb, command_handle)
+            ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_verifier_build_proof_request: <<< res: {:?}", res);
+
+    res
+}
+
 /// Get unqualified form (short form without method) of a fully qualified entity like DID.
 ///
 /// This function should be used to the proper casting of fully qualified entity to unqualified form in the following cases:
@@ -2469,12 +4103,13 @@ pub  extern "C" fn indy_to_unqualified(command_handle: CommandHandle,
     trace!("indy_to_unqualified: entities >>> entity: {:?}", entity);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(AnoncredsCommand::ToUnqualified(
+        .send_api_call(command_handle, Command::Anoncreds(AnoncredsCommand::ToUnqualified(
             entity,
             Box::new(move |result| {
                 let (err, res) = prepare_result_1!(result, String::new());
                 trace!("indy_to_unqualified: did: {:?}", res);
                 let res = ctypes::string_to_cstring(res);
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err, res.as_ptr())
             }),
         )));
@@ -2486,3 +4121,390 @@ pub  extern "C" fn indy_to_unqualified(command_handle: CommandHandle,
     res
 }
 
+/// Encodes raw credential attribute values according to the de facto Indy encoding rules.
+///
+/// Issuer can use this function to build `cred_values_json` for indy_issuer_create_credential.
+/// Raw values that are 32-bit integers are encoded as themselves,
+/// any other raw value is encoded as decimal representation of big-endian SHA-256 hash of its UTF-8 bytes.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// values_json: raw credential values
+///     {
+///         "attr1": "raw_value1",
+///         "attr2": "raw_value2",
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// cred_values_json: credential values with raw and encoded representation
+///     {
+///         "attr1": {"raw": "raw_value1", "encoded": "encoded_value1"},
+///         "attr2": {"raw": "raw_value2", "encoded": "encoded_value2"},
+///     }
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern "C" fn indy_encode_credential_values(command_handle: CommandHandle,
+                                            values_json: *const c_char,
+                                            cb: Option<extern "C" fn(command_handle_: CommandHandle,
+                                                                     err: ErrorCode,
+                                                                     cred_values_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_encode_credential_values: >>> values_json: {:?}", values_json);
+
+    check_useful_json!(values_json, ErrorCode::CommonInvalidParam2, ShortCredentialValues);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_encode_credential_values: entities >>> values_json: {:?}", values_json);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Anoncreds(AnoncredsCommand::EncodeCredentialValues(
+            values_json,
+            None,
+            boxed_callback_string!("indy_encode_credential_values", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_encode_credential_values: <<< res: {:?}", res);
+
+    res
+}
+
+/// Encodes raw credential attribute values according to the attribute types declared in the schema.
+///
+/// Attributes without declared type are encoded as in indy_encode_credential_values.
+/// Values that don't match the declared type are rejected (see indy_issuer_create_typed_schema for encoding of types).
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// values_json: raw credential values
+///     {
+///         "attr1": "raw_value1",
+///         "attr2": "raw_value2",
+///     }
+/// schema_json: schema json with attribute types, as returned by indy_issuer_create_typed_schema
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// cred_values_json: credential values with raw and encoded representation
+///     {
+///         "attr1": {"raw": "raw_value1", "encoded": "encoded_value1"},
+///         "attr2": {"raw": "raw_value2", "encoded": "encoded_value2"},
+///     }
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern "C" fn indy_encode_typed_credential_values(command_handle: CommandHandle,
+                                                  values_json: *const c_char,
+                                                  schema_json: *const c_char,
+                                                  cb: Option<extern "C" fn(command_handle_: CommandHandle,
+                                                                           err: ErrorCode,
+                                                                           cred_values_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_encode_typed_credential_values: >>> values_json: {:?}, schema_json: {:?}", values_json, schema_json);
+
+    check_useful_json!(values_json, ErrorCode::CommonInvalidParam2, ShortCredentialValues);
+    check_useful_validatable_json!(schema_json, ErrorCode::CommonInvalidParam3, Schema);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_encode_typed_credential_values: entities >>> values_json: {:?}, schema_json: {:?}", values_json, schema_json);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Anoncreds(AnoncredsCommand::EncodeCredentialValues(
+            values_json,
+            Some(SchemaV1::from(schema_json)),
+            boxed_callback_string!("indy_encode_typed_credential_values", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_encode_typed_credential_values: <<< res: {:?}", res);
+
+    res
+}
+
+/// Builds a predicate over a date attribute for a proof request.
+///
+/// Date attributes are encoded as number of days since 1970-01-01 (see indy_issuer_create_typed_schema).
+/// This function converts the date of the predicate to the same representation,
+/// for example "born before 2006-10-17" becomes `{"name": "dob", "p_type": "<", "p_value": 13438}`.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// date_predicate_json: predicate with date value
+///     {
+///         "name": attribute name, (case insensitive and ignore spaces)
+///         "p_type": predicate type (">=", ">", "<=", "<")
+///         "date": date in "YYYY-MM-DD" format
+///         "restrictions": Optional<filter_json>, // see indy_prover_create_proof
+///         "non_revoked": Optional<<non_revoc_interval>>, // see indy_prover_create_proof
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// predicate_json: predicate info to put into "requested_predicates" of proof request
+///     {
+///         "name": attribute name,
+///         "p_type": predicate type,
+///         "p_value": number of days since 1970-01-01,
+///         "restrictions": Optional<filter_json>,
+///         "non_revoked": Optional<<non_revoc_interval>>,
+///     }
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern "C" fn indy_build_date_predicate(command_handle: CommandHandle,
+                                        date_predicate_json: *const c_char,
+                                        cb: Option<extern "C" fn(command_handle_: CommandHandle,
+                                                                 err: ErrorCode,
+                                                                 predicate_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_date_predicate: >>> date_predicate_json: {:?}", date_predicate_json);
+
+    check_useful_json!(date_predicate_json, ErrorCode::CommonInvalidParam2, DatePredicateInfo);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_build_date_predicate: entities >>> date_predicate_json: {:?}", date_predicate_json);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Anoncreds(AnoncredsCommand::BuildDatePredicate(
+            date_predicate_json,
+            boxed_callback_string!("indy_build_date_predicate", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_build_date_predicate: <<< res: {:?}", res);
+
+    res
+}
+
+/// Wraps a credential into a W3C Verifiable Credential document.
+///
+/// Raw attribute values are placed into `credentialSubject`. The encoded values and the CL signature data
+/// that have no counterpart in the W3C data model are carried in the `proof` section,
+/// so the credential can be restored without any loss with `indy_credential_from_w3c`.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// cred_json: credential json (see `indy_issuer_create_credential`)
+/// issuance_date: RFC 3339 UTC time the credential was issued, e.g. "2020-01-01T00:00:00Z".
+///     The credential does not keep this time, so it must be taken from the issuance context.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// w3c_cred_json: W3C Verifiable Credential json
+///     {
+///         "@context": ["https://www.w3.org/2018/credentials/v1", <AnonCreds context>],
+///         "type": ["VerifiableCredential", "AnonCredsCredential"],
+///         "issuer": string, // DID of the issuer
+///         "issuanceDate": string, // `issuance_date` param
+///         "credentialSchema": {
+///             "type": "AnonCredsDefinition",
+///             "definition": string, // credential definition id
+///             "schema": string, // schema id
+///             "revocation": Optional<string>, // revocation registry id
+///         },
+///         "credentialSubject": {
+///             "attr1": "raw_value1",
+///             "attr2": "raw_value2",
+///         },
+///         "proof": {
+///             "type": "AnonCredsProof2023",
+///             "proofValue": string, // opaque CL signature data
+///         }
+///     }
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern "C" fn indy_credential_to_w3c(command_handle: CommandHandle,
+                                     cred_json: *const c_char,
+                                     issuance_date: *const c_char,
+                                     cb: Option<extern "C" fn(command_handle_: CommandHandle,
+                                                              err: ErrorCode,
+                                                              w3c_cred_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_credential_to_w3c: >>> cred_json: {:?}, issuance_date: {:?}", cred_json, issuance_date);
+
+    check_useful_validatable_json!(cred_json, ErrorCode::CommonInvalidParam2, Credential);
+    check_useful_c_str!(issuance_date, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_credential_to_w3c: entities >>> cred_json: {:?}, issuance_date: {:?}", secret!(&cred_json), issuance_date);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Anoncreds(AnoncredsCommand::CredentialToW3C(
+            cred_json,
+            issuance_date,
+            boxed_callback_string!("indy_credential_to_w3c", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_credential_to_w3c: <<< res: {:?}", res);
+
+    res
+}
+
+/// Restores a credential from a W3C Verifiable Credential document created by `indy_credential_to_w3c`.
+///
+/// Every raw value in `credentialSubject` must match the encoded value signed in `proof`
+/// according to the attribute type declared in the schema (see `indy_issuer_create_typed_schema`).
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// w3c_cred_json: W3C Verifiable Credential json (see `indy_credential_to_w3c`)
+/// schema_json: schema json the credential was issued for
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// cred_json: credential json (see `indy_issuer_create_credential`)
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern "C" fn indy_credential_from_w3c(command_handle: CommandHandle,
+                                       w3c_cred_json: *const c_char,
+                                       schema_json: *const c_char,
+                                       cb: Option<extern "C" fn(command_handle_: CommandHandle,
+                                                                err: ErrorCode,
+                                                                cred_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_credential_from_w3c: >>> w3c_cred_json: {:?}, schema_json: {:?}", w3c_cred_json, schema_json);
+
+    check_useful_validatable_json!(w3c_cred_json, ErrorCode::CommonInvalidParam2, W3CCredential);
+    check_useful_validatable_json!(schema_json, ErrorCode::CommonInvalidParam3, Schema);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_credential_from_w3c: entities >>> w3c_cred_json: {:?}, schema_json: {:?}", secret!(&w3c_cred_json), schema_json);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Anoncreds(AnoncredsCommand::CredentialFromW3C(
+            w3c_cred_json,
+            SchemaV1::from(schema_json),
+            boxed_callback_string!("indy_credential_from_w3c", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_credential_from_w3c: <<< res: {:?}", res);
+
+    res
+}
+
+/// Wraps a proof into a W3C Verifiable Presentation document.
+///
+/// Every sub proof becomes an entry of `verifiableCredential` whose `credentialSubject` contains
+/// the attribute values revealed from that credential. The CL sub proofs, the aggregated proof and
+/// the requested proof mapping are carried in the `proof` sections, so the presentation
+/// can be verified with `indy_verifier_verify_w3c_presentation` or converted back with `indy_proof_from_w3c`.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// proof_request_json: proof request json the proof was created for (see `indy_prover_create_proof`)
+/// proof_json: proof json (see `indy_prover_create_proof`)
+/// issuance_dates_json: RFC 3339 UTC times the proven credentials were issued,
+///     in the order of `identifiers` of the proof
+///     ["2020-01-01T00:00:00Z", ...]
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// w3c_presentation_json: W3C Verifiable Presentation json
+///     {
+///         "@context": ["https://www.w3.org/2018/credentials/v1", <AnonCreds context>],
+///         "type": ["VerifiablePresentation", "AnonCredsPresentation"],
+///         "verifiableCredential": [
+///             {
+///                 "@context": [..],
+///                 "type": ["VerifiableCredential", "AnonCredsCredential"],
+///                 "issuer": string, // DID of the issuer
+///                 "issuanceDate": string, // corresponding entry of `issuance_dates_json`
+///                 "credentialSchema": <see `indy_credential_to_w3c`>,
+///                 "credentialSubject": {
+///                     "attr1": "revealed_raw_value1",
+///                 },
+///                 "proof": {
+///                     "type": "AnonCredsProof2023",
+///                     "proofValue": string, // opaque CL sub proof data
+///                 }
+///             }
+///         ],
+///         "proof": {
+///             "type": "AnonCredsProof2023",
+///             "challenge": string, // nonce of the proof request
+///             "proofValue": string, // opaque CL aggregated proof and requested proof mapping
+///         }
+///     }
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern "C" fn indy_proof_to_w3c(command_handle: CommandHandle,
+                                proof_request_json: *const c_char,
+                                proof_json: *const c_char,
+                                issuance_dates_json: *const c_char,
+                                cb: Option<extern "C" fn(command_handle_: CommandHandle,
+                                                         err: ErrorCode,
+                                                         w3c_presentation_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_proof_to_w3c: >>> proof_request_json: {:?}, proof_json: {:?}, issuance_dates_json: {:?}", proof_request_json, proof_json, issuance_dates_json);
+
+    check_useful_validatable_json!(proof_request_json, ErrorCode::CommonInvalidParam2, ProofRequest);
+    check_useful_validatable_json!(proof_json, ErrorCode::CommonInvalidParam3, Proof);
+    check_useful_json!(issuance_dates_json, ErrorCode::CommonInvalidParam4, Vec<String>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_proof_to_w3c: entities >>> proof_request_json: {:?}, proof_json: {:?}, issuance_dates_json: {:?}", proof_request_json, proof_json, issuance_dates_json);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Anoncreds(AnoncredsCommand::ProofToW3C(
+            proof_request_json,
+            proof_json,
+            issuance_dates_json,
+            boxed_callback_string!("indy_proof_to_w3c", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_proof_to_w3c: <<< res: {:?}", res);
+
+    res
+}
+
+/// Restores a proof from a W3C Verifiable Presentation document created by `indy_proof_to_w3c`.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// w3c_presentation_json: W3C Verifiable Presentation json (see `indy_proof_to_w3c`)
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// proof_json: proof json (see `indy_prover_create_proof`)
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern "C" fn indy_proof_from_w3c(command_handle: CommandHandle,
+                                  w3c_presentation_json: *const c_char,
+                                  cb: Option<extern "C" fn(command_handle_: CommandHandle,
+                                                           err: ErrorCode,
+                                                           proof_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_proof_from_w3c: >>> w3c_presentation_json: {:?}", w3c_presentation_json);
+
+    check_useful_validatable_json!(w3c_presentation_json, ErrorCode::CommonInvalidParam2, W3CPresentation);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_proof_from_w3c: entities >>> w3c_presentation_json: {:?}", w3c_presentation_json);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Anoncreds(AnoncredsCommand::ProofFromW3C(
+            w3c_presentation_json,
+            boxed_callback_string!("indy_proof_from_w3c", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_proof_from_w3c: <<< res: {:?}", res);
+
+    res
+}
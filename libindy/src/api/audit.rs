@@ -0,0 +1,164 @@
+use libc::{c_char, c_void};
+
+use indy_api_types::{ErrorCode, CommandHandle, WalletHandle};
+use indy_api_types::errors::prelude::*;
+use indy_utils::ctypes;
+
+use crate::commands::{Command, CommandExecutor};
+use crate::commands::audit::AuditCommand;
+use crate::domain::audit::AuditConfig;
+use crate::utils::audit::{self, AuditEventCB};
+
+/// Set audit log of security relevant operations.
+///
+/// Audit log is disabled by default. Once enabled, the following operations produce audit events:
+///     key_created - `indy_create_key`,
+///     did_created - `indy_create_and_store_my_did`,
+///     message_signed - `indy_crypto_sign`,
+///     request_signed - `indy_sign_request`, `indy_multi_sign_request` and `indy_sign_and_submit_request`,
+///     keys_rotation_started - `indy_replace_keys_start`,
+///     keys_rotated - `indy_replace_keys_apply`,
+///     wallet_exported - `indy_export_wallet`,
+///     wallet_migrated - `indy_migrate_wallet`,
+///     credential_issued - `indy_issuer_create_credential`,
+///     credential_revoked - `indy_issuer_revoke_credential`.
+/// Events are recorded once the operation has succeeded, so every event stands for a completed operation.
+/// Audit log never fails the operation: an event that can't be recorded is reported as a warning to the log
+/// and the operation result is returned as is. Signed data never gets into events, only its sha256 hash.
+///
+/// Events are linked into a hash chain: every event carries the hash of the previous one, so removal or
+/// modification of an event can be detected by `indy_verify_audit_log`.
+/// If events are stored in wallets, every wallet keeps its own chain. Otherwise events passed to `event`
+/// handler form a single chain of the process.
+///
+/// Every event is passed to `event` handler as JSON:
+/// {
+///     "seq_no": <int> - position of the event in the chain, starts from 1,
+///     "timestamp": <int> - time of the event in seconds since the Unix epoch,
+///     "event": <string> - type of the event, one of listed above,
+///     "did": <optional string> - DID the operation was performed with,
+///     "verkey": <optional string> - verkey the operation was performed with,
+///     "details": <object> - string details of the operation, e.g. "message_hash" for "message_signed",
+///     "prev_hash": <string> - hash of the previous event (64 zeros for the first event),
+///     "hash": <string> - hex encoded sha256 of the canonical JSON of the event without "hash" field
+/// }
+///
+/// NOTE: `event` is called from libindy command thread, so it must not block and must not call libindy functions.
+///
+/// #Params
+/// context: pointer to some audit context that will be available in the event handler.
+/// config_json: {
+///     "wallet": (optional) bool - append events to the wallet the operation was performed with (false by default),
+///     "events": (optional) [string] - types of events to record (all by default)
+/// }
+/// event: (optional) handler called for every recorded event.
+///     Audit log is disabled if events are neither stored in wallets nor passed to the handler.
+///
+/// #Returns
+/// Error code
+#[no_mangle]
+pub extern "C" fn indy_set_audit_log(context: *const c_void,
+                                     config_json: *const c_char,
+                                     event: Option<AuditEventCB>) -> ErrorCode {
+    trace!("indy_set_audit_log >>> context: {:?}, config_json: {:?}, event: {:?}", context, config_json, event);
+
+    check_useful_json!(config_json, ErrorCode::CommonInvalidParam2, AuditConfig);
+
+    audit::set_audit_log(context, config_json, event);
+
+    let res = ErrorCode::Success;
+
+    trace!("indy_set_audit_log: <<< res: {:?}", res);
+
+    res
+}
+
+/// Get audit events stored in the wallet.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// query_json: wql query over event tags:
+///     {
+///         "event": string - type of the event,
+///         "did": string - DID the operation was performed with,
+///         "verkey": string - verkey the operation was performed with,
+///         "~timestamp": string - time of the event in seconds since the Unix epoch,
+///                                zero padded to 20 digits, so range operators can be used
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// events_json: list of events ordered by "seq_no", see `indy_set_audit_log` for the event format.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern "C" fn indy_get_audit_log(command_handle: CommandHandle,
+                                     wallet_handle: WalletHandle,
+                                     query_json: *const c_char,
+                                     cb: Option<extern "C" fn(command_handle_: CommandHandle,
+                                                              err: ErrorCode,
+                                                              events_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_get_audit_log: >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
+
+    check_useful_c_str!(query_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_get_audit_log: entities >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Audit(AuditCommand::GetAuditLog(
+            wallet_handle,
+            query_json,
+            boxed_callback_string!("indy_get_audit_log", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_audit_log: <<< res: {:?}", res);
+
+    res
+}
+
+/// Verify hash chain of audit events stored in the wallet.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// verification_json: {
+///     "valid": bool - whether the chain is unbroken,
+///     "count": int - number of stored events,
+///     "broken_at": (optional) int - seq_no of the first missing or modified event,
+///     "reason": (optional) string - why the chain is broken
+/// }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern "C" fn indy_verify_audit_log(command_handle: CommandHandle,
+                                        wallet_handle: WalletHandle,
+                                        cb: Option<extern "C" fn(command_handle_: CommandHandle,
+                                                                 err: ErrorCode,
+                                                                 verification_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_verify_audit_log: >>> wallet_handle: {:?}", wallet_handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Audit(AuditCommand::VerifyAuditLog(
+            wallet_handle,
+            boxed_callback_string!("indy_verify_audit_log", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_verify_audit_log: <<< res: {:?}", res);
+
+    res
+}
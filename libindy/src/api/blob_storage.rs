@@ -4,6 +4,7 @@ use crate::commands::{Command, CommandExecutor};
 use crate::commands::blob_storage::BlobStorageCommand;
 use indy_api_types::errors::prelude::*;
 use indy_utils::ctypes;
+use indy_utils::tracer;
 
 use libc::c_char;
 
@@ -23,12 +24,13 @@ pub extern "C" fn indy_open_blob_storage_reader(command_handle: CommandHandle,
     trace!("indy_open_blob_storage_reader: entities >>> type_: {:?}, config_json: {:?}", type_, config_json);
 
     let result = CommandExecutor::instance()
-        .send(Command::BlobStorage(BlobStorageCommand::OpenReader(
+        .send_api_call(command_handle, Command::BlobStorage(BlobStorageCommand::OpenReader(
             type_,
             config_json,
             Box::new(move |result| {
                 let (err, handle) = prepare_result_1!(result, 0);
                 trace!("indy_open_blob_storage_reader: handle: {:?}", handle);
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err, handle)
             }),
         )));
@@ -56,12 +58,13 @@ pub extern "C" fn indy_open_blob_storage_writer(command_handle: CommandHandle,
     trace!("indy_open_blob_storage_writer: entities >>> type_: {:?}, config_json: {:?}", type_, config_json);
 
     let result = CommandExecutor::instance()
-        .send(Command::BlobStorage(BlobStorageCommand::OpenWriter(
+        .send_api_call(command_handle, Command::BlobStorage(BlobStorageCommand::OpenWriter(
             type_,
             config_json,
             Box::new(move |result| {
                 let (err, handle) = prepare_result_1!(result, 0);
                 trace!("indy_open_blob_storage_writer: handle: {:?}", handle);
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err, handle)
             }),
         )));
@@ -3,9 +3,12 @@ use crate::commands::{Command, CommandExecutor};
 use crate::commands::cache::CacheCommand;
 use indy_api_types::errors::prelude::*;
 use indy_utils::ctypes;
+use indy_utils::tracer;
 use crate::domain::cache::{GetCacheOptions, PurgeOptions};
 use crate::domain::anoncreds::schema::SchemaId;
 use crate::domain::anoncreds::credential_definition::CredentialDefinitionId;
+use crate::domain::anoncreds::revocation_registry_definition::RevocationRegistryId;
+use crate::domain::ledger::author_agreement::GetTxnAuthorAgreementData;
 use crate::domain::crypto::did::DidValue;
 use indy_api_types::validation::Validatable;
 use libc::c_char;
@@ -50,7 +53,7 @@ pub extern "C" fn indy_get_cred_def(command_handle: CommandHandle,
            pool_handle, wallet_handle, submitter_did, id, options_json);
 
     let result = CommandExecutor::instance()
-        .send(Command::Cache(CacheCommand::GetCredDef(
+        .send_api_call(command_handle, Command::Cache(CacheCommand::GetCredDef(
             pool_handle,
             wallet_handle,
             submitter_did,
@@ -108,7 +111,7 @@ pub extern "C" fn indy_get_schema(command_handle: CommandHandle,
            pool_handle, wallet_handle, submitter_did, id, options_json);
 
     let result = CommandExecutor::instance()
-        .send(Command::Cache(CacheCommand::GetSchema(
+        .send_api_call(command_handle, Command::Cache(CacheCommand::GetSchema(
             pool_handle,
             wallet_handle,
             submitter_did,
@@ -152,12 +155,13 @@ pub extern "C" fn indy_purge_cred_def_cache(command_handle: CommandHandle,
            wallet_handle, options_json);
 
     let result = CommandExecutor::instance()
-        .send(Command::Cache(CacheCommand::PurgeCredDefCache(
+        .send_api_call(command_handle, Command::Cache(CacheCommand::PurgeCredDefCache(
             wallet_handle,
             options_json,
             Box::new(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_purge_cred_def_cache:");
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err)
             })
         )));
@@ -197,12 +201,13 @@ pub extern "C" fn indy_purge_schema_cache(command_handle: CommandHandle,
            wallet_handle, options_json);
 
     let result = CommandExecutor::instance()
-        .send(Command::Cache(CacheCommand::PurgeSchemaCache(
+        .send_api_call(command_handle, Command::Cache(CacheCommand::PurgeSchemaCache(
             wallet_handle,
             options_json,
             Box::new(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_purge_schema_cache:");
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err)
             })
         )));
@@ -213,3 +218,466 @@ pub extern "C" fn indy_purge_schema_cache(command_handle: CommandHandle,
 
     res
 }
+
+/// Gets NYM data for specified DID.
+/// If data is present inside of cache, cached data is returned.
+/// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// wallet_handle: wallet handle (created by open_wallet).
+/// submitter_did: DID of the submitter stored in secured Wallet.
+/// target_did: Target DID as base58-encoded string for 16 or 32 bit DID value.
+/// options_json:
+///  {
+///    noCache: (bool, optional, false by default) Skip usage of cache,
+///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+///  }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// NYM data (see `indy_parse_get_nym_response`)
+/// {
+///     did: DID as base58-encoded string for 16 or 32 bit DID value.
+///     verkey: verification key as base58-encoded string.
+///     role: Role associated number
+/// }
+#[no_mangle]
+pub extern "C" fn indy_get_nym(command_handle: CommandHandle,
+                           pool_handle: PoolHandle,
+                           wallet_handle: WalletHandle,
+                           submitter_did: *const c_char,
+                           target_did: *const c_char,
+                           options_json: *const c_char,
+                           cb: Option<extern "C" fn(command_handle_: CommandHandle,
+                                                err: ErrorCode,
+                                                nym_data: *const c_char)>) -> ErrorCode {
+    trace!("indy_get_nym: >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, target_did: {:?}, options_json: {:?}",
+           pool_handle, wallet_handle, submitter_did, target_did, options_json);
+
+    check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam4, DidValue);
+    check_useful_validatable_string!(target_did, ErrorCode::CommonInvalidParam5, DidValue);
+    check_useful_json!(options_json, ErrorCode::CommonInvalidParam6, GetCacheOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_get_nym: entities >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, target_did: {:?}, options_json: {:?}",
+           pool_handle, wallet_handle, submitter_did, target_did, options_json);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Cache(CacheCommand::GetNym(
+            pool_handle,
+            wallet_handle,
+            submitter_did,
+            target_did,
+            options_json,
+            boxed_callback_string!("indy_get_nym", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_nym: <<< res: {:?}", res);
+
+    res
+}
+
+/// Gets revocation registry definition json data for specified revocation registry definition id.
+/// If data is present inside of cache, cached data is returned.
+/// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+///
+/// Revocation registry definition is immutable on the ledger, so cached data never expires:
+/// `minFresh` option is ignored for it.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// wallet_handle: wallet handle (created by open_wallet).
+/// submitter_did: DID of the submitter stored in secured Wallet.
+/// id: identifier of revocation registry definition.
+/// options_json:
+///  {
+///    noCache: (bool, optional, false by default) Skip usage of cache,
+///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+///  }
+/// cb: Callback that takes command result as parameter.
+#[no_mangle]
+pub extern "C" fn indy_get_revoc_reg_def(command_handle: CommandHandle,
+                                     pool_handle: PoolHandle,
+                                     wallet_handle: WalletHandle,
+                                     submitter_did: *const c_char,
+                                     id: *const c_char,
+                                     options_json: *const c_char,
+                                     cb: Option<extern "C" fn(command_handle_: CommandHandle,
+                                                          err: ErrorCode,
+                                                          revoc_reg_def_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_get_revoc_reg_def: >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, id: {:?}, options_json: {:?}",
+           pool_handle, wallet_handle, submitter_did, id, options_json);
+
+    check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam4, DidValue);
+    check_useful_validatable_string!(id, ErrorCode::CommonInvalidParam5, RevocationRegistryId);
+    check_useful_json!(options_json, ErrorCode::CommonInvalidParam6, GetCacheOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_get_revoc_reg_def: entities >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, id: {:?}, options_json: {:?}",
+           pool_handle, wallet_handle, submitter_did, id, options_json);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Cache(CacheCommand::GetRevocRegDef(
+            pool_handle,
+            wallet_handle,
+            submitter_did,
+            id,
+            options_json,
+            boxed_callback_string!("indy_get_revoc_reg_def", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_revoc_reg_def: <<< res: {:?}", res);
+
+    res
+}
+
+/// Gets revocation registry delta for specified revocation registry definition id and time interval.
+/// If data is present inside of cache, cached data is returned.
+/// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+///
+/// Deltas are cached per revocation registry and `from` value. A cached delta is returned for any `to` value
+/// between its accumulator timestamp and the `to` value it has been fetched with, otherwise it is fetched again.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// wallet_handle: wallet handle (created by open_wallet).
+/// submitter_did: DID of the submitter stored in secured Wallet.
+/// revoc_reg_def_id: identifier of revocation registry definition.
+/// from: Requested time represented as a total number of seconds from Unix Epoch, -1 means no lower bound.
+/// to: Requested time represented as a total number of seconds from Unix Epoch
+/// options_json:
+///  {
+///    noCache: (bool, optional, false by default) Skip usage of cache,
+///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+///  }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Revocation Registry Definition Id, Revocation Registry Delta json and Timestamp
+/// (see `indy_parse_get_revoc_reg_delta_response`).
+#[no_mangle]
+pub extern "C" fn indy_get_revoc_reg_delta(command_handle: CommandHandle,
+                                       pool_handle: PoolHandle,
+                                       wallet_handle: WalletHandle,
+                                       submitter_did: *const c_char,
+                                       revoc_reg_def_id: *const c_char,
+                                       from: i64,
+                                       to: i64,
+                                       options_json: *const c_char,
+                                       cb: Option<extern "C" fn(command_handle_: CommandHandle,
+                                                            err: ErrorCode,
+                                                            revoc_reg_def_id: *const c_char,
+                                                            revoc_reg_delta_json: *const c_char,
+                                                            timestamp: u64)>) -> ErrorCode {
+    trace!("indy_get_revoc_reg_delta: >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, revoc_reg_def_id: {:?}, from: {:?}, to: {:?}, options_json: {:?}",
+           pool_handle, wallet_handle, submitter_did, revoc_reg_def_id, from, to, options_json);
+
+    check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam4, DidValue);
+    check_useful_validatable_string!(revoc_reg_def_id, ErrorCode::CommonInvalidParam5, RevocationRegistryId);
+    check_useful_json!(options_json, ErrorCode::CommonInvalidParam8, GetCacheOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam9);
+
+    let from = if from != -1 { Some(from) } else { None };
+
+    trace!("indy_get_revoc_reg_delta: entities >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, revoc_reg_def_id: {:?}, from: {:?}, to: {:?}, options_json: {:?}",
+           pool_handle, wallet_handle, submitter_did, revoc_reg_def_id, from, to, options_json);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Cache(CacheCommand::GetRevocRegDelta(
+            pool_handle,
+            wallet_handle,
+            submitter_did,
+            revoc_reg_def_id,
+            from,
+            to,
+            options_json,
+            Box::new(move |result| {
+                let (err, revoc_reg_def_id, revoc_reg_delta_json, timestamp) = prepare_result_3!(result, String::new(), String::new(), 0);
+                trace!("indy_get_revoc_reg_delta: revoc_reg_def_id: {:?}, revoc_reg_delta_json: {:?}, timestamp: {:?}",
+                       revoc_reg_def_id, revoc_reg_delta_json, timestamp);
+
+                let revoc_reg_def_id = ctypes::string_to_cstring(revoc_reg_def_id);
+                let revoc_reg_delta_json = ctypes::string_to_cstring(revoc_reg_delta_json);
+                tracer::finish_api_call(command_handle);
+                cb(command_handle, err, revoc_reg_def_id.as_ptr(), revoc_reg_delta_json.as_ptr(), timestamp)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_revoc_reg_delta: <<< res: {:?}", res);
+
+    res
+}
+
+/// Gets Transaction Author Agreement.
+/// If data is present inside of cache, cached data is returned.
+/// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// wallet_handle: wallet handle (created by open_wallet).
+/// submitter_did: DID of the submitter stored in secured Wallet.
+/// data: (Optional) specifies a condition for getting specific TAA (see `indy_build_get_txn_author_agreement_request`).
+/// Null data or empty JSON are acceptable here. In this case, the latest version of TAA is returned.
+/// options_json:
+///  {
+///    noCache: (bool, optional, false by default) Skip usage of cache,
+///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+///  }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Transaction Author Agreement data as it stored on the ledger
+/// {
+///     text: string - text of TAA,
+///     version: string - version of TAA,
+///     digest: string - hash of TAA,
+///     ratification_ts: number - TAA ratification timestamp,
+///     ...
+/// }
+#[no_mangle]
+pub extern "C" fn indy_get_txn_author_agreement(command_handle: CommandHandle,
+                                            pool_handle: PoolHandle,
+                                            wallet_handle: WalletHandle,
+                                            submitter_did: *const c_char,
+                                            data: *const c_char,
+                                            options_json: *const c_char,
+                                            cb: Option<extern "C" fn(command_handle_: CommandHandle,
+                                                                 err: ErrorCode,
+                                                                 taa_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_get_txn_author_agreement: >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, data: {:?}, options_json: {:?}",
+           pool_handle, wallet_handle, submitter_did, data, options_json);
+
+    check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam4, DidValue);
+    check_useful_opt_validatable_json!(data, ErrorCode::CommonInvalidParam5, GetTxnAuthorAgreementData);
+    check_useful_json!(options_json, ErrorCode::CommonInvalidParam6, GetCacheOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_get_txn_author_agreement: entities >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, data: {:?}, options_json: {:?}",
+           pool_handle, wallet_handle, submitter_did, data, options_json);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Cache(CacheCommand::GetTxnAuthorAgreement(
+            pool_handle,
+            wallet_handle,
+            submitter_did,
+            data,
+            options_json,
+            boxed_callback_string!("indy_get_txn_author_agreement", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_txn_author_agreement: <<< res: {:?}", res);
+
+    res
+}
+
+/// Purge NYM cache.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// options_json:
+///  {
+///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+///  }
+/// cb: Callback that takes command result as parameter.
+#[no_mangle]
+pub extern "C" fn indy_purge_nym_cache(command_handle: CommandHandle,
+                                       wallet_handle: WalletHandle,
+                                       options_json: *const c_char,
+                                       cb: Option<extern "C" fn(command_handle_: CommandHandle,
+                                                                err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_purge_nym_cache: >>> wallet_handle: {:?}, options_json: {:?}",
+           wallet_handle, options_json);
+
+    check_useful_json!(options_json, ErrorCode::CommonInvalidParam3, PurgeOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_purge_nym_cache: entities >>> wallet_handle: {:?}, options_json: {:?}",
+           wallet_handle, options_json);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Cache(CacheCommand::PurgeNymCache(
+            wallet_handle,
+            options_json,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_purge_nym_cache:");
+                tracer::finish_api_call(command_handle);
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_purge_nym_cache: <<< res: {:?}", res);
+
+    res
+}
+
+/// Purge revocation registry definition cache.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// options_json:
+///  {
+///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+///  }
+/// cb: Callback that takes command result as parameter.
+#[no_mangle]
+pub extern "C" fn indy_purge_revoc_reg_def_cache(command_handle: CommandHandle,
+                                                 wallet_handle: WalletHandle,
+                                                 options_json: *const c_char,
+                                                 cb: Option<extern "C" fn(command_handle_: CommandHandle,
+                                                                          err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_purge_revoc_reg_def_cache: >>> wallet_handle: {:?}, options_json: {:?}",
+           wallet_handle, options_json);
+
+    check_useful_json!(options_json, ErrorCode::CommonInvalidParam3, PurgeOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_purge_revoc_reg_def_cache: entities >>> wallet_handle: {:?}, options_json: {:?}",
+           wallet_handle, options_json);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Cache(CacheCommand::PurgeRevocRegDefCache(
+            wallet_handle,
+            options_json,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_purge_revoc_reg_def_cache:");
+                tracer::finish_api_call(command_handle);
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_purge_revoc_reg_def_cache: <<< res: {:?}", res);
+
+    res
+}
+
+/// Purge revocation registry delta cache.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// options_json:
+///  {
+///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+///  }
+/// cb: Callback that takes command result as parameter.
+#[no_mangle]
+pub extern "C" fn indy_purge_revoc_reg_delta_cache(command_handle: CommandHandle,
+                                                   wallet_handle: WalletHandle,
+                                                   options_json: *const c_char,
+                                                   cb: Option<extern "C" fn(command_handle_: CommandHandle,
+                                                                            err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_purge_revoc_reg_delta_cache: >>> wallet_handle: {:?}, options_json: {:?}",
+           wallet_handle, options_json);
+
+    check_useful_json!(options_json, ErrorCode::CommonInvalidParam3, PurgeOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_purge_revoc_reg_delta_cache: entities >>> wallet_handle: {:?}, options_json: {:?}",
+           wallet_handle, options_json);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Cache(CacheCommand::PurgeRevocRegDeltaCache(
+            wallet_handle,
+            options_json,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_purge_revoc_reg_delta_cache:");
+                tracer::finish_api_call(command_handle);
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_purge_revoc_reg_delta_cache: <<< res: {:?}", res);
+
+    res
+}
+
+/// Purge Transaction Author Agreement cache.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// options_json:
+///  {
+///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+///  }
+/// cb: Callback that takes command result as parameter.
+#[no_mangle]
+pub extern "C" fn indy_purge_txn_author_agreement_cache(command_handle: CommandHandle,
+                                                        wallet_handle: WalletHandle,
+                                                        options_json: *const c_char,
+                                                        cb: Option<extern "C" fn(command_handle_: CommandHandle,
+                                                                                 err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_purge_txn_author_agreement_cache: >>> wallet_handle: {:?}, options_json: {:?}",
+           wallet_handle, options_json);
+
+    check_useful_json!(options_json, ErrorCode::CommonInvalidParam3, PurgeOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_purge_txn_author_agreement_cache: entities >>> wallet_handle: {:?}, options_json: {:?}",
+           wallet_handle, options_json);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Cache(CacheCommand::PurgeTxnAuthorAgreementCache(
+            wallet_handle,
+            options_json,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_purge_txn_author_agreement_cache:");
+                tracer::finish_api_call(command_handle);
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_purge_txn_author_agreement_cache: <<< res: {:?}", res);
+
+    res
+}
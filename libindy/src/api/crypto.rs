@@ -6,6 +6,7 @@ use crate::domain::crypto::pack::JWE;
 use crate::domain::crypto::key::KeyInfo;
 use indy_api_types::errors::prelude::*;
 use indy_utils::ctypes;
+use indy_utils::tracer;
 
 use serde_json;
 use libc::c_char;
@@ -50,7 +51,7 @@ pub extern "C" fn indy_create_key(command_handle: CommandHandle,
     trace!("indy_create_key: entities >>> wallet_handle: {:?}, key_json: {:?}", wallet_handle, secret!(&key_json));
 
     let result = CommandExecutor::instance()
-        .send(Command::Crypto(CryptoCommand::CreateKey(
+        .send_api_call(command_handle, Command::Crypto(CryptoCommand::CreateKey(
             wallet_handle,
             key_json,
             boxed_callback_string!("indy_create_key", cb, command_handle)
@@ -98,13 +99,14 @@ pub  extern "C" fn indy_set_key_metadata(command_handle: CommandHandle,
     trace!("indy_set_key_metadata: entities >>> wallet_handle: {:?}, verkey: {:?}, metadata: {:?}", wallet_handle, verkey, metadata);
 
     let result = CommandExecutor::instance()
-        .send(Command::Crypto(CryptoCommand::SetKeyMetadata(
+        .send_api_call(command_handle, Command::Crypto(CryptoCommand::SetKeyMetadata(
             wallet_handle,
             verkey,
             metadata,
             Box::new(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_set_key_metadata: ");
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err)
             })
         )));
@@ -150,7 +152,7 @@ pub  extern "C" fn indy_get_key_metadata(command_handle: CommandHandle,
     trace!("indy_get_key_metadata: entities >>> wallet_handle: {:?}, verkey: {:?}", wallet_handle, verkey);
 
     let result = CommandExecutor::instance()
-        .send(Command::Crypto(CryptoCommand::GetKeyMetadata(
+        .send_api_call(command_handle, Command::Crypto(CryptoCommand::GetKeyMetadata(
             wallet_handle,
             verkey,
             boxed_callback_string!("indy_get_key_metadata", cb, command_handle)
@@ -204,7 +206,7 @@ pub  extern "C" fn indy_crypto_sign(command_handle: CommandHandle,
            wallet_handle, signer_vk, message_raw, message_len);
 
     let result = CommandExecutor::instance()
-        .send(Command::Crypto(CryptoCommand::CryptoSign(
+        .send_api_call(command_handle, Command::Crypto(CryptoCommand::CryptoSign(
             wallet_handle,
             signer_vk,
             message_raw,
@@ -212,6 +214,7 @@ pub  extern "C" fn indy_crypto_sign(command_handle: CommandHandle,
                 let (err, signature) = prepare_result_1!(result, Vec::new());
                 trace!("indy_crypto_sign: signature: {:?}", signature);
                 let (signature_raw, signature_len) = ctypes::vec_to_pointer(&signature);
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err, signature_raw, signature_len)
             })
         )));
@@ -267,13 +270,14 @@ pub  extern "C" fn indy_crypto_verify(command_handle: CommandHandle,
            signer_vk, message_raw, message_len, signature_raw, signature_len);
 
     let result = CommandExecutor::instance()
-        .send(Command::Crypto(CryptoCommand::CryptoVerify(
+        .send_api_call(command_handle, Command::Crypto(CryptoCommand::CryptoVerify(
             signer_vk,
             message_raw,
             signature_raw,
             Box::new(move |result| {
                 let (err, valid) = prepare_result_1!(result, false);
                 trace!("indy_crypto_verify: valid: {:?}", valid);
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err, valid)
             })
         )));
@@ -337,7 +341,7 @@ pub  extern "C" fn indy_crypto_auth_crypt(command_handle: CommandHandle,
            wallet_handle, sender_vk, recipient_vk, msg_data, msg_len);
 
     let result = CommandExecutor::instance()
-        .send(Command::Crypto(CryptoCommand::AuthenticatedEncrypt(
+        .send_api_call(command_handle, Command::Crypto(CryptoCommand::AuthenticatedEncrypt(
             wallet_handle,
             sender_vk,
             recipient_vk,
@@ -346,6 +350,7 @@ pub  extern "C" fn indy_crypto_auth_crypt(command_handle: CommandHandle,
                 let (err, encrypted_msg) = prepare_result_1!(result, Vec::new());
                 trace!("indy_crypto_auth_crypt: encrypted_msg: {:?}", encrypted_msg);
                 let (encrypted_msg_raw, encrypted_msg_len) = ctypes::vec_to_pointer(&encrypted_msg);
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err, encrypted_msg_raw, encrypted_msg_len)
             })
         )));
@@ -406,7 +411,7 @@ pub  extern "C" fn indy_crypto_auth_decrypt(command_handle: CommandHandle,
            wallet_handle, recipient_vk, encrypted_msg, encrypted_len);
 
     let result = CommandExecutor::instance()
-        .send(Command::Crypto(CryptoCommand::AuthenticatedDecrypt(
+        .send_api_call(command_handle, Command::Crypto(CryptoCommand::AuthenticatedDecrypt(
             wallet_handle,
             recipient_vk,
             encrypted_msg,
@@ -415,6 +420,7 @@ pub  extern "C" fn indy_crypto_auth_decrypt(command_handle: CommandHandle,
                 trace!("indy_crypto_auth_decrypt: sender_vk: {:?}, msg: {:?}", sender_vk, msg);
                 let (msg_data, msg_len) = ctypes::vec_to_pointer(&msg);
                 let sender_vk = ctypes::string_to_cstring(sender_vk);
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err, sender_vk.as_ptr(), msg_data, msg_len)
             })
         )));
@@ -470,13 +476,14 @@ pub  extern "C" fn indy_crypto_anon_crypt(command_handle: CommandHandle,
     trace!("indy_crypto_anon_crypt: entities >>> recipient_vk: {:?}, msg_data: {:?}, msg_len: {:?}", recipient_vk, msg_data, msg_len);
 
     let result = CommandExecutor::instance()
-        .send(Command::Crypto(CryptoCommand::AnonymousEncrypt(
+        .send_api_call(command_handle, Command::Crypto(CryptoCommand::AnonymousEncrypt(
             recipient_vk,
             msg_data,
             Box::new(move |result| {
                 let (err, encrypted_msg) = prepare_result_1!(result, Vec::new());
                 trace!("indy_crypto_anon_crypt: encrypted_msg: {:?}", encrypted_msg);
                 let (encrypted_msg_raw, encrypted_msg_len) = ctypes::vec_to_pointer(&encrypted_msg);
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err, encrypted_msg_raw, encrypted_msg_len)
             })
         )));
@@ -535,7 +542,7 @@ pub  extern "C" fn indy_crypto_anon_decrypt(command_handle: CommandHandle,
            wallet_handle, recipient_vk, encrypted_msg, encrypted_len);
 
     let result = CommandExecutor::instance()
-        .send(Command::Crypto(CryptoCommand::AnonymousDecrypt(
+        .send_api_call(command_handle, Command::Crypto(CryptoCommand::AnonymousDecrypt(
             wallet_handle,
             recipient_vk,
             encrypted_msg,
@@ -543,6 +550,7 @@ pub  extern "C" fn indy_crypto_anon_decrypt(command_handle: CommandHandle,
                 let (err, msg) = prepare_result_1!(result, Vec::new());
                 trace!("indy_crypto_anon_decrypt: msg: {:?}", msg);
                 let (msg_data, msg_len) = ctypes::vec_to_pointer(&msg);
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err, msg_data, msg_len)
             })
         )));
@@ -654,7 +662,7 @@ pub extern "C" fn indy_pack_message(
         return IndyError::from_msg(IndyErrorKind::InvalidParam(4), "Empty RecipientKeys has been passed").into();
     }
 
-    let result = CommandExecutor::instance().send(Command::Crypto(CryptoCommand::PackMessage(
+    let result = CommandExecutor::instance().send_api_call(command_handle, Command::Crypto(CryptoCommand::PackMessage(
         message,
         receiver_list,
         sender,
@@ -663,6 +671,7 @@ pub extern "C" fn indy_pack_message(
             let (err, jwe) = prepare_result_1!(result, Vec::new());
             trace!("indy_auth_pack_message: jwe: {:?}", jwe);
             let (jwe_data, jwe_len) = ctypes::vec_to_pointer(&jwe);
+            tracer::finish_api_call(command_handle);
             cb(command_handle, err, jwe_data, jwe_len)
         }),
     )));
@@ -744,7 +753,7 @@ pub extern "C" fn indy_unpack_message(
         Err(_) => return ErrorCode::CommonInvalidParam3
     };
 
-    let result = CommandExecutor::instance().send(Command::Crypto(CryptoCommand::UnpackMessage(
+    let result = CommandExecutor::instance().send_api_call(command_handle, Command::Crypto(CryptoCommand::UnpackMessage(
         jwe_struct,
         wallet_handle,
         Box::new(move |result| {
@@ -753,6 +762,7 @@ pub extern "C" fn indy_unpack_message(
                 command_handle, err, res_json
             );
             let (res_json_data, res_json_len) = ctypes::vec_to_pointer(&res_json);
+            tracer::finish_api_call(command_handle);
             cb(command_handle, err, res_json_data, res_json_len)
         }),
     )));
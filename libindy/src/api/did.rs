@@ -5,6 +5,7 @@ use crate::domain::crypto::did::{TheirDidInfo, DidValue, MyDidInfo, DidMethod};
 use crate::domain::crypto::key::KeyInfo;
 use indy_api_types::errors::prelude::*;
 use indy_utils::ctypes;
+use indy_utils::tracer;
 use indy_api_types::validation::Validatable;
 
 use serde_json;
@@ -67,7 +68,7 @@ pub  extern "C" fn indy_create_and_store_my_did(command_handle: CommandHandle,
     trace!("indy_create_and_store_my_did: entities >>> wallet_handle: {:?}, did_json: {:?}", wallet_handle, secret!(&did_info));
 
     let result = CommandExecutor::instance()
-        .send(Command::Did(DidCommand::CreateAndStoreMyDid(
+        .send_api_call(command_handle, Command::Did(DidCommand::CreateAndStoreMyDid(
             wallet_handle,
             did_info,
             Box::new(move |result| {
@@ -75,6 +76,7 @@ pub  extern "C" fn indy_create_and_store_my_did(command_handle: CommandHandle,
                 trace!("indy_create_and_store_my_did: did: {:?}, verkey: {:?}", did, verkey);
                 let did = ctypes::string_to_cstring(did);
                 let verkey = ctypes::string_to_cstring(verkey);
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err, did.as_ptr(), verkey.as_ptr())
             }),
         )));
@@ -131,7 +133,7 @@ pub  extern "C" fn indy_replace_keys_start(command_handle: CommandHandle,
     trace!("indy_replace_keys_start: entities>>> wallet_handle: {:?}, did: {:?}, key_info: {:?}", wallet_handle, did, secret!(&key_info));
 
     let result = CommandExecutor::instance()
-        .send(Command::Did(DidCommand::ReplaceKeysStart(
+        .send_api_call(command_handle, Command::Did(DidCommand::ReplaceKeysStart(
             wallet_handle,
             key_info,
             did,
@@ -177,12 +179,13 @@ pub  extern "C" fn indy_replace_keys_apply(command_handle: CommandHandle,
     trace!("indy_replace_keys_apply: entities >>> wallet_handle: {:?}, did: {:?}", wallet_handle, did);
 
     let result = CommandExecutor::instance()
-        .send(Command::Did(DidCommand::ReplaceKeysApply(
+        .send_api_call(command_handle, Command::Did(DidCommand::ReplaceKeysApply(
             wallet_handle,
             did,
             Box::new(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_replace_keys_apply:");
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err)
             })
         )));
@@ -234,12 +237,13 @@ pub  extern "C" fn indy_store_their_did(command_handle: CommandHandle,
     trace!("indy_store_their_did: entities >>> wallet_handle: {:?}, identity_json: {:?}", wallet_handle, identity_json);
 
     let result = CommandExecutor::instance()
-        .send(Command::Did(DidCommand::StoreTheirDid(
+        .send_api_call(command_handle, Command::Did(DidCommand::StoreTheirDid(
             wallet_handle,
             identity_json,
             Box::new(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_store_their_did:");
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err)
             })
         )));
@@ -298,7 +302,7 @@ pub extern "C" fn indy_key_for_did(command_handle: CommandHandle,
     trace!("indy_key_for_did: entities >>> pool_handle: {:?}, wallet_handle: {:?}, did: {:?}", pool_handle, wallet_handle, did);
 
     let result = CommandExecutor::instance()
-        .send(Command::Did(DidCommand::KeyForDid(
+        .send_api_call(command_handle, Command::Did(DidCommand::KeyForDid(
             pool_handle,
             wallet_handle,
             did,
@@ -355,7 +359,7 @@ pub extern "C" fn indy_key_for_local_did(command_handle: CommandHandle,
     trace!("indy_key_for_local_did: entities >>> wallet_handle: {:?}, did: {:?}", wallet_handle, did);
 
     let result = CommandExecutor::instance()
-        .send(Command::Did(DidCommand::KeyForLocalDid(
+        .send_api_call(command_handle, Command::Did(DidCommand::KeyForLocalDid(
             wallet_handle,
             did,
             boxed_callback_string!("indy_key_for_local_did", cb, command_handle)
@@ -409,13 +413,14 @@ pub extern "C" fn indy_set_endpoint_for_did(command_handle: CommandHandle,
     let endpoint = Endpoint::new(address, Some(transport_key));
 
     let result = CommandExecutor::instance()
-        .send(Command::Did(DidCommand::SetEndpointForDid(
+        .send_api_call(command_handle, Command::Did(DidCommand::SetEndpointForDid(
             wallet_handle,
             did,
             endpoint,
             Box::new(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_set_endpoint_for_did:");
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err)
             })
         )));
@@ -464,7 +469,7 @@ pub extern "C" fn indy_get_endpoint_for_did(command_handle: CommandHandle,
     trace!("indy_get_endpoint_for_did: entities >>> wallet_handle: {:?}, pool_handle: {:?}, did: {:?}", wallet_handle, pool_handle, did);
 
     let result = CommandExecutor::instance()
-        .send(Command::Did(DidCommand::GetEndpointForDid(
+        .send_api_call(command_handle, Command::Did(DidCommand::GetEndpointForDid(
             wallet_handle,
             pool_handle,
             did,
@@ -473,6 +478,7 @@ pub extern "C" fn indy_get_endpoint_for_did(command_handle: CommandHandle,
                 trace!("indy_get_endpoint_for_did: address: {:?}, transport_vk: {:?}", address, transport_vk);
                 let address = ctypes::string_to_cstring(address);
                 let transport_vk = transport_vk.map(ctypes::string_to_cstring);
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err, address.as_ptr(),
                    transport_vk.as_ref().map(|vk| vk.as_ptr()).unwrap_or(ptr::null()));
             })
@@ -520,13 +526,14 @@ pub extern "C" fn indy_set_did_metadata(command_handle: CommandHandle,
     trace!("indy_set_did_metadata: entities >>> wallet_handle: {:?}, did: {:?}, metadata: {:?}", wallet_handle, did, metadata);
 
     let result = CommandExecutor::instance()
-        .send(Command::Did(DidCommand::SetDidMetadata(
+        .send_api_call(command_handle, Command::Did(DidCommand::SetDidMetadata(
             wallet_handle,
             did,
             metadata,
             Box::new(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_set_did_metadata:");
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err)
             })
         )));
@@ -572,7 +579,7 @@ pub extern "C" fn indy_get_did_metadata(command_handle: CommandHandle,
     trace!("indy_get_did_metadata: entities >>> wallet_handle: {:?}, did: {:?}", wallet_handle, did);
 
     let result = CommandExecutor::instance()
-        .send(Command::Did(DidCommand::GetDidMetadata(
+        .send_api_call(command_handle, Command::Did(DidCommand::GetDidMetadata(
             wallet_handle,
             did,
             boxed_callback_string!("indy_get_did_metadata", cb, command_handle))));
@@ -624,7 +631,7 @@ pub extern "C" fn indy_get_my_did_with_meta(command_handle: CommandHandle,
     trace!("indy_get_my_did_with_meta: entities >>> wallet_handle: {:?}, my_did: {:?}", wallet_handle, my_did);
 
     let result = CommandExecutor::instance()
-        .send(Command::Did(DidCommand::GetMyDidWithMeta(
+        .send_api_call(command_handle, Command::Did(DidCommand::GetMyDidWithMeta(
             wallet_handle,
             my_did,
             boxed_callback_string!("indy_get_my_did_with_meta", cb, command_handle)
@@ -672,7 +679,7 @@ pub extern "C" fn indy_list_my_dids_with_meta(command_handle: CommandHandle,
     trace!("indy_list_my_dids_with_meta: entities >>> wallet_handle: {:?}", wallet_handle);
 
     let result = CommandExecutor::instance()
-        .send(Command::Did(DidCommand::ListMyDidsWithMeta(
+        .send_api_call(command_handle, Command::Did(DidCommand::ListMyDidsWithMeta(
             wallet_handle,
             boxed_callback_string!("indy_list_my_dids_with_meta", cb, command_handle)
         )));
@@ -718,7 +725,7 @@ pub  extern "C" fn indy_abbreviate_verkey(command_handle: CommandHandle,
     trace!("indy_abbreviate_verkey: entities >>> did: {:?}, full_verkey: {:?}", did, full_verkey);
 
     let result = CommandExecutor::instance()
-        .send(Command::Did(DidCommand::AbbreviateVerkey(
+        .send_api_call(command_handle, Command::Did(DidCommand::AbbreviateVerkey(
             did,
             full_verkey,
             boxed_callback_string!("indy_abbreviate_verkey", cb, command_handle)
@@ -770,7 +777,7 @@ pub extern "C" fn indy_qualify_did(command_handle: CommandHandle,
     trace!("indy_qualify_did: entities >>> wallet_handle: {:?}, did: {:?}, method: {:?}", wallet_handle, did, method);
 
     let result = CommandExecutor::instance()
-        .send(Command::Did(DidCommand::QualifyDid(
+        .send_api_call(command_handle, Command::Did(DidCommand::QualifyDid(
             wallet_handle,
             did,
             method,
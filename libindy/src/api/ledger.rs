@@ -2,6 +2,7 @@ use indy_api_types::{CommandHandle, ErrorCode, PoolHandle, WalletHandle};
 use indy_api_types::errors::prelude::*;
 use indy_api_types::validation::Validatable;
 use indy_utils::ctypes;
+use indy_utils::tracer;
 use libc::c_char;
 use serde_json;
 
@@ -59,7 +60,7 @@ pub extern "C" fn indy_sign_and_submit_request(command_handle: CommandHandle,
            pool_handle, wallet_handle, submitter_did, request_json);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::SignAndSubmitRequest(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::SignAndSubmitRequest(
             pool_handle,
             wallet_handle,
             submitter_did,
@@ -105,7 +106,7 @@ pub extern "C" fn indy_submit_request(command_handle: CommandHandle,
     trace!("indy_submit_request: entities >>> pool_handle: {:?}, request_json: {:?}", pool_handle, request_json);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::SubmitRequest(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::SubmitRequest(
             pool_handle,
             request_json,
             boxed_callback_string!("indy_submit_request", cb, command_handle)
@@ -162,7 +163,7 @@ pub extern "C" fn indy_submit_action(command_handle: CommandHandle,
     trace!("indy_submit_action: entities >>> pool_handle: {:?}, request_json: {:?}, nodes: {:?}, timeout: {:?}", pool_handle, request_json, nodes, timeout);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(
+        .send_api_call(command_handle, Command::Ledger(
             LedgerCommand::SubmitAction(
                 pool_handle,
                 request_json,
@@ -215,7 +216,7 @@ pub extern "C" fn indy_sign_request(command_handle: CommandHandle,
     trace!("indy_sign_request: entities >>> wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}", wallet_handle, submitter_did, request_json);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::SignRequest(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::SignRequest(
             wallet_handle,
             submitter_did,
             request_json,
@@ -265,7 +266,7 @@ pub extern "C" fn indy_multi_sign_request(command_handle: CommandHandle,
     trace!("indy_multi_sign_request: entities >>> wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}", wallet_handle, submitter_did, request_json);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::MultiSignRequest(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::MultiSignRequest(
             wallet_handle,
             submitter_did,
             request_json,
@@ -309,7 +310,7 @@ pub extern "C" fn indy_build_get_ddo_request(command_handle: CommandHandle,
     trace!("indy_build_get_ddo_request: entities >>> submitter_did: {:?}, target_did: {:?}", submitter_did, target_did);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildGetDdoRequest(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::BuildGetDdoRequest(
             submitter_did,
             target_did,
             boxed_callback_string!("indy_build_get_ddo_request", cb, command_handle)
@@ -371,7 +372,7 @@ pub extern "C" fn indy_build_nym_request(command_handle: CommandHandle,
            submitter_did, target_did, verkey, alias, role);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildNymRequest(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::BuildNymRequest(
             submitter_did,
             target_did,
             verkey,
@@ -416,7 +417,7 @@ pub extern "C" fn indy_build_get_nym_request(command_handle: CommandHandle,
     trace!("indy_build_get_nym_request: entities >>> submitter_did: {:?}, target_did: {:?}", submitter_did, target_did);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildGetNymRequest(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::BuildGetNymRequest(
             submitter_did,
             target_did,
             boxed_callback_string!("indy_build_get_nym_request", cb, command_handle)
@@ -467,7 +468,7 @@ pub extern "C" fn indy_parse_get_nym_response(command_handle: CommandHandle,
     trace!("indy_parse_get_nym_response: entities >>> get_nym_response: {:?}", get_nym_response);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::ParseGetNymResponse(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::ParseGetNymResponse(
             get_nym_response,
             boxed_callback_string!("indy_parse_get_nym_response", cb, command_handle)
         )));
@@ -526,7 +527,7 @@ pub extern "C" fn indy_build_attrib_request(command_handle: CommandHandle,
     }
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildAttribRequest(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::BuildAttribRequest(
             submitter_did,
             target_did,
             hash,
@@ -588,7 +589,7 @@ pub extern "C" fn indy_build_get_attrib_request(command_handle: CommandHandle,
     }
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildGetAttribRequest(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::BuildGetAttribRequest(
             submitter_did,
             target_did,
             raw,
@@ -641,7 +642,7 @@ pub extern "C" fn indy_build_schema_request(command_handle: CommandHandle,
     trace!("indy_build_schema_request: entities >>> submitter_did: {:?}, data: {:?}", submitter_did, data);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildSchemaRequest(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::BuildSchemaRequest(
             submitter_did,
             data,
             boxed_callback_string!("indy_build_schema_request", cb, command_handle)
@@ -683,7 +684,7 @@ pub extern "C" fn indy_build_get_schema_request(command_handle: CommandHandle,
     trace!("indy_build_get_schema_request: entities >>> submitter_did: {:?}, id: {:?}", submitter_did, id);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildGetSchemaRequest(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::BuildGetSchemaRequest(
             submitter_did,
             id,
             boxed_callback_string!("indy_build_get_schema_request", cb, command_handle)
@@ -730,13 +731,14 @@ pub extern "C" fn indy_parse_get_schema_response(command_handle: CommandHandle,
     trace!("indy_parse_get_schema_response: entities >>> get_schema_response: {:?}", get_schema_response);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::ParseGetSchemaResponse(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::ParseGetSchemaResponse(
             get_schema_response,
             Box::new(move |result| {
                 let (err, schema_id, schema_json) = prepare_result_2!(result, String::new(), String::new());
                 trace!("indy_parse_get_schema_response: schema_id: {:?}, schema_json: {:?}", schema_id, schema_json);
                 let schema_id = ctypes::string_to_cstring(schema_id);
                 let schema_json = ctypes::string_to_cstring(schema_json);
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err, schema_id.as_ptr(), schema_json.as_ptr())
             })
         )));
@@ -790,7 +792,7 @@ pub extern "C" fn indy_build_cred_def_request(command_handle: CommandHandle,
     trace!("indy_build_cred_def_request: entities >>> submitter_did: {:?}, data: {:?}", submitter_did, data);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildCredDefRequest(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::BuildCredDefRequest(
             submitter_did,
             data,
             boxed_callback_string!("indy_build_cred_def_request", cb, command_handle)
@@ -833,7 +835,7 @@ pub extern "C" fn indy_build_get_cred_def_request(command_handle: CommandHandle,
     trace!("indy_build_get_cred_def_request: entities >>> submitter_did: {:?}, id: {:?}", submitter_did, id);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildGetCredDefRequest(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::BuildGetCredDefRequest(
             submitter_did,
             id,
             boxed_callback_string!("indy_build_get_cred_def_request", cb, command_handle)
@@ -884,13 +886,14 @@ pub extern "C" fn indy_parse_get_cred_def_response(command_handle: CommandHandle
     trace!("indy_parse_get_cred_def_response: entities >>> get_cred_def_response: {:?}", get_cred_def_response);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::ParseGetCredDefResponse(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::ParseGetCredDefResponse(
             get_cred_def_response,
             Box::new(move |result| {
                 let (err, cred_def_id, cred_def_json) = prepare_result_2!(result, String::new(), String::new());
                 trace!("indy_parse_get_cred_def_response: cred_def_id: {:?}, cred_def_json: {:?}", cred_def_id, cred_def_json);
                 let cred_def_id = ctypes::string_to_cstring(cred_def_id);
                 let cred_def_json = ctypes::string_to_cstring(cred_def_json);
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err, cred_def_id.as_ptr(), cred_def_json.as_ptr())
             })
         )));
@@ -944,7 +947,7 @@ pub extern "C" fn indy_build_node_request(command_handle: CommandHandle,
     trace!("indy_build_node_request: entities >>> submitter_did: {:?}, target_did: {:?}, data: {:?}", submitter_did, target_did, data);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildNodeRequest(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::BuildNodeRequest(
             submitter_did,
             target_did,
             data,
@@ -979,7 +982,7 @@ pub extern "C" fn indy_build_get_validator_info_request(command_handle: CommandH
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildGetValidatorInfoRequest(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::BuildGetValidatorInfoRequest(
             submitter_did,
             boxed_callback_string!("indy_build_get_validator_info_request", cb, command_handle)
         )));
@@ -1022,7 +1025,7 @@ pub extern "C" fn indy_build_get_txn_request(command_handle: CommandHandle,
     trace!("indy_build_get_txn_request: entities >>> submitter_did: {:?}, ledger_type: {:?}, seq_no: {:?}", submitter_did, ledger_type, seq_no);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildGetTxnRequest(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::BuildGetTxnRequest(
             submitter_did,
             ledger_type,
             seq_no,
@@ -1069,7 +1072,7 @@ pub extern "C" fn indy_build_pool_config_request(command_handle: CommandHandle,
     trace!("indy_build_pool_config_request: entities >>> submitter_did: {:?}, writes: {:?}, force: {:?}", submitter_did, writes, force);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildPoolConfigRequest(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::BuildPoolConfigRequest(
             submitter_did,
             writes,
             force,
@@ -1119,7 +1122,7 @@ pub extern "C" fn indy_build_pool_restart_request(command_handle: CommandHandle,
     }
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(
+        .send_api_call(command_handle, Command::Ledger(
             LedgerCommand::BuildPoolRestartRequest(
                 submitter_did,
                 action,
@@ -1206,7 +1209,7 @@ pub extern "C" fn indy_build_pool_upgrade_request(command_handle: CommandHandle,
     }
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(
+        .send_api_call(command_handle, Command::Ledger(
             LedgerCommand::BuildPoolUpgradeRequest(
                 submitter_did,
                 name,
@@ -1274,7 +1277,7 @@ pub extern "C" fn indy_build_revoc_reg_def_request(command_handle: CommandHandle
     trace!("indy_build_revoc_reg_def_request: entities >>> submitter_did: {:?}, data: {:?}", submitter_did, data);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildRevocRegDefRequest(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::BuildRevocRegDefRequest(
             submitter_did,
             data,
             boxed_callback_string!("indy_build_revoc_reg_def_request", cb, command_handle)
@@ -1317,7 +1320,7 @@ pub extern "C" fn indy_build_get_revoc_reg_def_request(command_handle: CommandHa
     trace!("indy_build_get_revoc_reg_def_request: entities>>> submitter_did: {:?}, id: {:?}", submitter_did, id);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildGetRevocRegDefRequest(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::BuildGetRevocRegDefRequest(
             submitter_did,
             id,
             boxed_callback_string!("indy_build_get_revoc_reg_def_request", cb, command_handle)
@@ -1372,7 +1375,7 @@ pub extern "C" fn indy_parse_get_revoc_reg_def_response(command_handle: CommandH
     trace!("indy_parse_get_revoc_reg_def_response: entities >>> get_revoc_reg_def_response: {:?}", get_revoc_reg_def_response);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::ParseGetRevocRegDefResponse(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::ParseGetRevocRegDefResponse(
             get_revoc_reg_def_response,
             Box::new(move |result| {
                 let (err, revoc_reg_def_id, revoc_reg_def_json) = prepare_result_2!(result, String::new(), String::new());
@@ -1380,6 +1383,7 @@ pub extern "C" fn indy_parse_get_revoc_reg_def_response(command_handle: CommandH
 
                 let revoc_reg_def_id = ctypes::string_to_cstring(revoc_reg_def_id);
                 let revoc_reg_def_json = ctypes::string_to_cstring(revoc_reg_def_json);
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err, revoc_reg_def_id.as_ptr(), revoc_reg_def_json.as_ptr())
             })
         )));
@@ -1440,7 +1444,7 @@ pub extern "C" fn indy_build_revoc_reg_entry_request(command_handle: CommandHand
            submitter_did, revoc_reg_def_id, rev_def_type, value);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildRevocRegEntryRequest(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::BuildRevocRegEntryRequest(
             submitter_did,
             revoc_reg_def_id,
             rev_def_type,
@@ -1487,7 +1491,7 @@ pub extern "C" fn indy_build_get_revoc_reg_request(command_handle: CommandHandle
     trace!("indy_build_get_revoc_reg_request: entities >>> submitter_did: {:?}, revoc_reg_def_id: {:?}, timestamp: {:?}", submitter_did, revoc_reg_def_id, timestamp);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildGetRevocRegRequest(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::BuildGetRevocRegRequest(
             submitter_did,
             revoc_reg_def_id,
             timestamp,
@@ -1535,7 +1539,7 @@ pub extern "C" fn indy_parse_get_revoc_reg_response(command_handle: CommandHandl
     trace!("indy_parse_get_revoc_reg_response: entities >>> get_revoc_reg_response: {:?}", get_revoc_reg_response);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::ParseGetRevocRegResponse(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::ParseGetRevocRegResponse(
             get_revoc_reg_response,
             Box::new(move |result| {
                 let (err, revoc_reg_def_id, revoc_reg_json, timestamp) = prepare_result_3!(result, String::new(), String::new(), 0);
@@ -1544,6 +1548,7 @@ pub extern "C" fn indy_parse_get_revoc_reg_response(command_handle: CommandHandl
 
                 let revoc_reg_def_id = ctypes::string_to_cstring(revoc_reg_def_id);
                 let revoc_reg_json = ctypes::string_to_cstring(revoc_reg_json);
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err, revoc_reg_def_id.as_ptr(), revoc_reg_json.as_ptr(), timestamp)
             })
         )));
@@ -1594,7 +1599,7 @@ pub extern "C" fn indy_build_get_revoc_reg_delta_request(command_handle: Command
            submitter_did, revoc_reg_def_id, from, to);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildGetRevocRegDeltaRequest(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::BuildGetRevocRegDeltaRequest(
             submitter_did,
             revoc_reg_def_id,
             from,
@@ -1646,7 +1651,7 @@ pub extern "C" fn indy_parse_get_revoc_reg_delta_response(command_handle: Comman
     trace!("indy_parse_get_revoc_reg_delta_response: entities >>> get_revoc_reg_delta_response: {:?}", get_revoc_reg_delta_response);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::ParseGetRevocRegDeltaResponse(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::ParseGetRevocRegDeltaResponse(
             get_revoc_reg_delta_response,
             Box::new(move |result| {
                 let (err, revoc_reg_def_id, revoc_reg_delta_json, timestamp) = prepare_result_3!(result, String::new(), String::new(), 0);
@@ -1655,6 +1660,7 @@ pub extern "C" fn indy_parse_get_revoc_reg_delta_response(command_handle: Comman
 
                 let revoc_reg_def_id = ctypes::string_to_cstring(revoc_reg_def_id);
                 let revoc_reg_delta_json = ctypes::string_to_cstring(revoc_reg_delta_json);
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err, revoc_reg_def_id.as_ptr(), revoc_reg_delta_json.as_ptr(), timestamp)
             })
         )));
@@ -1714,13 +1720,14 @@ pub extern "C" fn indy_register_transaction_parser_for_sp(command_handle: Comman
            txn_type, parser, free);
 
     let res = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::RegisterSPParser(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::RegisterSPParser(
             txn_type,
             parser,
             free,
             Box::new(move |res| {
                 let res = prepare_result!(res);
                 trace!("indy_register_transaction_parser_for_sp: res: {:?}", res);
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, res)
             }),
         )));
@@ -1780,7 +1787,7 @@ pub extern "C" fn indy_get_response_metadata(command_handle: CommandHandle,
     trace!("indy_get_response_metadata: entities >>> response: {:?}", response);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::GetResponseMetadata(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::GetResponseMetadata(
             response,
             boxed_callback_string!("indy_get_response_metadata", cb, command_handle)
         )));
@@ -1819,7 +1826,7 @@ pub extern "C" fn indy_build_ledgers_freeze_request(command_handle: CommandHandl
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildLedgersFreezeRequest(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::BuildLedgersFreezeRequest(
             submitter_did,
             ledgers_ids,
             boxed_callback_string!("indy_build_ledgers_freeze_request", cb, command_handle)
@@ -1865,7 +1872,7 @@ pub extern "C" fn indy_build_get_frozen_ledgers_request(command_handle: CommandH
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildGetFrozenLedgersRequest(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::BuildGetFrozenLedgersRequest(
             submitter_did,
             boxed_callback_string!("indy_build_get_frozen_ledgers_request", cb, command_handle)
         )));
@@ -1946,7 +1953,7 @@ pub extern "C" fn indy_build_auth_rule_request(command_handle: CommandHandle,
            submitter_did, txn_type, action, field, old_value, new_value, constraint);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildAuthRuleRequest(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::BuildAuthRuleRequest(
             submitter_did,
             txn_type,
             action,
@@ -2013,7 +2020,7 @@ pub extern "C" fn indy_build_auth_rules_request(command_handle: CommandHandle,
     trace!("indy_build_auth_rules_request: entities >>> submitter_did: {:?}, rules: {:?}", submitter_did, rules);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildAuthRulesRequest(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::BuildAuthRulesRequest(
             submitter_did,
             rules,
             boxed_callback_string!("indy_build_auth_rules_request", cb, command_handle)
@@ -2076,7 +2083,7 @@ pub extern "C" fn indy_build_get_auth_rule_request(command_handle: CommandHandle
            submitter_did, txn_type, action, field, old_value, new_value);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildGetAuthRuleRequest(
+        .send_api_call(command_handle, Command::Ledger(LedgerCommand::BuildGetAuthRuleRequest(
             submitter_did,
             txn_type,
             action,
@@ -2154,7 +2161,7 @@ pub extern "C" fn indy_build_txn_author_agreement_request(command_handle: Comman
            submitter_did, text, version, ratification_ts, retirement_ts);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(
+        .send_api_call(command_handle, Command::Ledger(
             LedgerCommand::BuildTxnAuthorAgreementRequest(
                 submitter_did,
                 text,
@@ -2201,7 +2208,7 @@ pub extern "C" fn indy_build_disable_all_txn_author_agreements_request(command_h
     trace!("indy_build_disable_all_txn_author_agreements_request: entities >>> submitter_did: {:?}", submitter_did);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(
+        .send_api_call(command_handle, Command::Ledger(
             LedgerCommand::BuildDisableAllTxnAuthorAgreementsRequest(
                 submitter_did,
                 boxed_callback_string!("indy_build_disable_all_txn_author_agreements_request", cb, command_handle)
@@ -2253,7 +2260,7 @@ pub extern "C" fn indy_build_get_txn_author_agreement_request(command_handle: Co
     trace!("indy_build_get_txn_author_agreement_request: entities >>> submitter_did: {:?}, data: {:?}", submitter_did, data);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(
+        .send_api_call(command_handle, Command::Ledger(
             LedgerCommand::BuildGetTxnAuthorAgreementRequest(
                 submitter_did,
                 data,
@@ -2313,7 +2320,7 @@ pub extern "C" fn indy_build_acceptance_mechanisms_request(command_handle: Comma
            submitter_did, aml, version, aml_context);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(
+        .send_api_call(command_handle, Command::Ledger(
             LedgerCommand::BuildAcceptanceMechanismRequests(
                 submitter_did,
                 aml,
@@ -2367,7 +2374,7 @@ pub extern "C" fn indy_build_get_acceptance_mechanisms_request(command_handle: C
     trace!("indy_build_get_acceptance_mechanisms_request: entities >>> submitter_did: {:?}, timestamp: {:?}, version: {:?}", submitter_did, timestamp, version);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(
+        .send_api_call(command_handle, Command::Ledger(
             LedgerCommand::BuildGetAcceptanceMechanismsRequest(
                 submitter_did,
                 timestamp,
@@ -2436,7 +2443,7 @@ pub extern "C" fn indy_append_txn_author_agreement_acceptance_to_request(command
            request_json, text, version, taa_digest, mechanism, time);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(
+        .send_api_call(command_handle, Command::Ledger(
             LedgerCommand::AppendTxnAuthorAgreementAcceptanceToRequest(
                 request_json,
                 text,
@@ -2490,7 +2497,7 @@ pub extern "C" fn indy_append_request_endorser(command_handle: CommandHandle,
     trace!("indy_append_request_endorser: entities >>> request_json: {:?},endorser_did: {:?}", request_json, endorser_did);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(
+        .send_api_call(command_handle, Command::Ledger(
             LedgerCommand::AppendRequestEndorser(
                 request_json,
                 endorser_did,
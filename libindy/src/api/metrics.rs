@@ -1,6 +1,7 @@
 use indy_api_types::{ErrorCode, CommandHandle};
 use crate::commands::{Command, CommandExecutor};
 use crate::commands::metrics::MetricsCommand;
+use crate::domain::metrics::CollectMetricsOptions;
 use indy_utils::ctypes;
 use libc::c_char;
 
@@ -22,10 +23,55 @@ pub extern "C" fn indy_collect_metrics(command_handle: CommandHandle,
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
 
     let result = CommandExecutor::instance()
-        .send(Command::Metrics(MetricsCommand::CollectMetrics(
+        .send_api_call(command_handle, Command::Metrics(MetricsCommand::CollectMetrics(
+            CollectMetricsOptions::default(),
             boxed_callback_string!("indy_collect_metrics", cb, command_handle)
         )));
     let res = prepare_result!(result);
     trace!("indy_collect_metrics: <<< res: {:?}", res);
     res
 }
+
+/// Collect metrics in the requested format.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// options_json: {
+///     format: (optional) "json" (default) - map in the JSON format, the same as `indy_collect_metrics` returns,
+///             "openmetrics" - OpenMetrics text exposition (counters, gauges and histograms
+///                             with `_bucket`, `_sum` and `_count` series), suitable for Prometheus scraping.
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Collected metrics in the requested format.
+/// Besides the command queue, threadpool and wallet counters it contains
+///     pool node replies per pool, node and reply type,
+///     pool requests per pool and consensus outcome,
+///     storage operations per opened wallet and operation.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern "C" fn indy_collect_metrics_with_options(command_handle: CommandHandle,
+                                                    options_json: *const c_char,
+                                                    cb: Option<extern "C" fn(command_handle_: CommandHandle,
+                                                                             err: ErrorCode,
+                                                                             metrics: *const c_char)>) -> ErrorCode {
+    trace!("indy_collect_metrics_with_options: >>> command_handle: {:?}, options_json: {:?}, cb: {:?}",
+           command_handle, options_json, cb);
+
+    check_useful_json!(options_json, ErrorCode::CommonInvalidParam2, CollectMetricsOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_collect_metrics_with_options: entities >>> options_json: {:?}", options_json);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Metrics(MetricsCommand::CollectMetrics(
+            options_json,
+            boxed_callback_string!("indy_collect_metrics_with_options", cb, command_handle)
+        )));
+    let res = prepare_result!(result);
+    trace!("indy_collect_metrics_with_options: <<< res: {:?}", res);
+    res
+}
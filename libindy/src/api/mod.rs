@@ -10,6 +10,8 @@ pub mod non_secrets;
 pub mod payments;
 pub mod payments_v2;
 pub mod logger;
+pub mod tracer;
+pub mod audit;
 pub mod cache;
 pub mod metrics;
 
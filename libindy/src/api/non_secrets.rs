@@ -5,6 +5,7 @@ use crate::commands::non_secrets::NonSecretsCommand;
 use indy_api_types::domain::wallet::Tags;
 use indy_api_types::errors::prelude::*;
 use indy_utils::ctypes;
+use indy_utils::tracer;
 
 use serde_json;
 use libc::c_char;
@@ -47,7 +48,7 @@ pub extern "C" fn indy_add_wallet_record(command_handle: CommandHandle,
     trace!("indy_add_wallet_record: entities >>> wallet_handle: {:?}, type_: {:?}, id: {:?}, value: {:?}, tags_json: {:?}", wallet_handle, type_, id, value, tags_json);
 
     let result = CommandExecutor::instance()
-        .send(Command::NonSecrets(
+        .send_api_call(command_handle, Command::NonSecrets(
             NonSecretsCommand::AddRecord(
                 wallet_handle,
                 type_,
@@ -57,6 +58,7 @@ pub extern "C" fn indy_add_wallet_record(command_handle: CommandHandle,
                 Box::new(move |result| {
                     let err = prepare_result!(result);
                     trace!("indy_add_wallet_record:");
+                    tracer::finish_api_call(command_handle);
                     cb(command_handle, err)
                 })
             )));
@@ -93,7 +95,7 @@ pub extern "C" fn indy_update_wallet_record_value(command_handle: CommandHandle,
     trace!("indy_update_wallet_record_value: entities >>> wallet_handle: {:?}, type_: {:?}, id: {:?}, value: {:?}", wallet_handle, type_, id, value);
 
     let result = CommandExecutor::instance()
-        .send(Command::NonSecrets(
+        .send_api_call(command_handle, Command::NonSecrets(
             NonSecretsCommand::UpdateRecordValue(
                 wallet_handle,
                 type_,
@@ -102,6 +104,7 @@ pub extern "C" fn indy_update_wallet_record_value(command_handle: CommandHandle,
                 Box::new(move |result| {
                     let err = prepare_result!(result);
                     trace!("indy_update_wallet_record_value:");
+                    tracer::finish_api_call(command_handle);
                     cb(command_handle, err)
                 })
             )));
@@ -147,7 +150,7 @@ pub extern "C" fn indy_update_wallet_record_tags(command_handle: CommandHandle,
     trace!("indy_update_wallet_record_tags: entities >>> wallet_handle: {:?}, type_: {:?}, id: {:?}, tags_json: {:?}", wallet_handle, type_, id, tags_json);
 
     let result = CommandExecutor::instance()
-        .send(Command::NonSecrets(
+        .send_api_call(command_handle, Command::NonSecrets(
             NonSecretsCommand::UpdateRecordTags(
                 wallet_handle,
                 type_,
@@ -156,6 +159,7 @@ pub extern "C" fn indy_update_wallet_record_tags(command_handle: CommandHandle,
                 Box::new(move |result| {
                     let err = prepare_result!(result);
                     trace!("indy_update_wallet_record_tags:");
+                    tracer::finish_api_call(command_handle);
                     cb(command_handle, err)
                 })
             )));
@@ -203,7 +207,7 @@ pub extern "C" fn indy_add_wallet_record_tags(command_handle: CommandHandle,
     trace!("indy_add_wallet_record_tags: entities >>> wallet_handle: {:?}, type_: {:?}, id: {:?}, tags_json: {:?}", wallet_handle, type_, id, tags_json);
 
     let result = CommandExecutor::instance()
-        .send(Command::NonSecrets(
+        .send_api_call(command_handle, Command::NonSecrets(
             NonSecretsCommand::AddRecordTags(
                 wallet_handle,
                 type_,
@@ -212,6 +216,7 @@ pub extern "C" fn indy_add_wallet_record_tags(command_handle: CommandHandle,
                 Box::new(move |result| {
                     let err = prepare_result!(result);
                     trace!("indy_add_wallet_record_tags:");
+                    tracer::finish_api_call(command_handle);
                     cb(command_handle, err)
                 })
             )));
@@ -249,7 +254,7 @@ pub extern "C" fn indy_delete_wallet_record_tags(command_handle: CommandHandle,
     trace!("indy_delete_wallet_record_tags: entities >>> wallet_handle: {:?}, type_: {:?}, id: {:?}, tag_names_json: {:?}", wallet_handle, type_, id, tag_names_json);
 
     let result = CommandExecutor::instance()
-        .send(Command::NonSecrets(
+        .send_api_call(command_handle, Command::NonSecrets(
             NonSecretsCommand::DeleteRecordTags(
                 wallet_handle,
                 type_,
@@ -258,6 +263,7 @@ pub extern "C" fn indy_delete_wallet_record_tags(command_handle: CommandHandle,
                 Box::new(move |result| {
                     let err = prepare_result!(result);
                     trace!("indy_delete_wallet_record_tags:");
+                    tracer::finish_api_call(command_handle);
                     cb(command_handle, err)
                 })
             )));
@@ -291,7 +297,7 @@ pub extern "C" fn indy_delete_wallet_record(command_handle: CommandHandle,
     trace!("indy_delete_wallet_record: entities >>> wallet_handle: {:?}, type_: {:?}, id: {:?}", wallet_handle, type_, id);
 
     let result = CommandExecutor::instance()
-        .send(Command::NonSecrets(
+        .send_api_call(command_handle, Command::NonSecrets(
             NonSecretsCommand::DeleteRecord(
                 wallet_handle,
                 type_,
@@ -299,6 +305,7 @@ pub extern "C" fn indy_delete_wallet_record(command_handle: CommandHandle,
                 Box::new(move |result| {
                     let err = prepare_result!(result);
                     trace!("indy_delete_wallet_record:");
+                    tracer::finish_api_call(command_handle);
                     cb(command_handle, err)
                 })
             )));
@@ -349,7 +356,7 @@ pub  extern "C" fn indy_get_wallet_record(command_handle: CommandHandle,
     trace!("indy_get_wallet_record: entities >>> wallet_handle: {:?}, type_: {:?}, id: {:?}, options_json: {:?}", wallet_handle, type_, id, options_json);
 
     let result = CommandExecutor::instance()
-        .send(Command::NonSecrets(
+        .send_api_call(command_handle, Command::NonSecrets(
             NonSecretsCommand::GetRecord(
                 wallet_handle,
                 type_,
@@ -411,7 +418,7 @@ pub  extern "C" fn indy_open_wallet_search(command_handle: CommandHandle,
     trace!("indy_open_wallet_search: entities >>> wallet_handle: {:?}, type_: {:?}, query_json: {:?}, options_json: {:?}", wallet_handle, type_, query_json, options_json);
 
     let result = CommandExecutor::instance()
-        .send(Command::NonSecrets(
+        .send_api_call(command_handle, Command::NonSecrets(
             NonSecretsCommand::OpenSearch(
                 wallet_handle,
                 type_,
@@ -420,6 +427,7 @@ pub  extern "C" fn indy_open_wallet_search(command_handle: CommandHandle,
                 Box::new(move |result| {
                     let (err, handle) = prepare_result_1!(result, INVALID_SEARCH_HANDLE);
                     trace!("indy_open_wallet_search: handle: {:?}", handle);
+                    tracer::finish_api_call(command_handle);
                     cb(command_handle, err, handle)
                 })
             )));
@@ -465,7 +473,7 @@ pub  extern "C" fn indy_fetch_wallet_search_next_records(command_handle: Command
     trace!("indy_fetch_wallet_search_next_records: entities >>> wallet_handle: {:?}, wallet_search_handle: {:?}, count: {:?}", wallet_handle, wallet_search_handle, count);
 
     let result = CommandExecutor::instance()
-        .send(Command::NonSecrets(
+        .send_api_call(command_handle, Command::NonSecrets(
             NonSecretsCommand::FetchSearchNextRecords(
                 wallet_handle,
                 wallet_search_handle,
@@ -495,12 +503,13 @@ pub  extern "C" fn indy_close_wallet_search(command_handle: CommandHandle,
     trace!("indy_close_wallet_search: entities >>> wallet_search_handle: {:?}", wallet_search_handle);
 
     let result = CommandExecutor::instance()
-        .send(Command::NonSecrets(
+        .send_api_call(command_handle, Command::NonSecrets(
             NonSecretsCommand::CloseSearch(
                 wallet_search_handle,
                 Box::new(move |result| {
                     let err = prepare_result!(result);
                     trace!("indy_close_wallet_search:");
+                    tracer::finish_api_call(command_handle);
                     cb(command_handle, err)
                 })
             )));
@@ -3,6 +3,7 @@ use crate::commands::{Command, CommandExecutor};
 use crate::commands::pairwise::PairwiseCommand;
 use indy_api_types::errors::prelude::*;
 use indy_utils::ctypes;
+use indy_utils::tracer;
 use indy_api_types::validation::Validatable;
 use crate::domain::crypto::did::DidValue;
 
@@ -37,12 +38,13 @@ pub  extern "C" fn indy_is_pairwise_exists(command_handle: CommandHandle,
     trace!("indy_is_pairwise_exists: entities >>> wallet_handle: {:?}, their_did: {:?}", wallet_handle, their_did);
 
     let result = CommandExecutor::instance()
-        .send(Command::Pairwise(PairwiseCommand::PairwiseExists(
+        .send_api_call(command_handle, Command::Pairwise(PairwiseCommand::PairwiseExists(
             wallet_handle,
             their_did,
             Box::new(move |result| {
                 let (err, exists) = prepare_result_1!(result, false);
                 trace!("indy_is_pairwise_exists: exists: {:?}", exists);
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err, exists)
             })
         )));
@@ -89,7 +91,7 @@ pub  extern "C" fn indy_create_pairwise(command_handle: CommandHandle,
     trace!("indy_create_pairwise: entities >>> wallet_handle: {:?}, their_did: {:?}, my_did: {:?}, metadata: {:?}", wallet_handle, their_did, my_did, metadata);
 
     let result = CommandExecutor::instance()
-        .send(Command::Pairwise(PairwiseCommand::CreatePairwise(
+        .send_api_call(command_handle, Command::Pairwise(PairwiseCommand::CreatePairwise(
             wallet_handle,
             their_did,
             my_did,
@@ -97,6 +99,7 @@ pub  extern "C" fn indy_create_pairwise(command_handle: CommandHandle,
             Box::new(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_create_pairwise:");
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err)
             })
         )));
@@ -134,7 +137,7 @@ pub  extern "C" fn indy_list_pairwise(command_handle: CommandHandle,
     trace!("indy_list_pairwise: entities >>> wallet_handle: {:?}", wallet_handle);
 
     let result = CommandExecutor::instance()
-        .send(Command::Pairwise(PairwiseCommand::ListPairwise(
+        .send_api_call(command_handle, Command::Pairwise(PairwiseCommand::ListPairwise(
             wallet_handle,
             boxed_callback_string!("indy_list_pairwise", cb, command_handle)
         )));
@@ -175,7 +178,7 @@ pub  extern "C" fn indy_get_pairwise(command_handle: CommandHandle,
     trace!("indy_get_pairwise: entities >>> wallet_handle: {:?}, their_did: {:?}", wallet_handle, their_did);
 
     let result = CommandExecutor::instance()
-        .send(Command::Pairwise(PairwiseCommand::GetPairwise(
+        .send_api_call(command_handle, Command::Pairwise(PairwiseCommand::GetPairwise(
             wallet_handle,
             their_did,
             boxed_callback_string!("indy_get_pairwise", cb, command_handle)
@@ -219,13 +222,14 @@ pub  extern "C" fn indy_set_pairwise_metadata(command_handle: CommandHandle,
     trace!("indy_set_pairwise_metadata: entities >>> wallet_handle: {:?}, their_did: {:?}, metadata: {:?}", wallet_handle, their_did, metadata);
 
     let result = CommandExecutor::instance()
-        .send(Command::Pairwise(PairwiseCommand::SetPairwiseMetadata(
+        .send_api_call(command_handle, Command::Pairwise(PairwiseCommand::SetPairwiseMetadata(
             wallet_handle,
             their_did,
             metadata,
             Box::new(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_set_pairwise_metadata:");
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err)
             })
         )));
@@ -5,6 +5,7 @@ use crate::commands::payments::PaymentsCommand;
 use crate::services::payments::PaymentsMethodCBs;
 use indy_api_types::errors::prelude::*;
 use indy_utils::ctypes;
+use indy_utils::tracer;
 use crate::services::payments::{RequesterInfo, Fees};
 use crate::domain::crypto::did::DidValue;
 use indy_api_types::validation::Validatable;
@@ -432,12 +433,13 @@ pub extern "C" fn indy_register_payment_method(command_handle: CommandHandle,
         verify_with_address
     );
     let result =
-        CommandExecutor::instance().send(
+        CommandExecutor::instance().send_api_call(command_handle,
             Command::Payments(
                 PaymentsCommand::RegisterMethod(
                     payment_method,
                     cbs,
                     Box::new(move |result| {
+                        tracer::finish_api_call(command_handle);
                         cb(command_handle, result.into());
                     }))
             ));
@@ -487,7 +489,7 @@ pub extern "C" fn indy_create_payment_address(command_handle: CommandHandle,
     trace!("indy_create_payment_address: entities >>> wallet_handle: {:?}, payment_method: {:?}, config: {:?}", wallet_handle, payment_method, config);
 
     let result =
-        CommandExecutor::instance().send(
+        CommandExecutor::instance().send_api_call(command_handle,
             Command::Payments(
                 PaymentsCommand::CreateAddress(
                     wallet_handle,
@@ -525,7 +527,7 @@ pub extern "C" fn indy_list_payment_addresses(command_handle: CommandHandle,
     trace!("indy_list_payment_address: entities >>> wallet_handle: {:?}", wallet_handle);
 
     let result =
-        CommandExecutor::instance().send(
+        CommandExecutor::instance().send_api_call(command_handle,
             Command::Payments(
                 PaymentsCommand::ListAddresses(
                     wallet_handle,
@@ -596,7 +598,7 @@ pub extern "C" fn indy_add_request_fees(command_handle: CommandHandle,
            wallet_handle, submitter_did, req_json, inputs_json, outputs_json, extra);
 
     let result =
-        CommandExecutor::instance().send(
+        CommandExecutor::instance().send_api_call(command_handle,
             Command::Payments(
                 PaymentsCommand::AddRequestFees(
                     wallet_handle,
@@ -610,6 +612,7 @@ pub extern "C" fn indy_add_request_fees(command_handle: CommandHandle,
                         trace!("indy_add_request_fees: req_with_fees_json: {:?}, payment_method: {:?}", req_with_fees_json, payment_method);
                         let req_with_fees_json = ctypes::string_to_cstring(req_with_fees_json);
                         let payment_method = ctypes::string_to_cstring(payment_method);
+                        tracer::finish_api_call(command_handle);
                         cb(command_handle, err, req_with_fees_json.as_ptr(), payment_method.as_ptr());
                     }))
             ));
@@ -651,7 +654,7 @@ pub extern "C" fn indy_parse_response_with_fees(command_handle: CommandHandle,
     trace!("indy_parse_response_with_fees: entities >>> payment_method: {:?}, resp_json: {:?}", payment_method, resp_json);
 
     let result =
-        CommandExecutor::instance().send(
+        CommandExecutor::instance().send_api_call(command_handle,
             Command::Payments(
                 PaymentsCommand::ParseResponseWithFees(
                     payment_method,
@@ -694,7 +697,7 @@ pub extern "C" fn indy_build_get_payment_sources_request(command_handle: Command
     trace!("indy_build_get_payment_sources_request: entities >>> wallet_handle: {:?}, submitter_did: {:?}, payment_address: {:?}", wallet_handle, submitter_did, payment_address);
 
     let result =
-        CommandExecutor::instance().send(
+        CommandExecutor::instance().send_api_call(command_handle,
             Command::Payments(
                 PaymentsCommand::BuildGetPaymentSourcesRequest(
                     wallet_handle,
@@ -706,6 +709,7 @@ pub extern "C" fn indy_build_get_payment_sources_request(command_handle: Command
                         trace!("indy_build_get_payment_sources_request: get_sources_txn_json: {:?}, payment_method: {:?}", get_sources_txn_json, payment_method);
                         let get_sources_txn_json = ctypes::string_to_cstring(get_sources_txn_json);
                         let payment_method = ctypes::string_to_cstring(payment_method);
+                        tracer::finish_api_call(command_handle);
                         cb(command_handle, err, get_sources_txn_json.as_ptr(), payment_method.as_ptr());
                     }))
             ));
@@ -748,7 +752,7 @@ pub extern "C" fn indy_parse_get_payment_sources_response(command_handle: Comman
     trace!("indy_parse_get_payment_sources_response: entities >>> payment_method: {:?}, resp_json: {:?}", payment_method, resp_json);
 
     let result =
-        CommandExecutor::instance().send(
+        CommandExecutor::instance().send_api_call(command_handle,
             Command::Payments(
                 PaymentsCommand::ParseGetPaymentSourcesResponse(
                     payment_method,
@@ -757,6 +761,7 @@ pub extern "C" fn indy_parse_get_payment_sources_response(command_handle: Comman
                         let (err, sources_json, _) = prepare_result_2!(result, String::new(), -1);
                         trace!("indy_parse_get_payment_sources_response: sources_json: {:?}", sources_json);
                         let sources_json = ctypes::string_to_cstring(sources_json);
+                        tracer::finish_api_call(command_handle);
                         cb(command_handle, err, sources_json.as_ptr());
                     }))
             ));
@@ -816,7 +821,7 @@ pub extern "C" fn indy_build_payment_req(command_handle: CommandHandle,
            wallet_handle, submitter_did, inputs_json, outputs_json, extra);
 
     let result =
-        CommandExecutor::instance().send(
+        CommandExecutor::instance().send_api_call(command_handle,
             Command::Payments(
                 PaymentsCommand::BuildPaymentReq(
                     wallet_handle,
@@ -829,6 +834,7 @@ pub extern "C" fn indy_build_payment_req(command_handle: CommandHandle,
                         trace!("indy_build_payment_req: payment_req_json: {:?}, payment_method: {:?}", payment_req_json, payment_method);
                         let payment_req_json = ctypes::string_to_cstring(payment_req_json);
                         let payment_method = ctypes::string_to_cstring(payment_method);
+                        tracer::finish_api_call(command_handle);
                         cb(command_handle, err, payment_req_json.as_ptr(), payment_method.as_ptr());
                     }))
             ));
@@ -870,7 +876,7 @@ pub extern "C" fn indy_parse_payment_response(command_handle: CommandHandle,
     trace!("indy_parse_payment_response: entities >>> payment_method: {:?}, resp_json: {:?}", payment_method, resp_json);
 
     let result =
-        CommandExecutor::instance().send(
+        CommandExecutor::instance().send_api_call(command_handle,
             Command::Payments(
                 PaymentsCommand::ParsePaymentResponse(
                     payment_method,
@@ -936,7 +942,7 @@ pub extern "C" fn indy_prepare_payment_extra_with_acceptance_data(command_handle
            extra_json, text, version, taa_digest, mechanism, time);
 
     let result = CommandExecutor::instance()
-        .send(Command::Payments(
+        .send_api_call(command_handle, Command::Payments(
             PaymentsCommand::AppendTxnAuthorAgreementAcceptanceToExtra(
                 extra_json,
                 text,
@@ -990,7 +996,7 @@ pub extern "C" fn indy_build_mint_req(command_handle: CommandHandle,
     trace!("indy_build_mint_req: entities >>> wallet_handle: {:?}, submitter_did: {:?}, outputs_json: {:?}, extra: {:?}", wallet_handle, submitter_did, outputs_json, extra);
 
     let result =
-        CommandExecutor::instance().send(
+        CommandExecutor::instance().send_api_call(command_handle,
             Command::Payments(
                 PaymentsCommand::BuildMintReq(
                     wallet_handle,
@@ -1002,6 +1008,7 @@ pub extern "C" fn indy_build_mint_req(command_handle: CommandHandle,
                         trace!("indy_build_mint_req: mint_req_json: {:?}, payment_method: {:?}", mint_req_json, payment_method);
                         let mint_req_json = ctypes::string_to_cstring(mint_req_json);
                         let payment_method = ctypes::string_to_cstring(payment_method);
+                        tracer::finish_api_call(command_handle);
                         cb(command_handle, err, mint_req_json.as_ptr(), payment_method.as_ptr());
                     }))
             ));
@@ -1046,7 +1053,7 @@ pub extern "C" fn indy_build_set_txn_fees_req(command_handle: CommandHandle,
     trace!("indy_build_set_txn_fees_req: entitites >>> wallet_handle: {:?}, submitter_did: {:?}, payment_method: {:?}, fees_json: {:?}", wallet_handle, submitter_did, payment_method, fees_json);
 
     let result =
-        CommandExecutor::instance().send(
+        CommandExecutor::instance().send_api_call(command_handle,
             Command::Payments(
                 PaymentsCommand::BuildSetTxnFeesReq(
                     wallet_handle,
@@ -1088,7 +1095,7 @@ pub extern "C" fn indy_build_get_txn_fees_req(command_handle: CommandHandle,
     trace!("indy_build_get_txn_fees_req: entities >>> wallet_handle: {:?}, submitter_did: {:?}, payment_method: {:?}", wallet_handle, submitter_did, payment_method);
 
     let result =
-        CommandExecutor::instance().send(
+        CommandExecutor::instance().send_api_call(command_handle,
             Command::Payments(
                 PaymentsCommand::BuildGetTxnFeesReq(
                     wallet_handle,
@@ -1133,7 +1140,7 @@ pub extern "C" fn indy_parse_get_txn_fees_response(command_handle: CommandHandle
 
     let result =
         CommandExecutor::instance()
-            .send(Command::Payments(
+            .send_api_call(command_handle, Command::Payments(
                 PaymentsCommand::ParseGetTxnFeesResponse(
                     payment_method,
                     resp_json,
@@ -1174,7 +1181,7 @@ pub extern "C" fn indy_build_verify_payment_req(command_handle: CommandHandle,
     trace!("indy_build_verify_payment_req: entities >>> wallet_handle {:?}, submitter_did: {:?}, receipt: {:?}", wallet_handle, submitter_did, receipt);
 
     let result = CommandExecutor::instance()
-        .send(Command::Payments(
+        .send_api_call(command_handle, Command::Payments(
             PaymentsCommand::BuildVerifyPaymentReq(
                 wallet_handle,
                 submitter_did,
@@ -1184,6 +1191,7 @@ pub extern "C" fn indy_build_verify_payment_req(command_handle: CommandHandle,
                     trace!("indy_build_verify_payment_req: verify_txn_json: {:?}, payment_method: {:?}", verify_txn_json, payment_method);
                     let verify_txn_json = ctypes::string_to_cstring(verify_txn_json);
                     let payment_method = ctypes::string_to_cstring(payment_method);
+                    tracer::finish_api_call(command_handle);
                     cb(command_handle, err, verify_txn_json.as_ptr(), payment_method.as_ptr());
                 })
             )));
@@ -1227,7 +1235,7 @@ pub extern "C" fn indy_parse_verify_payment_response(command_handle: CommandHand
     trace!("indy_parse_verify_payment_response: entities >>> resp_json: {:?}", resp_json);
 
     let result = CommandExecutor::instance()
-        .send(Command::Payments(
+        .send_api_call(command_handle, Command::Payments(
             PaymentsCommand::ParseVerifyPaymentResponse(
                 payment_method,
                 resp_json,
@@ -1291,7 +1299,7 @@ pub extern "C" fn indy_get_request_info(command_handle: CommandHandle,
            get_auth_rule_response_json, requester_info_json, fees_json);
 
     let result = CommandExecutor::instance()
-        .send(Command::Payments(
+        .send_api_call(command_handle, Command::Payments(
             PaymentsCommand::GetRequestInfo(
                 get_auth_rule_response_json,
                 requester_info_json,
@@ -1343,7 +1351,7 @@ pub extern "C" fn indy_sign_with_address(command_handle: CommandHandle,
            wallet_handle, address, message_raw, message_len);
 
     let result = CommandExecutor::instance()
-        .send(Command::Payments(
+        .send_api_call(command_handle, Command::Payments(
             PaymentsCommand::SignWithAddressReq(wallet_handle,
                                                 address,
                                                 message_raw,
@@ -1351,6 +1359,7 @@ pub extern "C" fn indy_sign_with_address(command_handle: CommandHandle,
                                                     let (err, signature) = prepare_result_1!(result, Vec::new());
                                                     trace!("indy_sign_with_address: signature: {:?}", signature);
                                                     let (signature_raw, signature_len) = ctypes::vec_to_pointer(&signature);
+                                                    tracer::finish_api_call(command_handle);
                                                     cb(command_handle, err, signature_raw, signature_len)
                                         }))
         ));
@@ -1404,13 +1413,14 @@ pub extern "C" fn indy_verify_with_address(command_handle: CommandHandle,
            address, message_raw, message_len, signature_raw, signature_len);
 
     let result = CommandExecutor::instance()
-        .send(Command::Payments(PaymentsCommand::VerifyWithAddressReq(
+        .send_api_call(command_handle, Command::Payments(PaymentsCommand::VerifyWithAddressReq(
             address,
             message_raw,
             signature_raw,
             Box::new(move |result| {
                 let (err, valid) = prepare_result_1!(result, false);
                 trace!("indy_verify_with_address: valid: {:?}", valid);
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err, valid)
             })
         )));
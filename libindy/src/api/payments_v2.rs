@@ -6,6 +6,7 @@ use crate::commands::CommandExecutor;
 use crate::commands::Command;
 use crate::commands::payments::PaymentsCommand;
 use indy_utils::ctypes;
+use indy_utils::tracer;
 use indy_api_types::errors::prelude::*;
 use crate::domain::crypto::did::DidValue;
 use indy_api_types::validation::Validatable;
@@ -43,7 +44,7 @@ pub extern "C" fn indy_build_get_payment_sources_with_from_request(command_handl
     trace!("indy_build_get_payment_sources_with_from_request: entities >>> wallet_handle: {:?}, submitter_did: {:?}, payment_address: {:?}, from: {:?}", wallet_handle, submitter_did, payment_address, from);
 
     let result =
-        CommandExecutor::instance().send(
+        CommandExecutor::instance().send_api_call(command_handle,
             Command::Payments(
                 PaymentsCommand::BuildGetPaymentSourcesRequest(
                     wallet_handle,
@@ -55,6 +56,7 @@ pub extern "C" fn indy_build_get_payment_sources_with_from_request(command_handl
                         trace!("indy_build_get_payment_sources_with_from_request: get_sources_txn_json: {:?}, payment_method: {:?}", get_sources_txn_json, payment_method);
                         let get_sources_txn_json = ctypes::string_to_cstring(get_sources_txn_json);
                         let payment_method = ctypes::string_to_cstring(payment_method);
+                        tracer::finish_api_call(command_handle);
                         cb(command_handle, err, get_sources_txn_json.as_ptr(), payment_method.as_ptr());
                     }))
             ));
@@ -98,7 +100,7 @@ pub extern "C" fn indy_parse_get_payment_sources_with_from_response(command_hand
     trace!("indy_parse_get_payment_sources_with_from_response: entities >>> payment_method: {:?}, resp_json: {:?}", payment_method, resp_json);
 
     let result =
-        CommandExecutor::instance().send(
+        CommandExecutor::instance().send_api_call(command_handle,
             Command::Payments(
                 PaymentsCommand::ParseGetPaymentSourcesResponse(
                     payment_method,
@@ -107,6 +109,7 @@ pub extern "C" fn indy_parse_get_payment_sources_with_from_response(command_hand
                         let (err, sources_json, next) = prepare_result_2!(result, String::new(), -1);
                         trace!("indy_parse_get_payment_sources_with_from_response: sources_json: {:?}", sources_json);
                         let sources_json = ctypes::string_to_cstring(sources_json);
+                        tracer::finish_api_call(command_handle);
                         cb(command_handle, err, sources_json.as_ptr(), next);
                     }))
             ));
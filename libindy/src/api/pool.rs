@@ -5,6 +5,7 @@ use crate::commands::pool::PoolCommand;
 use crate::domain::pool::{PoolConfig, PoolOpenConfig};
 use indy_api_types::errors::prelude::*;
 use indy_utils::ctypes;
+use indy_utils::tracer;
 use indy_api_types::validation::Validatable;
 
 use serde_json;
@@ -41,12 +42,13 @@ pub extern "C" fn indy_create_pool_ledger_config(command_handle: CommandHandle,
     trace!("indy_create_pool_ledger_config: entities >>> config_name: {:?}, config: {:?}", config_name, config);
 
     let result = CommandExecutor::instance()
-        .send(Command::Pool(PoolCommand::Create(
+        .send_api_call(command_handle, Command::Pool(PoolCommand::Create(
             config_name,
             config,
             Box::new(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_create_pool_ledger_config:");
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err)
             })
         )));
@@ -104,12 +106,13 @@ pub extern "C" fn indy_open_pool_ledger(command_handle: CommandHandle,
     trace!("indy_open_pool_ledger: entities >>> config_name: {:?}, config: {:?}", config_name, config);
 
     let result = CommandExecutor::instance()
-        .send(Command::Pool(PoolCommand::Open(
+        .send_api_call(command_handle, Command::Pool(PoolCommand::Open(
             config_name,
             config,
             Box::new(move |result| {
                 let (err, pool_handle) = prepare_result_1!(result, INVALID_POOL_HANDLE);
                 trace!("indy_open_pool_ledger: pool_handle: {:?}", pool_handle);
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err, pool_handle)
             })
         )));
@@ -144,11 +147,12 @@ pub extern "C" fn indy_refresh_pool_ledger(command_handle: CommandHandle,
     trace!("indy_refresh_pool_ledger: entities >>> handle: {:?}", handle);
 
     let result = CommandExecutor::instance()
-        .send(Command::Pool(PoolCommand::Refresh(
+        .send_api_call(command_handle, Command::Pool(PoolCommand::Refresh(
             handle,
             Box::new(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_refresh_pool_ledger:");
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err)
             })
         )));
@@ -180,7 +184,7 @@ pub extern "C" fn indy_list_pools(command_handle: CommandHandle,
     trace!("indy_list_pools: entities >>>");
 
     let result = CommandExecutor::instance()
-        .send(Command::Pool(PoolCommand::List(boxed_callback_string!("indy_list_pools", cb, command_handle))));
+        .send_api_call(command_handle, Command::Pool(PoolCommand::List(boxed_callback_string!("indy_list_pools", cb, command_handle))));
 
     let res = prepare_result!(result);
 
@@ -189,6 +193,65 @@ pub extern "C" fn indy_list_pools(command_handle: CommandHandle,
     res
 }
 
+/// Returns the status of the last open or refresh of the pool ledger.
+/// Pool status is available while the pool is opened and after the opening has failed,
+/// so it can be used to find the nodes which prevent the pool from being opened.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// config_name: Name of the pool ledger configuration.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+/// status_json: {
+///     "state": string - one of "not_opened", "in_progress", "catching_up", "synced", "failed",
+///     "error": Optional<string> - error the opening has failed with,
+///     "txn_count": Optional<int> - size of the local pool ledger (on catchup start or after syncing),
+///     "merkle_root": Optional<string> - merkle root of the local pool ledger,
+///     "elapsed_ms": Optional<int> - time from catchup start to syncing or failure,
+///     "nodes": {
+///         <node alias>: {
+///             "address": string - client address of the node,
+///             "bls_key": bool - whether the node has BLS key,
+///             "reply": string - reply of the node to the pool ledger status:
+///                 one of "pending", "ledger_status", "consistency_proof", "timeout",
+///             "txn_count": Optional<int> - size of the pool ledger of the node,
+///             "merkle_root": Optional<string> - merkle root of the pool ledger of the node,
+///             "rtt_ms": Optional<int> - time from catchup start to the reply of the node,
+///         },
+///         ...
+///     }
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern "C" fn indy_get_pool_status(command_handle: CommandHandle,
+                                       config_name: *const c_char,
+                                       cb: Option<extern "C" fn(command_handle_: CommandHandle,
+                                                                err: ErrorCode,
+                                                                status_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_get_pool_status: >>> config_name: {:?}", config_name);
+
+    check_useful_c_str!(config_name, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_get_pool_status: entities >>> config_name: {:?}", config_name);
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Pool(PoolCommand::GetStatus(
+            config_name,
+            boxed_callback_string!("indy_get_pool_status", cb, command_handle))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_pool_status: <<< res: {:?}", res);
+
+    res
+}
+
 /// Closes opened pool ledger, opened nodes connections and frees allocated resources.
 ///
 /// #Params
@@ -212,11 +275,12 @@ pub extern "C" fn indy_close_pool_ledger(command_handle: CommandHandle,
     trace!("indy_close_pool_ledger: entities >>> handle: {:?}", handle);
 
     let result = CommandExecutor::instance()
-        .send(Command::Pool(PoolCommand::Close(
+        .send_api_call(command_handle, Command::Pool(PoolCommand::Close(
             handle,
             Box::new(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_close_pool_ledger:");
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err)
             })
         )));
@@ -252,11 +316,12 @@ pub extern "C" fn indy_delete_pool_ledger_config(command_handle: CommandHandle,
     trace!("indy_delete_pool_ledger_config: entities >>> config_name: {:?}", config_name);
 
     let result = CommandExecutor::instance()
-        .send(Command::Pool(PoolCommand::Delete(
+        .send_api_call(command_handle, Command::Pool(PoolCommand::Delete(
             config_name,
             Box::new(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_delete_pool_ledger_config:");
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err)
             })
         )));
@@ -297,12 +362,13 @@ pub extern "C" fn indy_set_protocol_version(command_handle: CommandHandle,
     trace!("indy_set_protocol_version: entities >>> protocol_version: {:?}", protocol_version);
 
     let result = CommandExecutor::instance()
-        .send(Command::Pool(
+        .send_api_call(command_handle, Command::Pool(
             PoolCommand::SetProtocolVersion(
             protocol_version,
             Box::new(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_set_protocol_version:");
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err)
             })
         )));
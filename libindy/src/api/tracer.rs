@@ -0,0 +1,48 @@
+use libc::c_void;
+
+use indy_api_types::ErrorCode;
+
+use crate::utils::tracer::{self, ExportSpanCB};
+
+/// Set tracing spans exporter.
+///
+/// Allows library user to follow a single API call through the command thread, crypto threadpool,
+/// pool worker and wallet storage by forwarding the spans to their own tracing system.
+///
+/// A span is opened per API call when its command is sent, carries its command handle and is finished right before the result is passed
+/// to the callback of the call. Command execution, wallet storage operations,
+/// pool requests with node sends and replies, and crypto threadpool jobs are reported as child spans.
+/// Every finished span is passed to `export` handler as JSON:
+/// {
+///     "trace_id": <int> - id of the trace, the same for all spans of an API call,
+///     "span_id": <int> - id of the span,
+///     "parent_span_id": <optional int> - id of the parent span,
+///     "name": <string> - name of the span (name of the sent command for the API call span),
+///     "command_handle": <optional int> - command handle of the API call span,
+///     "thread": <optional string> - name of the thread finished the span,
+///     "start_us": <int> - span start time in microseconds since the Unix epoch,
+///     "duration_us": <int> - span duration in microseconds,
+///     "attributes": <object> - string attributes of the span
+/// }
+///
+/// NOTE: `export` is called from libindy threads, so it must be thread safe and should not block.
+///
+/// #Params
+/// context: pointer to some tracer context that will be available in the export handler.
+/// export: (optional) "export" operation handler - called for every finished span. Pass NULL to disable tracing.
+///
+/// #Returns
+/// Error code
+#[no_mangle]
+pub extern "C" fn indy_set_tracer(context: *const c_void,
+                                  export: Option<ExportSpanCB>) -> ErrorCode {
+    trace!("indy_set_tracer >>> context: {:?}, export: {:?}", context, export);
+
+    tracer::set_tracer(context, export);
+
+    let res = ErrorCode::Success;
+
+    trace!("indy_set_tracer: <<< res: {:?}", res);
+
+    res
+}
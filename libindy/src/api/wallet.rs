@@ -6,6 +6,7 @@ use indy_api_types::domain::wallet::{Config, Credentials, ExportConfig, KeyConfi
 use indy_api_types::wallet::*;
 use indy_api_types::errors::prelude::*;
 use indy_utils::ctypes;
+use indy_utils::tracer;
 use indy_api_types::validation::Validatable;
 
 use serde_json;
@@ -104,7 +105,7 @@ pub extern "C" fn indy_register_wallet_storage(command_handle: CommandHandle,
     trace!("indy_register_wallet_type: params type_: {:?}", type_);
 
     let result = CommandExecutor::instance()
-        .send(Command::Wallet(
+        .send_api_call(command_handle, Command::Wallet(
             WalletCommand::RegisterWalletType(
                 type_,
                 create,
@@ -134,6 +135,7 @@ pub extern "C" fn indy_register_wallet_storage(command_handle: CommandHandle,
                 Box::new(move |result| {
                     let err = prepare_result!(result);
                     trace!("indy_register_wallet_type: cb command_handle: {:?}, err: {:?}", command_handle, err);
+                    tracer::finish_api_call(command_handle);
                     cb(command_handle, err)
                 })
             )));
@@ -199,12 +201,13 @@ pub extern "C" fn indy_create_wallet(command_handle: CommandHandle,
            config, secret!(&credentials));
 
     let result = CommandExecutor::instance()
-        .send(Command::Wallet(WalletCommand::Create(
+        .send_api_call(command_handle, Command::Wallet(WalletCommand::Create(
             config,
             credentials,
             Box::new(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_create_wallet: cb command_handle: {:?}, err: {:?}", command_handle, err);
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err)
             })
         )));
@@ -281,13 +284,14 @@ pub extern "C" fn indy_open_wallet(command_handle: CommandHandle,
            config, secret!(&credentials));
 
     let result = CommandExecutor::instance()
-        .send(Command::Wallet(WalletCommand::Open(
+        .send_api_call(command_handle, Command::Wallet(WalletCommand::Open(
             config,
             credentials,
             Box::new(move |result| {
                 let (err, handle) = prepare_result_1!(result, INVALID_WALLET_HANDLE);
                 trace!("indy_open_wallet: cb command_handle: {:?} err: {:?}, handle: {:?}",
                        command_handle, err, handle);
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err, handle)
             })
         )));
@@ -333,12 +337,13 @@ pub extern "C" fn indy_export_wallet(command_handle: CommandHandle,
     trace!("indy_export_wallet: params wallet_handle: {:?}, export_config: {:?}", wallet_handle, secret!(&export_config));
 
     let result = CommandExecutor::instance()
-        .send(Command::Wallet(WalletCommand::Export(
+        .send_api_call(command_handle, Command::Wallet(WalletCommand::Export(
             wallet_handle,
             export_config,
             Box::new(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_export_wallet: cb command_handle: {:?} err: {:?}", command_handle, err);
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err)
             })
         )));
@@ -414,13 +419,14 @@ pub extern "C" fn indy_import_wallet(command_handle: CommandHandle,
            config, secret!(&credentials), secret!(&import_config));
 
     let result = CommandExecutor::instance()
-        .send(Command::Wallet(WalletCommand::Import(
+        .send_api_call(command_handle, Command::Wallet(WalletCommand::Import(
             config,
             credentials,
             import_config,
             Box::new(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_import_wallet: cb command_handle: {:?}, err: {:?}", command_handle, err);
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err)
             })
         )));
@@ -431,6 +437,88 @@ pub extern "C" fn indy_import_wallet(command_handle: CommandHandle,
 }
 
 
+/// Creates a new secure wallet and then copies all records of the opened wallet into it.
+/// Records are re-encrypted with keys of the new wallet without writing them to an intermediate file,
+/// so it allows to move a wallet between storage types (e.g. from 'default' to a plugged storage).
+/// After copying the number of records of every type in the new wallet is checked against the opened one.
+/// If migration fails the new wallet is deleted.
+/// Records are copied in batches, so the opened wallet must not be closed until the migration completes.
+///
+/// #Params
+/// wallet_handle: wallet handle returned by indy_open_wallet
+/// config: Configuration json of the new wallet.
+/// {
+///   "id": string, Identifier of the wallet.
+///         Configured storage uses this identifier to lookup exact wallet data placement.
+///   "storage_type": optional<string>, Type of the wallet storage. Defaults to 'default'.
+///                  'Default' storage type allows to store wallet data in the local file.
+///                  Custom storage types can be registered with indy_register_wallet_storage call.
+///   "storage_config": optional<object>, Storage configuration json. Storage type defines set of supported keys.
+///                     Can be optional if storage supports default configuration.
+///                     For 'default' storage type configuration is:
+///   {
+///     "path": optional<string>, Path to the directory with wallet files.
+///             Defaults to $HOME/.indy_client/wallet.
+///             Wallet will be stored in the file {path}/{id}/sqlite.db
+///   }
+/// }
+/// credentials: Credentials json of the new wallet.
+/// {
+///   "key": string, Key or passphrase used for wallet key derivation.
+///                  Look to key_derivation_method param for information about supported key derivation methods.
+///   "storage_credentials": optional<object> Credentials for wallet storage. Storage type defines set of supported keys.
+///                          Can be optional if storage supports default configuration.
+///                          For 'default' storage type should be empty.
+///   "key_derivation_method": optional<string> Algorithm to use for wallet key derivation:
+///                             ARGON2I_MOD - derive secured wallet master key (used by default)
+///                             ARGON2I_INT - derive secured wallet master key (less secured but faster)
+///                             RAW - raw wallet key master provided (skip derivation).
+///                                RAW keys can be generated with indy_generate_wallet_key call
+/// }
+///
+/// #Returns
+/// Error code
+/// migration_result: Migrated records json
+/// {
+///   "records": {<record type>: <number of records>, ...},
+///   "total": <number of records>
+/// }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern "C" fn indy_migrate_wallet(command_handle: CommandHandle,
+                                      wallet_handle: WalletHandle,
+                                      config: *const c_char,
+                                      credentials: *const c_char,
+                                      cb: Option<extern "C" fn(command_handle_: CommandHandle,
+                                                               err: ErrorCode,
+                                                               migration_result: *const c_char)>) -> ErrorCode {
+    trace!("indy_migrate_wallet: >>> command_handle: {:?}, wallet_handle: {:?}, config: {:?}, credentials: {:?}, cb: {:?}",
+           command_handle, wallet_handle, config, credentials, cb);
+
+    check_useful_validatable_json!(config, ErrorCode::CommonInvalidParam3, Config);
+    check_useful_json!(credentials, ErrorCode::CommonInvalidParam4, Credentials);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_migrate_wallet: params wallet_handle: {:?}, config: {:?}, credentials: {:?}",
+           wallet_handle, config, secret!(&credentials));
+
+    let result = CommandExecutor::instance()
+        .send_api_call(command_handle, Command::Wallet(WalletCommand::Migrate(
+            wallet_handle,
+            config,
+            credentials,
+            boxed_callback_string!("indy_migrate_wallet", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+    trace!("indy_migrate_wallet: <<< res: {:?}", res);
+    res
+}
+
+
 /// Closes opened wallet and frees allocated resources.
 ///
 /// #Params
@@ -455,11 +543,12 @@ pub extern "C" fn indy_close_wallet(command_handle: CommandHandle,
     trace!("indy_close_wallet: params wallet_handle: {:?}", wallet_handle);
 
     let result = CommandExecutor::instance()
-        .send(Command::Wallet(WalletCommand::Close(
+        .send_api_call(command_handle, Command::Wallet(WalletCommand::Close(
             wallet_handle,
             Box::new(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_close_wallet: cb command_handle: {:?}, err: {:?}", command_handle, err);
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err)
             })
         )));
@@ -524,12 +613,13 @@ pub extern "C" fn indy_delete_wallet(command_handle: CommandHandle,
     trace!("indy_delete_wallet: params config: {:?}, credentials: {:?}", config, secret!(&credentials));
 
     let result = CommandExecutor::instance()
-        .send(Command::Wallet(WalletCommand::Delete(
+        .send_api_call(command_handle, Command::Wallet(WalletCommand::Delete(
             config,
             credentials,
             Box::new(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_delete_wallet: cb command_handle: {:?}, err: {:?}", command_handle, err);
+                tracer::finish_api_call(command_handle);
                 cb(command_handle, err)
             })
         )));
@@ -571,7 +661,7 @@ pub extern "C" fn indy_generate_wallet_key(command_handle: CommandHandle,
     trace!("indy_generate_wallet_key: params config: {:?}", secret!(config.as_ref()));
 
     let result = CommandExecutor::instance()
-        .send(Command::Wallet(WalletCommand::GenerateKey(
+        .send_api_call(command_handle, Command::Wallet(WalletCommand::GenerateKey(
             config,
             boxed_callback_string!("indy_generate_wallet_key", cb, command_handle)
         )));
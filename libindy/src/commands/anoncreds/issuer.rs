@@ -7,7 +7,8 @@ use ursa::cl::{
     RevocationRegistryDelta as CryptoRevocationRegistryDelta,
     Witness,
 };
-use ursa::cl::{CredentialKeyCorrectnessProof, CredentialPrivateKey};
+use ursa::cl::{CredentialKeyCorrectnessProof, CredentialPrivateKey, RevocationKeyPrivate, RevocationTailsGenerator, Tail};
+use ursa::cl::RevocationRegistry as CryptoRevocationRegistry;
 
 use crate::commands::{Command, CommandExecutor, BoxedCallbackStringStringSend};
 use crate::commands::anoncreds::AnoncredsCommand;
@@ -25,6 +26,7 @@ use crate::domain::anoncreds::credential_definition::{
 };
 use crate::domain::anoncreds::credential_offer::CredentialOffer;
 use crate::domain::anoncreds::credential_request::CredentialRequest;
+use crate::domain::anoncreds::issuance_record::{IssuanceRecord, IssuanceRecordConfig, ISSUANCE_RECORD_VALUES_SALT_SIZE};
 use crate::domain::anoncreds::revocation_registry::{
     RevocationRegistry,
     RevocationRegistryV1,
@@ -37,6 +39,7 @@ use crate::domain::anoncreds::revocation_registry_definition::{
     RevocationRegistryDefinitionPrivate,
     RevocationRegistryDefinitionV1,
     RevocationRegistryDefinitionValue,
+    RevocationRegistryDefinitionValuePublicKeys,
     RevocationRegistryInfo,
     RevocationRegistryId
 };
@@ -44,20 +47,25 @@ use crate::domain::anoncreds::revocation_registry_delta::{
     RevocationRegistryDelta,
     RevocationRegistryDeltaV1,
 };
-use crate::domain::anoncreds::schema::{AttributeNames, Schema, SchemaV1, SchemaId};
+use crate::domain::anoncreds::schema::{AttributeNames, AttributeTypes, Schema, SchemaV1, SchemaId};
 use crate::domain::crypto::did::DidValue;
 use indy_api_types::domain::wallet::Tags;
 use indy_api_types::errors::prelude::*;
+use indy_api_types::validation::Validatable;
+use crate::domain::audit::{AuditEvent, AuditEventType};
 use crate::services::anoncreds::AnoncredsService;
-use crate::services::anoncreds::helpers::parse_cred_rev_id;
+use crate::services::audit::AuditService;
+use crate::services::anoncreds::helpers::{get_seconds_since_epoch, parse_cred_rev_id, is_credential_value_encoded};
 use crate::services::blob_storage::BlobStorageService;
 use crate::services::crypto::CryptoService;
 use crate::services::pool::PoolService;
-use indy_wallet::{RecordOptions, WalletService};
+use crate::utils::rollback::Rollback;
+use indy_wallet::{RecordOptions, SearchOptions, WalletSearch, WalletService};
 
-use super::tails::{SDKTailsAccessor, store_tails_from_generator};
-use indy_api_types::{WalletHandle, CommandHandle};
-use indy_utils::next_command_handle;
+use super::tails::{SDKTailsAccessor, TAILS_CHUNK_SIZE, abort_tails_blob, create_tails_blob, finalize_tails_blob, generate_tails_chunk};
+use indy_api_types::{WalletHandle, CommandHandle, SearchHandle};
+use indy_utils::{next_command_handle, next_search_handle};
+use indy_utils::crypto::randombytes;
 
 pub enum IssuerCommand {
     CreateSchema(
@@ -65,6 +73,7 @@ pub enum IssuerCommand {
         String, // name
         String, // version
         AttributeNames, // attribute names
+        Option<AttributeTypes>, // attribute types
         BoxedCallbackStringStringSend),
     CreateAndStoreCredentialDefinition(
         WalletHandle,
@@ -112,7 +121,17 @@ pub enum IssuerCommand {
         CredentialDefinitionId, // credential definition id
         RevocationRegistryConfig, // config
         i32, // tails writer handle
+        Option<Box<dyn Fn(u32, u32) + Send>>, // progress callback
         Box<dyn Fn(IndyResult<(String, String, String)>) + Send>),
+    CreateAndStoreRevocationRegistryContinue(
+        IndyResult<(RevocationRegistryDefinitionValuePublicKeys,
+                    RevocationKeyPrivate,
+                    CryptoRevocationRegistry,
+                    RevocationTailsGenerator)>,
+        CommandHandle),
+    CreateAndStoreRevocationRegistryTails(
+        IndyResult<Vec<u8>>, // tails bytes
+        CommandHandle),
     CreateCredentialOffer(
         WalletHandle,
         CredentialDefinitionId, // credential definition id
@@ -124,6 +143,7 @@ pub enum IssuerCommand {
         CredentialValues, // credential values
         Option<RevocationRegistryId>, // revocation registry id
         Option<i32>, // blob storage reader config handle
+        Option<IssuanceRecordConfig>, // issuance record config
         Box<dyn Fn(IndyResult<(String, Option<String>, Option<String>)>) + Send>),
     RevokeCredential(
         WalletHandle,
@@ -131,6 +151,26 @@ pub enum IssuerCommand {
         RevocationRegistryId, //revocation registry id
         String, //credential revoc id
         Box<dyn Fn(IndyResult<String>) + Send>),
+    RevokeCredentialByRecord(
+        WalletHandle,
+        i32, // blob storage reader config handle
+        String, // issuance record id
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    GetIssuanceRecord(
+        WalletHandle,
+        String, // issuance record id
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    SearchIssuanceRecords(
+        WalletHandle,
+        Option<String>, // query json
+        Box<dyn Fn(IndyResult<(SearchHandle, usize)>) + Send>),
+    FetchIssuanceRecords(
+        SearchHandle, // search handle
+        usize, // count
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    CloseIssuanceRecordsSearch(
+        SearchHandle, // search handle
+        Box<dyn Fn(IndyResult<()>) + Send>),
     /*    RecoverCredential(
             WalletHandle,
             i32, // blob storage reader config handle
@@ -149,8 +189,29 @@ pub struct IssuerCommandExecutor {
     pub pool_service: Rc<PoolService>,
     pub wallet_service: Rc<WalletService>,
     pub crypto_service: Rc<CryptoService>,
+    audit_service: Rc<AuditService>,
     pending_str_str_callbacks: RefCell<HashMap<CommandHandle, BoxedCallbackStringStringSend>>,
     pending_str_callbacks: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<String>) + Send>>>,
+    issuance_record_searches: RefCell<HashMap<SearchHandle, Box<WalletSearch>>>,
+    pending_revocation_registries: RefCell<HashMap<CommandHandle, PendingRevocationRegistry>>,
+}
+
+/// Revocation registry which tails are generated in the threadpool.
+struct PendingRevocationRegistry {
+    wallet_handle: WalletHandle,
+    rev_reg_id: RevocationRegistryId,
+    rev_reg_type: RegistryType,
+    tag: String,
+    cred_def_id: CredentialDefinitionId,
+    max_cred_num: u32,
+    issuance_type: IssuanceType,
+    tails_writer_handle: i32,
+    registry: Option<(RevocationRegistryDefinitionValuePublicKeys, RevocationKeyPrivate, CryptoRevocationRegistry)>,
+    blob_handle: Option<i32>,
+    tails_written: u32,
+    tails_count: u32,
+    progress: Option<Box<dyn Fn(u32, u32) + Send>>,
+    cb: Box<dyn Fn(IndyResult<(String, String, String)>) + Send>,
 }
 
 impl IssuerCommandExecutor {
@@ -158,23 +219,27 @@ impl IssuerCommandExecutor {
                pool_service: Rc<PoolService>,
                blob_storage_service: Rc<BlobStorageService>,
                wallet_service: Rc<WalletService>,
-               crypto_service: Rc<CryptoService>) -> IssuerCommandExecutor {
+               crypto_service: Rc<CryptoService>,
+               audit_service: Rc<AuditService>) -> IssuerCommandExecutor {
         IssuerCommandExecutor {
             anoncreds_service,
             pool_service,
             blob_storage_service,
             wallet_service,
             crypto_service,
+            audit_service,
             pending_str_str_callbacks: RefCell::new(HashMap::new()),
             pending_str_callbacks: RefCell::new(HashMap::new()),
+            issuance_record_searches: RefCell::new(HashMap::new()),
+            pending_revocation_registries: RefCell::new(HashMap::new()),
         }
     }
 
     pub fn execute(&self, command: IssuerCommand) {
         match command {
-            IssuerCommand::CreateSchema(issuer_did, name, version, attrs, cb) => {
+            IssuerCommand::CreateSchema(issuer_did, name, version, attrs, attr_types, cb) => {
                 debug!(target: "issuer_command_executor", "CreateSchema command received");
-                cb(self.create_schema(&issuer_did, &name, &version, attrs));
+                cb(self.create_schema(&issuer_did, &name, &version, attrs, attr_types));
             }
             IssuerCommand::CreateAndStoreCredentialDefinition(wallet_handle, issuer_did, schema, tag, type_, config, cb) => {
                 debug!(target: "issuer_command_executor", "CreateAndStoreCredentialDefinition command received");
@@ -198,28 +263,58 @@ impl IssuerCommandExecutor {
                 cb(self.rotate_credential_definition_apply(wallet_handle, &cred_def_id));
             }
             IssuerCommand::CreateAndStoreRevocationRegistry(wallet_handle, issuer_did, type_, tag, cred_def_id, config,
-                                                            tails_writer_handle, cb) => {
+                                                            tails_writer_handle, progress, cb) => {
                 debug!(target: "issuer_command_executor", "CreateAndStoreRevocationRegistryRegistry command received");
-                cb(self.create_and_store_revocation_registry(wallet_handle,
-                                                             &issuer_did,
-                                                             type_.as_ref().map(String::as_str),
-                                                             &tag,
-                                                             &cred_def_id,
-                                                             &config,
-                                                             tails_writer_handle));
+                self.create_and_store_revocation_registry(wallet_handle,
+                                                          &issuer_did,
+                                                          type_.as_ref().map(String::as_str),
+                                                          &tag,
+                                                          &cred_def_id,
+                                                          &config,
+                                                          tails_writer_handle,
+                                                          progress,
+                                                          cb);
+            }
+            IssuerCommand::CreateAndStoreRevocationRegistryContinue(result, cb_id) => {
+                debug!(target: "issuer_command_executor", "CreateAndStoreRevocationRegistryContinue command received");
+                self._create_and_store_revocation_registry_continue(cb_id, result);
+            }
+            IssuerCommand::CreateAndStoreRevocationRegistryTails(result, cb_id) => {
+                debug!(target: "issuer_command_executor", "CreateAndStoreRevocationRegistryTails command received");
+                self._create_and_store_revocation_registry_tails(cb_id, result);
             }
             IssuerCommand::CreateCredentialOffer(wallet_handle, cred_def_id, cb) => {
                 debug!(target: "issuer_command_executor", "CreateCredentialOffer command received");
                 cb(self.create_credential_offer(wallet_handle, &cred_def_id));
             }
-            IssuerCommand::CreateCredential(wallet_handle, cred_offer, cred_req, cred_values, rev_reg_id, blob_storage_reader_handle, cb) => {
+            IssuerCommand::CreateCredential(wallet_handle, cred_offer, cred_req, cred_values, rev_reg_id, blob_storage_reader_handle, record_config, cb) => {
                 debug!(target: "issuer_command_executor", "CreateCredential command received");
-                cb(self.new_credential(wallet_handle, &cred_offer, &cred_req, &cred_values, rev_reg_id.as_ref(), blob_storage_reader_handle));
+                cb(self.new_credential(wallet_handle, &cred_offer, &cred_req, &cred_values, rev_reg_id.as_ref(), blob_storage_reader_handle, record_config.as_ref()));
             }
             IssuerCommand::RevokeCredential(wallet_handle, blob_storage_reader_handle, rev_reg_id, cred_revoc_id, cb) => {
                 debug!(target: "issuer_command_executor", "RevokeCredential command received");
                 cb(self.revoke_credential(wallet_handle, blob_storage_reader_handle, &rev_reg_id, &cred_revoc_id));
             }
+            IssuerCommand::RevokeCredentialByRecord(wallet_handle, blob_storage_reader_handle, record_id, cb) => {
+                debug!(target: "issuer_command_executor", "RevokeCredentialByRecord command received");
+                cb(self.revoke_credential_by_record(wallet_handle, blob_storage_reader_handle, &record_id));
+            }
+            IssuerCommand::GetIssuanceRecord(wallet_handle, record_id, cb) => {
+                debug!(target: "issuer_command_executor", "GetIssuanceRecord command received");
+                cb(self.get_issuance_record(wallet_handle, &record_id));
+            }
+            IssuerCommand::SearchIssuanceRecords(wallet_handle, query_json, cb) => {
+                debug!(target: "issuer_command_executor", "SearchIssuanceRecords command received");
+                cb(self.search_issuance_records(wallet_handle, query_json.as_ref().map(String::as_str)));
+            }
+            IssuerCommand::FetchIssuanceRecords(search_handle, count, cb) => {
+                debug!(target: "issuer_command_executor", "FetchIssuanceRecords command received");
+                cb(self.fetch_issuance_records(search_handle, count));
+            }
+            IssuerCommand::CloseIssuanceRecordsSearch(search_handle, cb) => {
+                debug!(target: "issuer_command_executor", "CloseIssuanceRecordsSearch command received");
+                cb(self.close_issuance_records_search(search_handle));
+            }
             /*            IssuerCommand::RecoverCredential(wallet_handle, blob_storage_reader_handle, rev_reg_id, cred_revoc_id, cb) => {
                             debug!(target: "issuer_command_executor", "RecoverCredential command received");
                             cb(self.recovery_credential(wallet_handle, blob_storage_reader_handle, &rev_reg_id, &cred_revoc_id));
@@ -236,8 +331,9 @@ impl IssuerCommandExecutor {
                      issuer_did: &DidValue,
                      name: &str,
                      version: &str,
-                     attrs: AttributeNames) -> IndyResult<(String, String)> {
-        debug!("create_schema >>> issuer_did: {:?}, name: {:?}, version: {:?}, attrs: {:?}", issuer_did, name, version, attrs);
+                     attrs: AttributeNames,
+                     attr_types: Option<AttributeTypes>) -> IndyResult<(String, String)> {
+        debug!("create_schema >>> issuer_did: {:?}, name: {:?}, version: {:?}, attrs: {:?}, attr_types: {:?}", issuer_did, name, version, attrs, attr_types);
 
         self.crypto_service.validate_did(issuer_did)?;
 
@@ -249,8 +345,14 @@ impl IssuerCommandExecutor {
             version: version.to_string(),
             attr_names: attrs,
             seq_no: None,
+            attr_types: attr_types.clone(),
         });
 
+        if attr_types.is_some() {
+            schema.validate()
+                .map_err(|err| err_msg(IndyErrorKind::InvalidStructure, err))?;
+        }
+
         let schema_json = serde_json::to_string(&schema)
             .to_indy(IndyErrorKind::InvalidState, "Cannot serialize Schema")?;
 
@@ -310,7 +412,7 @@ impl IssuerCommandExecutor {
                                                                 CredentialPrivateKey,
                                                                 CredentialKeyCorrectnessProof)>) + Send>) {
         let attr_names = attr_names.clone();
-        crate::commands::THREADPOOL.lock().unwrap().execute(move || cb(crate::services::anoncreds::issuer::Issuer::new_credential_definition(&attr_names, support_revocation)));
+        crate::commands::execute_in_threadpool("new_credential_definition", move || cb(crate::services::anoncreds::issuer::Issuer::new_credential_definition(&attr_names, support_revocation)));
     }
 
     fn _create_and_store_credential_definition_continue(&self,
@@ -548,10 +650,63 @@ impl IssuerCommandExecutor {
                                             tag: &str,
                                             cred_def_id: &CredentialDefinitionId,
                                             config: &RevocationRegistryConfig,
-                                            tails_writer_handle: i32) -> IndyResult<(String, String, String)> {
+                                            tails_writer_handle: i32,
+                                            progress: Option<Box<dyn Fn(u32, u32) + Send>>,
+                                            cb: Box<dyn Fn(IndyResult<(String, String, String)>) + Send>) {
         debug!("create_and_store_revocation_registry >>> wallet_handle: {:?}, issuer_did: {:?}, type_: {:?}, tag: {:?}, cred_def_id: {:?}, config: {:?}, \
                tails_handle: {:?}", wallet_handle, issuer_did, type_, tag, cred_def_id, config, tails_writer_handle);
 
+        let (rev_reg_id, rev_reg_type, cred_def) =
+            try_cb!(self._prepare_create_and_store_revocation_registry(wallet_handle, issuer_did, type_, tag, cred_def_id), cb);
+
+        if let (Ok(rev_reg_def), Ok(rev_reg)) = (self.wallet_service.get_indy_record_value::<RevocationRegistryDefinition>(wallet_handle, &rev_reg_id.0, &RecordOptions::id_value()),
+                                                 self.wallet_service.get_indy_record_value::<RevocationRegistry>(wallet_handle, &rev_reg_id.0, &RecordOptions::id_value())) {
+            return cb(Ok((cred_def_id.0.to_string(), rev_reg_def, rev_reg)));
+        }
+
+        let issuance_type = config.issuance_type.clone().unwrap_or(IssuanceType::ISSUANCE_ON_DEMAND);
+        let max_cred_num = config.max_cred_num.unwrap_or(100000);
+        let issuance_by_default = issuance_type.to_bool();
+
+        let cb_id = next_command_handle();
+
+        self.pending_revocation_registries.borrow_mut().insert(cb_id, PendingRevocationRegistry {
+            wallet_handle,
+            rev_reg_id,
+            rev_reg_type,
+            tag: tag.to_string(),
+            cred_def_id: cred_def_id.clone(),
+            max_cred_num,
+            issuance_type,
+            tails_writer_handle,
+            registry: None,
+            blob_handle: None,
+            tails_written: 0,
+            tails_count: 0,
+            progress,
+            cb,
+        });
+
+        let issuer_did = issuer_did.clone();
+
+        crate::commands::execute_in_threadpool("new_revocation_registry", move || {
+            let res = crate::services::anoncreds::issuer::Issuer::new_revocation_registry(&cred_def, max_cred_num, issuance_by_default, &issuer_did);
+
+            CommandExecutor::instance().send(
+                Command::Anoncreds(
+                    AnoncredsCommand::Issuer(
+                        IssuerCommand::CreateAndStoreRevocationRegistryContinue(res, cb_id)
+                    )
+                )).unwrap();
+        });
+    }
+
+    fn _prepare_create_and_store_revocation_registry(&self,
+                                                     wallet_handle: WalletHandle,
+                                                     issuer_did: &DidValue,
+                                                     type_: Option<&str>,
+                                                     tag: &str,
+                                                     cred_def_id: &CredentialDefinitionId) -> IndyResult<(RevocationRegistryId, RegistryType, CredentialDefinitionV1)> {
         match (issuer_did.get_method(), cred_def_id.get_method()) {
             (None, Some(_)) => {
                 return Err(IndyError::from_msg(IndyErrorKind::InvalidStructure, "You can't use unqualified Did with fully qualified Credential Definition"));
@@ -569,30 +724,132 @@ impl IssuerCommandExecutor {
             RegistryType::CL_ACCUM
         };
 
-        let issuance_type = config.issuance_type.clone().unwrap_or(IssuanceType::ISSUANCE_ON_DEMAND);
-        let max_cred_num = config.max_cred_num.unwrap_or(100000);
-
         let rev_reg_id = RevocationRegistryId::new(&issuer_did, &cred_def_id, &rev_reg_type.to_str(), tag);
 
-        if let (Ok(rev_reg_def), Ok(rev_reg)) = (self.wallet_service.get_indy_record_value::<RevocationRegistryDefinition>(wallet_handle, &rev_reg_id.0, &RecordOptions::id_value()),
-                                                 self.wallet_service.get_indy_record_value::<RevocationRegistry>(wallet_handle, &rev_reg_id.0, &RecordOptions::id_value())) {
-            return Ok((cred_def_id.0.to_string(), rev_reg_def, rev_reg));
+        let cred_def: CredentialDefinition = self.wallet_service.get_indy_object(wallet_handle, &cred_def_id.0, &RecordOptions::id_value())?;
+
+        Ok((rev_reg_id, rev_reg_type, CredentialDefinitionV1::from(cred_def)))
+    }
+
+    fn _create_and_store_revocation_registry_continue(&self,
+                                                      cb_id: CommandHandle,
+                                                      result: IndyResult<(RevocationRegistryDefinitionValuePublicKeys,
+                                                                          RevocationKeyPrivate,
+                                                                          CryptoRevocationRegistry,
+                                                                          RevocationTailsGenerator)>) {
+        let res = result.and_then(|(rev_keys_pub, rev_key_priv, rev_reg, rev_tails_generator)| {
+            let mut pending_revocation_registries = self.pending_revocation_registries.borrow_mut();
+            let pending = pending_revocation_registries.get_mut(&cb_id)
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Pending RevocationRegistry not found"))?;
+
+            pending.blob_handle = Some(create_tails_blob(self.blob_storage_service.clone(), pending.tails_writer_handle)?);
+            pending.registry = Some((rev_keys_pub, rev_key_priv, rev_reg));
+            pending.tails_count = rev_tails_generator.count();
+
+            // Ursa generator can only produce tails in order, so one job generates all of them
+            // and sends them by chunks to be written to the blob while the rest are generated.
+            crate::commands::execute_in_threadpool("generate_tails", move || {
+                let mut rev_tails_generator = rev_tails_generator;
+
+                while rev_tails_generator.count() > 0 {
+                    let res = generate_tails_chunk(&mut rev_tails_generator, TAILS_CHUNK_SIZE);
+                    let is_err = res.is_err();
+
+                    CommandExecutor::instance().send(
+                        Command::Anoncreds(
+                            AnoncredsCommand::Issuer(
+                                IssuerCommand::CreateAndStoreRevocationRegistryTails(res, cb_id)
+                            )
+                        )).unwrap();
+
+                    if is_err {
+                        break;
+                    }
+                }
+            });
+
+            Ok(())
+        });
+
+        match res {
+            Ok(()) => self._try_complete_create_and_store_revocation_registry(cb_id),
+            Err(err) => self._fail_create_and_store_revocation_registry(cb_id, err)
         }
+    }
 
-        let cred_def: CredentialDefinition = self.wallet_service.get_indy_object(wallet_handle, &cred_def_id.0, &RecordOptions::id_value())?;
+    fn _create_and_store_revocation_registry_tails(&self,
+                                                   cb_id: CommandHandle,
+                                                   result: IndyResult<Vec<u8>>) {
+        let res = result.and_then(|tails| {
+            let mut pending_revocation_registries = self.pending_revocation_registries.borrow_mut();
+
+            // Registry creation has already failed, the rest of tails are dropped.
+            let pending = match pending_revocation_registries.get_mut(&cb_id) {
+                Some(pending) => pending,
+                None => return Ok(())
+            };
+
+            let blob_handle = pending.blob_handle
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Tails blob not found"))?;
+
+            self.blob_storage_service.append(blob_handle, tails.as_slice())?;
+
+            pending.tails_written += (tails.len() / Tail::BYTES_REPR_SIZE) as u32;
+
+            if let Some(ref progress) = pending.progress {
+                progress(pending.tails_written, pending.tails_count);
+            }
+
+            Ok(())
+        });
 
-        let (revoc_public_keys, revoc_key_private, revoc_registry, mut revoc_tails_generator) =
-            self.anoncreds_service.issuer.new_revocation_registry(&CredentialDefinitionV1::from(cred_def),
-                                                                  max_cred_num,
-                                                                  issuance_type.to_bool(),
-                                                                  &issuer_did)?;
+        match res {
+            Ok(()) => self._try_complete_create_and_store_revocation_registry(cb_id),
+            Err(err) => self._fail_create_and_store_revocation_registry(cb_id, err)
+        }
+    }
+
+    fn _try_complete_create_and_store_revocation_registry(&self, cb_id: CommandHandle) {
+        let is_completed = self.pending_revocation_registries.borrow().get(&cb_id)
+            .map(|pending| pending.registry.is_some() && pending.tails_written == pending.tails_count)
+            .unwrap_or(false);
+
+        if !is_completed {
+            return;
+        }
+
+        let mut pending = self.pending_revocation_registries.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
 
-        let (tails_location, tails_hash) =
-            store_tails_from_generator(self.blob_storage_service.clone(), tails_writer_handle, &mut revoc_tails_generator)?;
+        let res = self._complete_create_and_store_revocation_registry(&mut pending);
+
+        (pending.cb)(res);
+    }
+
+    fn _fail_create_and_store_revocation_registry(&self, cb_id: CommandHandle, err: IndyError) {
+        if let Some(pending) = self.pending_revocation_registries.borrow_mut().remove(&cb_id) {
+            if let Some(blob_handle) = pending.blob_handle {
+                abort_tails_blob(self.blob_storage_service.clone(), blob_handle);
+            }
+
+            (pending.cb)(Err(err));
+        }
+    }
+
+    fn _complete_create_and_store_revocation_registry(&self, pending: &mut PendingRevocationRegistry) -> IndyResult<(String, String, String)> {
+        let (revoc_public_keys, revoc_key_private, revoc_registry) = pending.registry.take()
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "RevocationRegistry not found"))?;
+
+        let blob_handle = pending.blob_handle
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Tails blob not found"))?;
+
+        let (tails_location, tails_hash) = finalize_tails_blob(self.blob_storage_service.clone(), blob_handle)?;
+
+        let wallet_handle = pending.wallet_handle;
+        let rev_reg_id = &pending.rev_reg_id;
 
         let revoc_reg_def_value = RevocationRegistryDefinitionValue {
-            max_cred_num,
-            issuance_type,
+            max_cred_num: pending.max_cred_num,
+            issuance_type: pending.issuance_type.clone(),
             public_keys: revoc_public_keys,
             tails_location,
             tails_hash,
@@ -602,9 +859,9 @@ impl IssuerCommandExecutor {
             RevocationRegistryDefinition::RevocationRegistryDefinitionV1(
                 RevocationRegistryDefinitionV1 {
                     id: rev_reg_id.clone(),
-                    revoc_def_type: rev_reg_type,
-                    tag: tag.to_string(),
-                    cred_def_id: cred_def_id.clone(),
+                    revoc_def_type: pending.rev_reg_type.clone(),
+                    tag: pending.tag.clone(),
+                    cred_def_id: pending.cred_def_id.clone(),
                     value: revoc_reg_def_value,
                 });
 
@@ -636,7 +893,7 @@ impl IssuerCommandExecutor {
         debug!("create_and_store_revocation_registry <<< rev_reg_id: {:?}, revoc_reg_def_json: {:?}, revoc_reg_json: {:?}",
                rev_reg_id, revoc_reg_def_json, revoc_reg_json);
 
-        Ok((rev_reg_id.0, revoc_reg_def_json, revoc_reg_json))
+        Ok((rev_reg_id.0.clone(), revoc_reg_def_json, revoc_reg_json))
     }
 
     fn create_credential_offer(&self,
@@ -673,9 +930,16 @@ impl IssuerCommandExecutor {
                       cred_request: &CredentialRequest,
                       cred_values: &CredentialValues,
                       rev_reg_id: Option<&RevocationRegistryId>,
-                      blob_storage_reader_handle: Option<i32>) -> IndyResult<(String, Option<String>, Option<String>)> {
-        debug!("new_credential >>> wallet_handle: {:?}, cred_offer: {:?}, cred_req: {:?}, cred_values_json: {:?}, rev_reg_id: {:?}, blob_storage_reader_handle: {:?}",
-               wallet_handle, secret!(&cred_offer), secret!(&cred_request), secret!(&cred_values), rev_reg_id, blob_storage_reader_handle);
+                      blob_storage_reader_handle: Option<i32>,
+                      record_config: Option<&IssuanceRecordConfig>) -> IndyResult<(String, Option<String>, Option<String>)> {
+        debug!("new_credential >>> wallet_handle: {:?}, cred_offer: {:?}, cred_req: {:?}, cred_values_json: {:?}, rev_reg_id: {:?}, blob_storage_reader_handle: {:?}, record_config: {:?}",
+               wallet_handle, secret!(&cred_offer), secret!(&cred_request), secret!(&cred_values), rev_reg_id, blob_storage_reader_handle, record_config);
+
+        if let Some(record_config) = record_config {
+            if self.wallet_service.record_exists::<IssuanceRecord>(wallet_handle, &record_config.id)? {
+                return Err(err_msg(IndyErrorKind::WalletItemAlreadyExists, format!("IssuanceRecord already exists for id: {}", record_config.id)));
+            }
+        }
 
         let cred_def_id = match cred_offer.method_name {
             Some(ref method_name) => cred_offer.cred_def_id.qualify(method_name),
@@ -689,6 +953,8 @@ impl IssuerCommandExecutor {
         let cred_def_priv_key: CredentialDefinitionPrivateKey =
             self.wallet_service.get_indy_object(wallet_handle, &cred_def_id.0, &RecordOptions::id_value())?;
 
+        self._check_typed_credential_values(wallet_handle, &cred_def, cred_values)?;
+
         let (rev_reg_def, mut rev_reg,
             rev_reg_def_priv, sdk_tails_accessor, rev_reg_info) = match rev_reg_id {
             Some(ref r_reg_id) => {
@@ -782,14 +1048,66 @@ impl IssuerCommandExecutor {
             .map_or(Ok(None), |v| v.map(Some))
             .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationRegistryDelta")?;
 
-        if let (Some(r_reg), Some(r_reg_id), Some(r_reg_info)) = (credential.rev_reg, rev_reg_id, rev_reg_info.clone()) {
+        let cred_rev_id = rev_reg_info.as_ref().map(|r_reg_info| r_reg_info.curr_id.to_string());
+
+        let mut event = AuditEvent::new(AuditEventType::CredentialIssued)
+            .detail("cred_def_id", &cred_def_id.0);
+
+        if let Some((issuer_did, _, _, _)) = cred_def_id.parts() {
+            event = event.did(&issuer_did.0);
+        }
+
+        if let (Some(r_reg_id), Some(ref cred_rev_id)) = (rev_reg_id, &cred_rev_id) {
+            event = event.detail("rev_reg_id", &r_reg_id.0).detail("cred_rev_id", cred_rev_id);
+        }
+
+        if let Some(record_config) = record_config {
+            event = event.detail("record_id", &record_config.id);
+        }
+
+        // Wallet storage has no transactions, so the issuance record, the registry and the registry info
+        // are written one after another and the applied writes are undone if a later one fails.
+        // The record goes first, so a stored registry state never refers to an unrecorded issuance.
+        let mut rollback = Rollback::new();
+
+        if let Some(record_config) = record_config {
+            let values_salt = randombytes::randombytes(ISSUANCE_RECORD_VALUES_SALT_SIZE);
+
+            let record = IssuanceRecord {
+                id: record_config.id.clone(),
+                cred_def_id: cred_def_id.clone(),
+                rev_reg_id: rev_reg_id.cloned(),
+                cred_rev_id: cred_rev_id.clone(),
+                issued_at: get_seconds_since_epoch()?,
+                revoked_at: None,
+                tags: record_config.tags.clone(),
+                values_hash: self.anoncreds_service.issuer.credential_values_hash(cred_values, &values_salt)?,
+                values_salt: hex::encode(&values_salt),
+            };
+
+            rollback.apply("issuance record",
+                           || self.wallet_service.add_indy_object(wallet_handle, &record.id, &record, &record.wallet_tags()).map(|_| ()),
+                           move || self.wallet_service.delete_indy_record::<IssuanceRecord>(wallet_handle, &record_config.id))?;
+        }
+
+        if let (Some(r_reg), Some(r_reg_id), Some(r_reg_info)) = (credential.rev_reg, rev_reg_id, rev_reg_info) {
             let revoc_reg = RevocationRegistry::RevocationRegistryV1(RevocationRegistryV1 { value: r_reg });
 
-            self.wallet_service.update_indy_object(wallet_handle, &r_reg_id.0, &revoc_reg)?;
-            self.wallet_service.update_indy_object(wallet_handle, &r_reg_id.0, &r_reg_info)?;
+            let prev_revoc_reg = self._wallet_get_rev_reg(wallet_handle, r_reg_id)?;
+            let prev_rev_reg_info = self._wallet_get_rev_reg_info(wallet_handle, r_reg_id)?;
+
+            rollback.apply("revocation registry",
+                           || self.wallet_service.update_indy_object(wallet_handle, &r_reg_id.0, &revoc_reg).map(|_| ()),
+                           move || self.wallet_service.update_indy_object(wallet_handle, &r_reg_id.0, &prev_revoc_reg).map(|_| ()))?;
+
+            rollback.apply("revocation registry info",
+                           || self.wallet_service.update_indy_object(wallet_handle, &r_reg_id.0, &r_reg_info).map(|_| ()),
+                           move || self.wallet_service.update_indy_object(wallet_handle, &r_reg_id.0, &prev_rev_reg_info).map(|_| ()))?;
         };
 
-        let cred_rev_id = rev_reg_info.map(|r_reg_info| r_reg_info.curr_id.to_string());
+        rollback.commit();
+
+        self.audit_service.record(wallet_handle, event);
 
         debug!("new_credential <<< cred_json: {:?}, cred_rev_id: {:?}, rev_reg_delta_json: {:?}", secret!(&cred_json), secret!(&cred_rev_id), rev_reg_delta_json);
 
@@ -847,14 +1165,170 @@ impl IssuerCommandExecutor {
 
         let rev_reg = RevocationRegistry::RevocationRegistryV1(rev_reg);
 
-        self.wallet_service.update_indy_object(wallet_handle, &rev_reg_id.0, &rev_reg)?;
+        let mut event = AuditEvent::new(AuditEventType::CredentialRevoked)
+            .detail("rev_reg_id", &rev_reg_id.0)
+            .detail("cred_rev_id", &cred_revoc_id.to_string());
+
+        if let Some((issuer_did, _, _, _)) = rev_reg_id.parts() {
+            event = event.did(&issuer_did.0);
+        }
+
+        let prev_rev_reg = self._wallet_get_rev_reg(wallet_handle, rev_reg_id)?;
+
+        let mut rollback = Rollback::new();
+
+        rollback.apply("revocation registry",
+                       || self.wallet_service.update_indy_object(wallet_handle, &rev_reg_id.0, &rev_reg).map(|_| ()),
+                       move || self.wallet_service.update_indy_object(wallet_handle, &rev_reg_id.0, &prev_rev_reg).map(|_| ()))?;
+
         self.wallet_service.update_indy_object(wallet_handle, &rev_reg_id.0, &rev_reg_info)?;
 
+        rollback.commit();
+
+        self.audit_service.record(wallet_handle, event);
+
+        // The registry is already revoked at this point, so the delta is returned even if the issuance
+        // record can't be marked: the caller must still publish it and a retry would fail.
+        if let Err(err) = self._mark_issuance_record_revoked(wallet_handle, rev_reg_id, &cred_revoc_id.to_string()) {
+            warn!("Cannot mark issuance record of revocation id {} in {:?} as revoked: {:?}", cred_revoc_id, rev_reg_id.0, err);
+        }
+
         debug!("revoke_credential <<< rev_reg_delta_json: {:?}", rev_reg_delta_json);
 
         Ok(rev_reg_delta_json)
     }
 
+    fn revoke_credential_by_record(&self,
+                                   wallet_handle: WalletHandle,
+                                   blob_storage_reader_handle: i32,
+                                   record_id: &str) -> IndyResult<String> {
+        debug!("revoke_credential_by_record >>> wallet_handle: {:?}, blob_storage_reader_handle: {:?}, record_id: {:?}",
+               wallet_handle, blob_storage_reader_handle, record_id);
+
+        let record: IssuanceRecord = self.wallet_service.get_indy_object(wallet_handle, record_id, &RecordOptions::id_value())?;
+
+        let (rev_reg_id, cred_rev_id) = match (record.rev_reg_id, record.cred_rev_id) {
+            (Some(rev_reg_id), Some(cred_rev_id)) => (rev_reg_id, cred_rev_id),
+            _ => return Err(err_msg(IndyErrorKind::InvalidStructure, format!("IssuanceRecord {} refers to non-revocable credential", record_id)))
+        };
+
+        let res = self.revoke_credential(wallet_handle, blob_storage_reader_handle, &rev_reg_id, &cred_rev_id)?;
+
+        debug!("revoke_credential_by_record <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn _mark_issuance_record_revoked(&self,
+                                     wallet_handle: WalletHandle,
+                                     rev_reg_id: &RevocationRegistryId,
+                                     cred_rev_id: &str) -> IndyResult<()> {
+        let query_json = json!({
+            "rev_reg_id": rev_reg_id.0,
+            "cred_rev_id": cred_rev_id,
+        }).to_string();
+
+        let mut search = self.wallet_service.search_indy_records::<IssuanceRecord>(wallet_handle, &query_json, &SearchOptions::id_value())?;
+
+        while let Some(wallet_record) = search.fetch_next_record()? {
+            let mut record = self._get_issuance_record(wallet_record.get_id(), wallet_record.get_value())?;
+
+            if record.revoked_at.is_some() {
+                continue;
+            }
+
+            record.revoked_at = Some(get_seconds_since_epoch()?);
+
+            self.wallet_service.update_indy_object(wallet_handle, &record.id, &record)?;
+            self.wallet_service.update_record_tags(wallet_handle, self.wallet_service.add_prefix("IssuanceRecord").as_str(), &record.id, &record.wallet_tags())?;
+        }
+
+        Ok(())
+    }
+
+    fn get_issuance_record(&self,
+                           wallet_handle: WalletHandle,
+                           record_id: &str) -> IndyResult<String> {
+        debug!("get_issuance_record >>> wallet_handle: {:?}, record_id: {:?}", wallet_handle, record_id);
+
+        let record: IssuanceRecord = self.wallet_service.get_indy_object(wallet_handle, record_id, &RecordOptions::id_value())?;
+
+        let res = serde_json::to_string(&record)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize IssuanceRecord")?;
+
+        debug!("get_issuance_record <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn search_issuance_records(&self,
+                               wallet_handle: WalletHandle,
+                               query_json: Option<&str>) -> IndyResult<(SearchHandle, usize)> {
+        debug!("search_issuance_records >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
+
+        let search =
+            self.wallet_service.search_indy_records::<IssuanceRecord>(wallet_handle, query_json.unwrap_or("{}"), &SearchOptions::id_value())?;
+
+        let total_count = search.get_total_count()?.unwrap_or(0);
+
+        let handle: SearchHandle = next_search_handle();
+
+        self.issuance_record_searches.borrow_mut().insert(handle, Box::new(search));
+
+        let res = (handle, total_count);
+
+        debug!("search_issuance_records <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn fetch_issuance_records(&self,
+                              search_handle: SearchHandle,
+                              count: usize) -> IndyResult<String> {
+        trace!("fetch_issuance_records >>> search_handle: {:?}, count: {:?}", search_handle, count);
+
+        let mut searches = self.issuance_record_searches.borrow_mut();
+        let search = searches.get_mut(&search_handle)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidWalletHandle, format!("Unknown IssuanceRecordsSearch handle: {:?}", search_handle)))?;
+
+        let mut records: Vec<IssuanceRecord> = Vec::new();
+
+        for _ in 0..count {
+            match search.fetch_next_record()? {
+                Some(wallet_record) => records.push(self._get_issuance_record(wallet_record.get_id(), wallet_record.get_value())?),
+                None => break
+            }
+        }
+
+        let res = serde_json::to_string(&records)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize list of IssuanceRecord")?;
+
+        trace!("fetch_issuance_records <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn close_issuance_records_search(&self, search_handle: SearchHandle) -> IndyResult<()> {
+        trace!("close_issuance_records_search >>> search_handle: {:?}", search_handle);
+
+        match self.issuance_record_searches.borrow_mut().remove(&search_handle) {
+            Some(_) => Ok(()),
+            None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, format!("Unknown IssuanceRecordsSearch handle: {:?}", search_handle)))
+        }?;
+
+        trace!("close_issuance_records_search <<< res: ()");
+
+        Ok(())
+    }
+
+    fn _get_issuance_record(&self, id: &str, value: Option<&str>) -> IndyResult<IssuanceRecord> {
+        let value = value
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, format!("IssuanceRecord not found for id: {}", id)))?;
+
+        serde_json::from_str(value)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize IssuanceRecord")
+    }
+
     fn _recovery_credential(&self,
                             wallet_handle: WalletHandle,
                             blob_storage_reader_handle: i32,
@@ -931,6 +1405,31 @@ impl IssuerCommandExecutor {
         Ok(merged_rev_reg_delta_json)
     }
 
+    // Schema is stored together with the credential definition, so values can be checked against declared attribute types.
+    fn _check_typed_credential_values(&self, wallet_handle: WalletHandle, cred_def: &CredentialDefinitionV1, cred_values: &CredentialValues) -> IndyResult<()> {
+        let schema: SchemaV1 = match self.wallet_service.get_indy_object::<Schema>(wallet_handle, &cred_def.schema_id.0, &RecordOptions::id_value()) {
+            Ok(schema) => SchemaV1::from(schema),
+            Err(ref err) if err.kind() == IndyErrorKind::WalletItemNotFound => {
+                // credential definitions created before typed schemas were introduced have no stored schema
+                warn!("Schema {:?} of credential definition {:?} is not found in the wallet, so credential values are not checked against attribute types",
+                      cred_def.schema_id, cred_def.id);
+                return Ok(());
+            }
+            Err(err) => return Err(err)
+        };
+
+        for (attr, values) in cred_values.0.iter() {
+            if let Some(attr_type) = schema.attr_type(attr) {
+                if !is_credential_value_encoded(&values.raw, &values.encoded, Some(attr_type))? {
+                    return Err(err_msg(IndyErrorKind::InvalidStructure,
+                                       format!("Value of attribute \"{}\" does not match declared type {:?} or its encoded value", attr, attr_type)));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     // TODO: DELETE IT
     fn _wallet_set_schema_id(&self, wallet_handle: WalletHandle, id: &str, schema_id: &SchemaId) -> IndyResult<()> {
         self.wallet_service.add_record(wallet_handle, &self.wallet_service.add_prefix("SchemaId"), id, &schema_id.0, &Tags::new())
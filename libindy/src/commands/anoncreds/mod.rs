@@ -8,11 +8,18 @@ use crate::commands::anoncreds::prover::{ProverCommand, ProverCommandExecutor};
 use crate::commands::anoncreds::verifier::{VerifierCommand, VerifierCommandExecutor};
 
 use crate::services::anoncreds::AnoncredsService;
+use crate::services::audit::AuditService;
 use crate::services::blob_storage::BlobStorageService;
 use crate::services::pool::PoolService;
 use indy_wallet::WalletService;
 use crate::services::crypto::CryptoService;
-use crate::services::anoncreds::helpers::to_unqualified;
+use crate::services::anoncreds::helpers::{encode_credential_values, parse_date_to_days, to_unqualified};
+use crate::services::anoncreds::w3c;
+use crate::domain::anoncreds::credential::{Credential, CredentialValues, ShortCredentialValues};
+use crate::domain::anoncreds::proof::Proof;
+use crate::domain::anoncreds::proof_request::{DatePredicateInfo, PredicateInfo, ProofRequest};
+use crate::domain::anoncreds::schema::SchemaV1;
+use crate::domain::anoncreds::w3c::{W3CCredential, W3CPresentation};
 
 use indy_api_types::errors::prelude::*;
 
@@ -24,6 +31,29 @@ pub enum AnoncredsCommand {
     Verifier(VerifierCommand),
     ToUnqualified(
         String, // entity
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    EncodeCredentialValues(
+        ShortCredentialValues, // raw credential values
+        Option<SchemaV1>, // schema with attribute types
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    BuildDatePredicate(
+        DatePredicateInfo, // date predicate
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    CredentialToW3C(
+        Credential, // credential
+        String, // issuance date
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    CredentialFromW3C(
+        W3CCredential, // w3c credential
+        SchemaV1, // schema
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    ProofToW3C(
+        ProofRequest, // proof request
+        Proof, // proof
+        Vec<String>, // issuance dates
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    ProofFromW3C(
+        W3CPresentation, // w3c presentation
         Box<dyn Fn(IndyResult<String>) + Send>)
 }
 
@@ -38,11 +68,12 @@ impl AnoncredsCommandExecutor {
                blob_storage_service: Rc<BlobStorageService>,
                pool_service: Rc<PoolService>,
                wallet_service: Rc<WalletService>,
-               crypto_service: Rc<CryptoService>) -> AnoncredsCommandExecutor {
+               crypto_service: Rc<CryptoService>,
+               audit_service: Rc<AuditService>) -> AnoncredsCommandExecutor {
         AnoncredsCommandExecutor {
             issuer_command_cxecutor: IssuerCommandExecutor::new(
                 anoncreds_service.clone(), pool_service.clone(),
-                blob_storage_service.clone(), wallet_service.clone(), crypto_service.clone()),
+                blob_storage_service.clone(), wallet_service.clone(), crypto_service.clone(), audit_service.clone()),
             prover_command_cxecutor: ProverCommandExecutor::new(
                 anoncreds_service.clone(), wallet_service.clone(), crypto_service.clone(), blob_storage_service.clone()),
             verifier_command_cxecutor: VerifierCommandExecutor::new(
@@ -68,6 +99,114 @@ impl AnoncredsCommandExecutor {
                 debug!("ToUnqualified command received");
                 cb(to_unqualified(&entity));
             }
+            AnoncredsCommand::EncodeCredentialValues(values, schema, cb) => {
+                debug!("EncodeCredentialValues command received");
+                cb(self.encode_credential_values(&values, schema.as_ref()));
+            }
+            AnoncredsCommand::BuildDatePredicate(date_predicate, cb) => {
+                debug!("BuildDatePredicate command received");
+                cb(self.build_date_predicate(date_predicate));
+            }
+            AnoncredsCommand::CredentialToW3C(credential, issuance_date, cb) => {
+                debug!("CredentialToW3C command received");
+                cb(self.credential_to_w3c(credential, &issuance_date));
+            }
+            AnoncredsCommand::CredentialFromW3C(w3c_credential, schema, cb) => {
+                debug!("CredentialFromW3C command received");
+                cb(self.credential_from_w3c(&w3c_credential, &schema));
+            }
+            AnoncredsCommand::ProofToW3C(proof_req, proof, issuance_dates, cb) => {
+                debug!("ProofToW3C command received");
+                cb(self.proof_to_w3c(&proof_req, proof, &issuance_dates));
+            }
+            AnoncredsCommand::ProofFromW3C(presentation, cb) => {
+                debug!("ProofFromW3C command received");
+                cb(self.proof_from_w3c(&presentation));
+            }
+        };
+    }
+
+    fn encode_credential_values(&self, values: &ShortCredentialValues, schema: Option<&SchemaV1>) -> IndyResult<String> {
+        debug!("encode_credential_values >>> values: {:?}, schema: {:?}", values, schema);
+
+        let credential_values = CredentialValues(encode_credential_values(values, schema)?);
+
+        let res = serde_json::to_string(&credential_values)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize CredentialValues")?;
+
+        debug!("encode_credential_values <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn build_date_predicate(&self, date_predicate: DatePredicateInfo) -> IndyResult<String> {
+        debug!("build_date_predicate >>> date_predicate: {:?}", date_predicate);
+
+        let predicate = PredicateInfo {
+            p_value: parse_date_to_days(&date_predicate.date)?,
+            name: date_predicate.name,
+            p_type: date_predicate.p_type,
+            restrictions: date_predicate.restrictions,
+            non_revoked: date_predicate.non_revoked,
         };
+
+        let res = serde_json::to_string(&predicate)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize PredicateInfo")?;
+
+        debug!("build_date_predicate <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn credential_to_w3c(&self, credential: Credential, issuance_date: &str) -> IndyResult<String> {
+        debug!("credential_to_w3c >>> credential: {:?}, issuance_date: {:?}", secret!(&credential), issuance_date);
+
+        let w3c_credential = w3c::credential_to_w3c(credential, issuance_date)?;
+
+        let res = serde_json::to_string(&w3c_credential)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize W3C Credential")?;
+
+        debug!("credential_to_w3c <<< res: {:?}", secret!(&res));
+
+        Ok(res)
+    }
+
+    fn credential_from_w3c(&self, w3c_credential: &W3CCredential, schema: &SchemaV1) -> IndyResult<String> {
+        debug!("credential_from_w3c >>> w3c_credential: {:?}, schema: {:?}", secret!(w3c_credential), schema);
+
+        let credential = w3c::credential_from_w3c(w3c_credential, schema)?;
+
+        let res = serde_json::to_string(&credential)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize Credential")?;
+
+        debug!("credential_from_w3c <<< res: {:?}", secret!(&res));
+
+        Ok(res)
+    }
+
+    fn proof_to_w3c(&self, proof_req: &ProofRequest, proof: Proof, issuance_dates: &[String]) -> IndyResult<String> {
+        debug!("proof_to_w3c >>> proof_req: {:?}, proof: {:?}, issuance_dates: {:?}", proof_req, proof, issuance_dates);
+
+        let presentation = w3c::proof_to_w3c(proof_req.value(), proof, issuance_dates)?;
+
+        let res = serde_json::to_string(&presentation)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize W3C Presentation")?;
+
+        debug!("proof_to_w3c <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn proof_from_w3c(&self, presentation: &W3CPresentation) -> IndyResult<String> {
+        debug!("proof_from_w3c >>> presentation: {:?}", presentation);
+
+        let proof = w3c::proof_from_w3c(presentation)?;
+
+        let res = serde_json::to_string(&proof)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize Proof")?;
+
+        debug!("proof_from_w3c <<< res: {:?}", res);
+
+        Ok(res)
     }
 }
@@ -2,42 +2,66 @@ use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 
-use ursa::cl::{new_nonce, RevocationRegistry, Witness};
+use ursa::cl::{new_nonce, MasterSecret as CryptoMasterSecret, RevocationRegistry, Witness};
 
 use serde_json::Value;
 
 use crate::domain::anoncreds::credential_attr_tag_policy::CredentialAttrTagPolicy;
+use crate::domain::anoncreds::credential_bundle::{CredentialBundle, CredentialBundlePayload, CredentialBundleRevocation, CredentialExportConfig, CredentialImportConfig};
 use crate::domain::anoncreds::credential::{Credential, CredentialInfo};
 use crate::domain::anoncreds::credential_definition::{cred_defs_map_to_cred_defs_v1_map, CredentialDefinition, CredentialDefinitionV1, CredentialDefinitionId, CredentialDefinitions};
 use crate::domain::anoncreds::credential_for_proof_request::{CredentialsForProofRequest, RequestedCredential};
 use crate::domain::anoncreds::credential_offer::CredentialOffer;
 use crate::domain::anoncreds::credential_request::{CredentialRequest, CredentialRequestMetadata};
-use crate::domain::anoncreds::master_secret::MasterSecret;
+use crate::domain::anoncreds::credential_status::{CREDENTIAL_STATUS_EXPIRES_AT_TAG, CREDENTIAL_STATUS_FLAG, CREDENTIAL_STATUS_REPLACED_TAG,
+                                                  CREDENTIAL_STATUS_REVOKED_AS_OF_TAG, CREDENTIAL_STATUS_REVOKED_TAG, CredentialExpiryAttr, CredentialIdentityAttr, CredentialStatus, CredentialStatusInfo};
+use crate::domain::anoncreds::master_secret::{CreateProofOptions, MasterSecret, MasterSecretInfo, MasterSecretRotation};
 use crate::domain::anoncreds::proof_request::{NonRevocedInterval, PredicateInfo, ProofRequest, ProofRequestExtraQuery};
+use crate::domain::anoncreds::proof_request_diagnostics::{CredentialDiagnostics, DiagnosticsIssue, DiagnosticsIssueReason, ProofRequestDiagnostics, ReferentDiagnostics};
 use crate::domain::anoncreds::requested_credential::RequestedCredentials;
 use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefinition, RevocationRegistryDefinitionV1};
 use crate::domain::anoncreds::revocation_registry_delta::{RevocationRegistryDelta, RevocationRegistryDeltaV1};
 use crate::domain::anoncreds::revocation_state::{RevocationState, RevocationStates};
 use crate::domain::anoncreds::schema::{schemas_map_to_schemas_v1_map, SchemaV1, SchemaId, Schemas};
 use crate::domain::crypto::did::DidValue;
+use indy_api_types::domain::wallet::Tags;
 use indy_api_types::errors::prelude::*;
 use crate::services::anoncreds::AnoncredsService;
-use crate::services::anoncreds::helpers::{parse_cred_rev_id, get_non_revoc_interval};
+use crate::services::anoncreds::prover::Prover;
+use crate::services::anoncreds::helpers::{parse_cred_rev_id, get_non_revoc_interval, get_seconds_since_epoch};
 use crate::services::blob_storage::BlobStorageService;
 use crate::services::crypto::CryptoService;
 use indy_wallet::{RecordOptions, SearchOptions, WalletRecord, WalletSearch, WalletService};
 use indy_utils::{next_search_handle};
+use crate::utils::rollback::Rollback;
 use crate::utils::wql::Query;
 
 use super::tails::SDKTailsAccessor;
 use indy_api_types::{WalletHandle, SearchHandle};
 use crate::commands::BoxedCallbackStringStringSend;
 
+const MASTER_SECRET_DEFAULT_TAG: &str = "default";
+const MASTER_SECRET_RETIRED_TAG: &str = "retired";
+const CREDENTIAL_MASTER_SECRET_TAG: &str = "master_secret_id";
+const EXPLAIN_MAX_CREDENTIALS: usize = 100;
+
 pub enum ProverCommand {
     CreateMasterSecret(
         WalletHandle,
         Option<String>, // master secret id
         Box<dyn Fn(IndyResult<String>) + Send>),
+    ListMasterSecrets(
+        WalletHandle,
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    SetDefaultMasterSecret(
+        WalletHandle,
+        String, // master secret id
+        Box<dyn Fn(IndyResult<()>) + Send>),
+    RotateMasterSecret(
+        WalletHandle,
+        String, // master secret id
+        Option<String>, // new master secret id
+        Box<dyn Fn(IndyResult<String>) + Send>),
     CreateCredentialRequest(
         WalletHandle,
         DidValue, // prover did
@@ -55,6 +79,16 @@ pub enum ProverCommand {
         WalletHandle,
         CredentialDefinitionId, // credential definition id
         Box<dyn Fn(IndyResult<String>) + Send>),
+    SetCredentialExpiryAttr(
+        WalletHandle,
+        CredentialDefinitionId, // credential definition id
+        Option<String>, // expiry attribute name
+        Box<dyn Fn(IndyResult<()>) + Send>),
+    SetCredentialIdentityAttr(
+        WalletHandle,
+        CredentialDefinitionId, // credential definition id
+        Option<String>, // identity attribute name
+        Box<dyn Fn(IndyResult<()>) + Send>),
     StoreCredential(
         WalletHandle,
         Option<String>, // credential id
@@ -71,6 +105,20 @@ pub enum ProverCommand {
         WalletHandle,
         String, // credential id
         Box<dyn Fn(IndyResult<String>) + Send>),
+    GetCredentialStatus(
+        WalletHandle,
+        String, // credential id
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    ExportCredential(
+        WalletHandle,
+        String, // credential id
+        CredentialExportConfig, // export config
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    ImportCredential(
+        WalletHandle,
+        CredentialBundle, // credential bundle
+        CredentialImportConfig, // import config
+        Box<dyn Fn(IndyResult<String>) + Send>),
     DeleteCredential(
         WalletHandle,
         String, // credential id
@@ -103,14 +151,20 @@ pub enum ProverCommand {
     CloseCredentialsSearchForProofReq(
         SearchHandle,
         Box<dyn Fn(IndyResult<()>) + Send>),
+    ExplainProofReq(
+        WalletHandle,
+        ProofRequest, // proof request
+        Option<ProofRequestExtraQuery>, // extra query
+        Box<dyn Fn(IndyResult<String>) + Send>),
     CreateProof(
         WalletHandle,
         ProofRequest, // proof request
         RequestedCredentials, // requested credentials
-        String, // master secret name
+        Option<String>, // master secret name, default master secret if not set
         Schemas, // schemas
         CredentialDefinitions, // credential defs
         RevocationStates, // revocation states
+        CreateProofOptions, // proof creation options
         Box<dyn Fn(IndyResult<String>) + Send>),
     CreateRevocationState(
         i32, // blob storage reader handle
@@ -119,6 +173,14 @@ pub enum ProverCommand {
         u64, //timestamp
         String, //credential revocation id
         Box<dyn Fn(IndyResult<String>) + Send>),
+    CreateCredentialRevocationState(
+        WalletHandle,
+        i32, // blob storage reader handle
+        RevocationRegistryDefinition, // revocation registry definition
+        RevocationRegistryDelta, // revocation registry delta
+        u64, //timestamp
+        String, // credential id
+        Box<dyn Fn(IndyResult<String>) + Send>),
     UpdateRevocationState(
         i32, // tails reader _handle
         RevocationState, // revocation state
@@ -177,6 +239,18 @@ impl ProverCommandExecutor {
                 debug!(target: "prover_command_executor", "CreateMasterSecret command received");
                 cb(self.create_master_secret(wallet_handle, master_secret_id.as_ref().map(String::as_str)));
             }
+            ProverCommand::ListMasterSecrets(wallet_handle, cb) => {
+                debug!(target: "prover_command_executor", "ListMasterSecrets command received");
+                cb(self.list_master_secrets(wallet_handle));
+            }
+            ProverCommand::SetDefaultMasterSecret(wallet_handle, master_secret_id, cb) => {
+                debug!(target: "prover_command_executor", "SetDefaultMasterSecret command received");
+                cb(self.set_default_master_secret(wallet_handle, &master_secret_id));
+            }
+            ProverCommand::RotateMasterSecret(wallet_handle, master_secret_id, new_master_secret_id, cb) => {
+                debug!(target: "prover_command_executor", "RotateMasterSecret command received");
+                cb(self.rotate_master_secret(wallet_handle, &master_secret_id, new_master_secret_id.as_ref().map(String::as_str)));
+            }
             ProverCommand::CreateCredentialRequest(wallet_handle, prover_did, credential_offer,
                                                    credential_def, master_secret_name, cb) => {
                 debug!(target: "prover_command_executor", "CreateCredentialRequest command received");
@@ -191,6 +265,14 @@ impl ProverCommandExecutor {
                 debug!(target: "prover_command_executor", "GetCredentialAttrTagPolicy command received");
                 cb(self.get_credential_attr_tag_policy(wallet_handle, &cred_def_id));
             }
+            ProverCommand::SetCredentialExpiryAttr(wallet_handle, cred_def_id, attr_name, cb) => {
+                debug!(target: "prover_command_executor", "SetCredentialExpiryAttr command received");
+                cb(self.set_credential_expiry_attr(wallet_handle, &cred_def_id, attr_name.as_ref().map(String::as_str)));
+            }
+            ProverCommand::SetCredentialIdentityAttr(wallet_handle, cred_def_id, attr_name, cb) => {
+                debug!(target: "prover_command_executor", "SetCredentialIdentityAttr command received");
+                cb(self.set_credential_identity_attr(wallet_handle, &cred_def_id, attr_name.as_ref().map(String::as_str)));
+            }
             ProverCommand::StoreCredential(wallet_handle, cred_id, cred_req_metadata, mut cred, cred_def, rev_reg_def, cb) => {
                 debug!(target: "prover_command_executor", "StoreCredential command received");
                 cb(self.store_credential(wallet_handle, cred_id.as_ref().map(String::as_str),
@@ -206,6 +288,18 @@ impl ProverCommandExecutor {
                 debug!(target: "prover_command_executor", "GetCredential command received");
                 cb(self.get_credential(wallet_handle, &cred_id));
             }
+            ProverCommand::GetCredentialStatus(wallet_handle, cred_id, cb) => {
+                debug!(target: "prover_command_executor", "GetCredentialStatus command received");
+                cb(self.get_credential_status(wallet_handle, &cred_id));
+            }
+            ProverCommand::ExportCredential(wallet_handle, cred_id, config, cb) => {
+                debug!(target: "prover_command_executor", "ExportCredential command received");
+                cb(self.export_credential(wallet_handle, &cred_id, &config));
+            }
+            ProverCommand::ImportCredential(wallet_handle, bundle, config, cb) => {
+                debug!(target: "prover_command_executor", "ImportCredential command received");
+                cb(self.import_credential(wallet_handle, &bundle, &config));
+            }
             ProverCommand::DeleteCredential(wallet_handle, cred_id, cb) => {
                 debug!(target: "prover_command_executor", "DeleteCredential command received");
                 cb(self.delete_credential(wallet_handle, &cred_id));
@@ -238,18 +332,28 @@ impl ProverCommandExecutor {
                 debug!(target: "prover_command_executor", "CloseCredentialsSearchForProofReq command received");
                 cb(self.close_credentials_search_for_proof_req(search_handle));
             }
+            ProverCommand::ExplainProofReq(wallet_handle, proof_req, extra_query, cb) => {
+                debug!(target: "prover_command_executor", "ExplainProofReq command received");
+                cb(self.explain_proof_req(wallet_handle, &proof_req, extra_query.as_ref()));
+            }
             ProverCommand::CreateProof(wallet_handle, proof_req, requested_credentials, master_secret_name,
-                                       schemas, cred_defs, rev_states, cb) => {
+                                       schemas, cred_defs, rev_states, options, cb) => {
                 debug!(target: "prover_command_executor", "CreateProof command received");
-                cb(self.create_proof(wallet_handle, &proof_req, &requested_credentials, &master_secret_name,
+                cb(self.create_proof(wallet_handle, &proof_req, &requested_credentials,
+                                     master_secret_name.as_ref().map(String::as_str),
                                      &schemas_map_to_schemas_v1_map(schemas),
                                      &cred_defs_map_to_cred_defs_v1_map(cred_defs),
-                                     &rev_states));
+                                     &rev_states,
+                                     &options));
             }
             ProverCommand::CreateRevocationState(blob_storage_reader_handle, rev_reg_def, rev_reg_delta, timestamp, cred_rev_id, cb) => {
                 debug!(target: "prover_command_executor", "CreateRevocationState command received");
                 cb(self.create_revocation_state(blob_storage_reader_handle, rev_reg_def, rev_reg_delta, timestamp, &cred_rev_id));
             }
+            ProverCommand::CreateCredentialRevocationState(wallet_handle, blob_storage_reader_handle, rev_reg_def, rev_reg_delta, timestamp, cred_id, cb) => {
+                debug!(target: "prover_command_executor", "CreateCredentialRevocationState command received");
+                cb(self.create_credential_revocation_state(wallet_handle, blob_storage_reader_handle, rev_reg_def, rev_reg_delta, timestamp, &cred_id));
+            }
             ProverCommand::UpdateRevocationState(blob_storage_reader_handle, rev_state, rev_reg_def, rev_reg_delta, timestamp, cred_rev_id, cb) => {
                 debug!(target: "prover_command_executor", "UpdateRevocationState command received");
                 cb(self.update_revocation_state(blob_storage_reader_handle, rev_state, rev_reg_def, rev_reg_delta, timestamp, &cred_rev_id));
@@ -274,13 +378,131 @@ impl ProverCommandExecutor {
             value: master_secret
         };
 
-        self.wallet_service.add_indy_object(wallet_handle, &master_secret_id, &master_secret, &HashMap::new())?;
+        // The first master secret in the wallet becomes the default one
+        let mut tags = Tags::new();
+        if self._wallet_get_default_master_secret_id(wallet_handle)?.is_none() {
+            tags.insert(MASTER_SECRET_DEFAULT_TAG.to_string(), "1".to_string());
+        }
+
+        self.wallet_service.add_indy_object(wallet_handle, &master_secret_id, &master_secret, &tags)?;
 
         debug!("create_master_secret <<< master_secret_id: {:?}", master_secret_id);
 
         Ok(master_secret_id)
     }
 
+    fn list_master_secrets(&self,
+                           wallet_handle: WalletHandle) -> IndyResult<String> {
+        debug!("list_master_secrets >>> wallet_handle: {:?}", wallet_handle);
+
+        let options_json = json!({
+            "retrieveRecords": true,
+            "retrieveTotalCount": false,
+            "retrieveType": false,
+            "retrieveValue": false,
+            "retrieveTags": true,
+        }).to_string();
+
+        let mut master_secrets_search =
+            self.wallet_service.search_indy_records::<MasterSecret>(wallet_handle, "{}", &options_json)?;
+
+        let mut master_secrets: Vec<MasterSecretInfo> = Vec::new();
+
+        while let Some(master_secret_record) = master_secrets_search.fetch_next_record()? {
+            master_secrets.push(ProverCommandExecutor::_master_secret_info(&master_secret_record));
+        }
+
+        master_secrets.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let res = serde_json::to_string(&master_secrets)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize list of MasterSecretInfo")?;
+
+        debug!("list_master_secrets <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn set_default_master_secret(&self,
+                                 wallet_handle: WalletHandle,
+                                 master_secret_id: &str) -> IndyResult<()> {
+        debug!("set_default_master_secret >>> wallet_handle: {:?}, master_secret_id: {:?}", wallet_handle, master_secret_id);
+
+        let master_secret_info = self._wallet_get_master_secret_info(wallet_handle, master_secret_id)?;
+
+        if master_secret_info.retired {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("MasterSecret {} is retired and cannot be used as default", master_secret_id)));
+        }
+
+        if !master_secret_info.default {
+            let type_ = self.wallet_service.add_prefix("MasterSecret");
+
+            if let Some(current_default_id) = self._wallet_get_default_master_secret_id(wallet_handle)? {
+                self.wallet_service.delete_record_tags(wallet_handle, &type_, &current_default_id, &[MASTER_SECRET_DEFAULT_TAG])?;
+            }
+
+            let mut tags = Tags::new();
+            tags.insert(MASTER_SECRET_DEFAULT_TAG.to_string(), "1".to_string());
+
+            self.wallet_service.add_record_tags(wallet_handle, &type_, master_secret_id, &tags)?;
+        }
+
+        debug!("set_default_master_secret <<<");
+
+        Ok(())
+    }
+
+    fn rotate_master_secret(&self,
+                            wallet_handle: WalletHandle,
+                            master_secret_id: &str,
+                            new_master_secret_id: Option<&str>) -> IndyResult<String> {
+        debug!("rotate_master_secret >>> wallet_handle: {:?}, master_secret_id: {:?}, new_master_secret_id: {:?}",
+               wallet_handle, master_secret_id, new_master_secret_id);
+
+        let master_secret_info = self._wallet_get_master_secret_info(wallet_handle, master_secret_id)?;
+
+        if master_secret_info.retired {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("MasterSecret {} is already retired", master_secret_id)));
+        }
+
+        let new_master_secret_id = self.create_master_secret(wallet_handle, new_master_secret_id)?;
+
+        let mut tags = Tags::new();
+        tags.insert(MASTER_SECRET_RETIRED_TAG.to_string(), "1".to_string());
+
+        self.wallet_service.add_record_tags(wallet_handle, &self.wallet_service.add_prefix("MasterSecret"), master_secret_id, &tags)?;
+
+        if master_secret_info.default {
+            self.set_default_master_secret(wallet_handle, &new_master_secret_id)?;
+        }
+
+        let mut query = serde_json::Map::new();
+        query.insert(CREDENTIAL_MASTER_SECRET_TAG.to_string(), Value::String(master_secret_id.to_string()));
+        let query_json = Value::Object(query).to_string();
+
+        let mut credentials_search =
+            self.wallet_service.search_indy_records::<Credential>(wallet_handle, &query_json, &SearchOptions::id_value())?;
+
+        let mut credentials_to_reissue: Vec<CredentialInfo> = Vec::new();
+
+        while let Some(credential_record) = credentials_search.fetch_next_record()? {
+            let (referent, credential) = self._get_credential(&credential_record)?;
+            credentials_to_reissue.push(self._get_credential_info(&referent, credential));
+        }
+
+        let rotation = MasterSecretRotation {
+            master_secret_id: new_master_secret_id,
+            retired_master_secret_id: master_secret_id.to_string(),
+            credentials_to_reissue,
+        };
+
+        let res = serde_json::to_string(&rotation)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize MasterSecretRotation")?;
+
+        debug!("rotate_master_secret <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn create_credential_request(&self,
                                  wallet_handle: WalletHandle,
                                  prover_did: &DidValue,
@@ -292,6 +514,11 @@ impl ProverCommandExecutor {
 
         self.crypto_service.validate_did(&prover_did)?;
 
+        if self._wallet_get_master_secret_info(wallet_handle, master_secret_id)?.retired {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("MasterSecret {} is retired and cannot be used for new credentials", master_secret_id)));
+        }
+
         let master_secret: MasterSecret = self._wallet_get_master_secret(wallet_handle, &master_secret_id)?;
 
         let (blinded_ms, ms_blinding_data, blinded_ms_correctness_proof) =
@@ -347,11 +574,28 @@ impl ProverCommandExecutor {
         // Cascade whether we updated policy or not: could be a retroactive cred attr tags reset to existing policy
         if retroactive {
             let query_json = format!(r#"{{"cred_def_id": "{}"}}"#, cred_def_id.0);
-            let mut credentials_search = self.wallet_service.search_indy_records::<Credential>(wallet_handle, query_json.as_str(), &SearchOptions::id_value())?;
+            let options_json = json!({
+                "retrieveRecords": true,
+                "retrieveTotalCount": false,
+                "retrieveType": false,
+                "retrieveValue": true,
+                "retrieveTags": true,
+            }).to_string();
+            let mut credentials_search = self.wallet_service.search_indy_records::<Credential>(wallet_handle, query_json.as_str(), &options_json)?;
 
             while let Some(credential_record) = credentials_search.fetch_next_record()? {
                 let (_, credential) = self._get_credential(&credential_record)?;
-                let cred_tags = self.anoncreds_service.prover.build_credential_tags(&credential, catpol)?;
+                let mut cred_tags = self.anoncreds_service.prover.build_credential_tags(&credential, catpol)?;
+
+                // master secret binding and status are not derived from credential, so they are kept as is
+                if let Some(tags) = credential_record.get_tags() {
+                    if let Some(master_secret_id) = tags.get(CREDENTIAL_MASTER_SECRET_TAG) {
+                        cred_tags.insert(CREDENTIAL_MASTER_SECRET_TAG.to_string(), master_secret_id.to_string());
+                    }
+
+                    cred_tags.extend(CredentialStatus::from_tags(tags).to_tags());
+                }
+
                 self.wallet_service.update_record_tags(wallet_handle, self.wallet_service.add_prefix("Credential").as_str(), credential_record.get_id(), &cred_tags)?;
             }
         }
@@ -379,6 +623,82 @@ impl ProverCommandExecutor {
         Ok(catpol_json)
     }
 
+    fn set_credential_expiry_attr(&self,
+                                  wallet_handle: WalletHandle,
+                                  cred_def_id: &CredentialDefinitionId,
+                                  attr_name: Option<&str>) -> IndyResult<()> {
+        debug!("set_credential_expiry_attr >>> wallet_handle: {:?}, cred_def_id: {:?}, attr_name: {:?}", wallet_handle, cred_def_id, attr_name);
+
+        match attr_name {
+            Some(attr_name) => {
+                self.wallet_service.upsert_indy_object(wallet_handle, &cred_def_id.0, &CredentialExpiryAttr { attr_name: attr_name.to_string() })?;
+            }
+            None => {
+                if self.wallet_service.record_exists::<CredentialExpiryAttr>(wallet_handle, &cred_def_id.0)? {
+                    self.wallet_service.delete_indy_record::<CredentialExpiryAttr>(wallet_handle, &cred_def_id.0)?;
+                }
+            }
+        };
+
+        // Expiry of already stored credentials follows the designated attribute
+        let query_json = json!({"cred_def_id": cred_def_id.0}).to_string();
+        let options_json = json!({
+            "retrieveRecords": true,
+            "retrieveTotalCount": false,
+            "retrieveType": false,
+            "retrieveValue": true,
+            "retrieveTags": true,
+        }).to_string();
+        let mut credentials_search = self.wallet_service.search_indy_records::<Credential>(wallet_handle, &query_json, &options_json)?;
+
+        let type_ = self.wallet_service.add_prefix("Credential");
+
+        while let Some(credential_record) = credentials_search.fetch_next_record()? {
+            let (referent, credential) = self._get_credential(&credential_record)?;
+
+            let has_expiry = credential_record.get_tags()
+                .map(|tags| tags.contains_key(CREDENTIAL_STATUS_EXPIRES_AT_TAG))
+                .unwrap_or(false);
+
+            match self._get_credential_expiry(wallet_handle, &referent, &credential)? {
+                Some(expires_at) => {
+                    let status = CredentialStatus { expires_at: Some(expires_at), ..CredentialStatus::default() };
+                    self.wallet_service.add_record_tags(wallet_handle, &type_, &referent, &status.to_tags())?;
+                }
+                None if has_expiry => {
+                    self.wallet_service.delete_record_tags(wallet_handle, &type_, &referent, &[CREDENTIAL_STATUS_EXPIRES_AT_TAG])?;
+                }
+                None => {}
+            }
+        }
+
+        debug!("set_credential_expiry_attr <<<");
+
+        Ok(())
+    }
+
+    fn set_credential_identity_attr(&self,
+                                    wallet_handle: WalletHandle,
+                                    cred_def_id: &CredentialDefinitionId,
+                                    attr_name: Option<&str>) -> IndyResult<()> {
+        debug!("set_credential_identity_attr >>> wallet_handle: {:?}, cred_def_id: {:?}, attr_name: {:?}", wallet_handle, cred_def_id, attr_name);
+
+        match attr_name {
+            Some(attr_name) => {
+                self.wallet_service.upsert_indy_object(wallet_handle, &cred_def_id.0, &CredentialIdentityAttr { attr_name: attr_name.to_string() })?;
+            }
+            None => {
+                if self.wallet_service.record_exists::<CredentialIdentityAttr>(wallet_handle, &cred_def_id.0)? {
+                    self.wallet_service.delete_indy_record::<CredentialIdentityAttr>(wallet_handle, &cred_def_id.0)?;
+                }
+            }
+        };
+
+        debug!("set_credential_identity_attr <<<");
+
+        Ok(())
+    }
+
     fn store_credential(&self,
                         wallet_handle: WalletHandle,
                         cred_id: Option<&str>,
@@ -409,8 +729,29 @@ impl ProverCommandExecutor {
             None
         };
 
-        let cred_tags = self.anoncreds_service.prover.build_credential_tags(&credential, catpol.as_ref())?;
-        self.wallet_service.add_indy_object(wallet_handle, &out_cred_id, credential, &cred_tags)?;
+        let mut cred_tags = self.anoncreds_service.prover.build_credential_tags(&credential, catpol.as_ref())?;
+        cred_tags.insert(CREDENTIAL_MASTER_SECRET_TAG.to_string(), cred_req_metadata.master_secret_name.clone());
+
+        let status = CredentialStatus {
+            expires_at: self._get_credential_expiry(wallet_handle, &out_cred_id, &credential)?,
+            ..CredentialStatus::default()
+        };
+        cred_tags.extend(status.to_tags());
+
+        let mut rollback = Rollback::new();
+
+        rollback.apply("credential",
+                       || self.wallet_service.add_indy_object(wallet_handle, &out_cred_id, credential, &cred_tags),
+                       || self.wallet_service.delete_indy_record::<Credential>(wallet_handle, &out_cred_id))?;
+
+        // kept to move the credential together with its metadata (see `export_credential`)
+        rollback.apply("credential request metadata",
+                       || self.wallet_service.add_indy_object(wallet_handle, &out_cred_id, cred_req_metadata, &HashMap::new()),
+                       || self.wallet_service.delete_indy_record::<CredentialRequestMetadata>(wallet_handle, &out_cred_id))?;
+
+        self._replace_credentials(wallet_handle, &out_cred_id, &credential)?;
+
+        rollback.commit();
 
         debug!("store_credential <<< out_cred_id: {:?}", out_cred_id);
 
@@ -422,11 +763,11 @@ impl ProverCommandExecutor {
                        filter_json: Option<&str>) -> IndyResult<String> {
         debug!("get_credentials >>> wallet_handle: {:?}, filter_json: {:?}", wallet_handle, filter_json);
 
-        let filter_json = filter_json.unwrap_or("{}");
+        let filter_json = self._process_typed_query(filter_json.unwrap_or("{}"))?;
         let mut credentials_info: Vec<CredentialInfo> = Vec::new();
 
         let mut credentials_search =
-            self.wallet_service.search_indy_records::<Credential>(wallet_handle, filter_json, &SearchOptions::id_value())?;
+            self.wallet_service.search_indy_records::<Credential>(wallet_handle, &filter_json, &SearchOptions::id_value())?;
 
         while let Some(credential_record) = credentials_search.fetch_next_record()? {
             let (referent, credential) = self._get_credential(&credential_record)?;
@@ -458,13 +799,36 @@ impl ProverCommandExecutor {
         Ok(credential_info_json)
     }
 
+    fn get_credential_status(&self,
+                             wallet_handle: WalletHandle,
+                             cred_id: &str) -> IndyResult<String> {
+        debug!("get_credential_status >>> wallet_handle: {:?}, cred_id: {:?}", wallet_handle, cred_id);
+
+        let status = self._get_credential_status(wallet_handle, cred_id)?;
+
+        let status_info = CredentialStatusInfo {
+            referent: cred_id.to_string(),
+            active: status.is_active(get_seconds_since_epoch()?),
+            status,
+        };
+
+        let status_info_json = serde_json::to_string(&status_info)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize CredentialStatusInfo")?;
+
+        debug!("get_credential_status <<< status_info_json: {:?}", status_info_json);
+
+        Ok(status_info_json)
+    }
+
     fn search_credentials(&self,
                           wallet_handle: WalletHandle,
                           query_json: Option<&str>) -> IndyResult<(SearchHandle, usize)> {
         debug!("search_credentials >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
 
+        let query_json = self._process_typed_query(query_json.unwrap_or("{}"))?;
+
         let credentials_search =
-            self.wallet_service.search_indy_records::<Credential>(wallet_handle, query_json.unwrap_or("{}"), &SearchOptions::id_value())?;
+            self.wallet_service.search_indy_records::<Credential>(wallet_handle, &query_json, &SearchOptions::id_value())?;
 
         let total_count = credentials_search.get_total_count()?.unwrap_or(0);
 
@@ -657,6 +1021,216 @@ impl ProverCommandExecutor {
         Ok(())
     }
 
+    fn explain_proof_req(&self,
+                         wallet_handle: WalletHandle,
+                         proof_request: &ProofRequest,
+                         extra_query: Option<&ProofRequestExtraQuery>) -> IndyResult<String> {
+        debug!("explain_proof_req >>> wallet_handle: {:?}, proof_request: {:?}, extra_query: {:?}", wallet_handle, proof_request, extra_query);
+
+        let proof_req = proof_request.value();
+        let version = proof_request.version();
+
+        let mut diagnostics = ProofRequestDiagnostics {
+            satisfiable: false,
+            requested_attributes: HashMap::new(),
+            requested_predicates: HashMap::new(),
+        };
+
+        for (attr_id, requested_attr) in proof_req.requested_attributes.iter() {
+            let query = self.anoncreds_service.prover.process_proof_request_restrictions(&version,
+                                                                                         &requested_attr.name,
+                                                                                         &requested_attr.names,
+                                                                                         &attr_id,
+                                                                                         &requested_attr.restrictions,
+                                                                                         &extra_query)?;
+
+            let attr_names = requested_attr.names.clone()
+                .or_else(|| requested_attr.name.clone().map(|name| vec![name]))
+                .unwrap_or_default();
+
+            let interval = get_non_revoc_interval(&proof_req.non_revoked, &requested_attr.non_revoked);
+
+            diagnostics.requested_attributes.insert(attr_id.to_string(),
+                                                    self._explain_referent(wallet_handle, &attr_names, &query, None, interval)?);
+        }
+
+        for (predicate_id, requested_predicate) in proof_req.requested_predicates.iter() {
+            let query = self.anoncreds_service.prover.process_proof_request_restrictions(&version,
+                                                                                         &Some(requested_predicate.name.clone()),
+                                                                                         &None,
+                                                                                         &predicate_id,
+                                                                                         &requested_predicate.restrictions,
+                                                                                         &extra_query)?;
+
+            let interval = get_non_revoc_interval(&proof_req.non_revoked, &requested_predicate.non_revoked);
+
+            diagnostics.requested_predicates.insert(predicate_id.to_string(),
+                                                    self._explain_referent(wallet_handle, &[requested_predicate.name.clone()], &query,
+                                                                           Some(requested_predicate), interval)?);
+        }
+
+        diagnostics.finalize();
+
+        let diagnostics_json = serde_json::to_string(&diagnostics)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize ProofRequestDiagnostics")?;
+
+        debug!("explain_proof_req <<< diagnostics_json: {:?}", diagnostics_json);
+
+        Ok(diagnostics_json)
+    }
+
+    fn export_credential(&self,
+                         wallet_handle: WalletHandle,
+                         cred_id: &str,
+                         config: &CredentialExportConfig) -> IndyResult<String> {
+        debug!("export_credential >>> wallet_handle: {:?}, cred_id: {:?}, config: {:?}", wallet_handle, cred_id, secret!(config));
+
+        let options_json = json!({
+            "retrieveType": false,
+            "retrieveValue": true,
+            "retrieveTags": true,
+        }).to_string();
+
+        let credential_record = self.wallet_service.get_indy_record::<Credential>(wallet_handle, cred_id, &options_json)?;
+        let (_, credential) = self._get_credential(&credential_record)?;
+        let cred_tags = credential_record.get_tags().cloned().unwrap_or_default();
+
+        // The bundle is bound to the master secret the credential was issued to, a caller can only confirm it.
+        let master_secret_id = match (cred_tags.get(CREDENTIAL_MASTER_SECRET_TAG), config.master_secret_id.as_ref()) {
+            (Some(cred_master_secret_id), Some(master_secret_id)) if cred_master_secret_id != master_secret_id =>
+                return Err(err_msg(IndyErrorKind::InvalidStructure,
+                                   format!("Credential {} is bound to MasterSecret {} other than MasterSecret {}", cred_id, cred_master_secret_id, master_secret_id))),
+            (Some(cred_master_secret_id), _) => cred_master_secret_id.clone(),
+            // credentials stored before master secret binding was tracked
+            (None, Some(master_secret_id)) => master_secret_id.clone(),
+            (None, None) => self._wallet_get_default_master_secret_id(wallet_handle)?
+                .ok_or_else(|| err_msg(IndyErrorKind::WalletItemNotFound, "Default MasterSecret not found"))?
+        };
+
+        let master_secret: MasterSecret = self._wallet_get_master_secret(wallet_handle, &master_secret_id)?;
+
+        let master_secret_fingerprint = self.anoncreds_service.prover.master_secret_fingerprint(&master_secret.value)?;
+
+        let cred_req_metadata = self.wallet_service.get_indy_opt_object::<CredentialRequestMetadata>(wallet_handle, cred_id, &RecordOptions::id_value())?;
+
+        let status = CredentialStatus::from_tags(&cred_tags);
+
+        let revocation = credential.rev_reg_id.clone()
+            .map(|rev_reg_id| CredentialBundleRevocation {
+                rev_reg_id,
+                cred_rev_id: credential.signature.extract_index().map(|rev_idx| rev_idx.to_string()),
+                revoked: status.revoked,
+                revoked_as_of: status.revoked_as_of,
+            });
+
+        let payload = CredentialBundlePayload {
+            cred_id: cred_id.to_string(),
+            credential,
+            master_secret_id,
+            master_secret_fingerprint,
+            master_secret: if config.include_master_secret { Some(master_secret) } else { None },
+            revocation,
+            cred_req_metadata,
+        };
+
+        let bundle = self.anoncreds_service.prover.seal_credential_bundle(&payload, &config.key, &config.key_derivation_method)?;
+
+        let res = serde_json::to_string(&bundle)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize CredentialBundle")?;
+
+        debug!("export_credential <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn import_credential(&self,
+                         wallet_handle: WalletHandle,
+                         bundle: &CredentialBundle,
+                         config: &CredentialImportConfig) -> IndyResult<String> {
+        debug!("import_credential >>> wallet_handle: {:?}, bundle: {:?}, config: {:?}", wallet_handle, bundle, secret!(config));
+
+        let payload = self.anoncreds_service.prover.open_credential_bundle(bundle, &config.key)?;
+
+        let cred_id = config.cred_id.clone().unwrap_or_else(|| payload.cred_id.clone());
+        let master_secret_id = config.master_secret_id.clone().unwrap_or_else(|| payload.master_secret_id.clone());
+
+        if self.wallet_service.record_exists::<Credential>(wallet_handle, &cred_id)? {
+            return Err(err_msg(IndyErrorKind::WalletItemAlreadyExists, format!("Credential {} already exists", cred_id)));
+        }
+
+        if payload.cred_req_metadata.is_some() && self.wallet_service.record_exists::<CredentialRequestMetadata>(wallet_handle, &cred_id)? {
+            return Err(err_msg(IndyErrorKind::WalletItemAlreadyExists, format!("CredentialRequestMetadata {} already exists", cred_id)));
+        }
+
+        // The credential is usable only with the link secret it was issued to,
+        // so the local link secret with the same id must be exactly that one.
+        let master_secret_to_store = if self.wallet_service.record_exists::<MasterSecret>(wallet_handle, &master_secret_id)? {
+            let master_secret = self._wallet_get_master_secret(wallet_handle, &master_secret_id)?;
+
+            if self.anoncreds_service.prover.master_secret_fingerprint(&master_secret.value)? != payload.master_secret_fingerprint {
+                return Err(err_msg(IndyErrorKind::InvalidStructure,
+                                   format!("Credential is bound to a link secret other than MasterSecret {}", master_secret_id)));
+            }
+
+            None
+        } else {
+            match payload.master_secret {
+                Some(master_secret) => Some(master_secret),
+                None => return Err(err_msg(IndyErrorKind::WalletItemNotFound,
+                                           format!("MasterSecret {} not found and credential bundle does not carry link secret", master_secret_id)))
+            }
+        };
+
+        let credential = payload.credential;
+
+        // the metadata follows the link secret to its local id
+        let cred_req_metadata = payload.cred_req_metadata
+            .map(|cred_req_metadata| CredentialRequestMetadata { master_secret_name: master_secret_id.clone(), ..cred_req_metadata });
+
+        if payload.revocation.as_ref().map(|revocation| &revocation.rev_reg_id) != credential.rev_reg_id.as_ref() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Revocation info of credential bundle doesn't match the credential"));
+        }
+
+        let catpol = self.wallet_service.get_indy_opt_object::<CredentialAttrTagPolicy>(wallet_handle, &credential.cred_def_id.0, &RecordOptions::id_value())?;
+        let mut cred_tags = self.anoncreds_service.prover.build_credential_tags(&credential, catpol.as_ref())?;
+        cred_tags.insert(CREDENTIAL_MASTER_SECRET_TAG.to_string(), master_secret_id.clone());
+
+        let status = CredentialStatus {
+            revoked: payload.revocation.as_ref().map(|revocation| revocation.revoked).unwrap_or(false),
+            revoked_as_of: payload.revocation.as_ref().and_then(|revocation| revocation.revoked_as_of),
+            expires_at: self._get_credential_expiry(wallet_handle, &cred_id, &credential)?,
+            replaced_by: None,
+        };
+        cred_tags.extend(status.to_tags());
+
+        // Everything is validated, the records are written together or not at all.
+        let mut rollback = Rollback::new();
+
+        if let Some(master_secret) = master_secret_to_store {
+            rollback.apply("master secret",
+                           || self.wallet_service.add_indy_object(wallet_handle, &master_secret_id, &master_secret, &HashMap::new()),
+                           || self.wallet_service.delete_indy_record::<MasterSecret>(wallet_handle, &master_secret_id))?;
+        }
+
+        rollback.apply("credential",
+                       || self.wallet_service.add_indy_object(wallet_handle, &cred_id, &credential, &cred_tags),
+                       || self.wallet_service.delete_indy_record::<Credential>(wallet_handle, &cred_id))?;
+
+        if let Some(cred_req_metadata) = cred_req_metadata {
+            rollback.apply("credential request metadata",
+                           || self.wallet_service.add_indy_object(wallet_handle, &cred_id, &cred_req_metadata, &HashMap::new()),
+                           || self.wallet_service.delete_indy_record::<CredentialRequestMetadata>(wallet_handle, &cred_id))?;
+        }
+
+        self._replace_credentials(wallet_handle, &cred_id, &credential)?;
+
+        rollback.commit();
+
+        debug!("import_credential <<< cred_id: {:?}", cred_id);
+
+        Ok(cred_id)
+    }
+
     fn delete_credential(&self,
                          wallet_handle: WalletHandle,
                          cred_id: &str) -> IndyResult<()> {
@@ -666,22 +1240,36 @@ impl ProverCommandExecutor {
             return Err(err_msg(IndyErrorKind::WalletItemNotFound, format!("Credential {} not found", cred_id)));
         }
 
-        self.wallet_service.delete_indy_record::<Credential>(wallet_handle, cred_id)
+        self.wallet_service.delete_indy_record::<Credential>(wallet_handle, cred_id)?;
+
+        if self.wallet_service.record_exists::<CredentialRequestMetadata>(wallet_handle, cred_id)? {
+            self.wallet_service.delete_indy_record::<CredentialRequestMetadata>(wallet_handle, cred_id)?;
+        }
+
+        Ok(())
     }
 
     fn create_proof(&self,
                     wallet_handle: WalletHandle,
                     proof_req: &ProofRequest,
                     requested_credentials: &RequestedCredentials,
-                    master_secret_id: &str,
+                    master_secret_id: Option<&str>,
                     schemas: &HashMap<SchemaId, SchemaV1>,
                     cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
-                    rev_states: &RevocationStates) -> IndyResult<String> {
+                    rev_states: &RevocationStates,
+                    options: &CreateProofOptions) -> IndyResult<String> {
         debug!("create_proof >>> wallet_handle: {:?}, proof_req: {:?}, requested_credentials: {:?}, master_secret_id: {:?}, schemas: {:?}, \
-        cred_defs: {:?}, rev_states: {:?}",
-               wallet_handle, proof_req, requested_credentials, master_secret_id, schemas, cred_defs, rev_states);
+        cred_defs: {:?}, rev_states: {:?}, options: {:?}",
+               wallet_handle, proof_req, requested_credentials, master_secret_id, schemas, cred_defs, rev_states, options);
 
-        let master_secret: MasterSecret = self._wallet_get_master_secret(wallet_handle, &master_secret_id)?;
+        let master_secret_id = match master_secret_id {
+            Some(master_secret_id) => master_secret_id.to_string(),
+            None => self._wallet_get_default_master_secret_id(wallet_handle)?
+                .ok_or_else(|| err_msg(IndyErrorKind::WalletItemNotFound, "Default MasterSecret not found"))?
+        };
+
+        let mut master_secrets: HashMap<String, MasterSecret> = HashMap::new();
+        master_secrets.insert(master_secret_id.clone(), self._wallet_get_master_secret(wallet_handle, &master_secret_id)?);
 
         let cred_refs_for_attrs =
             requested_credentials.requested_attributes
@@ -698,16 +1286,51 @@ impl ProverCommandExecutor {
         let cred_referents = cred_refs_for_attrs.union(&cred_refs_for_predicates).cloned().collect::<Vec<String>>();
 
         let mut credentials: HashMap<String, Credential> = HashMap::with_capacity(cred_referents.len());
+        let mut cred_master_secret_ids: HashMap<String, String> = HashMap::with_capacity(cred_referents.len());
+
+        let options_json = json!({
+            "retrieveType": false,
+            "retrieveValue": true,
+            "retrieveTags": true,
+        }).to_string();
 
         for cred_referent in cred_referents.into_iter() {
-            let credential: Credential = self.wallet_service.get_indy_object(wallet_handle, &cred_referent, &RecordOptions::id_value())?;
+            let credential_record = self.wallet_service.get_indy_record::<Credential>(wallet_handle, &cred_referent, &options_json)?;
+            let (_, credential) = self._get_credential(&credential_record)?;
+
+            // credentials stored before master secret binding was tracked are assumed to be bound to the requested one
+            let cred_master_secret_id = credential_record.get_tags()
+                .and_then(|tags| tags.get(CREDENTIAL_MASTER_SECRET_TAG))
+                .cloned()
+                .unwrap_or_else(|| master_secret_id.clone());
+
+            if cred_master_secret_id != master_secret_id {
+                if !options.allow_mixed_master_secrets {
+                    return Err(err_msg(IndyErrorKind::InvalidStructure,
+                                       format!("Credential {} is bound to MasterSecret {} other than MasterSecret {} used for proof",
+                                               cred_referent, cred_master_secret_id, master_secret_id)));
+                }
+
+                if !master_secrets.contains_key(&cred_master_secret_id) {
+                    let master_secret = self._wallet_get_master_secret(wallet_handle, &cred_master_secret_id)?;
+                    master_secrets.insert(cred_master_secret_id.clone(), master_secret);
+                }
+            }
+
+            cred_master_secret_ids.insert(cred_referent.clone(), cred_master_secret_id);
             credentials.insert(cred_referent, credential);
         }
 
+        let cred_master_secrets: HashMap<String, &CryptoMasterSecret> =
+            cred_master_secret_ids
+                .iter()
+                .map(|(cred_referent, master_secret_id)| (cred_referent.clone(), &master_secrets[master_secret_id].value))
+                .collect();
+
         let proof = self.anoncreds_service.prover.create_proof(&credentials,
                                                                &proof_req,
                                                                &requested_credentials,
-                                                               &master_secret.value,
+                                                               &cred_master_secrets,
                                                                schemas,
                                                                cred_defs,
                                                                rev_states)?;
@@ -755,6 +1378,57 @@ impl ProverCommandExecutor {
         Ok(revocation_state_json)
     }
 
+    fn create_credential_revocation_state(&self,
+                                          wallet_handle: WalletHandle,
+                                          blob_storage_reader_handle: i32,
+                                          revoc_reg_def: RevocationRegistryDefinition,
+                                          rev_reg_delta: RevocationRegistryDelta,
+                                          timestamp: u64,
+                                          cred_id: &str) -> IndyResult<String> {
+        debug!("create_credential_revocation_state >>> wallet_handle: {:?}, blob_storage_reader_handle: {:?}, revoc_reg_def: {:?}, rev_reg_delta: {:?}, \
+        timestamp: {:?}, cred_id: {:?}", wallet_handle, blob_storage_reader_handle, revoc_reg_def, rev_reg_delta, timestamp, cred_id);
+
+        let credential: Credential = self.wallet_service.get_indy_object(wallet_handle, cred_id, &RecordOptions::id_value())?;
+
+        let rev_idx = credential.signature.extract_index()
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Credential {} is not revocable", cred_id)))?;
+
+        let rev_reg_id = match revoc_reg_def {
+            RevocationRegistryDefinition::RevocationRegistryDefinitionV1(ref revoc_reg_def) => revoc_reg_def.id.clone()
+        };
+
+        if credential.rev_reg_id.as_ref() != Some(&rev_reg_id) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Credential {} doesn't belong to RevocationRegistry {}", cred_id, rev_reg_id.0)));
+        }
+
+        let revoked = match rev_reg_delta {
+            RevocationRegistryDelta::RevocationRegistryDeltaV1(ref rev_reg_delta) =>
+                self.anoncreds_service.prover.revocation_in_delta(rev_reg_delta, rev_idx)?
+        };
+
+        let revocation_state_json = self.create_revocation_state(blob_storage_reader_handle, revoc_reg_def, rev_reg_delta, timestamp, &rev_idx.to_string())?;
+
+        // The earliest known revoked timestamp is kept. Revocation is cleared only by a delta
+        // issuing the credential after it, as the issuer can recover a revoked credential.
+        if let Some(revoked) = revoked {
+            let status = self._get_credential_status(wallet_handle, cred_id)?;
+            let is_before_revocation = status.revoked_as_of.map(|revoked_as_of| timestamp < revoked_as_of).unwrap_or(true);
+
+            if revoked && (!status.revoked || is_before_revocation) {
+                let status = CredentialStatus { revoked: true, revoked_as_of: Some(timestamp), ..CredentialStatus::default() };
+                self.wallet_service.add_record_tags(wallet_handle, &self.wallet_service.add_prefix("Credential"), cred_id, &status.to_tags())?;
+            } else if !revoked && status.revoked && !is_before_revocation {
+                self.wallet_service.delete_record_tags(wallet_handle, &self.wallet_service.add_prefix("Credential"), cred_id,
+                                                       &[CREDENTIAL_STATUS_REVOKED_TAG, CREDENTIAL_STATUS_REVOKED_AS_OF_TAG])?;
+            }
+        }
+
+        debug!("create_credential_revocation_state <<< revocation_state_json: {:?}", revocation_state_json);
+
+        Ok(revocation_state_json)
+    }
+
     fn update_revocation_state(&self,
                                blob_storage_reader_handle: i32,
                                mut rev_state: RevocationState,
@@ -807,6 +1481,100 @@ impl ProverCommandExecutor {
         }
     }
 
+    fn _get_credential_status(&self,
+                              wallet_handle: WalletHandle,
+                              cred_id: &str) -> IndyResult<CredentialStatus> {
+        let options_json = json!({
+            "retrieveType": false,
+            "retrieveValue": false,
+            "retrieveTags": true,
+        }).to_string();
+
+        let credential_record = self.wallet_service.get_indy_record::<Credential>(wallet_handle, cred_id, &options_json)?;
+
+        Ok(credential_record.get_tags()
+            .map(CredentialStatus::from_tags)
+            .unwrap_or_default())
+    }
+
+    // Invalid expiry value doesn't prevent storing of the credential, it is just not tracked.
+    fn _get_credential_expiry(&self,
+                              wallet_handle: WalletHandle,
+                              cred_id: &str,
+                              credential: &Credential) -> IndyResult<Option<u64>> {
+        let expiry_attr = self.wallet_service.get_indy_opt_object::<CredentialExpiryAttr>(wallet_handle, &credential.cred_def_id.0, &RecordOptions::id_value())?;
+
+        Ok(match expiry_attr {
+            Some(expiry_attr) => match self.anoncreds_service.prover.get_credential_expiry(credential, &expiry_attr.attr_name) {
+                Ok(expires_at) => expires_at,
+                Err(err) => {
+                    warn!("Expiry of credential {} is not tracked: {}", cred_id, err);
+                    None
+                }
+            },
+            None => None
+        })
+    }
+
+    // The newly stored credential replaces the other not yet replaced credentials of the same credential definition
+    // with the same value of the identity attribute. Without designated identity attribute nothing is replaced.
+    fn _replace_credentials(&self,
+                            wallet_handle: WalletHandle,
+                            cred_id: &str,
+                            credential: &Credential) -> IndyResult<()> {
+        let identity_attr = match self.wallet_service.get_indy_opt_object::<CredentialIdentityAttr>(wallet_handle, &credential.cred_def_id.0, &RecordOptions::id_value())? {
+            Some(identity_attr) => identity_attr,
+            None => return Ok(())
+        };
+
+        let identity = match self.anoncreds_service.prover.get_credential_values_for_attribute(&credential.values.0, &identity_attr.attr_name) {
+            Some(values) => values.raw,
+            None => {
+                warn!("Credential {} doesn't replace other credentials as it has no identity attribute \"{}\"", cred_id, identity_attr.attr_name);
+                return Ok(());
+            }
+        };
+
+        let query_json = Query::And(vec![
+            Query::Eq("cred_def_id".to_string(), credential.cred_def_id.0.clone()),
+            Query::Not(Box::new(Query::Eq(CREDENTIAL_STATUS_REPLACED_TAG.to_string(), CREDENTIAL_STATUS_FLAG.to_string()))),
+        ]).to_string();
+
+        let mut credentials_search =
+            self.wallet_service.search_indy_records::<Credential>(wallet_handle, &query_json, &SearchOptions::id_value())?;
+
+        let mut replaced_cred_ids: Vec<String> = Vec::new();
+
+        while let Some(credential_record) = credentials_search.fetch_next_record()? {
+            let (referent, other_credential) = self._get_credential(&credential_record)?;
+
+            let same_identity = self.anoncreds_service.prover.get_credential_values_for_attribute(&other_credential.values.0, &identity_attr.attr_name)
+                .map(|values| values.raw == identity)
+                .unwrap_or(false);
+
+            if referent != cred_id && same_identity {
+                replaced_cred_ids.push(referent);
+            }
+        }
+
+        let status = CredentialStatus { replaced_by: Some(cred_id.to_string()), ..CredentialStatus::default() };
+        let type_ = self.wallet_service.add_prefix("Credential");
+
+        for replaced_cred_id in replaced_cred_ids {
+            self.wallet_service.add_record_tags(wallet_handle, &type_, &replaced_cred_id, &status.to_tags())?;
+        }
+
+        Ok(())
+    }
+
+    // Malformed queries are passed to the wallet as is, so they are reported the same way as before.
+    fn _process_typed_query(&self, query_json: &str) -> IndyResult<String> {
+        match serde_json::from_str::<Query>(query_json) {
+            Ok(query) => Ok(Prover::process_typed_query(query)?.to_string()),
+            Err(_) => Ok(query_json.to_string())
+        }
+    }
+
     fn _get_credential(&self,
                        record: &WalletRecord) -> IndyResult<(String, Credential)> {
         let referent = record.get_id();
@@ -878,8 +1646,114 @@ impl ProverCommandExecutor {
     }
 
 
+    fn _explain_referent(&self,
+                         wallet_handle: WalletHandle,
+                         attr_names: &[String],
+                         query: &Query,
+                         predicate_info: Option<&PredicateInfo>,
+                         interval: Option<NonRevocedInterval>) -> IndyResult<ReferentDiagnostics> {
+        let mut diagnostics = ReferentDiagnostics::new(interval.clone());
+
+        if let Some(NonRevocedInterval { from: Some(from), to: Some(to) }) = &interval {
+            if from > to {
+                diagnostics.issues.push(
+                    DiagnosticsIssue::new(DiagnosticsIssueReason::InvalidInterval,
+                                          &format!("Non-revocation interval is invalid: \"from\" {} is greater than \"to\" {}", from, to)));
+            }
+        }
+
+        let prover = &self.anoncreds_service.prover;
+
+        let options_json = json!({
+            "retrieveRecords": true,
+            "retrieveTotalCount": false,
+            "retrieveType": false,
+            "retrieveValue": true,
+            "retrieveTags": true,
+        }).to_string();
+
+        // only credentials holding all requested attributes are worth explaining
+        let mut credentials_search =
+            self.wallet_service.search_indy_records::<Credential>(wallet_handle, &prover.attr_markers_query(attr_names).to_string(), &options_json)?;
+
+        while let Some(credential_record) = credentials_search.fetch_next_record()? {
+            if diagnostics.credentials.len() == EXPLAIN_MAX_CREDENTIALS {
+                diagnostics.truncated = true;
+                break;
+            }
+
+            let (referent, credential) = self._get_credential(&credential_record)?;
+            let tags = credential_record.get_tags().cloned().unwrap_or_default();
+
+            let mut issues = prover.explain_query_mismatch(query, &tags);
+
+            if let Some(predicate) = predicate_info {
+                if let Some(attr_values) = prover.get_credential_values_for_attribute(&credential.values.0, &predicate.name) {
+                    match prover.attribute_satisfy_predicate(predicate, &attr_values.encoded) {
+                        Ok(true) => {}
+                        Ok(false) => issues.push(
+                            DiagnosticsIssue::new(DiagnosticsIssueReason::PredicateNotSatisfied,
+                                                  &format!("Credential attribute \"{}\" value \"{}\" does not satisfy predicate \"{} {} {}\"",
+                                                           predicate.name, attr_values.raw, predicate.name, predicate.p_type, predicate.p_value))),
+                        Err(_) => issues.push(
+                            DiagnosticsIssue::new(DiagnosticsIssueReason::InvalidAttributeValue,
+                                                  &format!("Credential attribute \"{}\" value \"{}\" is not a 32-bit integer and cannot be used in predicate",
+                                                           predicate.name, attr_values.raw))),
+                    }
+                }
+            }
+
+            if interval.is_some() && credential.rev_reg_id.is_none() {
+                issues.push(
+                    DiagnosticsIssue::new(DiagnosticsIssueReason::CredentialNotRevocable,
+                                          "Proof request requires non-revocation proof but credential is not revocable, \
+                                          so verifier will reject proof without revocation timestamp"));
+            }
+
+            diagnostics.credentials.push(CredentialDiagnostics::new(self._get_credential_info(&referent, credential), issues));
+        }
+
+        diagnostics.finalize();
+
+        Ok(diagnostics)
+    }
+
     fn _wallet_get_master_secret(&self, wallet_handle: WalletHandle, key: &str) -> IndyResult<MasterSecret> {
         self.wallet_service.get_indy_object(wallet_handle, &key, &RecordOptions::id_value())
     }
+
+    fn _wallet_get_master_secret_info(&self, wallet_handle: WalletHandle, key: &str) -> IndyResult<MasterSecretInfo> {
+        let options_json = json!({
+            "retrieveType": false,
+            "retrieveValue": false,
+            "retrieveTags": true,
+        }).to_string();
+
+        let master_secret_record = self.wallet_service.get_indy_record::<MasterSecret>(wallet_handle, key, &options_json)?;
+
+        Ok(ProverCommandExecutor::_master_secret_info(&master_secret_record))
+    }
+
+    fn _wallet_get_default_master_secret_id(&self, wallet_handle: WalletHandle) -> IndyResult<Option<String>> {
+        let query_json = format!(r#"{{"{}": "1"}}"#, MASTER_SECRET_DEFAULT_TAG);
+
+        let mut master_secrets_search =
+            self.wallet_service.search_indy_records::<MasterSecret>(wallet_handle, &query_json, &SearchOptions::id_value())?;
+
+        Ok(master_secrets_search.fetch_next_record()?.map(|record| record.get_id().to_string()))
+    }
+
+    fn _master_secret_info(record: &WalletRecord) -> MasterSecretInfo {
+        let is_set = |tag_name: &str| record.get_tags()
+            .and_then(|tags| tags.get(tag_name))
+            .map(|value| value == "1")
+            .unwrap_or(false);
+
+        MasterSecretInfo {
+            id: record.get_id().to_string(),
+            default: is_set(MASTER_SECRET_DEFAULT_TAG),
+            retired: is_set(MASTER_SECRET_RETIRED_TAG),
+        }
+    }
 }
 
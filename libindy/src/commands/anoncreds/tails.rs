@@ -7,11 +7,15 @@ use ursa::errors::prelude::{UrsaCryptoError, UrsaCryptoErrorKind};
 
 use rust_base58::{ToBase58, FromBase58};
 
+use std::cmp;
 use std::rc::Rc;
 
 const TAILS_BLOB_TAG_SZ: u8 = 2;
 const TAIL_SIZE: usize = Tail::BYTES_REPR_SIZE;
 
+/// Count of tails written to the blob at once during revocation registry creation.
+pub const TAILS_CHUNK_SIZE: u32 = 1024;
+
 pub struct SDKTailsAccessor {
     tails_service: Rc<BlobStorageService>,
     tails_reader_handle: i32,
@@ -64,23 +68,104 @@ impl RevocationTailsAccessor for SDKTailsAccessor {
     }
 }
 
-pub fn store_tails_from_generator(service: Rc<BlobStorageService>,
-                                  writer_handle: i32,
-                                  rtg: &mut RevocationTailsGenerator) -> IndyResult<(String, String)> {
-    debug!("store_tails_from_generator >>> writer_handle: {:?}", writer_handle);
+pub fn create_tails_blob(service: Rc<BlobStorageService>, writer_handle: i32) -> IndyResult<i32> {
+    debug!("create_tails_blob >>> writer_handle: {:?}", writer_handle);
 
     let blob_handle = service.create_blob(writer_handle)?;
 
     let version = vec![0u8, TAILS_BLOB_TAG_SZ];
-    service.append(blob_handle, version.as_slice())?;
-
-    while let Some(tail) = rtg.try_next()? {
-        let tail_bytes = tail.to_bytes()?;
-        service.append(blob_handle, tail_bytes.as_slice())?;
+    if let Err(err) = service.append(blob_handle, version.as_slice()) {
+        abort_tails_blob(service, blob_handle);
+        return Err(err);
     }
 
+    debug!("create_tails_blob <<< blob_handle: {:?}", blob_handle);
+    Ok(blob_handle)
+}
+
+pub fn finalize_tails_blob(service: Rc<BlobStorageService>, blob_handle: i32) -> IndyResult<(String, String)> {
+    debug!("finalize_tails_blob >>> blob_handle: {:?}", blob_handle);
+
     let res = service.finalize(blob_handle).map(|(location, hash)| (location, hash.to_base58()))?;
 
-    debug!("store_tails_from_generator <<< res: {:?}", res);
+    debug!("finalize_tails_blob <<< res: {:?}", res);
     Ok(res)
 }
+
+pub fn abort_tails_blob(service: Rc<BlobStorageService>, blob_handle: i32) {
+    debug!("abort_tails_blob >>> blob_handle: {:?}", blob_handle);
+
+    #[allow(unused_must_use)]
+        {
+            service.abort(blob_handle)
+                .map_err(map_err_err!());
+        }
+
+    debug!("abort_tails_blob <<<");
+}
+
+/// Generates up to `chunk_size` next tails of the generator.
+pub fn generate_tails_chunk(rtg: &mut RevocationTailsGenerator, chunk_size: u32) -> IndyResult<Vec<u8>> {
+    let chunk_size = cmp::min(rtg.count(), chunk_size);
+    let mut bytes = Vec::with_capacity(chunk_size as usize * TAIL_SIZE);
+
+    for _ in 0..chunk_size {
+        match rtg.try_next()? {
+            Some(tail) => bytes.extend_from_slice(tail.to_bytes()?.as_slice()),
+            None => break
+        }
+    }
+
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::domain::anoncreds::credential_definition::{CredentialDefinitionId, CredentialDefinitionV1, SignatureType};
+    use crate::domain::anoncreds::schema::{AttributeNames, SchemaId};
+    use crate::domain::crypto::did::DidValue;
+    use crate::services::anoncreds::issuer::Issuer;
+
+    fn _tails_generator(max_cred_num: u32) -> RevocationTailsGenerator {
+        let mut attr_names = AttributeNames::new();
+        attr_names.0.insert("name".to_string());
+
+        let (cred_def_data, _, _) = Issuer::new_credential_definition(&attr_names, true).unwrap();
+
+        let cred_def = CredentialDefinitionV1 {
+            id: CredentialDefinitionId("NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:tag".to_string()),
+            schema_id: SchemaId("1".to_string()),
+            signature_type: SignatureType::CL,
+            tag: "tag".to_string(),
+            value: cred_def_data,
+        };
+
+        let (_, _, _, rtg) =
+            Issuer::new_revocation_registry(&cred_def, max_cred_num, true, &DidValue("NcYxiDXkpYi6ov5FcYDi1e".to_string())).unwrap();
+        rtg
+    }
+
+    #[test]
+    fn generate_tails_chunk_works() {
+        let mut rtg = _tails_generator(5);
+        let count = rtg.count();
+
+        let mut chunks = Vec::new();
+        let mut chunked_rtg = rtg.clone();
+        while chunked_rtg.count() > 0 {
+            chunks.push(generate_tails_chunk(&mut chunked_rtg, 4).unwrap());
+        }
+
+        assert_eq!(3, chunks.len());
+        assert_eq!(count as usize * TAIL_SIZE, chunks.iter().map(Vec::len).sum::<usize>());
+
+        let mut expected = Vec::new();
+        while let Some(tail) = rtg.try_next().unwrap() {
+            expected.extend_from_slice(tail.to_bytes().unwrap().as_slice());
+        }
+
+        assert_eq!(expected, chunks.concat());
+    }
+}
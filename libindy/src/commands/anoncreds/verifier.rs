@@ -4,11 +4,17 @@ use std::rc::Rc;
 use crate::domain::anoncreds::credential_definition::{cred_defs_map_to_cred_defs_v1_map, CredentialDefinitionV1, CredentialDefinitionId, CredentialDefinitions};
 use crate::domain::anoncreds::proof::Proof;
 use crate::domain::anoncreds::proof_request::{ProofRequest, ProofRequestPayload};
+use crate::domain::anoncreds::proof_request_template::ProofRequestTemplate;
 use crate::domain::anoncreds::revocation_registry::{rev_regs_map_to_rev_regs_local_map, RevocationRegistryV1, RevocationRegistries};
 use crate::domain::anoncreds::revocation_registry_definition::{rev_reg_defs_map_to_rev_reg_defs_v1_map, RevocationRegistryDefinitionV1, RevocationRegistryId, RevocationRegistryDefinitions};
 use crate::domain::anoncreds::schema::{schemas_map_to_schemas_v1_map, SchemaV1, SchemaId, Schemas};
+use crate::domain::anoncreds::verification_options::VerifyProofOptions;
+use crate::domain::anoncreds::w3c::W3CPresentation;
+use crate::domain::to_canonical_json;
 use indy_api_types::errors::prelude::*;
 use crate::services::anoncreds::AnoncredsService;
+use crate::services::anoncreds::helpers::get_seconds_since_epoch;
+use crate::services::anoncreds::w3c;
 
 pub enum VerifierCommand {
     VerifyProof(
@@ -18,8 +24,29 @@ pub enum VerifierCommand {
         CredentialDefinitions, // credential defs
         RevocationRegistryDefinitions, // rev reg defs
         RevocationRegistries, // rev reg entries
+        VerifyProofOptions, // verification options
+        Box<dyn Fn(IndyResult<bool>) + Send>),
+    VerifyProofDetailed(
+        ProofRequest, // proof request
+        Proof, // proof
+        Schemas, // credential schemas
+        CredentialDefinitions, // credential defs
+        RevocationRegistryDefinitions, // rev reg defs
+        RevocationRegistries, // rev reg entries
+        VerifyProofOptions, // verification options
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    VerifyW3CPresentation(
+        ProofRequest, // proof request
+        W3CPresentation, // w3c presentation
+        Schemas, // credential schemas
+        CredentialDefinitions, // credential defs
+        RevocationRegistryDefinitions, // rev reg defs
+        RevocationRegistries, // rev reg entries
         Box<dyn Fn(IndyResult<bool>) + Send>),
     GenerateNonce(
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    BuildProofRequest(
+        ProofRequestTemplate, // proof request template
         Box<dyn Fn(IndyResult<String>) + Send>)
 }
 
@@ -36,18 +63,40 @@ impl VerifierCommandExecutor {
 
     pub fn execute(&self, command: VerifierCommand) {
         match command {
-            VerifierCommand::VerifyProof(proof_request, proof, schemas, credential_defs, rev_reg_defs, rev_regs, cb) => {
+            VerifierCommand::VerifyProof(proof_request, proof, schemas, credential_defs, rev_reg_defs, rev_regs, options, cb) => {
                 debug!(target: "verifier_command_executor", "VerifyProof command received");
                 cb(self.verify_proof(&proof_request.value(), proof,
                                      &schemas_map_to_schemas_v1_map(schemas),
                                      &cred_defs_map_to_cred_defs_v1_map(credential_defs),
                                      &rev_reg_defs_map_to_rev_reg_defs_v1_map(rev_reg_defs),
-                                     &rev_regs_map_to_rev_regs_local_map(rev_regs)));
+                                     &rev_regs_map_to_rev_regs_local_map(rev_regs),
+                                     &options));
+            }
+            VerifierCommand::VerifyProofDetailed(proof_request, proof, schemas, credential_defs, rev_reg_defs, rev_regs, options, cb) => {
+                debug!(target: "verifier_command_executor", "VerifyProofDetailed command received");
+                cb(self.verify_proof_detailed(&proof_request.value(), proof,
+                                              &schemas_map_to_schemas_v1_map(schemas),
+                                              &cred_defs_map_to_cred_defs_v1_map(credential_defs),
+                                              &rev_reg_defs_map_to_rev_reg_defs_v1_map(rev_reg_defs),
+                                              &rev_regs_map_to_rev_regs_local_map(rev_regs),
+                                              &options));
+            }
+            VerifierCommand::VerifyW3CPresentation(proof_request, presentation, schemas, credential_defs, rev_reg_defs, rev_regs, cb) => {
+                debug!(target: "verifier_command_executor", "VerifyW3CPresentation command received");
+                cb(self.verify_w3c_presentation(&proof_request.value(), &presentation,
+                                                &schemas_map_to_schemas_v1_map(schemas),
+                                                &cred_defs_map_to_cred_defs_v1_map(credential_defs),
+                                                &rev_reg_defs_map_to_rev_reg_defs_v1_map(rev_reg_defs),
+                                                &rev_regs_map_to_rev_regs_local_map(rev_regs)));
             }
             VerifierCommand::GenerateNonce(cb) => {
                 debug!(target: "verifier_command_executor", "GenerateNonce command received");
                 cb(self.generate_nonce());
             }
+            VerifierCommand::BuildProofRequest(template, cb) => {
+                debug!(target: "verifier_command_executor", "BuildProofRequest command received");
+                cb(self.build_proof_request(&template));
+            }
         };
     }
 
@@ -57,23 +106,81 @@ impl VerifierCommandExecutor {
                     schemas: &HashMap<SchemaId, SchemaV1>,
                     cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
                     rev_reg_defs: &HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
-                    rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>) -> IndyResult<bool> {
+                    rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>,
+                    options: &VerifyProofOptions) -> IndyResult<bool> {
         debug!("verify_proof >>> proof_req: {:?}, proof: {:?}, schemas: {:?}, cred_defs: {:?},  \
-               rev_reg_defs: {:?}, rev_regs: {:?}",
-               proof_req, proof, schemas, cred_defs, rev_reg_defs, rev_regs);
+               rev_reg_defs: {:?}, rev_regs: {:?}, options: {:?}",
+               proof_req, proof, schemas, cred_defs, rev_reg_defs, rev_regs, options);
 
         let result = self.anoncreds_service.verifier.verify(&proof,
                                                             &proof_req,
                                                             schemas,
                                                             cred_defs,
                                                             rev_reg_defs,
-                                                            rev_regs)?;
+                                                            rev_regs,
+                                                            options)?;
 
         debug!("verify_proof <<< result: {:?}", result);
 
         Ok(result)
     }
 
+    fn verify_proof_detailed(&self,
+                             proof_req: &ProofRequestPayload,
+                             proof: Proof,
+                             schemas: &HashMap<SchemaId, SchemaV1>,
+                             cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
+                             rev_reg_defs: &HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
+                             rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>,
+                             options: &VerifyProofOptions) -> IndyResult<String> {
+        debug!("verify_proof_detailed >>> proof_req: {:?}, proof: {:?}, schemas: {:?}, cred_defs: {:?},  \
+               rev_reg_defs: {:?}, rev_regs: {:?}, options: {:?}",
+               proof_req, proof, schemas, cred_defs, rev_reg_defs, rev_regs, options);
+
+        let report = self.anoncreds_service.verifier.verify_detailed(&proof,
+                                                                     &proof_req,
+                                                                     schemas,
+                                                                     cred_defs,
+                                                                     rev_reg_defs,
+                                                                     rev_regs,
+                                                                     options)?;
+
+        let res = serde_json::to_string(&report)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize VerificationReport")?;
+
+        debug!("verify_proof_detailed <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn verify_w3c_presentation(&self,
+                               proof_req: &ProofRequestPayload,
+                               presentation: &W3CPresentation,
+                               schemas: &HashMap<SchemaId, SchemaV1>,
+                               cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
+                               rev_reg_defs: &HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
+                               rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>) -> IndyResult<bool> {
+        debug!("verify_w3c_presentation >>> proof_req: {:?}, presentation: {:?}, schemas: {:?}, cred_defs: {:?},  \
+               rev_reg_defs: {:?}, rev_regs: {:?}",
+               proof_req, presentation, schemas, cred_defs, rev_reg_defs, rev_regs);
+
+        let proof = w3c::proof_from_w3c(presentation)?;
+
+        w3c::check_presentation_subjects(proof_req, presentation, &proof)?;
+
+        let result = self.anoncreds_service.verifier.verify(&proof,
+                                                            &proof_req,
+                                                            schemas,
+                                                            cred_defs,
+                                                            rev_reg_defs,
+                                                            rev_regs,
+                                                            &VerifyProofOptions::default())?;
+
+        debug!("verify_w3c_presentation <<< result: {:?}", result);
+
+        Ok(result)
+    }
+
     fn generate_nonce(&self) -> IndyResult<String> {
         debug!("generate_nonce >>> ");
 
@@ -86,4 +193,18 @@ impl VerifierCommandExecutor {
 
         Ok(result)
     }
+
+    fn build_proof_request(&self, template: &ProofRequestTemplate) -> IndyResult<String> {
+        debug!("build_proof_request >>> template: {:?}", template);
+
+        let nonce = self.anoncreds_service.verifier.generate_nonce()?;
+
+        let proof_request = self.anoncreds_service.verifier.build_proof_request(template, nonce, get_seconds_since_epoch()?)?;
+
+        let res = to_canonical_json(&proof_request)?;
+
+        debug!("build_proof_request <<< res: {:?}", res);
+
+        Ok(res)
+    }
 }
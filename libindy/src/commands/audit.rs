@@ -0,0 +1,67 @@
+use std::rc::Rc;
+
+use indy_api_types::WalletHandle;
+use indy_api_types::errors::prelude::*;
+
+use crate::services::audit::AuditService;
+
+pub enum AuditCommand {
+    GetAuditLog(
+        WalletHandle,
+        String, // query json
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    VerifyAuditLog(
+        WalletHandle,
+        Box<dyn Fn(IndyResult<String>) + Send>),
+}
+
+pub struct AuditCommandExecutor {
+    audit_service: Rc<AuditService>,
+}
+
+impl AuditCommandExecutor {
+    pub fn new(audit_service: Rc<AuditService>) -> AuditCommandExecutor {
+        AuditCommandExecutor {
+            audit_service,
+        }
+    }
+
+    pub fn execute(&self, command: AuditCommand) {
+        match command {
+            AuditCommand::GetAuditLog(wallet_handle, query_json, cb) => {
+                debug!(target: "audit_command_executor", "GetAuditLog command received");
+                cb(self.get_audit_log(wallet_handle, &query_json));
+            }
+            AuditCommand::VerifyAuditLog(wallet_handle, cb) => {
+                debug!(target: "audit_command_executor", "VerifyAuditLog command received");
+                cb(self.verify_audit_log(wallet_handle));
+            }
+        };
+    }
+
+    fn get_audit_log(&self, wallet_handle: WalletHandle, query_json: &str) -> IndyResult<String> {
+        debug!("get_audit_log >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
+
+        let events = self.audit_service.get_events(wallet_handle, query_json)?;
+
+        let res = serde_json::to_string(&events)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize list of AuditEvent")?;
+
+        debug!("get_audit_log <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn verify_audit_log(&self, wallet_handle: WalletHandle) -> IndyResult<String> {
+        debug!("verify_audit_log >>> wallet_handle: {:?}", wallet_handle);
+
+        let verification = self.audit_service.verify(wallet_handle)?;
+
+        let res = serde_json::to_string(&verification)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize AuditLogVerification")?;
+
+        debug!("verify_audit_log <<< res: {:?}", res);
+
+        Ok(res)
+    }
+}
@@ -6,18 +6,24 @@ use std::time::{SystemTime, UNIX_EPOCH};
 use indy_api_types::domain::wallet::Tags;
 use crate::domain::anoncreds::schema::SchemaId;
 use crate::domain::anoncreds::credential_definition::CredentialDefinitionId;
+use crate::domain::anoncreds::revocation_registry_definition::RevocationRegistryId;
 use indy_api_types::errors::prelude::*;
 use indy_wallet::{WalletService, WalletRecord};
 use indy_api_types::{WalletHandle, PoolHandle, CommandHandle};
 use crate::commands::{Command, CommandExecutor};
 use crate::commands::ledger::LedgerCommand;
-use crate::domain::cache::{GetCacheOptions, PurgeOptions};
+use crate::domain::cache::{CachedRevocRegDelta, GetCacheOptions, PurgeOptions};
 use crate::domain::crypto::did::DidValue;
+use crate::domain::ledger::author_agreement::GetTxnAuthorAgreementData;
 
 use indy_utils::next_command_handle;
 
 const CRED_DEF_CACHE: &str = "cred_def_cache";
 const SCHEMA_CACHE: &str = "schema_cache";
+const NYM_CACHE: &str = "nym_cache";
+const REVOC_REG_DEF_CACHE: &str = "revoc_reg_def_cache";
+const REVOC_REG_DELTA_CACHE: &str = "revoc_reg_delta_cache";
+const TXN_AUTHOR_AGREEMENT_CACHE: &str = "txn_author_agreement_cache";
 
 pub enum CacheCommand {
     GetSchema(PoolHandle,
@@ -44,12 +50,74 @@ pub enum CacheCommand {
         GetCacheOptions,              // options
         CommandHandle,                          // cb_id
     ),
+    GetNym(PoolHandle,
+           WalletHandle,
+           DidValue, // submitter_did
+           DidValue, // target_did
+           GetCacheOptions, // options
+           Box<dyn Fn(IndyResult<String>) + Send>),
+    GetNymContinue(
+        WalletHandle,
+        IndyResult<(String, String)>, // ledger_response
+        GetCacheOptions,              // options
+        CommandHandle,                          // cb_id
+    ),
+    GetRevocRegDef(PoolHandle,
+                   WalletHandle,
+                   DidValue, // submitter_did
+                   RevocationRegistryId, // id
+                   GetCacheOptions, // options
+                   Box<dyn Fn(IndyResult<String>) + Send>),
+    GetRevocRegDefContinue(
+        WalletHandle,
+        IndyResult<(String, String)>, // ledger_response
+        GetCacheOptions,              // options
+        CommandHandle,                          // cb_id
+    ),
+    GetRevocRegDelta(PoolHandle,
+                     WalletHandle,
+                     DidValue, // submitter_did
+                     RevocationRegistryId, // revocation registry definition id
+                     Option<i64>, // from
+                     i64, // to
+                     GetCacheOptions, // options
+                     Box<dyn Fn(IndyResult<(String, String, u64)>) + Send>),
+    GetRevocRegDeltaContinue(
+        WalletHandle,
+        IndyResult<(String, String)>, // ledger_response
+        GetCacheOptions,              // options
+        CommandHandle,                          // cb_id
+    ),
+    GetTxnAuthorAgreement(PoolHandle,
+                          WalletHandle,
+                          DidValue, // submitter_did
+                          Option<GetTxnAuthorAgreementData>, // data
+                          GetCacheOptions, // options
+                          Box<dyn Fn(IndyResult<String>) + Send>),
+    GetTxnAuthorAgreementContinue(
+        WalletHandle,
+        IndyResult<(String, String)>, // ledger_response
+        GetCacheOptions,              // options
+        CommandHandle,                          // cb_id
+    ),
     PurgeSchemaCache(WalletHandle,
                      PurgeOptions, // options
                      Box<dyn Fn(IndyResult<()>) + Send>),
     PurgeCredDefCache(WalletHandle,
                       PurgeOptions, // options
                       Box<dyn Fn(IndyResult<()>) + Send>),
+    PurgeNymCache(WalletHandle,
+                  PurgeOptions, // options
+                  Box<dyn Fn(IndyResult<()>) + Send>),
+    PurgeRevocRegDefCache(WalletHandle,
+                          PurgeOptions, // options
+                          Box<dyn Fn(IndyResult<()>) + Send>),
+    PurgeRevocRegDeltaCache(WalletHandle,
+                            PurgeOptions, // options
+                            Box<dyn Fn(IndyResult<()>) + Send>),
+    PurgeTxnAuthorAgreementCache(WalletHandle,
+                                 PurgeOptions, // options
+                                 Box<dyn Fn(IndyResult<()>) + Send>),
 }
 
 pub struct CacheCommandExecutor {
@@ -105,13 +173,61 @@ impl CacheCommandExecutor {
                 debug!(target: "non_secrets_command_executor", "GetCredDefContinue command received");
                 self._get_cred_def_continue(wallet_handle, ledger_response, options, cb_id);
             }
+            CacheCommand::GetNym(pool_handle, wallet_handle, submitter_did, target_did, options, cb) => {
+                debug!(target: "non_secrets_command_executor", "GetNym command received");
+                self.get_nym(pool_handle, wallet_handle, &submitter_did, &target_did, options, cb);
+            }
+            CacheCommand::GetNymContinue(wallet_handle, ledger_response, options, cb_id) => {
+                debug!(target: "non_secrets_command_executor", "GetNymContinue command received");
+                self._get_continue(wallet_handle, ledger_response, options, cb_id, NYM_CACHE);
+            }
+            CacheCommand::GetRevocRegDef(pool_handle, wallet_handle, submitter_did, id, options, cb) => {
+                debug!(target: "non_secrets_command_executor", "GetRevocRegDef command received");
+                self.get_revoc_reg_def(pool_handle, wallet_handle, &submitter_did, &id, options, cb);
+            }
+            CacheCommand::GetRevocRegDefContinue(wallet_handle, ledger_response, options, cb_id) => {
+                debug!(target: "non_secrets_command_executor", "GetRevocRegDefContinue command received");
+                self._get_continue(wallet_handle, ledger_response, options, cb_id, REVOC_REG_DEF_CACHE);
+            }
+            CacheCommand::GetRevocRegDelta(pool_handle, wallet_handle, submitter_did, id, from, to, options, cb) => {
+                debug!(target: "non_secrets_command_executor", "GetRevocRegDelta command received");
+                self.get_revoc_reg_delta(pool_handle, wallet_handle, &submitter_did, &id, from, to, options, cb);
+            }
+            CacheCommand::GetRevocRegDeltaContinue(wallet_handle, ledger_response, options, cb_id) => {
+                debug!(target: "non_secrets_command_executor", "GetRevocRegDeltaContinue command received");
+                self._get_continue(wallet_handle, ledger_response, options, cb_id, REVOC_REG_DELTA_CACHE);
+            }
+            CacheCommand::GetTxnAuthorAgreement(pool_handle, wallet_handle, submitter_did, data, options, cb) => {
+                debug!(target: "non_secrets_command_executor", "GetTxnAuthorAgreement command received");
+                self.get_txn_author_agreement(pool_handle, wallet_handle, &submitter_did, data, options, cb);
+            }
+            CacheCommand::GetTxnAuthorAgreementContinue(wallet_handle, ledger_response, options, cb_id) => {
+                debug!(target: "non_secrets_command_executor", "GetTxnAuthorAgreementContinue command received");
+                self._get_continue(wallet_handle, ledger_response, options, cb_id, TXN_AUTHOR_AGREEMENT_CACHE);
+            }
             CacheCommand::PurgeSchemaCache(wallet_handle, options, cb) => {
                 debug!(target: "non_secrets_command_executor", "PurgeSchemaCache command received");
-                cb(self.purge_schema_cache(wallet_handle, options));
+                cb(self.purge_cache(wallet_handle, options, SCHEMA_CACHE));
             }
             CacheCommand::PurgeCredDefCache(wallet_handle, options, cb) => {
                 debug!(target: "non_secrets_command_executor", "PurgeCredDefCache command received");
-                cb(self.purge_cred_def_cache(wallet_handle, options));
+                cb(self.purge_cache(wallet_handle, options, CRED_DEF_CACHE));
+            }
+            CacheCommand::PurgeNymCache(wallet_handle, options, cb) => {
+                debug!(target: "non_secrets_command_executor", "PurgeNymCache command received");
+                cb(self.purge_cache(wallet_handle, options, NYM_CACHE));
+            }
+            CacheCommand::PurgeRevocRegDefCache(wallet_handle, options, cb) => {
+                debug!(target: "non_secrets_command_executor", "PurgeRevocRegDefCache command received");
+                cb(self.purge_cache(wallet_handle, options, REVOC_REG_DEF_CACHE));
+            }
+            CacheCommand::PurgeRevocRegDeltaCache(wallet_handle, options, cb) => {
+                debug!(target: "non_secrets_command_executor", "PurgeRevocRegDeltaCache command received");
+                cb(self.purge_cache(wallet_handle, options, REVOC_REG_DELTA_CACHE));
+            }
+            CacheCommand::PurgeTxnAuthorAgreementCache(wallet_handle, options, cb) => {
+                debug!(target: "non_secrets_command_executor", "PurgeTxnAuthorAgreementCache command received");
+                cb(self.purge_cache(wallet_handle, options, TXN_AUTHOR_AGREEMENT_CACHE));
             }
         }
     }
@@ -268,6 +384,254 @@ impl CacheCommandExecutor {
         }
     }
 
+    fn get_nym(&self,
+               pool_handle: PoolHandle,
+               wallet_handle: WalletHandle,
+               submitter_did: &DidValue,
+               target_did: &DidValue,
+               options: GetCacheOptions,
+               cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        trace!("get_nym >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, target_did: {:?}, options: {:?}",
+               pool_handle, wallet_handle, submitter_did, target_did, options);
+
+        let cache = self.get_record_from_cache(wallet_handle, &target_did.0, &options, NYM_CACHE);
+        let cache = try_cb!(cache, cb);
+
+        check_cache!(cache, options, cb);
+
+        if options.no_update.unwrap_or(false) {
+            return cb(Err(IndyError::from(IndyErrorKind::LedgerItemNotFound)));
+        }
+
+        let cb_id = next_command_handle();
+        self.pending_callbacks.borrow_mut().insert(cb_id, cb);
+
+        let cache_id = target_did.0.clone();
+
+        CommandExecutor::instance().send(
+            Command::Ledger(
+                LedgerCommand::GetNym(
+                    pool_handle,
+                    Some(submitter_did.clone()),
+                    target_did.clone(),
+                    Box::new(move |ledger_response| {
+                        CommandExecutor::instance().send(
+                            Command::Cache(
+                                CacheCommand::GetNymContinue(
+                                    wallet_handle,
+                                    ledger_response.map(|nym_json| (cache_id.clone(), nym_json)),
+                                    options.clone(),
+                                    cb_id,
+                                )
+                            )
+                        ).unwrap();
+                    })
+                )
+            )
+        ).unwrap();
+    }
+
+    fn get_revoc_reg_def(&self,
+                         pool_handle: PoolHandle,
+                         wallet_handle: WalletHandle,
+                         submitter_did: &DidValue,
+                         id: &RevocationRegistryId,
+                         options: GetCacheOptions,
+                         cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        trace!("get_revoc_reg_def >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, id: {:?}, options: {:?}",
+               pool_handle, wallet_handle, submitter_did, id, options);
+
+        let cache = self.get_record_from_cache(wallet_handle, &id.0, &options, REVOC_REG_DEF_CACHE);
+        let cache = try_cb!(cache, cb);
+
+        // Revocation registry definition never changes on the ledger, so any cached entry is fresh.
+        let cache_options = GetCacheOptions { min_fresh: None, ..options.clone() };
+        check_cache!(cache, cache_options, cb);
+
+        if options.no_update.unwrap_or(false) {
+            return cb(Err(IndyError::from(IndyErrorKind::LedgerItemNotFound)));
+        }
+
+        let cb_id = next_command_handle();
+        self.pending_callbacks.borrow_mut().insert(cb_id, cb);
+
+        CommandExecutor::instance().send(
+            Command::Ledger(
+                LedgerCommand::GetRevocRegDef(
+                    pool_handle,
+                    Some(submitter_did.clone()),
+                    id.clone(),
+                    Box::new(move |ledger_response| {
+                        CommandExecutor::instance().send(
+                            Command::Cache(
+                                CacheCommand::GetRevocRegDefContinue(
+                                    wallet_handle,
+                                    ledger_response,
+                                    options.clone(),
+                                    cb_id,
+                                )
+                            )
+                        ).unwrap();
+                    })
+                )
+            )
+        ).unwrap();
+    }
+
+    fn get_revoc_reg_delta(&self,
+                           pool_handle: PoolHandle,
+                           wallet_handle: WalletHandle,
+                           submitter_did: &DidValue,
+                           id: &RevocationRegistryId,
+                           from: Option<i64>,
+                           to: i64,
+                           options: GetCacheOptions,
+                           cb: Box<dyn Fn(IndyResult<(String, String, u64)>) + Send>) {
+        trace!("get_revoc_reg_delta >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, id: {:?}, from: {:?}, to: {:?}, options: {:?}",
+               pool_handle, wallet_handle, submitter_did, id, from, to, options);
+
+        // Cached deltas are stored as CachedRevocRegDelta and unpacked on the way out.
+        let cb: Box<dyn Fn(IndyResult<String>) + Send> =
+            Box::new(move |res: IndyResult<String>| cb(res.and_then(|cached| CacheCommandExecutor::_unpack_revoc_reg_delta(&cached))));
+
+        // Delta is cached per registry and `from`. The cached delta is also the answer for any `to`
+        // between its accumulator timestamp and the `to` it has been requested with.
+        let cache_id = format!("{}:{}", id.0, from.unwrap_or(-1));
+
+        let cache = self.get_record_from_cache(wallet_handle, &cache_id, &options, REVOC_REG_DELTA_CACHE);
+        let cache = try_cb!(cache, cb)
+            .filter(|cache| CacheCommandExecutor::_revoc_reg_delta_covers(cache, to));
+
+        check_cache!(cache, options, cb);
+
+        if options.no_update.unwrap_or(false) {
+            return cb(Err(IndyError::from(IndyErrorKind::LedgerItemNotFound)));
+        }
+
+        let cb_id = next_command_handle();
+        self.pending_callbacks.borrow_mut().insert(cb_id, cb);
+
+        CommandExecutor::instance().send(
+            Command::Ledger(
+                LedgerCommand::GetRevocRegDelta(
+                    pool_handle,
+                    Some(submitter_did.clone()),
+                    id.clone(),
+                    from,
+                    to,
+                    Box::new(move |ledger_response| {
+                        let ledger_response = ledger_response
+                            .and_then(|(rev_reg_def_id, rev_reg_delta, timestamp)|
+                                CacheCommandExecutor::_pack_revoc_reg_delta(rev_reg_def_id, rev_reg_delta, timestamp, to))
+                            .map(|cached| (cache_id.clone(), cached));
+
+                        CommandExecutor::instance().send(
+                            Command::Cache(
+                                CacheCommand::GetRevocRegDeltaContinue(
+                                    wallet_handle,
+                                    ledger_response,
+                                    options.clone(),
+                                    cb_id,
+                                )
+                            )
+                        ).unwrap();
+                    })
+                )
+            )
+        ).unwrap();
+    }
+
+    fn get_txn_author_agreement(&self,
+                                pool_handle: PoolHandle,
+                                wallet_handle: WalletHandle,
+                                submitter_did: &DidValue,
+                                data: Option<GetTxnAuthorAgreementData>,
+                                options: GetCacheOptions,
+                                cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        trace!("get_txn_author_agreement >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, data: {:?}, options: {:?}",
+               pool_handle, wallet_handle, submitter_did, data, options);
+
+        let cache_id = CacheCommandExecutor::_txn_author_agreement_cache_id(data.as_ref());
+
+        let cache = self.get_record_from_cache(wallet_handle, &cache_id, &options, TXN_AUTHOR_AGREEMENT_CACHE);
+        let cache = try_cb!(cache, cb);
+
+        check_cache!(cache, options, cb);
+
+        if options.no_update.unwrap_or(false) {
+            return cb(Err(IndyError::from(IndyErrorKind::LedgerItemNotFound)));
+        }
+
+        let cb_id = next_command_handle();
+        self.pending_callbacks.borrow_mut().insert(cb_id, cb);
+
+        CommandExecutor::instance().send(
+            Command::Ledger(
+                LedgerCommand::GetTxnAuthorAgreement(
+                    pool_handle,
+                    Some(submitter_did.clone()),
+                    data,
+                    Box::new(move |ledger_response| {
+                        CommandExecutor::instance().send(
+                            Command::Cache(
+                                CacheCommand::GetTxnAuthorAgreementContinue(
+                                    wallet_handle,
+                                    ledger_response.map(|taa_json| (cache_id.clone(), taa_json)),
+                                    options.clone(),
+                                    cb_id,
+                                )
+                            )
+                        ).unwrap();
+                    })
+                )
+            )
+        ).unwrap();
+    }
+
+    fn _get_continue(&self,
+                     wallet_handle: WalletHandle,
+                     ledger_response: IndyResult<(String, String)>,
+                     options: GetCacheOptions,
+                     cb_id: CommandHandle,
+                     which_cache: &str) {
+        let cb = self.pending_callbacks.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+
+        let (id, json) = try_cb!(ledger_response, cb);
+
+        match self._delete_and_add_record(wallet_handle, options, &id, &json, which_cache) {
+            Ok(_) => cb(Ok(json)),
+            Err(err) => cb(Err(IndyError::from_msg(IndyErrorKind::InvalidState, format!("Cannot store ledger data in {}: {:?}", which_cache, err))))
+        }
+    }
+
+    fn _txn_author_agreement_cache_id(data: Option<&GetTxnAuthorAgreementData>) -> String {
+        match data {
+            Some(GetTxnAuthorAgreementData { digest: Some(digest), .. }) => format!("digest:{}", digest),
+            Some(GetTxnAuthorAgreementData { version: Some(version), .. }) => format!("version:{}", version),
+            Some(GetTxnAuthorAgreementData { timestamp: Some(timestamp), .. }) => format!("timestamp:{}", timestamp),
+            _ => "latest".to_string()
+        }
+    }
+
+    fn _pack_revoc_reg_delta(rev_reg_def_id: String, rev_reg_delta: String, timestamp: u64, to: i64) -> IndyResult<String> {
+        serde_json::to_string(&CachedRevocRegDelta { rev_reg_def_id, rev_reg_delta, timestamp, to })
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize CachedRevocRegDelta")
+    }
+
+    fn _revoc_reg_delta_covers(cache: &WalletRecord, to: i64) -> bool {
+        cache.get_value()
+            .and_then(|cached| serde_json::from_str::<CachedRevocRegDelta>(cached).ok())
+            .map(|cached| cached.timestamp as i64 <= to && to <= cached.to)
+            .unwrap_or(false)
+    }
+
+    fn _unpack_revoc_reg_delta(cached: &str) -> IndyResult<(String, String, u64)> {
+        let cached: CachedRevocRegDelta = serde_json::from_str(cached)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize CachedRevocRegDelta")?;
+
+        Ok((cached.rev_reg_def_id, cached.rev_reg_delta, cached.timestamp))
+    }
+
     fn get_seconds_since_epoch() -> Result<i32, IndyError> {
         match SystemTime::now().duration_since(UNIX_EPOCH) {
             Ok(ts) => Ok(ts.as_secs() as i32),
@@ -287,40 +651,11 @@ impl CacheCommandExecutor {
         }
     }
 
-    fn purge_schema_cache(&self,
-                          wallet_handle: WalletHandle,
-                          options: PurgeOptions) -> IndyResult<()> {
-        trace!("purge_schema_cache >>> wallet_handle: {:?}, options: {:?}", wallet_handle, options);
-
-        let max_age = options.max_age.unwrap_or(-1);
-        let query_json = CacheCommandExecutor::build_query_json(max_age)?;
-
-        let options_json = json!({
-            "retrieveType": false,
-            "retrieveValue": false,
-            "retrieveTags": false,
-        }).to_string();
-
-        let mut search = self.wallet_service.search_records(
-            wallet_handle,
-            SCHEMA_CACHE,
-            &query_json,
-            &options_json,
-        )?;
-
-        while let Some(record) = search.fetch_next_record()? {
-            self.wallet_service.delete_record(wallet_handle, SCHEMA_CACHE, record.get_id())?;
-        }
-
-        trace!("purge_schema_cache <<< res: ()");
-
-        Ok(())
-    }
-
-    fn purge_cred_def_cache(&self,
-                            wallet_handle: WalletHandle,
-                            options: PurgeOptions) -> IndyResult<()> {
-        trace!("purge_cred_def_cache >>> wallet_handle: {:?}, options: {:?}", wallet_handle, options);
+    fn purge_cache(&self,
+                   wallet_handle: WalletHandle,
+                   options: PurgeOptions,
+                   which_cache: &str) -> IndyResult<()> {
+        trace!("purge_cache >>> wallet_handle: {:?}, options: {:?}, which_cache: {:?}", wallet_handle, options, which_cache);
 
         let max_age = options.max_age.unwrap_or(-1);
         let query_json = CacheCommandExecutor::build_query_json(max_age)?;
@@ -333,16 +668,16 @@ impl CacheCommandExecutor {
 
         let mut search = self.wallet_service.search_records(
             wallet_handle,
-            CRED_DEF_CACHE,
+            which_cache,
             &query_json,
             &options_json,
         )?;
 
         while let Some(record) = search.fetch_next_record()? {
-            self.wallet_service.delete_record(wallet_handle, CRED_DEF_CACHE, record.get_id())?;
+            self.wallet_service.delete_record(wallet_handle, which_cache, record.get_id())?;
         }
 
-        trace!("purge_cred_def_cache <<< res: ()");
+        trace!("purge_cache <<< res: ()");
 
         Ok(())
     }
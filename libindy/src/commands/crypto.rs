@@ -3,6 +3,8 @@ use std::collections::HashMap;
 use crate::domain::crypto::key::{Key, KeyInfo, KeyMetadata};
 use crate::domain::crypto::pack::*;
 use indy_api_types::errors::prelude::*;
+use crate::domain::audit::{sha256_hex, AuditEvent, AuditEventType};
+use crate::services::audit::AuditService;
 use crate::services::crypto::CryptoService;
 use indy_wallet::{RecordOptions, WalletService};
 
@@ -88,16 +90,19 @@ pub enum CryptoCommand {
 pub struct CryptoCommandExecutor {
     wallet_service: Rc<WalletService>,
     crypto_service: Rc<CryptoService>,
+    audit_service: Rc<AuditService>,
 }
 
 impl CryptoCommandExecutor {
     pub fn new(
         wallet_service: Rc<WalletService>,
         crypto_service: Rc<CryptoService>,
+        audit_service: Rc<AuditService>,
     ) -> CryptoCommandExecutor {
         CryptoCommandExecutor {
             wallet_service,
             crypto_service,
+            audit_service,
         }
     }
 
@@ -158,9 +163,12 @@ impl CryptoCommandExecutor {
         );
 
         let key = self.crypto_service.create_key(key_info)?;
+
         self.wallet_service
             .add_indy_object(wallet_handle, &key.verkey, &key, &HashMap::new())?;
 
+        self.audit_service.record(wallet_handle, AuditEvent::new(AuditEventType::KeyCreated).verkey(&key.verkey));
+
         let res = key.verkey.to_string();
         debug!("create_key <<< res: {:?}", res);
         Ok(res)
@@ -182,6 +190,10 @@ impl CryptoCommandExecutor {
 
         let res = self.crypto_service.sign(&key, msg)?;
 
+        self.audit_service.record(wallet_handle, AuditEvent::new(AuditEventType::MessageSigned)
+            .verkey(my_vk)
+            .detail("message_hash", &sha256_hex(msg)));
+
         trace!("crypto_sign <<< res: {:?}", res);
 
         Ok(res)
@@ -13,6 +13,8 @@ use crate::domain::ledger::nym::{GetNymReplyResult, GetNymResultDataV0};
 use crate::domain::ledger::response::Reply;
 use crate::domain::pairwise::Pairwise;
 use indy_api_types::errors::prelude::*;
+use crate::domain::audit::{AuditEvent, AuditEventType};
+use crate::services::audit::AuditService;
 use crate::services::crypto::CryptoService;
 use crate::services::ledger::LedgerService;
 use indy_wallet::{RecordOptions, SearchOptions, WalletService};
@@ -113,17 +115,20 @@ pub struct DidCommandExecutor {
     wallet_service: Rc<WalletService>,
     crypto_service: Rc<CryptoService>,
     ledger_service: Rc<LedgerService>,
+    audit_service: Rc<AuditService>,
     deferred_commands: RefCell<HashMap<CommandHandle, DidCommand>>,
 }
 
 impl DidCommandExecutor {
     pub fn new(wallet_service: Rc<WalletService>,
                crypto_service: Rc<CryptoService>,
-               ledger_service: Rc<LedgerService>) -> DidCommandExecutor {
+               ledger_service: Rc<LedgerService>,
+               audit_service: Rc<AuditService>) -> DidCommandExecutor {
         DidCommandExecutor {
             wallet_service,
             crypto_service,
             ledger_service,
+            audit_service,
             deferred_commands: RefCell::new(HashMap::new()),
         }
     }
@@ -216,6 +221,8 @@ impl DidCommandExecutor {
         self.wallet_service.add_indy_object(wallet_handle, &did.did.0, &did, &HashMap::new())?;
         let _ = self.wallet_service.add_indy_object(wallet_handle, &key.verkey, &key, &HashMap::new()).ok();
 
+        self.audit_service.record(wallet_handle, AuditEvent::new(AuditEventType::DidCreated).did(&did.did.0).verkey(&did.verkey));
+
         let res = (did.did.0, did.verkey);
 
         debug!("create_and_store_my_did <<< res: {:?}", res);
@@ -239,6 +246,10 @@ impl DidCommandExecutor {
         self.wallet_service.add_indy_object(wallet_handle, &temporary_key.verkey, &temporary_key, &HashMap::new())?;
         self.wallet_service.add_indy_object(wallet_handle, &my_temporary_did.did.0, &my_temporary_did, &HashMap::new())?;
 
+        self.audit_service.record(wallet_handle, AuditEvent::new(AuditEventType::KeysRotationStarted)
+            .did(&my_temporary_did.did.0)
+            .verkey(&my_temporary_did.verkey));
+
         let res = my_temporary_did.verkey;
 
         debug!("replace_keys_start <<< res: {:?}", res);
@@ -257,11 +268,17 @@ impl DidCommandExecutor {
         let my_temporary_did: TemporaryDid =
             self.wallet_service.get_indy_object(wallet_handle, &my_did.did.0, &RecordOptions::id_value())?;
 
+        let previous_verkey = my_did.verkey;
         let my_did = Did::from(my_temporary_did);
 
         self.wallet_service.update_indy_object(wallet_handle, &my_did.did.0, &my_did)?;
         self.wallet_service.delete_indy_record::<TemporaryDid>(wallet_handle, &my_did.did.0)?;
 
+        self.audit_service.record(wallet_handle, AuditEvent::new(AuditEventType::KeysRotated)
+            .did(&my_did.did.0)
+            .verkey(&my_did.verkey)
+            .detail("previous_verkey", &previous_verkey));
+
         debug!("replace_keys_apply <<<");
 
         Ok(())
@@ -6,6 +6,7 @@ use std::string::ToString;
 use indy_api_types::{CommandHandle, PoolHandle, WalletHandle};
 use indy_api_types::errors::prelude::*;
 use indy_utils::next_command_handle;
+use indy_utils::tracer;
 use rust_base58::ToBase58;
 use serde_json;
 use serde_json::Value;
@@ -25,6 +26,8 @@ use crate::domain::ledger::author_agreement::{AcceptanceMechanisms, GetTxnAuthor
 use crate::domain::ledger::node::NodeOperationData;
 use crate::domain::ledger::pool::Schedule;
 use crate::domain::ledger::request::Request;
+use crate::domain::audit::{sha256_hex, AuditEvent, AuditEventType};
+use crate::services::audit::AuditService;
 use crate::services::crypto::CryptoService;
 use crate::services::ledger::LedgerService;
 use crate::services::pool::{
@@ -239,6 +242,48 @@ pub enum LedgerCommand {
         IndyResult<String>,
         CommandHandle,
     ),
+    GetNym(
+        PoolHandle,
+        Option<DidValue>,
+        DidValue, // target did
+        Box<dyn Fn(IndyResult<String>) + Send>,
+    ),
+    GetNymContinue(
+        IndyResult<String>,
+        CommandHandle,
+    ),
+    GetRevocRegDef(
+        PoolHandle,
+        Option<DidValue>,
+        RevocationRegistryId,
+        BoxedCallbackStringStringSend,
+    ),
+    GetRevocRegDefContinue(
+        IndyResult<String>,
+        CommandHandle,
+    ),
+    GetRevocRegDelta(
+        PoolHandle,
+        Option<DidValue>,
+        RevocationRegistryId,
+        Option<i64>, // from
+        i64, // to
+        Box<dyn Fn(IndyResult<(String, String, u64)>) + Send>,
+    ),
+    GetRevocRegDeltaContinue(
+        IndyResult<String>,
+        CommandHandle,
+    ),
+    GetTxnAuthorAgreement(
+        PoolHandle,
+        Option<DidValue>,
+        Option<GetTxnAuthorAgreementData>,
+        Box<dyn Fn(IndyResult<String>) + Send>,
+    ),
+    GetTxnAuthorAgreementContinue(
+        IndyResult<String>,
+        CommandHandle,
+    ),
     BuildTxnAuthorAgreementRequest(
         DidValue, // submitter did
         Option<String>, // text
@@ -290,23 +335,30 @@ pub struct LedgerCommandExecutor {
     crypto_service: Rc<CryptoService>,
     wallet_service: Rc<WalletService>,
     ledger_service: Rc<LedgerService>,
+    audit_service: Rc<AuditService>,
 
     send_callbacks: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<String>)>>>,
     pending_callbacks: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<(String, String)>)>>>,
+    pending_str_callbacks: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<String>)>>>,
+    pending_delta_callbacks: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<(String, String, u64)>)>>>,
 }
 
 impl LedgerCommandExecutor {
     pub fn new(pool_service: Rc<PoolService>,
                crypto_service: Rc<CryptoService>,
                wallet_service: Rc<WalletService>,
-               ledger_service: Rc<LedgerService>) -> LedgerCommandExecutor {
+               ledger_service: Rc<LedgerService>,
+               audit_service: Rc<AuditService>) -> LedgerCommandExecutor {
         LedgerCommandExecutor {
             pool_service,
             crypto_service,
             wallet_service,
             ledger_service,
+            audit_service,
             send_callbacks: RefCell::new(HashMap::new()),
             pending_callbacks: RefCell::new(HashMap::new()),
+            pending_str_callbacks: RefCell::new(HashMap::new()),
+            pending_delta_callbacks: RefCell::new(HashMap::new()),
         }
     }
 
@@ -322,6 +374,8 @@ impl LedgerCommandExecutor {
             }
             LedgerCommand::SubmitAck(handle, result) => {
                 debug!(target: "ledger_command_executor", "SubmitAck command received");
+                // The pool request may complete without taking the span it has been handed over
+                tracer::take_handed_over(handle);
                 match self.send_callbacks.borrow_mut().remove(&handle) {
                     Some(cb) => cb(result.map_err(IndyError::from)),
                     None => {
@@ -499,6 +553,38 @@ impl LedgerCommandExecutor {
                 debug!(target: "ledger_command_executor", "GetCredDefContinue command received");
                 self._get_cred_def_continue(id, pool_response, cb_id);
             }
+            LedgerCommand::GetNym(pool_handle, submitter_did, target_did, cb) => {
+                debug!(target: "ledger_command_executor", "GetNym command received");
+                self.get_nym(pool_handle, submitter_did.as_ref(), &target_did, cb);
+            }
+            LedgerCommand::GetNymContinue(pool_response, cb_id) => {
+                debug!(target: "ledger_command_executor", "GetNymContinue command received");
+                self._get_nym_continue(pool_response, cb_id);
+            }
+            LedgerCommand::GetRevocRegDef(pool_handle, submitter_did, id, cb) => {
+                debug!(target: "ledger_command_executor", "GetRevocRegDef command received");
+                self.get_revoc_reg_def(pool_handle, submitter_did.as_ref(), &id, cb);
+            }
+            LedgerCommand::GetRevocRegDefContinue(pool_response, cb_id) => {
+                debug!(target: "ledger_command_executor", "GetRevocRegDefContinue command received");
+                self._get_revoc_reg_def_continue(pool_response, cb_id);
+            }
+            LedgerCommand::GetRevocRegDelta(pool_handle, submitter_did, id, from, to, cb) => {
+                debug!(target: "ledger_command_executor", "GetRevocRegDelta command received");
+                self.get_revoc_reg_delta(pool_handle, submitter_did.as_ref(), &id, from, to, cb);
+            }
+            LedgerCommand::GetRevocRegDeltaContinue(pool_response, cb_id) => {
+                debug!(target: "ledger_command_executor", "GetRevocRegDeltaContinue command received");
+                self._get_revoc_reg_delta_continue(pool_response, cb_id);
+            }
+            LedgerCommand::GetTxnAuthorAgreement(pool_handle, submitter_did, data, cb) => {
+                debug!(target: "ledger_command_executor", "GetTxnAuthorAgreement command received");
+                self.get_txn_author_agreement(pool_handle, submitter_did.as_ref(), data.as_ref(), cb);
+            }
+            LedgerCommand::GetTxnAuthorAgreementContinue(pool_response, cb_id) => {
+                debug!(target: "ledger_command_executor", "GetTxnAuthorAgreementContinue command received");
+                self._get_txn_author_agreement_continue(pool_response, cb_id);
+            }
             LedgerCommand::BuildTxnAuthorAgreementRequest(submitter_did, text, version, ratification_ts, retirement_ts, cb) => {
                 debug!(target: "ledger_command_executor", "BuildTxnAuthorAgreementRequest command received");
                 cb(self.build_txn_author_agreement_request(&submitter_did, text.as_ref().map(String::as_str), &version, ratification_ts, retirement_ts));
@@ -592,6 +678,20 @@ impl LedgerCommandExecutor {
         let signature = self.crypto_service.sign(&my_key, &serialized_request.as_bytes().to_vec())?;
         let did = my_did.did.to_short();
 
+        let mut event = AuditEvent::new(AuditEventType::RequestSigned)
+            .did(&my_did.did.0)
+            .verkey(&my_did.verkey)
+            .detail("request_hash", &sha256_hex(serialized_request.as_bytes()))
+            .detail("signature_type", match signature_type { SignatureType::Single => "single", SignatureType::Multi => "multi" });
+
+        if let Some(req_id) = request.get("reqId").and_then(Value::as_u64) {
+            event = event.detail("req_id", &req_id.to_string());
+        }
+
+        if let Some(txn_type) = request["operation"]["type"].as_str() {
+            event = event.detail("txn_type", txn_type);
+        }
+
         match signature_type {
             SignatureType::Single => { request["signature"] = Value::String(signature.to_base58()); }
             SignatureType::Multi => {
@@ -613,6 +713,8 @@ impl LedgerCommandExecutor {
         let res: String = serde_json::to_string(&request)
             .to_indy(IndyErrorKind::InvalidState, "Can't serialize message after signing")?;
 
+        self.audit_service.record(wallet_handle, event);
+
         debug!("_sign_request <<< res: {:?}", res);
 
         Ok(res)
@@ -1324,6 +1426,104 @@ impl LedgerCommandExecutor {
         cb(self.ledger_service.parse_get_cred_def_response(&pool_response, id.get_method().as_ref().map(String::as_str)))
     }
 
+    fn get_nym(&self, pool_handle: i32, submitter_did: Option<&DidValue>, target_did: &DidValue, cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        let request_json = try_cb!(self.build_get_nym_request(submitter_did, target_did), cb);
+
+        let cb_id = next_command_handle();
+        self.pending_str_callbacks.borrow_mut().insert(cb_id, cb);
+
+        self.submit_request(pool_handle, &request_json, Box::new(move |response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::GetNymContinue(
+                        response,
+                        cb_id
+                    )
+                )
+            ).unwrap();
+        }));
+    }
+
+    fn _get_nym_continue(&self, pool_response: IndyResult<String>, cb_id: CommandHandle) {
+        let cb = self.pending_str_callbacks.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+        let pool_response = try_cb!(pool_response, cb);
+        cb(self.ledger_service.parse_get_nym_response(&pool_response))
+    }
+
+    fn get_revoc_reg_def(&self, pool_handle: i32, submitter_did: Option<&DidValue>, id: &RevocationRegistryId, cb: BoxedCallbackStringStringSend) {
+        let request_json = try_cb!(self.build_get_revoc_reg_def_request(submitter_did, id), cb);
+
+        let cb_id = next_command_handle();
+        self.pending_callbacks.borrow_mut().insert(cb_id, cb);
+
+        self.submit_request(pool_handle, &request_json, Box::new(move |response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::GetRevocRegDefContinue(
+                        response,
+                        cb_id
+                    )
+                )
+            ).unwrap();
+        }));
+    }
+
+    fn _get_revoc_reg_def_continue(&self, pool_response: IndyResult<String>, cb_id: CommandHandle) {
+        let cb = self.pending_callbacks.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+        let pool_response = try_cb!(pool_response, cb);
+        cb(self.ledger_service.parse_get_revoc_reg_def_response(&pool_response))
+    }
+
+    fn get_revoc_reg_delta(&self, pool_handle: i32, submitter_did: Option<&DidValue>, id: &RevocationRegistryId, from: Option<i64>, to: i64,
+                           cb: Box<dyn Fn(IndyResult<(String, String, u64)>) + Send>) {
+        let request_json = try_cb!(self.build_get_revoc_reg_delta_request(submitter_did, id, from, to), cb);
+
+        let cb_id = next_command_handle();
+        self.pending_delta_callbacks.borrow_mut().insert(cb_id, cb);
+
+        self.submit_request(pool_handle, &request_json, Box::new(move |response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::GetRevocRegDeltaContinue(
+                        response,
+                        cb_id
+                    )
+                )
+            ).unwrap();
+        }));
+    }
+
+    fn _get_revoc_reg_delta_continue(&self, pool_response: IndyResult<String>, cb_id: CommandHandle) {
+        let cb = self.pending_delta_callbacks.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+        let pool_response = try_cb!(pool_response, cb);
+        cb(self.ledger_service.parse_get_revoc_reg_delta_response(&pool_response))
+    }
+
+    fn get_txn_author_agreement(&self, pool_handle: i32, submitter_did: Option<&DidValue>, data: Option<&GetTxnAuthorAgreementData>,
+                                cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        let request_json = try_cb!(self.build_get_txn_author_agreement_request(submitter_did, data), cb);
+
+        let cb_id = next_command_handle();
+        self.pending_str_callbacks.borrow_mut().insert(cb_id, cb);
+
+        self.submit_request(pool_handle, &request_json, Box::new(move |response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::GetTxnAuthorAgreementContinue(
+                        response,
+                        cb_id
+                    )
+                )
+            ).unwrap();
+        }));
+    }
+
+    fn _get_txn_author_agreement_continue(&self, pool_response: IndyResult<String>, cb_id: CommandHandle) {
+        let cb = self.pending_str_callbacks.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+        let pool_response = try_cb!(pool_response, cb);
+        cb(self.ledger_service.parse_get_txn_author_agreement_response(&pool_response))
+    }
+
     fn build_ledgers_freeze_request(&self, submitter_did: &DidValue, ledgers_ids: Vec<u64>) -> IndyResult<String>{
         debug!("build_ledgers_freeze_request >>> submitter_did: {:?}, ledgers_ids: {:?}", submitter_did, ledgers_ids);
 
@@ -1,4 +1,6 @@
+use crate::domain::metrics::{CollectMetricsOptions, MetricsFormat};
 use crate::services::metrics::models::MetricsValue;
+use crate::services::metrics::openmetrics;
 use crate::services::metrics::MetricsService;
 use indy_api_types::errors::prelude::*;
 use indy_wallet::WalletService;
@@ -16,7 +18,9 @@ const PENDING_FOR_IMPORT_WALLETS_COUNT: &str = "pending_for_import";
 const PENDING_FOR_OPEN_WALLETS_COUNT: &str = "pending_for_open";
 
 pub enum MetricsCommand {
-    CollectMetrics(Box<dyn Fn(IndyResult<String>) + Send>),
+    CollectMetrics(
+        CollectMetricsOptions, // options
+        Box<dyn Fn(IndyResult<String>) + Send>),
 }
 
 pub struct MetricsCommandExecutor {
@@ -37,22 +41,37 @@ impl MetricsCommandExecutor {
 
     pub fn execute(&self, command: MetricsCommand) {
         match command {
-            MetricsCommand::CollectMetrics(cb) => {
+            MetricsCommand::CollectMetrics(options, cb) => {
                 debug!(target: "metrics_command_executor", "CollectMetrics command received");
-                cb(self.collect());
+                cb(self.collect(&options));
             }
         };
     }
 
-    fn collect(&self) -> IndyResult<String> {
-        trace!("_collect >>>");
+    fn collect(&self, options: &CollectMetricsOptions) -> IndyResult<String> {
+        trace!("_collect >>> options: {:?}", options);
         let mut metrics_map = serde_json::Map::new();
         self.append_threapool_metrics(&mut metrics_map)?;
         self.append_wallet_metrics(&mut metrics_map)?;
+        self.append_wallet_storage_metrics(&mut metrics_map)?;
         self.metrics_service
-            .append_command_metrics(&mut metrics_map)?;
-        let res = serde_json::to_string(&metrics_map)
-            .to_indy(IndyErrorKind::InvalidState, "Can't serialize a metrics map")?;
+            .append_pool_metrics(&mut metrics_map)?;
+
+        let res = match options.format {
+            MetricsFormat::Json => {
+                self.metrics_service
+                    .append_command_metrics(&mut metrics_map)?;
+                serde_json::to_string(&metrics_map)
+                    .to_indy(IndyErrorKind::InvalidState, "Can't serialize a metrics map")?
+            }
+            MetricsFormat::OpenMetrics => {
+                let mut res = String::new();
+                openmetrics::write_metrics_map(&mut res, &metrics_map);
+                self.metrics_service.write_command_openmetrics(&mut res);
+                openmetrics::write_eof(&mut res);
+                res
+            }
+        };
 
         trace!("_collect <<< res: {:?}", res);
         debug!("collecting metrics from command thread");
@@ -133,6 +152,43 @@ impl MetricsCommandExecutor {
         Ok(())
     }
 
+    fn append_wallet_storage_metrics(&self, metrics_map: &mut Map<String, Value>) -> IndyResult<()> {
+        let mut wallet_storage_ops_count = Vec::new();
+        let mut wallet_storage_op_errors_count = Vec::new();
+        let mut wallet_storage_ops_duration_ms = Vec::new();
+
+        for (wallet_id, operation, counters) in self.wallet_service.get_storage_counters() {
+            let mut tags = HashMap::<String, String>::new();
+            tags.insert(String::from("wallet"), wallet_id);
+            tags.insert(String::from("operation"), String::from(operation));
+
+            let to_json = |value: usize| serde_json::to_value(MetricsValue::new(value, tags.clone()))
+                .to_indy(IndyErrorKind::IOError, "Unable to convert json");
+
+            wallet_storage_ops_count.push(to_json(counters.count as usize)?);
+            wallet_storage_op_errors_count.push(to_json(counters.errors as usize)?);
+            wallet_storage_ops_duration_ms.push(to_json(counters.duration_ms_sum as usize)?);
+        }
+
+        metrics_map.insert(
+            String::from("wallet_storage_ops_count"),
+            serde_json::to_value(wallet_storage_ops_count)
+                .to_indy(IndyErrorKind::IOError, "Unable to convert json")?,
+        );
+        metrics_map.insert(
+            String::from("wallet_storage_op_errors_count"),
+            serde_json::to_value(wallet_storage_op_errors_count)
+                .to_indy(IndyErrorKind::IOError, "Unable to convert json")?,
+        );
+        metrics_map.insert(
+            String::from("wallet_storage_ops_duration_ms"),
+            serde_json::to_value(wallet_storage_ops_duration_ms)
+                .to_indy(IndyErrorKind::IOError, "Unable to convert json")?,
+        );
+
+        Ok(())
+    }
+
     fn get_metric_json(&self, label: &str, value: usize) -> IndyResult<Value> {
         let mut tag = HashMap::<String, String>::new();
         tag.insert(String::from("label"), String::from(label));
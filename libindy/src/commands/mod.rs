@@ -8,6 +8,7 @@ use std::sync::mpsc::{channel, Sender};
 use std::thread;
 
 use crate::commands::anoncreds::{AnoncredsCommand, AnoncredsCommandExecutor};
+use crate::commands::audit::{AuditCommand, AuditCommandExecutor};
 use crate::commands::blob_storage::{BlobStorageCommand, BlobStorageCommandExecutor};
 use crate::commands::crypto::{CryptoCommand, CryptoCommandExecutor};
 use crate::commands::did::{DidCommand, DidCommandExecutor};
@@ -20,8 +21,10 @@ use crate::commands::wallet::{WalletCommand, WalletCommandExecutor};
 use crate::commands::cache::{CacheCommand, CacheCommandExecutor};
 use crate::commands::metrics::{MetricsCommand, MetricsCommandExecutor};
 use crate::domain::IndyConfig;
+use indy_api_types::CommandHandle;
 use indy_api_types::errors::prelude::*;
 use crate::services::anoncreds::AnoncredsService;
+use crate::services::audit::AuditService;
 use crate::services::blob_storage::BlobStorageService;
 use crate::services::crypto::CryptoService;
 use crate::services::ledger::LedgerService;
@@ -30,11 +33,13 @@ use crate::services::pool::{PoolService, set_freshness_threshold};
 use crate::services::metrics::MetricsService;
 use crate::services::metrics::command_metrics::CommandMetric;
 use indy_wallet::WalletService;
+use indy_utils::tracer::{self, Span, SpanContext};
 
 use self::threadpool::ThreadPool;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 pub mod anoncreds;
+pub mod audit;
 pub mod blob_storage;
 pub mod crypto;
 pub mod ledger;
@@ -63,27 +68,66 @@ pub enum Command {
     Payments(PaymentsCommand),
     Cache(CacheCommand),
     Metrics(MetricsCommand),
+    Audit(AuditCommand),
 }
 
 pub struct InstrumentedCommand {
     pub enqueue_ts: u128,
-    pub command: Command
+    pub command: Command,
+    pub span_context: Option<SpanContext>,
+    // Span of the call without a command handle that sent the command. It is finished once the command is executed.
+    pub api_span: Option<Span>,
 }
 
 impl InstrumentedCommand {
-    pub fn new(command: Command) -> InstrumentedCommand {
+    pub fn new(command: Command, command_handle: Option<CommandHandle>) -> InstrumentedCommand {
+        // Commands sent while executing another command continue its trace,
+        // any other command is an API call and starts a new one.
+        let parent = if command_handle.is_none() { tracer::current() } else { None };
+
+        let (span_context, api_span) = match (&command, parent, command_handle) {
+            (Command::Exit, _, _) => (None, None),
+            (_, Some(parent), _) => (Some(parent), None),
+            (_, None, command_handle) => {
+                let cmd_index: CommandMetric = (&command).into();
+                let name = MetricsService::cmd_name(cmd_index as usize);
+
+                match command_handle {
+                    // The span of an API call is finished from its callback
+                    Some(command_handle) => (tracer::start_api_call(&name, command_handle), None),
+                    None => {
+                        let api_span = Span::api_call(&name, None);
+                        (api_span.context(), Some(api_span))
+                    }
+                }
+            }
+        };
+
         InstrumentedCommand {
             enqueue_ts: get_cur_time(),
-            command
+            command,
+            span_context,
+            api_span,
         }
     }
 }
 
-
 lazy_static! {
     static ref THREADPOOL: Mutex<ThreadPool> = Mutex::new(ThreadPool::new(4));
 }
 
+/// Executes the job in the crypto threadpool as a child of the current tracing span.
+pub fn execute_in_threadpool<F>(job_name: &'static str, job: F) where F: FnOnce() + Send + 'static {
+    let parent = tracer::current();
+
+    THREADPOOL.lock().unwrap().execute(move || {
+        let span = Span::child_of(parent, "threadpool")
+            .with_attribute("job", job_name);
+        let _span_guard = span.enter();
+        job()
+    });
+}
+
 pub fn indy_set_runtime_config(config: IndyConfig) {
     if let Some(crypto_thread_pool_size) = config.crypto_thread_pool_size {
         THREADPOOL.lock().unwrap().set_num_threads(crypto_thread_pool_size);
@@ -134,22 +178,24 @@ impl CommandExecutor {
                 let pool_service = Rc::new(PoolService::new());
                 let wallet_service = Rc::new(WalletService::new());
                 let metrics_service = Rc::new(MetricsService::new());
+                let audit_service = Rc::new(AuditService::new(wallet_service.clone()));
 
-                let anoncreds_command_executor = AnoncredsCommandExecutor::new(anoncreds_service.clone(), blob_storage_service.clone(), pool_service.clone(), wallet_service.clone(), crypto_service.clone());
-                let crypto_command_executor = CryptoCommandExecutor::new(wallet_service.clone(), crypto_service.clone());
-                let ledger_command_executor = LedgerCommandExecutor::new(pool_service.clone(), crypto_service.clone(), wallet_service.clone(), ledger_service.clone());
+                let anoncreds_command_executor = AnoncredsCommandExecutor::new(anoncreds_service.clone(), blob_storage_service.clone(), pool_service.clone(), wallet_service.clone(), crypto_service.clone(), audit_service.clone());
+                let crypto_command_executor = CryptoCommandExecutor::new(wallet_service.clone(), crypto_service.clone(), audit_service.clone());
+                let ledger_command_executor = LedgerCommandExecutor::new(pool_service.clone(), crypto_service.clone(), wallet_service.clone(), ledger_service.clone(), audit_service.clone());
                 let pool_command_executor = PoolCommandExecutor::new(pool_service.clone());
-                let did_command_executor = DidCommandExecutor::new(wallet_service.clone(), crypto_service.clone(), ledger_service.clone());
-                let wallet_command_executor = WalletCommandExecutor::new(wallet_service.clone(), crypto_service.clone());
+                let did_command_executor = DidCommandExecutor::new(wallet_service.clone(), crypto_service.clone(), ledger_service.clone(), audit_service.clone());
+                let wallet_command_executor = WalletCommandExecutor::new(wallet_service.clone(), crypto_service.clone(), audit_service.clone());
                 let pairwise_command_executor = PairwiseCommandExecutor::new(wallet_service.clone());
                 let blob_storage_command_executor = BlobStorageCommandExecutor::new(blob_storage_service.clone());
                 let non_secret_command_executor = NonSecretsCommandExecutor::new(wallet_service.clone());
                 let payments_command_executor = PaymentsCommandExecutor::new(payments_service.clone(), wallet_service.clone(), crypto_service.clone(), ledger_service.clone());
                 let cache_command_executor = CacheCommandExecutor::new(wallet_service.clone());
                 let metrics_command_executor = MetricsCommandExecutor::new(wallet_service.clone(), metrics_service.clone());
+                let audit_command_executor = AuditCommandExecutor::new(audit_service.clone());
 
                 loop {
-                    let instrumented_cmd = match receiver.recv() {
+                    let mut instrumented_cmd = match receiver.recv() {
                         Ok(cmd) => {
                             cmd
                         }
@@ -163,6 +209,16 @@ impl CommandExecutor {
                     metrics_service.cmd_left_queue(cmd_index,
                                                    start_execution_ts - instrumented_cmd.enqueue_ts);
 
+                    let span = if let Command::Exit = instrumented_cmd.command {
+                        Span::child_of(None, "command")
+                    } else {
+                        Span::child_or_root(instrumented_cmd.span_context, "command")
+                            .with_attribute("command", MetricsService::cmd_name(cmd_index as usize))
+                            .with_attribute("queued_ms", start_execution_ts - instrumented_cmd.enqueue_ts)
+                    };
+                    let span_guard = span.enter();
+                    let api_span = instrumented_cmd.api_span.take();
+
                     match instrumented_cmd.command {
                         Command::Anoncreds(cmd) => {
                             debug!("AnoncredsCommand command received");
@@ -212,11 +268,18 @@ impl CommandExecutor {
                             debug!("MetricsCommand command received");
                             metrics_command_executor.execute(cmd);
                         }
+                        Command::Audit(cmd) => {
+                            debug!("AuditCommand command received");
+                            audit_command_executor.execute(cmd);
+                        }
                         Command::Exit => {
                             debug!("Exit command received");
                             break
                         }
                     }
+                    drop(span_guard);
+                    drop(span);
+                    drop(api_span);
                     metrics_service.cmd_executed(cmd_index,
                                                  get_cur_time() - start_execution_ts);
                 }
@@ -225,8 +288,23 @@ impl CommandExecutor {
     }
 
     pub fn send(&self, cmd: Command) -> IndyResult<()> {
+        self._send(InstrumentedCommand::new(cmd, None))
+    }
+
+    /// Sends the command of the API call identified by `command_handle`.
+    /// The call is traced as a root span carrying the handle that is finished from the callback of the call.
+    pub fn send_api_call(&self, command_handle: CommandHandle, cmd: Command) -> IndyResult<()> {
+        self._send(InstrumentedCommand::new(cmd, Some(command_handle)))
+            .map_err(|err| {
+                // The callback is not called if the command has not been sent
+                tracer::finish_api_call(command_handle);
+                err
+            })
+    }
+
+    fn _send(&self, cmd: InstrumentedCommand) -> IndyResult<()> {
         self.sender
-            .send(InstrumentedCommand::new(cmd))
+            .send(cmd)
             .map_err(|err| err_msg(IndyErrorKind::InvalidState, format!("Can't send msg to CommandExecutor: {}", err)))
     }
 }
@@ -38,6 +38,9 @@ pub enum PoolCommand {
     SetProtocolVersion(
         usize, // protocol version
         Box<dyn Fn(IndyResult<()>) + Send>),
+    GetStatus(
+        String, // name
+        Box<dyn Fn(IndyResult<String>) + Send>),
 }
 
 pub struct PoolCommandExecutor {
@@ -132,6 +135,10 @@ impl PoolCommandExecutor {
                 debug!(target: "pool_command_executor", "SetProtocolVersion command received");
                 cb(self.set_protocol_version(protocol_version));
             }
+            PoolCommand::GetStatus(name, cb) => {
+                debug!(target: "pool_command_executor", "GetStatus command received");
+                cb(self.get_status(&name));
+            }
         };
     }
 
@@ -185,6 +192,18 @@ impl PoolCommandExecutor {
         Ok(res)
     }
 
+    fn get_status(&self, name: &str) -> IndyResult<String> {
+        debug!("get_status >>> name: {:?}", name);
+
+        let res = self.pool_service
+            .get_status(name)
+            .and_then(|status| ::serde_json::to_string(&status)
+                .to_indy(IndyErrorKind::InvalidState, "Can't serialize pool status"))?;
+
+        debug!("get_status << res: {:?}", res);
+        Ok(res)
+    }
+
     fn close(&self, pool_handle: PoolHandle, cb: Box<dyn Fn(IndyResult<()>) + Send>) {
         debug!("close >>> handle: {:?}", pool_handle);
 
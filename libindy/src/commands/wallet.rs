@@ -1,13 +1,15 @@
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::rc::Rc;
 
 use indy_api_types::wallet::*;
 use crate::commands::{Command, CommandExecutor};
 use indy_api_types::domain::wallet::{Config, Credentials, ExportConfig, KeyConfig};
 use indy_api_types::errors::prelude::*;
+use crate::domain::audit::{AuditEvent, AuditEventType};
+use crate::services::audit::AuditService;
 use crate::services::crypto::CryptoService;
-use indy_wallet::{KeyDerivationData, WalletService, Metadata};
+use indy_wallet::{EncryptedMigrationBatch, KeyDerivationData, WalletService, Metadata};
 use indy_utils::crypto::{chacha20poly1305_ietf, randombytes};
 use indy_utils::crypto::chacha20poly1305_ietf::Key as MasterKey;
 use indy_api_types::{WalletHandle, CallbackHandle};
@@ -84,6 +86,19 @@ pub enum WalletCommand {
                    WalletHandle,
                    CallbackHandle
     ),
+    Migrate(WalletHandle,
+            Config, // destination config
+            Credentials, // destination credentials
+            Box<dyn Fn(IndyResult<String>) + Send>),
+    MigrateContinue(WalletHandle,
+                    Config, // destination config
+                    Credentials, // destination credentials
+                    KeyDerivationData,
+                    DeriveKeyResult<MasterKey>,
+                    CallbackHandle),
+    MigrateBatch(WalletHandle,
+                 EncryptedMigrationBatch,
+                 CallbackHandle),
     GenerateKey(Option<KeyConfig>, // config
                 Box<dyn Fn(IndyResult<String>) + Send>),
     DeriveKey(KeyDerivationData,
@@ -100,17 +115,21 @@ macro_rules! get_cb {
 pub struct WalletCommandExecutor {
     wallet_service: Rc<WalletService>,
     crypto_service: Rc<CryptoService>,
+    audit_service: Rc<AuditService>,
     open_callbacks: RefCell<HashMap<WalletHandle, Box<dyn Fn(IndyResult<WalletHandle>) + Send>>>,
-    pending_callbacks: RefCell<HashMap<CallbackHandle, Box<dyn Fn(IndyResult<()>) + Send>>>
+    pending_callbacks: RefCell<HashMap<CallbackHandle, Box<dyn Fn(IndyResult<()>) + Send>>>,
+    migrate_callbacks: RefCell<HashMap<CallbackHandle, (Config /* destination config */, Box<dyn Fn(IndyResult<String>) + Send>)>>
 }
 
 impl WalletCommandExecutor {
-    pub fn new(wallet_service: Rc<WalletService>, crypto_service: Rc<CryptoService>) -> WalletCommandExecutor {
+    pub fn new(wallet_service: Rc<WalletService>, crypto_service: Rc<CryptoService>, audit_service: Rc<AuditService>) -> WalletCommandExecutor {
         WalletCommandExecutor {
             wallet_service,
             crypto_service,
+            audit_service,
             open_callbacks: RefCell::new(HashMap::new()),
-            pending_callbacks: RefCell::new(HashMap::new())
+            pending_callbacks: RefCell::new(HashMap::new()),
+            migrate_callbacks: RefCell::new(HashMap::new())
         }
     }
 
@@ -174,6 +193,18 @@ impl WalletCommandExecutor {
                 debug!(target: "wallet_command_executor", "ImportContinue command received");
                 self._import_continue(cb_id, wallet_handle, &config, &credential, key_result);
             }
+            WalletCommand::Migrate(wallet_handle, config, credentials, cb) => {
+                debug!(target: "wallet_command_executor", "Migrate command received");
+                self._migrate(wallet_handle, &config, &credentials, cb);
+            }
+            WalletCommand::MigrateContinue(wallet_handle, config, credentials, key_data, key_result, cb_id) => {
+                debug!(target: "wallet_command_executor", "MigrateContinue command received");
+                self._migrate_continue(cb_id, wallet_handle, &config, &credentials, key_data, key_result);
+            }
+            WalletCommand::MigrateBatch(wallet_handle, batch, cb_id) => {
+                debug!(target: "wallet_command_executor", "MigrateBatch command received");
+                self._migrate_batch(cb_id, wallet_handle, batch);
+            }
             WalletCommand::GenerateKey(config, cb) => {
                 debug!(target: "wallet_command_executor", "DeriveKey command received");
                 cb(self._generate_key(config.as_ref()));
@@ -426,7 +457,8 @@ impl WalletCommandExecutor {
                         key_result: DeriveKeyResult<MasterKey>) {
         let cb = get_cb!(self, cb_id);
         cb(key_result
-            .and_then(|key| self.wallet_service.export_wallet(wallet_handle, export_config, 0, (&key_data,& key)))) // TODO - later add proper versioning
+            .and_then(|key| self.wallet_service.export_wallet(wallet_handle, export_config, 0, (&key_data,& key))) // TODO - later add proper versioning
+            .map(|_| self.audit_service.record(wallet_handle, AuditEvent::new(AuditEventType::WalletExported).detail("path", &export_config.path))))
     }
 
     fn _import(&self,
@@ -485,6 +517,107 @@ impl WalletCommandExecutor {
             .and_then(|key| self.wallet_service.import_wallet_continue(wallet_handle, &config, &credential, key)))
     }
 
+    fn _migrate(&self,
+                wallet_handle: WalletHandle,
+                config: &Config,
+                credentials: &Credentials,
+                cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        trace!("_migrate >>> handle: {:?}, config: {:?}, credentials: {:?}", wallet_handle, config, secret!(credentials));
+
+        try_cb!(self.wallet_service.check(wallet_handle), cb);
+
+        let key_data = KeyDerivationData::from_passphrase_with_new_salt(&credentials.key, &credentials.key_derivation_method);
+
+        let cb_id: CallbackHandle = indy_utils::sequence::get_next_id();
+        self.migrate_callbacks.borrow_mut().insert(cb_id, (config.clone(), cb));
+
+        let config = config.clone();
+        let credentials = credentials.clone();
+
+        CommandExecutor::instance().send(
+            Command::Wallet(WalletCommand::DeriveKey(
+                key_data.clone(),
+                Box::new(move |master_key_res| {
+                    CommandExecutor::instance().send(Command::Wallet(WalletCommand::MigrateContinue(
+                        wallet_handle,
+                        config.clone(),
+                        credentials.clone(),
+                        key_data.clone(),
+                        master_key_res,
+                        cb_id,
+                    ))).unwrap();
+                })
+            ))
+        ).unwrap();
+
+        trace!("_migrate <<<");
+    }
+
+    fn _migrate_continue(&self,
+                         cb_id: CallbackHandle,
+                         wallet_handle: WalletHandle,
+                         config: &Config,
+                         credentials: &Credentials,
+                         key_data: KeyDerivationData,
+                         key_result: DeriveKeyResult<MasterKey>) {
+        let res = key_result
+            .and_then(|key| self.wallet_service.migrate_wallet_prepare(wallet_handle, config, credentials, (&key_data, &key)));
+
+        match res {
+            Ok(()) => self._migrate_next(cb_id, wallet_handle),
+            Err(err) => self._migrate_complete(cb_id, wallet_handle, Err(err))
+        }
+    }
+
+    // Records are read and written on the command thread, as wallets are bound to it,
+    // and re-encrypted for the destination wallet in the threadpool batch by batch.
+    fn _migrate_next(&self, cb_id: CallbackHandle, wallet_handle: WalletHandle) {
+        match self.wallet_service.migrate_wallet_next(wallet_handle) {
+            Ok(Some(batch)) => {
+                crate::commands::execute_in_threadpool("migrate_wallet", move || {
+                    let batch = batch.encrypt();
+
+                    CommandExecutor::instance().send(
+                        Command::Wallet(WalletCommand::MigrateBatch(wallet_handle, batch, cb_id))
+                    ).unwrap();
+                });
+            }
+            Ok(None) => self._migrate_complete(cb_id, wallet_handle, self.wallet_service.migrate_wallet_finish(wallet_handle)),
+            Err(err) => self._migrate_complete(cb_id, wallet_handle, Err(err))
+        }
+    }
+
+    fn _migrate_batch(&self,
+                      cb_id: CallbackHandle,
+                      wallet_handle: WalletHandle,
+                      batch: EncryptedMigrationBatch) {
+        match self.wallet_service.migrate_wallet_add(wallet_handle, batch) {
+            Ok(()) => self._migrate_next(cb_id, wallet_handle),
+            Err(err) => self._migrate_complete(cb_id, wallet_handle, Err(err))
+        }
+    }
+
+    fn _migrate_complete(&self,
+                         cb_id: CallbackHandle,
+                         wallet_handle: WalletHandle,
+                         res: IndyResult<BTreeMap<String, usize>>) {
+        let (config, cb) = match self.migrate_callbacks.borrow_mut().remove(&cb_id) {
+            Some(val) => val,
+            None => return error!("No pending command for id: {}", cb_id)
+        };
+
+        cb(res.map(|counts| {
+            let total: usize = counts.values().sum();
+
+            self.audit_service.record(wallet_handle, AuditEvent::new(AuditEventType::WalletMigrated)
+                .detail("id", &config.id)
+                .detail("storage_type", config.storage_type.as_ref().map(String::as_str).unwrap_or("default"))
+                .detail("records", &total.to_string()));
+
+            json!({"records": counts, "total": total}).to_string()
+        }))
+    }
+
     fn _generate_key(&self,
                      config: Option<&KeyConfig>) -> IndyResult<String> {
         trace!("_generate_key >>>config: {:?}", secret!(config));
@@ -503,6 +636,6 @@ impl WalletCommandExecutor {
     }
 
     fn _derive_key(&self, key_data: KeyDerivationData, cb: Box<dyn Fn(DeriveKeyResult<MasterKey>) + Send>){
-        crate::commands::THREADPOOL.lock().unwrap().execute(move || cb(key_data.calc_master_key()));
+        crate::commands::execute_in_threadpool("calc_master_key", move || cb(key_data.calc_master_key()));
     }
 }
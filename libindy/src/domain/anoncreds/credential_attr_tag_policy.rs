@@ -1,33 +1,82 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use serde::ser::{Serialize, Serializer, SerializeSeq};
-use serde::de::{Deserializer, Deserialize};
+use serde::de::{self, Deserializer, Deserialize};
+
+use super::schema::AttributeType;
+
+const TYPE_DELIMITER: &str = "::";
 
 #[derive(Debug)]
 pub struct CredentialAttrTagPolicy {
-    pub taggable: HashSet<String>
+    pub taggable: HashSet<String>,
+    // attributes opted in for unencrypted sortable tags with types declared by the schema
+    pub typed: HashMap<String, AttributeType>,
 }
 
 impl CredentialAttrTagPolicy {
     pub fn is_taggable(&self, attr_name: &str) -> bool {
-        self.taggable.contains(&attr_name.to_string().replace(" ", "").to_lowercase())
+        let attr_name = _attr_common_view(attr_name);
+        self.taggable.contains(&attr_name) || self.typed.contains_key(&attr_name)
+    }
+
+    pub fn attr_type(&self, attr_name: &str) -> Option<AttributeType> {
+        self.typed.get(&_attr_common_view(attr_name)).cloned()
+    }
+
+    fn parse(entries: Vec<String>) -> Result<Self, String> {
+        let mut taggable = HashSet::new();
+        let mut typed = HashMap::new();
+
+        for entry in entries {
+            let entry = _attr_common_view(&entry);
+
+            match entry.find(TYPE_DELIMITER) {
+                Some(pos) => {
+                    let attr_type = match &entry[pos + TYPE_DELIMITER.len()..] {
+                        "integer" => AttributeType::Integer,
+                        "date" => AttributeType::Date,
+                        "boolean" => AttributeType::Boolean,
+                        type_ => return Err(format!("Unsupported type \"{}\" of sortable attribute tag: integer, date or boolean expected", type_))
+                    };
+                    typed.insert(entry[..pos].to_string(), attr_type);
+                }
+                None => { taggable.insert(entry); }
+            }
+        }
+
+        Ok(CredentialAttrTagPolicy { taggable, typed })
     }
 }
 
+fn _attr_common_view(attr_name: &str) -> String {
+    attr_name.replace(" ", "").to_lowercase()
+}
+
 impl From<Vec<String>> for CredentialAttrTagPolicy {
     fn from(taggables: Vec<String>) -> Self {
         CredentialAttrTagPolicy {
-            taggable: taggables.into_iter().map(|a| a.replace(" ", "").to_lowercase()).collect()
+            taggable: taggables.into_iter().map(|a| _attr_common_view(&a)).collect(),
+            typed: HashMap::new(),
         }
     }
 }
 
 impl Serialize for CredentialAttrTagPolicy {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer, {
-        let mut seq = serializer.serialize_seq(Some(self.taggable.len()))?;
+        let mut seq = serializer.serialize_seq(Some(self.taggable.len() + self.typed.len()))?;
         for ref element in &self.taggable {
             seq.serialize_element(&element)?;
         }
+        for (attr_name, attr_type) in &self.typed {
+            let attr_type = match attr_type {
+                AttributeType::Integer => "integer",
+                AttributeType::Date => "date",
+                AttributeType::Boolean => "boolean",
+                AttributeType::String => continue,
+            };
+            seq.serialize_element(&format!("{}{}{}", attr_name, TYPE_DELIMITER, attr_type))?;
+        }
         seq.end()
     }
 }
@@ -35,6 +84,35 @@ impl Serialize for CredentialAttrTagPolicy {
 impl<'de> Deserialize<'de> for CredentialAttrTagPolicy {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de>, {
         let attr_names = Vec::deserialize(deserializer)?;
-        Ok(CredentialAttrTagPolicy::from(attr_names))
+        CredentialAttrTagPolicy::parse(attr_names).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_works_for_typed_attributes() {
+        let catpol: CredentialAttrTagPolicy = serde_json::from_str(r#"["name", "Age::integer", "dob::date"]"#).unwrap();
+
+        assert!(catpol.is_taggable("name"));
+        assert!(catpol.is_taggable("age"));
+        assert!(!catpol.is_taggable("height"));
+
+        assert_eq!(None, catpol.attr_type("name"));
+        assert_eq!(Some(AttributeType::Integer), catpol.attr_type("age"));
+        assert_eq!(Some(AttributeType::Date), catpol.attr_type("DOB"));
+    }
+
+    #[test]
+    fn deserialize_works_for_unsupported_type() {
+        assert!(serde_json::from_str::<CredentialAttrTagPolicy>(r#"["name::string"]"#).is_err());
+    }
+
+    #[test]
+    fn serialize_works_for_typed_attributes() {
+        let catpol: CredentialAttrTagPolicy = serde_json::from_str(r#"["age::integer"]"#).unwrap();
+        assert_eq!(r#"["age::integer"]"#, serde_json::to_string(&catpol).unwrap());
     }
 }
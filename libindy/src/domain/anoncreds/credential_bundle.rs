@@ -0,0 +1,104 @@
+use indy_api_types::domain::wallet::KeyDerivationMethod;
+use indy_api_types::validation::Validatable;
+
+use super::credential::Credential;
+use super::credential_request::CredentialRequestMetadata;
+use super::master_secret::MasterSecret;
+use super::revocation_registry_definition::RevocationRegistryId;
+
+pub const CREDENTIAL_BUNDLE_VERSION: &str = "1.0";
+
+fn default_key_derivation_method() -> KeyDerivationMethod {
+    KeyDerivationMethod::ARGON2I_MOD
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CredentialExportConfig {
+    pub key: String,
+    #[serde(default = "default_key_derivation_method")]
+    pub key_derivation_method: KeyDerivationMethod,
+    pub master_secret_id: Option<String>,
+    #[serde(default)]
+    pub include_master_secret: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CredentialImportConfig {
+    pub key: String,
+    pub cred_id: Option<String>,
+    pub master_secret_id: Option<String>,
+}
+
+/// Encrypted credential bundle transferred between wallets.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CredentialBundle {
+    pub ver: String,
+    pub key_derivation_method: KeyDerivationMethod,
+    pub salt: String,
+    pub nonce: String,
+    pub ciphertext: String,
+}
+
+/// Content of the credential bundle before encryption.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CredentialBundlePayload {
+    pub cred_id: String,
+    pub credential: Credential,
+    pub master_secret_id: String,
+    pub master_secret_fingerprint: String,
+    pub master_secret: Option<MasterSecret>,
+    pub revocation: Option<CredentialBundleRevocation>,
+    // Not known for credentials stored before the wallet kept the metadata.
+    pub cred_req_metadata: Option<CredentialRequestMetadata>,
+}
+
+/// Revocation info of the credential and its revocation status known to the exporting holder.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CredentialBundleRevocation {
+    pub rev_reg_id: RevocationRegistryId,
+    pub cred_rev_id: Option<String>,
+    pub revoked: bool,
+    pub revoked_as_of: Option<u64>,
+}
+
+impl Validatable for CredentialExportConfig {
+    fn validate(&self) -> Result<(), String> {
+        if self.key.is_empty() {
+            return Err(String::from("Credential bundle key is empty"));
+        }
+
+        if let KeyDerivationMethod::RAW = self.key_derivation_method {
+            return Err(String::from("RAW key derivation method is not acceptable for credential bundle"));
+        }
+
+        if self.master_secret_id.as_ref().map(String::is_empty).unwrap_or(false) {
+            return Err(String::from("Master secret id is empty"));
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable for CredentialImportConfig {
+    fn validate(&self) -> Result<(), String> {
+        if self.key.is_empty() {
+            return Err(String::from("Credential bundle key is empty"));
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable for CredentialBundle {
+    fn validate(&self) -> Result<(), String> {
+        if self.ver != CREDENTIAL_BUNDLE_VERSION {
+            return Err(format!("Unsupported credential bundle version: {}", self.ver));
+        }
+
+        if let KeyDerivationMethod::RAW = self.key_derivation_method {
+            return Err(String::from("RAW key derivation method is not acceptable for credential bundle"));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+use indy_api_types::domain::wallet::Tags;
+
+use crate::domain::sortable_u64;
+
+/// Query only tag: `{"status::active": "1"}` matches credentials that are neither known revoked,
+/// nor replaced, nor expired, `{"status::active": "0"}` matches the rest.
+pub const CREDENTIAL_STATUS_ACTIVE_TAG: &str = "status::active";
+pub const CREDENTIAL_STATUS_REVOKED_TAG: &str = "status::revoked";
+pub const CREDENTIAL_STATUS_REVOKED_AS_OF_TAG: &str = "~status::revoked_as_of";
+pub const CREDENTIAL_STATUS_REPLACED_TAG: &str = "status::replaced";
+pub const CREDENTIAL_STATUS_REPLACED_BY_TAG: &str = "status::replaced_by";
+pub const CREDENTIAL_STATUS_EXPIRES_AT_TAG: &str = "~status::expires_at";
+
+pub const CREDENTIAL_STATUS_FLAG: &str = "1";
+
+/// Holder side lifecycle status of a stored credential.
+/// It is kept in tags of the credential record, so credential searches can filter inactive credentials.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct CredentialStatus {
+    pub revoked: bool,
+    pub revoked_as_of: Option<u64>,
+    pub expires_at: Option<u64>,
+    pub replaced_by: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CredentialStatusInfo {
+    pub referent: String,
+    #[serde(flatten)]
+    pub status: CredentialStatus,
+    pub active: bool,
+}
+
+/// Attribute carrying expiry of credentials of a credential definition,
+/// as designated by the issuer. Values are unix timestamps or dates in YYYY-MM-DD format.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CredentialExpiryAttr {
+    pub attr_name: String
+}
+
+/// Attribute identifying the subject of credentials of a credential definition, as designated by the holder.
+/// Stored credential replaces only credentials of the same credential definition with the same value of this attribute.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CredentialIdentityAttr {
+    pub attr_name: String
+}
+
+impl CredentialStatus {
+    pub fn from_tags(tags: &Tags) -> CredentialStatus {
+        let timestamp = |tag_name: &str| tags.get(tag_name).and_then(|value| value.parse::<u64>().ok());
+
+        CredentialStatus {
+            revoked: tags.get(CREDENTIAL_STATUS_REVOKED_TAG).map(|value| value == CREDENTIAL_STATUS_FLAG).unwrap_or(false),
+            revoked_as_of: timestamp(CREDENTIAL_STATUS_REVOKED_AS_OF_TAG),
+            expires_at: timestamp(CREDENTIAL_STATUS_EXPIRES_AT_TAG),
+            replaced_by: tags.get(CREDENTIAL_STATUS_REPLACED_BY_TAG).cloned(),
+        }
+    }
+
+    pub fn to_tags(&self) -> Tags {
+        let mut tags = Tags::new();
+
+        if self.revoked {
+            tags.insert(CREDENTIAL_STATUS_REVOKED_TAG.to_string(), CREDENTIAL_STATUS_FLAG.to_string());
+        }
+
+        if let Some(revoked_as_of) = self.revoked_as_of {
+            tags.insert(CREDENTIAL_STATUS_REVOKED_AS_OF_TAG.to_string(), sortable_u64(revoked_as_of));
+        }
+
+        if let Some(expires_at) = self.expires_at {
+            tags.insert(CREDENTIAL_STATUS_EXPIRES_AT_TAG.to_string(), sortable_u64(expires_at));
+        }
+
+        if let Some(ref replaced_by) = self.replaced_by {
+            tags.insert(CREDENTIAL_STATUS_REPLACED_TAG.to_string(), CREDENTIAL_STATUS_FLAG.to_string());
+            tags.insert(CREDENTIAL_STATUS_REPLACED_BY_TAG.to_string(), replaced_by.clone());
+        }
+
+        tags
+    }
+
+    pub fn is_active(&self, now: u64) -> bool {
+        !self.revoked && self.replaced_by.is_none() && self.expires_at.map(|expires_at| expires_at > now).unwrap_or(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn credential_status_tags_roundtrip_works() {
+        let status = CredentialStatus {
+            revoked: true,
+            revoked_as_of: Some(1_600_000_000),
+            expires_at: Some(1_700_000_000),
+            replaced_by: Some("cred_2".to_string()),
+        };
+
+        let tags = status.to_tags();
+        assert_eq!("00000000001700000000", tags[CREDENTIAL_STATUS_EXPIRES_AT_TAG]);
+        assert_eq!("1", tags[CREDENTIAL_STATUS_REPLACED_TAG]);
+        assert_eq!(status, CredentialStatus::from_tags(&tags));
+    }
+
+    #[test]
+    fn credential_status_is_active_works() {
+        assert!(CredentialStatus::default().is_active(100));
+        assert!(CredentialStatus { expires_at: Some(101), ..CredentialStatus::default() }.is_active(100));
+        assert!(!CredentialStatus { expires_at: Some(100), ..CredentialStatus::default() }.is_active(100));
+        assert!(!CredentialStatus { revoked: true, ..CredentialStatus::default() }.is_active(100));
+        assert!(!CredentialStatus { replaced_by: Some("cred_2".to_string()), ..CredentialStatus::default() }.is_active(100));
+    }
+}
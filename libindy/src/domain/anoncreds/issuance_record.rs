@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use indy_api_types::validation::Validatable;
+
+use super::credential_definition::CredentialDefinitionId;
+use super::revocation_registry_definition::RevocationRegistryId;
+
+/// Tags maintained by libindy on every issuance record. Caller tags can't override them.
+pub const ISSUANCE_RECORD_RESERVED_TAGS: [&str; 4] = ["cred_def_id", "rev_reg_id", "cred_rev_id", "revoked"];
+
+pub const ISSUANCE_RECORD_VALUES_SALT_SIZE: usize = 32;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct IssuanceRecordConfig {
+    pub id: String,
+    #[serde(default)]
+    pub tags: HashMap<String, String>,
+}
+
+/// Issuer side record of an issued credential.
+/// Keeps credential values only as a hash salted by a random per-record salt. The salt makes hashes of the same
+/// values differ between records, but low-entropy values can still be guessed by anyone who reads the record.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct IssuanceRecord {
+    pub id: String,
+    pub cred_def_id: CredentialDefinitionId,
+    pub rev_reg_id: Option<RevocationRegistryId>,
+    pub cred_rev_id: Option<String>,
+    pub issued_at: u64,
+    pub revoked_at: Option<u64>,
+    pub tags: HashMap<String, String>,
+    pub values_hash: String,
+    pub values_salt: String,
+}
+
+impl Validatable for IssuanceRecordConfig {
+    fn validate(&self) -> Result<(), String> {
+        if self.id.is_empty() {
+            return Err(String::from("Issuance record id is empty"));
+        }
+
+        if let Some(tag) = self.tags.keys().find(|tag| ISSUANCE_RECORD_RESERVED_TAGS.contains(&tag.trim_start_matches('~'))) {
+            return Err(format!("Issuance record tag \"{}\" is reserved", tag));
+        }
+
+        Ok(())
+    }
+}
+
+impl IssuanceRecord {
+    pub fn wallet_tags(&self) -> HashMap<String, String> {
+        let mut tags = self.tags.clone();
+
+        tags.insert("cred_def_id".to_string(), self.cred_def_id.0.clone());
+        tags.insert("rev_reg_id".to_string(), self.rev_reg_id.as_ref().map(|id| id.0.clone()).unwrap_or_else(|| "None".to_string()));
+        if let Some(cred_rev_id) = self.cred_rev_id.as_ref() {
+            tags.insert("cred_rev_id".to_string(), cred_rev_id.clone());
+        }
+        tags.insert("revoked".to_string(), if self.revoked_at.is_some() { "1" } else { "0" }.to_string());
+
+        tags
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _config(tags: HashMap<String, String>) -> IssuanceRecordConfig {
+        IssuanceRecordConfig { id: "record_1".to_string(), tags }
+    }
+
+    #[test]
+    fn validate_issuance_record_config_works() {
+        let mut tags = HashMap::new();
+        tags.insert("connection_id".to_string(), "conn_1".to_string());
+        tags.insert("~issued_on".to_string(), "2020-01-01".to_string());
+
+        _config(tags).validate().unwrap();
+    }
+
+    #[test]
+    fn validate_issuance_record_config_works_for_reserved_tag() {
+        let mut tags = HashMap::new();
+        tags.insert("~revoked".to_string(), "0".to_string());
+
+        _config(tags).validate().unwrap_err();
+    }
+
+    #[test]
+    fn validate_issuance_record_config_works_for_empty_id() {
+        IssuanceRecordConfig { id: String::new(), tags: HashMap::new() }.validate().unwrap_err();
+    }
+
+    #[test]
+    fn issuance_record_wallet_tags_works() {
+        let mut tags = HashMap::new();
+        tags.insert("connection_id".to_string(), "conn_1".to_string());
+
+        let record = IssuanceRecord {
+            id: "record_1".to_string(),
+            cred_def_id: CredentialDefinitionId("NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:tag".to_string()),
+            rev_reg_id: None,
+            cred_rev_id: None,
+            issued_at: 1,
+            revoked_at: None,
+            tags,
+            values_hash: String::new(),
+            values_salt: String::new(),
+        };
+
+        let wallet_tags = record.wallet_tags();
+
+        assert_eq!("conn_1", wallet_tags["connection_id"]);
+        assert_eq!("NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:tag", wallet_tags["cred_def_id"]);
+        assert_eq!("None", wallet_tags["rev_reg_id"]);
+        assert_eq!("0", wallet_tags["revoked"]);
+        assert!(!wallet_tags.contains_key("cred_rev_id"));
+    }
+}
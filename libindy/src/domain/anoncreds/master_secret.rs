@@ -2,9 +2,32 @@ use ursa::cl::MasterSecret as CryptoMasterSecret;
 
 use indy_api_types::validation::Validatable;
 
+use super::credential::CredentialInfo;
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct MasterSecret {
     pub value: CryptoMasterSecret,
 }
 
 impl Validatable for MasterSecret {}
+
+/// Public part of master secret record: the secret value itself is never returned.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct MasterSecretInfo {
+    pub id: String,
+    pub default: bool,
+    pub retired: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MasterSecretRotation {
+    pub master_secret_id: String,
+    pub retired_master_secret_id: String,
+    pub credentials_to_reissue: Vec<CredentialInfo>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct CreateProofOptions {
+    #[serde(default)]
+    pub allow_mixed_master_secrets: bool,
+}
@@ -1,17 +1,25 @@
 pub mod credential;
 pub mod credential_attr_tag_policy;
+pub mod credential_bundle;
 pub mod credential_definition;
 pub mod credential_for_proof_request;
 pub mod credential_offer;
 pub mod credential_request;
+pub mod credential_status;
+pub mod issuance_record;
 pub mod proof;
 pub mod proof_request;
+pub mod proof_request_template;
+pub mod proof_request_diagnostics;
 pub mod requested_credential;
 pub mod revocation_registry_definition;
 pub mod revocation_registry_delta;
 pub mod revocation_registry;
 pub mod revocation_state;
 pub mod schema;
+pub mod verification_options;
+pub mod verification_report;
+pub mod w3c;
 pub mod master_secret;
 
 pub const DELIMITER: &str = ":";
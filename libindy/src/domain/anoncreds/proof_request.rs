@@ -148,6 +148,16 @@ pub struct PredicateInfo {
     pub non_revoked: Option<NonRevocedInterval>
 }
 
+/// Predicate over a date attribute expressed with a calendar date instead of number of days since epoch.
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct DatePredicateInfo {
+    pub name: String,
+    pub p_type: PredicateTypes,
+    pub date: String,
+    pub restrictions: Option<Query>,
+    pub non_revoked: Option<NonRevocedInterval>
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 pub enum PredicateTypes {
     #[serde(rename = ">=")]
@@ -244,6 +254,71 @@ impl ProofRequest {
             }
         }
     }
+
+    pub fn to_qualified(self, method: &str) -> ProofRequest {
+        let convert = |proof_request: &mut ProofRequestPayload| {
+            for (_, requested_attribute) in proof_request.requested_attributes.iter_mut() {
+                requested_attribute.restrictions = requested_attribute.restrictions.as_ref().map(|restrictions| _convert_query_to_qualified(restrictions, method));
+            }
+            for (_, requested_predicate) in proof_request.requested_predicates.iter_mut() {
+                requested_predicate.restrictions = requested_predicate.restrictions.as_ref().map(|restrictions| _convert_query_to_qualified(restrictions, method));
+            }
+        };
+
+        match self {
+            ProofRequest::ProofRequestV2(mut proof_request) => {
+                convert(&mut proof_request);
+                ProofRequest::ProofRequestV2(proof_request)
+            }
+            ProofRequest::ProofRequestV1(mut proof_request) => {
+                convert(&mut proof_request);
+                ProofRequest::ProofRequestV1(proof_request)
+            }
+        }
+    }
+}
+
+fn _convert_query_to_qualified(query: &Query, method: &str) -> Query {
+    match query {
+        Query::Eq(ref tag_name, ref tag_value) => { Query::Eq(tag_name.to_string(), _convert_value_to_qualified(tag_name, tag_value, method)) }
+        Query::Neq(ref tag_name, ref tag_value) => { Query::Neq(tag_name.to_string(), _convert_value_to_qualified(tag_name, tag_value, method)) }
+        Query::In(ref tag_name, ref tag_values) => {
+            Query::In(tag_name.to_string(),
+                      tag_values
+                          .iter()
+                          .map(|tag_value| _convert_value_to_qualified(tag_name, tag_value, method))
+                          .collect::<Vec<String>>()
+            )
+        }
+        Query::And(ref queries) => {
+            Query::And(
+                queries
+                    .iter()
+                    .map(|query| _convert_query_to_qualified(query, method))
+                    .collect::<Vec<Query>>()
+            )
+        }
+        Query::Or(ref queries) => {
+            Query::Or(
+                queries
+                    .iter()
+                    .map(|query| _convert_query_to_qualified(query, method))
+                    .collect::<Vec<Query>>()
+            )
+        }
+        Query::Not(ref query) => { Query::Not(Box::new(_convert_query_to_qualified(query, method))) }
+        query => query.clone()
+    }
+}
+
+// Values are unqualified first, so already qualified identifiers get the requested method.
+fn _convert_value_to_qualified(tag_name: &str, tag_value: &str, method: &str) -> String {
+    match tag_name {
+        "issuer_did" | "schema_issuer_did" => DidValue(tag_value.to_string()).to_unqualified().qualify(method).0,
+        "schema_id" => SchemaId(tag_value.to_string()).to_unqualified().qualify(method).0,
+        "cred_def_id" => CredentialDefinitionId(tag_value.to_string()).to_unqualified().qualify(method).0,
+        _ => tag_value.to_string()
+    }
 }
 
 fn _convert_query_to_unqualified(query: &Query) -> Query {
@@ -448,4 +523,48 @@ mod tests {
             assert_eq!(ProofRequestsVersion::V2, proof_request.version());
         }
     }
+
+    mod to_qualified {
+        use super::*;
+
+        const DID_QUALIFIED: &str = "did:sov:NcYxiDXkpYi6ov5FcYDi1e";
+        const DID_UNQUALIFIED: &str = "NcYxiDXkpYi6ov5FcYDi1e";
+        const SCHEMA_ID_QUALIFIED: &str = "schema:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0";
+        const CRED_DEF_ID_QUALIFIED: &str = "creddef:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:3:CL:schema:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0:tag";
+        const CRED_DEF_ID_UNQUALIFIED: &str = "NcYxiDXkpYi6ov5FcYDi1e:3:CL:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0:tag";
+
+        #[test]
+        fn proof_request_to_qualified() {
+            let mut requested_attributes: HashMap<String, AttributeInfo> = HashMap::new();
+            requested_attributes.insert("attr1_referent".to_string(), AttributeInfo {
+                name: Some("name".to_string()),
+                names: None,
+                restrictions: Some(Query::Or(vec![
+                    Query::Eq("issuer_did".to_string(), DID_UNQUALIFIED.to_string()),
+                    Query::Eq("schema_id".to_string(), SCHEMA_ID_QUALIFIED.to_string()),
+                    Query::Not(Box::new(Query::Eq("cred_def_id".to_string(), CRED_DEF_ID_UNQUALIFIED.to_string()))),
+                ])),
+                non_revoked: None,
+            });
+
+            let proof_request = ProofRequest::ProofRequestV2(ProofRequestPayload {
+                nonce: Nonce::new().unwrap(),
+                name: "proof_request_to_qualified".to_string(),
+                version: "1.0".to_string(),
+                requested_attributes,
+                requested_predicates: HashMap::new(),
+                non_revoked: None,
+            });
+
+            let expected_restrictions = Query::Or(vec![
+                Query::Eq("issuer_did".to_string(), DID_QUALIFIED.to_string()),
+                Query::Eq("schema_id".to_string(), SCHEMA_ID_QUALIFIED.to_string()),
+                Query::Not(Box::new(Query::Eq("cred_def_id".to_string(), CRED_DEF_ID_QUALIFIED.to_string()))),
+            ]);
+
+            let proof_request = proof_request.to_qualified("sov");
+            assert_eq!(Some(expected_restrictions), proof_request.value().requested_attributes["attr1_referent"].restrictions);
+            assert_eq!(ProofRequestsVersion::V2, proof_request.version());
+        }
+    }
 }
\ No newline at end of file
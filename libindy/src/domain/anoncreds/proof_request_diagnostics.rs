@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+use super::credential::CredentialInfo;
+use super::proof_request::NonRevocedInterval;
+use crate::utils::wql::Query;
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosticsIssueReason {
+    NoCredentialWithAttribute,
+    RestrictionMismatch,
+    PredicateNotSatisfied,
+    InvalidAttributeValue,
+    CredentialNotRevocable,
+    InvalidInterval,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct DiagnosticsIssue {
+    pub reason: DiagnosticsIssueReason,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restriction: Option<Query>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actual: Option<String>,
+}
+
+impl DiagnosticsIssue {
+    pub fn new(reason: DiagnosticsIssueReason, message: &str) -> DiagnosticsIssue {
+        DiagnosticsIssue {
+            reason,
+            message: message.to_string(),
+            restriction: None,
+            tag: None,
+            actual: None,
+        }
+    }
+
+    pub fn restriction_mismatch(restriction: &Query, tag: Option<&str>, actual: Option<&str>) -> DiagnosticsIssue {
+        let message = match (tag, actual) {
+            (Some(tag), Some(actual)) => format!("Credential tag \"{}\" has value \"{}\" that does not satisfy restriction {}", tag, actual, restriction.to_string()),
+            (Some(tag), None) => format!("Credential has no tag \"{}\" required by restriction {}", tag, restriction.to_string()),
+            _ => format!("Credential does not satisfy restriction {}", restriction.to_string()),
+        };
+
+        DiagnosticsIssue {
+            reason: DiagnosticsIssueReason::RestrictionMismatch,
+            message,
+            restriction: Some(restriction.clone()),
+            tag: tag.map(String::from),
+            actual: actual.map(String::from),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct CredentialDiagnostics {
+    pub cred_info: CredentialInfo,
+    pub satisfies: bool,
+    #[serde(default)]
+    pub issues: Vec<DiagnosticsIssue>,
+}
+
+impl CredentialDiagnostics {
+    pub fn new(cred_info: CredentialInfo, issues: Vec<DiagnosticsIssue>) -> CredentialDiagnostics {
+        CredentialDiagnostics {
+            cred_info,
+            satisfies: issues.is_empty(),
+            issues,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct ReferentDiagnostics {
+    pub satisfiable: bool,
+    pub non_revoked: Option<NonRevocedInterval>,
+    #[serde(default)]
+    pub credentials: Vec<CredentialDiagnostics>,
+    #[serde(default)]
+    pub issues: Vec<DiagnosticsIssue>,
+    #[serde(default)]
+    pub truncated: bool,
+}
+
+impl ReferentDiagnostics {
+    pub fn new(non_revoked: Option<NonRevocedInterval>) -> ReferentDiagnostics {
+        ReferentDiagnostics {
+            satisfiable: false,
+            non_revoked,
+            credentials: Vec::new(),
+            issues: Vec::new(),
+            truncated: false,
+        }
+    }
+
+    pub fn finalize(&mut self) {
+        if self.credentials.is_empty() && self.issues.is_empty() {
+            self.issues.push(DiagnosticsIssue::new(DiagnosticsIssueReason::NoCredentialWithAttribute,
+                                                   "Wallet does not contain any credential with the requested attribute(s)"));
+        }
+
+        self.satisfiable = self.issues.is_empty() &&
+            self.credentials.iter().any(|credential| credential.satisfies);
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct ProofRequestDiagnostics {
+    pub satisfiable: bool,
+    #[serde(default)]
+    pub requested_attributes: HashMap<String, ReferentDiagnostics>,
+    #[serde(default)]
+    pub requested_predicates: HashMap<String, ReferentDiagnostics>,
+}
+
+impl ProofRequestDiagnostics {
+    pub fn finalize(&mut self) {
+        self.satisfiable =
+            self.requested_attributes.values().all(|diagnostics| diagnostics.satisfiable) &&
+                self.requested_predicates.values().all(|diagnostics| diagnostics.satisfiable);
+    }
+}
@@ -0,0 +1,172 @@
+use indy_api_types::validation::Validatable;
+
+use crate::utils::wql::Query;
+
+use super::credential_definition::CredentialDefinitionId;
+use super::proof_request::{NonRevocedInterval, PredicateTypes};
+use super::schema::SchemaId;
+use super::super::crypto::did::DidValue;
+
+pub const DEFAULT_PROOF_REQUEST_NAME: &str = "proof_req";
+pub const DEFAULT_PROOF_REQUEST_VERSION: &str = "1.0";
+pub const DEFAULT_QUALIFIER_METHOD: &str = "sov";
+
+const SUPPORTED_RESTRICTION_TAGS: [&str; 6] = ["schema_id", "schema_issuer_did", "schema_name", "schema_version", "issuer_did", "cred_def_id"];
+
+/// High level description of a proof request, see `indy_verifier_build_proof_request`.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ProofRequestTemplate {
+    pub name: Option<String>,
+    pub version: Option<String>,
+    #[serde(default)]
+    pub attributes: Vec<AttributeTemplate>,
+    #[serde(default)]
+    pub predicates: Vec<PredicateTemplate>,
+    #[serde(default)]
+    pub issuer_dids: Vec<DidValue>,
+    #[serde(default)]
+    pub schema_ids: Vec<SchemaId>,
+    #[serde(default)]
+    pub cred_def_ids: Vec<CredentialDefinitionId>,
+    pub non_revoked: Option<NonRevocedInterval>,
+    /// Width of non-revocation interval ending at the time of building, in seconds.
+    pub freshness: Option<u64>,
+    pub identifiers: Option<IdentifiersFormat>,
+    pub method: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AttributeTemplate {
+    pub referent: Option<String>,
+    pub name: Option<String>,
+    pub names: Option<Vec<String>>,
+    pub restrictions: Option<Query>,
+    pub non_revoked: Option<NonRevocedInterval>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PredicateTemplate {
+    pub referent: Option<String>,
+    pub name: String,
+    pub p_type: PredicateTypes,
+    pub p_value: i32,
+    pub restrictions: Option<Query>,
+    pub non_revoked: Option<NonRevocedInterval>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum IdentifiersFormat {
+    Qualified,
+    Unqualified,
+}
+
+impl Validatable for ProofRequestTemplate {
+    fn validate(&self) -> Result<(), String> {
+        if self.non_revoked.is_some() && self.freshness.is_some() {
+            return Err(String::from("Proof Request template validation failed: `non_revoked` and `freshness` can't be used together"));
+        }
+
+        if self.method.is_some() && self.identifiers != Some(IdentifiersFormat::Qualified) {
+            return Err(String::from("Proof Request template validation failed: `method` can be used only with \"qualified\" identifiers"));
+        }
+
+        _check_interval(&self.non_revoked)?;
+
+        for attribute in self.attributes.iter() {
+            _check_interval(&attribute.non_revoked)?;
+
+            if let Some(ref restrictions) = attribute.restrictions {
+                _check_restrictions(restrictions)?;
+            }
+        }
+
+        for predicate in self.predicates.iter() {
+            _check_interval(&predicate.non_revoked)?;
+
+            if let Some(ref restrictions) = predicate.restrictions {
+                _check_restrictions(restrictions)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn _check_interval(interval: &Option<NonRevocedInterval>) -> Result<(), String> {
+    match interval {
+        Some(NonRevocedInterval { from: None, to: None }) =>
+            Err(String::from("Proof Request template validation failed: `non_revoked` interval has neither `from` nor `to`")),
+        Some(NonRevocedInterval { from: Some(from), to: Some(to) }) if from > to =>
+            Err(format!("Proof Request template validation failed: `non_revoked` interval starts at {} after its end at {}", from, to)),
+        _ => Ok(())
+    }
+}
+
+// Only operators and tags the verifier can check against a proof are allowed.
+fn _check_restrictions(restrictions: &Query) -> Result<(), String> {
+    let check_tag = |tag_name: &str| -> Result<(), String> {
+        let is_attr_tag = tag_name.starts_with("attr::") && (tag_name.ends_with("::value") || tag_name.ends_with("::marker"));
+
+        if SUPPORTED_RESTRICTION_TAGS.contains(&tag_name) || is_attr_tag {
+            Ok(())
+        } else {
+            Err(format!("Proof Request template validation failed: unsupported restriction tag \"{}\"", tag_name))
+        }
+    };
+
+    match restrictions {
+        Query::Eq(ref tag_name, _) | Query::Neq(ref tag_name, _) | Query::In(ref tag_name, _) => check_tag(tag_name),
+        Query::And(ref operators) | Query::Or(ref operators) => {
+            operators.iter().map(_check_restrictions).collect::<Result<Vec<()>, String>>()?;
+            Ok(())
+        }
+        Query::Not(ref operator) => _check_restrictions(operator),
+        _ => Err(format!("Proof Request template validation failed: unsupported restriction operator in {}", restrictions.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _template(value: serde_json::Value) -> ProofRequestTemplate {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn proof_request_template_validate_works() {
+        _template(json!({
+            "attributes": [{"name": "name", "restrictions": {"$or": [{"issuer_did": "NcYxiDXkpYi6ov5FcYDi1e"}, {"attr::name::value": "Alex"}]}}],
+            "predicates": [{"name": "age", "p_type": ">=", "p_value": 18}],
+            "non_revoked": {"from": 10, "to": 20}
+        })).validate().unwrap();
+    }
+
+    #[test]
+    fn proof_request_template_validate_works_for_unsupported_operator() {
+        _template(json!({
+            "attributes": [{"name": "name", "restrictions": {"schema_version": {"$gt": "1.0"}}}]
+        })).validate().unwrap_err();
+    }
+
+    #[test]
+    fn proof_request_template_validate_works_for_unsupported_tag() {
+        _template(json!({
+            "predicates": [{"name": "age", "p_type": ">=", "p_value": 18, "restrictions": {"rev_reg_id": "id"}}]
+        })).validate().unwrap_err();
+    }
+
+    #[test]
+    fn proof_request_template_validate_works_for_invalid_interval() {
+        _template(json!({
+            "attributes": [{"name": "name", "non_revoked": {"from": 20, "to": 10}}]
+        })).validate().unwrap_err();
+
+        _template(json!({
+            "attributes": [{"name": "name"}],
+            "non_revoked": {"from": 10, "to": 20},
+            "freshness": 100
+        })).validate().unwrap_err();
+    }
+}
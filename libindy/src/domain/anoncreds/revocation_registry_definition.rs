@@ -38,7 +38,7 @@ impl IssuanceType {
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Deserialize, Debug, Serialize, PartialEq)]
+#[derive(Deserialize, Debug, Serialize, PartialEq, Clone)]
 pub enum RegistryType {
     CL_ACCUM,
 }
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use ursa::cl::RevocationRegistryDelta as RegistryDelta;
 
 use indy_api_types::validation::Validatable;
@@ -23,4 +25,13 @@ impl From<RevocationRegistryDelta> for RevocationRegistryDeltaV1 {
     }
 }
 
-impl Validatable for RevocationRegistryDelta {}
\ No newline at end of file
+impl Validatable for RevocationRegistryDelta {}
+
+/// Indices changed by the delta as they are published on the ledger in `value.issued` and `value.revoked`.
+#[derive(Debug, Default, Deserialize)]
+pub struct RevocationRegistryDeltaIndices {
+    #[serde(default)]
+    pub issued: HashSet<u32>,
+    #[serde(default)]
+    pub revoked: HashSet<u32>,
+}
\ No newline at end of file
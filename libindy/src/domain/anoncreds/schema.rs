@@ -18,6 +18,19 @@ pub struct SchemaV1 {
     #[serde(rename = "attrNames")]
     pub attr_names: AttributeNames,
     pub seq_no: Option<u32>,
+    // Not a part of the ledger transaction, so it is kept only by parties exchanging the schema off-ledger.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attr_types: Option<AttributeTypes>,
+}
+
+impl SchemaV1 {
+    pub fn attr_type(&self, attr: &str) -> Option<AttributeType> {
+        let attr = _attr_common_view(attr);
+
+        self.attr_types.as_ref()
+            .and_then(|attr_types| attr_types.iter().find(|(name, _)| _attr_common_view(name) == attr))
+            .map(|(_, attr_type)| *attr_type)
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -37,6 +50,7 @@ impl Schema {
                     version: schema.version,
                     attr_names: schema.attr_names,
                     seq_no: schema.seq_no,
+                    attr_types: schema.attr_types,
                 })
             }
         }
@@ -79,11 +93,37 @@ impl Into<HashSet<String>> for AttributeNames {
     }
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum AttributeType {
+    // 32-bit integer encoded as itself
+    Integer,
+    // "YYYY-MM-DD" encoded as number of days since 1970-01-01
+    Date,
+    // "true" or "false" encoded as 1 or 0
+    Boolean,
+    // any value encoded as SHA-256 hash even if it looks like an integer
+    String,
+}
+
+pub type AttributeTypes = HashMap<String, AttributeType>;
+
+fn _attr_common_view(attr: &str) -> String {
+    attr.replace(" ", "").to_lowercase()
+}
+
 impl Validatable for Schema {
     fn validate(&self) -> Result<(), String> {
         match self {
             Schema::SchemaV1(schema) => {
                 schema.attr_names.validate()?;
+                if let Some(ref attr_types) = schema.attr_types {
+                    for name in attr_types.keys() {
+                        if !schema.attr_names.0.iter().any(|attr| _attr_common_view(attr) == _attr_common_view(name)) {
+                            return Err(format!("Type is declared for attribute \"{}\" that is not in the Schema", name));
+                        }
+                    }
+                }
                 schema.id.validate()?;
                 if let Some((_, name, version)) = schema.id.parts() {
                     if name != schema.name {
@@ -342,5 +382,40 @@ mod tests {
             let schema: Schema = serde_json::from_str(&schema_json).unwrap();
             schema.validate().unwrap_err();
         }
+
+        #[test]
+        fn test_valid_typed_schema() {
+            let schema_json = json!({
+                "id": _schema_id_qualified(),
+                "name": "gvt",
+                "ver": "1.0",
+                "version": "1.0",
+                "attrNames": ["name", "dob", "age"],
+                "attrTypes": {"dob": "date", "Age": "integer"},
+            }).to_string();
+
+            let schema: Schema = serde_json::from_str(&schema_json).unwrap();
+            schema.validate().unwrap();
+
+            let schema = SchemaV1::from(schema);
+            assert_eq!(Some(AttributeType::Date), schema.attr_type("dob"));
+            assert_eq!(Some(AttributeType::Integer), schema.attr_type("age"));
+            assert_eq!(None, schema.attr_type("name"));
+        }
+
+        #[test]
+        fn test_invalid_typed_schema_for_unknown_attribute() {
+            let schema_json = json!({
+                "id": _schema_id_qualified(),
+                "name": "gvt",
+                "ver": "1.0",
+                "version": "1.0",
+                "attrNames": ["name", "age"],
+                "attrTypes": {"dob": "date"},
+            }).to_string();
+
+            let schema: Schema = serde_json::from_str(&schema_json).unwrap();
+            schema.validate().unwrap_err();
+        }
     }
 }
\ No newline at end of file
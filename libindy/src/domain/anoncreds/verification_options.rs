@@ -0,0 +1,5 @@
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct VerifyProofOptions {
+    #[serde(default)]
+    pub check_raw_encoding: bool,
+}
@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+use super::credential_definition::CredentialDefinitionId;
+use super::proof::Identifier;
+use super::proof_request::NonRevocedInterval;
+use super::revocation_registry_definition::RevocationRegistryId;
+use super::schema::SchemaId;
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum VerificationFailureReason {
+    MissingReferent,
+    UnexpectedReferent,
+    RestrictionMismatch,
+    RevealedAttributeMismatch,
+    EncodedValueMismatch,
+    RawValueMismatch,
+    MissingTimestamp,
+    TimestampOutsideInterval,
+    MissingSchema,
+    MissingCredentialDefinition,
+    MissingRevocationRegistryDefinition,
+    MissingRevocationRegistry,
+    CryptoVerificationFailed,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct VerificationFailure {
+    pub reason: VerificationFailureReason,
+    pub message: String,
+}
+
+impl VerificationFailure {
+    pub fn new(reason: VerificationFailureReason, message: &str) -> VerificationFailure {
+        VerificationFailure {
+            reason,
+            message: message.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct ReferentReport {
+    pub valid: bool,
+    pub sub_proof_index: Option<u32>,
+    #[serde(default)]
+    pub revealed: bool,
+    #[serde(default)]
+    pub self_attested: bool,
+    pub timestamp: Option<u64>,
+    pub non_revoked: Option<NonRevocedInterval>,
+    #[serde(default)]
+    pub failures: Vec<VerificationFailure>,
+}
+
+impl ReferentReport {
+    pub fn new() -> ReferentReport {
+        ReferentReport {
+            valid: true,
+            sub_proof_index: None,
+            revealed: false,
+            self_attested: false,
+            timestamp: None,
+            non_revoked: None,
+            failures: Vec::new(),
+        }
+    }
+
+    pub fn fail(&mut self, reason: VerificationFailureReason, message: &str) {
+        self.valid = false;
+        self.failures.push(VerificationFailure::new(reason, message));
+    }
+
+    // `verify_proof` only requires a timestamp to be present, the detailed report also checks that it is inside the interval.
+    pub fn check_interval(&mut self) {
+        if let (Some(timestamp), Some(interval)) = (self.timestamp, self.non_revoked.clone()) {
+            if !interval.from.map(|from| from <= timestamp).unwrap_or(true) || !interval.to.map(|to| timestamp <= to).unwrap_or(true) {
+                self.fail(VerificationFailureReason::TimestampOutsideInterval,
+                          &format!("Timestamp {} is outside of requested non-revocation interval {:?}", timestamp, interval));
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct SubProofReport {
+    pub sub_proof_index: u32,
+    pub schema_id: SchemaId,
+    pub cred_def_id: CredentialDefinitionId,
+    pub rev_reg_id: Option<RevocationRegistryId>,
+    pub timestamp: Option<u64>,
+    pub non_revocation_checked: bool,
+    #[serde(default)]
+    pub failures: Vec<VerificationFailure>,
+}
+
+impl SubProofReport {
+    pub fn new(sub_proof_index: u32, identifier: &Identifier) -> SubProofReport {
+        SubProofReport {
+            sub_proof_index,
+            schema_id: identifier.schema_id.clone(),
+            cred_def_id: identifier.cred_def_id.clone(),
+            rev_reg_id: identifier.rev_reg_id.clone(),
+            timestamp: identifier.timestamp,
+            non_revocation_checked: false,
+            failures: Vec::new(),
+        }
+    }
+
+    pub fn fail(&mut self, reason: VerificationFailureReason, message: &str) {
+        self.failures.push(VerificationFailure::new(reason, message));
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct VerificationReport {
+    pub valid: bool,
+    #[serde(default)]
+    pub requested_attributes: HashMap<String, ReferentReport>,
+    #[serde(default)]
+    pub requested_predicates: HashMap<String, ReferentReport>,
+    #[serde(default)]
+    pub sub_proofs: Vec<SubProofReport>,
+    #[serde(default)]
+    pub failures: Vec<VerificationFailure>,
+}
+
+impl VerificationReport {
+    pub fn fail(&mut self, reason: VerificationFailureReason, message: &str) {
+        self.failures.push(VerificationFailure::new(reason, message));
+    }
+
+    pub fn finalize(&mut self) {
+        self.valid = self.failures.is_empty() &&
+            self.requested_attributes.values().all(|report| report.valid) &&
+            self.requested_predicates.values().all(|report| report.valid) &&
+            self.sub_proofs.iter().all(|report| report.failures.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn referent_report_check_interval_works() {
+        let mut report = ReferentReport::new();
+        report.timestamp = Some(1234);
+        report.non_revoked = Some(NonRevocedInterval { from: Some(1000), to: Some(1234) });
+        report.check_interval();
+        assert!(report.valid);
+
+        report.non_revoked = Some(NonRevocedInterval { from: None, to: Some(1233) });
+        report.check_interval();
+        assert!(!report.valid);
+        assert_eq!(VerificationFailureReason::TimestampOutsideInterval, report.failures[0].reason);
+    }
+}
@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+
+use ursa::cl::{
+    CredentialSignature,
+    RevocationRegistry,
+    SignatureCorrectnessProof,
+    Witness
+};
+
+use indy_api_types::validation::Validatable;
+
+use super::credential::ShortCredentialValues;
+use super::credential_definition::CredentialDefinitionId;
+use super::proof::RequestedProof;
+use super::revocation_registry_definition::RevocationRegistryId;
+use super::schema::SchemaId;
+
+pub const W3C_CREDENTIALS_CONTEXT: &str = "https://www.w3.org/2018/credentials/v1";
+pub const ANONCREDS_CONTEXT: &str = "https://raw.githubusercontent.com/hyperledger/anoncreds-spec/main/data/anoncreds-w3c-context.json";
+
+pub const W3C_CREDENTIAL_TYPE: &str = "VerifiableCredential";
+pub const W3C_PRESENTATION_TYPE: &str = "VerifiablePresentation";
+pub const ANONCREDS_CREDENTIAL_TYPE: &str = "AnonCredsCredential";
+pub const ANONCREDS_PRESENTATION_TYPE: &str = "AnonCredsPresentation";
+
+pub const ANONCREDS_DEFINITION_TYPE: &str = "AnonCredsDefinition";
+pub const ANONCREDS_PROOF_TYPE: &str = "AnonCredsProof2023";
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct W3CCredentialSchema {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub definition: CredentialDefinitionId,
+    pub schema: SchemaId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revocation: Option<RevocationRegistryId>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct W3CProof {
+    #[serde(rename = "type")]
+    pub type_: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub challenge: Option<String>,
+    pub proof_value: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct W3CCredential {
+    #[serde(rename = "@context")]
+    pub context: Vec<String>,
+    #[serde(rename = "type")]
+    pub type_: Vec<String>,
+    pub issuer: String,
+    pub issuance_date: String,
+    pub credential_schema: W3CCredentialSchema,
+    pub credential_subject: ShortCredentialValues,
+    pub proof: W3CProof,
+}
+
+/// CL data of a credential that has no counterpart in the W3C data model.
+/// Carried base64-encoded in the `proofValue` of the credential.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CredentialProofValue {
+    pub encoded: HashMap<String, String>,
+    pub signature: CredentialSignature,
+    pub signature_correctness_proof: SignatureCorrectnessProof,
+    pub rev_reg: Option<RevocationRegistry>,
+    pub witness: Option<Witness>,
+}
+
+/// Sub proof of a single credential in a presentation together with its identifier timestamp.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PresentedCredentialProofValue {
+    pub sub_proof: serde_json::Value,
+    pub timestamp: Option<u64>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct W3CPresentation {
+    #[serde(rename = "@context")]
+    pub context: Vec<String>,
+    #[serde(rename = "type")]
+    pub type_: Vec<String>,
+    pub verifiable_credential: Vec<W3CCredential>,
+    pub proof: W3CProof,
+}
+
+/// Aggregated proof and requested proof mapping of a presentation.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PresentationProofValue {
+    pub aggregated_proof: serde_json::Value,
+    pub requested_proof: RequestedProof,
+}
+
+fn _validate_context_and_type(context: &[String], type_: &[String], expected_type: &str) -> Result<(), String> {
+    if !context.iter().any(|context| context == W3C_CREDENTIALS_CONTEXT) {
+        return Err(format!("`@context` must contain \"{}\"", W3C_CREDENTIALS_CONTEXT));
+    }
+
+    if !type_.iter().any(|type_| type_ == expected_type) {
+        return Err(format!("`type` must contain \"{}\"", expected_type));
+    }
+
+    Ok(())
+}
+
+fn _validate_issuance_date(issuance_date: &str) -> Result<(), String> {
+    if issuance_date.is_empty() {
+        return Err(String::from("`issuanceDate` is empty"));
+    }
+
+    Ok(())
+}
+
+fn _validate_proof_type(proof: &W3CProof) -> Result<(), String> {
+    if proof.type_ != ANONCREDS_PROOF_TYPE {
+        return Err(format!("Unsupported proof type \"{}\", expected \"{}\"", proof.type_, ANONCREDS_PROOF_TYPE));
+    }
+
+    Ok(())
+}
+
+impl Validatable for W3CCredentialSchema {
+    fn validate(&self) -> Result<(), String> {
+        if self.type_ != ANONCREDS_DEFINITION_TYPE {
+            return Err(format!("Unsupported credential schema type \"{}\", expected \"{}\"", self.type_, ANONCREDS_DEFINITION_TYPE));
+        }
+
+        self.definition.validate()?;
+        self.schema.validate()?;
+
+        if let Some(ref revocation) = self.revocation {
+            revocation.validate()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable for W3CCredential {
+    fn validate(&self) -> Result<(), String> {
+        _validate_context_and_type(&self.context, &self.type_, W3C_CREDENTIAL_TYPE)
+            .map_err(|err| format!("W3C Credential validation failed: {}", err))?;
+        _validate_issuance_date(&self.issuance_date)
+            .map_err(|err| format!("W3C Credential validation failed: {}", err))?;
+        _validate_proof_type(&self.proof)
+            .map_err(|err| format!("W3C Credential validation failed: {}", err))?;
+        self.credential_schema.validate()?;
+
+        if self.credential_subject.is_empty() {
+            return Err(String::from("W3C Credential validation failed: `credentialSubject` is empty"));
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable for W3CPresentation {
+    fn validate(&self) -> Result<(), String> {
+        _validate_context_and_type(&self.context, &self.type_, W3C_PRESENTATION_TYPE)
+            .map_err(|err| format!("W3C Presentation validation failed: {}", err))?;
+        _validate_proof_type(&self.proof)
+            .map_err(|err| format!("W3C Presentation validation failed: {}", err))?;
+
+        for credential in self.verifiable_credential.iter() {
+            _validate_context_and_type(&credential.context, &credential.type_, W3C_CREDENTIAL_TYPE)
+                .map_err(|err| format!("W3C Presentation validation failed: {}", err))?;
+            _validate_issuance_date(&credential.issuance_date)
+                .map_err(|err| format!("W3C Presentation validation failed: {}", err))?;
+            _validate_proof_type(&credential.proof)
+                .map_err(|err| format!("W3C Presentation validation failed: {}", err))?;
+            credential.credential_schema.validate()?;
+        }
+
+        Ok(())
+    }
+}
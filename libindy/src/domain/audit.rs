@@ -0,0 +1,279 @@
+use std::collections::{BTreeMap, HashSet};
+
+use indy_api_types::domain::wallet::Tags;
+use indy_api_types::errors::prelude::*;
+use sha2::Sha256;
+use sha2::digest::{FixedOutput, Update};
+
+use crate::domain::{sortable_u64, to_canonical_json};
+
+/// Hash the first event of a chain refers to.
+pub const AUDIT_CHAIN_GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+pub const AUDIT_CHAIN_HEAD_ID: &str = "head";
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditEventType {
+    KeyCreated,
+    DidCreated,
+    MessageSigned,
+    RequestSigned,
+    KeysRotationStarted,
+    KeysRotated,
+    WalletExported,
+    WalletMigrated,
+    CredentialIssued,
+    CredentialRevoked,
+}
+
+impl AuditEventType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditEventType::KeyCreated => "key_created",
+            AuditEventType::DidCreated => "did_created",
+            AuditEventType::MessageSigned => "message_signed",
+            AuditEventType::RequestSigned => "request_signed",
+            AuditEventType::KeysRotationStarted => "keys_rotation_started",
+            AuditEventType::KeysRotated => "keys_rotated",
+            AuditEventType::WalletExported => "wallet_exported",
+            AuditEventType::WalletMigrated => "wallet_migrated",
+            AuditEventType::CredentialIssued => "credential_issued",
+            AuditEventType::CredentialRevoked => "credential_revoked",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct AuditConfig {
+    /// Append events to the wallet the operation was performed with.
+    #[serde(default)]
+    pub wallet: bool,
+    /// Event types to record, all if not set.
+    pub events: Option<HashSet<AuditEventType>>,
+}
+
+impl AuditConfig {
+    pub fn is_recorded(&self, event: AuditEventType) -> bool {
+        self.events.as_ref().map(|events| events.contains(&event)).unwrap_or(true)
+    }
+}
+
+/// Security relevant operation linked into a hash chain.
+/// `hash` is sha256 of the canonical json of all other fields, including `prev_hash`.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct AuditEvent {
+    pub seq_no: u64,
+    pub timestamp: u64,
+    pub event: AuditEventType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub did: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verkey: Option<String>,
+    pub details: BTreeMap<String, String>,
+    pub prev_hash: String,
+    #[serde(default)]
+    pub hash: String,
+}
+
+impl AuditEvent {
+    pub fn new(event: AuditEventType) -> AuditEvent {
+        AuditEvent {
+            seq_no: 0,
+            timestamp: 0,
+            event,
+            did: None,
+            verkey: None,
+            details: BTreeMap::new(),
+            prev_hash: String::new(),
+            hash: String::new(),
+        }
+    }
+
+    pub fn did(mut self, did: &str) -> AuditEvent {
+        self.did = Some(did.to_string());
+        self
+    }
+
+    pub fn verkey(mut self, verkey: &str) -> AuditEvent {
+        self.verkey = Some(verkey.to_string());
+        self
+    }
+
+    pub fn detail(mut self, name: &str, value: &str) -> AuditEvent {
+        self.details.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// Places the event after `head` of the chain and seals it with its hash.
+    pub fn chain(mut self, head: &AuditChainHead, timestamp: u64) -> IndyResult<AuditEvent> {
+        self.seq_no = head.seq_no + 1;
+        self.timestamp = timestamp;
+        self.prev_hash = head.hash.clone();
+        self.hash = self.compute_hash()?;
+        Ok(self)
+    }
+
+    pub fn compute_hash(&self) -> IndyResult<String> {
+        let mut value = serde_json::to_value(self)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize AuditEvent")?;
+
+        if let Some(object) = value.as_object_mut() {
+            object.remove("hash");
+        }
+
+        Ok(sha256_hex(to_canonical_json(&value)?.as_bytes()))
+    }
+
+    pub fn tags(&self) -> Tags {
+        let mut tags = Tags::new();
+        tags.insert("event".to_string(), self.event.as_str().to_string());
+        if let Some(ref did) = self.did {
+            tags.insert("did".to_string(), did.clone());
+        }
+        if let Some(ref verkey) = self.verkey {
+            tags.insert("verkey".to_string(), verkey.clone());
+        }
+        tags.insert("~timestamp".to_string(), sortable_u64(self.timestamp));
+        tags
+    }
+}
+
+/// Signed data isn't put into events, only its hash.
+pub fn sha256_hex(data: &[u8]) -> String {
+    let mut hasher = Sha256::default();
+    hasher.update(data);
+    hex::encode(hasher.finalize_fixed().as_slice())
+}
+
+/// Last event of a hash chain.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct AuditChainHead {
+    pub seq_no: u64,
+    pub hash: String,
+}
+
+impl Default for AuditChainHead {
+    fn default() -> AuditChainHead {
+        AuditChainHead {
+            seq_no: 0,
+            hash: AUDIT_CHAIN_GENESIS_HASH.to_string(),
+        }
+    }
+}
+
+impl AuditChainHead {
+    pub fn of(event: &AuditEvent) -> AuditChainHead {
+        AuditChainHead {
+            seq_no: event.seq_no,
+            hash: event.hash.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct AuditLogVerification {
+    pub valid: bool,
+    pub count: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub broken_at: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl AuditLogVerification {
+    /// Checks that events ordered by `seq_no` form an unbroken chain ending at `head`.
+    pub fn verify(events: &[AuditEvent], head: &AuditChainHead) -> IndyResult<AuditLogVerification> {
+        let mut expected = AuditChainHead::default();
+
+        for event in events {
+            let broken = |reason: &str| Ok(AuditLogVerification {
+                valid: false,
+                count: events.len() as u64,
+                broken_at: Some(expected.seq_no + 1),
+                reason: Some(reason.to_string()),
+            });
+
+            if event.seq_no != expected.seq_no + 1 {
+                return broken("missing event");
+            }
+
+            if event.prev_hash != expected.hash {
+                return broken("previous hash mismatch");
+            }
+
+            if event.hash != event.compute_hash()? {
+                return broken("event hash mismatch");
+            }
+
+            expected = AuditChainHead::of(event);
+        }
+
+        if expected != *head {
+            return Ok(AuditLogVerification {
+                valid: false,
+                count: events.len() as u64,
+                broken_at: Some(expected.seq_no + 1),
+                reason: Some("chain head mismatch".to_string()),
+            });
+        }
+
+        Ok(AuditLogVerification {
+            valid: true,
+            count: events.len() as u64,
+            broken_at: None,
+            reason: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _chain() -> (Vec<AuditEvent>, AuditChainHead) {
+        let mut head = AuditChainHead::default();
+        let mut events = Vec::new();
+
+        for (i, event) in [AuditEventType::KeyCreated, AuditEventType::MessageSigned, AuditEventType::WalletExported].iter().enumerate() {
+            let event = AuditEvent::new(*event).verkey("verkey").chain(&head, 100 + i as u64).unwrap();
+            head = AuditChainHead::of(&event);
+            events.push(event);
+        }
+
+        (events, head)
+    }
+
+    #[test]
+    fn audit_chain_verify_works() {
+        let (events, head) = _chain();
+
+        assert_eq!(3, head.seq_no);
+        assert_eq!(AUDIT_CHAIN_GENESIS_HASH, events[0].prev_hash);
+        assert_eq!(events[1].hash, events[2].prev_hash);
+
+        let verification = AuditLogVerification::verify(&events, &head).unwrap();
+        assert!(verification.valid);
+        assert_eq!(3, verification.count);
+    }
+
+    #[test]
+    fn audit_chain_verify_works_for_modified_event() {
+        let (mut events, head) = _chain();
+        events[1].details.insert("message_hash".to_string(), "forged".to_string());
+
+        let verification = AuditLogVerification::verify(&events, &head).unwrap();
+        assert!(!verification.valid);
+        assert_eq!(Some(2), verification.broken_at);
+    }
+
+    #[test]
+    fn audit_chain_verify_works_for_removed_event() {
+        let (mut events, head) = _chain();
+
+        events.remove(1);
+        assert_eq!(Some(2), AuditLogVerification::verify(&events, &head).unwrap().broken_at);
+
+        events.pop();
+        assert_eq!(Some(2), AuditLogVerification::verify(&events, &head).unwrap().broken_at);
+    }
+}
@@ -11,4 +11,13 @@ pub struct GetCacheOptions {
     pub no_update: Option<bool>,    // Use only cached data, do not try to update.
     pub no_store: Option<bool>,     // Skip storing fresh data if updated
     pub min_fresh: Option<i32>,     // Return cached data if not older than this many seconds. -1 means do not check age.
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CachedRevocRegDelta {
+    pub rev_reg_def_id: String,
+    pub rev_reg_delta: String,
+    pub timestamp: u64, // timestamp of the accumulator
+    pub to: i64, // `to` the delta has been requested with
+}
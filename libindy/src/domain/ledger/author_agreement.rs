@@ -2,6 +2,7 @@ use std::collections::HashMap;
 
 use indy_api_types::validation::Validatable;
 
+use super::response::ReplyType;
 use super::constants::{GET_TXN_AUTHR_AGRMT, GET_TXN_AUTHR_AGRMT_AML, TXN_AUTHR_AGRMT, TXN_AUTHR_AGRMT_AML, DISABLE_ALL_TXN_AUTHR_AGRMTS};
 
 #[derive(Serialize, PartialEq, Debug)]
@@ -85,6 +86,17 @@ impl GetTxnAuthorAgreementOperation {
     }
 }
 
+#[derive(Deserialize, Debug)]
+pub struct GetTxnAuthorAgreementResult {
+    pub data: Option<::serde_json::Value>,
+}
+
+impl ReplyType for GetTxnAuthorAgreementResult {
+    fn get_type<'a>() -> &'a str {
+        GET_TXN_AUTHR_AGRMT
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub struct AcceptanceMechanisms(pub HashMap<String, ::serde_json::Value>);
 
@@ -0,0 +1,19 @@
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone, Copy)]
+pub enum MetricsFormat {
+    #[serde(rename = "json")]
+    Json,
+    #[serde(rename = "openmetrics")]
+    OpenMetrics,
+}
+
+impl Default for MetricsFormat {
+    fn default() -> Self {
+        MetricsFormat::Json
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct CollectMetricsOptions {
+    #[serde(default)]
+    pub format: MetricsFormat,
+}
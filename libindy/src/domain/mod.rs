@@ -4,7 +4,10 @@ pub mod ledger;
 pub mod pairwise;
 pub mod pool;
 pub mod cache;
+pub mod metrics;
+pub mod audit;
 
+use indy_api_types::errors::prelude::*;
 use indy_api_types::validation::Validatable;
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -14,4 +17,16 @@ pub struct IndyConfig {
     pub freshness_threshold: Option<u64>
 }
 
-impl Validatable for IndyConfig {}
\ No newline at end of file
+impl Validatable for IndyConfig {}
+
+/// Fixed width, so lexicographical order of wallet tag values and record ids is the numeric order.
+pub fn sortable_u64(value: u64) -> String {
+    format!("{:020}", value)
+}
+
+/// serde_json::Value keeps keys sorted, so the same data always gets the same json.
+pub fn to_canonical_json<T: serde::Serialize>(value: &T) -> IndyResult<String> {
+    Ok(serde_json::to_value(value)
+        .to_indy(IndyErrorKind::InvalidState, "Cannot serialize to canonical json")?
+        .to_string())
+}
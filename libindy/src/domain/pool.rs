@@ -1,3 +1,6 @@
+use std::collections::BTreeMap;
+use std::time::Instant;
+
 use indy_api_types::validation::Validatable;
 
 pub const POOL_CON_ACTIVE_TO: i64 = 5;
@@ -97,3 +100,60 @@ impl PoolOpenConfig {
 
     fn default_socks_proxy() -> String { String::new() }
 }
+
+/// State of the last open or refresh of the pool.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PoolOpenState {
+    NotOpened,
+    InProgress,
+    CatchingUp,
+    Synced,
+    Failed,
+}
+
+/// What the node has replied to the ledger status sent on catchup start.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NodeReplyStatus {
+    Pending,
+    LedgerStatus,
+    ConsistencyProof,
+    Timeout,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PoolNodeStatus {
+    pub address: String,
+    pub bls_key: bool,
+    pub reply: NodeReplyStatus,
+    pub txn_count: Option<usize>,
+    pub merkle_root: Option<String>,
+    pub rtt_ms: Option<u64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PoolStatus {
+    pub state: PoolOpenState,
+    pub error: Option<String>,
+    pub txn_count: Option<usize>,
+    pub merkle_root: Option<String>,
+    pub elapsed_ms: Option<u64>,
+    pub nodes: BTreeMap<String, PoolNodeStatus>,
+    #[serde(skip)]
+    pub started: Option<Instant>,
+}
+
+impl Default for PoolStatus {
+    fn default() -> Self {
+        PoolStatus {
+            state: PoolOpenState::NotOpened,
+            error: None,
+            txn_count: None,
+            merkle_root: None,
+            elapsed_ms: None,
+            nodes: BTreeMap::new(),
+            started: None,
+        }
+    }
+}
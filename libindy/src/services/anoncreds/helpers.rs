@@ -1,11 +1,11 @@
 use indy_api_types::errors::prelude::*;
 
-use crate::domain::anoncreds::credential::AttributeValues;
+use crate::domain::anoncreds::credential::{AttributeValues, ShortCredentialValues};
 use crate::domain::anoncreds::proof_request::{AttributeInfo, PredicateInfo, NonRevocedInterval};
 use ursa::cl::{issuer, verifier, CredentialSchema, NonCredentialSchema, MasterSecret, CredentialValues, SubProofRequest};
 
 use crate::domain::crypto::did::DidValue;
-use crate::domain::anoncreds::schema::SchemaId;
+use crate::domain::anoncreds::schema::{SchemaId, SchemaV1, AttributeType};
 use crate::domain::anoncreds::credential_definition::CredentialDefinitionId;
 use crate::domain::anoncreds::revocation_registry_definition::RevocationRegistryId;
 use crate::domain::anoncreds::schema::Schema;
@@ -16,6 +16,11 @@ use crate::domain::anoncreds::credential_request::CredentialRequest;
 use crate::domain::anoncreds::proof_request::ProofRequest;
 
 use std::collections::{HashSet, HashMap};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use sha2::Sha256;
+use sha2::digest::{FixedOutput, Update};
+use ursa::bn::BigNumber;
 
 pub fn attr_common_view(attr: &str) -> String {
     attr.replace(" ", "").to_lowercase()
@@ -110,6 +115,124 @@ pub fn parse_cred_rev_id(cred_rev_id: &str) -> IndyResult<u32> {
     Ok(res)
 }
 
+// De facto standard encoding used by Indy ecosystem: 32-bit integers are encoded as themselves,
+// any other value is encoded as decimal representation of big-endian SHA-256 hash of its raw bytes.
+pub fn encode_credential_value(raw: &str) -> IndyResult<String> {
+    trace!("encode_credential_value >>> raw: {:?}", raw);
+
+    let res = match raw.parse::<i32>() {
+        Ok(value) => value.to_string(),
+        Err(_) => _hash_credential_value(raw)?
+    };
+
+    trace!("encode_credential_value <<< res: {:?}", res);
+
+    Ok(res)
+}
+
+// Encoding of values of attributes with declared type. Values that don't match the type are rejected.
+pub fn encode_typed_credential_value(raw: &str, attr_type: Option<AttributeType>) -> IndyResult<String> {
+    trace!("encode_typed_credential_value >>> raw: {:?}, attr_type: {:?}", raw, attr_type);
+
+    let res = match attr_type {
+        None => encode_credential_value(raw)?,
+        Some(AttributeType::Integer) => raw.parse::<i32>()
+            .to_indy(IndyErrorKind::InvalidStructure, format!("Value \"{}\" of integer attribute is not a 32-bit integer", raw))?
+            .to_string(),
+        Some(AttributeType::Date) => parse_date_to_days(raw)?.to_string(),
+        Some(AttributeType::Boolean) => match raw {
+            "true" => "1".to_string(),
+            "false" => "0".to_string(),
+            _ => return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Value \"{}\" of boolean attribute is neither \"true\" nor \"false\"", raw)))
+        },
+        Some(AttributeType::String) => _hash_credential_value(raw)?
+    };
+
+    trace!("encode_typed_credential_value <<< res: {:?}", res);
+
+    Ok(res)
+}
+
+fn _hash_credential_value(raw: &str) -> IndyResult<String> {
+    let mut hasher = Sha256::default();
+    hasher.update(raw.as_bytes());
+    Ok(BigNumber::from_bytes(hasher.finalize_fixed().as_slice())?.to_dec()?)
+}
+
+// Converts "YYYY-MM-DD" date to the number of days since 1970-01-01 (negative for earlier dates).
+pub fn parse_date_to_days(date: &str) -> IndyResult<i32> {
+    let invalid_date = || err_msg(IndyErrorKind::InvalidStructure, format!("Invalid date \"{}\", expected format is YYYY-MM-DD", date));
+
+    let parts: Vec<&str> = date.split('-').collect();
+
+    if parts.len() != 3 || parts[0].len() != 4 || parts[1].len() != 2 || parts[2].len() != 2
+        || !parts.iter().all(|part| part.chars().all(|c| c.is_ascii_digit())) {
+        return Err(invalid_date());
+    }
+
+    let year = parts[0].parse::<i64>().map_err(|_| invalid_date())?;
+    let month = parts[1].parse::<i64>().map_err(|_| invalid_date())?;
+    let day = parts[2].parse::<i64>().map_err(|_| invalid_date())?;
+
+    let is_leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year => 29,
+        2 => 28,
+        _ => return Err(invalid_date())
+    };
+
+    if day < 1 || day > days_in_month {
+        return Err(invalid_date());
+    }
+
+    // days from civil algorithm by Howard Hinnant
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    Ok((era * 146097 + day_of_era - 719468) as i32)
+}
+
+pub fn encode_credential_values(values: &ShortCredentialValues, schema: Option<&SchemaV1>) -> IndyResult<HashMap<String, AttributeValues>> {
+    trace!("encode_credential_values >>> values: {:?}, schema: {:?}", values, schema);
+
+    let res = values
+        .iter()
+        .map(|(attr, raw)| {
+            let encoded = encode_typed_credential_value(raw, schema.and_then(|schema| schema.attr_type(attr)))?;
+            Ok((attr.to_string(), AttributeValues { raw: raw.to_string(), encoded }))
+        })
+        .collect::<IndyResult<HashMap<String, AttributeValues>>>()?;
+
+    trace!("encode_credential_values <<< res: {:?}", res);
+
+    Ok(res)
+}
+
+pub fn is_credential_value_encoded(raw: &str, encoded: &str, attr_type: Option<AttributeType>) -> IndyResult<bool> {
+    let expected = match encode_typed_credential_value(raw, attr_type) {
+        Ok(expected) => expected,
+        Err(_) => return Ok(false)
+    };
+
+    let res = match BigNumber::from_dec(encoded) {
+        Ok(encoded) => BigNumber::from_dec(&expected)? == encoded,
+        Err(_) => false
+    };
+
+    Ok(res)
+}
+
+pub fn get_seconds_since_epoch() -> IndyResult<u64> {
+    SystemTime::now().duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .to_indy(IndyErrorKind::InvalidState, "Cannot get time since epoch")
+}
+
 pub fn get_non_revoc_interval(global_interval: &Option<NonRevocedInterval>, local_interval: &Option<NonRevocedInterval>) -> Option<NonRevocedInterval> {
     trace!("get_non_revoc_interval >>> global_interval: {:?}, local_interval: {:?}", global_interval, local_interval);
 
@@ -187,6 +310,99 @@ mod tests {
         assert_eq!(None, res);
     }
 
+    mod encode_credential_value {
+        use super::*;
+
+        #[test]
+        fn encode_credential_value_works_for_int32() {
+            assert_eq!("28", encode_credential_value("28").unwrap());
+            assert_eq!("0", encode_credential_value("0").unwrap());
+            assert_eq!("-5", encode_credential_value("-5").unwrap());
+            assert_eq!("2147483647", encode_credential_value("2147483647").unwrap());
+        }
+
+        #[test]
+        fn encode_credential_value_works_for_string() {
+            assert_eq!("5944657099558967239210949258394887428692050081607692519917050011144233115103", encode_credential_value("male").unwrap());
+            assert_eq!("99262857098057710338306967609588410025648622308394250666849665532448612202874", encode_credential_value("Alex").unwrap());
+            assert_eq!("102987336249554097029535212322581322789799900648198034993379397001115665086549", encode_credential_value("").unwrap());
+        }
+
+        #[test]
+        fn encode_credential_value_works_for_out_of_int32_range() {
+            assert_eq!("26221484005389514539852548961319751347124425277437769688639924217837557266135", encode_credential_value("2147483648").unwrap());
+            assert_eq!("71991296136747855077697001202532249706619088658469249105695717234028982732581", encode_credential_value("1.5").unwrap());
+        }
+
+        #[test]
+        fn encode_credential_values_works() {
+            let mut values = ShortCredentialValues::new();
+            values.insert("sex".to_string(), "male".to_string());
+            values.insert("age".to_string(), "28".to_string());
+
+            let res = encode_credential_values(&values, None).unwrap();
+            assert_eq!(AttributeValues { raw: "28".to_string(), encoded: "28".to_string() }, res["age"]);
+            assert_eq!("5944657099558967239210949258394887428692050081607692519917050011144233115103", res["sex"].encoded);
+        }
+
+        #[test]
+        fn is_credential_value_encoded_works() {
+            assert!(is_credential_value_encoded("28", "28", None).unwrap());
+            assert!(is_credential_value_encoded("male", "5944657099558967239210949258394887428692050081607692519917050011144233115103", None).unwrap());
+            assert!(!is_credential_value_encoded("female", "5944657099558967239210949258394887428692050081607692519917050011144233115103", None).unwrap());
+            assert!(!is_credential_value_encoded("28", "29", None).unwrap());
+            assert!(!is_credential_value_encoded("28", "not a number", None).unwrap());
+        }
+
+        #[test]
+        fn parse_date_to_days_works() {
+            assert_eq!(0, parse_date_to_days("1970-01-01").unwrap());
+            assert_eq!(13438, parse_date_to_days("2006-10-17").unwrap());
+            assert_eq!(11016, parse_date_to_days("2000-02-29").unwrap());
+            assert_eq!(-1, parse_date_to_days("1969-12-31").unwrap());
+            assert_eq!(-25508, parse_date_to_days("1900-03-01").unwrap());
+        }
+
+        #[test]
+        fn parse_date_to_days_works_for_invalid_date() {
+            assert_kind!(IndyErrorKind::InvalidStructure, parse_date_to_days("2006-10-32"));
+            assert_kind!(IndyErrorKind::InvalidStructure, parse_date_to_days("1900-02-29"));
+            assert_kind!(IndyErrorKind::InvalidStructure, parse_date_to_days("2006-1-17"));
+            assert_kind!(IndyErrorKind::InvalidStructure, parse_date_to_days("17.10.2006"));
+            assert_kind!(IndyErrorKind::InvalidStructure, parse_date_to_days("+006-10-17"));
+        }
+
+        #[test]
+        fn encode_typed_credential_value_works() {
+            assert_eq!("28", encode_typed_credential_value("28", Some(AttributeType::Integer)).unwrap());
+            assert_eq!("13438", encode_typed_credential_value("2006-10-17", Some(AttributeType::Date)).unwrap());
+            assert_eq!("1", encode_typed_credential_value("true", Some(AttributeType::Boolean)).unwrap());
+            assert_eq!("0", encode_typed_credential_value("false", Some(AttributeType::Boolean)).unwrap());
+            assert_eq!(encode_credential_value("male").unwrap(), encode_typed_credential_value("male", Some(AttributeType::String)).unwrap());
+            assert_eq!("28", encode_typed_credential_value("28", None).unwrap());
+        }
+
+        #[test]
+        fn encode_typed_credential_value_works_for_string_looking_like_integer() {
+            assert_eq!("40654426461387431593023896448597934904317945932538746522277789984804865638746",
+                       encode_typed_credential_value("28", Some(AttributeType::String)).unwrap());
+        }
+
+        #[test]
+        fn encode_typed_credential_value_works_for_invalid_values() {
+            assert_kind!(IndyErrorKind::InvalidStructure, encode_typed_credential_value("2147483648", Some(AttributeType::Integer)));
+            assert_kind!(IndyErrorKind::InvalidStructure, encode_typed_credential_value("yes", Some(AttributeType::Boolean)));
+            assert_kind!(IndyErrorKind::InvalidStructure, encode_typed_credential_value("2006/10/17", Some(AttributeType::Date)));
+        }
+
+        #[test]
+        fn is_credential_value_encoded_works_for_typed_values() {
+            assert!(is_credential_value_encoded("2006-10-17", "13438", Some(AttributeType::Date)).unwrap());
+            assert!(!is_credential_value_encoded("2006-10-17", "13438", None).unwrap());
+            assert!(!is_credential_value_encoded("not a date", "13438", Some(AttributeType::Date)).unwrap());
+        }
+    }
+
     mod to_unqualified {
         use super::*;
 
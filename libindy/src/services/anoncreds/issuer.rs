@@ -13,6 +13,8 @@ use ursa::cl::{
 };
 use ursa::cl::issuer::Issuer as CryptoIssuer;
 
+use std::collections::BTreeMap;
+
 use crate::domain::anoncreds::schema::AttributeNames;
 use crate::domain::anoncreds::credential::CredentialValues;
 use crate::domain::anoncreds::credential_definition::{CredentialDefinitionData, CredentialDefinitionV1 as CredentialDefinition};
@@ -20,6 +22,7 @@ use crate::domain::anoncreds::credential_request::CredentialRequest;
 use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefinitionV1, RevocationRegistryDefinitionValuePublicKeys};
 use crate::domain::crypto::did::DidValue;
 use indy_api_types::errors::prelude::*;
+use indy_utils::crypto::hash::hash as openssl_hash;
 use crate::services::anoncreds::helpers::*;
 
 pub struct Issuer {}
@@ -52,8 +55,7 @@ impl Issuer {
         Ok((credential_definition_value, credential_private_key, credential_key_correctness_proof))
     }
 
-    pub fn new_revocation_registry(&self,
-                                   cred_def: &CredentialDefinition,
+    pub fn new_revocation_registry(cred_def: &CredentialDefinition,
                                    max_cred_num: u32,
                                    issuance_by_default: bool,
                                    issuer_did: &DidValue) -> IndyResult<(RevocationRegistryDefinitionValuePublicKeys,
@@ -178,4 +180,45 @@ impl Issuer {
 
         Ok(rev_reg_delta)
     }
+
+    // Attributes are sorted, so the hash doesn't depend on the order of values in json.
+    pub fn credential_values_hash(&self, cred_values: &CredentialValues, salt: &[u8]) -> IndyResult<String> {
+        let values = cred_values.0.iter().collect::<BTreeMap<_, _>>();
+
+        let values = serde_json::to_vec(&values)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize CredentialValues")?;
+
+        Ok(hex::encode(openssl_hash(&[salt, values.as_slice()].concat())?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::domain::anoncreds::credential::AttributeValues;
+
+    #[test]
+    fn credential_values_hash_works() {
+        let cred_values: CredentialValues = serde_json::from_str(r#"{
+            "name": {"raw": "Alex", "encoded": "1139481716457488690172217916278103335"},
+            "age": {"raw": "28", "encoded": "28"}
+        }"#).unwrap();
+
+        let same_cred_values: CredentialValues = serde_json::from_str(r#"{
+            "age": {"raw": "28", "encoded": "28"},
+            "name": {"raw": "Alex", "encoded": "1139481716457488690172217916278103335"}
+        }"#).unwrap();
+
+        let mut other_cred_values = cred_values.clone();
+        other_cred_values.0.insert("age".to_string(), AttributeValues { raw: "29".to_string(), encoded: "29".to_string() });
+
+        let issuer = Issuer::new();
+        let hash = issuer.credential_values_hash(&cred_values, b"salt").unwrap();
+
+        assert_eq!(64, hash.len());
+        assert_eq!(hash, issuer.credential_values_hash(&same_cred_values, b"salt").unwrap());
+        assert_ne!(hash, issuer.credential_values_hash(&other_cred_values, b"salt").unwrap());
+        assert_ne!(hash, issuer.credential_values_hash(&cred_values, b"other salt").unwrap());
+    }
 }
@@ -15,23 +15,37 @@ use ursa::cl::verifier::Verifier as CryptoVerifier;
 
 use crate::domain::anoncreds::credential::{AttributeValues, Credential};
 use crate::domain::anoncreds::credential_attr_tag_policy::CredentialAttrTagPolicy;
+use crate::domain::anoncreds::credential_bundle::{CredentialBundle, CredentialBundlePayload, CREDENTIAL_BUNDLE_VERSION};
 use crate::domain::anoncreds::credential_definition::{CredentialDefinitionV1 as CredentialDefinition, CredentialDefinitionId};
 use crate::domain::anoncreds::credential_offer::CredentialOffer;
 use crate::domain::anoncreds::credential_request::CredentialRequestMetadata;
+use crate::domain::anoncreds::credential_status::{CREDENTIAL_STATUS_ACTIVE_TAG, CREDENTIAL_STATUS_EXPIRES_AT_TAG, CREDENTIAL_STATUS_FLAG,
+                                                  CREDENTIAL_STATUS_REPLACED_TAG, CREDENTIAL_STATUS_REVOKED_TAG};
 use crate::domain::anoncreds::proof::{Identifier, Proof, RequestedProof, RevealedAttributeInfo, SubProofReferent, RevealedAttributeGroupInfo, AttributeValue};
+use crate::domain::anoncreds::proof_request_diagnostics::DiagnosticsIssue;
 use crate::domain::anoncreds::proof_request::{PredicateInfo, PredicateTypes, ProofRequest, ProofRequestPayload, ProofRequestsVersion, RequestedAttributeInfo, RequestedPredicateInfo, ProofRequestExtraQuery};
 use crate::domain::anoncreds::requested_credential::ProvingCredentialKey;
 use crate::domain::anoncreds::requested_credential::RequestedCredentials;
 use crate::domain::anoncreds::revocation_registry_definition::RevocationRegistryDefinitionV1;
+use crate::domain::anoncreds::revocation_registry_delta::{RevocationRegistryDeltaIndices, RevocationRegistryDeltaV1};
 use crate::domain::anoncreds::revocation_state::RevocationState;
-use crate::domain::anoncreds::schema::{SchemaV1, SchemaId};
+use crate::domain::anoncreds::schema::{AttributeType, SchemaV1, SchemaId};
+use crate::domain::sortable_u64;
+use indy_api_types::domain::wallet::KeyDerivationMethod;
 use indy_api_types::errors::prelude::*;
+use indy_utils::crypto::{base64, chacha20poly1305_ietf, pwhash_argon2i13};
+use indy_utils::crypto::hash::hash as openssl_hash;
 use crate::services::anoncreds::helpers::*;
 use crate::utils::wql::Query;
 use crate::services::anoncreds::verifier::Verifier;
+use regex::Regex;
 
 const ATTRIBUTE_EXISTENCE_MARKER: &str = "1";
 
+lazy_static! {
+    static ref TYPED_TAG_MATCHER: Regex = Regex::new("^attr::([^:]+)::(int|date)$").unwrap();
+}
+
 pub struct Prover {}
 
 impl Prover {
@@ -106,12 +120,12 @@ impl Prover {
                         credentials: &HashMap<String, Credential>,
                         proof_req: &ProofRequest,
                         requested_credentials: &RequestedCredentials,
-                        master_secret: &MasterSecret,
+                        master_secrets: &HashMap<String, &MasterSecret>,
                         schemas: &HashMap<SchemaId, SchemaV1>,
                         cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinition>,
                         rev_states: &HashMap<String, HashMap<u64, RevocationState>>) -> IndyResult<Proof> {
-        trace!("create_proof >>> credentials: {:?}, proof_req: {:?}, requested_credentials: {:?}, master_secret: {:?}, schemas: {:?}, cred_defs: {:?}, rev_states: {:?}",
-               credentials, proof_req, requested_credentials, secret!(&master_secret), schemas, cred_defs, rev_states);
+        trace!("create_proof >>> credentials: {:?}, proof_req: {:?}, requested_credentials: {:?}, master_secrets: {:?}, schemas: {:?}, cred_defs: {:?}, rev_states: {:?}",
+               credentials, proof_req, requested_credentials, secret!(&master_secrets), schemas, cred_defs, rev_states);
 
         let proof_req_val = proof_req.value();
         let mut proof_builder = CryptoProver::new_proof_builder()?;
@@ -152,6 +166,9 @@ impl Prover {
             let credential_pub_key = CredentialPublicKey::build_from_parts(&cred_def.value.primary, cred_def.value.revocation.as_ref())?;
 
             let credential_schema = build_credential_schema(&schema.attr_names.0)?;
+            let master_secret: &MasterSecret = master_secrets.get(cred_key.cred_id.as_str())
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("MasterSecret not found for credential: {:?}", cred_key.cred_id)))?;
+
             let credential_values = build_credential_values(&credential.values.0, Some(master_secret))?;
             let sub_proof_request = Prover::_build_sub_proof_request(&req_attrs_for_cred, &req_predicates_for_cred)?;
 
@@ -309,6 +326,11 @@ impl Prover {
                     // abstain for attrs policy marks untaggable
                     res.insert(Self::_build_attr_marker_tag(attr), ATTRIBUTE_EXISTENCE_MARKER.to_string());
                     res.insert(Self::_build_attr_value_tag(attr), values.raw.clone());
+
+                    // unencrypted, so the wallet can compare values in typed queries; only for attributes the policy opts in
+                    if let Some(value) = catpol.and_then(|cp| cp.attr_type(attr)).and_then(|attr_type| Self::_typed_int_value(values, attr_type)) {
+                        res.insert(Self::_build_attr_int_tag(attr), Self::_sortable_int_tag_value(value));
+                    }
                 }
             });
 
@@ -317,6 +339,68 @@ impl Prover {
         Ok(res)
     }
 
+    pub fn master_secret_fingerprint(&self, master_secret: &MasterSecret) -> IndyResult<String> {
+        let master_secret = serde_json::to_vec(master_secret)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize MasterSecret")?;
+
+        Ok(base64::encode(&openssl_hash(&master_secret)?))
+    }
+
+    pub fn seal_credential_bundle(&self,
+                                  payload: &CredentialBundlePayload,
+                                  key: &str,
+                                  key_derivation_method: &KeyDerivationMethod) -> IndyResult<CredentialBundle> {
+        trace!("seal_credential_bundle >>> payload: {:?}, key_derivation_method: {:?}", secret!(payload), key_derivation_method);
+
+        let payload = serde_json::to_vec(payload)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize CredentialBundlePayload")?;
+
+        let salt = pwhash_argon2i13::gen_salt();
+        let key = chacha20poly1305_ietf::derive_key(key, &salt, key_derivation_method)?;
+        let (ciphertext, nonce) = chacha20poly1305_ietf::gen_nonce_and_encrypt(&payload, &key);
+
+        let res = CredentialBundle {
+            ver: CREDENTIAL_BUNDLE_VERSION.to_string(),
+            key_derivation_method: key_derivation_method.clone(),
+            salt: base64::encode(&salt[..]),
+            nonce: base64::encode(&nonce[..]),
+            ciphertext: base64::encode(&ciphertext),
+        };
+
+        trace!("seal_credential_bundle <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    pub fn open_credential_bundle(&self,
+                                  bundle: &CredentialBundle,
+                                  key: &str) -> IndyResult<CredentialBundlePayload> {
+        trace!("open_credential_bundle >>> bundle: {:?}", bundle);
+
+        let salt = pwhash_argon2i13::Salt::from_slice(&base64::decode(&bundle.salt)?)?;
+        let nonce = chacha20poly1305_ietf::Nonce::from_slice(&base64::decode(&bundle.nonce)?)?;
+        let ciphertext = base64::decode(&bundle.ciphertext)?;
+
+        let key = chacha20poly1305_ietf::derive_key(key, &salt, &bundle.key_derivation_method)?;
+
+        let payload = chacha20poly1305_ietf::decrypt(&ciphertext, &key, &nonce)
+            .map_err(|err| err.extend("Invalid key or corrupted credential bundle"))?;
+
+        let res: CredentialBundlePayload = serde_json::from_slice(&payload)
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize CredentialBundlePayload")?;
+
+        trace!("open_credential_bundle <<< res: {:?}", secret!(&res));
+
+        Ok(res)
+    }
+
+    /// Query matching credentials that contain all the given attributes.
+    pub fn attr_markers_query(&self, names: &[String]) -> Query {
+        Query::And(names.iter()
+            .map(|name| Query::Eq(Self::_build_attr_marker_tag(name), ATTRIBUTE_EXISTENCE_MARKER.to_string()))
+            .collect())
+    }
+
     fn _build_attr_marker_tag(attr: &str) -> String {
         format!("attr::{}::marker", attr_common_view(&attr))
     }
@@ -325,6 +409,132 @@ impl Prover {
         format!("attr::{}::value", attr_common_view(&attr))
     }
 
+    fn _build_attr_int_tag(attr: &str) -> String {
+        format!("~attr::{}::int", attr_common_view(&attr))
+    }
+
+    // Integer behind the encoded value if the raw value is valid for the declared integer, date or boolean type.
+    fn _typed_int_value(values: &AttributeValues, attr_type: AttributeType) -> Option<i32> {
+        let expected = encode_typed_credential_value(&values.raw, Some(attr_type)).ok()?;
+
+        if expected != values.encoded { return None; }
+
+        values.encoded.parse::<i32>().ok()
+    }
+
+    // Offset binary with fixed width, so lexicographical order of tag values is the numeric order.
+    fn _sortable_int_tag_value(value: i32) -> String {
+        format!("{:010}", i64::from(value) - i64::from(i32::min_value()))
+    }
+
+    /// Rewrites comparisons on `attr::<name>::int` and `attr::<name>::date` keys to comparisons of unencrypted
+    /// sortable tags. Values of `attr::<name>::date` keys are dates in YYYY-MM-DD format.
+    /// `status::active` key is expanded to conditions on credential status tags.
+    pub fn process_typed_query(query: Query) -> IndyResult<Query> {
+        let typed = |tag_name: &str| -> Option<(String, bool)> {
+            TYPED_TAG_MATCHER.captures(tag_name)
+                .and_then(|caps| match (caps.get(1), caps.get(2)) {
+                    (Some(name), Some(type_)) => Some((Self::_build_attr_int_tag(name.as_str()), type_.as_str() == "date")),
+                    _ => None
+                })
+        };
+
+        let value = |tag_name: &str, value: &str, is_date: bool| -> IndyResult<String> {
+            let value = if is_date {
+                parse_date_to_days(value)?
+            } else {
+                value.parse::<i32>()
+                    .to_indy(IndyErrorKind::InvalidStructure, format!("Value \"{}\" of \"{}\" query is not a 32-bit integer", value, tag_name))?
+            };
+            Ok(Self::_sortable_int_tag_value(value))
+        };
+
+        macro_rules! _typed_operator {
+            ($op:path, $tag_name:expr, $tag_value:expr) => {
+                match typed($tag_name.as_str()) {
+                    Some((int_tag_name, is_date)) => $op(int_tag_name, value($tag_name.as_str(), $tag_value.as_str(), is_date)?),
+                    None => $op($tag_name, $tag_value)
+                }
+            }
+        }
+
+        Ok(match query {
+            Query::Eq(ref tag_name, ref tag_value) if tag_name == CREDENTIAL_STATUS_ACTIVE_TAG => match tag_value.as_str() {
+                "1" => Self::credential_status_active_query(get_seconds_since_epoch()?),
+                "0" => Query::Not(Box::new(Self::credential_status_active_query(get_seconds_since_epoch()?))),
+                _ => return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Value of \"{}\" query must be \"1\" or \"0\"", tag_name)))
+            },
+            Query::Eq(tag_name, tag_value) => _typed_operator!(Query::Eq, tag_name, tag_value),
+            Query::Neq(tag_name, tag_value) => _typed_operator!(Query::Neq, tag_name, tag_value),
+            Query::Gt(tag_name, tag_value) => _typed_operator!(Query::Gt, tag_name, tag_value),
+            Query::Gte(tag_name, tag_value) => _typed_operator!(Query::Gte, tag_name, tag_value),
+            Query::Lt(tag_name, tag_value) => _typed_operator!(Query::Lt, tag_name, tag_value),
+            Query::Lte(tag_name, tag_value) => _typed_operator!(Query::Lte, tag_name, tag_value),
+            Query::Like(tag_name, tag_value) => {
+                if typed(tag_name.as_str()).is_some() {
+                    return Err(err_msg(IndyErrorKind::InvalidStructure, format!("$like operator is not supported for \"{}\" query", tag_name)));
+                }
+                Query::Like(tag_name, tag_value)
+            }
+            Query::In(tag_name, tag_values) => match typed(tag_name.as_str()) {
+                Some((int_tag_name, is_date)) => Query::In(int_tag_name, tag_values.iter()
+                    .map(|tag_value| value(tag_name.as_str(), tag_value.as_str(), is_date))
+                    .collect::<IndyResult<Vec<String>>>()?),
+                None => Query::In(tag_name, tag_values)
+            },
+            Query::And(operators) => Query::And(operators.into_iter().map(Self::process_typed_query).collect::<IndyResult<Vec<Query>>>()?),
+            Query::Or(operators) => Query::Or(operators.into_iter().map(Self::process_typed_query).collect::<IndyResult<Vec<Query>>>()?),
+            Query::Not(operator) => Query::Not(Box::new(Self::process_typed_query(*operator)?)),
+        })
+    }
+
+    // Credentials stored without status tags don't match `$not` operands, so they are active.
+    pub fn credential_status_active_query(now: u64) -> Query {
+        Query::And(vec![
+            Query::Not(Box::new(Query::Eq(CREDENTIAL_STATUS_REVOKED_TAG.to_string(), CREDENTIAL_STATUS_FLAG.to_string()))),
+            Query::Not(Box::new(Query::Eq(CREDENTIAL_STATUS_REPLACED_TAG.to_string(), CREDENTIAL_STATUS_FLAG.to_string()))),
+            Query::Not(Box::new(Query::Lte(CREDENTIAL_STATUS_EXPIRES_AT_TAG.to_string(), sortable_u64(now)))),
+        ])
+    }
+
+    /// Expiry of the credential taken from the attribute designated by the issuer.
+    /// Attribute value is a unix timestamp or a date in YYYY-MM-DD format, the latter expires at the start of the day.
+    pub fn get_credential_expiry(&self, credential: &Credential, attr_name: &str) -> IndyResult<Option<u64>> {
+        let values = match self.get_credential_values_for_attribute(&credential.values.0, attr_name) {
+            Some(values) => values,
+            None => return Ok(None)
+        };
+
+        let expires_at = if !values.raw.is_empty() && values.raw.chars().all(|c| c.is_ascii_digit()) {
+            values.raw.parse::<u64>()
+                .to_indy(IndyErrorKind::InvalidStructure, format!("Value \"{}\" of expiry attribute \"{}\" is too large", values.raw, attr_name))?
+        } else {
+            let days = parse_date_to_days(&values.raw)?;
+            if days < 0 {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Value \"{}\" of expiry attribute \"{}\" is before unix epoch", values.raw, attr_name)));
+            }
+            days as u64 * 86400
+        };
+
+        Ok(Some(expires_at))
+    }
+
+    /// Returns `Some(true)` if the delta revokes the credential with `rev_idx`, `Some(false)` if it issues it
+    /// and `None` if the delta doesn't change it.
+    pub fn revocation_in_delta(&self, rev_reg_delta: &RevocationRegistryDeltaV1, rev_idx: u32) -> IndyResult<Option<bool>> {
+        let indices: RevocationRegistryDeltaIndices = serde_json::to_value(&rev_reg_delta.value)
+            .and_then(serde_json::from_value)
+            .to_indy(IndyErrorKind::InvalidStructure, "Invalid RevocationRegistryDelta")?;
+
+        Ok(if indices.revoked.contains(&rev_idx) {
+            Some(true)
+        } else if indices.issued.contains(&rev_idx) {
+            Some(false)
+        } else {
+            None
+        })
+    }
+
     pub fn attribute_satisfy_predicate(&self,
                                        predicate: &PredicateInfo,
                                        attribute_value: &str) -> IndyResult<bool> {
@@ -477,7 +687,7 @@ impl Prover {
         }
 
         if let Some(extra_query_) = extra_query.as_ref().and_then(|query| query.get(referent)) {
-            queries.push(extra_query_.clone())
+            queries.push(Self::process_typed_query(extra_query_.clone())?)
         }
 
         // put attr_queries last as this results in a better performing query with large datasets
@@ -587,6 +797,109 @@ impl Prover {
             _ => return Err(IndyError::from_msg(IndyErrorKind::InvalidStructure, "unsupported operator"))
         })
     }
+
+    pub fn explain_query_mismatch(&self,
+                                  query: &Query,
+                                  tags: &HashMap<String, String>) -> Vec<DiagnosticsIssue> {
+        trace!("explain_query_mismatch >>> query: {:?}, tags: {:?}", query, tags);
+
+        let res = Self::_query_mismatches(query, tags);
+
+        trace!("explain_query_mismatch <<< res: {:?}", res);
+
+        res
+    }
+
+    fn _query_mismatches(query: &Query, tags: &HashMap<String, String>) -> Vec<DiagnosticsIssue> {
+        match query {
+            Query::Eq(tag_name, tag_value) =>
+                Self::_tag_mismatches(query, tags, tag_name, |actual| actual == tag_value),
+            Query::Neq(tag_name, tag_value) => {
+                match tags.get(tag_name) {
+                    Some(actual) if actual == tag_value =>
+                        vec![DiagnosticsIssue::restriction_mismatch(query, Some(tag_name), Some(actual))],
+                    _ => vec![]
+                }
+            }
+            Query::In(tag_name, tag_values) =>
+                Self::_tag_mismatches(query, tags, tag_name, |actual| tag_values.iter().any(|value| value == actual)),
+            Query::Gt(tag_name, tag_value) =>
+                Self::_tag_mismatches(query, tags, tag_name, |actual| actual > tag_value.as_str()),
+            Query::Gte(tag_name, tag_value) =>
+                Self::_tag_mismatches(query, tags, tag_name, |actual| actual >= tag_value.as_str()),
+            Query::Lt(tag_name, tag_value) =>
+                Self::_tag_mismatches(query, tags, tag_name, |actual| actual < tag_value.as_str()),
+            Query::Lte(tag_name, tag_value) =>
+                Self::_tag_mismatches(query, tags, tag_name, |actual| actual <= tag_value.as_str()),
+            Query::Like(tag_name, pattern) =>
+                Self::_tag_mismatches(query, tags, tag_name, |actual| Self::_like(pattern, actual)),
+            Query::And(operators) => {
+                operators
+                    .iter()
+                    .flat_map(|operator| Self::_query_mismatches(operator, tags))
+                    .collect()
+            }
+            Query::Or(operators) => {
+                // report the alternative that is closest to be satisfied
+                operators
+                    .iter()
+                    .map(|operator| Self::_query_mismatches(operator, tags))
+                    .min_by_key(|mismatches| mismatches.len())
+                    .unwrap_or_default()
+            }
+            Query::Not(operator) => {
+                if Self::_query_mismatches(operator, tags).is_empty() {
+                    vec![DiagnosticsIssue::restriction_mismatch(query, None, None)]
+                } else {
+                    vec![]
+                }
+            }
+        }
+    }
+
+    fn _tag_mismatches<F>(query: &Query, tags: &HashMap<String, String>, tag_name: &str, satisfied: F) -> Vec<DiagnosticsIssue>
+        where F: Fn(&str) -> bool {
+        let actual = tags.get(tag_name).map(String::as_str);
+
+        if actual.map(satisfied).unwrap_or(false) {
+            vec![]
+        } else {
+            vec![DiagnosticsIssue::restriction_mismatch(query, Some(tag_name), actual)]
+        }
+    }
+
+    // SQL LIKE matching with two pointers: on mismatch the last `%` is retried against one more character.
+    fn _like(pattern: &str, value: &str) -> bool {
+        let pattern: Vec<char> = pattern.chars().collect();
+        let value: Vec<char> = value.chars().collect();
+
+        let (mut p, mut v) = (0, 0);
+        // positions of the last `%` in the pattern and of the value character it has been matched up to
+        let mut last_wildcard: Option<(usize, usize)> = None;
+
+        while v < value.len() {
+            match pattern.get(p) {
+                Some('%') => {
+                    last_wildcard = Some((p, v));
+                    p += 1;
+                }
+                Some(&c) if c == '_' || c == value[v] => {
+                    p += 1;
+                    v += 1;
+                }
+                _ => match last_wildcard {
+                    Some((wildcard_p, wildcard_v)) => {
+                        last_wildcard = Some((wildcard_p, wildcard_v + 1));
+                        p = wildcard_p + 1;
+                        v = wildcard_v + 1;
+                    }
+                    None => return false
+                }
+            }
+        }
+
+        pattern[p..].iter().all(|&c| c == '%')
+    }
 }
 
 #[cfg(test)]
@@ -638,6 +951,23 @@ mod tests {
             ).unwrap()
         }
 
+        #[test]
+        fn get_credential_expiry_works() {
+            let ps = Prover::new();
+            let mut credential = _credential();
+
+            assert_eq!(None, ps.get_credential_expiry(&credential, "expiry").unwrap());
+
+            credential.values.0.insert("Expiry".to_string(), AttributeValues { raw: "1700000000".to_string(), encoded: "1700000000".to_string() });
+            assert_eq!(Some(1_700_000_000), ps.get_credential_expiry(&credential, "expiry").unwrap());
+
+            credential.values.0.insert("Expiry".to_string(), AttributeValues { raw: "2020-09-13".to_string(), encoded: "18518".to_string() });
+            assert_eq!(Some(1_599_955_200), ps.get_credential_expiry(&credential, "expiry").unwrap());
+
+            credential.values.0.insert("Expiry".to_string(), AttributeValues { raw: "next year".to_string(), encoded: "12345".to_string() });
+            assert_kind!(IndyErrorKind::InvalidStructure, ps.get_credential_expiry(&credential, "expiry"));
+        }
+
         #[test]
         fn build_credential_tags_works() {
             let ps = Prover::new();
@@ -681,6 +1011,18 @@ mod tests {
             assert_eq!(expected_tags, tags)
         }
 
+        #[test]
+        fn build_credential_tags_works_for_typed_catpol() {
+            let ps = Prover::new();
+            let catpol: CredentialAttrTagPolicy = serde_json::from_str(r#"["name::integer", "age::integer"]"#).unwrap();
+            let tags = ps.build_credential_tags(&_credential(), Some(catpol).as_ref()).unwrap();
+
+            // "Alex" is not a valid integer, so only the declared and valid attribute gets the sortable tag
+            assert_eq!(None, tags.get("~attr::name::int"));
+            assert_eq!("2147483673", tags["~attr::age::int"]);
+            assert_eq!("25", tags["attr::age::value"]);
+        }
+
         #[test]
         fn build_credential_tags_works_for_rev_reg_id() {
             let ps = Prover::new();
@@ -779,6 +1121,188 @@ mod tests {
         }
     }
 
+    mod credential_bundle {
+        use super::*;
+
+        const KEY: &str = "credential_bundle_key";
+
+        fn _payload() -> CredentialBundlePayload {
+            let mut attr_values: HashMap<String, AttributeValues> = HashMap::new();
+            attr_values.insert("name".to_string(), AttributeValues { raw: "Alex".to_string(), encoded: "12345".to_string() });
+
+            let credential = serde_json::from_value::<Credential>(json!({
+                "schema_id": SCHEMA_ID,
+                "cred_def_id": CRED_DEF_ID,
+                "values": attr_values,
+                "signature": {
+                    "p_credential": {"m_2": "0","a": "0","e": "0","v": "0"}
+                },
+                "signature_correctness_proof": {"se":"0", "c":"0"}
+            })).unwrap();
+
+            CredentialBundlePayload {
+                cred_id: "cred_id".to_string(),
+                credential,
+                master_secret_id: "master_secret_id".to_string(),
+                master_secret_fingerprint: "fingerprint".to_string(),
+                master_secret: None,
+                revocation: None,
+                cred_req_metadata: None,
+            }
+        }
+
+        #[test]
+        fn seal_credential_bundle_works() {
+            let ps = Prover::new();
+
+            let bundle = ps.seal_credential_bundle(&_payload(), KEY, &KeyDerivationMethod::ARGON2I_INT).unwrap();
+            let payload = ps.open_credential_bundle(&bundle, KEY).unwrap();
+
+            assert_eq!(CREDENTIAL_BUNDLE_VERSION, bundle.ver);
+            assert_eq!("cred_id", payload.cred_id);
+            assert_eq!("master_secret_id", payload.master_secret_id);
+            assert_eq!("Alex", payload.credential.values.0["name"].raw);
+            assert!(payload.master_secret.is_none());
+        }
+
+        #[test]
+        fn open_credential_bundle_works_for_other_key() {
+            let ps = Prover::new();
+
+            let bundle = ps.seal_credential_bundle(&_payload(), KEY, &KeyDerivationMethod::ARGON2I_INT).unwrap();
+
+            let res = ps.open_credential_bundle(&bundle, "other_key");
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn master_secret_fingerprint_works() {
+            let ps = Prover::new();
+
+            let master_secret = ps.new_master_secret().unwrap();
+            let other_master_secret = ps.new_master_secret().unwrap();
+
+            assert_eq!(ps.master_secret_fingerprint(&master_secret).unwrap(), ps.master_secret_fingerprint(&master_secret).unwrap());
+            assert_ne!(ps.master_secret_fingerprint(&master_secret).unwrap(), ps.master_secret_fingerprint(&other_master_secret).unwrap());
+        }
+    }
+
+    mod explain_query_mismatch {
+        use super::*;
+        use crate::domain::anoncreds::proof_request_diagnostics::DiagnosticsIssueReason;
+
+        fn _tags() -> HashMap<String, String> {
+            hashmap!(
+                "schema_id".to_string() => SCHEMA_ID.to_string(),
+                "issuer_did".to_string() => ISSUER_DID.to_string(),
+                "cred_def_id".to_string() => CRED_DEF_ID.to_string(),
+                "attr::name::marker".to_string() => ATTRIBUTE_EXISTENCE_MARKER.to_string(),
+                "attr::name::value".to_string() => "Alex".to_string()
+            )
+        }
+
+        #[test]
+        fn explain_query_mismatch_works_for_matched_query() {
+            let ps = Prover::new();
+            let query = Query::And(vec![
+                Query::Eq("schema_id".to_string(), SCHEMA_ID.to_string()),
+                Query::Eq("attr::name::marker".to_string(), ATTRIBUTE_EXISTENCE_MARKER.to_string())
+            ]);
+
+            assert!(ps.explain_query_mismatch(&query, &_tags()).is_empty());
+        }
+
+        #[test]
+        fn explain_query_mismatch_works_for_wrong_value() {
+            let ps = Prover::new();
+            let query = Query::And(vec![
+                Query::Eq("issuer_did".to_string(), "other_issuer_did".to_string()),
+                Query::Eq("attr::name::value".to_string(), "Alex".to_string())
+            ]);
+
+            let issues = ps.explain_query_mismatch(&query, &_tags());
+            assert_eq!(1, issues.len());
+            assert_eq!(DiagnosticsIssueReason::RestrictionMismatch, issues[0].reason);
+            assert_eq!(Some("issuer_did".to_string()), issues[0].tag);
+            assert_eq!(Some(ISSUER_DID.to_string()), issues[0].actual);
+        }
+
+        #[test]
+        fn explain_query_mismatch_works_for_missed_tag() {
+            let ps = Prover::new();
+            let query = Query::Eq("attr::age::marker".to_string(), ATTRIBUTE_EXISTENCE_MARKER.to_string());
+
+            let issues = ps.explain_query_mismatch(&query, &_tags());
+            assert_eq!(1, issues.len());
+            assert_eq!(Some("attr::age::marker".to_string()), issues[0].tag);
+            assert_eq!(None, issues[0].actual);
+        }
+
+        #[test]
+        fn explain_query_mismatch_works_for_or_reports_closest_alternative() {
+            let ps = Prover::new();
+            let query = Query::Or(vec![
+                Query::And(vec![
+                    Query::Eq("schema_id".to_string(), "other_schema_id".to_string()),
+                    Query::Eq("issuer_did".to_string(), "other_issuer_did".to_string())
+                ]),
+                Query::And(vec![
+                    Query::Eq("schema_id".to_string(), SCHEMA_ID.to_string()),
+                    Query::Eq("cred_def_id".to_string(), "other_cred_def_id".to_string())
+                ])
+            ]);
+
+            let issues = ps.explain_query_mismatch(&query, &_tags());
+            assert_eq!(1, issues.len());
+            assert_eq!(Some("cred_def_id".to_string()), issues[0].tag);
+        }
+
+        #[test]
+        fn explain_query_mismatch_works_for_not() {
+            let ps = Prover::new();
+            let query = Query::Not(Box::new(Query::Eq("schema_id".to_string(), SCHEMA_ID.to_string())));
+
+            let issues = ps.explain_query_mismatch(&query, &_tags());
+            assert_eq!(1, issues.len());
+            assert_eq!(Some(query), issues[0].restriction);
+        }
+
+        #[test]
+        fn explain_query_mismatch_works_for_like() {
+            let ps = Prover::new();
+
+            assert!(ps.explain_query_mismatch(&Query::Like("attr::name::value".to_string(), "Al%".to_string()), &_tags()).is_empty());
+            assert!(ps.explain_query_mismatch(&Query::Like("attr::name::value".to_string(), "A_ex".to_string()), &_tags()).is_empty());
+            assert_eq!(1, ps.explain_query_mismatch(&Query::Like("attr::name::value".to_string(), "Bo%".to_string()), &_tags()).len());
+        }
+
+        #[test]
+        fn like_works() {
+            assert!(Prover::_like("", ""));
+            assert!(Prover::_like("%", ""));
+            assert!(Prover::_like("%%", "Alex"));
+            assert!(Prover::_like("%le%", "Alex"));
+            assert!(Prover::_like("A%x", "Alex"));
+            assert!(Prover::_like("%a%b", "xaxab"));
+            assert!(Prover::_like("_l_x", "Alex"));
+            assert!(Prover::_like("Ал%", "Алекс"));
+            assert!(Prover::_like("____", "мега"));
+            assert!(!Prover::_like("", "Alex"));
+            assert!(!Prover::_like("_", ""));
+            assert!(!Prover::_like("A%y", "Alex"));
+            assert!(!Prover::_like("%a%b", "xaxba"));
+            assert!(!Prover::_like("Alex_", "Alex"));
+        }
+
+        #[test]
+        fn like_works_for_many_wildcards() {
+            let value = "a".repeat(1000);
+            let pattern = format!("{}b", "%a".repeat(50));
+            assert!(!Prover::_like(&pattern, &value));
+            assert!(Prover::_like(&"%a".repeat(50), &value));
+        }
+    }
+
     mod prepare_credentials_for_proving {
         use crate::domain::anoncreds::proof_request::{AttributeInfo, PredicateInfo};
         use crate::domain::anoncreds::requested_credential::RequestedAttribute;
@@ -972,6 +1496,102 @@ mod tests {
         }
     }
 
+    mod process_typed_query {
+        use super::*;
+
+        #[test]
+        fn process_typed_query_works_for_date() {
+            let query: Query = serde_json::from_value(json!({"attr::dob::date": {"$lt": "2006-10-17"}})).unwrap();
+            let expected: Query = serde_json::from_value(json!({"~attr::dob::int": {"$lt": "2147497086"}})).unwrap();
+            assert_eq!(expected, Prover::process_typed_query(query).unwrap());
+        }
+
+        #[test]
+        fn process_typed_query_works_for_int() {
+            let query: Query = serde_json::from_value(json!({"$and": [
+                {"attr::Age::int": {"$gte": "-1"}},
+                {"attr::age::int": {"$in": ["25", "28"]}},
+                {"attr::name::value": "Alex"}
+            ]})).unwrap();
+            let expected: Query = serde_json::from_value(json!({"$and": [
+                {"~attr::age::int": {"$gte": "2147483647"}},
+                {"~attr::age::int": {"$in": ["2147483673", "2147483676"]}},
+                {"attr::name::value": "Alex"}
+            ]})).unwrap();
+            assert_eq!(expected, Prover::process_typed_query(query).unwrap());
+        }
+
+        #[test]
+        fn process_typed_query_works_for_invalid_values() {
+            let query: Query = serde_json::from_value(json!({"attr::dob::date": {"$lt": "17.10.2006"}})).unwrap();
+            assert_kind!(IndyErrorKind::InvalidStructure, Prover::process_typed_query(query));
+
+            let query: Query = serde_json::from_value(json!({"attr::age::int": {"$gt": "old"}})).unwrap();
+            assert_kind!(IndyErrorKind::InvalidStructure, Prover::process_typed_query(query));
+
+            let query: Query = serde_json::from_value(json!({"attr::age::int": {"$like": "2%"}})).unwrap();
+            assert_kind!(IndyErrorKind::InvalidStructure, Prover::process_typed_query(query));
+        }
+
+        #[test]
+        fn process_typed_query_works_for_status_active() {
+            let query: Query = serde_json::from_value(json!({"$and": [{"status::active": "1"}, {"schema_name": "gvt"}]})).unwrap();
+
+            match Prover::process_typed_query(query).unwrap() {
+                Query::And(operators) => {
+                    assert_eq!(2, operators.len());
+                    match operators[0] {
+                        Query::And(ref status_operators) => assert_eq!(3, status_operators.len()),
+                        ref operator => panic!("Unexpected operator {:?}", operator)
+                    }
+                }
+                query => panic!("Unexpected query {:?}", query)
+            }
+
+            let query: Query = serde_json::from_value(json!({"status::active": "0"})).unwrap();
+            match Prover::process_typed_query(query).unwrap() {
+                Query::Not(_) => (),
+                query => panic!("Unexpected query {:?}", query)
+            }
+
+            let query: Query = serde_json::from_value(json!({"status::active": "yes"})).unwrap();
+            assert_kind!(IndyErrorKind::InvalidStructure, Prover::process_typed_query(query));
+        }
+
+        #[test]
+        fn credential_status_active_query_works() {
+            let expected: Query = serde_json::from_value(json!({"$and": [
+                {"$not": {"status::revoked": "1"}},
+                {"$not": {"status::replaced": "1"}},
+                {"$not": {"~status::expires_at": {"$lte": "00000000001600000000"}}}
+            ]})).unwrap();
+            assert_eq!(expected, Prover::credential_status_active_query(1_600_000_000));
+        }
+
+        #[test]
+        fn sortable_int_tag_value_keeps_order() {
+            let values = [i32::min_value(), -13438, -1, 0, 1, 25, 13438, i32::max_value()];
+            let tags: Vec<String> = values.iter().map(|value| Prover::_sortable_int_tag_value(*value)).collect();
+
+            assert_eq!("0000000000", tags[0]);
+            assert_eq!("4294967295", tags[7]);
+
+            let mut sorted = tags.clone();
+            sorted.sort();
+            assert_eq!(tags, sorted);
+        }
+
+        #[test]
+        fn typed_int_value_works() {
+            assert_eq!(Some(13438), Prover::_typed_int_value(&AttributeValues { raw: "2006-10-17".to_string(), encoded: "13438".to_string() }, AttributeType::Date));
+            assert_eq!(Some(1), Prover::_typed_int_value(&AttributeValues { raw: "true".to_string(), encoded: "1".to_string() }, AttributeType::Boolean));
+            assert_eq!(Some(25), Prover::_typed_int_value(&AttributeValues { raw: "25".to_string(), encoded: "25".to_string() }, AttributeType::Integer));
+            assert_eq!(None, Prover::_typed_int_value(&AttributeValues { raw: "25".to_string(), encoded: "25".to_string() }, AttributeType::Date));
+            assert_eq!(None, Prover::_typed_int_value(&AttributeValues { raw: "Alex".to_string(), encoded: "12345".to_string() }, AttributeType::Integer));
+            assert_eq!(None, Prover::_typed_int_value(&AttributeValues { raw: "25".to_string(), encoded: "40654426461387431593023896448597934904317945932538746522277789984804865638746".to_string() }, AttributeType::Integer));
+        }
+    }
+
     mod extend_operator {
         use super::*;
 
@@ -1,17 +1,21 @@
 use std::collections::{HashMap, HashSet};
 
 use crate::domain::anoncreds::credential_definition::{CredentialDefinitionV1, CredentialDefinitionId};
-use crate::domain::anoncreds::proof::{Proof, RequestedProof, Identifier, RevealedAttributeInfo};
-use crate::domain::anoncreds::proof_request::{AttributeInfo, PredicateInfo, ProofRequestPayload, NonRevocedInterval};
+use crate::domain::anoncreds::proof::{Proof, RequestedProof, Identifier, RevealedAttributeInfo, RevealedAttributeGroupInfo};
+use crate::domain::anoncreds::proof_request::{AttributeInfo, PredicateInfo, ProofRequest, ProofRequestPayload, NonRevocedInterval};
+use crate::domain::anoncreds::proof_request_template::{ProofRequestTemplate, IdentifiersFormat, DEFAULT_PROOF_REQUEST_NAME, DEFAULT_PROOF_REQUEST_VERSION, DEFAULT_QUALIFIER_METHOD};
 use crate::domain::anoncreds::revocation_registry::RevocationRegistryV1;
 use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefinitionV1, RevocationRegistryId};
-use crate::domain::anoncreds::schema::{SchemaV1, SchemaId};
+use crate::domain::anoncreds::schema::{AttributeType, SchemaV1, SchemaId};
+use crate::domain::anoncreds::verification_options::VerifyProofOptions;
+use crate::domain::anoncreds::verification_report::{VerificationReport, ReferentReport, SubProofReport, VerificationFailureReason};
 use indy_api_types::errors::prelude::*;
 use crate::services::anoncreds::helpers::*;
 
 use ursa::bn::BigNumber;
 use ursa::cl::{CredentialPublicKey, new_nonce, Nonce};
 use ursa::cl::verifier::Verifier as CryptoVerifier;
+use indy_api_types::validation::Validatable;
 use crate::utils::wql::Query;
 use regex::Regex;
 
@@ -30,6 +34,39 @@ lazy_static! {
     pub static ref MARKER_TAG_MATCHER: Regex = Regex::new("^attr::([^:]+)::marker$").unwrap();
 }
 
+// Part of the proof a failed check belongs to.
+enum CheckScope {
+    Attribute(String),
+    Predicate(String),
+    SubProof(u32),
+    Proof,
+}
+
+struct CheckFailure {
+    reason: VerificationFailureReason,
+    message: String,
+    // error returned by `verify`, `None` if the cryptographic proof is just invalid
+    error: Option<IndyError>,
+}
+
+impl CheckFailure {
+    fn new(reason: VerificationFailureReason, error: IndyError) -> CheckFailure {
+        CheckFailure {
+            reason,
+            message: Verifier::_failure_message(&error),
+            error: Some(error),
+        }
+    }
+
+    fn invalid_proof(message: &str) -> CheckFailure {
+        CheckFailure {
+            reason: VerificationFailureReason::CryptoVerificationFailed,
+            message: message.to_string(),
+            error: None,
+        }
+    }
+}
+
 pub struct Verifier {}
 
 impl Verifier {
@@ -43,36 +80,381 @@ impl Verifier {
                   schemas: &HashMap<SchemaId, SchemaV1>,
                   cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
                   rev_reg_defs: &HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
-                  rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>) -> IndyResult<bool> {
-        trace!("verify >>> full_proof: {:?}, proof_req: {:?}, schemas: {:?}, cred_defs: {:?}, rev_reg_defs: {:?} rev_regs: {:?}",
-               full_proof, proof_req, schemas, cred_defs, rev_reg_defs, rev_regs);
+                  rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>,
+                  options: &VerifyProofOptions) -> IndyResult<bool> {
+        trace!("verify >>> full_proof: {:?}, proof_req: {:?}, schemas: {:?}, cred_defs: {:?}, rev_reg_defs: {:?} rev_regs: {:?}, options: {:?}",
+               full_proof, proof_req, schemas, cred_defs, rev_reg_defs, rev_regs, options);
+
+        let failures = Verifier::_check_proof(full_proof, proof_req, schemas, cred_defs, rev_reg_defs, rev_regs, options)?;
+
+        let valid = match failures.into_iter().next() {
+            None => true,
+            Some((_, CheckFailure { error: Some(err), .. })) => return Err(err),
+            Some((_, CheckFailure { error: None, .. })) => false
+        };
+
+        trace!("verify <<< valid: {:?}", valid);
+
+        Ok(valid)
+    }
+
+    pub fn verify_detailed(&self,
+                           full_proof: &Proof,
+                           proof_req: &ProofRequestPayload,
+                           schemas: &HashMap<SchemaId, SchemaV1>,
+                           cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
+                           rev_reg_defs: &HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
+                           rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>,
+                           options: &VerifyProofOptions) -> IndyResult<VerificationReport> {
+        trace!("verify_detailed >>> full_proof: {:?}, proof_req: {:?}, schemas: {:?}, cred_defs: {:?}, rev_reg_defs: {:?} rev_regs: {:?}, options: {:?}",
+               full_proof, proof_req, schemas, cred_defs, rev_reg_defs, rev_regs, options);
+
+        let failures = Verifier::_check_proof(full_proof, proof_req, schemas, cred_defs, rev_reg_defs, rev_regs, options)?;
+
+        let received_revealed_attrs: HashMap<String, Identifier> = Verifier::_received_revealed_attrs(&full_proof)?;
+        let received_unrevealed_attrs: HashMap<String, Identifier> = Verifier::_received_unrevealed_attrs(&full_proof)?;
+        let received_predicates: HashMap<String, Identifier> = Verifier::_received_predicates(&full_proof)?;
+        let received_self_attested_attrs: HashSet<String> = Verifier::_received_self_attested_attrs(&full_proof);
+
+        let proof_attr_identifiers = Verifier::_proof_attr_identifiers(&received_revealed_attrs, &received_unrevealed_attrs);
+        let requested_proof = &full_proof.requested_proof;
+
+        let mut report = VerificationReport {
+            valid: false,
+            requested_attributes: HashMap::new(),
+            requested_predicates: HashMap::new(),
+            sub_proofs: Vec::new(),
+            failures: Vec::new(),
+        };
+
+        for (referent, info) in proof_req.requested_attributes.iter() {
+            let mut attr_report = ReferentReport::new();
+            attr_report.non_revoked = get_non_revoc_interval(&proof_req.non_revoked, &info.non_revoked);
+            attr_report.self_attested = received_self_attested_attrs.contains(referent);
+
+            if let Some(identifier) = proof_attr_identifiers.get(referent) {
+                attr_report.timestamp = identifier.timestamp;
+                attr_report.check_interval();
+                attr_report.revealed = received_revealed_attrs.contains_key(referent);
+                attr_report.sub_proof_index = requested_proof.revealed_attrs.get(referent).map(|info| info.sub_proof_index)
+                    .or_else(|| requested_proof.revealed_attr_groups.get(referent).map(|info| info.sub_proof_index))
+                    .or_else(|| requested_proof.unrevealed_attrs.get(referent).map(|info| info.sub_proof_index));
+            }
+
+            report.requested_attributes.insert(referent.to_string(), attr_report);
+        }
+
+        for (referent, info) in proof_req.requested_predicates.iter() {
+            let mut predicate_report = ReferentReport::new();
+            predicate_report.non_revoked = get_non_revoc_interval(&proof_req.non_revoked, &info.non_revoked);
+
+            if let Some(identifier) = received_predicates.get(referent) {
+                predicate_report.timestamp = identifier.timestamp;
+                predicate_report.check_interval();
+                predicate_report.sub_proof_index = requested_proof.predicates.get(referent).map(|info| info.sub_proof_index);
+            }
+
+            report.requested_predicates.insert(referent.to_string(), predicate_report);
+        }
+
+        for (sub_proof_index, identifier) in full_proof.identifiers.iter().enumerate() {
+            let mut sub_proof_report = SubProofReport::new(sub_proof_index as u32, identifier);
+            sub_proof_report.non_revocation_checked = identifier.timestamp.is_some();
+            report.sub_proofs.push(sub_proof_report);
+        }
 
+        for (scope, failure) in failures {
+            match scope {
+                CheckScope::Attribute(referent) => if let Some(attr_report) = report.requested_attributes.get_mut(&referent) {
+                    attr_report.fail(failure.reason, &failure.message)
+                },
+                CheckScope::Predicate(referent) => if let Some(predicate_report) = report.requested_predicates.get_mut(&referent) {
+                    predicate_report.fail(failure.reason, &failure.message)
+                },
+                CheckScope::SubProof(index) => if let Some(sub_proof_report) = report.sub_proofs.get_mut(index as usize) {
+                    sub_proof_report.fail(failure.reason, &failure.message)
+                },
+                CheckScope::Proof => report.fail(failure.reason, &failure.message)
+            }
+        }
+
+        report.finalize();
+
+        trace!("verify_detailed <<< report: {:?}", report);
+
+        Ok(report)
+    }
+
+    // Runs every check of the proof and returns all failures in the order the checks are done.
+    // `verify` reports the first failure, `verify_detailed` all of them.
+    fn _check_proof(full_proof: &Proof,
+                    proof_req: &ProofRequestPayload,
+                    schemas: &HashMap<SchemaId, SchemaV1>,
+                    cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
+                    rev_reg_defs: &HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
+                    rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>,
+                    options: &VerifyProofOptions) -> IndyResult<Vec<(CheckScope, CheckFailure)>> {
         let received_revealed_attrs: HashMap<String, Identifier> = Verifier::_received_revealed_attrs(&full_proof)?;
         let received_unrevealed_attrs: HashMap<String, Identifier> = Verifier::_received_unrevealed_attrs(&full_proof)?;
         let received_predicates: HashMap<String, Identifier> = Verifier::_received_predicates(&full_proof)?;
         let received_self_attested_attrs: HashSet<String> = Verifier::_received_self_attested_attrs(&full_proof);
 
-        Verifier::_compare_attr_from_proof_and_request(proof_req,
-                                                       &received_revealed_attrs,
-                                                       &received_unrevealed_attrs,
-                                                       &received_self_attested_attrs,
-                                                       &received_predicates)?;
+        let proof_attr_identifiers = Verifier::_proof_attr_identifiers(&received_revealed_attrs, &received_unrevealed_attrs);
+        let requested_attrs = Verifier::_requested_attrs_with_restrictions(proof_req, &received_self_attested_attrs);
+        let requested_proof = &full_proof.requested_proof;
+
+        let mut attr_referents: Vec<&String> = proof_req.requested_attributes.keys().collect();
+        attr_referents.sort();
+        let mut predicate_referents: Vec<&String> = proof_req.requested_predicates.keys().collect();
+        predicate_referents.sort();
+
+        let mut failures: Vec<(CheckScope, CheckFailure)> = Vec::new();
+
+        for referent in attr_referents.iter() {
+            if !proof_attr_identifiers.contains_key(*referent) && !received_self_attested_attrs.contains(*referent) {
+                failures.push((CheckScope::Attribute(referent.to_string()),
+                               CheckFailure::new(VerificationFailureReason::MissingReferent,
+                                                 err_msg(IndyErrorKind::InvalidStructure, "Requested attribute is not present in proof"))));
+            }
+        }
+
+        for referent in predicate_referents.iter() {
+            if !received_predicates.contains_key(*referent) {
+                failures.push((CheckScope::Predicate(referent.to_string()),
+                               CheckFailure::new(VerificationFailureReason::MissingReferent,
+                                                 err_msg(IndyErrorKind::InvalidStructure, "Requested predicate is not present in proof"))));
+            }
+        }
+
+        let mut unexpected_attrs = proof_attr_identifiers.keys()
+            .chain(received_self_attested_attrs.iter())
+            .filter(|referent| !proof_req.requested_attributes.contains_key(*referent))
+            .cloned()
+            .collect::<Vec<String>>();
+        unexpected_attrs.sort();
+        unexpected_attrs.dedup();
+
+        for referent in unexpected_attrs {
+            failures.push((CheckScope::Proof,
+                           CheckFailure::new(VerificationFailureReason::UnexpectedReferent,
+                                             err_msg(IndyErrorKind::InvalidStructure, format!("Attribute \"{}\" is present in proof but not requested", referent)))));
+        }
+
+        let mut unexpected_predicates = received_predicates.keys()
+            .filter(|referent| !proof_req.requested_predicates.contains_key(*referent))
+            .collect::<Vec<&String>>();
+        unexpected_predicates.sort();
+
+        for referent in unexpected_predicates {
+            failures.push((CheckScope::Proof,
+                           CheckFailure::new(VerificationFailureReason::UnexpectedReferent,
+                                             err_msg(IndyErrorKind::InvalidStructure, format!("Predicate \"{}\" is present in proof but not requested", referent)))));
+        }
+
+        for referent in attr_referents.iter() {
+            let res = match (requested_proof.revealed_attrs.get(*referent), requested_proof.revealed_attr_groups.get(*referent)) {
+                (Some(attr_info), _) => Verifier::_verify_revealed_attr(proof_req, full_proof, referent, attr_info),
+                (None, Some(attr_infos)) => Verifier::_verify_revealed_attr_group(proof_req, full_proof, referent, attr_infos),
+                (None, None) => Ok(())
+            };
+
+            if let Err(failure) = res {
+                failures.push((CheckScope::Attribute(referent.to_string()), failure));
+            }
+        }
+
+        if options.check_raw_encoding {
+            let schemas_by_sub_proof = Verifier::_schemas_by_sub_proof(full_proof, schemas);
+
+            for (referent, err) in Verifier::_verify_revealed_raw_values(proof_req, requested_proof, &schemas_by_sub_proof)? {
+                failures.push((CheckScope::Attribute(referent), CheckFailure::new(VerificationFailureReason::RawValueMismatch, err)));
+            }
+        }
+
+        for referent in attr_referents.iter() {
+            let res = if proof_attr_identifiers.contains_key(*referent) {
+                Verifier::_verify_attr_restrictions(proof_req, referent, &proof_req.requested_attributes[*referent], requested_proof, &proof_attr_identifiers)
+            } else if received_self_attested_attrs.contains(*referent) && requested_attrs.contains_key(*referent) {
+                Err(err_msg(IndyErrorKind::ProofRejected, "Self attested value is provided for attribute with restrictions"))
+            } else {
+                Ok(())
+            };
+
+            if let Err(err) = res {
+                failures.push((CheckScope::Attribute(referent.to_string()), CheckFailure::new(VerificationFailureReason::RestrictionMismatch, err)));
+            }
+        }
+
+        for referent in predicate_referents.iter().filter(|referent| received_predicates.contains_key(**referent)) {
+            if let Err(err) = Verifier::_verify_predicate_restrictions(referent, &proof_req.requested_predicates[*referent], requested_proof, &received_predicates, &requested_attrs) {
+                failures.push((CheckScope::Predicate(referent.to_string()), CheckFailure::new(VerificationFailureReason::RestrictionMismatch, err)));
+            }
+        }
+
+        for referent in attr_referents.iter().filter(|referent| proof_attr_identifiers.contains_key(**referent)) {
+            if let Err(err) = Verifier::_validate_timestamp(&proof_attr_identifiers, referent, &proof_req.non_revoked, &proof_req.requested_attributes[*referent].non_revoked) {
+                failures.push((CheckScope::Attribute(referent.to_string()), CheckFailure::new(VerificationFailureReason::MissingTimestamp, err)));
+            }
+        }
+
+        for referent in predicate_referents.iter().filter(|referent| received_predicates.contains_key(**referent)) {
+            if let Err(err) = Verifier::_validate_timestamp(&received_predicates, referent, &proof_req.non_revoked, &proof_req.requested_predicates[*referent].non_revoked) {
+                failures.push((CheckScope::Predicate(referent.to_string()), CheckFailure::new(VerificationFailureReason::MissingTimestamp, err)));
+            }
+        }
+
+        let mut sub_proof_objects_found = true;
+
+        for (sub_proof_index, identifier) in full_proof.identifiers.iter().enumerate() {
+            for failure in Verifier::_check_sub_proof_objects(identifier, schemas, cred_defs, rev_reg_defs, rev_regs) {
+                sub_proof_objects_found = false;
+                failures.push((CheckScope::SubProof(sub_proof_index as u32), failure));
+            }
+        }
+
+        if sub_proof_objects_found {
+            match Verifier::_verify_crypto_proof(full_proof, proof_req, schemas, cred_defs, rev_reg_defs, rev_regs) {
+                Ok(true) => {}
+                Ok(false) => {
+                    let revocable = full_proof.identifiers
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, identifier)| identifier.timestamp.is_some())
+                        .map(|(sub_proof_index, _)| sub_proof_index as u32)
+                        .collect::<Vec<u32>>();
+
+                    let message = if revocable.is_empty() {
+                        "Cryptographic proof is invalid".to_string()
+                    } else {
+                        format!("Cryptographic proof is invalid or credential of sub proofs {:?} is revoked", revocable)
+                    };
+
+                    failures.push((CheckScope::Proof, CheckFailure::invalid_proof(&message)));
+                }
+                Err(err) => failures.push((CheckScope::Proof, CheckFailure::new(VerificationFailureReason::CryptoVerificationFailed, err)))
+            }
+        }
+
+        Ok(failures)
+    }
 
-        Verifier::_verify_revealed_attribute_values(&proof_req, &full_proof)?;
+    pub fn generate_nonce(&self) -> IndyResult<Nonce> {
+        trace!("generate_nonce >>> ");
+
+        let nonce = new_nonce()?;
+
+        trace!("generate_nonce <<< nonce: {:?} ", nonce);
+
+        Ok(nonce)
+    }
+
+    pub fn build_proof_request(&self,
+                               template: &ProofRequestTemplate,
+                               nonce: Nonce,
+                               now: u64) -> IndyResult<ProofRequest> {
+        trace!("build_proof_request >>> template: {:?}, nonce: {:?}, now: {:?}", template, nonce, now);
+
+        let allowed = Verifier::_allowed_identifiers_restriction(template);
+        let restrict = |restrictions: &Option<Query>| -> Option<Query> {
+            match (allowed.clone(), restrictions.clone()) {
+                (Some(allowed), Some(restrictions)) => Some(Query::And(vec![allowed, restrictions])),
+                (allowed, restrictions) => allowed.or(restrictions)
+            }
+        };
+
+        let mut requested_attributes: HashMap<String, AttributeInfo> = HashMap::new();
+
+        for (index, attribute) in template.attributes.iter().enumerate() {
+            let referent = attribute.referent.clone().unwrap_or_else(|| format!("attr{}_referent", index + 1));
+
+            let attr_info = AttributeInfo {
+                name: attribute.name.clone(),
+                names: attribute.names.clone(),
+                restrictions: restrict(&attribute.restrictions),
+                non_revoked: attribute.non_revoked.clone(),
+            };
+
+            if requested_attributes.insert(referent.clone(), attr_info).is_some() {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Proof Request template contains duplicated attribute referent \"{}\"", referent)));
+            }
+        }
+
+        let mut requested_predicates: HashMap<String, PredicateInfo> = HashMap::new();
+
+        for (index, predicate) in template.predicates.iter().enumerate() {
+            let referent = predicate.referent.clone().unwrap_or_else(|| format!("predicate{}_referent", index + 1));
+
+            let predicate_info = PredicateInfo {
+                name: predicate.name.clone(),
+                p_type: predicate.p_type.clone(),
+                p_value: predicate.p_value,
+                restrictions: restrict(&predicate.restrictions),
+                non_revoked: predicate.non_revoked.clone(),
+            };
+
+            if requested_attributes.contains_key(&referent) || requested_predicates.insert(referent.clone(), predicate_info).is_some() {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Proof Request template contains duplicated predicate referent \"{}\"", referent)));
+            }
+        }
 
-        Verifier::_verify_requested_restrictions(&proof_req,
-                                                 &full_proof.requested_proof,
-                                                 &received_revealed_attrs,
-                                                 &received_unrevealed_attrs,
-                                                 &received_predicates,
-                                                 &received_self_attested_attrs)?;
+        let non_revoked = match template.freshness {
+            Some(freshness) => Some(NonRevocedInterval { from: Some(now.saturating_sub(freshness)), to: Some(now) }),
+            None => template.non_revoked.clone()
+        };
 
-        Verifier::_compare_timestamps_from_proof_and_request(proof_req,
-                                                             &received_revealed_attrs,
-                                                             &received_unrevealed_attrs,
-                                                             &received_self_attested_attrs,
-                                                             &received_predicates)?;
+        let proof_request = ProofRequest::ProofRequestV2(ProofRequestPayload {
+            nonce,
+            name: template.name.clone().unwrap_or_else(|| DEFAULT_PROOF_REQUEST_NAME.to_string()),
+            version: template.version.clone().unwrap_or_else(|| DEFAULT_PROOF_REQUEST_VERSION.to_string()),
+            requested_attributes,
+            requested_predicates,
+            non_revoked,
+        });
 
+        proof_request.validate()
+            .map_err(|err| err_msg(IndyErrorKind::InvalidStructure, err))?;
+
+        let proof_request = match template.identifiers {
+            Some(IdentifiersFormat::Qualified) => proof_request.to_qualified(template.method.as_ref().map(String::as_str).unwrap_or(DEFAULT_QUALIFIER_METHOD)),
+            Some(IdentifiersFormat::Unqualified) => proof_request.to_unqualified(),
+            None => proof_request
+        };
+
+        trace!("build_proof_request <<< proof_request: {:?}", proof_request);
+
+        Ok(proof_request)
+    }
+
+    // Every listed kind of identifiers must match, any of the listed values of a kind.
+    fn _allowed_identifiers_restriction(template: &ProofRequestTemplate) -> Option<Query> {
+        let any_of = |tag_name: &str, values: Vec<String>| -> Option<Query> {
+            let mut queries: Vec<Query> = values.into_iter().map(|value| Query::Eq(tag_name.to_string(), value)).collect();
+            match queries.len() {
+                0 => None,
+                1 => queries.pop(),
+                _ => Some(Query::Or(queries))
+            }
+        };
+
+        let mut queries: Vec<Query> = vec![
+            any_of("issuer_did", template.issuer_dids.iter().map(|did| did.0.clone()).collect()),
+            any_of("schema_id", template.schema_ids.iter().map(|schema_id| schema_id.0.clone()).collect()),
+            any_of("cred_def_id", template.cred_def_ids.iter().map(|cred_def_id| cred_def_id.0.clone()).collect()),
+        ].into_iter().filter_map(|query| query).collect();
+
+        match queries.len() {
+            0 => None,
+            1 => queries.pop(),
+            _ => Some(Query::And(queries))
+        }
+    }
+
+    fn _verify_crypto_proof(full_proof: &Proof,
+                            proof_req: &ProofRequestPayload,
+                            schemas: &HashMap<SchemaId, SchemaV1>,
+                            cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
+                            rev_reg_defs: &HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
+                            rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>) -> IndyResult<bool> {
         let mut proof_verifier = CryptoVerifier::new_proof_verifier()?;
         let non_credential_schema = build_non_credential_schema()?;
 
@@ -124,21 +506,9 @@ impl Verifier {
 
         let valid = proof_verifier.verify(&full_proof.proof, &proof_req.nonce)?;
 
-        trace!("verify <<< valid: {:?}", valid);
-
         Ok(valid)
     }
 
-    pub fn generate_nonce(&self) -> IndyResult<Nonce> {
-        trace!("generate_nonce >>> ");
-
-        let nonce = new_nonce()?;
-
-        trace!("generate_nonce <<< nonce: {:?} ", nonce);
-
-        Ok(nonce)
-    }
-
     fn _get_revealed_attributes_for_credential(sub_proof_index: usize,
                                                requested_proof: &RequestedProof,
                                                proof_req: &ProofRequestPayload) -> IndyResult<Vec<AttributeInfo>> {
@@ -187,67 +557,45 @@ impl Verifier {
         Ok(predicates_for_credential)
     }
 
-    fn _compare_attr_from_proof_and_request(proof_req: &ProofRequestPayload,
-                                            received_revealed_attrs: &HashMap<String, Identifier>,
-                                            received_unrevealed_attrs: &HashMap<String, Identifier>,
-                                            received_self_attested_attrs: &HashSet<String>,
-                                            received_predicates: &HashMap<String, Identifier>) -> IndyResult<()> {
-        let requested_attrs: HashSet<String> = proof_req.requested_attributes
-            .keys()
-            .cloned()
-            .collect();
+    fn _check_sub_proof_objects(identifier: &Identifier,
+                                schemas: &HashMap<SchemaId, SchemaV1>,
+                                cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
+                                rev_reg_defs: &HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
+                                rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>) -> Vec<CheckFailure> {
+        let mut failures = Vec::new();
 
-        let received_attrs: HashSet<String> = received_revealed_attrs
-            .iter()
-            .chain(received_unrevealed_attrs)
-            .map(|(r, _)| r.to_string())
-            .collect::<HashSet<String>>()
-            .union(&received_self_attested_attrs)
-            .cloned()
-            .collect();
+        let mut fail = |reason: VerificationFailureReason, message: String| {
+            failures.push(CheckFailure::new(reason, err_msg(IndyErrorKind::InvalidStructure, message)));
+        };
 
-        if requested_attrs != received_attrs {
-            return Err(err_msg(IndyErrorKind::InvalidStructure,
-                               format!("Requested attributes {:?} do not correspond to received {:?}", requested_attrs, received_attrs)));
+        if !schemas.contains_key(&identifier.schema_id) {
+            fail(VerificationFailureReason::MissingSchema,
+                 format!("Schema not found for id: {:?}", identifier.schema_id));
         }
 
-        let requested_predicates: HashSet<&String> = proof_req.requested_predicates
-            .keys()
-            .collect();
-
-        let received_predicates_: HashSet<&String> = received_predicates
-            .keys()
-            .collect();
-
-        if requested_predicates != received_predicates_ {
-            return Err(err_msg(IndyErrorKind::InvalidStructure,
-                               format!("Requested predicates {:?} do not correspond to received {:?}", requested_predicates, received_predicates)));
+        if !cred_defs.contains_key(&identifier.cred_def_id) {
+            fail(VerificationFailureReason::MissingCredentialDefinition,
+                 format!("CredentialDefinition not found for id: {:?}", identifier.cred_def_id));
         }
 
-        Ok(())
-    }
+        if let Some(timestamp) = identifier.timestamp {
+            match identifier.rev_reg_id {
+                Some(ref rev_reg_id) => {
+                    if !rev_reg_defs.contains_key(rev_reg_id) {
+                        fail(VerificationFailureReason::MissingRevocationRegistryDefinition,
+                             format!("RevocationRegistryDefinition not found for id: {:?}", rev_reg_id));
+                    }
 
-    fn _compare_timestamps_from_proof_and_request(proof_req: &ProofRequestPayload,
-                                                  received_revealed_attrs: &HashMap<String, Identifier>,
-                                                  received_unrevealed_attrs: &HashMap<String, Identifier>,
-                                                  received_self_attested_attrs: &HashSet<String>,
-                                                  received_predicates: &HashMap<String, Identifier>) -> IndyResult<()> {
-        proof_req.requested_attributes
-            .iter()
-            .map(|(referent, info)|
-                Verifier::_validate_timestamp(&received_revealed_attrs, referent, &proof_req.non_revoked, &info.non_revoked)
-                    .or_else(|_| Verifier::_validate_timestamp(&received_unrevealed_attrs, referent, &proof_req.non_revoked, &info.non_revoked))
-                    .or_else(|_| received_self_attested_attrs.get(referent).map(|_| ()).ok_or_else(|| IndyError::from(IndyErrorKind::InvalidStructure)))
-            )
-            .collect::<IndyResult<Vec<()>>>()?;
-
-        proof_req.requested_predicates
-            .iter()
-            .map(|(referent, info)|
-                Verifier::_validate_timestamp(received_predicates, referent, &proof_req.non_revoked, &info.non_revoked))
-            .collect::<IndyResult<Vec<()>>>()?;
+                    if rev_regs.get(rev_reg_id).and_then(|rev_regs_for_cred| rev_regs_for_cred.get(&timestamp)).is_none() {
+                        fail(VerificationFailureReason::MissingRevocationRegistry,
+                             format!("RevocationRegistry not found for id: {:?} and timestamp: {:?}", rev_reg_id, timestamp));
+                    }
+                }
+                None => fail(VerificationFailureReason::MissingRevocationRegistryDefinition, "Revocation Registry Id not found".to_string())
+            }
+        }
 
-        Ok(())
+        failures
     }
 
     fn _validate_timestamp(received_: &HashMap<String, Identifier>, referent: &str,
@@ -260,12 +608,21 @@ impl Verifier {
             .get(referent)
             .map(|attr| attr.timestamp.is_some())
             .unwrap_or(false) {
-            return Err(IndyError::from(IndyErrorKind::InvalidStructure));
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Non-revocation interval is requested but proof does not contain timestamp"));
         }
 
         Ok(())
     }
 
+    fn _failure_message(err: &IndyError) -> String {
+        err.to_string()
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<&str>>()
+            .join(" ")
+    }
+
     fn _received_revealed_attrs(proof: &Proof) -> IndyResult<HashMap<String, Identifier>> {
         let mut revealed_identifiers: HashMap<String, Identifier> = HashMap::new();
         for (referent, info) in proof.requested_proof.revealed_attrs.iter() {
@@ -322,150 +679,207 @@ impl Verifier {
             ))
     }
 
-    fn _verify_revealed_attribute_values(proof_req: &ProofRequestPayload,
-                                         proof: &Proof) -> IndyResult<()> {
-        for (attr_referent, attr_info) in proof.requested_proof.revealed_attrs.iter() {
+    fn _verify_revealed_attr(proof_req: &ProofRequestPayload,
+                             proof: &Proof,
+                             attr_referent: &str,
+                             attr_info: &RevealedAttributeInfo) -> Result<(), CheckFailure> {
+        let attr_name = proof_req.requested_attributes.get(attr_referent)
+            .and_then(|attr| attr.name.as_ref())
+            .ok_or_else(|| CheckFailure::new(VerificationFailureReason::RevealedAttributeMismatch,
+                                             IndyError::from_msg(IndyErrorKind::ProofRejected, format!("Attribute with referent \"{}\" not found in ProofRequests", attr_referent))))?;
+        Verifier::_verify_revealed_attribute_value(attr_name.as_str(), proof, &attr_info)
+    }
 
-            let attr_name = proof_req.requested_attributes.get(attr_referent)
-                .as_ref()
-                .ok_or(IndyError::from_msg(IndyErrorKind::ProofRejected, format!("Attribute with referent \"{}\" not found in ProofRequests", attr_referent)))?
-                .name.as_ref()
-                .ok_or(IndyError::from_msg(IndyErrorKind::ProofRejected, format!("Attribute with referent \"{}\" not found in ProofRequests", attr_referent)))?;
-            Verifier::_verify_revealed_attribute_value(attr_name.as_str(), proof, &attr_info)?;
+    fn _verify_revealed_attr_group(proof_req: &ProofRequestPayload,
+                                   proof: &Proof,
+                                   attr_referent: &str,
+                                   attr_infos: &RevealedAttributeGroupInfo) -> Result<(), CheckFailure> {
+        let mismatch = |kind: IndyErrorKind, message: String| CheckFailure::new(VerificationFailureReason::RevealedAttributeMismatch, IndyError::from_msg(kind, message));
+
+        let attr_names = proof_req.requested_attributes.get(attr_referent)
+            .and_then(|attr| attr.names.as_ref())
+            .ok_or_else(|| mismatch(IndyErrorKind::ProofRejected, format!("Attribute with referent \"{}\" not found in ProofRequests", attr_referent)))?;
+        if attr_infos.values.len() != attr_names.len() {
+            error!("Proof Revealed Attr Group does not match Proof Request Attribute Group, proof request attrs: {:?}, referent: {:?}, attr_infos: {:?}", proof_req.requested_attributes, attr_referent, attr_infos);
+            return Err(mismatch(IndyErrorKind::InvalidStructure, "Proof Revealed Attr Group does not match Proof Request Attribute Group".to_string()));
         }
-
-        for (attr_referent, attr_infos) in proof.requested_proof.revealed_attr_groups.iter() {
-            let attr_names = proof_req.requested_attributes.get(attr_referent)
-                .as_ref()
-                .ok_or(IndyError::from_msg(IndyErrorKind::ProofRejected, format!("Attribute with referent \"{}\" not found in ProofRequests", attr_referent)))?
-                .names.as_ref()
-                .ok_or(IndyError::from_msg(IndyErrorKind::ProofRejected, format!("Attribute with referent \"{}\" not found in ProofRequests", attr_referent)))?;
-            if attr_infos.values.len() != attr_names.len() {
-                error!("Proof Revealed Attr Group does not match Proof Request Attribute Group, proof request attrs: {:?}, referent: {:?}, attr_infos: {:?}", proof_req.requested_attributes, attr_referent, attr_infos);
-                return Err(IndyError::from_msg(IndyErrorKind::InvalidStructure, "Proof Revealed Attr Group does not match Proof Request Attribute Group"))
-            }
-            for attr_name in attr_names {
-                let attr_info = &attr_infos.values.get(attr_name)
-                    .ok_or(IndyError::from_msg(IndyErrorKind::InvalidStructure, "Proof Revealed Attr Group does not match Proof Request Attribute Group"))?;
-                Verifier::_verify_revealed_attribute_value(attr_name, proof, &RevealedAttributeInfo {
-                    sub_proof_index: attr_infos.sub_proof_index,
-                    raw: attr_info.raw.clone(),
-                    encoded: attr_info.encoded.clone()
-                })?;
-            }
+        for attr_name in attr_names {
+            let attr_info = &attr_infos.values.get(attr_name)
+                .ok_or_else(|| mismatch(IndyErrorKind::InvalidStructure, "Proof Revealed Attr Group does not match Proof Request Attribute Group".to_string()))?;
+            Verifier::_verify_revealed_attribute_value(attr_name, proof, &RevealedAttributeInfo {
+                sub_proof_index: attr_infos.sub_proof_index,
+                raw: attr_info.raw.clone(),
+                encoded: attr_info.encoded.clone()
+            })?;
         }
         Ok(())
     }
 
     fn _verify_revealed_attribute_value(attr_name: &str,
                                         proof: &Proof,
-                                        attr_info: &RevealedAttributeInfo) -> IndyResult<()> {
+                                        attr_info: &RevealedAttributeInfo) -> Result<(), CheckFailure> {
         let reveal_attr_encoded = &attr_info.encoded;
         let sub_proof_index = attr_info.sub_proof_index as usize;
 
         let crypto_proof_encoded = proof.proof.proofs
             .get(sub_proof_index)
-            .ok_or(IndyError::from_msg(IndyErrorKind::ProofRejected, format!("CryptoProof not found by index \"{}\"", sub_proof_index)))?
-            .revealed_attrs()?
-            .iter()
-            .find(|(key, _)| attr_common_view(attr_name) == attr_common_view(&key))
-            .map(|(_, val)| val.to_string())
-            .ok_or(IndyError::from_msg(IndyErrorKind::ProofRejected, format!("Attribute with name \"{}\" not found in CryptoProof", attr_name)))?;
+            .ok_or(IndyError::from_msg(IndyErrorKind::ProofRejected, format!("CryptoProof not found by index \"{}\"", sub_proof_index)))
+            .and_then(|sub_proof| sub_proof.revealed_attrs().map_err(IndyError::from))
+            .and_then(|revealed_attrs| revealed_attrs
+                .iter()
+                .find(|(key, _)| attr_common_view(attr_name) == attr_common_view(&key))
+                .map(|(_, val)| val.to_string())
+                .ok_or(IndyError::from_msg(IndyErrorKind::ProofRejected, format!("Attribute with name \"{}\" not found in CryptoProof", attr_name))))
+            .map_err(|err| CheckFailure::new(VerificationFailureReason::RevealedAttributeMismatch, err))?;
 
-        if BigNumber::from_dec(reveal_attr_encoded)? != BigNumber::from_dec(&crypto_proof_encoded)? {
-            return Err(IndyError::from_msg(IndyErrorKind::ProofRejected,
-                                           format!("Encoded Values for \"{}\" are different in RequestedProof \"{}\" and CryptoProof \"{}\"", attr_name, reveal_attr_encoded, crypto_proof_encoded)));
+        let mismatch = |err: IndyError| CheckFailure::new(VerificationFailureReason::EncodedValueMismatch, err);
+
+        if BigNumber::from_dec(reveal_attr_encoded).map_err(|err| mismatch(err.into()))? != BigNumber::from_dec(&crypto_proof_encoded).map_err(|err| mismatch(err.into()))? {
+            return Err(mismatch(IndyError::from_msg(IndyErrorKind::ProofRejected,
+                                                    format!("Encoded Values for \"{}\" are different in RequestedProof \"{}\" and CryptoProof \"{}\"", attr_name, reveal_attr_encoded, crypto_proof_encoded))));
         }
 
         Ok(())
     }
 
-    fn _verify_requested_restrictions(proof_req: &ProofRequestPayload,
-                                      requested_proof: &RequestedProof,
-                                      received_revealed_attrs: &HashMap<String, Identifier>,
-                                      received_unrevealed_attrs: &HashMap<String, Identifier>,
-                                      received_predicates: &HashMap<String, Identifier>,
-                                      self_attested_attrs: &HashSet<String>) -> IndyResult<()> {
-        let proof_attr_identifiers: HashMap<String, Identifier> = received_revealed_attrs
+    fn _schemas_by_sub_proof<'a>(full_proof: &Proof, schemas: &'a HashMap<SchemaId, SchemaV1>) -> Vec<Option<&'a SchemaV1>> {
+        full_proof.identifiers
+            .iter()
+            .map(|identifier| schemas.get(&identifier.schema_id))
+            .collect()
+    }
+
+    fn _verify_revealed_raw_values(proof_req: &ProofRequestPayload,
+                                   requested_proof: &RequestedProof,
+                                   schemas_by_sub_proof: &[Option<&SchemaV1>]) -> IndyResult<Vec<(String, IndyError)>> {
+        let attr_type = |sub_proof_index: u32, attr_name: Option<&str>| -> Option<AttributeType> {
+            match (schemas_by_sub_proof.get(sub_proof_index as usize), attr_name) {
+                (Some(Some(schema)), Some(attr_name)) => schema.attr_type(attr_name),
+                _ => None
+            }
+        };
+
+        let revealed_attrs = requested_proof.revealed_attrs
+            .iter()
+            .map(|(referent, info)| {
+                let attr_name = proof_req.requested_attributes.get(referent).and_then(|attr_info| attr_info.name.as_ref());
+                (referent, &info.raw, &info.encoded, attr_type(info.sub_proof_index, attr_name.map(String::as_str)))
+            });
+
+        let revealed_attr_groups = requested_proof.revealed_attr_groups
+            .iter()
+            .flat_map(|(referent, info)| info.values.iter()
+                .map(move |(attr_name, value)| (referent, &value.raw, &value.encoded, attr_type(info.sub_proof_index, Some(attr_name)))));
+
+        let mut mismatches = Vec::new();
+
+        for (referent, raw, encoded, attr_type) in revealed_attrs.chain(revealed_attr_groups) {
+            if !is_credential_value_encoded(raw, encoded, attr_type)? {
+                mismatches.push((referent.to_string(),
+                                 IndyError::from_msg(IndyErrorKind::ProofRejected,
+                                                     format!("Raw value \"{}\" of revealed attribute with referent \"{}\" does not match encoded value \"{}\"", raw, referent, encoded))));
+            }
+        }
+
+        mismatches.sort_by(|(referent_1, _), (referent_2, _)| referent_1.cmp(referent_2));
+
+        Ok(mismatches)
+    }
+
+    fn _proof_attr_identifiers(received_revealed_attrs: &HashMap<String, Identifier>,
+                               received_unrevealed_attrs: &HashMap<String, Identifier>) -> HashMap<String, Identifier> {
+        received_revealed_attrs
             .iter()
             .chain(received_unrevealed_attrs)
             .map(|(r, id)| (r.to_string(), id.clone()))
-            .collect();
+            .collect()
+    }
 
-        let requested_attrs: HashMap<String, AttributeInfo> = proof_req.requested_attributes
+    fn _requested_attrs_with_restrictions(proof_req: &ProofRequestPayload,
+                                          self_attested_attrs: &HashSet<String>) -> HashMap<String, AttributeInfo> {
+        proof_req.requested_attributes
             .iter()
             .filter(|&(referent, info)| !Verifier::_is_self_attested(&referent, &info, self_attested_attrs))
             .map(|(referent, info)| (referent.to_string(), info.clone()))
-            .collect();
-
-        for (referent, info) in requested_attrs.clone() {
-            if let Some(ref query) = info.restrictions {
-                let filter = Verifier::_gather_filter_info(&referent, &proof_attr_identifiers)?;
-
-                let name_value_map: HashMap<String, Option<&str>> = if let Some(name) = info.name {
-                    let mut map = HashMap::new();
-                    map.insert(name.clone(), requested_proof.revealed_attrs.get(&referent).map(|attr| attr.raw.as_str()));
-                    map
-                } else if let Some(names) = info.names {
-                    let mut map = HashMap::new();
-                    let attrs = requested_proof.revealed_attr_groups.get(&referent)
-                        .ok_or(IndyError::from_msg(IndyErrorKind::InvalidStructure, "Proof does not have referent from proof request"))?;
-                    for name in names {
-                        let val = attrs.values.get(&name).map(|attr| attr.raw.as_str());
-                        map.insert(name, val);
-                    }
-                    map
-                } else {
-                    error!(r#"Proof Request attribute restriction should contain "name" or "names" param. Current proof request: {:?}"#, proof_req);
-                    return Err(IndyError::from_msg(IndyErrorKind::InvalidStructure, r#"Proof Request attribute restriction should contain "name" or "names" param"#));
-                };
+            .collect()
+    }
 
-                Verifier::_do_process_operator(&name_value_map, &query, &filter)
-                    .map_err(|err| err.extend(format!("Requested restriction validation failed for \"{:?}\" attributes", &name_value_map)))?;
+    fn _verify_attr_restrictions(proof_req: &ProofRequestPayload,
+                                 referent: &str,
+                                 info: &AttributeInfo,
+                                 requested_proof: &RequestedProof,
+                                 proof_attr_identifiers: &HashMap<String, Identifier>) -> IndyResult<()> {
+        let query = match info.restrictions {
+            Some(ref query) => query,
+            None => return Ok(())
+        };
+
+        let filter = Verifier::_gather_filter_info(&referent, proof_attr_identifiers)?;
+
+        let name_value_map: HashMap<String, Option<&str>> = if let Some(ref name) = info.name {
+            let mut map = HashMap::new();
+            map.insert(name.clone(), requested_proof.revealed_attrs.get(referent).map(|attr| attr.raw.as_str()));
+            map
+        } else if let Some(ref names) = info.names {
+            let mut map = HashMap::new();
+            let attrs = requested_proof.revealed_attr_groups.get(referent)
+                .ok_or(IndyError::from_msg(IndyErrorKind::InvalidStructure, "Proof does not have referent from proof request"))?;
+            for name in names {
+                let val = attrs.values.get(name).map(|attr| attr.raw.as_str());
+                map.insert(name.clone(), val);
             }
-        }
+            map
+        } else {
+            error!(r#"Proof Request attribute restriction should contain "name" or "names" param. Current proof request: {:?}"#, proof_req);
+            return Err(IndyError::from_msg(IndyErrorKind::InvalidStructure, r#"Proof Request attribute restriction should contain "name" or "names" param"#));
+        };
 
-        for (referent, info) in proof_req.requested_predicates.iter() {
-            if let Some(ref query) = info.restrictions {
-                let filter = Verifier::_gather_filter_info(&referent, received_predicates)?;
-
-                // start with the predicate requested attribute, which is un-revealed
-                let mut attr_value_map = HashMap::new();
-                attr_value_map.insert(info.name.to_string(), None);
-
-                // include any revealed attributes for the same credential (based on sub_proof_index)
-                let pred_sub_proof_index = requested_proof.predicates.get(referent).unwrap().sub_proof_index;
-                for attr_referent in requested_proof.revealed_attrs.keys() {
-                    let attr_info = requested_proof.revealed_attrs.get(attr_referent).unwrap();
-                    let attr_sub_proof_index = attr_info.sub_proof_index;
-                    if pred_sub_proof_index == attr_sub_proof_index {
-                        let attr_name = requested_attrs.get(attr_referent).unwrap().name.clone();
-                        if let Some(name) = attr_name {
-                            attr_value_map.insert(name, Some(attr_info.raw.as_str()));
-                        }
-                    }
-                }
-                for attr_referent in requested_proof.revealed_attr_groups.keys() {
-                    let attr_info = requested_proof.revealed_attr_groups.get(attr_referent).unwrap();
-                    let attr_sub_proof_index = attr_info.sub_proof_index;
-                    if pred_sub_proof_index == attr_sub_proof_index {
-                        for name in attr_info.values.keys() {
-                            let raw_val = attr_info.values.get(name).unwrap().raw.as_str();
-                            attr_value_map.insert(name.clone(), Some(raw_val));
-                        }
-                    }
-                }
+        Verifier::_do_process_operator(&name_value_map, query, &filter)
+            .map_err(|err| err.extend(format!("Requested restriction validation failed for \"{:?}\" attributes", &name_value_map)))
+    }
+
+    fn _verify_predicate_restrictions(referent: &str,
+                                      info: &PredicateInfo,
+                                      requested_proof: &RequestedProof,
+                                      received_predicates: &HashMap<String, Identifier>,
+                                      requested_attrs: &HashMap<String, AttributeInfo>) -> IndyResult<()> {
+        let query = match info.restrictions {
+            Some(ref query) => query,
+            None => return Ok(())
+        };
 
-                Verifier::_do_process_operator(&attr_value_map, &query, &filter)
-                    .map_err(|err| err.extend(format!("Requested restriction validation failed for \"{}\" predicate", &info.name)))?;
+        let filter = Verifier::_gather_filter_info(&referent, received_predicates)?;
 
-                // old style :-/ which fails for attribute restrictions on predicates
-                //Verifier::_process_operator(&info.name, &query, &filter, None)
-                //    .map_err(|err| err.extend(format!("Requested restriction validation failed for \"{}\" predicate", &info.name)))?;
+        // start with the predicate requested attribute, which is un-revealed
+        let mut attr_value_map = HashMap::new();
+        attr_value_map.insert(info.name.to_string(), None);
+
+        // include any revealed attributes for the same credential (based on sub_proof_index)
+        let pred_sub_proof_index = requested_proof.predicates.get(referent)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Predicate not found in proof for referent: {}", referent)))?
+            .sub_proof_index;
+        for (attr_referent, attr_info) in requested_proof.revealed_attrs.iter() {
+            if pred_sub_proof_index == attr_info.sub_proof_index {
+                let attr_name = requested_attrs.get(attr_referent).and_then(|attr| attr.name.clone());
+                if let Some(name) = attr_name {
+                    attr_value_map.insert(name, Some(attr_info.raw.as_str()));
+                }
+            }
+        }
+        for attr_info in requested_proof.revealed_attr_groups.values() {
+            if pred_sub_proof_index == attr_info.sub_proof_index {
+                for (name, value) in attr_info.values.iter() {
+                    attr_value_map.insert(name.clone(), Some(value.raw.as_str()));
+                }
             }
         }
 
-        Ok(())
+        Verifier::_do_process_operator(&attr_value_map, query, &filter)
+            .map_err(|err| err.extend(format!("Requested restriction validation failed for \"{}\" predicate", &info.name)))
+
+        // old style :-/ which fails for attribute restrictions on predicates
+        //Verifier::_process_operator(&info.name, &query, &filter, None)
+        //    .map_err(|err| err.extend(format!("Requested restriction validation failed for \"{}\" predicate", &info.name)))?;
     }
 
     fn _is_self_attested(referent: &str, info: &AttributeInfo, self_attested_attrs: &HashSet<String>) -> bool {
@@ -969,4 +1383,123 @@ mod tests {
         Verifier::_validate_timestamp(&_received(), "referent_2", &None, &Some(_interval())).unwrap_err();
         Verifier::_validate_timestamp(&_received(), "referent_3", &None, &Some(_interval())).unwrap_err();
     }
+
+    fn _requested_proof_with_revealed_attr(raw: &str, encoded: &str) -> RequestedProof {
+        let mut requested_proof = RequestedProof::default();
+        requested_proof.revealed_attrs.insert("attr1_referent".to_string(), RevealedAttributeInfo {
+            sub_proof_index: 0,
+            raw: raw.to_string(),
+            encoded: encoded.to_string(),
+        });
+        requested_proof
+    }
+
+    fn _proof_req_with_requested_attr() -> ProofRequestPayload {
+        serde_json::from_value(json!({
+            "nonce": "123432421212",
+            "name": "proof_req_1",
+            "version": "0.1",
+            "requested_attributes": {"attr1_referent": {"name": "dob"}}
+        })).unwrap()
+    }
+
+    fn _typed_schema() -> SchemaV1 {
+        serde_json::from_value(json!({
+            "id": "NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0",
+            "name": "gvt",
+            "version": "1.0",
+            "attrNames": ["dob"],
+            "attrTypes": {"dob": "date"},
+            "seqNo": null
+        })).unwrap()
+    }
+
+    #[test]
+    fn verify_revealed_raw_values_works() {
+        let proof_req = _proof_req_with_requested_attr();
+        assert!(Verifier::_verify_revealed_raw_values(&proof_req, &_requested_proof_with_revealed_attr("28", "28"), &[]).unwrap().is_empty());
+        assert!(Verifier::_verify_revealed_raw_values(&proof_req, &_requested_proof_with_revealed_attr("male", "5944657099558967239210949258394887428692050081607692519917050011144233115103"), &[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn verify_revealed_raw_values_works_for_mismatch() {
+        let mismatches = Verifier::_verify_revealed_raw_values(&_proof_req_with_requested_attr(), &_requested_proof_with_revealed_attr("female", "5944657099558967239210949258394887428692050081607692519917050011144233115103"), &[]).unwrap();
+        assert_eq!(1, mismatches.len());
+        assert_eq!("attr1_referent", mismatches[0].0);
+        assert_eq!(IndyErrorKind::ProofRejected, mismatches[0].1.kind());
+    }
+
+    #[test]
+    fn verify_revealed_raw_values_works_for_typed_schema() {
+        let schema = _typed_schema();
+        let proof_req = _proof_req_with_requested_attr();
+        let requested_proof = _requested_proof_with_revealed_attr("2006-10-17", "13438");
+
+        assert!(Verifier::_verify_revealed_raw_values(&proof_req, &requested_proof, &[Some(&schema)]).unwrap().is_empty());
+        assert_eq!(1, Verifier::_verify_revealed_raw_values(&proof_req, &requested_proof, &[None]).unwrap().len());
+    }
+
+    fn _proof_request_template(value: serde_json::Value) -> ProofRequestTemplate {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn build_proof_request_works() {
+        let template = _proof_request_template(json!({
+            "attributes": [{"name": "name"}, {"referent": "address", "names": ["street", "city"], "restrictions": {"schema_name": "address"}}],
+            "predicates": [{"name": "age", "p_type": ">=", "p_value": 18}],
+            "issuer_dids": ["NcYxiDXkpYi6ov5FcYDi1e"],
+            "freshness": 100
+        }));
+
+        let proof_request = Verifier::new().build_proof_request(&template, new_nonce().unwrap(), 1000).unwrap();
+        let proof_request = proof_request.value();
+
+        assert_eq!(DEFAULT_PROOF_REQUEST_NAME, proof_request.name);
+        assert_eq!(Some(NonRevocedInterval { from: Some(900), to: Some(1000) }), proof_request.non_revoked);
+        assert_eq!(Some(Query::Eq("issuer_did".to_string(), "NcYxiDXkpYi6ov5FcYDi1e".to_string())),
+                   proof_request.requested_attributes["attr1_referent"].restrictions);
+        assert_eq!(Some(Query::And(vec![
+            Query::Eq("issuer_did".to_string(), "NcYxiDXkpYi6ov5FcYDi1e".to_string()),
+            Query::Eq("schema_name".to_string(), "address".to_string()),
+        ])), proof_request.requested_attributes["address"].restrictions);
+        assert!(proof_request.requested_predicates.contains_key("predicate1_referent"));
+    }
+
+    #[test]
+    fn build_proof_request_works_for_several_allowed_identifiers() {
+        let template = _proof_request_template(json!({
+            "attributes": [{"name": "name"}],
+            "issuer_dids": ["NcYxiDXkpYi6ov5FcYDi1e", "did:sov:CnEDk9HrMnmiHXEV1WFgbV"],
+            "schema_ids": ["NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0"],
+            "identifiers": "qualified"
+        }));
+
+        let proof_request = Verifier::new().build_proof_request(&template, new_nonce().unwrap(), 1000).unwrap();
+
+        assert_eq!(Some(Query::And(vec![
+            Query::Or(vec![
+                Query::Eq("issuer_did".to_string(), "did:sov:NcYxiDXkpYi6ov5FcYDi1e".to_string()),
+                Query::Eq("issuer_did".to_string(), "did:sov:CnEDk9HrMnmiHXEV1WFgbV".to_string()),
+            ]),
+            Query::Eq("schema_id".to_string(), "schema:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0".to_string()),
+        ])), proof_request.value().requested_attributes["attr1_referent"].restrictions);
+    }
+
+    #[test]
+    fn build_proof_request_works_for_duplicated_referent() {
+        let template = _proof_request_template(json!({
+            "attributes": [{"referent": "age", "name": "name"}],
+            "predicates": [{"referent": "age", "name": "age", "p_type": ">=", "p_value": 18}]
+        }));
+
+        let res = Verifier::new().build_proof_request(&template, new_nonce().unwrap(), 1000);
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn build_proof_request_works_for_empty_template() {
+        let res = Verifier::new().build_proof_request(&_proof_request_template(json!({})), new_nonce().unwrap(), 1000);
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
 }
@@ -0,0 +1,423 @@
+use std::collections::HashMap;
+
+use indy_api_types::errors::prelude::*;
+use indy_utils::crypto::base64;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use ursa::cl::Proof as CryptoProof;
+
+use crate::domain::anoncreds::credential::{AttributeValues, Credential, CredentialValues, ShortCredentialValues};
+use crate::domain::anoncreds::credential_definition::CredentialDefinitionId;
+use crate::domain::anoncreds::proof::{Identifier, Proof, RequestedProof};
+use crate::domain::anoncreds::proof_request::ProofRequestPayload;
+use crate::domain::anoncreds::revocation_registry_definition::RevocationRegistryId;
+use crate::domain::anoncreds::schema::{SchemaId, SchemaV1};
+use crate::domain::anoncreds::w3c::{
+    W3CCredential,
+    W3CCredentialSchema,
+    W3CPresentation,
+    W3CProof,
+    CredentialProofValue,
+    PresentedCredentialProofValue,
+    PresentationProofValue,
+    W3C_CREDENTIALS_CONTEXT,
+    ANONCREDS_CONTEXT,
+    W3C_CREDENTIAL_TYPE,
+    W3C_PRESENTATION_TYPE,
+    ANONCREDS_CREDENTIAL_TYPE,
+    ANONCREDS_PRESENTATION_TYPE,
+    ANONCREDS_DEFINITION_TYPE,
+    ANONCREDS_PROOF_TYPE,
+};
+use crate::services::anoncreds::helpers::is_credential_value_encoded;
+
+const DEFAULT_ISSUER_METHOD: &str = "sov";
+
+pub fn credential_to_w3c(credential: Credential, issuance_date: &str) -> IndyResult<W3CCredential> {
+    trace!("credential_to_w3c >>> credential: {:?}, issuance_date: {:?}", secret!(&credential), issuance_date);
+
+    _check_issuance_date(issuance_date)?;
+
+    let issuer = _issuer(&credential.cred_def_id)?;
+
+    let mut credential_subject = ShortCredentialValues::new();
+    let mut encoded = HashMap::new();
+
+    for (name, value) in credential.values.0 {
+        credential_subject.insert(name.clone(), value.raw);
+        encoded.insert(name, value.encoded);
+    }
+
+    let proof_value = CredentialProofValue {
+        encoded,
+        signature: credential.signature,
+        signature_correctness_proof: credential.signature_correctness_proof,
+        rev_reg: credential.rev_reg,
+        witness: credential.witness,
+    };
+
+    let res = W3CCredential {
+        context: _context(),
+        type_: vec![W3C_CREDENTIAL_TYPE.to_string(), ANONCREDS_CREDENTIAL_TYPE.to_string()],
+        issuer,
+        issuance_date: issuance_date.to_string(),
+        credential_schema: _credential_schema(credential.cred_def_id, credential.schema_id, credential.rev_reg_id),
+        credential_subject,
+        proof: W3CProof {
+            type_: ANONCREDS_PROOF_TYPE.to_string(),
+            challenge: None,
+            proof_value: _encode_proof_value(&proof_value)?,
+        },
+    };
+
+    trace!("credential_to_w3c <<< res: {:?}", secret!(&res));
+
+    Ok(res)
+}
+
+pub fn credential_from_w3c(w3c_credential: &W3CCredential, schema: &SchemaV1) -> IndyResult<Credential> {
+    trace!("credential_from_w3c >>> w3c_credential: {:?}, schema: {:?}", secret!(w3c_credential), schema);
+
+    if w3c_credential.credential_schema.schema != schema.id {
+        return Err(err_msg(IndyErrorKind::InvalidStructure,
+                           format!("W3C Credential schema {:?} does not match the given Schema {:?}", w3c_credential.credential_schema.schema, schema.id)));
+    }
+
+    let proof_value: CredentialProofValue = _decode_proof_value(&w3c_credential.proof.proof_value)?;
+
+    if proof_value.encoded.len() != w3c_credential.credential_subject.len() {
+        return Err(err_msg(IndyErrorKind::InvalidStructure,
+                           "W3C Credential `credentialSubject` does not correspond to the attributes signed in `proof`"));
+    }
+
+    let mut values = HashMap::new();
+
+    for (name, raw) in w3c_credential.credential_subject.iter() {
+        let encoded = proof_value.encoded.get(name)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure,
+                                   format!("W3C Credential `proof` does not contain encoded value for attribute \"{}\"", name)))?;
+
+        if !is_credential_value_encoded(raw, encoded, schema.attr_type(name))? {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("W3C Credential `credentialSubject` value of attribute \"{}\" does not match the encoded value signed in `proof`", name)));
+        }
+
+        values.insert(name.clone(), AttributeValues { raw: raw.clone(), encoded: encoded.clone() });
+    }
+
+    let res = Credential {
+        schema_id: w3c_credential.credential_schema.schema.clone(),
+        cred_def_id: w3c_credential.credential_schema.definition.clone(),
+        rev_reg_id: w3c_credential.credential_schema.revocation.clone(),
+        values: CredentialValues(values),
+        signature: proof_value.signature,
+        signature_correctness_proof: proof_value.signature_correctness_proof,
+        rev_reg: proof_value.rev_reg,
+        witness: proof_value.witness,
+    };
+
+    trace!("credential_from_w3c <<< res: {:?}", secret!(&res));
+
+    Ok(res)
+}
+
+pub fn proof_to_w3c(proof_req: &ProofRequestPayload, proof: Proof, issuance_dates: &[String]) -> IndyResult<W3CPresentation> {
+    trace!("proof_to_w3c >>> proof_req: {:?}, proof: {:?}, issuance_dates: {:?}", proof_req, proof, issuance_dates);
+
+    if issuance_dates.len() != proof.identifiers.len() {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, "Number of issuance dates does not match number of identifiers"));
+    }
+
+    for issuance_date in issuance_dates {
+        _check_issuance_date(issuance_date)?;
+    }
+
+    let mut crypto_proof = serde_json::to_value(&proof.proof)
+        .to_indy(IndyErrorKind::InvalidState, "Cannot serialize Proof")?;
+
+    let sub_proofs = match crypto_proof["proofs"].take() {
+        serde_json::Value::Array(sub_proofs) => sub_proofs,
+        _ => return Err(err_msg(IndyErrorKind::InvalidStructure, "Proof does not contain sub proofs"))
+    };
+
+    if sub_proofs.len() != proof.identifiers.len() {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, "Number of sub proofs does not match number of identifiers"));
+    }
+
+    let credential_subjects = _credential_subjects(proof_req, &proof.requested_proof, proof.identifiers.len())?;
+
+    let mut verifiable_credential = Vec::with_capacity(sub_proofs.len());
+
+    let credentials = sub_proofs.into_iter()
+        .zip(proof.identifiers.into_iter())
+        .zip(credential_subjects.into_iter())
+        .zip(issuance_dates.iter());
+
+    for (((sub_proof, identifier), credential_subject), issuance_date) in credentials {
+        let proof_value = PresentedCredentialProofValue {
+            sub_proof,
+            timestamp: identifier.timestamp,
+        };
+
+        verifiable_credential.push(W3CCredential {
+            context: _context(),
+            type_: vec![W3C_CREDENTIAL_TYPE.to_string(), ANONCREDS_CREDENTIAL_TYPE.to_string()],
+            issuer: _issuer(&identifier.cred_def_id)?,
+            issuance_date: issuance_date.clone(),
+            credential_schema: _credential_schema(identifier.cred_def_id, identifier.schema_id, identifier.rev_reg_id),
+            credential_subject,
+            proof: W3CProof {
+                type_: ANONCREDS_PROOF_TYPE.to_string(),
+                challenge: None,
+                proof_value: _encode_proof_value(&proof_value)?,
+            },
+        });
+    }
+
+    let proof_value = PresentationProofValue {
+        aggregated_proof: crypto_proof["aggregated_proof"].take(),
+        requested_proof: proof.requested_proof,
+    };
+
+    let res = W3CPresentation {
+        context: _context(),
+        type_: vec![W3C_PRESENTATION_TYPE.to_string(), ANONCREDS_PRESENTATION_TYPE.to_string()],
+        verifiable_credential,
+        proof: W3CProof {
+            type_: ANONCREDS_PROOF_TYPE.to_string(),
+            challenge: Some(proof_req.nonce.to_dec()?),
+            proof_value: _encode_proof_value(&proof_value)?,
+        },
+    };
+
+    trace!("proof_to_w3c <<< res: {:?}", res);
+
+    Ok(res)
+}
+
+pub fn proof_from_w3c(presentation: &W3CPresentation) -> IndyResult<Proof> {
+    trace!("proof_from_w3c >>> presentation: {:?}", presentation);
+
+    let presentation_proof_value: PresentationProofValue = _decode_proof_value(&presentation.proof.proof_value)?;
+
+    let mut sub_proofs = Vec::with_capacity(presentation.verifiable_credential.len());
+    let mut identifiers = Vec::with_capacity(presentation.verifiable_credential.len());
+
+    for credential in presentation.verifiable_credential.iter() {
+        let proof_value: PresentedCredentialProofValue = _decode_proof_value(&credential.proof.proof_value)?;
+
+        sub_proofs.push(proof_value.sub_proof);
+        identifiers.push(Identifier {
+            schema_id: credential.credential_schema.schema.clone(),
+            cred_def_id: credential.credential_schema.definition.clone(),
+            rev_reg_id: credential.credential_schema.revocation.clone(),
+            timestamp: proof_value.timestamp,
+        });
+    }
+
+    let crypto_proof: CryptoProof = serde_json::from_value(json!({
+        "proofs": sub_proofs,
+        "aggregated_proof": presentation_proof_value.aggregated_proof,
+    }))
+        .to_indy(IndyErrorKind::InvalidStructure, "W3C Presentation `proof` does not contain valid CL proof")?;
+
+    let res = Proof {
+        proof: crypto_proof,
+        requested_proof: presentation_proof_value.requested_proof,
+        identifiers,
+    };
+
+    trace!("proof_from_w3c <<< res: {:?}", res);
+
+    Ok(res)
+}
+
+/// Checks that the attribute values shown in `credentialSubject` of the presented credentials
+/// are exactly the values revealed by the underlying proof.
+pub fn check_presentation_subjects(proof_req: &ProofRequestPayload,
+                                   presentation: &W3CPresentation,
+                                   proof: &Proof) -> IndyResult<()> {
+    trace!("check_presentation_subjects >>> proof_req: {:?}, presentation: {:?}, proof: {:?}", proof_req, presentation, proof);
+
+    let expected_subjects = _credential_subjects(proof_req, &proof.requested_proof, presentation.verifiable_credential.len())?;
+
+    for (index, (credential, expected_subject)) in presentation.verifiable_credential.iter().zip(expected_subjects.iter()).enumerate() {
+        if credential.credential_subject != *expected_subject {
+            return Err(err_msg(IndyErrorKind::ProofRejected,
+                               format!("`credentialSubject` of presented credential {} does not match revealed attribute values", index)));
+        }
+    }
+
+    trace!("check_presentation_subjects <<<");
+
+    Ok(())
+}
+
+fn _credential_subjects(proof_req: &ProofRequestPayload,
+                        requested_proof: &RequestedProof,
+                        count: usize) -> IndyResult<Vec<ShortCredentialValues>> {
+    let mut subjects = vec![ShortCredentialValues::new(); count];
+
+    for (referent, info) in requested_proof.revealed_attrs.iter() {
+        let name = proof_req.requested_attributes.get(referent)
+            .and_then(|attr_info| attr_info.name.clone())
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure,
+                                   format!("Proof Request does not contain attribute name for referent \"{}\"", referent)))?;
+
+        _subject(&mut subjects, info.sub_proof_index)?.insert(name, info.raw.clone());
+    }
+
+    for group in requested_proof.revealed_attr_groups.values() {
+        let subject = _subject(&mut subjects, group.sub_proof_index)?;
+
+        for (name, value) in group.values.iter() {
+            subject.insert(name.clone(), value.raw.clone());
+        }
+    }
+
+    Ok(subjects)
+}
+
+fn _subject(subjects: &mut Vec<ShortCredentialValues>, sub_proof_index: u32) -> IndyResult<&mut ShortCredentialValues> {
+    subjects.get_mut(sub_proof_index as usize)
+        .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Proof references unknown sub proof {}", sub_proof_index)))
+}
+
+// Neither the credential nor the proof keeps the time of issuance, so the caller provides it.
+fn _check_issuance_date(issuance_date: &str) -> IndyResult<()> {
+    let is_valid = time::strptime(issuance_date, "%Y-%m-%dT%H:%M:%SZ")
+        .map(|tm| tm.rfc3339().to_string() == issuance_date)
+        .unwrap_or(false);
+
+    if !is_valid {
+        return Err(err_msg(IndyErrorKind::InvalidStructure,
+                           format!("Issuance date {:?} is not an RFC 3339 UTC time, e.g. \"2020-01-01T00:00:00Z\"", issuance_date)));
+    }
+
+    Ok(())
+}
+
+fn _context() -> Vec<String> {
+    vec![W3C_CREDENTIALS_CONTEXT.to_string(), ANONCREDS_CONTEXT.to_string()]
+}
+
+fn _credential_schema(cred_def_id: CredentialDefinitionId,
+                      schema_id: SchemaId,
+                      rev_reg_id: Option<RevocationRegistryId>) -> W3CCredentialSchema {
+    W3CCredentialSchema {
+        type_: ANONCREDS_DEFINITION_TYPE.to_string(),
+        definition: cred_def_id,
+        schema: schema_id,
+        revocation: rev_reg_id,
+    }
+}
+
+fn _issuer(cred_def_id: &CredentialDefinitionId) -> IndyResult<String> {
+    let issuer_did = cred_def_id.issuer_did()
+        .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Cannot get issuer DID from Credential Definition Id {:?}", cred_def_id)))?;
+
+    let issuer_did = if issuer_did.is_fully_qualified() { issuer_did } else { issuer_did.qualify(DEFAULT_ISSUER_METHOD) };
+
+    Ok(issuer_did.0)
+}
+
+fn _encode_proof_value<T: Serialize>(value: &T) -> IndyResult<String> {
+    let value = serde_json::to_vec(value)
+        .to_indy(IndyErrorKind::InvalidState, "Cannot serialize proof value")?;
+
+    Ok(base64::encode(&value))
+}
+
+fn _decode_proof_value<T: DeserializeOwned>(proof_value: &str) -> IndyResult<T> {
+    let value = base64::decode(proof_value)?;
+
+    serde_json::from_slice(&value)
+        .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize `proofValue`")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _proof_req() -> ProofRequestPayload {
+        serde_json::from_value(json!({
+            "nonce": "123432421212",
+            "name": "proof_req_1",
+            "version": "0.1",
+            "requested_attributes": {
+                "attr1_referent": { "name": "name" },
+                "attr2_referent": { "names": ["age", "sex"] }
+            },
+            "requested_predicates": {}
+        })).unwrap()
+    }
+
+    fn _requested_proof() -> RequestedProof {
+        serde_json::from_value(json!({
+            "revealed_attrs": {
+                "attr1_referent": { "sub_proof_index": 0, "raw": "Alex", "encoded": "1139481716457488690172217916278103335" }
+            },
+            "revealed_attr_groups": {
+                "attr2_referent": {
+                    "sub_proof_index": 1,
+                    "values": {
+                        "age": { "raw": "28", "encoded": "28" },
+                        "sex": { "raw": "male", "encoded": "5944657099558967239210949258394887428692050081607692519917050011144233115103" }
+                    }
+                }
+            }
+        })).unwrap()
+    }
+
+    #[test]
+    fn credential_subjects_works() {
+        let subjects = _credential_subjects(&_proof_req(), &_requested_proof(), 2).unwrap();
+
+        assert_eq!(2, subjects.len());
+        assert_eq!(1, subjects[0].len());
+        assert_eq!("Alex", subjects[0]["name"]);
+        assert_eq!(2, subjects[1].len());
+        assert_eq!("28", subjects[1]["age"]);
+        assert_eq!("male", subjects[1]["sex"]);
+    }
+
+    #[test]
+    fn credential_subjects_works_for_unknown_sub_proof() {
+        let res = _credential_subjects(&_proof_req(), &_requested_proof(), 1);
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn issuer_works() {
+        let cred_def_id = CredentialDefinitionId("NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:TAG".to_string());
+        assert_eq!("did:sov:NcYxiDXkpYi6ov5FcYDi1e", _issuer(&cred_def_id).unwrap());
+    }
+
+    #[test]
+    fn issuer_works_for_fully_qualified() {
+        let cred_def_id = CredentialDefinitionId("creddef:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:TAG".to_string());
+        assert_eq!("did:sov:NcYxiDXkpYi6ov5FcYDi1e", _issuer(&cred_def_id).unwrap());
+    }
+
+    #[test]
+    fn check_issuance_date_works() {
+        _check_issuance_date("2020-01-01T00:00:00Z").unwrap();
+        _check_issuance_date("2006-10-17T23:59:59Z").unwrap();
+    }
+
+    #[test]
+    fn check_issuance_date_works_for_invalid_date() {
+        assert_kind!(IndyErrorKind::InvalidStructure, _check_issuance_date(""));
+        assert_kind!(IndyErrorKind::InvalidStructure, _check_issuance_date("2020-01-01"));
+        assert_kind!(IndyErrorKind::InvalidStructure, _check_issuance_date("2020-01-01T00:00:00+01:00"));
+        assert_kind!(IndyErrorKind::InvalidStructure, _check_issuance_date("2020-01-01T00:00:00Zabc"));
+    }
+
+    #[test]
+    fn decode_proof_value_works_for_invalid_base64() {
+        let res = _decode_proof_value::<PresentedCredentialProofValue>("!!!");
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+}
@@ -0,0 +1,136 @@
+use std::rc::Rc;
+use std::sync::{Mutex, RwLock};
+
+use indy_api_types::WalletHandle;
+use indy_api_types::errors::prelude::*;
+use indy_wallet::{RecordOptions, SearchOptions, WalletService};
+
+use crate::domain::audit::{AuditChainHead, AuditConfig, AuditEvent, AuditLogVerification, AUDIT_CHAIN_HEAD_ID};
+use crate::domain::sortable_u64;
+use crate::services::anoncreds::helpers::get_seconds_since_epoch;
+
+pub type AuditSink = Box<dyn Fn(&AuditEvent) + Send + Sync>;
+
+struct AuditSettings {
+    config: AuditConfig,
+    sink: Option<AuditSink>,
+}
+
+lazy_static! {
+    static ref SETTINGS: RwLock<Option<AuditSettings>> = RwLock::new(None);
+    // Events that are passed to the sink only are chained across all wallets of the process.
+    static ref SINK_CHAIN_HEAD: Mutex<AuditChainHead> = Mutex::new(AuditChainHead::default());
+}
+
+/// Enables audit log. It stays disabled if events go neither to wallets nor to the sink.
+pub fn set_audit_log(config: AuditConfig, sink: Option<AuditSink>) {
+    let settings = if config.wallet || sink.is_some() {
+        Some(AuditSettings { config, sink })
+    } else {
+        None
+    };
+
+    *SETTINGS.write().unwrap() = settings;
+}
+
+pub struct AuditService {
+    wallet_service: Rc<WalletService>,
+}
+
+impl AuditService {
+    pub fn new(wallet_service: Rc<WalletService>) -> AuditService {
+        AuditService {
+            wallet_service,
+        }
+    }
+
+    /// Records the event of an operation that has succeeded. Audit log doesn't fail the operation:
+    /// an event that can't be recorded is logged as a warning and the chain is kept as it was.
+    pub fn record(&self, wallet_handle: WalletHandle, event: AuditEvent) {
+        let event_type = event.event;
+
+        if let Err(err) = self._record(wallet_handle, event) {
+            warn!("Audit event {:?} has not been recorded: {:?}", event_type, err);
+        }
+    }
+
+    fn _record(&self, wallet_handle: WalletHandle, event: AuditEvent) -> IndyResult<()> {
+        let settings = SETTINGS.read().unwrap();
+
+        let settings = match *settings {
+            Some(ref settings) if settings.config.is_recorded(event.event) => settings,
+            _ => return Ok(())
+        };
+
+        trace!("record >>> wallet_handle: {:?}, event: {:?}", wallet_handle, event);
+
+        let timestamp = get_seconds_since_epoch()?;
+
+        let event = if settings.config.wallet {
+            let head = self.wallet_service.get_indy_opt_object::<AuditChainHead>(wallet_handle, AUDIT_CHAIN_HEAD_ID, &RecordOptions::id_value())?
+                .unwrap_or_default();
+
+            let event = event.chain(&head, timestamp)?;
+
+            self.wallet_service.add_indy_object(wallet_handle, &AuditService::_event_id(event.seq_no), &event, &event.tags())?;
+            self.wallet_service.upsert_indy_object(wallet_handle, AUDIT_CHAIN_HEAD_ID, &AuditChainHead::of(&event))?;
+            event
+        } else {
+            let mut head = SINK_CHAIN_HEAD.lock().unwrap();
+            let event = event.chain(&head, timestamp)?;
+            *head = AuditChainHead::of(&event);
+            event
+        };
+
+        if let Some(ref sink) = settings.sink {
+            sink(&event);
+        }
+
+        trace!("record <<< seq_no: {:?}", event.seq_no);
+
+        Ok(())
+    }
+
+    /// Returns events stored in the wallet that match wql `query_json`, ordered by `seq_no`.
+    pub fn get_events(&self, wallet_handle: WalletHandle, query_json: &str) -> IndyResult<Vec<AuditEvent>> {
+        trace!("get_events >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
+
+        let mut search = self.wallet_service.search_indy_records::<AuditEvent>(wallet_handle, query_json, &SearchOptions::id_value())?;
+
+        let mut events: Vec<AuditEvent> = Vec::new();
+
+        while let Some(record) = search.fetch_next_record()? {
+            let value = record.get_value()
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "AuditEvent not found"))?;
+
+            events.push(serde_json::from_str(value)
+                .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize AuditEvent")?);
+        }
+
+        events.sort_by_key(|event| event.seq_no);
+
+        trace!("get_events <<< count: {:?}", events.len());
+
+        Ok(events)
+    }
+
+    pub fn verify(&self, wallet_handle: WalletHandle) -> IndyResult<AuditLogVerification> {
+        trace!("verify >>> wallet_handle: {:?}", wallet_handle);
+
+        let events = self.get_events(wallet_handle, "{}")?;
+
+        let head = self.wallet_service.get_indy_opt_object::<AuditChainHead>(wallet_handle, AUDIT_CHAIN_HEAD_ID, &RecordOptions::id_value())?
+            .unwrap_or_default();
+
+        let res = AuditLogVerification::verify(&events, &head)?;
+
+        trace!("verify <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    // Record ids are ordered as the chain.
+    fn _event_id(seq_no: u64) -> String {
+        sortable_u64(seq_no)
+    }
+}
@@ -91,6 +91,19 @@ impl WritableBlob for DefaultWriter {
         trace!("finalize <<< {}", res);
         Ok(res)
     }
+
+    fn abort(self: Box<Self>) -> IndyResult<()> {
+        trace!("abort >>>");
+
+        let id = self.id;
+        drop(self); // close the file before removing it
+
+        fs::remove_file(&tmp_storage_file(id))
+            .map_err(map_err_trace!())?;
+
+        trace!("abort <<<");
+        Ok(())
+    }
 }
 
 fn tmp_storage_file(id: i32) -> PathBuf {
@@ -21,6 +21,7 @@ trait Writer {
 trait WritableBlob {
     fn append(&mut self, bytes: &[u8]) -> IndyResult<usize>;
     fn finalize(&mut self, hash: &[u8]) -> IndyResult<String>;
+    fn abort(self: Box<Self>) -> IndyResult<()>;
 }
 
 trait ReaderType {
@@ -109,6 +110,13 @@ impl BlobStorageService {
         writer.finalize(hash.as_slice())
             .map(|location| (location, hash))
     }
+
+    pub fn abort(&self, handle: i32) -> IndyResult<()> {
+        let (writer, _) = self.writer_blobs.try_borrow_mut()?
+            .remove(&handle).ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Invalid BlobStorage handle"))?; // FIXME: Review error kind
+
+        writer.abort()
+    }
 }
 
 /* Reader */
@@ -269,6 +269,7 @@ impl LedgerService {
                 version: res.data.version,
                 attr_names: res.data.attr_names.into(),
                 seq_no: Some(res.seq_no),
+                attr_types: None,
             },
             GetSchemaReplyResult::GetSchemaReplyResultV1(res) => {
                 SchemaV1 {
@@ -280,6 +281,7 @@ impl LedgerService {
                         None => res.txn.data.id
                     },
                     seq_no: Some(res.txn_metadata.seq_no),
+                    attr_types: None,
                 }
             }
         };
@@ -452,6 +454,16 @@ impl LedgerService {
         build_result!(GetTxnAuthorAgreementOperation, identifier, data)
     }
 
+    #[logfn(Info)]
+    pub fn parse_get_txn_author_agreement_response(&self, get_txn_author_agreement_response: &str) -> IndyResult<String> {
+        let reply: Reply<GetTxnAuthorAgreementResult> = LedgerService::parse_response(get_txn_author_agreement_response)?;
+
+        let taa = reply.result().data
+            .ok_or_else(|| err_msg(IndyErrorKind::LedgerItemNotFound, "Transaction Author Agreement not found"))?;
+
+        Ok(taa.to_string())
+    }
+
     #[logfn(Info)]
     pub fn build_acceptance_mechanisms_request(&self, identifier: &DidValue, aml: AcceptanceMechanisms, version: &str, aml_context: Option<&str>) -> IndyResult<String> {
         build_result!(SetAcceptanceMechanismOperation, Some(identifier), aml, version.to_string(), aml_context.map(String::from))
@@ -725,6 +737,7 @@ mod tests {
             version: "1.0".to_string(),
             attr_names,
             seq_no: None,
+            attr_types: None,
         };
 
         let expected_result = json!({
@@ -1193,6 +1206,43 @@ mod tests {
             let request = ledger_service.build_get_txn_author_agreement_request(Some(&identifier()), Some(&data)).unwrap();
             check_request(&request, expected_result);
         }
+
+        #[test]
+        fn parse_get_txn_author_agreement_response_works() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_TXN_AUTHR_AGRMT,
+                    "data": {"text": TEXT, "version": VERSION, "ratification_ts": 12345},
+                    "seqNo": 1,
+                    "txnTime": 12345
+                }
+            }).to_string();
+
+            let taa = ledger_service.parse_get_txn_author_agreement_response(&response).unwrap();
+            let taa: serde_json::Value = serde_json::from_str(&taa).unwrap();
+            assert_eq!(json!({"text": TEXT, "version": VERSION, "ratification_ts": 12345}), taa);
+        }
+
+        #[test]
+        fn parse_get_txn_author_agreement_response_works_for_not_found() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_TXN_AUTHR_AGRMT,
+                    "data": null,
+                    "seqNo": null,
+                    "txnTime": null
+                }
+            }).to_string();
+
+            let res = ledger_service.parse_get_txn_author_agreement_response(&response);
+            assert_kind!(IndyErrorKind::LedgerItemNotFound, res);
+        }
     }
 
     mod acceptance_mechanism {
@@ -16,6 +16,7 @@ use crate::commands::payments::PaymentsCommand;
 use crate::commands::cache::CacheCommand;
 use std::fmt;
 use crate::commands::metrics::MetricsCommand;
+use crate::commands::audit::AuditCommand;
 
 impl fmt::Display for CommandMetric {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -37,7 +38,7 @@ impl From<usize> for CommandMetric {
 impl From<&IssuerCommand> for CommandMetric {
     fn from(cmd: &IssuerCommand) -> Self {
         match cmd {
-            IssuerCommand::CreateSchema(_, _, _, _, _) => {
+            IssuerCommand::CreateSchema(_, _, _, _, _, _) => {
                 CommandMetric::IssuerCommandCreateSchema
             }
             IssuerCommand::CreateAndStoreCredentialDefinition(_, _, _, _, _, _, _) => {
@@ -55,18 +56,39 @@ impl From<&IssuerCommand> for CommandMetric {
             IssuerCommand::RotateCredentialDefinitionApply(_, _, _) => {
                 CommandMetric::IssuerCommandRotateCredentialDefinitionApply
             }
-            IssuerCommand::CreateAndStoreRevocationRegistry(_, _, _, _, _, _, _, _) => {
+            IssuerCommand::CreateAndStoreRevocationRegistry(_, _, _, _, _, _, _, _, _) => {
                 CommandMetric::IssuerCommandCreateAndStoreRevocationRegistry
             }
+            IssuerCommand::CreateAndStoreRevocationRegistryContinue(_, _) => {
+                CommandMetric::IssuerCommandCreateAndStoreRevocationRegistryContinue
+            }
+            IssuerCommand::CreateAndStoreRevocationRegistryTails(_, _) => {
+                CommandMetric::IssuerCommandCreateAndStoreRevocationRegistryTails
+            }
             IssuerCommand::CreateCredentialOffer(_, _, _) => {
                 CommandMetric::IssuerCommandCreateCredentialOffer
             }
-            IssuerCommand::CreateCredential(_, _, _, _, _, _, _) => {
+            IssuerCommand::CreateCredential(_, _, _, _, _, _, _, _) => {
                 CommandMetric::IssuerCommandCreateCredential
             }
             IssuerCommand::RevokeCredential(_, _, _, _, _) => {
                 CommandMetric::IssuerCommandRevokeCredential
             }
+            IssuerCommand::RevokeCredentialByRecord(_, _, _, _) => {
+                CommandMetric::IssuerCommandRevokeCredentialByRecord
+            }
+            IssuerCommand::GetIssuanceRecord(_, _, _) => {
+                CommandMetric::IssuerCommandGetIssuanceRecord
+            }
+            IssuerCommand::SearchIssuanceRecords(_, _, _) => {
+                CommandMetric::IssuerCommandSearchIssuanceRecords
+            }
+            IssuerCommand::FetchIssuanceRecords(_, _, _) => {
+                CommandMetric::IssuerCommandFetchIssuanceRecords
+            }
+            IssuerCommand::CloseIssuanceRecordsSearch(_, _) => {
+                CommandMetric::IssuerCommandCloseIssuanceRecordsSearch
+            }
             IssuerCommand::MergeRevocationRegistryDeltas(_, _, _) => {
                 CommandMetric::IssuerCommandMergeRevocationRegistryDeltas
             }
@@ -78,12 +100,20 @@ impl From<&ProverCommand> for CommandMetric {
     fn from(cmd: &ProverCommand) -> Self {
         match cmd {
             ProverCommand::CreateMasterSecret(_, _, _) => { CommandMetric::ProverCommandCreateMasterSecret }
+            ProverCommand::ListMasterSecrets(_, _) => { CommandMetric::ProverCommandListMasterSecrets }
+            ProverCommand::SetDefaultMasterSecret(_, _, _) => { CommandMetric::ProverCommandSetDefaultMasterSecret }
+            ProverCommand::RotateMasterSecret(_, _, _, _) => { CommandMetric::ProverCommandRotateMasterSecret }
             ProverCommand::CreateCredentialRequest(_, _, _, _, _, _) => { CommandMetric::ProverCommandCreateCredentialRequest }
             ProverCommand::SetCredentialAttrTagPolicy(_, _, _, _, _) => { CommandMetric::ProverCommandSetCredentialAttrTagPolicy }
             ProverCommand::GetCredentialAttrTagPolicy(_, _, _) => { CommandMetric::ProverCommandGetCredentialAttrTagPolicy }
+            ProverCommand::SetCredentialExpiryAttr(_, _, _, _) => { CommandMetric::ProverCommandSetCredentialExpiryAttr }
+            ProverCommand::SetCredentialIdentityAttr(_, _, _, _) => { CommandMetric::ProverCommandSetCredentialIdentityAttr }
             ProverCommand::StoreCredential(_, _, _, _, _, _, _) => { CommandMetric::ProverCommandStoreCredential }
             ProverCommand::GetCredentials(_, _, _) => { CommandMetric::ProverCommandGetCredentials }
             ProverCommand::GetCredential(_, _, _) => { CommandMetric::ProverCommandGetCredential }
+            ProverCommand::GetCredentialStatus(_, _, _) => { CommandMetric::ProverCommandGetCredentialStatus }
+            ProverCommand::ExportCredential(_, _, _, _) => { CommandMetric::ProverCommandExportCredential }
+            ProverCommand::ImportCredential(_, _, _, _) => { CommandMetric::ProverCommandImportCredential }
             ProverCommand::DeleteCredential(_, _, _) => { CommandMetric::ProverCommandDeleteCredential }
             ProverCommand::SearchCredentials(_, _, _) => { CommandMetric::ProverCommandSearchCredentials }
             ProverCommand::FetchCredentials(_, _, _) => { CommandMetric::ProverCommandFetchCredentials }
@@ -92,8 +122,10 @@ impl From<&ProverCommand> for CommandMetric {
             ProverCommand::SearchCredentialsForProofReq(_, _, _, _) => { CommandMetric::ProverCommandSearchCredentialsForProofReq }
             ProverCommand::FetchCredentialForProofReq(_, _, _, _) => { CommandMetric::ProverCommandFetchCredentialForProofReq }
             ProverCommand::CloseCredentialsSearchForProofReq(_, _) => { CommandMetric::ProverCommandCloseCredentialsSearchForProofReq }
-            ProverCommand::CreateProof(_, _, _, _, _, _, _, _) => { CommandMetric::ProverCommandCreateProof }
+            ProverCommand::ExplainProofReq(_, _, _, _) => { CommandMetric::ProverCommandExplainProofReq }
+            ProverCommand::CreateProof(_, _, _, _, _, _, _, _, _) => { CommandMetric::ProverCommandCreateProof }
             ProverCommand::CreateRevocationState(_, _, _, _, _, _) => { CommandMetric::ProverCommandCreateRevocationState }
+            ProverCommand::CreateCredentialRevocationState(_, _, _, _, _, _, _) => { CommandMetric::ProverCommandCreateCredentialRevocationState }
             ProverCommand::UpdateRevocationState(_, _, _, _, _, _, _) => { CommandMetric::ProverCommandUpdateRevocationState }
         }
     }
@@ -102,8 +134,11 @@ impl From<&ProverCommand> for CommandMetric {
 impl From<&VerifierCommand> for CommandMetric {
     fn from(cmd: &VerifierCommand) -> Self {
         match cmd {
-            VerifierCommand::VerifyProof(_, _, _, _, _, _, _) => { CommandMetric::VerifierCommandVerifyProof }
+            VerifierCommand::VerifyProof(_, _, _, _, _, _, _, _) => { CommandMetric::VerifierCommandVerifyProof }
+            VerifierCommand::VerifyProofDetailed(_, _, _, _, _, _, _, _) => { CommandMetric::VerifierCommandVerifyProofDetailed }
+            VerifierCommand::VerifyW3CPresentation(_, _, _, _, _, _, _) => { CommandMetric::VerifierCommandVerifyW3CPresentation }
             VerifierCommand::GenerateNonce(_) => { CommandMetric::VerifierCommandGenerateNonce }
+            VerifierCommand::BuildProofRequest(_, _) => { CommandMetric::VerifierCommandBuildProofRequest }
         }
     }
 }
@@ -118,6 +153,12 @@ impl From<&Command> for CommandMetric {
                     AnoncredsCommand::Prover(cmd) => { cmd.into() }
                     AnoncredsCommand::Verifier(cmd) => { cmd.into() }
                     AnoncredsCommand::ToUnqualified(_, _) => { CommandMetric::AnoncredsCommandToUnqualified }
+                    AnoncredsCommand::EncodeCredentialValues(_, _, _) => { CommandMetric::AnoncredsCommandEncodeCredentialValues }
+                    AnoncredsCommand::BuildDatePredicate(_, _) => { CommandMetric::AnoncredsCommandBuildDatePredicate }
+                    AnoncredsCommand::CredentialToW3C(_, _, _) => { CommandMetric::AnoncredsCommandCredentialToW3C }
+                    AnoncredsCommand::CredentialFromW3C(_, _, _) => { CommandMetric::AnoncredsCommandCredentialFromW3C }
+                    AnoncredsCommand::ProofToW3C(_, _, _, _) => { CommandMetric::AnoncredsCommandProofToW3C }
+                    AnoncredsCommand::ProofFromW3C(_, _) => { CommandMetric::AnoncredsCommandProofFromW3C }
                 }
             }
             Command::BlobStorage(cmd) => {
@@ -184,6 +225,14 @@ impl From<&Command> for CommandMetric {
                     LedgerCommand::GetSchemaContinue(_, _, _) => { CommandMetric::LedgerCommandGetSchemaContinue }
                     LedgerCommand::GetCredDef(_, _, _, _) => { CommandMetric::LedgerCommandGetCredDef }
                     LedgerCommand::GetCredDefContinue(_, _, _) => { CommandMetric::LedgerCommandGetCredDefContinue }
+                    LedgerCommand::GetNym(_, _, _, _) => { CommandMetric::LedgerCommandGetNym }
+                    LedgerCommand::GetNymContinue(_, _) => { CommandMetric::LedgerCommandGetNymContinue }
+                    LedgerCommand::GetRevocRegDef(_, _, _, _) => { CommandMetric::LedgerCommandGetRevocRegDef }
+                    LedgerCommand::GetRevocRegDefContinue(_, _) => { CommandMetric::LedgerCommandGetRevocRegDefContinue }
+                    LedgerCommand::GetRevocRegDelta(_, _, _, _, _, _) => { CommandMetric::LedgerCommandGetRevocRegDelta }
+                    LedgerCommand::GetRevocRegDeltaContinue(_, _) => { CommandMetric::LedgerCommandGetRevocRegDeltaContinue }
+                    LedgerCommand::GetTxnAuthorAgreement(_, _, _, _) => { CommandMetric::LedgerCommandGetTxnAuthorAgreement }
+                    LedgerCommand::GetTxnAuthorAgreementContinue(_, _) => { CommandMetric::LedgerCommandGetTxnAuthorAgreementContinue }
                     LedgerCommand::BuildTxnAuthorAgreementRequest(_, _, _, _, _, _) => { CommandMetric::LedgerCommandBuildTxnAuthorAgreementRequest }
                     LedgerCommand::BuildDisableAllTxnAuthorAgreementsRequest(_, _) => { CommandMetric::LedgerCommandBuildDisableAllTxnAuthorAgreementsRequest }
                     LedgerCommand::BuildGetTxnAuthorAgreementRequest(_, _, _) => { CommandMetric::LedgerCommandBuildGetTxnAuthorAgreementRequest }
@@ -207,6 +256,7 @@ impl From<&Command> for CommandMetric {
                     PoolCommand::Refresh(_, _) => { CommandMetric::PoolCommandRefresh }
                     PoolCommand::RefreshAck(_, _) => { CommandMetric::PoolCommandRefreshAck }
                     PoolCommand::SetProtocolVersion(_, _) => { CommandMetric::PoolCommandSetProtocolVersion }
+                    PoolCommand::GetStatus(_, _) => { CommandMetric::PoolCommandGetStatus }
                 }
             }
             Command::Did(cmd) => {
@@ -243,6 +293,9 @@ impl From<&Command> for CommandMetric {
                     WalletCommand::ExportContinue(_, _, _, _, _) => { CommandMetric::WalletCommandExportContinue }
                     WalletCommand::Import(_, _, _, _) => { CommandMetric::WalletCommandImport }
                     WalletCommand::ImportContinue(_, _, _, _, _) => { CommandMetric::WalletCommandImportContinue }
+                    WalletCommand::Migrate(_, _, _, _) => { CommandMetric::WalletCommandMigrate }
+                    WalletCommand::MigrateContinue(_, _, _, _, _, _) => { CommandMetric::WalletCommandMigrateContinue }
+                    WalletCommand::MigrateBatch(_, _, _) => { CommandMetric::WalletCommandMigrateBatch }
                     WalletCommand::GenerateKey(_, _) => { CommandMetric::WalletCommandGenerateKey }
                     WalletCommand::DeriveKey(_, _) => { CommandMetric::WalletCommandDeriveKey }
                 }
@@ -316,10 +369,28 @@ impl From<&Command> for CommandMetric {
                     CacheCommand::GetCredDefContinue(_, _, _, _) => { CommandMetric::CacheCommandGetCredDefContinue }
                     CacheCommand::PurgeSchemaCache(_, _, _) => { CommandMetric::CacheCommandPurgeSchemaCache }
                     CacheCommand::PurgeCredDefCache(_, _, _) => { CommandMetric::CacheCommandPurgeCredDefCache }
+                    CacheCommand::GetNym(_, _, _, _, _, _) => { CommandMetric::CacheCommandGetNym }
+                    CacheCommand::GetNymContinue(_, _, _, _) => { CommandMetric::CacheCommandGetNymContinue }
+                    CacheCommand::GetRevocRegDef(_, _, _, _, _, _) => { CommandMetric::CacheCommandGetRevocRegDef }
+                    CacheCommand::GetRevocRegDefContinue(_, _, _, _) => { CommandMetric::CacheCommandGetRevocRegDefContinue }
+                    CacheCommand::GetRevocRegDelta(_, _, _, _, _, _, _, _) => { CommandMetric::CacheCommandGetRevocRegDelta }
+                    CacheCommand::GetRevocRegDeltaContinue(_, _, _, _) => { CommandMetric::CacheCommandGetRevocRegDeltaContinue }
+                    CacheCommand::GetTxnAuthorAgreement(_, _, _, _, _, _) => { CommandMetric::CacheCommandGetTxnAuthorAgreement }
+                    CacheCommand::GetTxnAuthorAgreementContinue(_, _, _, _) => { CommandMetric::CacheCommandGetTxnAuthorAgreementContinue }
+                    CacheCommand::PurgeNymCache(_, _, _) => { CommandMetric::CacheCommandPurgeNymCache }
+                    CacheCommand::PurgeRevocRegDefCache(_, _, _) => { CommandMetric::CacheCommandPurgeRevocRegDefCache }
+                    CacheCommand::PurgeRevocRegDeltaCache(_, _, _) => { CommandMetric::CacheCommandPurgeRevocRegDeltaCache }
+                    CacheCommand::PurgeTxnAuthorAgreementCache(_, _, _) => { CommandMetric::CacheCommandPurgeTxnAuthorAgreementCache }
                 }
             }
             Command::Metrics(cmd) => {
-                match cmd { MetricsCommand::CollectMetrics(_) => { CommandMetric::MetricsCommandCollectMetrics } }
+                match cmd { MetricsCommand::CollectMetrics(_, _) => { CommandMetric::MetricsCommandCollectMetrics } }
+            }
+            Command::Audit(cmd) => {
+                match cmd {
+                    AuditCommand::GetAuditLog(_, _, _) => { CommandMetric::AuditCommandGetAuditLog }
+                    AuditCommand::VerifyAuditLog(_, _) => { CommandMetric::AuditCommandVerifyAuditLog }
+                }
             }
         }
     }
@@ -337,18 +408,33 @@ pub enum CommandMetric {
     IssuerCommandRotateCredentialDefinitionStartComplete,
     IssuerCommandRotateCredentialDefinitionApply,
     IssuerCommandCreateAndStoreRevocationRegistry,
+    IssuerCommandCreateAndStoreRevocationRegistryContinue,
+    IssuerCommandCreateAndStoreRevocationRegistryTails,
     IssuerCommandCreateCredentialOffer,
     IssuerCommandCreateCredential,
     IssuerCommandRevokeCredential,
+    IssuerCommandRevokeCredentialByRecord,
+    IssuerCommandGetIssuanceRecord,
+    IssuerCommandSearchIssuanceRecords,
+    IssuerCommandFetchIssuanceRecords,
+    IssuerCommandCloseIssuanceRecordsSearch,
     IssuerCommandMergeRevocationRegistryDeltas,
     // ProverCommand
     ProverCommandCreateMasterSecret,
+    ProverCommandListMasterSecrets,
+    ProverCommandSetDefaultMasterSecret,
+    ProverCommandRotateMasterSecret,
     ProverCommandCreateCredentialRequest,
     ProverCommandSetCredentialAttrTagPolicy,
     ProverCommandGetCredentialAttrTagPolicy,
+    ProverCommandSetCredentialExpiryAttr,
+    ProverCommandSetCredentialIdentityAttr,
     ProverCommandStoreCredential,
     ProverCommandGetCredentials,
     ProverCommandGetCredential,
+    ProverCommandGetCredentialStatus,
+    ProverCommandExportCredential,
+    ProverCommandImportCredential,
     ProverCommandDeleteCredential,
     ProverCommandSearchCredentials,
     ProverCommandFetchCredentials,
@@ -357,14 +443,25 @@ pub enum CommandMetric {
     ProverCommandSearchCredentialsForProofReq,
     ProverCommandFetchCredentialForProofReq,
     ProverCommandCloseCredentialsSearchForProofReq,
+    ProverCommandExplainProofReq,
     ProverCommandCreateProof,
     ProverCommandCreateRevocationState,
+    ProverCommandCreateCredentialRevocationState,
     ProverCommandUpdateRevocationState,
     // VerifierCommand
     VerifierCommandVerifyProof,
+    VerifierCommandVerifyProofDetailed,
+    VerifierCommandVerifyW3CPresentation,
     VerifierCommandGenerateNonce,
+    VerifierCommandBuildProofRequest,
     // AnoncredsCommand
     AnoncredsCommandToUnqualified,
+    AnoncredsCommandEncodeCredentialValues,
+    AnoncredsCommandBuildDatePredicate,
+    AnoncredsCommandCredentialToW3C,
+    AnoncredsCommandCredentialFromW3C,
+    AnoncredsCommandProofToW3C,
+    AnoncredsCommandProofFromW3C,
     // BlobStorage
     BlobStorageCommandOpenReader,
     BlobStorageCommandOpenWriter,
@@ -422,6 +519,14 @@ pub enum CommandMetric {
     LedgerCommandGetSchemaContinue,
     LedgerCommandGetCredDef,
     LedgerCommandGetCredDefContinue,
+    LedgerCommandGetNym,
+    LedgerCommandGetNymContinue,
+    LedgerCommandGetRevocRegDef,
+    LedgerCommandGetRevocRegDefContinue,
+    LedgerCommandGetRevocRegDelta,
+    LedgerCommandGetRevocRegDeltaContinue,
+    LedgerCommandGetTxnAuthorAgreement,
+    LedgerCommandGetTxnAuthorAgreementContinue,
     LedgerCommandBuildTxnAuthorAgreementRequest,
     LedgerCommandBuildDisableAllTxnAuthorAgreementsRequest,
     LedgerCommandBuildGetTxnAuthorAgreementRequest,
@@ -442,6 +547,7 @@ pub enum CommandMetric {
     PoolCommandRefresh,
     PoolCommandRefreshAck,
     PoolCommandSetProtocolVersion,
+    PoolCommandGetStatus,
     // DidCommand
     DidCommandCreateAndStoreMyDid,
     DidCommandReplaceKeysStart,
@@ -472,6 +578,9 @@ pub enum CommandMetric {
     WalletCommandExportContinue,
     WalletCommandImport,
     WalletCommandImportContinue,
+    WalletCommandMigrate,
+    WalletCommandMigrateContinue,
+    WalletCommandMigrateBatch,
     WalletCommandGenerateKey,
     WalletCommandDeriveKey,
     // PairwiseCommand
@@ -533,8 +642,23 @@ pub enum CommandMetric {
     CacheCommandGetCredDefContinue,
     CacheCommandPurgeSchemaCache,
     CacheCommandPurgeCredDefCache,
+    CacheCommandGetNym,
+    CacheCommandGetNymContinue,
+    CacheCommandGetRevocRegDef,
+    CacheCommandGetRevocRegDefContinue,
+    CacheCommandGetRevocRegDelta,
+    CacheCommandGetRevocRegDeltaContinue,
+    CacheCommandGetTxnAuthorAgreement,
+    CacheCommandGetTxnAuthorAgreementContinue,
+    CacheCommandPurgeNymCache,
+    CacheCommandPurgeRevocRegDefCache,
+    CacheCommandPurgeRevocRegDeltaCache,
+    CacheCommandPurgeTxnAuthorAgreementCache,
     // MetricsCommand
     MetricsCommandCollectMetrics,
+    // AuditCommand
+    AuditCommandGetAuditLog,
+    AuditCommandVerifyAuditLog,
     // Exit
     Exit,
 }
\ No newline at end of file
@@ -1,16 +1,34 @@
 use crate::services::metrics::command_metrics::CommandMetric;
 use convert_case::{Case, Casing};
 use indy_api_types::errors::{IndyErrorKind, IndyResult, IndyResultExt};
-use models::{MetricsValue, CommandCounters};
+use models::{MetricsValue, CommandCounters, PoolCounters};
 use serde_json::{Map, Value};
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::sync::Mutex;
 
 pub mod command_metrics;
 pub mod models;
+pub mod openmetrics;
 
 const COMMANDS_COUNT: usize = MetricsService::commands_count();
 
+pub const POOL_REPLY_TYPE_REPLY: &str = "reply";
+pub const POOL_REPLY_TYPE_REQNACK: &str = "reqnack";
+pub const POOL_REPLY_TYPE_REJECT: &str = "reject";
+pub const POOL_REPLY_TYPE_REQACK: &str = "reqack";
+pub const POOL_REPLY_TYPE_TIMEOUT: &str = "timeout";
+
+pub const POOL_REQUEST_SUCCESS: &str = "success";
+pub const POOL_REQUEST_CONSENSUS_IMPOSSIBLE: &str = "consensus_impossible";
+pub const POOL_REQUEST_TERMINATED: &str = "terminated";
+pub const POOL_REQUEST_FAILED: &str = "failed";
+
+lazy_static! {
+    // Pool requests are processed in the pool threads, so these counters can't live in MetricsService.
+    static ref POOL_COUNTERS: Mutex<PoolCounters> = Mutex::new(PoolCounters::default());
+}
+
 pub struct MetricsService {
     queued_counters: RefCell<[CommandCounters; COMMANDS_COUNT]>,
     executed_counters: RefCell<[CommandCounters; COMMANDS_COUNT]>,
@@ -100,6 +118,78 @@ impl MetricsService {
         Ok(())
     }
 
+    pub fn pool_node_replied(pool_name: &str, node_alias: &str, reply_type: &str) {
+        POOL_COUNTERS.lock().unwrap().node_replied(pool_name, node_alias, reply_type);
+    }
+
+    pub fn pool_request_finished(pool_name: &str, outcome: &str) {
+        POOL_COUNTERS.lock().unwrap().request_finished(pool_name, outcome);
+    }
+
+    pub fn append_pool_metrics(&self, metrics_map: &mut Map<String, Value>) -> IndyResult<()> {
+        let pool_counters = POOL_COUNTERS.lock().unwrap().clone();
+
+        let mut pool_node_replies_count = Vec::new();
+        for ((pool, node, reply_type), count) in pool_counters.node_replies {
+            let mut tags = HashMap::<String, String>::new();
+            tags.insert("pool".to_owned(), pool);
+            tags.insert("node".to_owned(), node);
+            tags.insert("type".to_owned(), reply_type);
+            pool_node_replies_count.push(self.get_metric_json(count as usize, tags)?);
+        }
+
+        let mut pool_requests_count = Vec::new();
+        for ((pool, outcome), count) in pool_counters.requests {
+            let mut tags = HashMap::<String, String>::new();
+            tags.insert("pool".to_owned(), pool);
+            tags.insert("outcome".to_owned(), outcome);
+            pool_requests_count.push(self.get_metric_json(count as usize, tags)?);
+        }
+
+        metrics_map.insert(
+            String::from("pool_node_replies_count"),
+            serde_json::to_value(pool_node_replies_count)
+                .to_indy(IndyErrorKind::IOError, "Unable to convert json")?,
+        );
+        metrics_map.insert(
+            String::from("pool_requests_count"),
+            serde_json::to_value(pool_requests_count)
+                .to_indy(IndyErrorKind::IOError, "Unable to convert json")?,
+        );
+
+        Ok(())
+    }
+
+    /// Writes command counters as OpenMetrics histogram. Commands that were never queued are skipped.
+    pub fn write_command_openmetrics(&self, out: &mut String) {
+        openmetrics::write_family(out, "commands_duration_ms", openmetrics::TYPE_HISTOGRAM,
+                                  "Duration of commands waiting in the queue and executing in milliseconds");
+
+        let executed_counters = self.executed_counters.borrow();
+        let queued_counters = self.queued_counters.borrow();
+
+        for index in 0..MetricsService::commands_count() {
+            if queued_counters[index].count == 0 && executed_counters[index].count == 0 {
+                continue;
+            }
+
+            let command_name = MetricsService::cmd_name(index);
+
+            for (stage, counters) in &[("executed", &executed_counters[index]), ("queued", &queued_counters[index])] {
+                let labels = vec![("command".to_string(), command_name.clone()), ("stage".to_string(), stage.to_string())];
+
+                for (index_bucket, bucket) in counters.duration_ms_bucket.iter().enumerate() {
+                    let mut bucket_labels = labels.clone();
+                    bucket_labels.push(("le".to_string(), models::bucket_le(index_bucket)));
+                    openmetrics::write_sample(out, "commands_duration_ms_bucket", &bucket_labels, bucket);
+                }
+
+                openmetrics::write_sample(out, "commands_duration_ms_sum", &labels, counters.duration_ms_sum);
+                openmetrics::write_sample(out, "commands_duration_ms_count", &labels, counters.count);
+            }
+        }
+    }
+
     fn get_metric_json(&self, value: usize, tags: HashMap<String, String>) -> IndyResult<Value> {
         let res = serde_json::to_value(MetricsValue::new(
             value,
@@ -273,6 +363,44 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_append_pool_metrics() {
+        let metrics_service = MetricsService::new();
+        let mut metrics_map = serde_json::Map::new();
+
+        MetricsService::pool_node_replied("test_append_pool_metrics", "Node1", POOL_REPLY_TYPE_REPLY);
+        MetricsService::pool_request_finished("test_append_pool_metrics", POOL_REQUEST_SUCCESS);
+
+        metrics_service.append_pool_metrics(&mut metrics_map).unwrap();
+
+        assert!(metrics_map["pool_node_replies_count"].as_array().unwrap()
+            .contains(&json!({"tags": {"pool": "test_append_pool_metrics", "node": "Node1", "type": "reply"}, "value": 1})));
+        assert!(metrics_map["pool_requests_count"].as_array().unwrap()
+            .contains(&json!({"tags": {"pool": "test_append_pool_metrics", "outcome": "success"}, "value": 1})));
+    }
+
+    #[test]
+    fn test_write_command_openmetrics() {
+        let metrics_service = MetricsService::new();
+        let index = CommandMetric::IssuerCommandCreateSchema;
+
+        metrics_service.cmd_left_queue(index, 1);
+        metrics_service.cmd_executed(index, 3);
+        metrics_service.cmd_executed(index, 700);
+
+        let mut out = String::new();
+        metrics_service.write_command_openmetrics(&mut out);
+
+        assert!(out.starts_with("# TYPE commands_duration_ms histogram\n"));
+        assert!(out.contains("commands_duration_ms_bucket{command=\"issuer_command_create_schema\",stage=\"executed\",le=\"2.0\"} 0\n"));
+        assert!(out.contains("commands_duration_ms_bucket{command=\"issuer_command_create_schema\",stage=\"executed\",le=\"5.0\"} 1\n"));
+        assert!(out.contains("commands_duration_ms_bucket{command=\"issuer_command_create_schema\",stage=\"executed\",le=\"+Inf\"} 2\n"));
+        assert!(out.contains("commands_duration_ms_sum{command=\"issuer_command_create_schema\",stage=\"executed\"} 703\n"));
+        assert!(out.contains("commands_duration_ms_count{command=\"issuer_command_create_schema\",stage=\"executed\"} 2\n"));
+        assert!(out.contains("commands_duration_ms_count{command=\"issuer_command_create_schema\",stage=\"queued\"} 1\n"));
+        assert!(!out.contains("metrics_command_collect_metrics"));
+    }
+
     fn generate_json(command: &str, stage: &str, value: usize) -> Value {
         json!({"tags":{"command": command, "stage": stage} ,"value": value})
     }
@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 const BUCKET_COUNT: usize = 16;
 const LIST_LE: [f64; BUCKET_COUNT-1] = [0.5, 1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0, 200.0, 500.0, 1000.0, 2000.0, 5000.0, 10000.0, 20000.0];
@@ -44,6 +44,35 @@ impl CommandCounters {
     }
 }
 
+/// Returns the upper bound of the bucket in the OpenMetrics `le` label format.
+pub fn bucket_le(index: usize) -> String {
+    match LIST_LE.get(index) {
+        Some(le) => format!("{:?}", le),
+        None => String::from("+Inf"),
+    }
+}
+
+/// Counters of pool requests. Updated from the pool threads.
+#[derive(Clone, Debug, Default)]
+pub struct PoolCounters {
+    pub node_replies: BTreeMap<(String /* pool */, String /* node */, String /* reply type */), u128>,
+    pub requests: BTreeMap<(String /* pool */, String /* outcome */), u128>,
+}
+
+impl PoolCounters {
+    pub fn node_replied(&mut self, pool_name: &str, node_alias: &str, reply_type: &str) {
+        *self.node_replies
+            .entry((pool_name.to_string(), node_alias.to_string(), reply_type.to_string()))
+            .or_insert(0) += 1;
+    }
+
+    pub fn request_finished(&mut self, pool_name: &str, outcome: &str) {
+        *self.requests
+            .entry((pool_name.to_string(), outcome.to_string()))
+            .or_insert(0) += 1;
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -55,4 +84,26 @@ mod test {
         assert_eq!(command_counters.duration_ms_sum, 0);
         assert_eq!(command_counters.duration_ms_bucket, [0; BUCKET_COUNT]);
     }
+
+    #[test]
+    fn test_bucket_le() {
+        assert_eq!(bucket_le(0), "0.5");
+        assert_eq!(bucket_le(1), "1.0");
+        assert_eq!(bucket_le(BUCKET_COUNT - 2), "20000.0");
+        assert_eq!(bucket_le(BUCKET_COUNT - 1), "+Inf");
+    }
+
+    #[test]
+    fn test_pool_counters_are_incremented() {
+        let mut pool_counters = PoolCounters::default();
+
+        pool_counters.node_replied("pool", "Node1", "reply");
+        pool_counters.node_replied("pool", "Node1", "reply");
+        pool_counters.node_replied("pool", "Node2", "timeout");
+        pool_counters.request_finished("pool", "success");
+
+        assert_eq!(pool_counters.node_replies[&("pool".to_string(), "Node1".to_string(), "reply".to_string())], 2);
+        assert_eq!(pool_counters.node_replies[&("pool".to_string(), "Node2".to_string(), "timeout".to_string())], 1);
+        assert_eq!(pool_counters.requests[&("pool".to_string(), "success".to_string())], 1);
+    }
 }
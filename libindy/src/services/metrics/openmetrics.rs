@@ -0,0 +1,109 @@
+use serde_json::{Map, Value};
+use std::fmt::Display;
+
+pub const TYPE_COUNTER: &str = "counter";
+pub const TYPE_GAUGE: &str = "gauge";
+pub const TYPE_HISTOGRAM: &str = "histogram";
+
+// (key in the JSON metrics map, metric family name, type, help)
+const FAMILIES: [(&str, &str, &str, &str); 7] = [
+    ("threadpool_threads_count", "threadpool_threads", TYPE_GAUGE, "Number of threads in the threadpool by state"),
+    ("wallet_count", "wallets", TYPE_GAUGE, "Number of wallets by state"),
+    ("pool_node_replies_count", "pool_node_replies", TYPE_COUNTER, "Number of node replies on pool requests by type"),
+    ("pool_requests_count", "pool_requests", TYPE_COUNTER, "Number of finished pool requests by outcome"),
+    ("wallet_storage_ops_count", "wallet_storage_ops", TYPE_COUNTER, "Number of wallet storage operations"),
+    ("wallet_storage_op_errors_count", "wallet_storage_op_errors", TYPE_COUNTER, "Number of failed wallet storage operations"),
+    ("wallet_storage_ops_duration_ms", "wallet_storage_ops_duration_ms", TYPE_COUNTER, "Duration of wallet storage operations in milliseconds"),
+];
+
+/// Renders gauges and counters collected in the JSON metrics map as OpenMetrics text.
+pub fn write_metrics_map(out: &mut String, metrics_map: &Map<String, Value>) {
+    for (key, name, type_, help) in FAMILIES.iter() {
+        let samples = match metrics_map.get(*key).and_then(Value::as_array) {
+            Some(samples) => samples,
+            None => continue
+        };
+
+        write_family(out, name, type_, help);
+
+        let sample_name = if *type_ == TYPE_COUNTER { format!("{}_total", name) } else { name.to_string() };
+
+        for sample in samples {
+            let mut labels: Vec<(String, String)> = sample["tags"].as_object()
+                .map(|tags| tags.iter()
+                    .map(|(k, v)| (k.to_string(), v.as_str().unwrap_or_default().to_string()))
+                    .collect())
+                .unwrap_or_default();
+            labels.sort();
+
+            write_sample(out, &sample_name, &labels, sample["value"].as_u64().unwrap_or(0));
+        }
+    }
+}
+
+pub fn write_family(out: &mut String, name: &str, type_: &str, help: &str) {
+    out.push_str(&format!("# TYPE {} {}\n", name, type_));
+    out.push_str(&format!("# HELP {} {}\n", name, help));
+}
+
+pub fn write_sample<V: Display>(out: &mut String, name: &str, labels: &[(String, String)], value: V) {
+    out.push_str(name);
+
+    if !labels.is_empty() {
+        let labels = labels.iter()
+            .map(|(k, v)| format!("{}=\"{}\"", k, _escape_label_value(v)))
+            .collect::<Vec<String>>()
+            .join(",");
+
+        out.push_str(&format!("{{{}}}", labels));
+    }
+
+    out.push_str(&format!(" {}\n", value));
+}
+
+pub fn write_eof(out: &mut String) {
+    out.push_str("# EOF\n");
+}
+
+fn _escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn write_sample_works() {
+        let mut out = String::new();
+        write_sample(&mut out, "metric_total", &[("a".to_string(), "x\"y\\z\n".to_string()), ("b".to_string(), "1".to_string())], 5);
+        assert_eq!(out, "metric_total{a=\"x\\\"y\\\\z\\n\",b=\"1\"} 5\n");
+    }
+
+    #[test]
+    fn write_sample_works_for_no_labels() {
+        let mut out = String::new();
+        write_sample(&mut out, "metric", &[], 0);
+        assert_eq!(out, "metric 0\n");
+    }
+
+    #[test]
+    fn write_metrics_map_works() {
+        let mut metrics_map = Map::new();
+        metrics_map.insert("wallet_count".to_string(), json!([{"value": 2, "tags": {"label": "opened"}}]));
+        metrics_map.insert("pool_requests_count".to_string(), json!([{"value": 3, "tags": {"pool": "p", "outcome": "success"}}]));
+
+        let mut out = String::new();
+        write_metrics_map(&mut out, &metrics_map);
+
+        assert_eq!(out, "# TYPE wallets gauge\n\
+                         # HELP wallets Number of wallets by state\n\
+                         wallets{label=\"opened\"} 2\n\
+                         # TYPE pool_requests counter\n\
+                         # HELP pool_requests Number of finished pool requests by outcome\n\
+                         pool_requests_total{outcome=\"success\",pool=\"p\"} 3\n");
+    }
+}
@@ -1,4 +1,5 @@
 pub mod anoncreds;
+pub mod audit;
 pub mod blob_storage;
 pub mod crypto;
 pub mod ledger;
@@ -19,7 +19,7 @@ use serde::de::DeserializeOwned;
 
 use crate::api::ledger::{CustomFree, CustomTransactionParser};
 use crate::domain::{
-    pool::{PoolConfig, PoolOpenConfig},
+    pool::{PoolConfig, PoolOpenConfig, PoolStatus},
     ledger::response::{
         Message,
         Reply,
@@ -32,6 +32,7 @@ use crate::utils::environment;
 use crate::services::pool::events::{COMMAND_EXIT, COMMAND_CONNECT, COMMAND_REFRESH};
 use indy_api_types::{CommandHandle, PoolHandle};
 use indy_utils::{next_command_handle, next_pool_handle};
+use indy_utils::tracer;
 use ursa::bls::VerKey;
 
 mod catchup;
@@ -42,6 +43,7 @@ mod networker;
 mod pool;
 mod request_handler;
 mod state_proof;
+mod status;
 mod types;
 
 lazy_static! {
@@ -140,7 +142,19 @@ impl PoolService {
         let path = environment::pool_path(name);
 
         fs::remove_dir_all(path)
-            .to_indy(IndyErrorKind::IOError, "Can't delete pool config directory")
+            .to_indy(IndyErrorKind::IOError, "Can't delete pool config directory")?;
+
+        status::remove(name);
+
+        Ok(())
+    }
+
+    pub fn get_status(&self, name: &str) -> IndyResult<PoolStatus> {
+        if !environment::pool_path(name).exists() {
+            return Err(err_msg(IndyErrorKind::PoolNotCreated, format!("Pool is not created for name: {:?}", name)));
+        }
+
+        Ok(status::get(name))
     }
 
     pub fn open(&self, name: &str, config: Option<PoolOpenConfig>) -> IndyResult<PoolHandle> {
@@ -186,7 +200,12 @@ impl PoolService {
 
         if let Some(ref pool) = pools.get(&handle) {
             let cmd_id: CommandHandle = next_command_handle();
-            self._send_msg(cmd_id, msg, &pool.cmd_socket, nodes, timeout)?;
+            tracer::hand_over(cmd_id);
+            self._send_msg(cmd_id, msg, &pool.cmd_socket, nodes, timeout)
+                .map_err(|err| {
+                    tracer::take_handed_over(cmd_id);
+                    err
+                })?;
             Ok(cmd_id)
         } else {
             Err(err_msg(IndyErrorKind::InvalidPoolHandle, format!("No pool with requested handle {:?}", handle)))
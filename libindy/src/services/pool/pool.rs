@@ -21,6 +21,7 @@ use crate::services::pool::events::*;
 use crate::services::pool::{merkle_tree_factory, Nodes};
 use crate::services::pool::networker::{Networker, ZMQNetworker};
 use crate::services::pool::request_handler::{RequestHandler, RequestHandlerImpl};
+use crate::services::pool::status;
 use rust_base58::{FromBase58, ToBase58};
 use crate::services::pool::types::{LedgerStatus, RemoteNode};
 use indy_utils::crypto::ed25519_sign;
@@ -281,6 +282,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                         match _get_request_handler_with_ledger_status_sent(state.networker.clone(), &pool_name, timeout, extended_timeout, number_read_nodes) {
                             Ok(request_handler) => PoolState::GettingCatchupTarget((request_handler, cmd_id, state).into()),
                             Err(err) => {
+                                status::failed(&pool_name, &err.to_string());
                                 CommandExecutor::instance().send(
                                     Command::Pool(
                                         PoolCommand::OpenAck(cmd_id, id, Err(err)))
@@ -304,11 +306,13 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                         PoolState::Closed(state.into())
                     }
                     PoolEvent::CatchupTargetNotFound(err) => {
+                        status::failed(&pool_name, &err.to_string());
                         _send_open_refresh_ack(state.cmd_id, id, state.refresh,Err(err));
                         PoolState::Terminated(state.into())
                     }
                     PoolEvent::CatchupRestart(merkle_tree) => {
                         if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&merkle_tree) {
+                            status::catchup_started(&pool_name, &merkle_tree, &nodes, &remotes);
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
                             state.request_handler = R::new(state.networker.clone(), _get_f(nodes.len()), &[], &nodes, &pool_name, timeout, extended_timeout, number_read_nodes);
                             let ls = _ledger_status(&merkle_tree);
@@ -323,6 +327,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
                             let mut request_handler = R::new(state.networker.clone(), _get_f(nodes.len()), &[], &nodes, &pool_name, timeout, extended_timeout, number_read_nodes);
                             request_handler.process_event(Some(RequestEvent::CatchupReq(merkle_tree, target_mt_size, target_mt_root)));
+                            status::catching_up(&pool_name);
                             PoolState::SyncCatchup((request_handler, state).into())
                         } else {
                             PoolState::Terminated(state.into())
@@ -331,6 +336,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                     PoolEvent::Synced(merkle) => {
                         if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&merkle) {
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
+                            status::synced(&pool_name, &merkle);
                             _send_open_refresh_ack(state.cmd_id, id, state.refresh, Ok(()));
                             PoolState::Active((state, nodes).into())
                         } else {
@@ -439,6 +445,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                     PoolEvent::Synced(merkle) => {
                         if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&merkle).map_err(map_err_err!()) {
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
+                            status::synced(&pool_name, &merkle);
                             _send_open_refresh_ack(state.cmd_id, id, state.refresh, Ok(()));
                             PoolState::Active((state, nodes).into())
                         } else {
@@ -631,6 +638,7 @@ fn _get_request_handler_with_ledger_status_sent<T: Networker, R: RequestHandler<
             }
         }
     };
+    status::catchup_started(pool_name, &merkle, &nodes, &remotes);
     networker.borrow_mut().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
     let mut request_handler = R::new(networker.clone(), _get_f(nodes.len()), &[], &nodes, pool_name, timeout, extended_timeout, number_read_nodes);
     let ls = _ledger_status(&merkle);
@@ -14,8 +14,13 @@ use self::super::THRESHOLD;
 use crate::commands::Command;
 use crate::commands::CommandExecutor;
 use crate::commands::ledger::LedgerCommand;
+use crate::domain::pool::NodeReplyStatus;
 use indy_api_types::errors::prelude::*;
 use crate::services::ledger::merkletree::merkletree::MerkleTree;
+use crate::services::metrics::MetricsService;
+use indy_utils::tracer::{self, Span};
+use crate::services::metrics::{POOL_REPLY_TYPE_REPLY, POOL_REPLY_TYPE_REQNACK, POOL_REPLY_TYPE_REJECT, POOL_REPLY_TYPE_REQACK, POOL_REPLY_TYPE_TIMEOUT};
+use crate::services::metrics::{POOL_REQUEST_SUCCESS, POOL_REQUEST_CONSENSUS_IMPOSSIBLE, POOL_REQUEST_TERMINATED, POOL_REQUEST_FAILED};
 use crate::services::pool::catchup::{build_catchup_req, CatchupProgress, check_cons_proofs, check_nodes_responses_on_status};
 use crate::services::pool::events::NetworkerEvent;
 use crate::services::pool::events::PoolEvent;
@@ -24,6 +29,7 @@ use crate::services::pool::{get_last_signed_time, Nodes};
 use crate::services::pool::merkle_tree_factory;
 use crate::services::pool::networker::Networker;
 use crate::services::pool::state_proof;
+use crate::services::pool::status;
 use crate::services::pool::types::CatchupRep;
 use crate::services::pool::types::HashableValue;
 
@@ -44,6 +50,7 @@ struct RequestSM<T: Networker> {
     extended_timeout: i64,
     number_read_nodes: u8,
     state: RequestState<T>,
+    span: Span,
 }
 
 /// Transitions of request state
@@ -77,6 +84,8 @@ impl<T: Networker> RequestSM<T> {
                nodes: &Nodes,
                pool_name: &str, timeout: i64, extended_timeout: i64, number_read_nodes: u8) -> Self {
         let generator: Generator = Generator::from_bytes(&DEFAULT_GENERATOR.from_base58().unwrap()).unwrap();
+        let span = Span::child_of(cmd_ids.first().and_then(|cmd_id| tracer::take_handed_over(*cmd_id)), "pool_request")
+            .with_attribute("pool", pool_name);
         RequestSM {
             f,
             cmd_ids: cmd_ids.to_owned(),
@@ -89,6 +98,7 @@ impl<T: Networker> RequestSM<T> {
             state: RequestState::Start(StartState {
                 networker
             }),
+            span,
         }
     }
 
@@ -100,7 +110,8 @@ impl<T: Networker> RequestSM<T> {
                 timeout: i64,
                 extended_timeout: i64,
                 number_read_nodes: u8,
-                state: RequestState<T>) -> Self {
+                state: RequestState<T>,
+                span: Span) -> Self {
         RequestSM {
             f,
             cmd_ids,
@@ -111,6 +122,7 @@ impl<T: Networker> RequestSM<T> {
             extended_timeout,
             number_read_nodes,
             state,
+            span,
         }
     }
 }
@@ -251,7 +263,10 @@ impl Hash for NodeResponse {
 
 impl<T: Networker> RequestSM<T> {
     fn handle_event(self, re: RequestEvent) -> (Self, Option<PoolEvent>) {
-        let RequestSM { state, f, cmd_ids, nodes, generator, pool_name, timeout, extended_timeout, number_read_nodes } = self;
+        let RequestSM { state, f, cmd_ids, nodes, generator, pool_name, timeout, extended_timeout, number_read_nodes, span } = self;
+        _count_node_reply(&pool_name, &re);
+        let span_guard = span.enter();
+        let event_span = _event_span(&re);
         let (state, event) = match state {
             RequestState::Start(state) => {
                 match re {
@@ -274,7 +289,7 @@ impl<T: Networker> RequestSM<T> {
                                 (RequestState::finish(), Some(PoolEvent::Synced(merkle)))
                             }
                             Err(e) => {
-                                _send_replies(&cmd_ids, Err(e));
+                                _send_replies(&cmd_ids, &pool_name, Err(e));
                                 (RequestState::finish(), None)
                             }
                         }
@@ -299,14 +314,14 @@ impl<T: Networker> RequestSM<T> {
                                         state.networker.borrow_mut().process_event(Some(NetworkerEvent::SendAllRequest(msg, req_id, timeout, Some(nodes_to_send.clone()))));
                                         (RequestState::Full((Some(nodes_to_send), state).into()), None)
                                     } else {
-                                        _send_replies(&cmd_ids, Err(err_msg(IndyErrorKind::InvalidStructure,
+                                        _send_replies(&cmd_ids, &pool_name, Err(err_msg(IndyErrorKind::InvalidStructure,
                                                                             format!("There is no known node in list to send {:?}, known nodes are {:?}",
                                                                                     nodes_to_send, nodes.keys()))));
                                         (RequestState::finish(), None)
                                     }
                                 }
                                 Err(err) => {
-                                    _send_replies(&cmd_ids, Err(err.to_indy(IndyErrorKind::InvalidStructure, "Invalid list of nodes to send")));
+                                    _send_replies(&cmd_ids, &pool_name, Err(err.to_indy(IndyErrorKind::InvalidStructure, "Invalid list of nodes to send")));
                                     (RequestState::finish(), None)
                                 }
                             }
@@ -340,7 +355,7 @@ impl<T: Networker> RequestSM<T> {
                             };
 
                             if cnt > f {
-                                _send_ok_replies(&cmd_ids, &raw_msg);
+                                _send_ok_replies(&cmd_ids, &pool_name, &raw_msg);
                                 state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, None)));
                                 (RequestState::finish(), None)
                             } else if state.is_consensus_reachable(f, nodes.len()) {
@@ -348,14 +363,14 @@ impl<T: Networker> RequestSM<T> {
                                 (RequestState::Consensus(state), None)
                             } else {
                                 //TODO: maybe we should change the error, but it was made to escape changing of ErrorCode returned to client
-                                _send_replies(&cmd_ids, Err(err_msg(IndyErrorKind::PoolTimeout, "Consensus is impossible")));
+                                _send_replies(&cmd_ids, &pool_name, Err(err_msg(IndyErrorKind::PoolTimeout, "Consensus is impossible")));
                                 state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, None)));
                                 (RequestState::finish(), None)
                             }
                         } else {
                             state.denied_nodes.insert(node_alias.clone());
                             if state.denied_nodes.len() + state.replies.len() == nodes.len() {
-                                _send_replies(&cmd_ids, Err(err_msg(IndyErrorKind::PoolTimeout, "Consensus is impossible")));
+                                _send_replies(&cmd_ids, &pool_name, Err(err_msg(IndyErrorKind::PoolTimeout, "Consensus is impossible")));
                                 (RequestState::finish(), None)
                             } else {
                                 (RequestState::Consensus(state), None)
@@ -373,13 +388,13 @@ impl<T: Networker> RequestSM<T> {
                             (RequestState::Consensus(state), None)
                         } else {
                             //TODO: maybe we should change the error, but it was made to escape changing of ErrorCode returned to client
-                            _send_replies(&cmd_ids, Err(err_msg(IndyErrorKind::PoolTimeout, "Consensus is impossible")));
+                            _send_replies(&cmd_ids, &pool_name, Err(err_msg(IndyErrorKind::PoolTimeout, "Consensus is impossible")));
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, None)));
                             (RequestState::finish(), None)
                         }
                     }
                     RequestEvent::Terminate => {
-                        _finish_request(&cmd_ids);
+                        _finish_request(&cmd_ids, &pool_name);
                         (RequestState::finish(), None)
                     }
                     _ => (RequestState::Consensus(state), None)
@@ -409,14 +424,14 @@ impl<T: Networker> RequestSM<T> {
                             if cnt > f
                                 || _check_state_proof(&result, f, &generator, &nodes, &raw_msg, state.sp_key.as_ref().map(Vec::as_slice), state.timestamps, last_write_time) {
                                 state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, None)));
-                                _send_ok_replies(&cmd_ids, if cnt > f { &soonest } else { &raw_msg });
+                                _send_ok_replies(&cmd_ids, &pool_name, if cnt > f { &soonest } else { &raw_msg });
                                 (RequestState::finish(), None)
                             } else {
-                                (state.try_to_continue(req_id, node_alias, &cmd_ids, nodes.len(), timeout), None)
+                                (state.try_to_continue(req_id, node_alias, &cmd_ids, &pool_name, nodes.len(), timeout), None)
                             }
                         } else {
                             state.denied_nodes.insert(node_alias.clone());
-                            (state.try_to_continue(req_id, node_alias, &cmd_ids, nodes.len(), timeout), None)
+                            (state.try_to_continue(req_id, node_alias, &cmd_ids, &pool_name, nodes.len(), timeout), None)
                         }
                     }
                     RequestEvent::ReqACK(_, _, node_alias, req_id) => {
@@ -425,10 +440,10 @@ impl<T: Networker> RequestSM<T> {
                     }
                     RequestEvent::Timeout(req_id, node_alias) => {
                         state.timeout_nodes.insert(node_alias.clone());
-                        (state.try_to_continue(req_id, node_alias, &cmd_ids, nodes.len(), timeout), None)
+                        (state.try_to_continue(req_id, node_alias, &cmd_ids, &pool_name, nodes.len(), timeout), None)
                     }
                     RequestEvent::Terminate => {
-                        _finish_request(&cmd_ids);
+                        _finish_request(&cmd_ids, &pool_name);
                         (RequestState::finish(), None)
                     }
                     _ => (RequestState::Single(state), None)
@@ -437,18 +452,21 @@ impl<T: Networker> RequestSM<T> {
             RequestState::CatchupConsensus(state) => {
                 match re {
                     RequestEvent::LedgerStatus(ls, Some(node_alias), _) => {
+                        status::node_replied(&pool_name, &node_alias, NodeReplyStatus::LedgerStatus, Some(ls.txnSeqNo), Some(ls.merkleRoot.as_str()));
                         RequestSM::_catchup_target_handle_consensus_state(
                             state,
                             ls.merkleRoot.clone(), ls.txnSeqNo, None,
                             node_alias, ls.merkleRoot, f, &nodes, &pool_name)
                     }
                     RequestEvent::ConsistencyProof(cp, node_alias) => {
+                        status::node_replied(&pool_name, &node_alias, NodeReplyStatus::ConsistencyProof, Some(cp.seqNoEnd), Some(cp.newMerkleRoot.as_str()));
                         RequestSM::_catchup_target_handle_consensus_state(
                             state,
                             cp.newMerkleRoot, cp.seqNoEnd, Some(cp.hashes),
                             node_alias, cp.oldMerkleRoot, f, &nodes, &pool_name)
                     }
                     RequestEvent::Timeout(req_id, node_alias) => {
+                        status::node_replied(&pool_name, &node_alias, NodeReplyStatus::Timeout, None, None);
                         RequestSM::_catchup_target_handle_consensus_state(
                             state,
                             "timeout".to_string(), 0, None,
@@ -456,7 +474,7 @@ impl<T: Networker> RequestSM<T> {
                     }
 
                     RequestEvent::Terminate => {
-                        _finish_request(&cmd_ids);
+                        _finish_request(&cmd_ids, &pool_name);
                         (RequestState::finish(), None)
                     }
                     _ => (RequestState::CatchupConsensus(state), None)
@@ -483,7 +501,7 @@ impl<T: Networker> RequestSM<T> {
                         (RequestState::CatchupSingle(state), None)
                     }
                     RequestEvent::Terminate => {
-                        _finish_request(&cmd_ids);
+                        _finish_request(&cmd_ids, &pool_name);
                         (RequestState::finish(), None)
                     }
                     _ => (RequestState::CatchupSingle(state), None)
@@ -495,13 +513,13 @@ impl<T: Networker> RequestSM<T> {
                     RequestEvent::ReqNACK(_, raw_msg, node_alias, req_id) |
                     RequestEvent::Reject(_, raw_msg, node_alias, req_id) =>
                         (RequestSM::_full_request_handle_consensus_state(
-                            state, req_id, node_alias, raw_msg, &cmd_ids, &nodes), None),
+                            state, req_id, node_alias, raw_msg, &cmd_ids, &pool_name, &nodes), None),
                     RequestEvent::Timeout(req_id, node_alias) =>
                         (RequestSM::_full_request_handle_consensus_state(
-                            state, req_id, node_alias, "timeout".to_string(), &cmd_ids, &nodes), None),
+                            state, req_id, node_alias, "timeout".to_string(), &cmd_ids, &pool_name, &nodes), None),
 
                     RequestEvent::Terminate => {
-                        _finish_request(&cmd_ids);
+                        _finish_request(&cmd_ids, &pool_name);
                         (RequestState::finish(), None)
                     }
                     _ => (RequestState::Full(state), None),
@@ -509,7 +527,9 @@ impl<T: Networker> RequestSM<T> {
             }
             RequestState::Finish(state) => (RequestState::Finish(state), None)
         };
-        (RequestSM::step(f, cmd_ids, nodes, generator, pool_name, timeout, extended_timeout, number_read_nodes, state), event)
+        drop(event_span);
+        drop(span_guard);
+        (RequestSM::step(f, cmd_ids, nodes, generator, pool_name, timeout, extended_timeout, number_read_nodes, state, span), event)
     }
 
     fn is_terminal(&self) -> bool {
@@ -527,6 +547,7 @@ impl<T: Networker> RequestSM<T> {
     fn _full_request_handle_consensus_state(mut state: FullState<T>,
                                             req_id: String, node_alias: String, node_result: String,
                                             cmd_ids: &[CommandHandle],
+                                            pool_name: &str,
                                             nodes: &Nodes) -> RequestState<T> {
         let is_first_resp = state.accum_reply.is_none();
         if is_first_resp {
@@ -547,7 +568,7 @@ impl<T: Networker> RequestSM<T> {
         if reply_cnt == required_reply_cnt {
             state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, None)));
             let reply = state.accum_reply.as_ref().unwrap().inner.to_string();
-            _send_ok_replies(&cmd_ids, &reply);
+            _send_ok_replies(&cmd_ids, &pool_name, &reply);
             RequestState::Finish(FinishState {})
         } else {
             state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, Some(node_alias))));
@@ -654,7 +675,7 @@ impl<T: Networker> SingleState<T> {
             < total_nodes_cnt
     }
 
-    fn try_to_continue(self, req_id: String, node_alias: String, cmd_ids: &[CommandHandle], nodes_cnt: usize, timeout: i64) -> RequestState<T> {
+    fn try_to_continue(self, req_id: String, node_alias: String, cmd_ids: &[CommandHandle], pool_name: &str, nodes_cnt: usize, timeout: i64) -> RequestState<T> {
         if self.is_consensus_reachable(nodes_cnt) {
             self.networker.borrow_mut().process_event(Some(NetworkerEvent::Resend(req_id.clone(), timeout)));
             self.networker.borrow_mut().process_event(Some(NetworkerEvent::Resend(req_id.clone(), timeout)));
@@ -662,7 +683,7 @@ impl<T: Networker> SingleState<T> {
             RequestState::Single(self)
         } else {
             //TODO: maybe we should change the error, but it was made to escape changing of ErrorCode returned to client
-            _send_replies(cmd_ids, Err(err_msg(IndyErrorKind::PoolTimeout, "Consensus is impossible")));
+            _send_replies(cmd_ids, pool_name, Err(err_msg(IndyErrorKind::PoolTimeout, "Consensus is impossible")));
             self.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, None)));
             RequestState::finish()
         }
@@ -677,9 +698,9 @@ impl<T: Networker> ConsensusState<T> {
     }
 }
 
-fn _parse_nack(denied_nodes: &mut HashSet<String>, f: usize, raw_msg: &str, cmd_ids: &[CommandHandle], node_alias: &str) -> bool {
+fn _parse_nack(denied_nodes: &mut HashSet<String>, f: usize, raw_msg: &str, cmd_ids: &[CommandHandle], pool_name: &str, node_alias: &str) -> bool {
     if denied_nodes.len() == f {
-        _send_ok_replies(cmd_ids, raw_msg);
+        _send_ok_replies(cmd_ids, pool_name, raw_msg);
         true
     } else {
         denied_nodes.insert(node_alias.to_string());
@@ -707,15 +728,68 @@ fn _process_catchup_reply(rep: &mut CatchupRep, merkle: &MerkleTree, target_mt_r
     Ok(merkle)
 }
 
-fn _send_ok_replies(cmd_ids: &[CommandHandle], msg: &str) {
-    _send_replies(cmd_ids, Ok(msg.to_string()))
+fn _node_reply_type(re: &RequestEvent) -> Option<&'static str> {
+    match re {
+        RequestEvent::Reply(..) => Some(POOL_REPLY_TYPE_REPLY),
+        RequestEvent::ReqNACK(..) => Some(POOL_REPLY_TYPE_REQNACK),
+        RequestEvent::Reject(..) => Some(POOL_REPLY_TYPE_REJECT),
+        RequestEvent::ReqACK(..) => Some(POOL_REPLY_TYPE_REQACK),
+        RequestEvent::Timeout(..) => Some(POOL_REPLY_TYPE_TIMEOUT),
+        _ => None
+    }
+}
+
+fn _count_node_reply(pool_name: &str, re: &RequestEvent) {
+    let node_alias = match re {
+        RequestEvent::Reply(_, _, node_alias, _) |
+        RequestEvent::ReqNACK(_, _, node_alias, _) |
+        RequestEvent::Reject(_, _, node_alias, _) |
+        RequestEvent::ReqACK(_, _, node_alias, _) |
+        RequestEvent::Timeout(_, node_alias) => node_alias,
+        _ => return
+    };
+
+    if let Some(reply_type) = _node_reply_type(re) {
+        MetricsService::pool_node_replied(pool_name, node_alias, reply_type);
+    }
+}
+
+fn _event_span(re: &RequestEvent) -> Span {
+    match re {
+        RequestEvent::Reply(_, _, node_alias, _) |
+        RequestEvent::ReqNACK(_, _, node_alias, _) |
+        RequestEvent::Reject(_, _, node_alias, _) |
+        RequestEvent::ReqACK(_, _, node_alias, _) |
+        RequestEvent::Timeout(_, node_alias) =>
+            Span::child("pool_node_reply")
+                .with_attribute("node", node_alias)
+                .with_attribute("type", _node_reply_type(re).unwrap_or_default()),
+        RequestEvent::CustomSingleRequest(..) => Span::child("pool_send").with_attribute("mode", "single"),
+        RequestEvent::CustomConsensusRequest(..) => Span::child("pool_send").with_attribute("mode", "consensus"),
+        RequestEvent::CustomFullRequest(..) => Span::child("pool_send").with_attribute("mode", "full"),
+        _ => Span::child_of(None, "pool_event")
+    }
+}
+
+fn _send_ok_replies(cmd_ids: &[CommandHandle], pool_name: &str, msg: &str) {
+    _send_replies(cmd_ids, pool_name, Ok(msg.to_string()))
 }
 
-fn _finish_request(cmd_ids: &[CommandHandle]) {
-    _send_replies(cmd_ids, Err(err_msg(IndyErrorKind::PoolTerminated, "Pool is terminated")))
+fn _finish_request(cmd_ids: &[CommandHandle], pool_name: &str) {
+    _send_replies(cmd_ids, pool_name, Err(err_msg(IndyErrorKind::PoolTerminated, "Pool is terminated")))
 }
 
-fn _send_replies(cmd_ids: &[CommandHandle], msg: IndyResult<String>) {
+fn _send_replies(cmd_ids: &[CommandHandle], pool_name: &str, msg: IndyResult<String>) {
+    if !cmd_ids.is_empty() {
+        let outcome = match msg {
+            Ok(_) => POOL_REQUEST_SUCCESS,
+            Err(ref err) if err.kind() == IndyErrorKind::PoolTimeout => POOL_REQUEST_CONSENSUS_IMPOSSIBLE,
+            Err(ref err) if err.kind() == IndyErrorKind::PoolTerminated => POOL_REQUEST_TERMINATED,
+            Err(_) => POOL_REQUEST_FAILED
+        };
+        MetricsService::pool_request_finished(pool_name, outcome);
+    }
+
     cmd_ids.iter().for_each(|id| {
         CommandExecutor::instance().send(
             Command::Ledger(
@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use rust_base58::ToBase58;
+
+use crate::domain::pool::{NodeReplyStatus, PoolNodeStatus, PoolOpenState, PoolStatus};
+use crate::services::ledger::merkletree::merkletree::MerkleTree;
+use crate::services::pool::Nodes;
+use crate::services::pool::types::RemoteNode;
+
+lazy_static! {
+    // Kept by pool name so the status of an open that has failed is still available.
+    static ref POOL_STATUSES: Mutex<HashMap<String, PoolStatus>> = Mutex::new(HashMap::new());
+}
+
+pub fn get(pool_name: &str) -> PoolStatus {
+    POOL_STATUSES.lock()
+        .ok()
+        .and_then(|statuses| statuses.get(pool_name).cloned())
+        .unwrap_or_default()
+}
+
+pub fn remove(pool_name: &str) {
+    _update_statuses(pool_name, |statuses| { statuses.remove(pool_name); })
+}
+
+/// Ledger status of the local pool ledger is about to be sent to the nodes.
+pub fn catchup_started(pool_name: &str, merkle: &MerkleTree, nodes: &Nodes, remotes: &[RemoteNode]) {
+    _update(pool_name, |status| {
+        *status = PoolStatus {
+            state: PoolOpenState::InProgress,
+            txn_count: Some(merkle.count()),
+            merkle_root: Some(merkle.root_hash().as_slice().to_base58()),
+            nodes: remotes.iter()
+                .map(|remote| (remote.name.clone(), PoolNodeStatus {
+                    address: remote.zaddr.clone(),
+                    bls_key: nodes.get(&remote.name).map(Option::is_some).unwrap_or(false),
+                    reply: NodeReplyStatus::Pending,
+                    txn_count: None,
+                    merkle_root: None,
+                    rtt_ms: None,
+                }))
+                .collect(),
+            started: Some(Instant::now()),
+            ..PoolStatus::default()
+        };
+    })
+}
+
+/// Only the first reply of the node is kept: a timeout after the reply doesn't hide it.
+pub fn node_replied(pool_name: &str, node_alias: &str, reply: NodeReplyStatus, txn_count: Option<usize>, merkle_root: Option<&str>) {
+    _update(pool_name, |status| {
+        let rtt_ms = status.started.map(_elapsed_ms);
+
+        if let Some(node) = status.nodes.get_mut(node_alias) {
+            if node.reply == NodeReplyStatus::Pending {
+                node.reply = reply;
+                node.txn_count = txn_count;
+                node.merkle_root = merkle_root.map(String::from);
+                node.rtt_ms = rtt_ms;
+            }
+        }
+    })
+}
+
+pub fn catching_up(pool_name: &str) {
+    _update(pool_name, |status| status.state = PoolOpenState::CatchingUp)
+}
+
+pub fn synced(pool_name: &str, merkle: &MerkleTree) {
+    _update(pool_name, |status| {
+        status.state = PoolOpenState::Synced;
+        status.txn_count = Some(merkle.count());
+        status.merkle_root = Some(merkle.root_hash().as_slice().to_base58());
+        status.elapsed_ms = status.started.map(_elapsed_ms);
+    })
+}
+
+pub fn failed(pool_name: &str, error: &str) {
+    _update(pool_name, |status| {
+        status.state = PoolOpenState::Failed;
+        status.error = Some(error.to_string());
+        status.elapsed_ms = status.started.map(_elapsed_ms);
+    })
+}
+
+fn _update<F: FnOnce(&mut PoolStatus)>(pool_name: &str, f: F) {
+    _update_statuses(pool_name, |statuses| f(statuses.entry(pool_name.to_string()).or_insert_with(PoolStatus::default)))
+}
+
+fn _update_statuses<F: FnOnce(&mut HashMap<String, PoolStatus>)>(pool_name: &str, f: F) {
+    match POOL_STATUSES.lock() {
+        Ok(mut statuses) => f(&mut statuses),
+        Err(err) => warn!("Status of pool {} has not been updated: {}", pool_name, err)
+    }
+}
+
+fn _elapsed_ms(started: Instant) -> u64 {
+    started.elapsed().as_millis() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _remote(name: &str) -> RemoteNode {
+        RemoteNode {
+            name: name.to_string(),
+            public_key: vec![],
+            zaddr: format!("tcp://127.0.0.1:970{}", name.len()),
+            is_blacklisted: false,
+        }
+    }
+
+    #[test]
+    fn get_works_for_not_opened() {
+        let status = get("pool_status_get_works_for_not_opened");
+        assert_eq!(PoolOpenState::NotOpened, status.state);
+        assert!(status.nodes.is_empty());
+    }
+
+    #[test]
+    fn status_works_for_catchup() {
+        let pool_name = "pool_status_works_for_catchup";
+        let merkle = MerkleTree::from_vec(vec![]).unwrap();
+        let nodes: Nodes = vec![("Node1".to_string(), None), ("Node2".to_string(), None)].into_iter().collect();
+
+        catchup_started(pool_name, &merkle, &nodes, &[_remote("Node1"), _remote("Node2")]);
+        node_replied(pool_name, "Node1", NodeReplyStatus::ConsistencyProof, Some(4), Some("root"));
+        node_replied(pool_name, "Node1", NodeReplyStatus::Timeout, None, None);
+        node_replied(pool_name, "Unknown", NodeReplyStatus::LedgerStatus, Some(4), Some("root"));
+
+        let status = get(pool_name);
+        assert_eq!(PoolOpenState::InProgress, status.state);
+        assert_eq!(Some(0), status.txn_count);
+        assert_eq!(2, status.nodes.len());
+        assert_eq!(NodeReplyStatus::ConsistencyProof, status.nodes["Node1"].reply);
+        assert_eq!(Some(4), status.nodes["Node1"].txn_count);
+        assert!(status.nodes["Node1"].rtt_ms.is_some());
+        assert_eq!(NodeReplyStatus::Pending, status.nodes["Node2"].reply);
+        assert!(!status.nodes["Node2"].bls_key);
+
+        failed(pool_name, "Pool timeout");
+
+        let status = get(pool_name);
+        assert_eq!(PoolOpenState::Failed, status.state);
+        assert_eq!(Some("Pool timeout".to_string()), status.error);
+        assert!(status.elapsed_ms.is_some());
+
+        remove(pool_name);
+        assert_eq!(PoolOpenState::NotOpened, get(pool_name).state);
+    }
+}
@@ -0,0 +1,41 @@
+use libc::{c_void, c_char};
+use std::ffi::CString;
+
+use crate::domain::audit::{AuditConfig, AuditEvent};
+use crate::services::audit;
+
+pub type AuditEventCB = extern "C" fn(context: *const c_void,
+                                      event_json: *const c_char);
+
+struct LibindyAuditSink {
+    context: *const c_void,
+    event: AuditEventCB,
+}
+
+unsafe impl Sync for LibindyAuditSink {}
+
+unsafe impl Send for LibindyAuditSink {}
+
+impl LibindyAuditSink {
+    fn event(&self, event: &AuditEvent) {
+        let event_json = match serde_json::to_string(event) {
+            Ok(event_json) => event_json,
+            Err(err) => {
+                warn!("Unable to serialize audit event: {:?}", err);
+                return;
+            }
+        };
+
+        let event_json = CString::new(event_json).unwrap();
+        (self.event)(self.context, event_json.as_ptr())
+    }
+}
+
+pub fn set_audit_log(context: *const c_void, config: AuditConfig, event: Option<AuditEventCB>) {
+    let sink = event.map(|event| {
+        let sink = LibindyAuditSink { context, event };
+        Box::new(move |event: &AuditEvent| sink.event(event)) as audit::AuditSink
+    });
+
+    audit::set_audit_log(config, sink)
+}
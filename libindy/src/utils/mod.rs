@@ -7,6 +7,12 @@ pub mod crypto;
 #[macro_use]
 pub mod logger;
 
+pub mod tracer;
+
+pub mod audit;
+
+pub mod rollback;
+
 #[allow(unused_macros)]
 #[macro_use]
 pub mod result;
@@ -0,0 +1,107 @@
+use indy_api_types::errors::prelude::*;
+
+type Undo<'a> = Box<dyn FnOnce() -> IndyResult<()> + 'a>;
+
+/// Guard for a sequence of wallet writes that belong together.
+///
+/// Wallet storage has no transactions, so every applied write registers an undo action.
+/// If the guard is dropped without `commit`, the applied writes are undone in reverse order.
+/// A failed undo is logged and does not replace the error that caused the rollback.
+pub struct Rollback<'a> {
+    applied: Vec<(&'static str, Undo<'a>)>,
+}
+
+impl<'a> Rollback<'a> {
+    pub fn new() -> Rollback<'a> {
+        Rollback { applied: Vec::new() }
+    }
+
+    pub fn apply<T, W, U>(&mut self, name: &'static str, write: W, undo: U) -> IndyResult<T>
+        where W: FnOnce() -> IndyResult<T>, U: FnOnce() -> IndyResult<()> + 'a {
+        let res = write()?;
+        self.applied.push((name, Box::new(undo)));
+        Ok(res)
+    }
+
+    pub fn commit(mut self) {
+        self.applied.clear();
+    }
+}
+
+impl<'a> Drop for Rollback<'a> {
+    fn drop(&mut self) {
+        while let Some((name, undo)) = self.applied.pop() {
+            if let Err(err) = undo() {
+                error!("Cannot roll back {}: {:?}", name, err);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::cell::RefCell;
+
+    fn _write(log: &RefCell<Vec<String>>, name: &str, fail: bool) -> IndyResult<()> {
+        if fail {
+            return Err(err_msg(IndyErrorKind::WalletStorageError, format!("{} failed", name)));
+        }
+        log.borrow_mut().push(format!("write {}", name));
+        Ok(())
+    }
+
+    fn _undo(log: &RefCell<Vec<String>>, name: &str, fail: bool) -> IndyResult<()> {
+        log.borrow_mut().push(format!("undo {}", name));
+        if fail {
+            return Err(err_msg(IndyErrorKind::WalletStorageError, format!("undo {} failed", name)));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn rollback_works_for_commit() {
+        let log = RefCell::new(Vec::new());
+        {
+            let mut rollback = Rollback::new();
+            rollback.apply("a", || _write(&log, "a", false), || _undo(&log, "a", false)).unwrap();
+            rollback.apply("b", || _write(&log, "b", false), || _undo(&log, "b", false)).unwrap();
+            rollback.commit();
+        }
+        assert_eq!(vec!["write a", "write b"], *log.borrow());
+    }
+
+    #[test]
+    fn rollback_works_for_failed_write() {
+        let log = RefCell::new(Vec::new());
+
+        let res: IndyResult<()> = (|| {
+            let mut rollback = Rollback::new();
+            rollback.apply("a", || _write(&log, "a", false), || _undo(&log, "a", false))?;
+            rollback.apply("b", || _write(&log, "b", false), || _undo(&log, "b", false))?;
+            rollback.apply("c", || _write(&log, "c", true), || _undo(&log, "c", false))?;
+            rollback.commit();
+            Ok(())
+        })();
+
+        assert_eq!(IndyErrorKind::WalletStorageError, res.unwrap_err().kind());
+        assert_eq!(vec!["write a", "write b", "undo b", "undo a"], *log.borrow());
+    }
+
+    #[test]
+    fn rollback_works_for_failed_undo() {
+        let log = RefCell::new(Vec::new());
+
+        let res: IndyResult<()> = (|| {
+            let mut rollback = Rollback::new();
+            rollback.apply("a", || _write(&log, "a", false), || _undo(&log, "a", false))?;
+            rollback.apply("b", || _write(&log, "b", false), || _undo(&log, "b", true))?;
+            Err(err_msg(IndyErrorKind::InvalidState, "operation failed"))
+        })();
+
+        // the original error is kept and the remaining writes are still undone
+        assert_eq!(IndyErrorKind::InvalidState, res.unwrap_err().kind());
+        assert_eq!(vec!["write a", "write b", "undo b", "undo a"], *log.borrow());
+    }
+}
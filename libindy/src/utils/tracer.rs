@@ -0,0 +1,41 @@
+use libc::{c_void, c_char};
+use std::ffi::CString;
+
+use indy_utils::tracer::{self, FinishedSpan};
+
+pub type ExportSpanCB = extern "C" fn(context: *const c_void,
+                                      span_json: *const c_char);
+
+struct LibindyTracer {
+    context: *const c_void,
+    export: ExportSpanCB,
+}
+
+unsafe impl Sync for LibindyTracer {}
+
+unsafe impl Send for LibindyTracer {}
+
+impl LibindyTracer {
+    fn export(&self, span: &FinishedSpan) {
+        let span_json = match serde_json::to_string(span) {
+            Ok(span_json) => span_json,
+            Err(err) => {
+                warn!("Unable to serialize tracing span: {:?}", err);
+                return;
+            }
+        };
+
+        let span_json = CString::new(span_json).unwrap();
+        (self.export)(self.context, span_json.as_ptr())
+    }
+}
+
+pub fn set_tracer(context: *const c_void, export: Option<ExportSpanCB>) {
+    match export {
+        Some(export) => {
+            let tracer = LibindyTracer { context, export };
+            tracer::set_exporter(Some(Box::new(move |span: &FinishedSpan| tracer.export(span))));
+        }
+        None => tracer::set_exporter(None)
+    }
+}
@@ -8,7 +8,7 @@ extern crate indyrs as api;
 extern crate indy_sys;
 
 use crate::utils::{wallet, anoncreds};
-use crate::utils::anoncreds::{COMMON_MASTER_SECRET, CREDENTIAL1_ID, ANONCREDS_WALLET_CONFIG};
+use crate::utils::anoncreds::{COMMON_MASTER_SECRET, CREDENTIAL1_ID, ANONCREDS_WALLET_CONFIG, ISSUANCE_DATE, ISSUANCE_DATES};
 
 use indy::ErrorCode;
 use crate::utils::constants::*;
@@ -74,6 +74,221 @@ mod high_cases {
         }
     }
 
+    mod prover_master_secrets {
+        use super::*;
+
+        const MASTER_SECRET_1: &str = "master_secret_1";
+        const MASTER_SECRET_2: &str = "master_secret_2";
+
+        fn _master_secrets(setup: &Setup) -> serde_json::Value {
+            serde_json::from_str(&anoncreds::prover_list_master_secrets(setup.wallet_handle).unwrap()).unwrap()
+        }
+
+        // Stores gvt credential bound to the first master secret and xyz credential bound to the second one
+        // and returns proof request, requested credentials, schemas and credential definitions proving both credentials.
+        fn _store_credentials(setup: &Setup, gvt_master_secret_id: &str, xyz_master_secret_id: &str) -> (String, String, String, String) {
+            let (gvt_cred_def_id, gvt_cred_def_json) = anoncreds::issuer_create_credential_definition(setup.wallet_handle, ISSUER_DID, &anoncreds::gvt_schema_json(),
+                                                                                                      TAG_1, None, Some(&anoncreds::default_cred_def_config())).unwrap();
+            let (xyz_cred_def_id, xyz_cred_def_json) = anoncreds::issuer_create_credential_definition(setup.wallet_handle, ISSUER_DID, &anoncreds::xyz_schema_json(),
+                                                                                                      TAG_1, None, Some(&anoncreds::default_cred_def_config())).unwrap();
+
+            anoncreds::multi_steps_create_credential(gvt_master_secret_id, setup.wallet_handle, setup.wallet_handle, CREDENTIAL1_ID,
+                                                     &anoncreds::gvt_credential_values_json(), &gvt_cred_def_id, &gvt_cred_def_json);
+            anoncreds::multi_steps_create_credential(xyz_master_secret_id, setup.wallet_handle, setup.wallet_handle, anoncreds::CREDENTIAL2_ID,
+                                                     &anoncreds::xyz_credential_values_json(), &xyz_cred_def_id, &xyz_cred_def_json);
+
+            let proof_req_json = json!({
+                "nonce": "123432421212",
+                "name": "proof_req_1",
+                "version": "0.1",
+                "requested_attributes": {
+                    "attr1_referent": {"name": "name"},
+                    "attr2_referent": {"name": "status"}
+                },
+                "requested_predicates": {}
+            }).to_string();
+
+            let requested_credentials_json = json!({
+                "self_attested_attributes": {},
+                "requested_attributes": {
+                    "attr1_referent": {"cred_id": CREDENTIAL1_ID, "revealed": true},
+                    "attr2_referent": {"cred_id": anoncreds::CREDENTIAL2_ID, "revealed": true}
+                },
+                "requested_predicates": {}
+            }).to_string();
+
+            let gvt_schema_id = anoncreds::gvt_schema_id();
+            let xyz_schema_id = anoncreds::xyz_schema_id();
+
+            let schemas_json = json!({
+                gvt_schema_id: serde_json::from_str::<serde_json::Value>(&anoncreds::gvt_schema_json()).unwrap(),
+                xyz_schema_id: serde_json::from_str::<serde_json::Value>(&anoncreds::xyz_schema_json()).unwrap(),
+            }).to_string();
+
+            let cred_defs_json = json!({
+                gvt_cred_def_id: serde_json::from_str::<serde_json::Value>(&gvt_cred_def_json).unwrap(),
+                xyz_cred_def_id: serde_json::from_str::<serde_json::Value>(&xyz_cred_def_json).unwrap(),
+            }).to_string();
+
+            (proof_req_json, requested_credentials_json, schemas_json, cred_defs_json)
+        }
+
+        #[test]
+        fn prover_list_master_secrets_works() {
+            let setup = Setup::wallet();
+
+            assert_eq!(json!([]), _master_secrets(&setup));
+
+            anoncreds::prover_create_master_secret(setup.wallet_handle, MASTER_SECRET_2).unwrap();
+            anoncreds::prover_create_master_secret(setup.wallet_handle, MASTER_SECRET_1).unwrap();
+
+            // the first created master secret becomes default
+            assert_eq!(json!([
+                {"id": MASTER_SECRET_1, "default": false, "retired": false},
+                {"id": MASTER_SECRET_2, "default": true, "retired": false},
+            ]), _master_secrets(&setup));
+        }
+
+        #[test]
+        fn prover_set_default_master_secret_works() {
+            let setup = Setup::wallet();
+
+            anoncreds::prover_create_master_secret(setup.wallet_handle, MASTER_SECRET_1).unwrap();
+            anoncreds::prover_create_master_secret(setup.wallet_handle, MASTER_SECRET_2).unwrap();
+
+            anoncreds::prover_set_default_master_secret(setup.wallet_handle, MASTER_SECRET_2).unwrap();
+
+            assert_eq!(json!([
+                {"id": MASTER_SECRET_1, "default": false, "retired": false},
+                {"id": MASTER_SECRET_2, "default": true, "retired": false},
+            ]), _master_secrets(&setup));
+        }
+
+        #[test]
+        fn prover_set_default_master_secret_works_for_unknown_master_secret() {
+            let setup = Setup::wallet();
+
+            let res = anoncreds::prover_set_default_master_secret(setup.wallet_handle, MASTER_SECRET_1);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        fn prover_store_credential_works_for_master_secret_id_tag() {
+            let setup = Setup::wallet();
+
+            anoncreds::prover_create_master_secret(setup.wallet_handle, MASTER_SECRET_1).unwrap();
+            anoncreds::prover_create_master_secret(setup.wallet_handle, MASTER_SECRET_2).unwrap();
+
+            _store_credentials(&setup, MASTER_SECRET_1, MASTER_SECRET_2);
+
+            let credentials = anoncreds::prover_get_credentials(setup.wallet_handle, &json!({"master_secret_id": MASTER_SECRET_2}).to_string()).unwrap();
+            let credentials: Vec<CredentialInfo> = serde_json::from_str(&credentials).unwrap();
+
+            assert_eq!(1, credentials.len());
+            assert_eq!(anoncreds::CREDENTIAL2_ID, credentials[0].referent);
+        }
+
+        #[test]
+        fn prover_rotate_master_secret_works() {
+            let setup = Setup::wallet();
+
+            anoncreds::prover_create_master_secret(setup.wallet_handle, MASTER_SECRET_1).unwrap();
+            let (cred_def_id, cred_def_json) = anoncreds::issuer_create_credential_definition(setup.wallet_handle, ISSUER_DID, &anoncreds::gvt_schema_json(),
+                                                                                              TAG_1, None, Some(&anoncreds::default_cred_def_config())).unwrap();
+            anoncreds::multi_steps_create_credential(MASTER_SECRET_1, setup.wallet_handle, setup.wallet_handle, CREDENTIAL1_ID,
+                                                     &anoncreds::gvt_credential_values_json(), &cred_def_id, &cred_def_json);
+
+            let rotation_json = anoncreds::prover_rotate_master_secret(setup.wallet_handle, MASTER_SECRET_1, Some(MASTER_SECRET_2)).unwrap();
+            let rotation: serde_json::Value = serde_json::from_str(&rotation_json).unwrap();
+
+            assert_eq!(MASTER_SECRET_2, rotation["master_secret_id"].as_str().unwrap());
+            assert_eq!(MASTER_SECRET_1, rotation["retired_master_secret_id"].as_str().unwrap());
+
+            let credentials_to_reissue: Vec<CredentialInfo> = serde_json::from_value(rotation["credentials_to_reissue"].clone()).unwrap();
+            assert_eq!(1, credentials_to_reissue.len());
+            assert_eq!(CREDENTIAL1_ID, credentials_to_reissue[0].referent);
+
+            assert_eq!(json!([
+                {"id": MASTER_SECRET_1, "default": false, "retired": true},
+                {"id": MASTER_SECRET_2, "default": true, "retired": false},
+            ]), _master_secrets(&setup));
+
+            // retired master secret can not be used for new credentials
+            let cred_offer_json = anoncreds::issuer_create_credential_offer(setup.wallet_handle, &cred_def_id).unwrap();
+            let res = anoncreds::prover_create_credential_req(setup.wallet_handle, DID_MY1, &cred_offer_json, &cred_def_json, MASTER_SECRET_1);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            let res = anoncreds::prover_set_default_master_secret(setup.wallet_handle, MASTER_SECRET_1);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            // credentials issued to the new master secret are not reported by the next rotation
+            anoncreds::multi_steps_create_credential(MASTER_SECRET_2, setup.wallet_handle, setup.wallet_handle, anoncreds::CREDENTIAL2_ID,
+                                                     &anoncreds::gvt_credential_values_json(), &cred_def_id, &cred_def_json);
+
+            let rotation_json = anoncreds::prover_rotate_master_secret(setup.wallet_handle, MASTER_SECRET_2, None).unwrap();
+            let rotation: serde_json::Value = serde_json::from_str(&rotation_json).unwrap();
+
+            let credentials_to_reissue: Vec<CredentialInfo> = serde_json::from_value(rotation["credentials_to_reissue"].clone()).unwrap();
+            assert_eq!(1, credentials_to_reissue.len());
+            assert_eq!(anoncreds::CREDENTIAL2_ID, credentials_to_reissue[0].referent);
+        }
+
+        #[test]
+        fn prover_rotate_master_secret_works_for_retired_master_secret() {
+            let setup = Setup::wallet();
+
+            anoncreds::prover_create_master_secret(setup.wallet_handle, MASTER_SECRET_1).unwrap();
+            anoncreds::prover_rotate_master_secret(setup.wallet_handle, MASTER_SECRET_1, Some(MASTER_SECRET_2)).unwrap();
+
+            let res = anoncreds::prover_rotate_master_secret(setup.wallet_handle, MASTER_SECRET_1, None);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn prover_create_proof_with_options_works_for_default_master_secret() {
+            let setup = Setup::wallet();
+
+            anoncreds::prover_create_master_secret(setup.wallet_handle, MASTER_SECRET_1).unwrap();
+
+            let (proof_req_json, requested_credentials_json, schemas_json, cred_defs_json) = _store_credentials(&setup, MASTER_SECRET_1, MASTER_SECRET_1);
+
+            let proof_json = anoncreds::prover_create_proof_with_options(setup.wallet_handle, &proof_req_json, &requested_credentials_json, None,
+                                                                         &schemas_json, &cred_defs_json, "{}", "{}").unwrap();
+
+            let valid = anoncreds::verifier_verify_proof(&proof_req_json, &proof_json, &schemas_json, &cred_defs_json, "{}", "{}").unwrap();
+            assert!(valid);
+        }
+
+        #[test]
+        fn prover_create_proof_works_for_mixed_master_secrets() {
+            let setup = Setup::wallet();
+
+            anoncreds::prover_create_master_secret(setup.wallet_handle, MASTER_SECRET_1).unwrap();
+            anoncreds::prover_create_master_secret(setup.wallet_handle, MASTER_SECRET_2).unwrap();
+
+            let (proof_req_json, requested_credentials_json, schemas_json, cred_defs_json) = _store_credentials(&setup, MASTER_SECRET_1, MASTER_SECRET_2);
+
+            let res = anoncreds::prover_create_proof(setup.wallet_handle, &proof_req_json, &requested_credentials_json, MASTER_SECRET_1,
+                                                     &schemas_json, &cred_defs_json, "{}");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            let res = anoncreds::prover_create_proof_with_options(setup.wallet_handle, &proof_req_json, &requested_credentials_json, Some(MASTER_SECRET_1),
+                                                                  &schemas_json, &cred_defs_json, "{}", "{}");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            let proof_json = anoncreds::prover_create_proof_with_options(setup.wallet_handle, &proof_req_json, &requested_credentials_json, Some(MASTER_SECRET_1),
+                                                                         &schemas_json, &cred_defs_json, "{}", r#"{"allow_mixed_master_secrets": true}"#).unwrap();
+
+            let proof: Proof = serde_json::from_str(&proof_json).unwrap();
+            assert_eq!("Alex", proof.requested_proof.revealed_attrs["attr1_referent"].raw);
+            assert_eq!("partial", proof.requested_proof.revealed_attrs["attr2_referent"].raw);
+
+            // every sub proof is checked against its own master secret only
+            let valid = anoncreds::verifier_verify_proof(&proof_req_json, &proof_json, &schemas_json, &cred_defs_json, "{}", "{}").unwrap();
+            assert!(valid);
+        }
+    }
+
     mod prover_create_credential_req {
         use super::*;
 
@@ -92,6 +307,75 @@ mod high_cases {
         }
     }
 
+    mod issuer_create_credential_with_record {
+        use super::*;
+
+        #[test]
+        fn issuer_create_credential_with_record_works() {
+            let (_, credential_offer, credential_req, _) = anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let (_, cred_rev_id, _) = anoncreds::issuer_create_credential_with_record(wallet_handle,
+                                                                                      &credential_offer,
+                                                                                      &credential_req,
+                                                                                      &anoncreds::gvt_credential_values_json(),
+                                                                                      None,
+                                                                                      None,
+                                                                                      r#"{"id":"issuance_record_works", "tags":{"offer_id":"offer_1"}}"#).unwrap();
+            assert!(cred_rev_id.is_none());
+
+            let record_json = anoncreds::issuer_get_issuance_record(wallet_handle, "issuance_record_works").unwrap();
+            let record: serde_json::Value = serde_json::from_str(&record_json).unwrap();
+            assert_eq!(json!(anoncreds::issuer_1_gvt_cred_def_id()), record["cred_def_id"]);
+            assert!(record["rev_reg_id"].is_null());
+
+            let (search_handle, total_count) = anoncreds::issuer_search_issuance_records(wallet_handle, r#"{"offer_id":"offer_1"}"#).unwrap();
+            assert_eq!(1, total_count);
+            anoncreds::issuer_close_issuance_records_search(search_handle).unwrap();
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn issuer_create_credential_with_record_works_for_reserved_tag() {
+            let (_, credential_offer, credential_req, _) = anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let res = anoncreds::issuer_create_credential_with_record(wallet_handle,
+                                                                      &credential_offer,
+                                                                      &credential_req,
+                                                                      &anoncreds::gvt_credential_values_json(),
+                                                                      None,
+                                                                      None,
+                                                                      r#"{"id":"issuance_record_reserved_tag", "tags":{"~cred_def_id":"1"}}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn issuer_revoke_credential_by_record_works_for_not_revocable_credential() {
+            let (_, credential_offer, credential_req, _) = anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            anoncreds::issuer_create_credential_with_record(wallet_handle,
+                                                            &credential_offer,
+                                                            &credential_req,
+                                                            &anoncreds::gvt_credential_values_json(),
+                                                            None,
+                                                            None,
+                                                            r#"{"id":"issuance_record_not_revocable"}"#).unwrap();
+
+            let res = anoncreds::issuer_revoke_credential_by_record(wallet_handle, -1, "issuance_record_not_revocable");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+    }
+
     mod prover_store_credential {
         use super::*;
 
@@ -282,629 +566,720 @@ mod high_cases {
         }
     }
 
-    mod prover_credentials_search {
+    mod prover_credential_status {
         use super::*;
+        use indy::WalletHandle;
+
+        fn _status(wallet_handle: WalletHandle, cred_id: &str) -> serde_json::Value {
+            let status_json = anoncreds::prover_get_credential_status(wallet_handle, cred_id).unwrap();
+            serde_json::from_str(&status_json).unwrap()
+        }
+
+        fn _active_cred_ids(wallet_handle: WalletHandle, active: &str) -> Vec<String> {
+            let credentials = anoncreds::prover_get_credentials(wallet_handle, &json!({"status::active": active}).to_string()).unwrap();
+            let credentials: Vec<CredentialInfo> = serde_json::from_str(&credentials).unwrap();
+            credentials.into_iter().map(|credential| credential.referent).collect()
+        }
 
         #[test]
-        fn credentials_search_works() {
+        fn prover_get_credential_status_works() {
             anoncreds::init_common_wallet();
 
             let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
 
-            let (search_handle, count) = anoncreds::prover_search_credentials(wallet_handle, "{}").unwrap();
-            assert_eq!(count, 4);
-
-            let credentials = anoncreds::prover_fetch_credentials(search_handle, count).unwrap();
-            let credentials: Vec<CredentialInfo> = serde_json::from_str(&credentials).unwrap();
-
-            assert_eq!(credentials.len(), 4);
-            assert!(credentials.contains(&anoncreds::issuer_1_gvt_credential()));
-            assert!(credentials.contains(&anoncreds::issuer_1_xyz_credential()));
-            assert!(credentials.contains(&anoncreds::issuer_2_gvt_credential()));
+            let status = _status(wallet_handle, CREDENTIAL1_ID);
+            assert_eq!(json!({
+                "referent": CREDENTIAL1_ID,
+                "revoked": false,
+                "revoked_as_of": null,
+                "expires_at": null,
+                "replaced_by": null,
+                "active": true
+            }), status);
 
-            anoncreds::prover_close_credentials_search(search_handle).unwrap();
             wallet::close_wallet(wallet_handle).unwrap();
         }
 
         #[test]
-        fn credentials_search_works_for_filter_by_issuer_did() {
+        fn prover_get_credential_status_works_for_not_found() {
             anoncreds::init_common_wallet();
 
             let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
 
-            let filter_json = json!({"issuer_did": ISSUER_DID}).to_string();
-            let (search_handle, count) = anoncreds::prover_search_credentials(wallet_handle, &filter_json).unwrap();
-            assert_eq!(count, 2);
-
-            let credentials = anoncreds::prover_fetch_credentials(search_handle, count).unwrap();
-            let credentials: Vec<CredentialInfo> = serde_json::from_str(&credentials).unwrap();
-
-            assert_eq!(credentials.len(), 2);
-            assert!(credentials.contains(&anoncreds::issuer_1_gvt_credential()));
-            assert!(credentials.contains(&anoncreds::issuer_1_xyz_credential()));
+            let res = anoncreds::prover_get_credential_status(wallet_handle, "other_cred_id");
+            assert_code!(ErrorCode::WalletItemNotFound, res);
 
-            anoncreds::prover_close_credentials_search(search_handle).unwrap();
             wallet::close_wallet(wallet_handle).unwrap();
         }
 
         #[test]
-        fn credentials_search_works_for_filter_by_schema_id() {
-            anoncreds::init_common_wallet();
+        fn prover_credential_status_works_for_replaced() {
+            let setup = Setup::wallet();
 
-            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+            let (cred_def_id, cred_def_json) = anoncreds::issuer_create_credential_definition(setup.wallet_handle, ISSUER_DID, &anoncreds::gvt_schema_json(),
+                                                                                              TAG_1, None, Some(&anoncreds::default_cred_def_config())).unwrap();
 
-            let filter_json = json!({"schema_id": anoncreds::gvt_schema_id()}).to_string();
-            let (search_handle, count) = anoncreds::prover_search_credentials(wallet_handle, &filter_json).unwrap();
-            assert_eq!(count, 2);
+            anoncreds::prover_create_master_secret(setup.wallet_handle, COMMON_MASTER_SECRET).unwrap();
 
-            let credentials = anoncreds::prover_fetch_credentials(search_handle, count).unwrap();
-            let credentials: Vec<CredentialInfo> = serde_json::from_str(&credentials).unwrap();
+            anoncreds::multi_steps_create_credential(COMMON_MASTER_SECRET, setup.wallet_handle, setup.wallet_handle, CREDENTIAL1_ID,
+                                                     &anoncreds::gvt_credential_values_json(), &cred_def_id, &cred_def_json);
+            anoncreds::multi_steps_create_credential(COMMON_MASTER_SECRET, setup.wallet_handle, setup.wallet_handle, anoncreds::CREDENTIAL2_ID,
+                                                     &anoncreds::gvt_credential_values_2_json(), &cred_def_id, &cred_def_json);
 
-            assert_eq!(credentials.len(), 2);
-            assert!(credentials.contains(&anoncreds::issuer_1_gvt_credential()));
-            assert!(credentials.contains(&anoncreds::issuer_2_gvt_credential()));
+            // Credentials are not replaced without identity attribute
+            assert!(_status(setup.wallet_handle, CREDENTIAL1_ID)["replaced_by"].is_null());
+            assert!(_active_cred_ids(setup.wallet_handle, "0").is_empty());
 
-            anoncreds::prover_close_credentials_search(search_handle).unwrap();
-            wallet::close_wallet(wallet_handle).unwrap();
+            // Newer credential replaces only the older one with the same identity attribute value
+            anoncreds::prover_set_credential_identity_attr(setup.wallet_handle, &cred_def_id, Some("name")).unwrap();
+
+            anoncreds::multi_steps_create_credential(COMMON_MASTER_SECRET, setup.wallet_handle, setup.wallet_handle, anoncreds::CREDENTIAL3_ID,
+                                                     &anoncreds::gvt_credential_values_json(), &cred_def_id, &cred_def_json);
+
+            assert_eq!(anoncreds::CREDENTIAL3_ID, _status(setup.wallet_handle, CREDENTIAL1_ID)["replaced_by"].as_str().unwrap());
+            assert!(_status(setup.wallet_handle, anoncreds::CREDENTIAL2_ID)["replaced_by"].is_null());
+
+            let mut active_cred_ids = _active_cred_ids(setup.wallet_handle, "1");
+            active_cred_ids.sort();
+            assert_eq!(vec![anoncreds::CREDENTIAL2_ID.to_string(), anoncreds::CREDENTIAL3_ID.to_string()], active_cred_ids);
+            assert_eq!(vec![CREDENTIAL1_ID.to_string()], _active_cred_ids(setup.wallet_handle, "0"));
         }
 
         #[test]
-        fn credentials_search_works_for_filter_by_schema_id_and_cred_def_id() {
-            anoncreds::init_common_wallet();
+        fn prover_credential_status_works_for_expired() {
+            let setup = Setup::wallet();
 
-            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+            let (cred_def_id, cred_def_json) = anoncreds::issuer_create_credential_definition(setup.wallet_handle, ISSUER_DID, &anoncreds::expiring_schema_json(),
+                                                                                              TAG_1, None, Some(&anoncreds::default_cred_def_config())).unwrap();
 
-            let filter_json = json!({
-                "schema_id": anoncreds::gvt_schema_id(),
-                "cred_def_id": anoncreds::issuer_1_gvt_cred_def_id()
-            }).to_string();
+            anoncreds::prover_create_master_secret(setup.wallet_handle, COMMON_MASTER_SECRET).unwrap();
 
-            let (search_handle, count) = anoncreds::prover_search_credentials(wallet_handle, &filter_json).unwrap();
-            assert_eq!(count, 1);
+            // 2000-01-01 and 2100-01-01
+            anoncreds::multi_steps_create_credential(COMMON_MASTER_SECRET, setup.wallet_handle, setup.wallet_handle, CREDENTIAL1_ID,
+                                                     &anoncreds::expiring_credential_values_json(946_684_800), &cred_def_id, &cred_def_json);
+            anoncreds::multi_steps_create_credential(COMMON_MASTER_SECRET, setup.wallet_handle, setup.wallet_handle, anoncreds::CREDENTIAL2_ID,
+                                                     &anoncreds::expiring_credential_values_json(4_102_444_800), &cred_def_id, &cred_def_json);
 
-            let credentials = anoncreds::prover_fetch_credentials(search_handle, count).unwrap();
-            let credentials: Vec<CredentialInfo> = serde_json::from_str(&credentials).unwrap();
+            // Credentials don't expire without expiry attribute
+            assert!(_status(setup.wallet_handle, CREDENTIAL1_ID)["expires_at"].is_null());
+            assert!(_active_cred_ids(setup.wallet_handle, "0").is_empty());
 
-            assert_eq!(credentials.len(), 1);
-            assert!(credentials.contains(&anoncreds::issuer_1_gvt_credential()));
+            anoncreds::prover_set_credential_expiry_attr(setup.wallet_handle, &cred_def_id, Some("expiry")).unwrap();
 
-            anoncreds::prover_close_credentials_search(search_handle).unwrap();
-            wallet::close_wallet(wallet_handle).unwrap();
+            let status = _status(setup.wallet_handle, CREDENTIAL1_ID);
+            assert_eq!(946_684_800, status["expires_at"].as_u64().unwrap());
+            assert!(!status["active"].as_bool().unwrap());
+
+            let status = _status(setup.wallet_handle, anoncreds::CREDENTIAL2_ID);
+            assert_eq!(4_102_444_800, status["expires_at"].as_u64().unwrap());
+            assert!(status["active"].as_bool().unwrap());
+
+            assert_eq!(vec![CREDENTIAL1_ID.to_string()], _active_cred_ids(setup.wallet_handle, "0"));
+            assert_eq!(vec![anoncreds::CREDENTIAL2_ID.to_string()], _active_cred_ids(setup.wallet_handle, "1"));
+
+            anoncreds::prover_set_credential_expiry_attr(setup.wallet_handle, &cred_def_id, None).unwrap();
+
+            let status = _status(setup.wallet_handle, CREDENTIAL1_ID);
+            assert!(status["expires_at"].is_null());
+            assert!(status["active"].as_bool().unwrap());
         }
 
         #[test]
-        fn credentials_search_works_for_filter_by_multiple_schema_ids() {
+        fn prover_get_credentials_works_for_invalid_status_query() {
             anoncreds::init_common_wallet();
 
             let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
 
-            let filter_json = json!({
-                "schema_id": json!({
-                    "$in": vec![anoncreds::gvt_schema_id(), anoncreds::xyz_schema_id()]
-                })
-            }).to_string();
-
-            let (search_handle, count) = anoncreds::prover_search_credentials(wallet_handle, &filter_json).unwrap();
-            assert_eq!(count, 3);
+            let res = anoncreds::prover_get_credentials(wallet_handle, r#"{"status::active": "yes"}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
 
-            let credentials = anoncreds::prover_fetch_credentials(search_handle, count).unwrap();
-            let credentials: Vec<CredentialInfo> = serde_json::from_str(&credentials).unwrap();
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+    }
 
-            assert_eq!(credentials.len(), 3);
-            assert!(credentials.contains(&anoncreds::issuer_1_gvt_credential()));
-            assert!(credentials.contains(&anoncreds::issuer_1_xyz_credential()));
-            assert!(credentials.contains(&anoncreds::issuer_2_gvt_credential()));
+    mod prover_export_import_credential {
+        use super::*;
 
-            anoncreds::prover_close_credentials_search(search_handle).unwrap();
-            wallet::close_wallet(wallet_handle).unwrap();
+        fn _export_config(include_master_secret: bool) -> String {
+            json!({
+                "key": "export_key",
+                "key_derivation_method": "ARGON2I_INT",
+                "master_secret_id": COMMON_MASTER_SECRET,
+                "include_master_secret": include_master_secret,
+            }).to_string()
         }
 
-        #[test]
-        fn credentials_search_works_for_filter_by_schema_id_or_cred_def_id() {
+        fn _export(include_master_secret: bool) -> String {
             anoncreds::init_common_wallet();
 
             let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
 
-            let filter_json = json!({
-                "$or": vec![
-                    json!({"schema_id": anoncreds::gvt_schema_id()}),
-                    json!({"cred_def_id": anoncreds::issuer_1_xyz_cred_def_id()}),
-                ]
-            }).to_string();
+            let bundle_json = anoncreds::prover_export_credential(wallet_handle, CREDENTIAL1_ID, &_export_config(include_master_secret)).unwrap();
 
-            let (search_handle, count) = anoncreds::prover_search_credentials(wallet_handle, &filter_json).unwrap();
-            assert_eq!(count, 3);
+            wallet::close_wallet(wallet_handle).unwrap();
 
-            let credentials = anoncreds::prover_fetch_credentials(search_handle, count).unwrap();
-            let credentials: Vec<CredentialInfo> = serde_json::from_str(&credentials).unwrap();
+            bundle_json
+        }
 
-            assert_eq!(credentials.len(), 3);
-            assert!(credentials.contains(&anoncreds::issuer_1_gvt_credential()));
-            assert!(credentials.contains(&anoncreds::issuer_1_xyz_credential()));
-            assert!(credentials.contains(&anoncreds::issuer_2_gvt_credential()));
+        #[test]
+        fn prover_export_credential_works() {
+            let bundle_json = _export(false);
 
-            anoncreds::prover_close_credentials_search(search_handle).unwrap();
-            wallet::close_wallet(wallet_handle).unwrap();
+            let bundle: serde_json::Value = serde_json::from_str(&bundle_json).unwrap();
+            assert_eq!("1.0", bundle["ver"].as_str().unwrap());
+            assert_eq!("ARGON2I_INT", bundle["key_derivation_method"].as_str().unwrap());
+            assert!(!bundle_json.contains("Alex"));
         }
 
         #[test]
-        fn credentials_search_works_for_no_results() {
+        fn prover_export_credential_works_for_omitted_master_secret_id() {
             anoncreds::init_common_wallet();
 
             let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
 
-            let filter_json = json!({"other_field": "other_value"}).to_string();
+            let config = json!({
+                "key": "export_key",
+                "key_derivation_method": "ARGON2I_INT",
+                "include_master_secret": true,
+            }).to_string();
 
-            let (search_handle, count) = anoncreds::prover_search_credentials(wallet_handle, &filter_json).unwrap();
-            assert_eq!(count, 0);
+            let bundle_json = anoncreds::prover_export_credential(wallet_handle, CREDENTIAL1_ID, &config).unwrap();
 
-            let credentials = anoncreds::prover_fetch_credentials(search_handle, 100).unwrap();
-            let credentials: Vec<CredentialInfo> = serde_json::from_str(&credentials).unwrap();
-            assert_eq!(credentials.len(), 0);
-
-            anoncreds::prover_close_credentials_search(search_handle).unwrap();
             wallet::close_wallet(wallet_handle).unwrap();
-        }
-    }
 
-    //NOTE: There are following credential stored in wallet:
-    // {"issuer_did": ISSUER_DID, "schema_id": gvt_schema_id}
-    // {"issuer_did": ISSUER_DID, "schema_id": xyz_schema_id}
-    // {"issuer_did": DID, "schema_id": gvt_schema_id}
-    mod prover_get_credentials_for_proof_req {
-        use super::*;
-        use crate::utils::domain::anoncreds::schema::SchemaId;
-        use crate::utils::domain::anoncreds::credential_definition::CredentialDefinitionId;
+            let setup = Setup::wallet();
+
+            anoncreds::prover_import_credential(setup.wallet_handle, &bundle_json, r#"{"key":"export_key"}"#).unwrap();
+
+            let credentials = anoncreds::prover_get_credentials(setup.wallet_handle, &json!({"master_secret_id": COMMON_MASTER_SECRET}).to_string()).unwrap();
+            let credentials: Vec<CredentialInfo> = serde_json::from_str(&credentials).unwrap();
+            assert_eq!(1, credentials.len());
+        }
 
         #[test]
-        fn prover_get_credentials_for_proof_req_works_for_empty_req() {
+        fn prover_export_credential_works_for_other_master_secret() {
             anoncreds::init_common_wallet();
 
             let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
 
-            let proof_req = json!({
-               "nonce":"123432421212",
-               "name":"proof_req_1",
-               "version":"0.1",
-               "requested_attributes": json!({}),
-               "requested_predicates": json!({}),
+            let config = json!({
+                "key": "export_key",
+                "key_derivation_method": "ARGON2I_INT",
+                "master_secret_id": "other_master_secret",
             }).to_string();
 
-            let res = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req);
+            let res = anoncreds::prover_export_credential(wallet_handle, CREDENTIAL1_ID, &config);
             assert_code!(ErrorCode::CommonInvalidStructure, res);
 
             wallet::close_wallet(wallet_handle).unwrap();
         }
 
         #[test]
-        fn prover_get_credentials_for_proof_req_works_for_revealed_attr_only() {
+        fn prover_export_credential_works_for_not_found() {
             anoncreds::init_common_wallet();
 
             let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
 
-            let proof_req = json!({
-               "nonce":"123432421212",
-               "name":"proof_req_1",
-               "version":"0.1",
-               "requested_attributes": json!({
-                   "attr1_referent": json!({
-                       "name":"name"
-                   })
-               }),
-               "requested_predicates": json!({ }),
-            }).to_string();
-
-            let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
-            println!("credentials_json:\n{}", credentials_json);
-
-            let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
-            assert_eq!(credentials.attrs.len(), 1);
-
-            let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
-            assert_eq!(credentials_for_attr_1.len(), 2);
+            let res = anoncreds::prover_export_credential(wallet_handle, "other_cred_id", &_export_config(false));
+            assert_code!(ErrorCode::WalletItemNotFound, res);
 
             wallet::close_wallet(wallet_handle).unwrap();
         }
 
         #[test]
-        fn prover_get_credentials_for_proof_req_works_for_revealed_attr_names_with_restrictions() {
+        fn prover_export_credential_works_for_raw_key_derivation() {
             anoncreds::init_common_wallet();
 
             let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
 
-            let proof_req = json!({
-               "nonce":"123432421212",
-               "name":"proof_req_1",
-               "version":"0.1",
-               "requested_attributes": json!({
-                   "attr1_referent": json!({
-                       "names":["name", "sex"],
-                       "restrictions": {
-                            "attr::name::value": "Alex"
-                       }
-                   })
-               }),
-               "requested_predicates": json!({ }),
+            let config = json!({
+                "key": "export_key",
+                "key_derivation_method": "RAW",
+                "master_secret_id": COMMON_MASTER_SECRET,
             }).to_string();
 
-            let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
-            println!("credentials_json:\n{}", credentials_json);
-
-            let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
-            assert_eq!(credentials.attrs.len(), 1);
-
-            let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
-            assert_eq!(credentials_for_attr_1.len(), 1);
+            let res = anoncreds::prover_export_credential(wallet_handle, CREDENTIAL1_ID, &config);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
 
             wallet::close_wallet(wallet_handle).unwrap();
         }
 
         #[test]
-        fn prover_get_credentials_for_proof_req_works_for_revealed_attr_names() {
-            anoncreds::init_common_wallet();
+        fn prover_import_credential_works_with_master_secret() {
+            let bundle_json = _export(true);
 
-            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+            let setup = Setup::wallet();
 
-            let proof_req = json!({
-               "nonce":"123432421212",
-               "name":"proof_req_1",
-               "version":"0.1",
-               "requested_attributes": json!({
-                   "attr1_referent": json!({
-                       "names":["name", "sex"]
-                   })
-               }),
-               "requested_predicates": json!({ }),
-            }).to_string();
+            let cred_id = anoncreds::prover_import_credential(setup.wallet_handle, &bundle_json, r#"{"key":"export_key"}"#).unwrap();
+            assert_eq!(CREDENTIAL1_ID, cred_id);
 
-            let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
+            let credential = anoncreds::prover_get_credential(setup.wallet_handle, &cred_id).unwrap();
+            let credential: CredentialInfo = serde_json::from_str(&credential).unwrap();
+            assert_eq!(credential, anoncreds::issuer_1_gvt_credential());
 
-            let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
-            assert_eq!(credentials.attrs.len(), 1);
+            let res = anoncreds::prover_create_master_secret(setup.wallet_handle, COMMON_MASTER_SECRET);
+            assert_code!(ErrorCode::AnoncredsMasterSecretDuplicateNameError, res);
+        }
 
-            let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
-            assert_eq!(credentials_for_attr_1.len(), 2);
+        #[test]
+        fn prover_import_credential_works_for_custom_cred_id() {
+            let bundle_json = _export(true);
 
-            wallet::close_wallet(wallet_handle).unwrap();
+            let setup = Setup::wallet();
+
+            let config = json!({"key": "export_key", "cred_id": "imported_cred_id"}).to_string();
+
+            let cred_id = anoncreds::prover_import_credential(setup.wallet_handle, &bundle_json, &config).unwrap();
+            assert_eq!("imported_cred_id", cred_id);
+
+            let credential = anoncreds::prover_get_credential(setup.wallet_handle, &cred_id).unwrap();
+            let credential: CredentialInfo = serde_json::from_str(&credential).unwrap();
+            assert_eq!(credential.attrs, anoncreds::issuer_1_gvt_credential().attrs);
         }
 
         #[test]
-        fn prover_get_credentials_for_proof_req_works_for_revealed_attr_in_upper_case() {
-            anoncreds::init_common_wallet();
-
-            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+        fn prover_import_credential_works_for_replacing() {
+            let bundle_json = _export(true);
 
-            let proof_req = json!({
-               "nonce":"123432421212",
-               "name":"proof_req_1",
-               "version":"0.1",
-               "requested_attributes": json!({
-                   "attr1_referent": json!({
-                       "name":"NAME"
-                   })
-               }),
-               "requested_predicates": json!({ }),
-            }).to_string();
+            let setup = Setup::wallet();
 
-            let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
+            anoncreds::prover_import_credential(setup.wallet_handle, &bundle_json, r#"{"key":"export_key"}"#).unwrap();
 
-            let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
-            assert_eq!(credentials.attrs.len(), 1);
+            anoncreds::prover_set_credential_identity_attr(setup.wallet_handle, &anoncreds::issuer_1_gvt_cred_def_id(), Some("name")).unwrap();
 
-            let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
-            assert_eq!(credentials_for_attr_1.len(), 2);
+            let config = json!({"key": "export_key", "cred_id": "imported_cred_id"}).to_string();
+            anoncreds::prover_import_credential(setup.wallet_handle, &bundle_json, &config).unwrap();
 
-            wallet::close_wallet(wallet_handle).unwrap();
+            let status_json = anoncreds::prover_get_credential_status(setup.wallet_handle, CREDENTIAL1_ID).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status_json).unwrap();
+            assert_eq!("imported_cred_id", status["replaced_by"].as_str().unwrap());
         }
 
         #[test]
-        fn prover_get_credentials_for_proof_req_works_for_revealed_attr_contains_spaces() {
-            anoncreds::init_common_wallet();
+        fn prover_import_credential_works_for_missed_master_secret() {
+            let bundle_json = _export(false);
 
-            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+            let setup = Setup::wallet();
 
-            let proof_req = json!({
-               "nonce":"123432421212",
-               "name":"proof_req_1",
-               "version":"0.1",
-               "requested_attributes": json!({
-                   "attr1_referent": json!({
-                       "name":" name"
-                   })
-               }),
-               "requested_predicates": json!({ }),
-            }).to_string();
+            let res = anoncreds::prover_import_credential(setup.wallet_handle, &bundle_json, r#"{"key":"export_key"}"#);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
 
-            let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
+        #[test]
+        fn prover_import_credential_works_for_other_master_secret() {
+            let bundle_json = _export(true);
 
-            let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
-            assert_eq!(credentials.attrs.len(), 1);
+            let setup = Setup::wallet();
 
-            let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
-            assert_eq!(credentials_for_attr_1.len(), 2);
+            anoncreds::prover_create_master_secret(setup.wallet_handle, COMMON_MASTER_SECRET).unwrap();
 
-            wallet::close_wallet(wallet_handle).unwrap();
+            let res = anoncreds::prover_import_credential(setup.wallet_handle, &bundle_json, r#"{"key":"export_key"}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
         }
 
-        mod attribute_restrictions_libindy_1_5_format {
-            use super::*;
-
-            #[test]
-            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_specific_issuer() {
-                anoncreds::init_common_wallet();
+        #[test]
+        fn prover_import_credential_works_for_wrong_key() {
+            let bundle_json = _export(true);
 
-                let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+            let setup = Setup::wallet();
 
-                let proof_req = json!({
-                   "nonce":"123432421212",
-                   "name":"proof_req_1",
-                   "version":"0.1",
-                   "requested_attributes": json!({
-                       "attr1_referent": json!({
-                           "name":"name",
-                           "restrictions": [json!({ "issuer_did": ISSUER_DID })]
-                       })
-                   }),
-                   "requested_predicates": json!({
-                   }),
-                }).to_string();
+            let res = anoncreds::prover_import_credential(setup.wallet_handle, &bundle_json, r#"{"key":"other_key"}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
 
-                let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
+        #[test]
+        fn prover_import_credential_works_for_existing_cred_id() {
+            let bundle_json = _export(false);
 
-                let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
-                assert_eq!(credentials.attrs.len(), 1);
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
 
-                let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
-                assert_eq!(credentials_for_attr_1.len(), 1);
+            let res = anoncreds::prover_import_credential(wallet_handle, &bundle_json, r#"{"key":"export_key"}"#);
+            assert_code!(ErrorCode::WalletItemAlreadyExists, res);
 
-                wallet::close_wallet(wallet_handle).unwrap();
-            }
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
 
-            #[test]
-            fn prover_get_credentials_for_proof_rea_works_for_revealed_attr_for_multiple_issuers() {
-                anoncreds::init_common_wallet();
+        #[test]
+        fn prover_import_credential_works_after_delete() {
+            let bundle_json = _export(true);
 
-                let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+            let setup = Setup::wallet();
 
-                let proof_req = json!({
-                   "nonce":"123432421212",
-                   "name":"proof_req_1",
-                   "version":"0.1",
-                   "requested_attributes": json!({
-                       "attr1_referent": json!({
-                           "name":"name",
-                           "restrictions": [json!({ "issuer_did": ISSUER_DID }), json!({ "issuer_did": ISSUER_DID_2 })]
-                       })
-                   }),
-                   "requested_predicates": json!({}),
-                }).to_string();
+            anoncreds::prover_import_credential(setup.wallet_handle, &bundle_json, r#"{"key":"export_key"}"#).unwrap();
 
-                let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
+            // the credential request metadata imported with the credential is deleted with it
+            anoncreds::prover_delete_credential(setup.wallet_handle, CREDENTIAL1_ID).unwrap();
 
-                let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
-                assert_eq!(credentials.attrs.len(), 1);
+            let cred_id = anoncreds::prover_import_credential(setup.wallet_handle, &bundle_json, r#"{"key":"export_key"}"#).unwrap();
+            assert_eq!(CREDENTIAL1_ID, cred_id);
+        }
 
-                let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
-                assert_eq!(credentials_for_attr_1.len(), 2);
+        #[test]
+        fn prover_import_credential_works_for_raw_key_derivation() {
+            let bundle_json = _export(true);
 
-                wallet::close_wallet(wallet_handle).unwrap();
-            }
+            let mut bundle: serde_json::Value = serde_json::from_str(&bundle_json).unwrap();
+            bundle["key_derivation_method"] = json!("RAW");
 
-            #[test]
-            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_specific_schema() {
-                anoncreds::init_common_wallet();
+            let setup = Setup::wallet();
 
-                let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+            let res = anoncreds::prover_import_credential(setup.wallet_handle, &bundle.to_string(), r#"{"key":"export_key"}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
 
-                let proof_req = json!({
-                   "nonce":"123432421212",
-                   "name":"proof_req_1",
-                   "version":"0.1",
-                   "requested_attributes": json!({
-                       "attr1_referent": json!({
-                           "name":"name",
-                           "restrictions": [json!({ "schema_id": anoncreds::gvt_schema_id() })]
-                       })
-                   }),
-                   "requested_predicates": json!({}),
-                }).to_string();
+    mod prover_credentials_search {
+        use super::*;
 
-                let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
+        #[test]
+        fn credentials_search_works() {
+            anoncreds::init_common_wallet();
 
-                let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
-                assert_eq!(credentials.attrs.len(), 1);
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
 
-                let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
-                assert_eq!(credentials_for_attr_1.len(), 2);
+            let (search_handle, count) = anoncreds::prover_search_credentials(wallet_handle, "{}").unwrap();
+            assert_eq!(count, 4);
 
-                wallet::close_wallet(wallet_handle).unwrap();
-            }
+            let credentials = anoncreds::prover_fetch_credentials(search_handle, count).unwrap();
+            let credentials: Vec<CredentialInfo> = serde_json::from_str(&credentials).unwrap();
 
-            #[test]
-            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_schema_name() {
-                anoncreds::init_common_wallet();
+            assert_eq!(credentials.len(), 4);
+            assert!(credentials.contains(&anoncreds::issuer_1_gvt_credential()));
+            assert!(credentials.contains(&anoncreds::issuer_1_xyz_credential()));
+            assert!(credentials.contains(&anoncreds::issuer_2_gvt_credential()));
 
-                let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+            anoncreds::prover_close_credentials_search(search_handle).unwrap();
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
 
-                let proof_req = json!({
-                   "nonce":"123432421212",
-                   "name":"proof_req_1",
-                   "version":"0.1",
-                   "requested_attributes": json!({
-                       "attr1_referent": json!({
-                           "name":"name",
-                           "restrictions": [json!({ "schema_name": GVT_SCHEMA_NAME })]
-                       })
-                   }),
-                   "requested_predicates": json!({}),
-                }).to_string();
+        #[test]
+        fn credentials_search_works_for_filter_by_issuer_did() {
+            anoncreds::init_common_wallet();
 
-                let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
 
-                let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
-                assert_eq!(credentials.attrs.len(), 1);
+            let filter_json = json!({"issuer_did": ISSUER_DID}).to_string();
+            let (search_handle, count) = anoncreds::prover_search_credentials(wallet_handle, &filter_json).unwrap();
+            assert_eq!(count, 2);
 
-                let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
-                assert_eq!(credentials_for_attr_1.len(), 2);
+            let credentials = anoncreds::prover_fetch_credentials(search_handle, count).unwrap();
+            let credentials: Vec<CredentialInfo> = serde_json::from_str(&credentials).unwrap();
 
-                wallet::close_wallet(wallet_handle).unwrap();
-            }
+            assert_eq!(credentials.len(), 2);
+            assert!(credentials.contains(&anoncreds::issuer_1_gvt_credential()));
+            assert!(credentials.contains(&anoncreds::issuer_1_xyz_credential()));
 
-            #[test]
-            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_schema_version() {
-                anoncreds::init_common_wallet();
+            anoncreds::prover_close_credentials_search(search_handle).unwrap();
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
 
-                let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+        #[test]
+        fn credentials_search_works_for_filter_by_schema_id() {
+            anoncreds::init_common_wallet();
 
-                let proof_req = json!({
-                   "nonce":"123432421212",
-                   "name":"proof_req_1",
-                   "version":"0.1",
-                   "requested_attributes": json!({
-                       "attr1_referent": json!({
-                           "name":"name",
-                           "restrictions": [json!({ "schema_version": SCHEMA_VERSION })]
-                       })
-                   }),
-                   "requested_predicates": json!({}),
-                }).to_string();
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
 
-                let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
+            let filter_json = json!({"schema_id": anoncreds::gvt_schema_id()}).to_string();
+            let (search_handle, count) = anoncreds::prover_search_credentials(wallet_handle, &filter_json).unwrap();
+            assert_eq!(count, 2);
 
-                let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
-                assert_eq!(credentials.attrs.len(), 1);
+            let credentials = anoncreds::prover_fetch_credentials(search_handle, count).unwrap();
+            let credentials: Vec<CredentialInfo> = serde_json::from_str(&credentials).unwrap();
 
-                let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
-                assert_eq!(credentials_for_attr_1.len(), 2);
+            assert_eq!(credentials.len(), 2);
+            assert!(credentials.contains(&anoncreds::issuer_1_gvt_credential()));
+            assert!(credentials.contains(&anoncreds::issuer_2_gvt_credential()));
 
-                wallet::close_wallet(wallet_handle).unwrap();
-            }
+            anoncreds::prover_close_credentials_search(search_handle).unwrap();
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
 
-            #[test]
-            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_schema_issuer_did() {
-                anoncreds::init_common_wallet();
+        #[test]
+        fn credentials_search_works_for_filter_by_schema_id_and_cred_def_id() {
+            anoncreds::init_common_wallet();
 
-                let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
 
-                let proof_req = json!({
-                   "nonce":"123432421212",
-                   "name":"proof_req_1",
-                   "version":"0.1",
-                   "requested_attributes": json!({
-                       "attr1_referent": json!({
-                           "name":"name",
-                           "restrictions": [json!({ "schema_issuer_did": ISSUER_DID })]
-                       })
-                   }),
-                   "requested_predicates": json!({}),
-                }).to_string();
+            let filter_json = json!({
+                "schema_id": anoncreds::gvt_schema_id(),
+                "cred_def_id": anoncreds::issuer_1_gvt_cred_def_id()
+            }).to_string();
 
-                let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
+            let (search_handle, count) = anoncreds::prover_search_credentials(wallet_handle, &filter_json).unwrap();
+            assert_eq!(count, 1);
 
-                let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
-                assert_eq!(credentials.attrs.len(), 1);
+            let credentials = anoncreds::prover_fetch_credentials(search_handle, count).unwrap();
+            let credentials: Vec<CredentialInfo> = serde_json::from_str(&credentials).unwrap();
 
-                let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
-                assert_eq!(credentials_for_attr_1.len(), 2);
+            assert_eq!(credentials.len(), 1);
+            assert!(credentials.contains(&anoncreds::issuer_1_gvt_credential()));
 
-                wallet::close_wallet(wallet_handle).unwrap();
-            }
+            anoncreds::prover_close_credentials_search(search_handle).unwrap();
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
 
-            #[test]
-            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_specific_schema_id_or_specific_cred_def_id() {
-                anoncreds::init_common_wallet();
+        #[test]
+        fn credentials_search_works_for_filter_by_multiple_schema_ids() {
+            anoncreds::init_common_wallet();
 
-                let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
 
-                let proof_req = json!({
-                   "nonce":"123432421212",
-                   "name":"proof_req_1",
-                   "version":"0.1",
-                   "requested_attributes": json!({
-                       "attr1_referent": json!({
-                           "name":"name",
-                           "restrictions": [json!({ "schema_id": anoncreds::gvt_schema_id() }), json!({ "cred_def_id": anoncreds::issuer_1_gvt_cred_def_id() })]
-                       })
-                   }),
-                   "requested_predicates": json!({}),
-                }).to_string();
+            let filter_json = json!({
+                "schema_id": json!({
+                    "$in": vec![anoncreds::gvt_schema_id(), anoncreds::xyz_schema_id()]
+                })
+            }).to_string();
 
-                let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
+            let (search_handle, count) = anoncreds::prover_search_credentials(wallet_handle, &filter_json).unwrap();
+            assert_eq!(count, 3);
 
-                let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
-                assert_eq!(credentials.attrs.len(), 1);
+            let credentials = anoncreds::prover_fetch_credentials(search_handle, count).unwrap();
+            let credentials: Vec<CredentialInfo> = serde_json::from_str(&credentials).unwrap();
 
-                let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
-                assert_eq!(credentials_for_attr_1.len(), 2);
+            assert_eq!(credentials.len(), 3);
+            assert!(credentials.contains(&anoncreds::issuer_1_gvt_credential()));
+            assert!(credentials.contains(&anoncreds::issuer_1_xyz_credential()));
+            assert!(credentials.contains(&anoncreds::issuer_2_gvt_credential()));
 
-                wallet::close_wallet(wallet_handle).unwrap();
-            }
+            anoncreds::prover_close_credentials_search(search_handle).unwrap();
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
 
-            #[test]
-            fn prover_get_credentials_for_proof_rea_works_for_revealed_attr_for_multiple_schemas() {
-                anoncreds::init_common_wallet();
+        #[test]
+        fn credentials_search_works_for_filter_by_schema_id_or_cred_def_id() {
+            anoncreds::init_common_wallet();
 
-                let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
 
-                let proof_req = json!({
-                   "nonce":"123432421212",
-                   "name":"proof_req_1",
-                   "version":"0.1",
-                   "requested_attributes": json!({
-                       "attr1_referent": json!({
-                           "name":"name",
-                           "restrictions": [json!({ "schema_id":  anoncreds::gvt_schema_id() }), json!({ "schema_id": anoncreds::xyz_schema_id() })]
-                       })
-                   }),
-                   "requested_predicates": json!({}),
-                }).to_string();
+            let filter_json = json!({
+                "$or": vec![
+                    json!({"schema_id": anoncreds::gvt_schema_id()}),
+                    json!({"cred_def_id": anoncreds::issuer_1_xyz_cred_def_id()}),
+                ]
+            }).to_string();
 
-                let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
+            let (search_handle, count) = anoncreds::prover_search_credentials(wallet_handle, &filter_json).unwrap();
+            assert_eq!(count, 3);
 
-                let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
-                assert_eq!(credentials.attrs.len(), 1);
+            let credentials = anoncreds::prover_fetch_credentials(search_handle, count).unwrap();
+            let credentials: Vec<CredentialInfo> = serde_json::from_str(&credentials).unwrap();
 
-                let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
-                assert_eq!(credentials_for_attr_1.len(), 2);
+            assert_eq!(credentials.len(), 3);
+            assert!(credentials.contains(&anoncreds::issuer_1_gvt_credential()));
+            assert!(credentials.contains(&anoncreds::issuer_1_xyz_credential()));
+            assert!(credentials.contains(&anoncreds::issuer_2_gvt_credential()));
 
-                wallet::close_wallet(wallet_handle).unwrap();
-            }
+            anoncreds::prover_close_credentials_search(search_handle).unwrap();
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
 
-            #[test]
-            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_specific_cred_def_id() {
-                anoncreds::init_common_wallet();
+        #[test]
+        fn credentials_search_works_for_no_results() {
+            anoncreds::init_common_wallet();
 
-                let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
 
-                let proof_req = json!({
-                   "nonce":"123432421212",
-                   "name":"proof_req_1",
-                   "version":"0.1",
-                   "requested_attributes": json!({
-                       "attr1_referent": json!({
-                           "name":"name",
-                           "restrictions": [json!({ "cred_def_id": anoncreds::issuer_1_gvt_cred_def_id() })]
-                       })
-                   }),
-                   "requested_predicates": json!({}),
-                }).to_string();
+            let filter_json = json!({"other_field": "other_value"}).to_string();
 
-                let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
+            let (search_handle, count) = anoncreds::prover_search_credentials(wallet_handle, &filter_json).unwrap();
+            assert_eq!(count, 0);
 
-                let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
-                let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
+            let credentials = anoncreds::prover_fetch_credentials(search_handle, 100).unwrap();
+            let credentials: Vec<CredentialInfo> = serde_json::from_str(&credentials).unwrap();
+            assert_eq!(credentials.len(), 0);
 
-                wallet::close_wallet(wallet_handle).unwrap();
+            anoncreds::prover_close_credentials_search(search_handle).unwrap();
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+    }
 
-                assert_eq!(credentials.attrs.len(), 1);
-                assert_eq!(credentials_for_attr_1.len(), 1);
-            }
+    //NOTE: There are following credential stored in wallet:
+    // {"issuer_did": ISSUER_DID, "schema_id": gvt_schema_id}
+    // {"issuer_did": ISSUER_DID, "schema_id": xyz_schema_id}
+    // {"issuer_did": DID, "schema_id": gvt_schema_id}
+    mod prover_get_credentials_for_proof_req {
+        use super::*;
+        use crate::utils::domain::anoncreds::schema::SchemaId;
+        use crate::utils::domain::anoncreds::credential_definition::CredentialDefinitionId;
 
-            #[test]
-            fn prover_get_credentials_for_proof_rea_works_for_revealed_attr_for_multiple_cred_def_ids() {
-                anoncreds::init_common_wallet();
+        #[test]
+        fn prover_get_credentials_for_proof_req_works_for_empty_req() {
+            anoncreds::init_common_wallet();
 
-                let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let proof_req = json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({}),
+               "requested_predicates": json!({}),
+            }).to_string();
+
+            let res = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn prover_get_credentials_for_proof_req_works_for_revealed_attr_only() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let proof_req = json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({
+                   "attr1_referent": json!({
+                       "name":"name"
+                   })
+               }),
+               "requested_predicates": json!({ }),
+            }).to_string();
+
+            let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
+            println!("credentials_json:\n{}", credentials_json);
+
+            let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
+            assert_eq!(credentials.attrs.len(), 1);
+
+            let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
+            assert_eq!(credentials_for_attr_1.len(), 2);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn prover_get_credentials_for_proof_req_works_for_revealed_attr_names_with_restrictions() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let proof_req = json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({
+                   "attr1_referent": json!({
+                       "names":["name", "sex"],
+                       "restrictions": {
+                            "attr::name::value": "Alex"
+                       }
+                   })
+               }),
+               "requested_predicates": json!({ }),
+            }).to_string();
+
+            let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
+            println!("credentials_json:\n{}", credentials_json);
+
+            let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
+            assert_eq!(credentials.attrs.len(), 1);
+
+            let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
+            assert_eq!(credentials_for_attr_1.len(), 1);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn prover_get_credentials_for_proof_req_works_for_revealed_attr_names() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let proof_req = json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({
+                   "attr1_referent": json!({
+                       "names":["name", "sex"]
+                   })
+               }),
+               "requested_predicates": json!({ }),
+            }).to_string();
+
+            let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
+
+            let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
+            assert_eq!(credentials.attrs.len(), 1);
+
+            let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
+            assert_eq!(credentials_for_attr_1.len(), 2);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn prover_get_credentials_for_proof_req_works_for_revealed_attr_in_upper_case() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let proof_req = json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({
+                   "attr1_referent": json!({
+                       "name":"NAME"
+                   })
+               }),
+               "requested_predicates": json!({ }),
+            }).to_string();
+
+            let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
+
+            let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
+            assert_eq!(credentials.attrs.len(), 1);
+
+            let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
+            assert_eq!(credentials_for_attr_1.len(), 2);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn prover_get_credentials_for_proof_req_works_for_revealed_attr_contains_spaces() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let proof_req = json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({
+                   "attr1_referent": json!({
+                       "name":" name"
+                   })
+               }),
+               "requested_predicates": json!({ }),
+            }).to_string();
+
+            let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
+
+            let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
+            assert_eq!(credentials.attrs.len(), 1);
+
+            let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
+            assert_eq!(credentials_for_attr_1.len(), 2);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        mod attribute_restrictions_libindy_1_5_format {
+            use super::*;
+
+            #[test]
+            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_specific_issuer() {
+                anoncreds::init_common_wallet();
+
+                let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
 
                 let proof_req = json!({
                    "nonce":"123432421212",
@@ -913,10 +1288,11 @@ mod high_cases {
                    "requested_attributes": json!({
                        "attr1_referent": json!({
                            "name":"name",
-                           "restrictions": [json!({ "cred_def_id": anoncreds::issuer_1_gvt_cred_def_id() }), json!({ "cred_def_id": anoncreds::issuer_2_gvt_cred_def_id() })]
+                           "restrictions": [json!({ "issuer_did": ISSUER_DID })]
                        })
                    }),
-                   "requested_predicates": json!({}),
+                   "requested_predicates": json!({
+                   }),
                 }).to_string();
 
                 let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
@@ -925,13 +1301,13 @@ mod high_cases {
                 assert_eq!(credentials.attrs.len(), 1);
 
                 let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
-                assert_eq!(credentials_for_attr_1.len(), 2);
+                assert_eq!(credentials_for_attr_1.len(), 1);
 
                 wallet::close_wallet(wallet_handle).unwrap();
             }
 
             #[test]
-            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_cred_def_id_or_issuer_did() {
+            fn prover_get_credentials_for_proof_rea_works_for_revealed_attr_for_multiple_issuers() {
                 anoncreds::init_common_wallet();
 
                 let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
@@ -943,7 +1319,7 @@ mod high_cases {
                    "requested_attributes": json!({
                        "attr1_referent": json!({
                            "name":"name",
-                           "restrictions": [json!({ "cred_def_id": anoncreds::issuer_1_gvt_cred_def_id() }), json!({ "issuer_did": ISSUER_DID_2 })]
+                           "restrictions": [json!({ "issuer_did": ISSUER_DID }), json!({ "issuer_did": ISSUER_DID_2 })]
                        })
                    }),
                    "requested_predicates": json!({}),
@@ -961,7 +1337,7 @@ mod high_cases {
             }
 
             #[test]
-            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_other_issuer() {
+            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_specific_schema() {
                 anoncreds::init_common_wallet();
 
                 let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
@@ -973,11 +1349,10 @@ mod high_cases {
                    "requested_attributes": json!({
                        "attr1_referent": json!({
                            "name":"name",
-                           "restrictions": [json!({ "issuer_did": DID_TRUSTEE })]
+                           "restrictions": [json!({ "schema_id": anoncreds::gvt_schema_id() })]
                        })
                    }),
-                   "requested_predicates": json!({
-                   }),
+                   "requested_predicates": json!({}),
                 }).to_string();
 
                 let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
@@ -986,13 +1361,13 @@ mod high_cases {
                 assert_eq!(credentials.attrs.len(), 1);
 
                 let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
-                assert_eq!(credentials_for_attr_1.len(), 0);
+                assert_eq!(credentials_for_attr_1.len(), 2);
 
                 wallet::close_wallet(wallet_handle).unwrap();
             }
 
             #[test]
-            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_other_schema_id() {
+            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_schema_name() {
                 anoncreds::init_common_wallet();
 
                 let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
@@ -1004,11 +1379,10 @@ mod high_cases {
                    "requested_attributes": json!({
                        "attr1_referent": json!({
                            "name":"name",
-                           "restrictions": [json!({ "schema_id": SchemaId::new(&DidValue(DID_TRUSTEE.to_string()), GVT_SCHEMA_NAME, SCHEMA_VERSION) })]
+                           "restrictions": [json!({ "schema_name": GVT_SCHEMA_NAME })]
                        })
                    }),
-                   "requested_predicates": json!({
-                   }),
+                   "requested_predicates": json!({}),
                 }).to_string();
 
                 let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
@@ -1017,13 +1391,13 @@ mod high_cases {
                 assert_eq!(credentials.attrs.len(), 1);
 
                 let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
-                assert_eq!(credentials_for_attr_1.len(), 0);
+                assert_eq!(credentials_for_attr_1.len(), 2);
 
                 wallet::close_wallet(wallet_handle).unwrap();
             }
 
             #[test]
-            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_other_cred_def_id() {
+            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_schema_version() {
                 anoncreds::init_common_wallet();
 
                 let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
@@ -1035,11 +1409,10 @@ mod high_cases {
                    "requested_attributes": json!({
                        "attr1_referent": json!({
                            "name":"name",
-                           "restrictions": [json!({ "cred_def_id": CredentialDefinitionId::new(&DidValue(DID_TRUSTEE.to_string()), &SchemaId(anoncreds::gvt_schema_id()), "CL", TAG_1) })]
+                           "restrictions": [json!({ "schema_version": SCHEMA_VERSION })]
                        })
                    }),
-                   "requested_predicates": json!({
-                   }),
+                   "requested_predicates": json!({}),
                 }).to_string();
 
                 let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
@@ -1048,39 +1421,29 @@ mod high_cases {
                 assert_eq!(credentials.attrs.len(), 1);
 
                 let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
-                assert_eq!(credentials_for_attr_1.len(), 0);
+                assert_eq!(credentials_for_attr_1.len(), 2);
 
                 wallet::close_wallet(wallet_handle).unwrap();
             }
 
             #[test]
-            fn prover_get_credentials_for_proof_req_works_for_null_restrictions() {
+            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_schema_issuer_did() {
                 anoncreds::init_common_wallet();
 
                 let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
 
-                let proof_req = r#"{
+                let proof_req = json!({
                    "nonce":"123432421212",
                    "name":"proof_req_1",
                    "version":"0.1",
-                   "requested_attributes":{
-                      "attr1_referent":{
-                         "name":"name",
-                         "restrictions":[
-                            {
-                               "schema_id":null,
-                               "schema_issuer_did":null,
-                               "schema_name":null,
-                               "schema_version":null,
-                               "issuer_did":"NcYxiDXkpYi6ov5FcYDi1e",
-                               "cred_def_id":null
-                            }
-                         ]
-                      }
-                   },
-                   "requested_predicates":{
-                   }
-                }"#;
+                   "requested_attributes": json!({
+                       "attr1_referent": json!({
+                           "name":"name",
+                           "restrictions": [json!({ "schema_issuer_did": ISSUER_DID })]
+                       })
+                   }),
+                   "requested_predicates": json!({}),
+                }).to_string();
 
                 let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
 
@@ -1088,17 +1451,13 @@ mod high_cases {
                 assert_eq!(credentials.attrs.len(), 1);
 
                 let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
-                assert_eq!(credentials_for_attr_1.len(), 1);
+                assert_eq!(credentials_for_attr_1.len(), 2);
 
                 wallet::close_wallet(wallet_handle).unwrap();
             }
-        }
-
-        mod attribute_restrictions_wql_format {
-            use super::*;
 
             #[test]
-            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_specific_issuer() {
+            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_specific_schema_id_or_specific_cred_def_id() {
                 anoncreds::init_common_wallet();
 
                 let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
@@ -1110,11 +1469,10 @@ mod high_cases {
                    "requested_attributes": json!({
                        "attr1_referent": json!({
                            "name":"name",
-                           "restrictions": json!({ "issuer_did": ISSUER_DID })
+                           "restrictions": [json!({ "schema_id": anoncreds::gvt_schema_id() }), json!({ "cred_def_id": anoncreds::issuer_1_gvt_cred_def_id() })]
                        })
                    }),
-                   "requested_predicates": json!({
-                   }),
+                   "requested_predicates": json!({}),
                 }).to_string();
 
                 let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
@@ -1123,13 +1481,13 @@ mod high_cases {
                 assert_eq!(credentials.attrs.len(), 1);
 
                 let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
-                assert_eq!(credentials_for_attr_1.len(), 1);
+                assert_eq!(credentials_for_attr_1.len(), 2);
 
                 wallet::close_wallet(wallet_handle).unwrap();
             }
 
             #[test]
-            fn prover_get_credentials_for_proof_rea_works_for_revealed_attr_for_multiple_issuers() {
+            fn prover_get_credentials_for_proof_rea_works_for_revealed_attr_for_multiple_schemas() {
                 anoncreds::init_common_wallet();
 
                 let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
@@ -1141,11 +1499,7 @@ mod high_cases {
                    "requested_attributes": json!({
                        "attr1_referent": json!({
                            "name":"name",
-                           "restrictions": json!({
-                               "issuer_did": json!({
-                                    "$in": vec![ISSUER_DID, ISSUER_DID_2]
-                               })
-                           })
+                           "restrictions": [json!({ "schema_id":  anoncreds::gvt_schema_id() }), json!({ "schema_id": anoncreds::xyz_schema_id() })]
                        })
                    }),
                    "requested_predicates": json!({}),
@@ -1163,7 +1517,7 @@ mod high_cases {
             }
 
             #[test]
-            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_specific_schema() {
+            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_specific_cred_def_id() {
                 anoncreds::init_common_wallet();
 
                 let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
@@ -1175,7 +1529,7 @@ mod high_cases {
                    "requested_attributes": json!({
                        "attr1_referent": json!({
                            "name":"name",
-                           "restrictions": json!({ "schema_id": anoncreds::gvt_schema_id() })
+                           "restrictions": [json!({ "cred_def_id": anoncreds::issuer_1_gvt_cred_def_id() })]
                        })
                    }),
                    "requested_predicates": json!({}),
@@ -1184,16 +1538,16 @@ mod high_cases {
                 let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
 
                 let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
-                assert_eq!(credentials.attrs.len(), 1);
-
                 let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
-                assert_eq!(credentials_for_attr_1.len(), 2);
 
                 wallet::close_wallet(wallet_handle).unwrap();
+
+                assert_eq!(credentials.attrs.len(), 1);
+                assert_eq!(credentials_for_attr_1.len(), 1);
             }
 
             #[test]
-            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_schema_name() {
+            fn prover_get_credentials_for_proof_rea_works_for_revealed_attr_for_multiple_cred_def_ids() {
                 anoncreds::init_common_wallet();
 
                 let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
@@ -1205,7 +1559,7 @@ mod high_cases {
                    "requested_attributes": json!({
                        "attr1_referent": json!({
                            "name":"name",
-                           "restrictions": json!({ "schema_name": GVT_SCHEMA_NAME })
+                           "restrictions": [json!({ "cred_def_id": anoncreds::issuer_1_gvt_cred_def_id() }), json!({ "cred_def_id": anoncreds::issuer_2_gvt_cred_def_id() })]
                        })
                    }),
                    "requested_predicates": json!({}),
@@ -1223,7 +1577,7 @@ mod high_cases {
             }
 
             #[test]
-            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_schema_version() {
+            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_cred_def_id_or_issuer_did() {
                 anoncreds::init_common_wallet();
 
                 let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
@@ -1235,7 +1589,7 @@ mod high_cases {
                    "requested_attributes": json!({
                        "attr1_referent": json!({
                            "name":"name",
-                           "restrictions": json!({ "schema_version": SCHEMA_VERSION })
+                           "restrictions": [json!({ "cred_def_id": anoncreds::issuer_1_gvt_cred_def_id() }), json!({ "issuer_did": ISSUER_DID_2 })]
                        })
                    }),
                    "requested_predicates": json!({}),
@@ -1253,7 +1607,7 @@ mod high_cases {
             }
 
             #[test]
-            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_schema_issuer_did() {
+            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_other_issuer() {
                 anoncreds::init_common_wallet();
 
                 let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
@@ -1265,10 +1619,11 @@ mod high_cases {
                    "requested_attributes": json!({
                        "attr1_referent": json!({
                            "name":"name",
-                           "restrictions": json!({ "schema_issuer_did": ISSUER_DID })
+                           "restrictions": [json!({ "issuer_did": DID_TRUSTEE })]
                        })
                    }),
-                   "requested_predicates": json!({}),
+                   "requested_predicates": json!({
+                   }),
                 }).to_string();
 
                 let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
@@ -1277,13 +1632,13 @@ mod high_cases {
                 assert_eq!(credentials.attrs.len(), 1);
 
                 let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
-                assert_eq!(credentials_for_attr_1.len(), 2);
+                assert_eq!(credentials_for_attr_1.len(), 0);
 
                 wallet::close_wallet(wallet_handle).unwrap();
             }
 
             #[test]
-            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_specific_schema_id_or_specific_cred_def_id() {
+            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_other_schema_id() {
                 anoncreds::init_common_wallet();
 
                 let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
@@ -1295,15 +1650,11 @@ mod high_cases {
                    "requested_attributes": json!({
                        "attr1_referent": json!({
                            "name":"name",
-                           "restrictions": json!({
-                                "$or": vec![
-                                    json!({ "schema_id": anoncreds::gvt_schema_id() }),
-                                    json!({ "cred_def_id": anoncreds::issuer_1_gvt_cred_def_id() })
-                                ]
-                           })
+                           "restrictions": [json!({ "schema_id": SchemaId::new(&DidValue(DID_TRUSTEE.to_string()), GVT_SCHEMA_NAME, SCHEMA_VERSION) })]
                        })
                    }),
-                   "requested_predicates": json!({}),
+                   "requested_predicates": json!({
+                   }),
                 }).to_string();
 
                 let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
@@ -1312,13 +1663,13 @@ mod high_cases {
                 assert_eq!(credentials.attrs.len(), 1);
 
                 let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
-                assert_eq!(credentials_for_attr_1.len(), 2);
+                assert_eq!(credentials_for_attr_1.len(), 0);
 
                 wallet::close_wallet(wallet_handle).unwrap();
             }
 
             #[test]
-            fn prover_get_credentials_for_proof_rea_works_for_revealed_attr_for_multiple_schemas() {
+            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_other_cred_def_id() {
                 anoncreds::init_common_wallet();
 
                 let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
@@ -1330,14 +1681,11 @@ mod high_cases {
                    "requested_attributes": json!({
                        "attr1_referent": json!({
                            "name":"name",
-                           "restrictions": json!({
-                               "schema_id": json!({
-                                    "$in": vec![anoncreds::gvt_schema_id(), anoncreds::xyz_schema_id()]
-                               })
-                           })
+                           "restrictions": [json!({ "cred_def_id": CredentialDefinitionId::new(&DidValue(DID_TRUSTEE.to_string()), &SchemaId(anoncreds::gvt_schema_id()), "CL", TAG_1) })]
                        })
                    }),
-                   "requested_predicates": json!({}),
+                   "requested_predicates": json!({
+                   }),
                 }).to_string();
 
                 let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
@@ -1346,13 +1694,57 @@ mod high_cases {
                 assert_eq!(credentials.attrs.len(), 1);
 
                 let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
-                assert_eq!(credentials_for_attr_1.len(), 2);
+                assert_eq!(credentials_for_attr_1.len(), 0);
 
                 wallet::close_wallet(wallet_handle).unwrap();
             }
 
             #[test]
-            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_specific_cred_def_id() {
+            fn prover_get_credentials_for_proof_req_works_for_null_restrictions() {
+                anoncreds::init_common_wallet();
+
+                let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+                let proof_req = r#"{
+                   "nonce":"123432421212",
+                   "name":"proof_req_1",
+                   "version":"0.1",
+                   "requested_attributes":{
+                      "attr1_referent":{
+                         "name":"name",
+                         "restrictions":[
+                            {
+                               "schema_id":null,
+                               "schema_issuer_did":null,
+                               "schema_name":null,
+                               "schema_version":null,
+                               "issuer_did":"NcYxiDXkpYi6ov5FcYDi1e",
+                               "cred_def_id":null
+                            }
+                         ]
+                      }
+                   },
+                   "requested_predicates":{
+                   }
+                }"#;
+
+                let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
+
+                let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
+                assert_eq!(credentials.attrs.len(), 1);
+
+                let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
+                assert_eq!(credentials_for_attr_1.len(), 1);
+
+                wallet::close_wallet(wallet_handle).unwrap();
+            }
+        }
+
+        mod attribute_restrictions_wql_format {
+            use super::*;
+
+            #[test]
+            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_specific_issuer() {
                 anoncreds::init_common_wallet();
 
                 let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
@@ -1364,10 +1756,11 @@ mod high_cases {
                    "requested_attributes": json!({
                        "attr1_referent": json!({
                            "name":"name",
-                           "restrictions": json!({ "cred_def_id": anoncreds::issuer_1_gvt_cred_def_id() })
+                           "restrictions": json!({ "issuer_did": ISSUER_DID })
                        })
                    }),
-                   "requested_predicates": json!({}),
+                   "requested_predicates": json!({
+                   }),
                 }).to_string();
 
                 let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
@@ -1382,7 +1775,7 @@ mod high_cases {
             }
 
             #[test]
-            fn prover_get_credentials_for_proof_rea_works_for_revealed_attr_for_multiple_cred_def_ids() {
+            fn prover_get_credentials_for_proof_rea_works_for_revealed_attr_for_multiple_issuers() {
                 anoncreds::init_common_wallet();
 
                 let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
@@ -1395,8 +1788,8 @@ mod high_cases {
                        "attr1_referent": json!({
                            "name":"name",
                            "restrictions": json!({
-                               "cred_def_id": json!({
-                                    "$in": vec![anoncreds::issuer_1_gvt_cred_def_id(), anoncreds::issuer_2_gvt_cred_def_id()]
+                               "issuer_did": json!({
+                                    "$in": vec![ISSUER_DID, ISSUER_DID_2]
                                })
                            })
                        })
@@ -1416,7 +1809,7 @@ mod high_cases {
             }
 
             #[test]
-            fn prover_get_credentials_for_proof_rea_works_for_revealed_attr_for_specific_schema_id_and_cred_def_id() {
+            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_specific_schema() {
                 anoncreds::init_common_wallet();
 
                 let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
@@ -1428,10 +1821,7 @@ mod high_cases {
                    "requested_attributes": json!({
                        "attr1_referent": json!({
                            "name":"name",
-                           "restrictions": json!({
-                                "schema_id":  anoncreds::gvt_schema_id(),
-                                "cred_def_id": anoncreds::issuer_1_gvt_cred_def_id()
-                           })
+                           "restrictions": json!({ "schema_id": anoncreds::gvt_schema_id() })
                        })
                    }),
                    "requested_predicates": json!({}),
@@ -1443,13 +1833,13 @@ mod high_cases {
                 assert_eq!(credentials.attrs.len(), 1);
 
                 let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
-                assert_eq!(credentials_for_attr_1.len(), 1);
+                assert_eq!(credentials_for_attr_1.len(), 2);
 
                 wallet::close_wallet(wallet_handle).unwrap();
             }
 
             #[test]
-            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_cred_def_id_or_issuer_did() {
+            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_schema_name() {
                 anoncreds::init_common_wallet();
 
                 let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
@@ -1461,12 +1851,7 @@ mod high_cases {
                    "requested_attributes": json!({
                        "attr1_referent": json!({
                            "name":"name",
-                           "restrictions": json!({
-                                "$or": vec![
-                                    json!({ "cred_def_id": anoncreds::issuer_1_gvt_cred_def_id() }),
-                                    json!({ "issuer_did": ISSUER_DID_2 })
-                                ]
-                           })
+                           "restrictions": json!({ "schema_name": GVT_SCHEMA_NAME })
                        })
                    }),
                    "requested_predicates": json!({}),
@@ -1484,7 +1869,7 @@ mod high_cases {
             }
 
             #[test]
-            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_other_issuer() {
+            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_schema_version() {
                 anoncreds::init_common_wallet();
 
                 let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
@@ -1496,11 +1881,10 @@ mod high_cases {
                    "requested_attributes": json!({
                        "attr1_referent": json!({
                            "name":"name",
-                           "restrictions": json!({ "issuer_did": DID_TRUSTEE })
+                           "restrictions": json!({ "schema_version": SCHEMA_VERSION })
                        })
                    }),
-                   "requested_predicates": json!({
-                   }),
+                   "requested_predicates": json!({}),
                 }).to_string();
 
                 let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
@@ -1509,13 +1893,13 @@ mod high_cases {
                 assert_eq!(credentials.attrs.len(), 1);
 
                 let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
-                assert_eq!(credentials_for_attr_1.len(), 0);
+                assert_eq!(credentials_for_attr_1.len(), 2);
 
                 wallet::close_wallet(wallet_handle).unwrap();
             }
 
             #[test]
-            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_other_schema_id() {
+            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_schema_issuer_did() {
                 anoncreds::init_common_wallet();
 
                 let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
@@ -1527,11 +1911,10 @@ mod high_cases {
                    "requested_attributes": json!({
                        "attr1_referent": json!({
                            "name":"name",
-                           "restrictions": json!({ "schema_id": SchemaId::new(&DidValue(DID_TRUSTEE.to_string()), GVT_SCHEMA_NAME, SCHEMA_VERSION) })
+                           "restrictions": json!({ "schema_issuer_did": ISSUER_DID })
                        })
                    }),
-                   "requested_predicates": json!({
-                   }),
+                   "requested_predicates": json!({}),
                 }).to_string();
 
                 let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
@@ -1540,13 +1923,13 @@ mod high_cases {
                 assert_eq!(credentials.attrs.len(), 1);
 
                 let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
-                assert_eq!(credentials_for_attr_1.len(), 0);
+                assert_eq!(credentials_for_attr_1.len(), 2);
 
                 wallet::close_wallet(wallet_handle).unwrap();
             }
 
             #[test]
-            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_other_cred_def_id() {
+            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_specific_schema_id_or_specific_cred_def_id() {
                 anoncreds::init_common_wallet();
 
                 let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
@@ -1558,11 +1941,15 @@ mod high_cases {
                    "requested_attributes": json!({
                        "attr1_referent": json!({
                            "name":"name",
-                           "restrictions": json!({ "cred_def_id": CredentialDefinitionId::new(&DidValue(DID_TRUSTEE.to_string()), &SchemaId(anoncreds::gvt_schema_id()), "CL", TAG_1) })
+                           "restrictions": json!({
+                                "$or": vec![
+                                    json!({ "schema_id": anoncreds::gvt_schema_id() }),
+                                    json!({ "cred_def_id": anoncreds::issuer_1_gvt_cred_def_id() })
+                                ]
+                           })
                        })
                    }),
-                   "requested_predicates": json!({
-                   }),
+                   "requested_predicates": json!({}),
                 }).to_string();
 
                 let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
@@ -1571,63 +1958,322 @@ mod high_cases {
                 assert_eq!(credentials.attrs.len(), 1);
 
                 let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
-                assert_eq!(credentials_for_attr_1.len(), 0);
+                assert_eq!(credentials_for_attr_1.len(), 2);
 
                 wallet::close_wallet(wallet_handle).unwrap();
             }
-        }
-
-        #[test]
-        fn prover_get_credentials_for_proof_req_works_for_predicate() {
-            anoncreds::init_common_wallet();
 
-            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+            #[test]
+            fn prover_get_credentials_for_proof_rea_works_for_revealed_attr_for_multiple_schemas() {
+                anoncreds::init_common_wallet();
 
-            let proof_req = json!({
-               "nonce":"123432421212",
-               "name":"proof_req_1",
-               "version":"0.1",
-               "requested_attributes": json!({}),
-               "requested_predicates": json!({
-                   "predicate1_referent": json!({ "name":"age", "p_type":">=", "p_value":18 })
-               }),
-            }).to_string();
+                let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
 
-            let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
-            println!("!!!\n{}", credentials_json);
+                let proof_req = json!({
+                   "nonce":"123432421212",
+                   "name":"proof_req_1",
+                   "version":"0.1",
+                   "requested_attributes": json!({
+                       "attr1_referent": json!({
+                           "name":"name",
+                           "restrictions": json!({
+                               "schema_id": json!({
+                                    "$in": vec![anoncreds::gvt_schema_id(), anoncreds::xyz_schema_id()]
+                               })
+                           })
+                       })
+                   }),
+                   "requested_predicates": json!({}),
+                }).to_string();
 
-            let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
-            assert_eq!(credentials.predicates.len(), 1);
+                let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
 
-            let credentials_for_predicate_1 = credentials.predicates.get("predicate1_referent").unwrap();
-            assert_eq!(credentials_for_predicate_1.len(), 2);
+                let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
+                assert_eq!(credentials.attrs.len(), 1);
 
-            wallet::close_wallet(wallet_handle).unwrap();
-        }
+                let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
+                assert_eq!(credentials_for_attr_1.len(), 2);
 
-        #[test]
-        fn prover_get_credentials_for_proof_req_works_for_predicate_attribute_in_upper_case() {
-            anoncreds::init_common_wallet();
+                wallet::close_wallet(wallet_handle).unwrap();
+            }
 
-            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+            #[test]
+            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_specific_cred_def_id() {
+                anoncreds::init_common_wallet();
 
-            let proof_req = json!({
-               "nonce":"123432421212",
-               "name":"proof_req_1",
-               "version":"0.1",
-               "requested_attributes": json!({}),
-               "requested_predicates": json!({
-                   "predicate1_referent": json!({ "name":"AGE", "p_type":">=", "p_value":18 })
-               }),
-            }).to_string();
+                let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
 
-            let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
+                let proof_req = json!({
+                   "nonce":"123432421212",
+                   "name":"proof_req_1",
+                   "version":"0.1",
+                   "requested_attributes": json!({
+                       "attr1_referent": json!({
+                           "name":"name",
+                           "restrictions": json!({ "cred_def_id": anoncreds::issuer_1_gvt_cred_def_id() })
+                       })
+                   }),
+                   "requested_predicates": json!({}),
+                }).to_string();
 
-            let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
-            assert_eq!(credentials.predicates.len(), 1);
+                let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
 
-            let credentials_for_predicate_1 = credentials.predicates.get("predicate1_referent").unwrap();
-            assert_eq!(credentials_for_predicate_1.len(), 2);
+                let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
+                assert_eq!(credentials.attrs.len(), 1);
+
+                let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
+                assert_eq!(credentials_for_attr_1.len(), 1);
+
+                wallet::close_wallet(wallet_handle).unwrap();
+            }
+
+            #[test]
+            fn prover_get_credentials_for_proof_rea_works_for_revealed_attr_for_multiple_cred_def_ids() {
+                anoncreds::init_common_wallet();
+
+                let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+                let proof_req = json!({
+                   "nonce":"123432421212",
+                   "name":"proof_req_1",
+                   "version":"0.1",
+                   "requested_attributes": json!({
+                       "attr1_referent": json!({
+                           "name":"name",
+                           "restrictions": json!({
+                               "cred_def_id": json!({
+                                    "$in": vec![anoncreds::issuer_1_gvt_cred_def_id(), anoncreds::issuer_2_gvt_cred_def_id()]
+                               })
+                           })
+                       })
+                   }),
+                   "requested_predicates": json!({}),
+                }).to_string();
+
+                let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
+
+                let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
+                assert_eq!(credentials.attrs.len(), 1);
+
+                let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
+                assert_eq!(credentials_for_attr_1.len(), 2);
+
+                wallet::close_wallet(wallet_handle).unwrap();
+            }
+
+            #[test]
+            fn prover_get_credentials_for_proof_rea_works_for_revealed_attr_for_specific_schema_id_and_cred_def_id() {
+                anoncreds::init_common_wallet();
+
+                let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+                let proof_req = json!({
+                   "nonce":"123432421212",
+                   "name":"proof_req_1",
+                   "version":"0.1",
+                   "requested_attributes": json!({
+                       "attr1_referent": json!({
+                           "name":"name",
+                           "restrictions": json!({
+                                "schema_id":  anoncreds::gvt_schema_id(),
+                                "cred_def_id": anoncreds::issuer_1_gvt_cred_def_id()
+                           })
+                       })
+                   }),
+                   "requested_predicates": json!({}),
+                }).to_string();
+
+                let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
+
+                let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
+                assert_eq!(credentials.attrs.len(), 1);
+
+                let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
+                assert_eq!(credentials_for_attr_1.len(), 1);
+
+                wallet::close_wallet(wallet_handle).unwrap();
+            }
+
+            #[test]
+            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_cred_def_id_or_issuer_did() {
+                anoncreds::init_common_wallet();
+
+                let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+                let proof_req = json!({
+                   "nonce":"123432421212",
+                   "name":"proof_req_1",
+                   "version":"0.1",
+                   "requested_attributes": json!({
+                       "attr1_referent": json!({
+                           "name":"name",
+                           "restrictions": json!({
+                                "$or": vec![
+                                    json!({ "cred_def_id": anoncreds::issuer_1_gvt_cred_def_id() }),
+                                    json!({ "issuer_did": ISSUER_DID_2 })
+                                ]
+                           })
+                       })
+                   }),
+                   "requested_predicates": json!({}),
+                }).to_string();
+
+                let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
+
+                let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
+                assert_eq!(credentials.attrs.len(), 1);
+
+                let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
+                assert_eq!(credentials_for_attr_1.len(), 2);
+
+                wallet::close_wallet(wallet_handle).unwrap();
+            }
+
+            #[test]
+            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_other_issuer() {
+                anoncreds::init_common_wallet();
+
+                let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+                let proof_req = json!({
+                   "nonce":"123432421212",
+                   "name":"proof_req_1",
+                   "version":"0.1",
+                   "requested_attributes": json!({
+                       "attr1_referent": json!({
+                           "name":"name",
+                           "restrictions": json!({ "issuer_did": DID_TRUSTEE })
+                       })
+                   }),
+                   "requested_predicates": json!({
+                   }),
+                }).to_string();
+
+                let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
+
+                let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
+                assert_eq!(credentials.attrs.len(), 1);
+
+                let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
+                assert_eq!(credentials_for_attr_1.len(), 0);
+
+                wallet::close_wallet(wallet_handle).unwrap();
+            }
+
+            #[test]
+            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_other_schema_id() {
+                anoncreds::init_common_wallet();
+
+                let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+                let proof_req = json!({
+                   "nonce":"123432421212",
+                   "name":"proof_req_1",
+                   "version":"0.1",
+                   "requested_attributes": json!({
+                       "attr1_referent": json!({
+                           "name":"name",
+                           "restrictions": json!({ "schema_id": SchemaId::new(&DidValue(DID_TRUSTEE.to_string()), GVT_SCHEMA_NAME, SCHEMA_VERSION) })
+                       })
+                   }),
+                   "requested_predicates": json!({
+                   }),
+                }).to_string();
+
+                let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
+
+                let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
+                assert_eq!(credentials.attrs.len(), 1);
+
+                let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
+                assert_eq!(credentials_for_attr_1.len(), 0);
+
+                wallet::close_wallet(wallet_handle).unwrap();
+            }
+
+            #[test]
+            fn prover_get_credentials_for_proof_req_works_for_revealed_attr_for_other_cred_def_id() {
+                anoncreds::init_common_wallet();
+
+                let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+                let proof_req = json!({
+                   "nonce":"123432421212",
+                   "name":"proof_req_1",
+                   "version":"0.1",
+                   "requested_attributes": json!({
+                       "attr1_referent": json!({
+                           "name":"name",
+                           "restrictions": json!({ "cred_def_id": CredentialDefinitionId::new(&DidValue(DID_TRUSTEE.to_string()), &SchemaId(anoncreds::gvt_schema_id()), "CL", TAG_1) })
+                       })
+                   }),
+                   "requested_predicates": json!({
+                   }),
+                }).to_string();
+
+                let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
+
+                let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
+                assert_eq!(credentials.attrs.len(), 1);
+
+                let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
+                assert_eq!(credentials_for_attr_1.len(), 0);
+
+                wallet::close_wallet(wallet_handle).unwrap();
+            }
+        }
+
+        #[test]
+        fn prover_get_credentials_for_proof_req_works_for_predicate() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let proof_req = json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({}),
+               "requested_predicates": json!({
+                   "predicate1_referent": json!({ "name":"age", "p_type":">=", "p_value":18 })
+               }),
+            }).to_string();
+
+            let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
+            println!("!!!\n{}", credentials_json);
+
+            let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
+            assert_eq!(credentials.predicates.len(), 1);
+
+            let credentials_for_predicate_1 = credentials.predicates.get("predicate1_referent").unwrap();
+            assert_eq!(credentials_for_predicate_1.len(), 2);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn prover_get_credentials_for_proof_req_works_for_predicate_attribute_in_upper_case() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let proof_req = json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({}),
+               "requested_predicates": json!({
+                   "predicate1_referent": json!({ "name":"AGE", "p_type":">=", "p_value":18 })
+               }),
+            }).to_string();
+
+            let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req).unwrap();
+
+            let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
+            assert_eq!(credentials.predicates.len(), 1);
+
+            let credentials_for_predicate_1 = credentials.predicates.get("predicate1_referent").unwrap();
+            assert_eq!(credentials_for_predicate_1.len(), 2);
 
             wallet::close_wallet(wallet_handle).unwrap();
         }
@@ -2973,544 +3619,1489 @@ mod high_cases {
 
                 anoncreds::prover_close_credentials_search_for_proof_req(search_handle).unwrap();
 
-                wallet::close_wallet(wallet_handle).unwrap();
-            }
+                wallet::close_wallet(wallet_handle).unwrap();
+            }
+
+            #[test]
+            fn prover_search_credentials_for_proof_req_works_for_empty_restrictions() {
+                anoncreds::init_common_wallet();
+
+                let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+                let proof_req = json!({
+                   "nonce":"123432421212",
+                   "name":"proof_req_1",
+                   "version":"0.1",
+                   "requested_attributes": json!({
+                       "attr1_referent": json!({
+                           "name":"name",
+                           "restrictions": {}
+                       }),
+                       "attr2_referent": json!({
+                           "name":"age",
+                           "restrictions": []
+                       })
+                   }),
+                   "requested_predicates": json!({ }),
+                }).to_string();
+
+                let search_handle = anoncreds::prover_search_credentials_for_proof_req(wallet_handle, &proof_req, None).unwrap();
+
+                let credentials_json = anoncreds::prover_fetch_next_credentials_for_proof_req(
+                    search_handle, "attr1_referent", 100).unwrap();
+
+                let credentials: Vec<RequestedCredential> = serde_json::from_str(&credentials_json).unwrap();
+                assert_eq!(credentials.len(), 2);
+
+                let credentials_json = anoncreds::prover_fetch_next_credentials_for_proof_req(
+                    search_handle, "attr2_referent", 100).unwrap();
+
+                let credentials: Vec<RequestedCredential> = serde_json::from_str(&credentials_json).unwrap();
+                assert_eq!(credentials.len(), 2);
+
+                anoncreds::prover_close_credentials_search_for_proof_req(search_handle).unwrap();
+
+                wallet::close_wallet(wallet_handle).unwrap();
+            }
+        }
+
+        mod validation {
+            use super::*;
+
+            #[test]
+            fn prover_search_credentials_for_proof_req_works_for_proof_req_v1_contains_fully_qualified_restrictions() {
+                anoncreds::init_common_wallet();
+
+                let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+                let proof_req = json!({
+                   "nonce":"123432421212",
+                   "name":"proof_req_1",
+                   "version":"0.1",
+                   "requested_attributes": json!({
+                       "attr1_referent": json!({
+                           "name":"name",
+                           "restrictions": {
+                                "issuer_did": ISSUER_DID_V1
+                           }
+                       })
+                   }),
+                   "requested_predicates": json!({ }),
+                }).to_string();
+
+                let res = anoncreds::prover_search_credentials_for_proof_req(wallet_handle, &proof_req, None);
+                assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+                wallet::close_wallet(wallet_handle).unwrap();
+            }
+
+            #[test]
+            fn prover_search_credentials_for_proof_req_works_for_fully_qualified_restrictions() {
+                anoncreds::init_common_wallet();
+
+                let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+                let proof_req = json!({
+                   "nonce":"123432421212",
+                   "name":"proof_req_1",
+                   "version":"0.1",
+                   "requested_attributes": json!({
+                       "attr1_referent": json!({
+                           "name":"name",
+                           "restrictions": {
+                                "issuer_did": ISSUER_DID_V1
+                           }
+                       })
+                   }),
+                   "requested_predicates": json!({ }),
+                   "ver": "2.0"
+                }).to_string();
+
+                let search_handle = anoncreds::prover_search_credentials_for_proof_req(wallet_handle, &proof_req, None).unwrap();
+
+                let credentials_json = anoncreds::prover_fetch_next_credentials_for_proof_req(
+                    search_handle, "attr1_referent", 100).unwrap();
+
+                let credentials: Vec<RequestedCredential> = serde_json::from_str(&credentials_json).unwrap();
+                assert_eq!(credentials.len(), 0);
+
+                anoncreds::prover_close_credentials_search_for_proof_req(search_handle).unwrap();
+
+                wallet::close_wallet(wallet_handle).unwrap();
+            }
+        }
+    }
+
+    mod prover_explain_proof_req {
+        use super::*;
+        use crate::utils::domain::anoncreds::proof_request_diagnostics::{DiagnosticsIssueReason, ProofRequestDiagnostics};
+
+        fn _explain(proof_req: &str) -> ProofRequestDiagnostics {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let diagnostics_json = anoncreds::prover_explain_proof_req(wallet_handle, proof_req, None).unwrap();
+
+            wallet::close_wallet(wallet_handle).unwrap();
+
+            serde_json::from_str(&diagnostics_json).unwrap()
+        }
+
+        #[test]
+        fn prover_explain_proof_req_works_for_satisfiable_request() {
+            let proof_req = json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({
+                   "attr1_referent": json!({
+                       "name":"name"
+                   })
+               }),
+               "requested_predicates": json!({
+                   "predicate1_referent": json!({ "name":"age", "p_type":">=", "p_value":18 })
+               }),
+            }).to_string();
+
+            let diagnostics = _explain(&proof_req);
+            assert!(diagnostics.satisfiable);
+
+            let attr_diagnostics = &diagnostics.requested_attributes["attr1_referent"];
+            assert!(attr_diagnostics.satisfiable);
+            assert_eq!(2, attr_diagnostics.credentials.len());
+            assert!(attr_diagnostics.credentials.iter().all(|credential| credential.satisfies && credential.issues.is_empty()));
+            assert!(!attr_diagnostics.truncated);
+
+            assert!(diagnostics.requested_predicates["predicate1_referent"].satisfiable);
+        }
+
+        #[test]
+        fn prover_explain_proof_req_works_for_restriction_mismatch() {
+            let proof_req = json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({
+                   "attr1_referent": json!({
+                       "name":"name",
+                       "restrictions": json!({ "issuer_did": DID_TRUSTEE })
+                   })
+               }),
+               "requested_predicates": json!({ }),
+            }).to_string();
+
+            let diagnostics = _explain(&proof_req);
+            assert!(!diagnostics.satisfiable);
+
+            let attr_diagnostics = &diagnostics.requested_attributes["attr1_referent"];
+            assert!(!attr_diagnostics.satisfiable);
+            assert_eq!(2, attr_diagnostics.credentials.len());
+
+            for credential in attr_diagnostics.credentials.iter() {
+                assert!(!credential.satisfies);
+                assert_eq!(1, credential.issues.len());
+                assert_eq!(DiagnosticsIssueReason::RestrictionMismatch, credential.issues[0].reason);
+                assert_eq!(Some("issuer_did".to_string()), credential.issues[0].tag);
+            }
+        }
+
+        #[test]
+        fn prover_explain_proof_req_works_for_attribute_value_restriction() {
+            let proof_req = json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({
+                   "attr1_referent": json!({
+                       "name":"name",
+                       "restrictions": json!({ "attr::name::value": "Alex" })
+                   })
+               }),
+               "requested_predicates": json!({ }),
+            }).to_string();
+
+            let diagnostics = _explain(&proof_req);
+            assert!(diagnostics.satisfiable);
+
+            let attr_diagnostics = &diagnostics.requested_attributes["attr1_referent"];
+            let near_miss = attr_diagnostics.credentials.iter().find(|credential| !credential.satisfies).unwrap();
+            assert_eq!(anoncreds::CREDENTIAL3_ID, near_miss.cred_info.referent);
+            assert_eq!(Some("attr::name::value".to_string()), near_miss.issues[0].tag);
+            assert_eq!(Some("Alexander".to_string()), near_miss.issues[0].actual);
+        }
+
+        #[test]
+        fn prover_explain_proof_req_works_for_unsatisfied_predicate() {
+            let proof_req = json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({ }),
+               "requested_predicates": json!({
+                   "predicate1_referent": json!({ "name":"age", "p_type":">=", "p_value":30 })
+               }),
+            }).to_string();
+
+            let diagnostics = _explain(&proof_req);
+            assert!(!diagnostics.satisfiable);
+
+            let predicate_diagnostics = &diagnostics.requested_predicates["predicate1_referent"];
+            assert_eq!(2, predicate_diagnostics.credentials.len());
+            assert!(predicate_diagnostics.credentials.iter()
+                .all(|credential| credential.issues.iter().any(|issue| issue.reason == DiagnosticsIssueReason::PredicateNotSatisfied)));
+        }
+
+        #[test]
+        fn prover_explain_proof_req_works_for_missed_attribute() {
+            let proof_req = json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({
+                   "attr1_referent": json!({ "name":"unknown_attribute" })
+               }),
+               "requested_predicates": json!({ }),
+            }).to_string();
+
+            let diagnostics = _explain(&proof_req);
+            assert!(!diagnostics.satisfiable);
+
+            let attr_diagnostics = &diagnostics.requested_attributes["attr1_referent"];
+            assert!(attr_diagnostics.credentials.is_empty());
+            assert_eq!(DiagnosticsIssueReason::NoCredentialWithAttribute, attr_diagnostics.issues[0].reason);
+        }
+
+        #[test]
+        fn prover_explain_proof_req_works_for_non_revocable_credentials() {
+            let proof_req = json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({
+                   "attr1_referent": json!({ "name":"name" })
+               }),
+               "requested_predicates": json!({ }),
+               "non_revoked": json!({ "from": 100, "to": 200 })
+            }).to_string();
+
+            let diagnostics = _explain(&proof_req);
+            assert!(!diagnostics.satisfiable);
+
+            let attr_diagnostics = &diagnostics.requested_attributes["attr1_referent"];
+            assert!(attr_diagnostics.credentials.iter()
+                .all(|credential| credential.issues.iter().any(|issue| issue.reason == DiagnosticsIssueReason::CredentialNotRevocable)));
+        }
+
+        #[test]
+        fn prover_explain_proof_req_works_for_invalid_wallet_handle() {
+            anoncreds::init_common_wallet();
+
+            let proof_req = json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({
+                   "attr1_referent": json!({ "name":"name" })
+               }),
+               "requested_predicates": json!({ }),
+            }).to_string();
+
+            let res = anoncreds::prover_explain_proof_req(INVALID_WALLET_HANDLE, &proof_req, None);
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+        }
+    }
+
+    mod prover_create_proof_works {
+        use super::*;
+
+        #[test]
+        fn prover_create_proof_works() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let requested_credentials_json = json!({
+                 "self_attested_attributes": json!({}),
+                 "requested_attributes": json!({
+                    "attr1_referent": json!({ "cred_id": CREDENTIAL1_ID, "revealed":true })
+                 }),
+                 "requested_predicates": json!({
+                    "predicate1_referent": json!({ "cred_id": CREDENTIAL1_ID })
+                 })
+            }).to_string();
+
+            anoncreds::prover_create_proof(wallet_handle,
+                                           &anoncreds::proof_request_attr_and_predicate(),
+                                           &requested_credentials_json,
+                                           COMMON_MASTER_SECRET,
+                                           &anoncreds::schemas_for_proof(),
+                                           &anoncreds::cred_defs_for_proof(),
+                                           "{}").unwrap();
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn prover_create_proof_works_for_names() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let requested_credentials_json = json!({
+                 "self_attested_attributes": {},
+                 "requested_attributes": {
+                    "attr1_referent": { "cred_id": CREDENTIAL1_ID, "revealed":true }
+                 },
+                 "requested_predicates": {}
+            }).to_string();
+
+            anoncreds::prover_create_proof(wallet_handle,
+                                           &anoncreds::proof_request_attr_names(),
+                                           &requested_credentials_json,
+                                           COMMON_MASTER_SECRET,
+                                           &anoncreds::schemas_for_proof(),
+                                           &anoncreds::cred_defs_for_proof(),
+                                           "{}").unwrap();
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn prover_create_proof_works_for_using_not_satisfy_credential() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let proof_req = json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({
+                   "attr1_referent": json!({
+                       "name":"some_attr"
+                   })
+               }),
+               "requested_predicates": json!({}),
+            }).to_string();
+
+            let requested_credentials_json = json!({
+                 "self_attested_attributes": json!({}),
+                 "requested_attributes": json!({
+                    "attr1_referent": json!({ "cred_id": CREDENTIAL1_ID, "revealed":true })
+                 }),
+                 "requested_predicates": json!({})
+            }).to_string();
+
+            let res = anoncreds::prover_create_proof(wallet_handle,
+                                                     &proof_req,
+                                                     &requested_credentials_json,
+                                                     COMMON_MASTER_SECRET,
+                                                     &anoncreds::schemas_for_proof(),
+                                                     &anoncreds::cred_defs_for_proof(),
+                                                     "{}");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+    }
+
+    mod verifier_verify_proof {
+        use super::*;
+
+        #[test]
+        fn verifier_verify_proof_works_for_correct_proof() {
+            let valid = anoncreds::verifier_verify_proof(&anoncreds::proof_request_attr(),
+                                                         &anoncreds::proof_json(),
+                                                         &anoncreds::schemas_for_proof(),
+                                                         &anoncreds::cred_defs_for_proof(),
+                                                         "{}",
+                                                         "{}").unwrap();
+            assert!(valid);
+        }
+
+        #[test]
+        fn verifier_verify_proof_works_for_proof_does_not_correspond_to_request() {
+            let other_proof_req_json = json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({
+                   "attr1_referent": json!({
+                       "name":"sex"
+                   })
+               }),
+               "requested_predicates": json!({
+                   "predicate1_referent": json!({ "name":"age", "p_type":">=", "p_value":18 })
+               }),
+            }).to_string();
+
+            let res = anoncreds::verifier_verify_proof(&other_proof_req_json,
+                                                       &anoncreds::proof_json(),
+                                                       &anoncreds::schemas_for_proof(),
+                                                       &anoncreds::cred_defs_for_proof(),
+                                                       "{}",
+                                                       "{}");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn verifier_verify_proof_works_for_proof_does_not_correspond_to_request_attribute() {
+            let other_proof_req_json = json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({
+                   "attr1_referent": json!({
+                       "name":"sex"
+                   })
+               }),
+               "requested_predicates": json!({}),
+            }).to_string();
+            let res = anoncreds::verifier_verify_proof(&other_proof_req_json,
+                                                       &anoncreds::proof_json(),
+                                                       &anoncreds::schemas_for_proof(),
+                                                       &anoncreds::cred_defs_for_proof(),
+                                                       "{}",
+                                                       "{}");
+            assert_code!(ErrorCode::AnoncredsProofRejected, res);
+        }
+
+        #[test]
+        fn verifier_verify_proof_works_for_wrong_revealed_attr_value() {
+            let proof_json = anoncreds::proof_json().replace(r#"name":"1139481716457488690172217916278103335"#, r#"name":"1111111111111111111111111111111111111"#);
+
+            let res = anoncreds::verifier_verify_proof(&anoncreds::proof_request_attr(),
+                                                       &proof_json,
+                                                       &anoncreds::schemas_for_proof(),
+                                                       &anoncreds::cred_defs_for_proof(),
+                                                       "{}",
+                                                       "{}");
+            assert_code!(ErrorCode::AnoncredsProofRejected, res);
+        }
+
+        #[test]
+        fn verifier_verify_proof_works_for_wrong_encoded() {
+            let proof_json = anoncreds::proof_json().replace(r#"encoded":"1139481716457488690172217916278103335"#, r#"encoded":"1111111111111111111111111111111111111"#);
+
+            let res = anoncreds::verifier_verify_proof(&anoncreds::proof_request_attr(),
+                                                       &proof_json,
+                                                       &anoncreds::schemas_for_proof(),
+                                                       &anoncreds::cred_defs_for_proof(),
+                                                       "{}",
+                                                       "{}");
+            assert_code!(ErrorCode::AnoncredsProofRejected, res);
+        }
+
+        #[test]
+        #[ignore] // TODO: Libindy doesn't aware about algorithm used for encoding of attribute values. We can do this check only on application level.
+        fn verifier_verify_proof_works_for_wrong_raw() {
+            let proof_json = anoncreds::proof_json().replace(r#"raw":"Alex"#, r#"raw":"Bob"#);
+
+            let res = anoncreds::verifier_verify_proof(&anoncreds::proof_request_attr(),
+                                                       &proof_json,
+                                                       &anoncreds::schemas_for_proof(),
+                                                       &anoncreds::cred_defs_for_proof(),
+                                                       "{}",
+                                                       "{}");
+            assert_code!(ErrorCode::AnoncredsProofRejected, res);
+        }
+
+        #[test]
+        fn verifier_verify_proof_works_for_revealed_attr_case_insensitive() {
+            let proof_req_json = anoncreds::proof_request_attr().replace(r#""name":"name""#, r#""name":"NAME""#);
+
+            let valid = anoncreds::verifier_verify_proof(&proof_req_json,
+                                                         &anoncreds::proof_json(),
+                                                         &anoncreds::schemas_for_proof(),
+                                                         &anoncreds::cred_defs_for_proof(),
+                                                         "{}",
+                                                         "{}").unwrap();
+            assert!(valid);
+        }
+
+        #[test]
+        fn verifier_verify_proof_works_for_proof_with_names() {
+            let other_proof_req_json = json!({
+                "nonce":"123432421212",
+                "name":"proof_req_1",
+                "version":"0.1",
+                "requested_attributes": {
+                    "attr1_referent": {
+                        "names":["name", "age"],
+                        "revealed": "true"
+                    }
+                },
+                "requested_predicates": {},
+            }).to_string();
+
+            let valid = anoncreds::verifier_verify_proof(&other_proof_req_json,
+                                                         &anoncreds::proof_json_names(),
+                                                         &anoncreds::schema_names(),
+                                                         &anoncreds::cred_defs_names(),
+                                                         "{}",
+                                                         "{}").unwrap();
+            assert!(valid);
+        }
+    }
+
+    #[test]
+    fn verifier_verify_proof_works_for_proof_with_names_in_different_credentials() {
+        let other_proof_req_json = json!({
+                "nonce":"123432421212",
+                "name":"proof_req_1",
+                "version":"0.1",
+                "requested_attributes": {
+                    "attr1_referent": {
+                        "names":["name", "age"],
+                        "revealed": "true",
+
+                    }
+                },
+                "requested_predicates": {},
+            }).to_string();
+
+        let valid = anoncreds::verifier_verify_proof(&other_proof_req_json,
+                                                     &anoncreds::proof_json_names_diff_creds(),
+                                                     &anoncreds::schema_names(),
+                                                     &anoncreds::cred_defs_names(),
+                                                     "{}",
+                                                     "{}").unwrap();
+        assert!(!valid);
+    }
+
+    mod verifier_verify_proof_with_proof_req_restrictions {
+        use super::*;
+
+        #[test]
+        fn verifier_verify_proof_successful() {
+            let valid = anoncreds::verifier_verify_proof(&anoncreds::proof_request_restrictions(),
+                                                         &anoncreds::proof_json_restrictions(),
+                                                         &anoncreds::schemas_for_proof_restrictions(),
+                                                         &anoncreds::cred_defs_for_proof_restrictions(),
+                                                         "{}",
+                                                         "{}").unwrap();
+            assert!(valid);
+        }
+
+        #[test]
+        fn verifier_verify_proof_success_for_valid_issuer_did() {
+            let proof_req = json!({
+                   "nonce":"123432421212",
+                   "name":"proof_req_1",
+                   "version":"0.1",
+                   "requested_attributes": {
+                       "attr1_referent": {
+                           "name":"name",
+                           "restrictions": { "issuer_did": { "$in": [ISSUER_DID] } }
+                       }
+                   },
+                   "requested_predicates": {},
+                }).to_string();
+
+            let valid = anoncreds::verifier_verify_proof(&proof_req,
+                                                         &anoncreds::proof_json(),
+                                                         &anoncreds::schemas_for_proof(),
+                                                         &anoncreds::cred_defs_for_proof(),
+                                                         "{}",
+                                                         "{}").unwrap();
+            assert!(valid);
+        }
+
+        #[test]
+        fn verifier_verify_proof_fails_for_missing_issuer_did() {
+            let proof_req = json!({
+                   "nonce":"123432421212",
+                   "name":"proof_req_1",
+                   "version":"0.1",
+                   "requested_attributes": {
+                       "attr1_referent": {
+                           "name":"name",
+                           "restrictions": { "issuer_did": { "$in": ["NO DID"] } }
+                       }
+                   },
+                   "requested_predicates": {},
+                }).to_string();
+
+            let valid = anoncreds::verifier_verify_proof(&proof_req,
+                                                         &anoncreds::proof_json(),
+                                                         &anoncreds::schemas_for_proof(),
+                                                         &anoncreds::cred_defs_for_proof(),
+                                                         "{}",
+                                                         "{}");
+            assert_code!(ErrorCode::AnoncredsProofRejected , valid);
+        }
+
+        #[test]
+        fn verifier_verify_proof_fails_for_missing_predicate_restriction() {
+            let proof_req = json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": {},
+               "requested_predicates": {
+                    "attr1_referent": {
+                    "name":"age", "p_type":">=", "p_value":18, "restrictions": { "schema_id": "Not HERE" }}
+
+               },
+            }).to_string();
+
+            let mut proof: Proof = serde_json::from_str(&anoncreds::proof_json()).unwrap();
+            proof.requested_proof.revealed_attrs.remove("attr1_referent").unwrap();
+            proof.requested_proof.predicates.insert(
+                "attr1_referent".to_string(),
+                serde_json::from_str(&json!({ "sub_proof_index": 0 }).to_string()).unwrap(),
+            );
+
+
+            let valid = anoncreds::verifier_verify_proof(&proof_req,
+                                                         &serde_json::to_string(&proof).unwrap(),
+                                                         &anoncreds::schemas_for_proof(),
+                                                         &anoncreds::cred_defs_for_proof(),
+                                                         "{}",
+                                                         "{}");
+            assert_code!(ErrorCode::AnoncredsProofRejected , valid);
+        }
+
+        #[test]
+        fn verifier_verify_proof_success_for_valid_schema_id() {
+            let proof_req = json!({
+                   "nonce":"123432421212",
+                   "name":"proof_req_1",
+                   "version":"0.1",
+                   "requested_attributes": {
+                       "attr1_referent": {
+                           "name":"name",
+                           "restrictions": { "schema_id": anoncreds::gvt_schema_id() },
+                       }
+                   },
+                   "requested_predicates": {},
+                }).to_string();
+
+            let valid = anoncreds::verifier_verify_proof(&proof_req,
+                                                         &anoncreds::proof_json(),
+                                                         &anoncreds::schemas_for_proof(),
+                                                         &anoncreds::cred_defs_for_proof(),
+                                                         "{}",
+                                                         "{}").unwrap();
+            assert!(valid);
+        }
+
+        #[test]
+        fn verifier_verify_proof_fails_for_missing_schema_id() {
+            let proof_req = json!({
+                   "nonce":"123432421212",
+                   "name":"proof_req_1",
+                   "version":"0.1",
+                   "requested_attributes": {
+                       "attr1_referent": {
+                           "name":"name",
+                           "restrictions": { "schema_id": "Not HERE" },
+                       }
+                   },
+                   "requested_predicates": {},
+                }).to_string();
+
+            let valid = anoncreds::verifier_verify_proof(&proof_req,
+                                                         &anoncreds::proof_json(),
+                                                         &anoncreds::schemas_for_proof(),
+                                                         &anoncreds::cred_defs_for_proof(),
+                                                         "{}",
+                                                         "{}");
+            assert_code!(ErrorCode::AnoncredsProofRejected , valid);
+        }
+
+        #[test]
+        fn verifier_verify_proof_success_for_valid_schema_issuer_did() {
+            let proof_req = json!({
+                   "nonce":"123432421212",
+                   "name":"proof_req_1",
+                   "version":"0.1",
+                   "requested_attributes": {
+                       "attr1_referent": {
+                           "name":"name",
+                           "restrictions": { "schema_issuer_did": ISSUER_DID },
+                       }
+                   },
+                   "requested_predicates": {},
+                }).to_string();
+
+            let valid = anoncreds::verifier_verify_proof(&proof_req,
+                                                         &anoncreds::proof_json(),
+                                                         &anoncreds::schemas_for_proof(),
+                                                         &anoncreds::cred_defs_for_proof(),
+                                                         "{}",
+                                                         "{}").unwrap();
+            assert!(valid);
+        }
+
+        #[test]
+        fn verifier_verify_proof_fails_for_missing_schema_issuer_did() {
+            let proof_req = json!({
+                   "nonce":"123432421212",
+                   "name":"proof_req_1",
+                   "version":"0.1",
+                   "requested_attributes": {
+                       "attr1_referent": {
+                           "name":"name",
+                           "restrictions": { "schema_issuer_did": "Not HERE" },
+                       }
+                   },
+                   "requested_predicates": {},
+                }).to_string();
+
+            let valid = anoncreds::verifier_verify_proof(&proof_req,
+                                                         &anoncreds::proof_json(),
+                                                         &anoncreds::schemas_for_proof(),
+                                                         &anoncreds::cred_defs_for_proof(),
+                                                         "{}",
+                                                         "{}");
+            assert_code!(ErrorCode::AnoncredsProofRejected , valid);
+        }
+
+        #[test]
+        fn verifier_verify_proof_success_for_valid_schema_name() {
+            let proof_req = json!({
+                   "nonce":"123432421212",
+                   "name":"proof_req_1",
+                   "version":"0.1",
+                   "requested_attributes": {
+                       "attr1_referent": {
+                           "name":"name",
+                           "restrictions": { "schema_name": GVT_SCHEMA_NAME },
+                       }
+                   },
+                   "requested_predicates": {},
+                }).to_string();
+
+            let valid = anoncreds::verifier_verify_proof(&proof_req,
+                                                         &anoncreds::proof_json(),
+                                                         &anoncreds::schemas_for_proof(),
+                                                         &anoncreds::cred_defs_for_proof(),
+                                                         "{}",
+                                                         "{}").unwrap();
+            assert!(valid);
+        }
+
+        #[test]
+        fn verifier_verify_proof_fails_for_missing_schema_name() {
+            let proof_req = json!({
+                   "nonce":"123432421212",
+                   "name":"proof_req_1",
+                   "version":"0.1",
+                   "requested_attributes": {
+                       "attr1_referent": {
+                           "name":"name",
+                           "restrictions": { "schema_name": "Not HERE" },
+                       }
+                   },
+                   "requested_predicates": {},
+                }).to_string();
+
+            let valid = anoncreds::verifier_verify_proof(&proof_req,
+                                                         &anoncreds::proof_json(),
+                                                         &anoncreds::schemas_for_proof(),
+                                                         &anoncreds::cred_defs_for_proof(),
+                                                         "{}",
+                                                         "{}");
+            assert_code!(ErrorCode::AnoncredsProofRejected , valid);
+        }
+
+        #[test]
+        fn verifier_verify_proof_success_for_valid_schema_version() {
+            let proof_req = json!({
+                   "nonce":"123432421212",
+                   "name":"proof_req_1",
+                   "version":"0.1",
+                   "requested_attributes": {
+                       "attr1_referent": {
+                           "name":"name",
+                           "restrictions": { "schema_version": SCHEMA_VERSION },
+                       }
+                   },
+                   "requested_predicates": {},
+                }).to_string();
+
+            let valid = anoncreds::verifier_verify_proof(&proof_req,
+                                                         &anoncreds::proof_json(),
+                                                         &anoncreds::schemas_for_proof(),
+                                                         &anoncreds::cred_defs_for_proof(),
+                                                         "{}",
+                                                         "{}").unwrap();
+            assert!(valid);
+        }
+
+        #[test]
+        fn verifier_verify_proof_fails_for_missing_schema_version() {
+            let proof_req = json!({
+                   "nonce":"123432421212",
+                   "name":"proof_req_1",
+                   "version":"0.1",
+                   "requested_attributes": {
+                       "attr1_referent": {
+                           "name":"name",
+                           "restrictions": { "schema_version": "Not HERE" },
+                       }
+                   },
+                   "requested_predicates": {},
+                }).to_string();
+
+            let valid = anoncreds::verifier_verify_proof(&proof_req,
+                                                         &anoncreds::proof_json(),
+                                                         &anoncreds::schemas_for_proof(),
+                                                         &anoncreds::cred_defs_for_proof(),
+                                                         "{}",
+                                                         "{}");
+            assert_code!(ErrorCode::AnoncredsProofRejected , valid);
+        }
+
+        #[test]
+        fn verifier_verify_proof_success_for_valid_cred_def_id() {
+            let proof_req = json!({
+                   "nonce":"123432421212",
+                   "name":"proof_req_1",
+                   "version":"0.1",
+                   "requested_attributes": {
+                       "attr1_referent": {
+                           "name":"name",
+                           "restrictions": { "cred_def_id": anoncreds::issuer_1_gvt_cred_def_id() },
+                       }
+                   },
+                   "requested_predicates": {},
+                }).to_string();
+
+            let valid = anoncreds::verifier_verify_proof(&proof_req,
+                                                         &anoncreds::proof_json(),
+                                                         &anoncreds::schemas_for_proof(),
+                                                         &anoncreds::cred_defs_for_proof(),
+                                                         "{}",
+                                                         "{}").unwrap();
+            assert!(valid);
+        }
 
-            #[test]
-            fn prover_search_credentials_for_proof_req_works_for_empty_restrictions() {
-                anoncreds::init_common_wallet();
+        #[test]
+        fn verifier_verify_proof_fails_for_missing_cred_def_id() {
+            let proof_req = json!({
+                   "nonce":"123432421212",
+                   "name":"proof_req_1",
+                   "version":"0.1",
+                   "requested_attributes": {
+                       "attr1_referent": {
+                           "name":"name",
+                           "restrictions": { "cred_def_id": "Not HERE" },
+                       }
+                   },
+                   "requested_predicates": {},
+                }).to_string();
 
-                let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+            let valid = anoncreds::verifier_verify_proof(&proof_req,
+                                                         &anoncreds::proof_json(),
+                                                         &anoncreds::schemas_for_proof(),
+                                                         &anoncreds::cred_defs_for_proof(),
+                                                         "{}",
+                                                         "{}");
+            assert_code!(ErrorCode::AnoncredsProofRejected , valid);
+        }
 
-                let proof_req = json!({
+        #[test]
+        fn verifier_verify_proof_fails_for_unknown_restriction() {
+            let proof_req = json!({
                    "nonce":"123432421212",
                    "name":"proof_req_1",
                    "version":"0.1",
-                   "requested_attributes": json!({
-                       "attr1_referent": json!({
+                   "requested_attributes": {
+                       "attr1_referent": {
                            "name":"name",
-                           "restrictions": {}
-                       }),
-                       "attr2_referent": json!({
-                           "name":"age",
-                           "restrictions": []
-                       })
-                   }),
-                   "requested_predicates": json!({ }),
+                           "restrictions": { "UNKNOWN": "Not HERE" },
+                       }
+                   },
+                   "requested_predicates": {},
                 }).to_string();
 
-                let search_handle = anoncreds::prover_search_credentials_for_proof_req(wallet_handle, &proof_req, None).unwrap();
+            let res = anoncreds::verifier_verify_proof(&proof_req,
+                                                       &anoncreds::proof_json(),
+                                                       &anoncreds::schemas_for_proof(),
+                                                       &anoncreds::cred_defs_for_proof(),
+                                                       "{}",
+                                                       "{}");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
 
-                let credentials_json = anoncreds::prover_fetch_next_credentials_for_proof_req(
-                    search_handle, "attr1_referent", 100).unwrap();
+    mod verifier_verify_proof_with_options {
+        use super::*;
 
-                let credentials: Vec<RequestedCredential> = serde_json::from_str(&credentials_json).unwrap();
-                assert_eq!(credentials.len(), 2);
+        #[test]
+        fn verifier_verify_proof_with_options_works_for_default_options() {
+            let valid = anoncreds::verifier_verify_proof_with_options(&anoncreds::proof_request_attr(),
+                                                                      &anoncreds::proof_json(),
+                                                                      &anoncreds::schemas_for_proof(),
+                                                                      &anoncreds::cred_defs_for_proof(),
+                                                                      "{}",
+                                                                      "{}",
+                                                                      "{}").unwrap();
+            assert!(valid);
+        }
 
-                let credentials_json = anoncreds::prover_fetch_next_credentials_for_proof_req(
-                    search_handle, "attr2_referent", 100).unwrap();
+        #[test]
+        fn verifier_verify_proof_with_options_works_for_raw_value_not_matching_encoding() {
+            // encoded value of "name" in the proof is not produced by the standard encoding
+            let res = anoncreds::verifier_verify_proof_with_options(&anoncreds::proof_request_attr(),
+                                                                    &anoncreds::proof_json(),
+                                                                    &anoncreds::schemas_for_proof(),
+                                                                    &anoncreds::cred_defs_for_proof(),
+                                                                    "{}",
+                                                                    "{}",
+                                                                    r#"{"check_raw_encoding": true}"#);
+            assert_code!(ErrorCode::AnoncredsProofRejected, res);
+        }
 
-                let credentials: Vec<RequestedCredential> = serde_json::from_str(&credentials_json).unwrap();
-                assert_eq!(credentials.len(), 2);
+        #[test]
+        fn verifier_verify_proof_with_options_works_for_invalid_options() {
+            let res = anoncreds::verifier_verify_proof_with_options(&anoncreds::proof_request_attr(),
+                                                                    &anoncreds::proof_json(),
+                                                                    &anoncreds::schemas_for_proof(),
+                                                                    &anoncreds::cred_defs_for_proof(),
+                                                                    "{}",
+                                                                    "{}",
+                                                                    r#"{"check_raw_encoding": "yes"}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
 
-                anoncreds::prover_close_credentials_search_for_proof_req(search_handle).unwrap();
+    mod encode_credential_values {
+        use super::*;
+        use crate::utils::domain::anoncreds::credential::CredentialValues;
 
-                wallet::close_wallet(wallet_handle).unwrap();
-            }
+        #[test]
+        fn encode_credential_values_works() {
+            let cred_values_json = anoncreds::encode_credential_values(r#"{"sex": "male", "age": "28"}"#).unwrap();
+            let cred_values: CredentialValues = serde_json::from_str(&cred_values_json).unwrap();
+
+            assert_eq!("male", cred_values.0["sex"].raw);
+            assert_eq!("5944657099558967239210949258394887428692050081607692519917050011144233115103", cred_values.0["sex"].encoded);
+            assert_eq!("28", cred_values.0["age"].encoded);
         }
 
-        mod validation {
-            use super::*;
+        #[test]
+        fn encode_credential_values_works_for_invalid_values() {
+            let res = anoncreds::encode_credential_values(r#"{"age": 28}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
 
-            #[test]
-            fn prover_search_credentials_for_proof_req_works_for_proof_req_v1_contains_fully_qualified_restrictions() {
-                anoncreds::init_common_wallet();
+    mod typed_schema {
+        use super::*;
+        use crate::utils::domain::anoncreds::credential::CredentialValues;
 
-                let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+        const TYPED_SCHEMA_NAME: &str = "typed";
+        const TYPED_SCHEMA_ATTRIBUTES: &str = r#"{"name": "string", "dob": "date", "height": "integer", "verified": "boolean"}"#;
 
-                let proof_req = json!({
-                   "nonce":"123432421212",
-                   "name":"proof_req_1",
-                   "version":"0.1",
-                   "requested_attributes": json!({
-                       "attr1_referent": json!({
-                           "name":"name",
-                           "restrictions": {
-                                "issuer_did": ISSUER_DID_V1
-                           }
-                       })
-                   }),
-                   "requested_predicates": json!({ }),
-                }).to_string();
+        fn _typed_schema() -> (String, String) {
+            anoncreds::issuer_create_typed_schema(ISSUER_DID, TYPED_SCHEMA_NAME, SCHEMA_VERSION, TYPED_SCHEMA_ATTRIBUTES).unwrap()
+        }
 
-                let res = anoncreds::prover_search_credentials_for_proof_req(wallet_handle, &proof_req, None);
-                assert_code!(ErrorCode::CommonInvalidStructure, res);
+        fn _cred_values(schema_json: &str, name: &str, dob: &str) -> String {
+            let values = json!({"name": name, "dob": dob, "height": "175", "verified": "true"}).to_string();
+            anoncreds::encode_typed_credential_values(&values, schema_json).unwrap()
+        }
 
-                wallet::close_wallet(wallet_handle).unwrap();
-            }
+        #[test]
+        fn issuer_create_typed_schema_works() {
+            let (_, schema_json) = _typed_schema();
+            let schema: serde_json::Value = serde_json::from_str(&schema_json).unwrap();
 
-            #[test]
-            fn prover_search_credentials_for_proof_req_works_for_fully_qualified_restrictions() {
-                anoncreds::init_common_wallet();
+            assert_eq!(4, schema["attrNames"].as_array().unwrap().len());
+            assert_eq!("date", schema["attrTypes"]["dob"].as_str().unwrap());
+            assert_eq!("boolean", schema["attrTypes"]["verified"].as_str().unwrap());
+        }
 
-                let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+        #[test]
+        fn issuer_create_typed_schema_works_for_unknown_type() {
+            let res = anoncreds::issuer_create_typed_schema(ISSUER_DID, TYPED_SCHEMA_NAME, SCHEMA_VERSION, r#"{"dob": "datetime"}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
 
-                let proof_req = json!({
-                   "nonce":"123432421212",
-                   "name":"proof_req_1",
-                   "version":"0.1",
-                   "requested_attributes": json!({
-                       "attr1_referent": json!({
-                           "name":"name",
-                           "restrictions": {
-                                "issuer_did": ISSUER_DID_V1
-                           }
-                       })
-                   }),
-                   "requested_predicates": json!({ }),
-                   "ver": "2.0"
-                }).to_string();
+        #[test]
+        fn encode_typed_credential_values_works() {
+            let (_, schema_json) = _typed_schema();
 
-                let search_handle = anoncreds::prover_search_credentials_for_proof_req(wallet_handle, &proof_req, None).unwrap();
+            let cred_values: CredentialValues = serde_json::from_str(&_cred_values(&schema_json, "Alex", "2006-10-17")).unwrap();
 
-                let credentials_json = anoncreds::prover_fetch_next_credentials_for_proof_req(
-                    search_handle, "attr1_referent", 100).unwrap();
+            assert_eq!("2006-10-17", cred_values.0["dob"].raw);
+            assert_eq!("13438", cred_values.0["dob"].encoded);
+            assert_eq!("175", cred_values.0["height"].encoded);
+            assert_eq!("1", cred_values.0["verified"].encoded);
+            assert_eq!("99262857098057710338306967609588410025648622308394250666849665532448612202874", cred_values.0["name"].encoded);
+        }
 
-                let credentials: Vec<RequestedCredential> = serde_json::from_str(&credentials_json).unwrap();
-                assert_eq!(credentials.len(), 0);
+        #[test]
+        fn encode_typed_credential_values_works_for_value_not_matching_type() {
+            let (_, schema_json) = _typed_schema();
 
-                anoncreds::prover_close_credentials_search_for_proof_req(search_handle).unwrap();
+            let res = anoncreds::encode_typed_credential_values(r#"{"dob": "17.10.2006"}"#, &schema_json);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
 
-                wallet::close_wallet(wallet_handle).unwrap();
-            }
+        #[test]
+        fn build_date_predicate_works() {
+            let predicate_json = anoncreds::build_date_predicate(r#"{"name": "dob", "p_type": "<", "date": "2006-10-17"}"#).unwrap();
+            let predicate: serde_json::Value = serde_json::from_str(&predicate_json).unwrap();
+
+            assert_eq!(json!({"name": "dob", "p_type": "<", "p_value": 13438, "restrictions": null, "non_revoked": null}), predicate);
         }
-    }
 
-    mod prover_create_proof_works {
-        use super::*;
+        #[test]
+        fn build_date_predicate_works_for_invalid_date() {
+            let res = anoncreds::build_date_predicate(r#"{"name": "dob", "p_type": "<", "date": "2006-13-01"}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
 
         #[test]
-        fn prover_create_proof_works() {
-            anoncreds::init_common_wallet();
+        fn issuer_create_credential_works_for_value_not_matching_type() {
+            let setup = Setup::wallet();
 
-            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+            let (_, schema_json) = _typed_schema();
+            let (cred_def_id, cred_def_json) = anoncreds::issuer_create_credential_definition(setup.wallet_handle, ISSUER_DID, &schema_json,
+                                                                                              TAG_1, None, Some(&anoncreds::default_cred_def_config())).unwrap();
 
-            let requested_credentials_json = json!({
-                 "self_attested_attributes": json!({}),
-                 "requested_attributes": json!({
-                    "attr1_referent": json!({ "cred_id": CREDENTIAL1_ID, "revealed":true })
-                 }),
-                 "requested_predicates": json!({
-                    "predicate1_referent": json!({ "cred_id": CREDENTIAL1_ID })
-                 })
-            }).to_string();
+            anoncreds::prover_create_master_secret(setup.wallet_handle, COMMON_MASTER_SECRET).unwrap();
 
-            anoncreds::prover_create_proof(wallet_handle,
-                                           &anoncreds::proof_request_attr_and_predicate(),
-                                           &requested_credentials_json,
-                                           COMMON_MASTER_SECRET,
-                                           &anoncreds::schemas_for_proof(),
-                                           &anoncreds::cred_defs_for_proof(),
-                                           "{}").unwrap();
+            let cred_offer_json = anoncreds::issuer_create_credential_offer(setup.wallet_handle, &cred_def_id).unwrap();
+            let (cred_req_json, _) = anoncreds::prover_create_credential_req(setup.wallet_handle, DID_MY1, &cred_offer_json,
+                                                                             &cred_def_json, COMMON_MASTER_SECRET).unwrap();
 
-            wallet::close_wallet(wallet_handle).unwrap();
+            // untyped encoding of a date is a hash
+            let cred_values = anoncreds::encode_credential_values(r#"{"name": "Alex", "dob": "2006-10-17", "height": "175", "verified": "true"}"#).unwrap();
+
+            let res = anoncreds::issuer_create_credential(setup.wallet_handle, &cred_offer_json, &cred_req_json, &cred_values, None, None);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
         }
 
         #[test]
-        fn prover_create_proof_works_for_names() {
-            anoncreds::init_common_wallet();
+        fn typed_schema_works_for_search_without_tag_policy() {
+            let setup = Setup::wallet();
 
-            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+            let (_, schema_json) = _typed_schema();
+            let (cred_def_id, cred_def_json) = anoncreds::issuer_create_credential_definition(setup.wallet_handle, ISSUER_DID, &schema_json,
+                                                                                              TAG_1, None, Some(&anoncreds::default_cred_def_config())).unwrap();
 
-            let requested_credentials_json = json!({
-                 "self_attested_attributes": {},
-                 "requested_attributes": {
-                    "attr1_referent": { "cred_id": CREDENTIAL1_ID, "revealed":true }
-                 },
-                 "requested_predicates": {}
-            }).to_string();
+            anoncreds::prover_create_master_secret(setup.wallet_handle, COMMON_MASTER_SECRET).unwrap();
 
-            anoncreds::prover_create_proof(wallet_handle,
-                                           &anoncreds::proof_request_attr_names(),
-                                           &requested_credentials_json,
-                                           COMMON_MASTER_SECRET,
-                                           &anoncreds::schemas_for_proof(),
-                                           &anoncreds::cred_defs_for_proof(),
-                                           "{}").unwrap();
+            anoncreds::multi_steps_create_credential(COMMON_MASTER_SECRET, setup.wallet_handle, setup.wallet_handle, CREDENTIAL1_ID,
+                                                     &_cred_values(&schema_json, "Alex", "2000-01-01"), &cred_def_id, &cred_def_json);
 
-            wallet::close_wallet(wallet_handle).unwrap();
+            // no unencrypted tags are written unless the tag policy opts in
+            let (search_handle, count) = anoncreds::prover_search_credentials(setup.wallet_handle, r#"{"attr::height::int": {"$gte": "170"}}"#).unwrap();
+            assert_eq!(0, count);
+            anoncreds::prover_close_credentials_search(search_handle).unwrap();
         }
 
         #[test]
-        fn prover_create_proof_works_for_using_not_satisfy_credential() {
-            anoncreds::init_common_wallet();
+        fn typed_schema_works_for_date_search_and_predicate() {
+            let setup = Setup::wallet();
 
-            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+            let (schema_id, schema_json) = _typed_schema();
+            let (cred_def_id, cred_def_json) = anoncreds::issuer_create_credential_definition(setup.wallet_handle, ISSUER_DID, &schema_json,
+                                                                                              TAG_1, None, Some(&anoncreds::default_cred_def_config())).unwrap();
 
-            let proof_req = json!({
-               "nonce":"123432421212",
-               "name":"proof_req_1",
-               "version":"0.1",
-               "requested_attributes": json!({
-                   "attr1_referent": json!({
-                       "name":"some_attr"
-                   })
-               }),
-               "requested_predicates": json!({}),
+            anoncreds::prover_create_master_secret(setup.wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+            // Prover opts in for unencrypted sortable tags of attributes with types declared by the schema
+            anoncreds::prover_set_credential_attr_tag_policy(setup.wallet_handle, &cred_def_id,
+                                                             Some(r#"["name", "dob::date", "height::integer", "verified::boolean"]"#), false).unwrap();
+
+            anoncreds::multi_steps_create_credential(COMMON_MASTER_SECRET, setup.wallet_handle, setup.wallet_handle, CREDENTIAL1_ID,
+                                                     &_cred_values(&schema_json, "Alex", "2000-01-01"), &cred_def_id, &cred_def_json);
+            anoncreds::multi_steps_create_credential(COMMON_MASTER_SECRET, setup.wallet_handle, setup.wallet_handle, anoncreds::CREDENTIAL2_ID,
+                                                     &_cred_values(&schema_json, "Bob", "2010-05-05"), &cred_def_id, &cred_def_json);
+
+            // Prover searches credentials of holders born before 2006-10-17
+            let (search_handle, count) = anoncreds::prover_search_credentials(setup.wallet_handle, r#"{"attr::dob::date": {"$lt": "2006-10-17"}}"#).unwrap();
+            assert_eq!(1, count);
+
+            let credentials = anoncreds::prover_fetch_credentials(search_handle, count).unwrap();
+            let credentials: Vec<CredentialInfo> = serde_json::from_str(&credentials).unwrap();
+            assert_eq!(CREDENTIAL1_ID, credentials[0].referent);
+            anoncreds::prover_close_credentials_search(search_handle).unwrap();
+
+            let (search_handle, count) = anoncreds::prover_search_credentials(setup.wallet_handle, r#"{"attr::height::int": {"$gte": "170"}}"#).unwrap();
+            assert_eq!(2, count);
+            anoncreds::prover_close_credentials_search(search_handle).unwrap();
+
+            // Verifier requests proof of birth before 2006-10-17
+            let predicate_json = anoncreds::build_date_predicate(r#"{"name": "dob", "p_type": "<", "date": "2006-10-17"}"#).unwrap();
+            let predicate: serde_json::Value = serde_json::from_str(&predicate_json).unwrap();
+
+            let proof_req_json = json!({
+                "nonce": "123432421212",
+                "name": "proof_req_1",
+                "version": "0.1",
+                "requested_attributes": {"attr1_referent": {"name": "dob"}},
+                "requested_predicates": {"predicate1_referent": predicate}
             }).to_string();
 
             let requested_credentials_json = json!({
-                 "self_attested_attributes": json!({}),
-                 "requested_attributes": json!({
-                    "attr1_referent": json!({ "cred_id": CREDENTIAL1_ID, "revealed":true })
-                 }),
-                 "requested_predicates": json!({})
+                "self_attested_attributes": {},
+                "requested_attributes": {"attr1_referent": {"cred_id": CREDENTIAL1_ID, "revealed": true}},
+                "requested_predicates": {"predicate1_referent": {"cred_id": CREDENTIAL1_ID}}
+            }).to_string();
+
+            let schemas_json = json!({schema_id: serde_json::from_str::<serde_json::Value>(&schema_json).unwrap()}).to_string();
+            let cred_defs_json = json!({cred_def_id.clone(): serde_json::from_str::<serde_json::Value>(&cred_def_json).unwrap()}).to_string();
+
+            let proof_json = anoncreds::prover_create_proof(setup.wallet_handle, &proof_req_json, &requested_credentials_json,
+                                                            COMMON_MASTER_SECRET, &schemas_json, &cred_defs_json, "{}").unwrap();
+
+            let proof: Proof = serde_json::from_str(&proof_json).unwrap();
+            assert_eq!("2000-01-01", proof.requested_proof.revealed_attrs["attr1_referent"].raw);
+
+            let valid = anoncreds::verifier_verify_proof_with_options(&proof_req_json, &proof_json, &schemas_json, &cred_defs_json,
+                                                                      "{}", "{}", r#"{"check_raw_encoding": true}"#).unwrap();
+            assert!(valid);
+
+            // Credential of a holder born after the date doesn't satisfy the predicate
+            let requested_credentials_json = json!({
+                "self_attested_attributes": {},
+                "requested_attributes": {"attr1_referent": {"cred_id": anoncreds::CREDENTIAL2_ID, "revealed": true}},
+                "requested_predicates": {"predicate1_referent": {"cred_id": anoncreds::CREDENTIAL2_ID}}
             }).to_string();
 
-            let res = anoncreds::prover_create_proof(wallet_handle,
-                                                     &proof_req,
-                                                     &requested_credentials_json,
-                                                     COMMON_MASTER_SECRET,
-                                                     &anoncreds::schemas_for_proof(),
-                                                     &anoncreds::cred_defs_for_proof(),
-                                                     "{}");
+            let res = anoncreds::prover_create_proof(setup.wallet_handle, &proof_req_json, &requested_credentials_json,
+                                                     COMMON_MASTER_SECRET, &schemas_json, &cred_defs_json, "{}");
             assert_code!(ErrorCode::CommonInvalidStructure, res);
-
-            wallet::close_wallet(wallet_handle).unwrap();
         }
     }
 
-    mod verifier_verify_proof {
+    mod credential_to_w3c {
         use super::*;
 
         #[test]
-        fn verifier_verify_proof_works_for_correct_proof() {
-            let valid = anoncreds::verifier_verify_proof(&anoncreds::proof_request_attr(),
-                                                         &anoncreds::proof_json(),
-                                                         &anoncreds::schemas_for_proof(),
-                                                         &anoncreds::cred_defs_for_proof(),
-                                                         "{}",
-                                                         "{}").unwrap();
-            assert!(valid);
+        fn credential_to_w3c_works() {
+            let (_, _, _, credential_json) = anoncreds::init_common_wallet();
+
+            let w3c_cred_json = anoncreds::credential_to_w3c(credential_json, ISSUANCE_DATE).unwrap();
+            let w3c_cred: serde_json::Value = serde_json::from_str(&w3c_cred_json).unwrap();
+
+            assert_eq!(json!(["VerifiableCredential", "AnonCredsCredential"]), w3c_cred["type"]);
+            assert_eq!(format!("did:sov:{}", ISSUER_DID), w3c_cred["issuer"].as_str().unwrap());
+            assert_eq!(ISSUANCE_DATE, w3c_cred["issuanceDate"].as_str().unwrap());
+            assert_eq!(anoncreds::issuer_1_gvt_cred_def_id(), w3c_cred["credentialSchema"]["definition"].as_str().unwrap());
+            assert_eq!("Alex", w3c_cred["credentialSubject"]["name"].as_str().unwrap());
+            assert_eq!("28", w3c_cred["credentialSubject"]["age"].as_str().unwrap());
+            assert_eq!("AnonCredsProof2023", w3c_cred["proof"]["type"].as_str().unwrap());
         }
 
         #[test]
-        fn verifier_verify_proof_works_for_proof_does_not_correspond_to_request() {
-            let other_proof_req_json = json!({
-               "nonce":"123432421212",
-               "name":"proof_req_1",
-               "version":"0.1",
-               "requested_attributes": json!({
-                   "attr1_referent": json!({
-                       "name":"sex"
-                   })
-               }),
-               "requested_predicates": json!({
-                   "predicate1_referent": json!({ "name":"age", "p_type":">=", "p_value":18 })
-               }),
-            }).to_string();
+        fn credential_to_w3c_works_for_invalid_issuance_date() {
+            let (_, _, _, credential_json) = anoncreds::init_common_wallet();
 
-            let res = anoncreds::verifier_verify_proof(&other_proof_req_json,
-                                                       &anoncreds::proof_json(),
-                                                       &anoncreds::schemas_for_proof(),
-                                                       &anoncreds::cred_defs_for_proof(),
-                                                       "{}",
-                                                       "{}");
+            let res = anoncreds::credential_to_w3c(credential_json, "2020-01-01");
             assert_code!(ErrorCode::CommonInvalidStructure, res);
         }
 
         #[test]
-        fn verifier_verify_proof_works_for_proof_does_not_correspond_to_request_attribute() {
-            let other_proof_req_json = json!({
-               "nonce":"123432421212",
-               "name":"proof_req_1",
-               "version":"0.1",
-               "requested_attributes": json!({
-                   "attr1_referent": json!({
-                       "name":"sex"
-                   })
-               }),
-               "requested_predicates": json!({}),
-            }).to_string();
-            let res = anoncreds::verifier_verify_proof(&other_proof_req_json,
-                                                       &anoncreds::proof_json(),
-                                                       &anoncreds::schemas_for_proof(),
-                                                       &anoncreds::cred_defs_for_proof(),
-                                                       "{}",
-                                                       "{}");
-            assert_code!(ErrorCode::AnoncredsProofRejected, res);
+        fn credential_to_w3c_works_for_invalid_credential() {
+            let res = anoncreds::credential_to_w3c(r#"{"schema_id":"NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0"}"#, ISSUANCE_DATE);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
         }
+    }
+
+    mod credential_from_w3c {
+        use super::*;
 
         #[test]
-        fn verifier_verify_proof_works_for_wrong_revealed_attr_value() {
-            let proof_json = anoncreds::proof_json().replace(r#"name":"1139481716457488690172217916278103335"#, r#"name":"1111111111111111111111111111111111111"#);
+        fn credential_from_w3c_works() {
+            let (_, _, _, credential_json) = anoncreds::init_common_wallet();
 
-            let res = anoncreds::verifier_verify_proof(&anoncreds::proof_request_attr(),
-                                                       &proof_json,
-                                                       &anoncreds::schemas_for_proof(),
-                                                       &anoncreds::cred_defs_for_proof(),
-                                                       "{}",
-                                                       "{}");
-            assert_code!(ErrorCode::AnoncredsProofRejected, res);
+            let w3c_cred_json = anoncreds::credential_to_w3c(credential_json, ISSUANCE_DATE).unwrap();
+            let restored_credential_json = anoncreds::credential_from_w3c(&w3c_cred_json, &anoncreds::gvt_schema_json()).unwrap();
+
+            let credential: serde_json::Value = serde_json::from_str(credential_json).unwrap();
+            let restored_credential: serde_json::Value = serde_json::from_str(&restored_credential_json).unwrap();
+            assert_eq!(credential, restored_credential);
         }
 
         #[test]
-        fn verifier_verify_proof_works_for_wrong_encoded() {
-            let proof_json = anoncreds::proof_json().replace(r#"encoded":"1139481716457488690172217916278103335"#, r#"encoded":"1111111111111111111111111111111111111"#);
+        fn credential_from_w3c_works_for_missed_attribute() {
+            let (_, _, _, credential_json) = anoncreds::init_common_wallet();
 
-            let res = anoncreds::verifier_verify_proof(&anoncreds::proof_request_attr(),
-                                                       &proof_json,
-                                                       &anoncreds::schemas_for_proof(),
-                                                       &anoncreds::cred_defs_for_proof(),
-                                                       "{}",
-                                                       "{}");
-            assert_code!(ErrorCode::AnoncredsProofRejected, res);
+            let w3c_cred_json = anoncreds::credential_to_w3c(credential_json, ISSUANCE_DATE).unwrap();
+            let mut w3c_cred: serde_json::Value = serde_json::from_str(&w3c_cred_json).unwrap();
+            w3c_cred["credentialSubject"].as_object_mut().unwrap().remove("age");
+
+            let res = anoncreds::credential_from_w3c(&w3c_cred.to_string(), &anoncreds::gvt_schema_json());
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
         }
 
         #[test]
-        #[ignore] // TODO: Libindy doesn't aware about algorithm used for encoding of attribute values. We can do this check only on application level.
-        fn verifier_verify_proof_works_for_wrong_raw() {
-            let proof_json = anoncreds::proof_json().replace(r#"raw":"Alex"#, r#"raw":"Bob"#);
+        fn credential_from_w3c_works_for_tampered_attribute_value() {
+            let (_, _, _, credential_json) = anoncreds::init_common_wallet();
 
-            let res = anoncreds::verifier_verify_proof(&anoncreds::proof_request_attr(),
-                                                       &proof_json,
-                                                       &anoncreds::schemas_for_proof(),
-                                                       &anoncreds::cred_defs_for_proof(),
-                                                       "{}",
-                                                       "{}");
-            assert_code!(ErrorCode::AnoncredsProofRejected, res);
+            let w3c_cred_json = anoncreds::credential_to_w3c(credential_json, ISSUANCE_DATE).unwrap();
+            let mut w3c_cred: serde_json::Value = serde_json::from_str(&w3c_cred_json).unwrap();
+            w3c_cred["credentialSubject"]["name"] = json!("Bob");
+
+            let res = anoncreds::credential_from_w3c(&w3c_cred.to_string(), &anoncreds::gvt_schema_json());
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
         }
 
         #[test]
-        fn verifier_verify_proof_works_for_revealed_attr_case_insensitive() {
-            let proof_req_json = anoncreds::proof_request_attr().replace(r#""name":"name""#, r#""name":"NAME""#);
+        fn credential_from_w3c_works_for_value_not_encoded_as_declared_type() {
+            let (_, _, _, credential_json) = anoncreds::init_common_wallet();
 
-            let valid = anoncreds::verifier_verify_proof(&proof_req_json,
-                                                         &anoncreds::proof_json(),
-                                                         &anoncreds::schemas_for_proof(),
-                                                         &anoncreds::cred_defs_for_proof(),
-                                                         "{}",
-                                                         "{}").unwrap();
-            assert!(valid);
+            let w3c_cred_json = anoncreds::credential_to_w3c(credential_json, ISSUANCE_DATE).unwrap();
+
+            // "28" is signed with the default encoding, which is not the encoding of a date
+            let mut schema: serde_json::Value = serde_json::from_str(&anoncreds::gvt_schema_json()).unwrap();
+            schema["attrTypes"] = json!({"age": "date"});
+
+            let res = anoncreds::credential_from_w3c(&w3c_cred_json, &schema.to_string());
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
         }
 
         #[test]
-        fn verifier_verify_proof_works_for_proof_with_names() {
-            let other_proof_req_json = json!({
-                "nonce":"123432421212",
-                "name":"proof_req_1",
-                "version":"0.1",
-                "requested_attributes": {
-                    "attr1_referent": {
-                        "names":["name", "age"],
-                        "revealed": "true"
-                    }
-                },
-                "requested_predicates": {},
-            }).to_string();
+        fn credential_from_w3c_works_for_other_schema() {
+            let (_, _, _, credential_json) = anoncreds::init_common_wallet();
 
-            let valid = anoncreds::verifier_verify_proof(&other_proof_req_json,
-                                                         &anoncreds::proof_json_names(),
-                                                         &anoncreds::schema_names(),
-                                                         &anoncreds::cred_defs_names(),
-                                                         "{}",
-                                                         "{}").unwrap();
-            assert!(valid);
+            let w3c_cred_json = anoncreds::credential_to_w3c(credential_json, ISSUANCE_DATE).unwrap();
+
+            let res = anoncreds::credential_from_w3c(&w3c_cred_json, &anoncreds::xyz_schema_json());
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn credential_from_w3c_works_for_unsupported_proof_type() {
+            let (_, _, _, credential_json) = anoncreds::init_common_wallet();
+
+            let w3c_cred_json = anoncreds::credential_to_w3c(credential_json, ISSUANCE_DATE).unwrap();
+            let mut w3c_cred: serde_json::Value = serde_json::from_str(&w3c_cred_json).unwrap();
+            w3c_cred["proof"]["type"] = json!("Ed25519Signature2018");
+
+            let res = anoncreds::credential_from_w3c(&w3c_cred.to_string(), &anoncreds::gvt_schema_json());
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
         }
     }
 
-    #[test]
-    fn verifier_verify_proof_works_for_proof_with_names_in_different_credentials() {
-        let other_proof_req_json = json!({
-                "nonce":"123432421212",
-                "name":"proof_req_1",
-                "version":"0.1",
-                "requested_attributes": {
-                    "attr1_referent": {
-                        "names":["name", "age"],
-                        "revealed": "true",
+    mod proof_to_w3c {
+        use super::*;
 
-                    }
-                },
-                "requested_predicates": {},
+        #[test]
+        fn proof_to_w3c_works() {
+            let w3c_presentation_json = anoncreds::proof_to_w3c(&anoncreds::proof_request_attr(), &anoncreds::proof_json(), ISSUANCE_DATES).unwrap();
+            let w3c_presentation: serde_json::Value = serde_json::from_str(&w3c_presentation_json).unwrap();
+
+            assert_eq!(json!(["VerifiablePresentation", "AnonCredsPresentation"]), w3c_presentation["type"]);
+            assert_eq!("123432421212", w3c_presentation["proof"]["challenge"].as_str().unwrap());
+
+            let credentials = w3c_presentation["verifiableCredential"].as_array().unwrap();
+            assert_eq!(1, credentials.len());
+            assert_eq!(json!({"name": "Alex"}), credentials[0]["credentialSubject"]);
+            assert_eq!(ISSUANCE_DATE, credentials[0]["issuanceDate"].as_str().unwrap());
+            assert_eq!("NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0", credentials[0]["credentialSchema"]["schema"].as_str().unwrap());
+        }
+
+        #[test]
+        fn proof_to_w3c_works_for_proof_request_not_matching_proof() {
+            let proof_req_json = json!({
+                "nonce": "123432421212",
+                "name": "proof_req_1",
+                "version": "0.1",
+                "requested_attributes": {},
+                "requested_predicates": {}
             }).to_string();
 
-        let valid = anoncreds::verifier_verify_proof(&other_proof_req_json,
-                                                     &anoncreds::proof_json_names_diff_creds(),
-                                                     &anoncreds::schema_names(),
-                                                     &anoncreds::cred_defs_names(),
-                                                     "{}",
-                                                     "{}").unwrap();
-        assert!(!valid);
+            let res = anoncreds::proof_to_w3c(&proof_req_json, &anoncreds::proof_json(), ISSUANCE_DATES);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn proof_to_w3c_works_for_issuance_dates_not_matching_proof() {
+            let issuance_dates = json!([ISSUANCE_DATE, ISSUANCE_DATE]).to_string();
+
+            let res = anoncreds::proof_to_w3c(&anoncreds::proof_request_attr(), &anoncreds::proof_json(), &issuance_dates);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
     }
 
-    mod verifier_verify_proof_with_proof_req_restrictions {
+    mod proof_from_w3c {
         use super::*;
 
         #[test]
-        fn verifier_verify_proof_successful() {
-            let valid = anoncreds::verifier_verify_proof(&anoncreds::proof_request_restrictions(),
-                                                         &anoncreds::proof_json_restrictions(),
-                                                         &anoncreds::schemas_for_proof_restrictions(),
-                                                         &anoncreds::cred_defs_for_proof_restrictions(),
+        fn proof_from_w3c_works() {
+            let w3c_presentation_json = anoncreds::proof_to_w3c(&anoncreds::proof_request_attr(), &anoncreds::proof_json(), ISSUANCE_DATES).unwrap();
+            let proof_json = anoncreds::proof_from_w3c(&w3c_presentation_json).unwrap();
+
+            let proof: serde_json::Value = serde_json::from_str(&proof_json).unwrap();
+            let expected_proof: serde_json::Value = serde_json::from_str(&anoncreds::proof_json(), ISSUANCE_DATES).unwrap();
+            assert_eq!(expected_proof["identifiers"], proof["identifiers"]);
+            assert_eq!(expected_proof["requested_proof"]["revealed_attrs"], proof["requested_proof"]["revealed_attrs"]);
+
+            let valid = anoncreds::verifier_verify_proof(&anoncreds::proof_request_attr(),
+                                                         &proof_json,
+                                                         &anoncreds::schemas_for_proof(),
+                                                         &anoncreds::cred_defs_for_proof(),
                                                          "{}",
                                                          "{}").unwrap();
             assert!(valid);
         }
 
         #[test]
-        fn verifier_verify_proof_success_for_valid_issuer_did() {
-            let proof_req = json!({
-                   "nonce":"123432421212",
-                   "name":"proof_req_1",
-                   "version":"0.1",
-                   "requested_attributes": {
-                       "attr1_referent": {
-                           "name":"name",
-                           "restrictions": { "issuer_did": { "$in": [ISSUER_DID] } }
-                       }
-                   },
-                   "requested_predicates": {},
-                }).to_string();
+        fn proof_from_w3c_works_for_invalid_proof_value() {
+            let w3c_presentation_json = anoncreds::proof_to_w3c(&anoncreds::proof_request_attr(), &anoncreds::proof_json(), ISSUANCE_DATES).unwrap();
+            let mut w3c_presentation: serde_json::Value = serde_json::from_str(&w3c_presentation_json).unwrap();
+            w3c_presentation["proof"]["proofValue"] = json!("invalid");
 
-            let valid = anoncreds::verifier_verify_proof(&proof_req,
-                                                         &anoncreds::proof_json(),
-                                                         &anoncreds::schemas_for_proof(),
-                                                         &anoncreds::cred_defs_for_proof(),
-                                                         "{}",
-                                                         "{}").unwrap();
+            let res = anoncreds::proof_from_w3c(&w3c_presentation.to_string());
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
+    mod verifier_verify_w3c_presentation {
+        use super::*;
+
+        #[test]
+        fn verifier_verify_w3c_presentation_works() {
+            let w3c_presentation_json = anoncreds::proof_to_w3c(&anoncreds::proof_request_attr(), &anoncreds::proof_json(), ISSUANCE_DATES).unwrap();
+
+            let valid = anoncreds::verifier_verify_w3c_presentation(&anoncreds::proof_request_attr(),
+                                                                    &w3c_presentation_json,
+                                                                    &anoncreds::schemas_for_proof(),
+                                                                    &anoncreds::cred_defs_for_proof(),
+                                                                    "{}",
+                                                                    "{}").unwrap();
             assert!(valid);
         }
 
         #[test]
-        fn verifier_verify_proof_fails_for_missing_issuer_did() {
-            let proof_req = json!({
-                   "nonce":"123432421212",
-                   "name":"proof_req_1",
-                   "version":"0.1",
-                   "requested_attributes": {
-                       "attr1_referent": {
-                           "name":"name",
-                           "restrictions": { "issuer_did": { "$in": ["NO DID"] } }
-                       }
-                   },
-                   "requested_predicates": {},
-                }).to_string();
+        fn verifier_verify_w3c_presentation_works_for_changed_credential_subject() {
+            let w3c_presentation_json = anoncreds::proof_to_w3c(&anoncreds::proof_request_attr(), &anoncreds::proof_json(), ISSUANCE_DATES).unwrap();
+            let mut w3c_presentation: serde_json::Value = serde_json::from_str(&w3c_presentation_json).unwrap();
+            w3c_presentation["verifiableCredential"][0]["credentialSubject"]["name"] = json!("Bob");
 
-            let valid = anoncreds::verifier_verify_proof(&proof_req,
-                                                         &anoncreds::proof_json(),
-                                                         &anoncreds::schemas_for_proof(),
-                                                         &anoncreds::cred_defs_for_proof(),
-                                                         "{}",
-                                                         "{}");
-            assert_code!(ErrorCode::AnoncredsProofRejected , valid);
+            let res = anoncreds::verifier_verify_w3c_presentation(&anoncreds::proof_request_attr(),
+                                                                  &w3c_presentation.to_string(),
+                                                                  &anoncreds::schemas_for_proof(),
+                                                                  &anoncreds::cred_defs_for_proof(),
+                                                                  "{}",
+                                                                  "{}");
+            assert_code!(ErrorCode::AnoncredsProofRejected, res);
         }
 
         #[test]
-        fn verifier_verify_proof_fails_for_missing_predicate_restriction() {
-            let proof_req = json!({
-               "nonce":"123432421212",
-               "name":"proof_req_1",
-               "version":"0.1",
-               "requested_attributes": {},
-               "requested_predicates": {
-                    "attr1_referent": {
-                    "name":"age", "p_type":">=", "p_value":18, "restrictions": { "schema_id": "Not HERE" }}
+        fn verifier_verify_w3c_presentation_works_for_other_nonce() {
+            let w3c_presentation_json = anoncreds::proof_to_w3c(&anoncreds::proof_request_attr(), &anoncreds::proof_json(), ISSUANCE_DATES).unwrap();
 
-               },
-            }).to_string();
+            let mut proof_req: serde_json::Value = serde_json::from_str(&anoncreds::proof_request_attr()).unwrap();
+            proof_req["nonce"] = json!("1111111111");
+
+            let valid = anoncreds::verifier_verify_w3c_presentation(&proof_req.to_string(),
+                                                                    &w3c_presentation_json,
+                                                                    &anoncreds::schemas_for_proof(),
+                                                                    &anoncreds::cred_defs_for_proof(),
+                                                                    "{}",
+                                                                    "{}").unwrap();
+            assert!(!valid);
+        }
+
+        #[test]
+        fn verifier_verify_w3c_presentation_works_for_not_presentation() {
+            let (_, _, _, credential_json) = anoncreds::init_common_wallet();
+            let w3c_cred_json = anoncreds::credential_to_w3c(credential_json, ISSUANCE_DATE).unwrap();
+
+            let res = anoncreds::verifier_verify_w3c_presentation(&anoncreds::proof_request_attr(),
+                                                                  &w3c_cred_json,
+                                                                  &anoncreds::schemas_for_proof(),
+                                                                  &anoncreds::cred_defs_for_proof(),
+                                                                  "{}",
+                                                                  "{}");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
 
-            let mut proof: Proof = serde_json::from_str(&anoncreds::proof_json()).unwrap();
-            proof.requested_proof.revealed_attrs.remove("attr1_referent").unwrap();
-            proof.requested_proof.predicates.insert(
-                "attr1_referent".to_string(),
-                serde_json::from_str(&json!({ "sub_proof_index": 0 }).to_string()).unwrap(),
-            );
+    mod verifier_verify_proof_detailed {
+        use super::*;
 
+        #[test]
+        fn verifier_verify_proof_detailed_works_for_correct_proof() {
+            let report = anoncreds::verifier_verify_proof_detailed(&anoncreds::proof_request_attr(),
+                                                                   &anoncreds::proof_json(),
+                                                                   &anoncreds::schemas_for_proof(),
+                                                                   &anoncreds::cred_defs_for_proof(),
+                                                                   "{}",
+                                                                   "{}",
+                                                                   "{}").unwrap();
+            let report: serde_json::Value = serde_json::from_str(&report).unwrap();
 
-            let valid = anoncreds::verifier_verify_proof(&proof_req,
-                                                         &serde_json::to_string(&proof).unwrap(),
-                                                         &anoncreds::schemas_for_proof(),
-                                                         &anoncreds::cred_defs_for_proof(),
-                                                         "{}",
-                                                         "{}");
-            assert_code!(ErrorCode::AnoncredsProofRejected , valid);
+            assert_eq!(true, report["valid"]);
+            assert_eq!(true, report["requested_attributes"]["attr1_referent"]["valid"]);
+            assert_eq!(true, report["requested_attributes"]["attr1_referent"]["revealed"]);
+            assert_eq!(0, report["requested_attributes"]["attr1_referent"]["sub_proof_index"]);
+            assert_eq!(1, report["sub_proofs"].as_array().unwrap().len());
+            assert!(report["failures"].as_array().unwrap().is_empty());
         }
 
         #[test]
-        fn verifier_verify_proof_success_for_valid_schema_id() {
-            let proof_req = json!({
-                   "nonce":"123432421212",
-                   "name":"proof_req_1",
-                   "version":"0.1",
-                   "requested_attributes": {
-                       "attr1_referent": {
-                           "name":"name",
-                           "restrictions": { "schema_id": anoncreds::gvt_schema_id() },
-                       }
-                   },
-                   "requested_predicates": {},
-                }).to_string();
+        fn verifier_verify_proof_detailed_works_for_wrong_encoded() {
+            let proof_json = anoncreds::proof_json().replace(r#"encoded":"1139481716457488690172217916278103335"#, r#"encoded":"1111111111111111111111111111111111111"#);
 
-            let valid = anoncreds::verifier_verify_proof(&proof_req,
-                                                         &anoncreds::proof_json(),
-                                                         &anoncreds::schemas_for_proof(),
-                                                         &anoncreds::cred_defs_for_proof(),
-                                                         "{}",
-                                                         "{}").unwrap();
-            assert!(valid);
+            let report = anoncreds::verifier_verify_proof_detailed(&anoncreds::proof_request_attr(),
+                                                                   &proof_json,
+                                                                   &anoncreds::schemas_for_proof(),
+                                                                   &anoncreds::cred_defs_for_proof(),
+                                                                   "{}",
+                                                                   "{}",
+                                                                   "{}").unwrap();
+            let report: serde_json::Value = serde_json::from_str(&report).unwrap();
+
+            assert_eq!(false, report["valid"]);
+            assert_eq!(false, report["requested_attributes"]["attr1_referent"]["valid"]);
+            assert_eq!("encoded_value_mismatch", report["requested_attributes"]["attr1_referent"]["failures"][0]["reason"]);
         }
 
         #[test]
-        fn verifier_verify_proof_fails_for_missing_schema_id() {
-            let proof_req = json!({
-                   "nonce":"123432421212",
-                   "name":"proof_req_1",
-                   "version":"0.1",
-                   "requested_attributes": {
-                       "attr1_referent": {
-                           "name":"name",
-                           "restrictions": { "schema_id": "Not HERE" },
-                       }
-                   },
-                   "requested_predicates": {},
-                }).to_string();
+        fn verifier_verify_proof_detailed_works_for_check_raw_encoding_option() {
+            // encoded value of "name" in the proof is not produced by the standard encoding
+            let report = anoncreds::verifier_verify_proof_detailed(&anoncreds::proof_request_attr(),
+                                                                   &anoncreds::proof_json(),
+                                                                   &anoncreds::schemas_for_proof(),
+                                                                   &anoncreds::cred_defs_for_proof(),
+                                                                   "{}",
+                                                                   "{}",
+                                                                   r#"{"check_raw_encoding": true}"#).unwrap();
+            let report: serde_json::Value = serde_json::from_str(&report).unwrap();
 
-            let valid = anoncreds::verifier_verify_proof(&proof_req,
-                                                         &anoncreds::proof_json(),
-                                                         &anoncreds::schemas_for_proof(),
-                                                         &anoncreds::cred_defs_for_proof(),
-                                                         "{}",
-                                                         "{}");
-            assert_code!(ErrorCode::AnoncredsProofRejected , valid);
+            assert_eq!(false, report["valid"]);
+            assert_eq!("raw_value_mismatch", report["requested_attributes"]["attr1_referent"]["failures"][0]["reason"]);
         }
 
         #[test]
-        fn verifier_verify_proof_success_for_valid_schema_issuer_did() {
-            let proof_req = json!({
-                   "nonce":"123432421212",
-                   "name":"proof_req_1",
-                   "version":"0.1",
-                   "requested_attributes": {
-                       "attr1_referent": {
-                           "name":"name",
-                           "restrictions": { "schema_issuer_did": ISSUER_DID },
-                       }
-                   },
-                   "requested_predicates": {},
-                }).to_string();
+        fn verifier_verify_proof_detailed_works_for_attribute_not_revealed_in_crypto_proof() {
+            let proof_req_json = anoncreds::proof_request_attr().replace(r#""name":"name""#, r#""name":"sex""#);
 
-            let valid = anoncreds::verifier_verify_proof(&proof_req,
-                                                         &anoncreds::proof_json(),
-                                                         &anoncreds::schemas_for_proof(),
-                                                         &anoncreds::cred_defs_for_proof(),
-                                                         "{}",
-                                                         "{}").unwrap();
-            assert!(valid);
+            let report = anoncreds::verifier_verify_proof_detailed(&proof_req_json,
+                                                                   &anoncreds::proof_json(),
+                                                                   &anoncreds::schemas_for_proof(),
+                                                                   &anoncreds::cred_defs_for_proof(),
+                                                                   "{}",
+                                                                   "{}",
+                                                                   "{}").unwrap();
+            let report: serde_json::Value = serde_json::from_str(&report).unwrap();
+
+            assert_eq!(false, report["valid"]);
+            assert_eq!("revealed_attribute_mismatch", report["requested_attributes"]["attr1_referent"]["failures"][0]["reason"]);
+
+            let res = anoncreds::verifier_verify_proof(&proof_req_json,
+                                                       &anoncreds::proof_json(),
+                                                       &anoncreds::schemas_for_proof(),
+                                                       &anoncreds::cred_defs_for_proof(),
+                                                       "{}",
+                                                       "{}");
+            assert_code!(ErrorCode::AnoncredsProofRejected, res);
         }
 
         #[test]
-        fn verifier_verify_proof_fails_for_missing_schema_issuer_did() {
+        fn verifier_verify_proof_detailed_works_for_restriction_mismatch() {
             let proof_req = json!({
                    "nonce":"123432421212",
                    "name":"proof_req_1",
@@ -3518,186 +5109,157 @@ mod high_cases {
                    "requested_attributes": {
                        "attr1_referent": {
                            "name":"name",
-                           "restrictions": { "schema_issuer_did": "Not HERE" },
+                           "restrictions": { "issuer_did": { "$in": ["NO DID"] } }
                        }
                    },
                    "requested_predicates": {},
                 }).to_string();
 
-            let valid = anoncreds::verifier_verify_proof(&proof_req,
-                                                         &anoncreds::proof_json(),
-                                                         &anoncreds::schemas_for_proof(),
-                                                         &anoncreds::cred_defs_for_proof(),
-                                                         "{}",
-                                                         "{}");
-            assert_code!(ErrorCode::AnoncredsProofRejected , valid);
+            let report = anoncreds::verifier_verify_proof_detailed(&proof_req,
+                                                                   &anoncreds::proof_json(),
+                                                                   &anoncreds::schemas_for_proof(),
+                                                                   &anoncreds::cred_defs_for_proof(),
+                                                                   "{}",
+                                                                   "{}",
+                                                                   "{}").unwrap();
+            let report: serde_json::Value = serde_json::from_str(&report).unwrap();
+
+            assert_eq!(false, report["valid"]);
+            assert_eq!("restriction_mismatch", report["requested_attributes"]["attr1_referent"]["failures"][0]["reason"]);
+            assert!(report["failures"].as_array().unwrap().is_empty());
         }
 
         #[test]
-        fn verifier_verify_proof_success_for_valid_schema_name() {
-            let proof_req = json!({
-                   "nonce":"123432421212",
-                   "name":"proof_req_1",
-                   "version":"0.1",
-                   "requested_attributes": {
-                       "attr1_referent": {
-                           "name":"name",
-                           "restrictions": { "schema_name": GVT_SCHEMA_NAME },
-                       }
-                   },
-                   "requested_predicates": {},
-                }).to_string();
+        fn verifier_verify_proof_detailed_works_for_missing_cred_def() {
+            let report = anoncreds::verifier_verify_proof_detailed(&anoncreds::proof_request_attr(),
+                                                                   &anoncreds::proof_json(),
+                                                                   &anoncreds::schemas_for_proof(),
+                                                                   "{}",
+                                                                   "{}",
+                                                                   "{}",
+                                                                   "{}").unwrap();
+            let report: serde_json::Value = serde_json::from_str(&report).unwrap();
 
-            let valid = anoncreds::verifier_verify_proof(&proof_req,
-                                                         &anoncreds::proof_json(),
-                                                         &anoncreds::schemas_for_proof(),
-                                                         &anoncreds::cred_defs_for_proof(),
-                                                         "{}",
-                                                         "{}").unwrap();
-            assert!(valid);
+            assert_eq!(false, report["valid"]);
+            assert_eq!(true, report["requested_attributes"]["attr1_referent"]["valid"]);
+            assert_eq!("missing_credential_definition", report["sub_proofs"][0]["failures"][0]["reason"]);
         }
 
         #[test]
-        fn verifier_verify_proof_fails_for_missing_schema_name() {
+        fn verifier_verify_proof_detailed_works_for_missing_referent() {
             let proof_req = json!({
                    "nonce":"123432421212",
                    "name":"proof_req_1",
                    "version":"0.1",
                    "requested_attributes": {
-                       "attr1_referent": {
-                           "name":"name",
-                           "restrictions": { "schema_name": "Not HERE" },
-                       }
+                       "attr1_referent": { "name":"name" },
+                       "attr2_referent": { "name":"sex" }
                    },
                    "requested_predicates": {},
                 }).to_string();
 
-            let valid = anoncreds::verifier_verify_proof(&proof_req,
-                                                         &anoncreds::proof_json(),
-                                                         &anoncreds::schemas_for_proof(),
-                                                         &anoncreds::cred_defs_for_proof(),
-                                                         "{}",
-                                                         "{}");
-            assert_code!(ErrorCode::AnoncredsProofRejected , valid);
+            let report = anoncreds::verifier_verify_proof_detailed(&proof_req,
+                                                                   &anoncreds::proof_json(),
+                                                                   &anoncreds::schemas_for_proof(),
+                                                                   &anoncreds::cred_defs_for_proof(),
+                                                                   "{}",
+                                                                   "{}",
+                                                                   "{}").unwrap();
+            let report: serde_json::Value = serde_json::from_str(&report).unwrap();
+
+            assert_eq!(false, report["valid"]);
+            assert_eq!(true, report["requested_attributes"]["attr1_referent"]["valid"]);
+            assert_eq!("missing_referent", report["requested_attributes"]["attr2_referent"]["failures"][0]["reason"]);
         }
+    }
+
+    mod verifier_build_proof_request {
+        use super::*;
 
         #[test]
-        fn verifier_verify_proof_success_for_valid_schema_version() {
-            let proof_req = json!({
-                   "nonce":"123432421212",
-                   "name":"proof_req_1",
-                   "version":"0.1",
-                   "requested_attributes": {
-                       "attr1_referent": {
-                           "name":"name",
-                           "restrictions": { "schema_version": SCHEMA_VERSION },
-                       }
-                   },
-                   "requested_predicates": {},
-                }).to_string();
+        fn verifier_build_proof_request_works() {
+            let template = json!({
+                "name": "proof_req_1",
+                "attributes": [{"name": "name"}],
+                "predicates": [{"name": "age", "p_type": ">=", "p_value": 18}],
+                "cred_def_ids": [anoncreds::issuer_1_gvt_cred_def_id()]
+            }).to_string();
 
-            let valid = anoncreds::verifier_verify_proof(&proof_req,
-                                                         &anoncreds::proof_json(),
-                                                         &anoncreds::schemas_for_proof(),
-                                                         &anoncreds::cred_defs_for_proof(),
-                                                         "{}",
-                                                         "{}").unwrap();
-            assert!(valid);
+            let proof_req_json = anoncreds::verifier_build_proof_request(&template).unwrap();
+            let proof_req: serde_json::Value = serde_json::from_str(&proof_req_json).unwrap();
+
+            assert_eq!("2.0", proof_req["ver"]);
+            assert_eq!("proof_req_1", proof_req["name"]);
+            assert!(proof_req["nonce"].as_str().unwrap().parse::<u128>().is_ok());
+            assert_eq!(json!({"cred_def_id": anoncreds::issuer_1_gvt_cred_def_id()}), proof_req["requested_attributes"]["attr1_referent"]["restrictions"]);
+            assert_eq!(json!({"cred_def_id": anoncreds::issuer_1_gvt_cred_def_id()}), proof_req["requested_predicates"]["predicate1_referent"]["restrictions"]);
+            assert_eq!(proof_req.to_string(), proof_req_json);
         }
 
         #[test]
-        fn verifier_verify_proof_fails_for_missing_schema_version() {
-            let proof_req = json!({
-                   "nonce":"123432421212",
-                   "name":"proof_req_1",
-                   "version":"0.1",
-                   "requested_attributes": {
-                       "attr1_referent": {
-                           "name":"name",
-                           "restrictions": { "schema_version": "Not HERE" },
-                       }
-                   },
-                   "requested_predicates": {},
-                }).to_string();
+        fn verifier_build_proof_request_works_for_getting_credentials() {
+            anoncreds::init_common_wallet();
 
-            let valid = anoncreds::verifier_verify_proof(&proof_req,
-                                                         &anoncreds::proof_json(),
-                                                         &anoncreds::schemas_for_proof(),
-                                                         &anoncreds::cred_defs_for_proof(),
-                                                         "{}",
-                                                         "{}");
-            assert_code!(ErrorCode::AnoncredsProofRejected , valid);
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let template = json!({
+                "attributes": [{"name": "name"}],
+                "issuer_dids": [ISSUER_DID]
+            }).to_string();
+
+            let proof_req_json = anoncreds::verifier_build_proof_request(&template).unwrap();
+
+            let credentials_json = anoncreds::prover_get_credentials_for_proof_req(wallet_handle, &proof_req_json).unwrap();
+            let credentials: CredentialsForProofRequest = serde_json::from_str(&credentials_json).unwrap();
+            let credentials_for_attr_1 = credentials.attrs.get("attr1_referent").unwrap();
+            assert_eq!(credentials_for_attr_1.len(), 1);
+            assert_eq!(anoncreds::issuer_1_gvt_credential(), credentials_for_attr_1[0].cred_info);
+
+            wallet::close_wallet(wallet_handle).unwrap();
         }
 
         #[test]
-        fn verifier_verify_proof_success_for_valid_cred_def_id() {
-            let proof_req = json!({
-                   "nonce":"123432421212",
-                   "name":"proof_req_1",
-                   "version":"0.1",
-                   "requested_attributes": {
-                       "attr1_referent": {
-                           "name":"name",
-                           "restrictions": { "cred_def_id": anoncreds::issuer_1_gvt_cred_def_id() },
-                       }
-                   },
-                   "requested_predicates": {},
-                }).to_string();
+        fn verifier_build_proof_request_works_for_qualified_identifiers() {
+            let template = json!({
+                "attributes": [{"name": "name"}],
+                "issuer_dids": [ISSUER_DID],
+                "identifiers": "qualified"
+            }).to_string();
 
-            let valid = anoncreds::verifier_verify_proof(&proof_req,
-                                                         &anoncreds::proof_json(),
-                                                         &anoncreds::schemas_for_proof(),
-                                                         &anoncreds::cred_defs_for_proof(),
-                                                         "{}",
-                                                         "{}").unwrap();
-            assert!(valid);
+            let proof_req_json = anoncreds::verifier_build_proof_request(&template).unwrap();
+            let proof_req: serde_json::Value = serde_json::from_str(&proof_req_json).unwrap();
+
+            assert_eq!(json!({"issuer_did": ISSUER_DID_V1}), proof_req["requested_attributes"]["attr1_referent"]["restrictions"]);
         }
 
         #[test]
-        fn verifier_verify_proof_fails_for_missing_cred_def_id() {
-            let proof_req = json!({
-                   "nonce":"123432421212",
-                   "name":"proof_req_1",
-                   "version":"0.1",
-                   "requested_attributes": {
-                       "attr1_referent": {
-                           "name":"name",
-                           "restrictions": { "cred_def_id": "Not HERE" },
-                       }
-                   },
-                   "requested_predicates": {},
-                }).to_string();
+        fn verifier_build_proof_request_works_for_freshness() {
+            let template = json!({
+                "attributes": [{"name": "name"}],
+                "freshness": 3600
+            }).to_string();
 
-            let valid = anoncreds::verifier_verify_proof(&proof_req,
-                                                         &anoncreds::proof_json(),
-                                                         &anoncreds::schemas_for_proof(),
-                                                         &anoncreds::cred_defs_for_proof(),
-                                                         "{}",
-                                                         "{}");
-            assert_code!(ErrorCode::AnoncredsProofRejected , valid);
+            let proof_req_json = anoncreds::verifier_build_proof_request(&template).unwrap();
+            let proof_req: serde_json::Value = serde_json::from_str(&proof_req_json).unwrap();
+
+            let from = proof_req["non_revoked"]["from"].as_u64().unwrap();
+            let to = proof_req["non_revoked"]["to"].as_u64().unwrap();
+            assert_eq!(3600, to - from);
         }
 
         #[test]
-        fn verifier_verify_proof_fails_for_unknown_restriction() {
-            let proof_req = json!({
-                   "nonce":"123432421212",
-                   "name":"proof_req_1",
-                   "version":"0.1",
-                   "requested_attributes": {
-                       "attr1_referent": {
-                           "name":"name",
-                           "restrictions": { "UNKNOWN": "Not HERE" },
-                       }
-                   },
-                   "requested_predicates": {},
-                }).to_string();
+        fn verifier_build_proof_request_works_for_unsupported_restriction() {
+            let template = json!({
+                "attributes": [{"name": "name", "restrictions": {"schema_version": {"$like": "1.%"}}}]
+            }).to_string();
 
-            let res = anoncreds::verifier_verify_proof(&proof_req,
-                                                       &anoncreds::proof_json(),
-                                                       &anoncreds::schemas_for_proof(),
-                                                       &anoncreds::cred_defs_for_proof(),
-                                                       "{}",
-                                                       "{}");
+            let res = anoncreds::verifier_build_proof_request(&template);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn verifier_build_proof_request_works_for_empty_template() {
+            let res = anoncreds::verifier_build_proof_request("{}");
             assert_code!(ErrorCode::CommonInvalidStructure, res);
         }
     }
@@ -2327,6 +2327,293 @@ mod demos {
     }
 
 
+    #[cfg(feature = "revocation_tests")]
+    #[test]
+    fn anoncreds_works_for_revocation_registry_creation_with_progress() {
+        Setup::empty();
+
+        //1. Issuer creates wallet, gets wallet handle
+        let (issuer_wallet_handle, issuer_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_revocation_registry_creation_with_progress").unwrap();
+
+        //2. Prover creates wallet, gets wallet handle
+        let (prover_wallet_handle, prover_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_revocation_registry_creation_with_progress").unwrap();
+
+        //3. Issuer creates Schema and Credential Definition
+        let (_, schema_json) = anoncreds::issuer_create_schema(ISSUER_DID,
+                                                               GVT_SCHEMA_NAME,
+                                                               SCHEMA_VERSION,
+                                                               GVT_SCHEMA_ATTRIBUTES).unwrap();
+
+        let (cred_def_id, cred_def_json) = anoncreds::issuer_create_credential_definition(issuer_wallet_handle,
+                                                                                          ISSUER_DID,
+                                                                                          &schema_json,
+                                                                                          TAG_1,
+                                                                                          None,
+                                                                                          Some(&anoncreds::revocation_cred_def_config())).unwrap();
+
+        //4. Issuer creates Revocation Registry which tails are generated by several chunks
+        let tails_writer_config = anoncreds::tails_writer_config();
+        let tails_writer_handle = utils::blob_storage::open_writer("default", &tails_writer_config).unwrap();
+
+        let progress_calls = ::std::sync::Arc::new(::std::sync::Mutex::new(Vec::new()));
+        let progress_calls_ = progress_calls.clone();
+
+        let (rev_reg_id, revoc_reg_def_json, _) =
+            anoncreds::issuer_create_and_store_revoc_reg_with_progress(issuer_wallet_handle,
+                                                                       &ISSUER_DID,
+                                                                       None,
+                                                                       TAG_1,
+                                                                       &cred_def_id,
+                                                                       r#"{"max_cred_num":1500, "issuance_type":"ISSUANCE_BY_DEFAULT"}"#,
+                                                                       tails_writer_handle,
+                                                                       Box::new(move |tails_written, tails_total| {
+                                                                           progress_calls_.lock().unwrap().push((tails_written, tails_total));
+                                                                       })).unwrap();
+
+        let progress_calls = progress_calls.lock().unwrap().clone();
+        assert_eq!(3, progress_calls.len());
+        assert!(progress_calls.windows(2).all(|calls| calls[0].0 < calls[1].0));
+        assert_eq!(Some(&(3001, 3001)), progress_calls.last());
+
+        //5. Issuer issues Credential with created Revocation Registry
+        let blob_storage_reader_handle = utils::blob_storage::open_reader(TYPE, &tails_writer_config).unwrap();
+
+        anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+        let (cred_rev_id, _) = anoncreds::multi_steps_create_revocation_credential(COMMON_MASTER_SECRET,
+                                                                                   prover_wallet_handle,
+                                                                                   issuer_wallet_handle,
+                                                                                   CREDENTIAL1_ID,
+                                                                                   &anoncreds::gvt_credential_values_json(),
+                                                                                   &cred_def_id,
+                                                                                   &cred_def_json,
+                                                                                   &rev_reg_id,
+                                                                                   &revoc_reg_def_json,
+                                                                                   blob_storage_reader_handle);
+
+        //6. Issuer revokes Credential
+        anoncreds::issuer_revoke_credential(issuer_wallet_handle, blob_storage_reader_handle, &rev_reg_id, &cred_rev_id).unwrap();
+
+        wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
+        wallet::close_and_delete_wallet(prover_wallet_handle, &prover_wallet_config).unwrap();
+    }
+
+    #[cfg(feature = "revocation_tests")]
+    #[test]
+    fn anoncreds_works_for_revocation_by_issuance_record() {
+        Setup::empty();
+
+        //1. Issuer creates wallet, gets wallet handle
+        let (issuer_wallet_handle, issuer_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_revocation_by_issuance_record").unwrap();
+
+        //2. Prover creates wallet, gets wallet handle
+        let (prover_wallet_handle, prover_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_revocation_by_issuance_record").unwrap();
+
+        //3. Issuer creates Schema, Credential Definition and Revocation Registry
+        let (_, _,
+            cred_def_id, cred_def_json,
+            rev_reg_id, _, _,
+            blob_storage_reader_handle) = anoncreds::multi_steps_issuer_revocation_preparation(issuer_wallet_handle,
+                                                                                               ISSUER_DID,
+                                                                                               GVT_SCHEMA_NAME,
+                                                                                               GVT_SCHEMA_ATTRIBUTES,
+                                                                                               r#"{"max_cred_num":5, "issuance_type":"ISSUANCE_ON_DEMAND"}"#);
+
+        //4. Prover creates Master Secret
+        anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+        //5. Issuer creates Credential Offer, Prover creates Credential Request
+        let cred_offer_json = anoncreds::issuer_create_credential_offer(issuer_wallet_handle, &cred_def_id).unwrap();
+
+        let (cred_req_json, _) = anoncreds::prover_create_credential_req(prover_wallet_handle,
+                                                                         DID_MY1,
+                                                                         &cred_offer_json,
+                                                                         &cred_def_json,
+                                                                         COMMON_MASTER_SECRET).unwrap();
+
+        //6. Issuer can't use reserved tags in issuance record
+        let res = anoncreds::issuer_create_credential_with_record(issuer_wallet_handle,
+                                                                  &cred_offer_json,
+                                                                  &cred_req_json,
+                                                                  &anoncreds::gvt_credential_values_json(),
+                                                                  Some(&rev_reg_id),
+                                                                  Some(blob_storage_reader_handle),
+                                                                  r#"{"id":"record_1", "tags":{"revoked":"1"}}"#);
+        assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+        //7. Issuer creates Credential with issuance record
+        let (_, cred_rev_id, _) = anoncreds::issuer_create_credential_with_record(issuer_wallet_handle,
+                                                                                  &cred_offer_json,
+                                                                                  &cred_req_json,
+                                                                                  &anoncreds::gvt_credential_values_json(),
+                                                                                  Some(&rev_reg_id),
+                                                                                  Some(blob_storage_reader_handle),
+                                                                                  r#"{"id":"record_1", "tags":{"connection_id":"conn_1"}}"#).unwrap();
+        let cred_rev_id = cred_rev_id.unwrap();
+
+        //8. Issuer can't reuse issuance record id
+        let res = anoncreds::issuer_create_credential_with_record(issuer_wallet_handle,
+                                                                  &cred_offer_json,
+                                                                  &cred_req_json,
+                                                                  &anoncreds::gvt_credential_values_json(),
+                                                                  Some(&rev_reg_id),
+                                                                  Some(blob_storage_reader_handle),
+                                                                  r#"{"id":"record_1"}"#);
+        assert_code!(ErrorCode::WalletItemAlreadyExists, res);
+
+        //9. Issuer gets issuance record
+        let record_json = anoncreds::issuer_get_issuance_record(issuer_wallet_handle, "record_1").unwrap();
+        let record: serde_json::Value = serde_json::from_str(&record_json).unwrap();
+
+        assert_eq!(json!(cred_def_id), record["cred_def_id"]);
+        assert_eq!(json!(rev_reg_id), record["rev_reg_id"]);
+        assert_eq!(json!(cred_rev_id), record["cred_rev_id"]);
+        assert_eq!(json!("conn_1"), record["tags"]["connection_id"]);
+        assert_eq!(64, record["values_hash"].as_str().unwrap().len());
+        assert_eq!(64, record["values_salt"].as_str().unwrap().len());
+        assert!(record["revoked_at"].is_null());
+
+        //10. Issuer searches issuance records by caller tag
+        let (search_handle, total_count) = anoncreds::issuer_search_issuance_records(issuer_wallet_handle, r#"{"connection_id":"conn_1", "revoked":"0"}"#).unwrap();
+        assert_eq!(1, total_count);
+
+        let records_json = anoncreds::issuer_fetch_issuance_records(search_handle, 10).unwrap();
+        let records: Vec<serde_json::Value> = serde_json::from_str(&records_json).unwrap();
+        assert_eq!(1, records.len());
+        assert_eq!(json!("record_1"), records[0]["id"]);
+
+        anoncreds::issuer_close_issuance_records_search(search_handle).unwrap();
+
+        //11. Issuer revokes Credential by issuance record
+        anoncreds::issuer_revoke_credential_by_record(issuer_wallet_handle, blob_storage_reader_handle, "record_1").unwrap();
+
+        let record_json = anoncreds::issuer_get_issuance_record(issuer_wallet_handle, "record_1").unwrap();
+        let record: serde_json::Value = serde_json::from_str(&record_json).unwrap();
+        assert!(record["revoked_at"].is_u64());
+
+        let (search_handle, total_count) = anoncreds::issuer_search_issuance_records(issuer_wallet_handle, r#"{"revoked":"1"}"#).unwrap();
+        assert_eq!(1, total_count);
+        anoncreds::issuer_close_issuance_records_search(search_handle).unwrap();
+
+        //12. Issuer can't revoke Credential twice
+        let res = anoncreds::issuer_revoke_credential_by_record(issuer_wallet_handle, blob_storage_reader_handle, "record_1");
+        assert_code!(ErrorCode::AnoncredsInvalidUserRevocId, res);
+
+        wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
+        wallet::close_and_delete_wallet(prover_wallet_handle, &prover_wallet_config).unwrap();
+    }
+
+    #[cfg(feature = "revocation_tests")]
+    #[test]
+    fn anoncreds_works_for_credential_revocation_status_tracking() {
+        Setup::empty();
+
+        //1. Issuer creates wallet, gets wallet handle
+        let (issuer_wallet_handle, issuer_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_credential_revocation_status_tracking").unwrap();
+
+        //2. Prover creates wallet, gets wallet handle
+        let (prover_wallet_handle, prover_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_credential_revocation_status_tracking").unwrap();
+
+        //3. Issuer creates Schema, Credential Definition and Revocation Registry
+        let (_, _,
+            cred_def_id, cred_def_json,
+            rev_reg_id, revoc_reg_def_json, _,
+            blob_storage_reader_handle) = anoncreds::multi_steps_issuer_revocation_preparation(issuer_wallet_handle,
+                                                                                               ISSUER_DID,
+                                                                                               GVT_SCHEMA_NAME,
+                                                                                               GVT_SCHEMA_ATTRIBUTES,
+                                                                                               r#"{"max_cred_num":5, "issuance_type":"ISSUANCE_ON_DEMAND"}"#);
+
+        //4. Prover creates Master Secret
+        anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+        //5. Issuance Credential
+        let (cred_rev_id, revoc_reg_delta_json) = anoncreds::multi_steps_create_revocation_credential(
+            COMMON_MASTER_SECRET,
+            prover_wallet_handle,
+            issuer_wallet_handle,
+            CREDENTIAL1_ID,
+            &anoncreds::gvt_credential_values_json(),
+            &cred_def_id,
+            &cred_def_json,
+            &rev_reg_id,
+            &revoc_reg_def_json,
+            blob_storage_reader_handle,
+        );
+        let issued_delta_json = revoc_reg_delta_json.unwrap();
+
+        //6. Prover creates RevocationState of not revoked Credential
+        anoncreds::prover_create_credential_revocation_state(prover_wallet_handle,
+                                                             blob_storage_reader_handle,
+                                                             &revoc_reg_def_json,
+                                                             &issued_delta_json,
+                                                             100,
+                                                             CREDENTIAL1_ID).unwrap();
+
+        let status_json = anoncreds::prover_get_credential_status(prover_wallet_handle, CREDENTIAL1_ID).unwrap();
+        let status: serde_json::Value = serde_json::from_str(&status_json).unwrap();
+        assert!(!status["revoked"].as_bool().unwrap());
+        assert!(status["active"].as_bool().unwrap());
+
+        //7. Issuer revokes Credential
+        let revoked_delta_json = anoncreds::issuer_revoke_credential(issuer_wallet_handle,
+                                                                     blob_storage_reader_handle,
+                                                                     &rev_reg_id,
+                                                                     &cred_rev_id).unwrap();
+
+        let revoc_reg_delta_json = anoncreds::issuer_merge_revocation_registry_deltas(&issued_delta_json, &revoked_delta_json).unwrap();
+
+        //8. Prover creates RevocationState of revoked Credential and Credential is marked as revoked
+        anoncreds::prover_create_credential_revocation_state(prover_wallet_handle,
+                                                             blob_storage_reader_handle,
+                                                             &revoc_reg_def_json,
+                                                             &revoc_reg_delta_json,
+                                                             200,
+                                                             CREDENTIAL1_ID).unwrap();
+
+        let status_json = anoncreds::prover_get_credential_status(prover_wallet_handle, CREDENTIAL1_ID).unwrap();
+        let status: serde_json::Value = serde_json::from_str(&status_json).unwrap();
+        assert!(status["revoked"].as_bool().unwrap());
+        assert_eq!(200, status["revoked_as_of"].as_u64().unwrap());
+        assert!(!status["active"].as_bool().unwrap());
+
+        //9. Prover searches only active Credentials
+        let (search_handle, total_count) = anoncreds::prover_search_credentials(prover_wallet_handle, r#"{"status::active": "1"}"#).unwrap();
+        assert_eq!(0, total_count);
+        anoncreds::prover_close_credentials_search(search_handle).unwrap();
+
+        //10. Prover creates RevocationState with a delta issuing Credential before the revocation and Credential stays revoked
+        anoncreds::prover_create_credential_revocation_state(prover_wallet_handle,
+                                                             blob_storage_reader_handle,
+                                                             &revoc_reg_def_json,
+                                                             &issued_delta_json,
+                                                             150,
+                                                             CREDENTIAL1_ID).unwrap();
+
+        let status_json = anoncreds::prover_get_credential_status(prover_wallet_handle, CREDENTIAL1_ID).unwrap();
+        let status: serde_json::Value = serde_json::from_str(&status_json).unwrap();
+        assert!(status["revoked"].as_bool().unwrap());
+        assert_eq!(200, status["revoked_as_of"].as_u64().unwrap());
+
+        //11. Prover creates RevocationState with a delta issuing Credential after the revocation and Credential is active again
+        anoncreds::prover_create_credential_revocation_state(prover_wallet_handle,
+                                                             blob_storage_reader_handle,
+                                                             &revoc_reg_def_json,
+                                                             &issued_delta_json,
+                                                             300,
+                                                             CREDENTIAL1_ID).unwrap();
+
+        let status_json = anoncreds::prover_get_credential_status(prover_wallet_handle, CREDENTIAL1_ID).unwrap();
+        let status: serde_json::Value = serde_json::from_str(&status_json).unwrap();
+        assert!(!status["revoked"].as_bool().unwrap());
+        assert!(status["revoked_as_of"].is_null());
+        assert!(status["active"].as_bool().unwrap());
+
+        wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
+        wallet::close_and_delete_wallet(prover_wallet_handle, &prover_wallet_config).unwrap();
+    }
+
+
     #[test]
     fn anoncreds_works_for_multiple_requested_predicates_from_one_credential() {
         Setup::empty();
@@ -0,0 +1,58 @@
+#[macro_use]
+mod utils;
+
+inject_indy_dependencies!();
+
+extern crate indyrs as indy;
+extern crate indyrs as api;
+
+use crate::utils::{audit, crypto, did};
+use crate::utils::Setup;
+
+use serde_json::Value;
+
+// Audit log is global, so all checks are kept in a single test.
+#[test]
+fn indy_audit_log_works() {
+    let setup = Setup::wallet();
+
+    audit::set_audit_log(r#"{"wallet": true}"#).unwrap();
+
+    let verkey = crypto::create_key(setup.wallet_handle, None).unwrap();
+    crypto::sign(setup.wallet_handle, &verkey, "message".as_bytes()).unwrap();
+    let (did, _) = did::create_and_store_my_did(setup.wallet_handle, None).unwrap();
+
+    audit::set_audit_log(r#"{"wallet": true, "events": ["did_created"]}"#).unwrap();
+
+    crypto::create_key(setup.wallet_handle, None).unwrap();
+
+    audit::unset_audit_log().unwrap();
+
+    crypto::create_key(setup.wallet_handle, None).unwrap();
+
+    let passed_events = audit::take_events();
+    assert_eq!(passed_events.len(), 3);
+
+    let events_json = audit::get_audit_log(setup.wallet_handle, "{}").unwrap();
+    let events: Vec<Value> = serde_json::from_str(&events_json).unwrap();
+    assert_eq!(events, passed_events);
+
+    assert_eq!(events[0]["event"], "key_created");
+    assert_eq!(events[0]["verkey"], verkey);
+    assert_eq!(events[0]["seq_no"], 1);
+
+    assert_eq!(events[1]["event"], "message_signed");
+    assert!(events[1]["details"]["message_hash"].is_string());
+    assert_eq!(events[1]["prev_hash"], events[0]["hash"]);
+
+    assert_eq!(events[2]["event"], "did_created");
+    assert_eq!(events[2]["did"], did);
+
+    let events_json = audit::get_audit_log(setup.wallet_handle, r#"{"event": "message_signed"}"#).unwrap();
+    let events: Vec<Value> = serde_json::from_str(&events_json).unwrap();
+    assert_eq!(events.len(), 1);
+
+    let verification_json = audit::verify_audit_log(setup.wallet_handle).unwrap();
+    let verification: Value = serde_json::from_str(&verification_json).unwrap();
+    assert_eq!(verification, json!({"valid": true, "count": 3}));
+}
@@ -426,4 +426,244 @@ mod high_cases {
             purge_cred_def_cache(setup.wallet_handle, &json!({"minFresh": 1000}).to_string()).unwrap();
         }
     }
-}
\ No newline at end of file
+
+    mod nym_cache {
+        use super::*;
+        use crate::utils::constants::*;
+
+        #[test]
+        fn indy_get_nym_cache_works() {
+            let setup = Setup::wallet_and_pool();
+
+            let options_json = json!({}).to_string();
+            let nym_json1 = get_nym_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                DID_TRUSTEE,
+                &options_json
+            ).unwrap();
+            let nym: serde_json::Value = serde_json::from_str(&nym_json1).unwrap();
+            assert_eq!(DID_TRUSTEE, nym["did"].as_str().unwrap());
+
+            // now retrieve it from cache
+            let options_json = json!({"noUpdate": true}).to_string();
+            let nym_json2 = get_nym_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                DID_TRUSTEE,
+                &options_json
+            ).unwrap();
+
+            assert_eq!(nym_json1, nym_json2);
+        }
+
+        #[test]
+        fn indy_get_nym_only_cache_no_cached_data() {
+            let setup = Setup::wallet_and_pool();
+
+            let options_json = json!({"noUpdate": true}).to_string();
+            let res = get_nym_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                DID_TRUSTEE,
+                &options_json
+            );
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+
+        #[test]
+        fn indy_purge_nym_cache_works() {
+            let setup = Setup::wallet_and_pool();
+
+            get_nym_cache(setup.pool_handle, setup.wallet_handle, DID_MY1, DID_TRUSTEE, "{}").unwrap();
+
+            purge_nym_cache(setup.wallet_handle, "{}").unwrap();
+
+            let options_json = json!({"noUpdate": true}).to_string();
+            let res = get_nym_cache(setup.pool_handle, setup.wallet_handle, DID_MY1, DID_TRUSTEE, &options_json);
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+    }
+
+    mod revoc_reg_def_cache {
+        use super::*;
+        use crate::utils::domain::anoncreds::revocation_registry_definition::RevocationRegistryDefinitionV1;
+        use crate::utils::constants::*;
+        use std::thread::sleep;
+
+        #[test]
+        fn indy_get_revoc_reg_def_cache_works() {
+            let setup = Setup::wallet_and_pool();
+
+            let (_, _, rev_reg_id) = utils::ledger::post_entities();
+
+            let revoc_reg_def_json1 = get_revoc_reg_def_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_id,
+                "{}"
+            ).unwrap();
+            let revoc_reg_def: RevocationRegistryDefinitionV1 = serde_json::from_str(&revoc_reg_def_json1).unwrap();
+            assert_eq!(rev_reg_id, revoc_reg_def.id.0);
+
+            // now retrieve it from cache
+            let options_json = json!({"noUpdate": true}).to_string();
+            let revoc_reg_def_json2 = get_revoc_reg_def_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_id,
+                &options_json
+            ).unwrap();
+
+            assert_eq!(revoc_reg_def_json1, revoc_reg_def_json2);
+        }
+
+        #[test]
+        fn indy_get_revoc_reg_def_cache_ignores_min_fresh() {
+            let setup = Setup::wallet_and_pool();
+
+            let (_, _, rev_reg_id) = utils::ledger::post_entities();
+
+            get_revoc_reg_def_cache(setup.pool_handle, setup.wallet_handle, DID_MY1, rev_reg_id, "{}").unwrap();
+
+            sleep(std::time::Duration::from_secs(2));
+
+            // revocation registry definition is immutable, so cached data is always fresh
+            let options_json = json!({"noUpdate": true, "minFresh": 1}).to_string();
+            get_revoc_reg_def_cache(setup.pool_handle, setup.wallet_handle, DID_MY1, rev_reg_id, &options_json).unwrap();
+        }
+
+        #[test]
+        fn indy_purge_revoc_reg_def_cache_works() {
+            let setup = Setup::wallet_and_pool();
+
+            let (_, _, rev_reg_id) = utils::ledger::post_entities();
+
+            get_revoc_reg_def_cache(setup.pool_handle, setup.wallet_handle, DID_MY1, rev_reg_id, "{}").unwrap();
+
+            purge_revoc_reg_def_cache(setup.wallet_handle, "{}").unwrap();
+
+            let options_json = json!({"noUpdate": true}).to_string();
+            let res = get_revoc_reg_def_cache(setup.pool_handle, setup.wallet_handle, DID_MY1, rev_reg_id, &options_json);
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+    }
+
+    mod revoc_reg_delta_cache {
+        use super::*;
+        use crate::utils::constants::*;
+
+        #[test]
+        fn indy_get_revoc_reg_delta_cache_works() {
+            let setup = Setup::wallet_and_pool();
+
+            let (_, _, rev_reg_id) = utils::ledger::post_entities();
+
+            let to = time::get_time().sec as i64 + 100;
+
+            let (revoc_reg_def_id, revoc_reg_delta_json1, timestamp1) = get_revoc_reg_delta_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_id,
+                -1,
+                to,
+                "{}"
+            ).unwrap();
+            assert_eq!(rev_reg_id, revoc_reg_def_id);
+
+            // now retrieve it from cache
+            let options_json = json!({"noUpdate": true}).to_string();
+            let (_, revoc_reg_delta_json2, timestamp2) = get_revoc_reg_delta_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_id,
+                -1,
+                to,
+                &options_json
+            ).unwrap();
+
+            assert_eq!(revoc_reg_delta_json1, revoc_reg_delta_json2);
+            assert_eq!(timestamp1, timestamp2);
+
+            // earlier `to` after the accumulator timestamp is served from cache
+            let (_, revoc_reg_delta_json3, timestamp3) = get_revoc_reg_delta_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_id,
+                -1,
+                to - 1,
+                &options_json
+            ).unwrap();
+
+            assert_eq!(revoc_reg_delta_json1, revoc_reg_delta_json3);
+            assert_eq!(timestamp1, timestamp3);
+
+            // `to` before the accumulator timestamp is not cached
+            let res = get_revoc_reg_delta_cache(setup.pool_handle, setup.wallet_handle, DID_MY1, rev_reg_id, -1, timestamp1 as i64 - 1, &options_json);
+            assert_code!(ErrorCode::LedgerNotFound, res);
+
+            // later `to` is not cached
+            let res = get_revoc_reg_delta_cache(setup.pool_handle, setup.wallet_handle, DID_MY1, rev_reg_id, -1, to + 1, &options_json);
+            assert_code!(ErrorCode::LedgerNotFound, res);
+
+            // other `from` is not cached
+            let res = get_revoc_reg_delta_cache(setup.pool_handle, setup.wallet_handle, DID_MY1, rev_reg_id, 0, to, &options_json);
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+
+        #[test]
+        fn indy_purge_revoc_reg_delta_cache_works() {
+            let setup = Setup::wallet();
+            purge_revoc_reg_delta_cache(setup.wallet_handle, &json!({"maxAge": 1000}).to_string()).unwrap();
+        }
+    }
+
+    mod txn_author_agreement_cache {
+        use super::*;
+        use crate::utils::constants::*;
+
+        #[test]
+        fn indy_get_txn_author_agreement_only_cache_no_cached_data() {
+            let setup = Setup::wallet_and_pool();
+
+            let options_json = json!({"noUpdate": true}).to_string();
+            let res = get_txn_author_agreement_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                None,
+                &options_json
+            );
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+
+        #[test]
+        fn indy_get_txn_author_agreement_for_invalid_data() {
+            let setup = Setup::wallet_and_pool();
+
+            let data = json!({"version": "1.0", "timestamp": 12345}).to_string();
+            let res = get_txn_author_agreement_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                Some(&data),
+                "{}"
+            );
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_purge_txn_author_agreement_cache_works() {
+            let setup = Setup::wallet();
+            purge_txn_author_agreement_cache(setup.wallet_handle, "{}").unwrap();
+        }
+    }
+}
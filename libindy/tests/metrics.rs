@@ -5,8 +5,10 @@ inject_indy_dependencies!();
 
 extern crate indyrs as api;
 extern crate indyrs as indy;
+use indy::ErrorCode;
 use crate::utils::constants::*;
 use crate::utils::metrics;
+use crate::utils::non_secrets;
 use crate::utils::wallet;
 use crate::utils::Setup;
 
@@ -142,6 +144,70 @@ mod collect {
         assert!(commands_duration_ms_bucket.contains(&json!({"tags":{"command": "payments_command_build_set_txn_fees_req_ack", "stage": "queued"} ,"value": 0})));
     }
 
+    #[test]
+    fn collect_metrics_includes_wallet_storage_ops() {
+        let setup = Setup::wallet();
+        let wallet_id = serde_json::from_str::<Value>(&setup.wallet_config).unwrap()["id"].as_str().unwrap().to_string();
+
+        non_secrets::add_wallet_record(setup.wallet_handle, "type", "id1", "value", None).unwrap();
+
+        let result_metrics = metrics::collect_metrics().unwrap();
+        let metrics_map = serde_json::from_str::<HashMap<String, Value>>(&result_metrics).unwrap();
+
+        assert!(metrics_map.contains_key("wallet_storage_ops_count"));
+        assert!(metrics_map.contains_key("wallet_storage_op_errors_count"));
+        assert!(metrics_map.contains_key("wallet_storage_ops_duration_ms"));
+        assert!(metrics_map.contains_key("pool_node_replies_count"));
+        assert!(metrics_map.contains_key("pool_requests_count"));
+
+        let wallet_storage_ops_count = metrics_map
+            .get("wallet_storage_ops_count")
+            .unwrap()
+            .as_array()
+            .unwrap();
+
+        assert!(wallet_storage_ops_count.contains(&json!({"tags":{"wallet": wallet_id, "operation": "add"}, "value": 1})));
+    }
+
+    #[test]
+    fn collect_metrics_with_options_works_for_json() {
+        let result_metrics = metrics::collect_metrics_with_options(r#"{"format": "json"}"#).unwrap();
+        let metrics_map = serde_json::from_str::<HashMap<String, Value>>(&result_metrics).unwrap();
+
+        assert!(metrics_map.contains_key("commands_count"));
+        assert!(metrics_map.contains_key("wallet_count"));
+    }
+
+    #[test]
+    fn collect_metrics_with_options_works_for_openmetrics() {
+        let setup = Setup::empty();
+        let config = config(&setup.name);
+        wallet::create_wallet(&config, WALLET_CREDENTIALS).unwrap();
+
+        let result_metrics = metrics::collect_metrics_with_options(r#"{"format": "openmetrics"}"#).unwrap();
+
+        assert!(result_metrics.ends_with("# EOF\n"));
+        assert!(result_metrics.contains("# TYPE threadpool_threads gauge\n"));
+        assert!(result_metrics.contains("threadpool_threads{label=\"active\"} "));
+        assert!(result_metrics.contains("# TYPE wallets gauge\n"));
+        assert!(result_metrics.contains("# TYPE commands_duration_ms histogram\n"));
+        assert!(result_metrics.contains("commands_duration_ms_bucket{command=\"wallet_command_create\",stage=\"executed\",le=\"+Inf\"} "));
+        assert!(result_metrics.contains("commands_duration_ms_sum{command=\"wallet_command_create\",stage=\"executed\"} "));
+        assert!(result_metrics.contains("commands_duration_ms_count{command=\"wallet_command_create\",stage=\"executed\"} "));
+    }
+
+    #[test]
+    fn collect_metrics_with_options_works_for_default_format() {
+        let result_metrics = metrics::collect_metrics_with_options("{}").unwrap();
+        serde_json::from_str::<HashMap<String, Value>>(&result_metrics).unwrap();
+    }
+
+    #[test]
+    fn collect_metrics_with_options_works_for_unknown_format() {
+        let res = metrics::collect_metrics_with_options(r#"{"format": "xml"}"#);
+        assert_code!(ErrorCode::CommonInvalidStructure, res);
+    }
+
     fn config(name: &str) -> String {
         json!({ "id": name }).to_string()
     }
@@ -121,6 +121,41 @@ mod high_cases {
         }
     }
 
+    mod get_status {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_pool_status_works() {
+            let setup = Setup::pool();
+
+            let status = pool::get_status(&setup.name).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+
+            assert_eq!("synced", status["state"].as_str().unwrap());
+            assert!(status["elapsed_ms"].is_u64());
+
+            let nodes = status["nodes"].as_object().unwrap();
+            assert_eq!(4, nodes.len());
+            assert!(nodes.values().all(|node| node["reply"] != "pending"));
+        }
+
+        #[test]
+        fn indy_get_pool_status_works_for_not_opened() {
+            let setup = Setup::empty();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let status = pool::get_status(&setup.name).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+
+            assert_eq!("not_opened", status["state"].as_str().unwrap());
+            assert!(status["nodes"].as_object().unwrap().is_empty());
+        }
+    }
+
     mod close {
         use super::*;
 
@@ -382,6 +417,38 @@ mod medium_cases {
         }
     }
 
+    mod get_status {
+        use super::*;
+
+        #[test]
+        fn indy_get_pool_status_works_for_not_created() {
+            let setup = Setup::empty();
+
+            let res = pool::get_status(&setup.name);
+            assert_code!(ErrorCode::PoolLedgerNotCreatedError, res);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_pool_status_works_for_wrong_ips() {
+            let setup = Setup::empty();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool_with_wrong_ips(&setup.name, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let res = pool::open_pool_ledger(&setup.name, None);
+            assert_code!(ErrorCode::PoolLedgerTimeout, res);
+
+            let status = pool::get_status(&setup.name).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+
+            assert_eq!("failed", status["state"].as_str().unwrap());
+            assert!(status["error"].is_string());
+            assert!(status["nodes"].as_object().unwrap().values().all(|node| node["reply"] == "timeout"));
+        }
+    }
+
     mod close {
         use super::*;
 
@@ -0,0 +1,48 @@
+#[macro_use]
+mod utils;
+
+inject_indy_dependencies!();
+
+extern crate indyrs as indy;
+extern crate indyrs as api;
+
+use crate::utils::{crypto, tracer};
+use crate::utils::Setup;
+
+use serde_json::Value;
+
+// Tracer is global, so all checks are kept in a single test.
+#[test]
+fn indy_set_tracer_works() {
+    let setup = Setup::wallet();
+
+    tracer::set_tracer().unwrap();
+
+    crypto::create_key(setup.wallet_handle, None).unwrap();
+    // the command span of the first call is finished before the second command is executed
+    crypto::create_key(setup.wallet_handle, None).unwrap();
+
+    tracer::unset_tracer().unwrap();
+
+    let spans = tracer::take_spans();
+
+    let api_spans: Vec<&Value> = spans.iter().filter(|span| span["name"] == "crypto_command_create_key" && span.get("parent_span_id").is_none()).collect();
+    assert_eq!(api_spans.len(), 2);
+
+    let api_span = api_spans[0];
+    assert!(api_span["command_handle"].is_i64());
+
+    let command_span = spans.iter().find(|span| span["parent_span_id"] == api_span["span_id"]).unwrap();
+    assert_eq!(command_span["name"], "command");
+    assert_eq!(command_span["attributes"]["command"], "crypto_command_create_key");
+    assert_eq!(command_span["trace_id"], api_span["trace_id"]);
+
+    let storage_spans: Vec<&Value> = spans.iter().filter(|span| span["parent_span_id"] == command_span["span_id"]).collect();
+    assert!(!storage_spans.is_empty());
+
+    for storage_span in storage_spans {
+        assert_eq!(storage_span["name"], "wallet_storage");
+        assert_eq!(storage_span["trace_id"], api_span["trace_id"]);
+        assert!(storage_span["attributes"]["operation"].is_string());
+    }
+}
@@ -33,6 +33,8 @@ pub const CREDENTIAL1_ID: &'static str = "credential1_id";
 pub const CREDENTIAL1_SUB_ID: &'static str = "credential1_sub_id";
 pub const CREDENTIAL2_ID: &'static str = "credential2_id";
 pub const CREDENTIAL3_ID: &'static str = "credential3_id";
+pub const ISSUANCE_DATE: &'static str = "2020-01-01T00:00:00Z";
+pub const ISSUANCE_DATES: &'static str = r#"["2020-01-01T00:00:00Z"]"#;
 pub const DELIMITER: &'static str = ":";
 pub const CRED_DEF_MARKER: &'static str = "3";
 
@@ -52,6 +54,10 @@ pub fn issuer_create_schema(issuer_did: &str, name: &str, version: &str, attr_na
     anoncreds::issuer_create_schema(issuer_did, name, version, attr_names).wait()
 }
 
+pub fn issuer_create_typed_schema(issuer_did: &str, name: &str, version: &str, attr_types: &str) -> Result<(String, String), IndyError> {
+    anoncreds::issuer_create_typed_schema(issuer_did, name, version, attr_types).wait()
+}
+
 pub fn issuer_create_credential_definition(wallet_handle: WalletHandle, issuer_did: &str, schema: &str, tag: &str,
                                            signature_type: Option<&str>, config: Option<&str>) -> Result<(String, String), IndyError> {
     anoncreds::issuer_create_and_store_credential_def(wallet_handle, issuer_did, schema, tag, signature_type, config.unwrap_or("{}")).wait() // TODO: FIXME OPTIONAL CONFIG
@@ -71,6 +77,12 @@ pub fn issuer_create_and_store_revoc_reg(wallet_handle: WalletHandle, issuer_did
     anoncreds::issuer_create_and_store_revoc_reg(wallet_handle, issuer_did, type_, tag, cred_def_id, config_json, tails_writer_handle).wait()
 }
 
+pub fn issuer_create_and_store_revoc_reg_with_progress(wallet_handle: WalletHandle, issuer_did: &str, type_: Option<&str>, tag: &str,
+                                                       cred_def_id: &str, config_json: &str, tails_writer_handle: i32,
+                                                       progress: Box<dyn Fn(u32, u32) + Send>) -> Result<(String, String, String), IndyError> {
+    anoncreds::issuer_create_and_store_revoc_reg_with_progress(wallet_handle, issuer_did, type_, tag, cred_def_id, config_json, tails_writer_handle, progress).wait()
+}
+
 pub fn issuer_create_credential_offer(wallet_handle: WalletHandle, cred_def_id: &str) -> Result<String, IndyError> {
     anoncreds::issuer_create_credential_offer(wallet_handle, cred_def_id).wait()
 }
@@ -84,6 +96,31 @@ pub fn issuer_revoke_credential(wallet_handle: WalletHandle, blob_storage_reader
     anoncreds::issuer_revoke_credential(wallet_handle, blob_storage_reader_handle, rev_reg_id, cred_revoc_id).wait()
 }
 
+pub fn issuer_create_credential_with_record(wallet_handle: WalletHandle, cred_offer_json: &str, cred_req_json: &str, cred_values_json: &str,
+                                            rev_reg_id: Option<&str>, blob_storage_reader_handle: Option<i32>, record_config_json: &str) -> Result<(String, Option<String>, Option<String>), IndyError> {
+    anoncreds::issuer_create_credential_with_record(wallet_handle, cred_offer_json, cred_req_json, cred_values_json, rev_reg_id, blob_storage_reader_handle.unwrap_or(-1), record_config_json).wait()
+}
+
+pub fn issuer_revoke_credential_by_record(wallet_handle: WalletHandle, blob_storage_reader_handle: i32, record_id: &str) -> Result<String, IndyError> {
+    anoncreds::issuer_revoke_credential_by_record(wallet_handle, blob_storage_reader_handle, record_id).wait()
+}
+
+pub fn issuer_get_issuance_record(wallet_handle: WalletHandle, record_id: &str) -> Result<String, IndyError> {
+    anoncreds::issuer_get_issuance_record(wallet_handle, record_id).wait()
+}
+
+pub fn issuer_search_issuance_records(wallet_handle: WalletHandle, query_json: &str) -> Result<(i32, usize), IndyError> {
+    anoncreds::issuer_search_issuance_records(wallet_handle, Some(query_json)).wait()
+}
+
+pub fn issuer_fetch_issuance_records(search_handle: i32, count: usize) -> Result<String, IndyError> {
+    anoncreds::issuer_fetch_issuance_records(search_handle, count).wait()
+}
+
+pub fn issuer_close_issuance_records_search(search_handle: i32) -> Result<(), IndyError> {
+    anoncreds::issuer_close_issuance_records_search(search_handle).wait()
+}
+
 pub fn issuer_merge_revocation_registry_deltas(rev_reg_delta: &str, other_rev_reg_delta: &str) -> Result<String, IndyError> {
     anoncreds::issuer_merge_revocation_registry_deltas(rev_reg_delta, other_rev_reg_delta).wait()
 }
@@ -92,6 +129,18 @@ pub fn prover_create_master_secret(wallet_handle: WalletHandle, master_secret_id
     anoncreds::prover_create_master_secret(wallet_handle, Some(master_secret_id)).wait()
 }
 
+pub fn prover_list_master_secrets(wallet_handle: WalletHandle) -> Result<String, IndyError> {
+    anoncreds::prover_list_master_secrets(wallet_handle).wait()
+}
+
+pub fn prover_set_default_master_secret(wallet_handle: WalletHandle, master_secret_id: &str) -> Result<(), IndyError> {
+    anoncreds::prover_set_default_master_secret(wallet_handle, master_secret_id).wait()
+}
+
+pub fn prover_rotate_master_secret(wallet_handle: WalletHandle, master_secret_id: &str, new_master_secret_id: Option<&str>) -> Result<String, IndyError> {
+    anoncreds::prover_rotate_master_secret(wallet_handle, master_secret_id, new_master_secret_id).wait()
+}
+
 pub fn prover_create_credential_req(wallet_handle: WalletHandle, prover_did: &str, cred_offer_json: &str,
                                     cred_def_json: &str, master_secret_id: &str) -> Result<(String, String), IndyError> {
     anoncreds::prover_create_credential_req(wallet_handle, prover_did, cred_offer_json, cred_def_json, master_secret_id).wait()
@@ -115,6 +164,14 @@ pub fn prover_delete_credential(wallet_handle: WalletHandle, cred_id: &str) -> R
     anoncreds::prover_delete_credential(wallet_handle, cred_id).wait()
 }
 
+pub fn prover_export_credential(wallet_handle: WalletHandle, cred_id: &str, export_config_json: &str) -> Result<String, IndyError> {
+    anoncreds::prover_export_credential(wallet_handle, cred_id, export_config_json).wait()
+}
+
+pub fn prover_import_credential(wallet_handle: WalletHandle, bundle_json: &str, import_config_json: &str) -> Result<String, IndyError> {
+    anoncreds::prover_import_credential(wallet_handle, bundle_json, import_config_json).wait()
+}
+
 //TODO mark as deprecated and use only in target tests
 pub fn prover_get_credentials(wallet_handle: WalletHandle, filter_json: &str) -> Result<String, IndyError> {
     anoncreds::prover_get_credentials(wallet_handle, Some(filter_json)).wait()
@@ -124,6 +181,18 @@ pub fn prover_get_credential(wallet_handle: WalletHandle, cred_id: &str) -> Resu
     anoncreds::prover_get_credential(wallet_handle, cred_id).wait()
 }
 
+pub fn prover_get_credential_status(wallet_handle: WalletHandle, cred_id: &str) -> Result<String, IndyError> {
+    anoncreds::prover_get_credential_status(wallet_handle, cred_id).wait()
+}
+
+pub fn prover_set_credential_expiry_attr(wallet_handle: WalletHandle, cred_def_id: &str, attr_name: Option<&str>) -> Result<(), IndyError> {
+    anoncreds::prover_set_credential_expiry_attr(wallet_handle, cred_def_id, attr_name).wait()
+}
+
+pub fn prover_set_credential_identity_attr(wallet_handle: WalletHandle, cred_def_id: &str, attr_name: Option<&str>) -> Result<(), IndyError> {
+    anoncreds::prover_set_credential_identity_attr(wallet_handle, cred_def_id, attr_name).wait()
+}
+
 pub fn prover_search_credentials(wallet_handle: WalletHandle, filter_json: &str) -> Result<(i32, usize), IndyError> {
     anoncreds::prover_search_credentials(wallet_handle, Some(filter_json)).wait()
 }
@@ -153,6 +222,10 @@ pub fn prover_close_credentials_search_for_proof_req(search_handle: i32) -> Resu
     anoncreds::prover_close_credentials_search_for_proof_req(search_handle).wait()
 }
 
+pub fn prover_explain_proof_req(wallet_handle: WalletHandle, proof_request_json: &str, extra_query_json: Option<&str>) -> Result<String, IndyError> {
+    anoncreds::prover_explain_proof_req(wallet_handle, proof_request_json, extra_query_json).wait()
+}
+
 pub fn prover_create_proof(wallet_handle: WalletHandle, proof_req_json: &str, requested_credentials_json: &str,
                            master_secret_name: &str, schemas_json: &str, cred_defs_json: &str,
                            rev_states_json: &str) -> Result<String, IndyError> {
@@ -160,16 +233,45 @@ pub fn prover_create_proof(wallet_handle: WalletHandle, proof_req_json: &str, re
                                    master_secret_name, schemas_json, cred_defs_json, rev_states_json).wait()
 }
 
+pub fn prover_create_proof_with_options(wallet_handle: WalletHandle, proof_req_json: &str, requested_credentials_json: &str,
+                                        master_secret_name: Option<&str>, schemas_json: &str, cred_defs_json: &str,
+                                        rev_states_json: &str, options_json: &str) -> Result<String, IndyError> {
+    anoncreds::prover_create_proof_with_options(wallet_handle, proof_req_json, requested_credentials_json,
+                                                master_secret_name, schemas_json, cred_defs_json, rev_states_json, options_json).wait()
+}
+
 pub fn verifier_verify_proof(proof_request_json: &str, proof_json: &str, schemas_json: &str,
                              cred_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str) -> Result<bool, IndyError> {
     anoncreds::verifier_verify_proof(proof_request_json, proof_json, schemas_json, cred_defs_json, rev_reg_defs_json, rev_regs_json).wait()
 }
 
+pub fn verifier_verify_proof_with_options(proof_request_json: &str, proof_json: &str, schemas_json: &str,
+                                          cred_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str,
+                                          options_json: &str) -> Result<bool, IndyError> {
+    anoncreds::verifier_verify_proof_with_options(proof_request_json, proof_json, schemas_json, cred_defs_json, rev_reg_defs_json, rev_regs_json, options_json).wait()
+}
+
+pub fn verifier_verify_w3c_presentation(proof_request_json: &str, w3c_presentation_json: &str, schemas_json: &str,
+                                        cred_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str) -> Result<bool, IndyError> {
+    anoncreds::verifier_verify_w3c_presentation(proof_request_json, w3c_presentation_json, schemas_json, cred_defs_json, rev_reg_defs_json, rev_regs_json).wait()
+}
+
+pub fn verifier_verify_proof_detailed(proof_request_json: &str, proof_json: &str, schemas_json: &str,
+                                      cred_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str,
+                                      options_json: &str) -> Result<String, IndyError> {
+    anoncreds::verifier_verify_proof_detailed(proof_request_json, proof_json, schemas_json, cred_defs_json, rev_reg_defs_json, rev_regs_json, options_json).wait()
+}
+
 pub fn create_revocation_state(blob_storage_reader_handle: i32, rev_reg_def_json: &str,
                                rev_reg_delta_json: &str, timestamp: u64, cred_rev_id: &str) -> Result<String, IndyError> {
     anoncreds::create_revocation_state(blob_storage_reader_handle, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_rev_id).wait()
 }
 
+pub fn prover_create_credential_revocation_state(wallet_handle: WalletHandle, blob_storage_reader_handle: i32, rev_reg_def_json: &str,
+                                                 rev_reg_delta_json: &str, timestamp: u64, cred_id: &str) -> Result<String, IndyError> {
+    anoncreds::prover_create_credential_revocation_state(wallet_handle, blob_storage_reader_handle, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_id).wait()
+}
+
 pub fn update_revocation_state(tails_reader_handle: i32, rev_state_json: &str, rev_reg_def_json: &str,
                                rev_reg_delta_json: &str, timestamp: u64, cred_rev_id: &str) -> Result<String, IndyError> {
     anoncreds::update_revocation_state(tails_reader_handle, rev_state_json, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_rev_id).wait()
@@ -179,10 +281,42 @@ pub fn generate_nonce() -> Result<String, IndyError> {
     anoncreds::generate_nonce().wait()
 }
 
+pub fn verifier_build_proof_request(template_json: &str) -> Result<String, IndyError> {
+    anoncreds::verifier_build_proof_request(template_json).wait()
+}
+
 pub fn to_unqualified(entity: &str) -> Result<String, IndyError> {
     anoncreds::to_unqualified(entity).wait()
 }
 
+pub fn encode_credential_values(values_json: &str) -> Result<String, IndyError> {
+    anoncreds::encode_credential_values(values_json).wait()
+}
+
+pub fn encode_typed_credential_values(values_json: &str, schema_json: &str) -> Result<String, IndyError> {
+    anoncreds::encode_typed_credential_values(values_json, schema_json).wait()
+}
+
+pub fn build_date_predicate(date_predicate_json: &str) -> Result<String, IndyError> {
+    anoncreds::build_date_predicate(date_predicate_json).wait()
+}
+
+pub fn credential_to_w3c(cred_json: &str, issuance_date: &str) -> Result<String, IndyError> {
+    anoncreds::credential_to_w3c(cred_json, issuance_date).wait()
+}
+
+pub fn credential_from_w3c(w3c_cred_json: &str, schema_json: &str) -> Result<String, IndyError> {
+    anoncreds::credential_from_w3c(w3c_cred_json, schema_json).wait()
+}
+
+pub fn proof_to_w3c(proof_request_json: &str, proof_json: &str, issuance_dates_json: &str) -> Result<String, IndyError> {
+    anoncreds::proof_to_w3c(proof_request_json, proof_json, issuance_dates_json).wait()
+}
+
+pub fn proof_from_w3c(w3c_presentation_json: &str) -> Result<String, IndyError> {
+    anoncreds::proof_from_w3c(w3c_presentation_json).wait()
+}
+
 pub fn default_cred_def_config() -> String {
     serde_json::to_string(&CredentialDefinitionConfig { support_revocation: false }).unwrap()
 }
@@ -242,6 +376,7 @@ pub fn gvt_schema() -> SchemaV1 {
         name: GVT_SCHEMA_NAME.to_string(),
         attr_names: serde_json::from_str::<HashSet<String>>(GVT_SCHEMA_ATTRIBUTES).unwrap().into(),
         seq_no: None,
+        attr_types: None,
     }
 }
 
@@ -252,6 +387,7 @@ pub fn gvt_sub_schema() -> SchemaV1 {
         name: GVT_SUB_SCHEMA_NAME.to_string(),
         attr_names: serde_json::from_str::<HashSet<String>>(GVT_SUB_SCHEMA_ATTRIBUTES).unwrap().into(),
         seq_no: None,
+        attr_types: None,
     }
 }
 
@@ -274,6 +410,7 @@ pub fn gvt_schema_issuer2() -> SchemaV1 {
         name: GVT_SCHEMA_NAME.to_string(),
         attr_names: serde_json::from_str::<HashSet<String>>(GVT_SCHEMA_ATTRIBUTES).unwrap().into(),
         seq_no: None,
+        attr_types: None,
     }
 }
 
@@ -293,6 +430,7 @@ pub fn xyz_schema() -> SchemaV1 {
         name: XYZ_SCHEMA_NAME.to_string(),
         attr_names: serde_json::from_str::<HashSet<String>>(XYZ_SCHEMA_ATTRIBUTES).unwrap().into(),
         seq_no: None,
+        attr_types: None,
     }
 }
 
@@ -300,6 +438,25 @@ pub fn xyz_schema_json() -> String {
     serde_json::to_string(&Schema::SchemaV1(xyz_schema())).unwrap()
 }
 
+pub fn expiring_schema_id() -> String {
+    SchemaId::new(&DidValue(ISSUER_DID.to_string()), EXPIRING_SCHEMA_NAME, SCHEMA_VERSION).0
+}
+
+pub fn expiring_schema() -> SchemaV1 {
+    SchemaV1 {
+        id: SchemaId(expiring_schema_id()),
+        version: SCHEMA_VERSION.to_string(),
+        name: EXPIRING_SCHEMA_NAME.to_string(),
+        attr_names: serde_json::from_str::<HashSet<String>>(EXPIRING_SCHEMA_ATTRIBUTES).unwrap().into(),
+        seq_no: None,
+        attr_types: None,
+    }
+}
+
+pub fn expiring_schema_json() -> String {
+    serde_json::to_string(&Schema::SchemaV1(expiring_schema())).unwrap()
+}
+
 pub fn xyz_schema_id_tag2() -> String {
     SchemaId::new(&DidValue(ISSUER_DID.to_string()), &format!("{}{}", XYZ_SCHEMA_NAME, TAG_2), SCHEMA_VERSION).0
 }
@@ -311,6 +468,7 @@ pub fn xyz_schema_tag2() -> SchemaV1 {
         name: format!("{}{}", XYZ_SCHEMA_NAME, TAG_2),
         attr_names: serde_json::from_str::<HashSet<String>>(XYZ_SCHEMA_ATTRIBUTES).unwrap().into(),
         seq_no: None,
+        attr_types: None,
     }
 }
 
@@ -399,6 +557,14 @@ pub fn xyz_credential_values_json() -> String {
     serde_json::to_string(&xyz_credential_values()).unwrap()
 }
 
+/// `expiry` is a unix timestamp.
+pub fn expiring_credential_values_json(expiry: u64) -> String {
+    serde_json::to_string(&map! {
+            "name".to_string() => AttributeValues {raw: "Alex".to_string(), encoded: "1139481716457488690172217916278103335".to_string()},
+            "expiry".to_string() => AttributeValues {raw: expiry.to_string(), encoded: expiry.to_string()}
+          }).unwrap()
+}
+
 pub fn gvt2_credential_values() -> HashMap<String, AttributeValues> {
     map! {
             "sex".to_string() => AttributeValues {raw: "male".to_string(), encoded: "2142657394558967239210949258394838228692050081607692519917028371144233115103".to_string()},
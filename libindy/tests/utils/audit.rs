@@ -0,0 +1,35 @@
+extern crate futures;
+
+use indy::{IndyError, WalletHandle};
+use indy::audit;
+use self::futures::Future;
+
+use serde_json::Value;
+
+use std::sync::Mutex;
+
+lazy_static! {
+    static ref EVENTS: Mutex<Vec<Value>> = Mutex::new(Vec::new());
+}
+
+pub fn set_audit_log(config_json: &str) -> Result<(), IndyError> {
+    audit::set_audit_log(config_json, Some(Box::new(|event_json: &str| {
+        EVENTS.lock().unwrap().push(serde_json::from_str(event_json).unwrap());
+    })))
+}
+
+pub fn unset_audit_log() -> Result<(), IndyError> {
+    audit::unset_audit_log()
+}
+
+pub fn take_events() -> Vec<Value> {
+    EVENTS.lock().unwrap().drain(..).collect()
+}
+
+pub fn get_audit_log(wallet_handle: WalletHandle, query_json: &str) -> Result<String, IndyError> {
+    audit::get_audit_log(wallet_handle, query_json).wait()
+}
+
+pub fn verify_audit_log(wallet_handle: WalletHandle) -> Result<String, IndyError> {
+    audit::verify_audit_log(wallet_handle).wait()
+}
@@ -26,11 +26,13 @@ pub const DEST_V1: &'static str = "did:sov:FYmoFw55GeQH7SRFa37dkx1d2dZ3zUF8ckg7w
 pub const GVT_SCHEMA_NAME: &'static str = "gvt";
 pub const GVT_SUB_SCHEMA_NAME: &'static str = "gvtsub";
 pub const XYZ_SCHEMA_NAME: &'static str = "xyz";
+pub const EXPIRING_SCHEMA_NAME: &'static str = "expiring";
 pub const SCHEMA_VERSION: &'static str = "1.0";
 pub const SCHEMA_SUB_VERSION: &'static str = "2.2";
 pub const GVT_SCHEMA_ATTRIBUTES: &'static str = r#"["name", "age", "sex", "height"]"#;
 pub const GVT_SUB_SCHEMA_ATTRIBUTES: &'static str = r#"["sex", "height_sub"]"#;
 pub const XYZ_SCHEMA_ATTRIBUTES: &'static str = r#"["status", "period"]"#;
+pub const EXPIRING_SCHEMA_ATTRIBUTES: &'static str = r#"["name", "expiry"]"#;
 pub const SCHEMA_DATA: &'static str = r#"{"id":"1", "name":"gvt","version":"1.0","attrNames":["name"],"ver":"1.0"}"#;
 pub const ENDPOINT: &'static str = "127.0.0.1:9700";
 pub const VERKEY: &'static str = "CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW";
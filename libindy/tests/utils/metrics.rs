@@ -7,4 +7,8 @@ use self::futures::Future;
 
 pub fn collect_metrics() -> Result<String, IndyError> {
     metrics::collect_metrics().wait()
-}
\ No newline at end of file
+}
+
+pub fn collect_metrics_with_options(options_json: &str) -> Result<String, IndyError> {
+    metrics::collect_metrics_with_options(options_json).wait()
+}
@@ -25,6 +25,8 @@ pub mod results;
 pub mod payments;
 pub mod rand_utils;
 pub mod logger;
+pub mod tracer;
+pub mod audit;
 pub mod cache;
 pub mod metrics;
 
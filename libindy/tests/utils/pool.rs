@@ -174,6 +174,10 @@ pub fn refresh(pool_handle: PoolHandle) -> Result<(), IndyError> {
     pool::refresh_pool_ledger(pool_handle).wait()
 }
 
+pub fn get_status(pool_name: &str) -> Result<String, IndyError> {
+    pool::get_pool_status(pool_name).wait()
+}
+
 pub fn close(pool_handle: PoolHandle) -> Result<(), IndyError> {
     pool::close_pool_ledger(pool_handle).wait()
 }
@@ -0,0 +1,24 @@
+use indy::IndyError;
+use indy::tracer;
+
+use serde_json::Value;
+
+use std::sync::Mutex;
+
+lazy_static! {
+    static ref SPANS: Mutex<Vec<Value>> = Mutex::new(Vec::new());
+}
+
+pub fn set_tracer() -> Result<(), IndyError> {
+    tracer::set_tracer(Box::new(|span_json: &str| {
+        SPANS.lock().unwrap().push(serde_json::from_str(span_json).unwrap());
+    }))
+}
+
+pub fn unset_tracer() -> Result<(), IndyError> {
+    tracer::unset_tracer()
+}
+
+pub fn take_spans() -> Vec<Value> {
+    SPANS.lock().unwrap().drain(..).collect()
+}
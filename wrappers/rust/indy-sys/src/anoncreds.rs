@@ -12,6 +12,13 @@ extern {
                                      attrs: CString,
                                      cb: Option<ResponseStringStringCB>) -> Error;
 
+    pub fn indy_issuer_create_typed_schema(command_handle: CommandHandle,
+                                           issuer_did: CString,
+                                           name: CString,
+                                           version: CString,
+                                           attr_types: CString,
+                                           cb: Option<ResponseStringStringCB>) -> Error;
+
     pub fn indy_issuer_create_and_store_credential_def(command_handle: CommandHandle,
                                                        wallet_handle: WalletHandle,
                                                        issuer_did: CString,
@@ -42,6 +49,17 @@ extern {
                                                   tails_writer_handle: TailWriterHandle,
                                                   cb: Option<ResponseStringStringStringCB>) -> Error;
 
+    pub fn indy_issuer_create_and_store_revoc_reg_with_progress(command_handle: CommandHandle,
+                                                                wallet_handle: WalletHandle,
+                                                                issuer_did: CString,
+                                                                revoc_def_type: CString,
+                                                                tag: CString,
+                                                                cred_def_id: CString,
+                                                                config_json: CString,
+                                                                tails_writer_handle: TailWriterHandle,
+                                                                progress_cb: Option<RevocRegProgressCB>,
+                                                                cb: Option<ResponseStringStringStringCB>) -> Error;
+
     pub fn indy_issuer_create_credential_offer(command_handle: CommandHandle,
                                                wallet_handle: WalletHandle,
                                                cred_def_id: CString,
@@ -63,6 +81,41 @@ extern {
                                          cred_revoc_id: CString,
                                          cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_issuer_create_credential_with_record(command_handle: CommandHandle,
+                                                     wallet_handle: WalletHandle,
+                                                     cred_offer_json: CString,
+                                                     cred_req_json: CString,
+                                                     cred_values_json: CString,
+                                                     rev_reg_id: CString,
+                                                     blob_storage_reader_handle: BlobStorageReaderHandle,
+                                                     record_config_json: CString,
+                                                     cb: Option<ResponseStringStringStringCB>) -> Error;
+
+    pub fn indy_issuer_revoke_credential_by_record(command_handle: CommandHandle,
+                                                   wallet_handle: WalletHandle,
+                                                   blob_storage_reader_cfg_handle: BlobStorageReaderCfgHandle,
+                                                   record_id: CString,
+                                                   cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_issuer_get_issuance_record(command_handle: CommandHandle,
+                                           wallet_handle: WalletHandle,
+                                           record_id: CString,
+                                           cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_issuer_search_issuance_records(command_handle: CommandHandle,
+                                               wallet_handle: WalletHandle,
+                                               query_json: CString,
+                                               cb: Option<ResponseI32UsizeCB>) -> Error;
+
+    pub fn indy_issuer_fetch_issuance_records(command_handle: CommandHandle,
+                                              search_handle: SearchHandle,
+                                              count: usize,
+                                              cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_issuer_close_issuance_records_search(command_handle: CommandHandle,
+                                                     search_handle: SearchHandle,
+                                                     cb: Option<ResponseEmptyCB>) -> Error;
+
     pub fn indy_issuer_merge_revocation_registry_deltas(command_handle: CommandHandle,
                                                         rev_reg_delta_json: CString,
                                                         other_rev_reg_delta_json: CString,
@@ -73,6 +126,21 @@ extern {
                                             master_secret_id: CString,
                                             cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_prover_list_master_secrets(command_handle: CommandHandle,
+                                           wallet_handle: WalletHandle,
+                                           cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_prover_set_default_master_secret(command_handle: CommandHandle,
+                                                 wallet_handle: WalletHandle,
+                                                 master_secret_id: CString,
+                                                 cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_prover_rotate_master_secret(command_handle: CommandHandle,
+                                            wallet_handle: WalletHandle,
+                                            master_secret_id: CString,
+                                            new_master_secret_id: CString,
+                                            cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_prover_create_credential_req(command_handle: CommandHandle,
                                              wallet_handle: WalletHandle,
                                              prover_did: CString,
@@ -107,11 +175,40 @@ extern {
                                       cred_id: CString,
                                       cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_prover_get_credential_status(command_handle: CommandHandle,
+                                             wallet_handle: WalletHandle,
+                                             cred_id: CString,
+                                             cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_prover_set_credential_expiry_attr(command_handle: CommandHandle,
+                                                  wallet_handle: WalletHandle,
+                                                  cred_def_id: CString,
+                                                  attr_name: CString,
+                                                  cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_prover_set_credential_identity_attr(command_handle: CommandHandle,
+                                                    wallet_handle: WalletHandle,
+                                                    cred_def_id: CString,
+                                                    attr_name: CString,
+                                                    cb: Option<ResponseEmptyCB>) -> Error;
+
     pub fn indy_prover_delete_credential(command_handle: CommandHandle,
                                          wallet_handle: WalletHandle,
                                          cred_id: CString,
                                          cb: Option<ResponseEmptyCB>) -> Error;
 
+    pub fn indy_prover_export_credential(command_handle: CommandHandle,
+                                         wallet_handle: WalletHandle,
+                                         cred_id: CString,
+                                         export_config_json: CString,
+                                         cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_prover_import_credential(command_handle: CommandHandle,
+                                         wallet_handle: WalletHandle,
+                                         bundle_json: CString,
+                                         import_config_json: CString,
+                                         cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_prover_get_credentials(command_handle: CommandHandle,
                                        wallet_handle: WalletHandle,
                                        filter_json: CString,
@@ -152,6 +249,12 @@ extern {
                                                               search_handle: SearchHandle,
                                                               cb: Option<ResponseEmptyCB>) -> Error;
 
+    pub fn indy_prover_explain_proof_req(command_handle: CommandHandle,
+                                         wallet_handle: WalletHandle,
+                                         proof_request_json: CString,
+                                         extra_query_json: CString,
+                                         cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_prover_create_proof(command_handle: CommandHandle,
                                     wallet_handle: WalletHandle,
                                     proof_req_json: CString,
@@ -162,6 +265,17 @@ extern {
                                     rev_states_json: CString,
                                     cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_prover_create_proof_with_options(command_handle: CommandHandle,
+                                                 wallet_handle: WalletHandle,
+                                                 proof_req_json: CString,
+                                                 requested_credentials_json: CString,
+                                                 master_secret_id: CString,
+                                                 schemas_json: CString,
+                                                 credential_defs_json: CString,
+                                                 rev_states_json: CString,
+                                                 options_json: CString,
+                                                 cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_verifier_verify_proof(command_handle: CommandHandle,
                                       proof_request_json: CString,
                                       proof_json: CString,
@@ -171,6 +285,35 @@ extern {
                                       rev_regs_json: CString,
                                       cb: Option<ResponseBoolCB>) -> Error;
 
+    pub fn indy_verifier_verify_proof_with_options(command_handle: CommandHandle,
+                                                   proof_request_json: CString,
+                                                   proof_json: CString,
+                                                   schemas_json: CString,
+                                                   credential_defs_json: CString,
+                                                   rev_reg_defs_json: CString,
+                                                   rev_regs_json: CString,
+                                                   options_json: CString,
+                                                   cb: Option<ResponseBoolCB>) -> Error;
+
+    pub fn indy_verifier_verify_proof_detailed(command_handle: CommandHandle,
+                                               proof_request_json: CString,
+                                               proof_json: CString,
+                                               schemas_json: CString,
+                                               credential_defs_json: CString,
+                                               rev_reg_defs_json: CString,
+                                               rev_regs_json: CString,
+                                               options_json: CString,
+                                               cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_verifier_verify_w3c_presentation(command_handle: CommandHandle,
+                                                 proof_request_json: CString,
+                                                 w3c_presentation_json: CString,
+                                                 schemas_json: CString,
+                                                 credential_defs_json: CString,
+                                                 rev_reg_defs_json: CString,
+                                                 rev_regs_json: CString,
+                                                 cb: Option<ResponseBoolCB>) -> Error;
+
     pub fn indy_create_revocation_state(command_handle: CommandHandle,
                                         blob_storage_reader_handle: BlobStorageReaderHandle,
                                         rev_reg_def_json: CString,
@@ -179,6 +322,15 @@ extern {
                                         cred_rev_id: CString,
                                         cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_prover_create_credential_revocation_state(command_handle: CommandHandle,
+                                                          wallet_handle: WalletHandle,
+                                                          blob_storage_reader_handle: BlobStorageReaderHandle,
+                                                          rev_reg_def_json: CString,
+                                                          rev_reg_delta_json: CString,
+                                                          timestamp: u64,
+                                                          cred_id: CString,
+                                                          cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_update_revocation_state(command_handle: CommandHandle,
                                         blob_storage_reader_handle: BlobStorageReaderHandle,
                                         rev_state_json: CString,
@@ -190,8 +342,47 @@ extern {
 
     pub fn indy_generate_nonce(command_handle: CommandHandle,
                                cb: Option<ResponseStringCB>) -> Error;
+    pub fn indy_verifier_build_proof_request(command_handle: CommandHandle,
+                                             template_json: CString,
+                                             cb: Option<ResponseStringCB>) -> Error;
     pub fn indy_to_unqualified(command_handle: CommandHandle,
                                entity: CString,
                                cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_encode_credential_values(command_handle: CommandHandle,
+                                         values_json: CString,
+                                         cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_encode_typed_credential_values(command_handle: CommandHandle,
+                                               values_json: CString,
+                                               schema_json: CString,
+                                               cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_build_date_predicate(command_handle: CommandHandle,
+                                     date_predicate_json: CString,
+                                     cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_credential_to_w3c(command_handle: CommandHandle,
+                                  cred_json: CString,
+                                  issuance_date: CString,
+                                  cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_credential_from_w3c(command_handle: CommandHandle,
+                                    w3c_cred_json: CString,
+                                    schema_json: CString,
+                                    cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_proof_to_w3c(command_handle: CommandHandle,
+                             proof_request_json: CString,
+                             proof_json: CString,
+                             issuance_dates_json: CString,
+                             cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_proof_from_w3c(command_handle: CommandHandle,
+                               w3c_presentation_json: CString,
+                               cb: Option<ResponseStringCB>) -> Error;
 }
 
+pub type RevocRegProgressCB = extern fn(xcommand_handle: CommandHandle,
+                                        tails_written: u32,
+                                        tails_total: u32);
@@ -0,0 +1,22 @@
+use super::*;
+
+use {CString, CVoid, Error, CommandHandle, WalletHandle};
+
+extern {
+
+    pub fn indy_set_audit_log(context: *const CVoid,
+                              config_json: CString,
+                              event: Option<AuditEventCB>) -> Error;
+
+    pub fn indy_get_audit_log(command_handle: CommandHandle,
+                              wallet_handle: WalletHandle,
+                              query_json: CString,
+                              cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_verify_audit_log(command_handle: CommandHandle,
+                                 wallet_handle: WalletHandle,
+                                 cb: Option<ResponseStringCB>) -> Error;
+}
+
+pub type AuditEventCB = extern fn(context: *const CVoid,
+                                  event_json: CString);
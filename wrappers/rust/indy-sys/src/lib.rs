@@ -11,6 +11,8 @@ pub mod payments;
 pub mod pool;
 pub mod wallet;
 pub mod logger;
+pub mod tracer;
+pub mod audit;
 pub mod cache;
 pub mod metrics;
 
@@ -1,8 +1,12 @@
 use super::*;
 
-use {Error, CommandHandle};
+use {CString, Error, CommandHandle};
 
 extern {
     pub fn indy_collect_metrics(command_handle: CommandHandle,
                                 cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_collect_metrics_with_options(command_handle: CommandHandle,
+                                             options_json: CString,
+                                             cb: Option<ResponseStringCB>) -> Error;
 }
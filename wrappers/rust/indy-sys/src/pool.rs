@@ -21,6 +21,10 @@ extern {
     pub fn indy_list_pools(command_handle: CommandHandle,
                            cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_get_pool_status(command_handle: CommandHandle,
+                                config_name: CString,
+                                cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_close_pool_ledger(command_handle: CommandHandle,
                                   handle: PoolHandle,
                                   cb: Option<ResponseEmptyCB>) -> Error;
@@ -0,0 +1,10 @@
+use {CString, CVoid, Error};
+
+extern {
+
+    pub fn indy_set_tracer(context: *const CVoid,
+                           export: Option<ExportSpanCB>) -> Error;
+}
+
+pub type ExportSpanCB = extern fn(context: *const CVoid,
+                                  span_json: CString);
@@ -53,6 +53,12 @@ extern {
                               import_config: CString,
                               cb: Option<ResponseEmptyCB>) -> Error;
 
+    pub fn indy_migrate_wallet(command_handle: CommandHandle,
+                               wallet_handle: WalletHandle,
+                               config: CString,
+                               credentials: CString,
+                               cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_close_wallet(command_handle: CommandHandle,
                              wallet_handle: WalletHandle,
                              cb: Option<ResponseEmptyCB>) -> Error;
@@ -1,7 +1,9 @@
 use {ErrorCode, IndyError};
 
+use std::collections::HashMap;
 use std::ffi::CString;
 use std::ptr::null;
+use std::sync::Mutex;
 
 use futures::Future;
 
@@ -15,6 +17,7 @@ use ffi::{ResponseStringStringCB,
           ResponseI32CB,
           ResponseEmptyCB,
           ResponseBoolCB};
+use ffi::anoncreds::RevocRegProgressCB;
 use {CommandHandle, WalletHandle, SearchHandle, BlobStorageReaderHandle, TailsWriterHandle};
 use ffi::BlobStorageReaderCfgHandle;
 
@@ -66,6 +69,39 @@ fn _issuer_create_schema(command_handle: CommandHandle, issuer_did: &str, name:
     })
 }
 
+/// Create credential schema entity with declared types of attributes.
+///
+/// Attribute types are `integer`, `date` (YYYY-MM-DD, encoded as days since 1970-01-01), `boolean` and `string`.
+/// Types are not a part of SCHEMA ledger transaction, so typed schema json must be shared off-ledger.
+///
+/// # Arguments
+/// * `issuer_did`: DID of schema issuer
+/// * `name`: a name the schema
+/// * `version`: a version of the schema
+/// * `attr_types`: schema attributes with their types {"name": "string", "dob": "date"}
+///
+/// # Returns
+/// * `schema_id`: identifier of created schema
+/// * `schema_json`: schema as json
+pub fn issuer_create_typed_schema(issuer_did: &str, name: &str, version: &str, attr_types: &str) -> Box<dyn Future<Item=(String, String), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string_string();
+
+    let err = _issuer_create_typed_schema(command_handle, issuer_did, name, version, attr_types, cb);
+
+    ResultHandler::str_str(command_handle, err, receiver)
+}
+
+fn _issuer_create_typed_schema(command_handle: CommandHandle, issuer_did: &str, name: &str, version: &str, attr_types: &str, cb: Option<ResponseStringStringCB>) -> ErrorCode {
+    let issuer_did = c_str!(issuer_did);
+    let name = c_str!(name);
+    let version = c_str!(version);
+    let attr_types = c_str!(attr_types);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_create_typed_schema(command_handle, issuer_did.as_ptr(), name.as_ptr(), version.as_ptr(), attr_types.as_ptr(), cb)
+    })
+}
+
 /// Create credential definition entity that encapsulates credentials issuer DID, credential schema, secrets used for signing credentials
 /// and secrets used for credentials revocation.
 ///
@@ -297,6 +333,67 @@ fn _issuer_create_and_store_revoc_reg(command_handle: CommandHandle, wallet_hand
     })
 }
 
+lazy_static! {
+    static ref REVOC_REG_PROGRESS_CALLBACKS: Mutex<HashMap<CommandHandle, Box<dyn Fn(u32, u32) + Send>>> = Mutex::new(HashMap::new());
+}
+
+/// Create a new revocation registry like `issuer_create_and_store_revoc_reg` does and report tails generation progress.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handler (created by Wallet::open_wallet).
+/// * `issuer_did`: a DID of the issuer signing transaction to the Ledger
+/// * `revoc_def_type`: (optional, default value depends on credential definition type) revocation registry type
+/// * `tag`: allows to distinct between revocation registries for the same issuer and credential definition
+/// * `cred_def_id`: id of stored in ledger credential definition
+/// * `config_json`: type-specific configuration of revocation registry as json (see `issuer_create_and_store_revoc_reg`)
+/// * `tails_writer_handle`: handle of blob storage to store tails
+/// * `progress`: function called with count of tails written so far and total count of tails.
+///   It is called from Libindy thread, so it should not block.
+///
+/// # Returns
+/// * `revoc_reg_id`: identifier of created revocation registry definition
+/// * `revoc_reg_def_json`: public part of revocation registry definition
+/// * `revoc_reg_entry_json`: revocation registry entry that defines initial state of revocation registry
+pub fn issuer_create_and_store_revoc_reg_with_progress(wallet_handle: WalletHandle,
+                                                       issuer_did: &str,
+                                                       revoc_def_type: Option<&str>,
+                                                       tag: &str,
+                                                       cred_def_id: &str,
+                                                       config_json: &str,
+                                                       tails_writer_handle: TailsWriterHandle,
+                                                       progress: Box<dyn Fn(u32, u32) + Send>) -> Box<dyn Future<Item=(String, String, String), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string_string_string();
+
+    REVOC_REG_PROGRESS_CALLBACKS.lock().unwrap().insert(command_handle, progress);
+
+    let err = _issuer_create_and_store_revoc_reg_with_progress(command_handle, wallet_handle, issuer_did, revoc_def_type, tag, cred_def_id, config_json, tails_writer_handle, Some(_revoc_reg_progress_cb), cb);
+
+    Box::new(ResultHandler::str_str_str(command_handle, err, receiver)
+        .then(move |res| {
+            REVOC_REG_PROGRESS_CALLBACKS.lock().unwrap().remove(&command_handle);
+            res
+        }))
+}
+
+extern fn _revoc_reg_progress_cb(command_handle: CommandHandle, tails_written: u32, tails_total: u32) {
+    if let Some(progress) = REVOC_REG_PROGRESS_CALLBACKS.lock().unwrap().get(&command_handle) {
+        progress(tails_written, tails_total);
+    }
+}
+
+fn _issuer_create_and_store_revoc_reg_with_progress(command_handle: CommandHandle, wallet_handle: WalletHandle, issuer_did: &str, revoc_def_type: Option<&str>, tag: &str, cred_def_id: &str, config_json: &str,
+                                                    tails_writer_handle: TailsWriterHandle, progress_cb: Option<RevocRegProgressCB>, cb: Option<ResponseStringStringStringCB>) -> ErrorCode {
+    let issuer_did = c_str!(issuer_did);
+    let revoc_def_type_str = opt_c_str!(revoc_def_type);
+    let tag = c_str!(tag);
+    let cred_def_id = c_str!(cred_def_id);
+    let config_json = c_str!(config_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_create_and_store_revoc_reg_with_progress(command_handle, wallet_handle, issuer_did.as_ptr(), opt_c_ptr!(revoc_def_type, revoc_def_type_str), tag.as_ptr(), cred_def_id.as_ptr(), config_json.as_ptr(), tails_writer_handle, progress_cb, cb)
+    })
+}
+
 /// Create credential offer that will be used by Prover for
 /// credential request creation. Offer includes nonce and key correctness proof
 /// for authentication between protocol steps and integrity checking.
@@ -444,6 +541,192 @@ fn _issuer_revoke_credential(command_handle: CommandHandle,
     })
 }
 
+/// Issue Credential for the given Cred Request like `issuer_create_credential` does,
+/// and store an issuance record for it in the wallet.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handler (created by Wallet::open_wallet).
+/// * `cred_offer_json`: a cred offer created by issuer_create_credential_offer
+/// * `cred_req_json`: a credential request created by prover_create_credential_req
+/// * `cred_values_json`: a credential containing attribute values for each of requested attribute names.
+/// * `rev_reg_id`: (Optional) id of revocation registry definition stored in the wallet
+/// * `blob_storage_reader_handle`: pre-configured blob storage reader instance handle that will allow to read revocation tails
+/// * `record_config_json`: issuance record config
+///     {
+///         "id": string, - id of the issuance record. Must be unique in the wallet.
+///         "tags": Optional<object> - caller tags of the issuance record
+///     }
+///
+/// # Returns
+/// * `cred_json`: Credential json containing signed credential values
+/// * `cred_revoc_id`: local id for revocation info (Can be used for revocation of this cred)
+/// * `revoc_reg_delta_json`: Revocation registry delta json with a newly issued credential
+pub fn issuer_create_credential_with_record(wallet_handle: WalletHandle,
+                                            cred_offer_json: &str,
+                                            cred_req_json: &str,
+                                            cred_values_json: &str,
+                                            rev_reg_id: Option<&str>,
+                                            blob_storage_reader_handle: BlobStorageReaderHandle,
+                                            record_config_json: &str) -> Box<dyn Future<Item=(String, Option<String>, Option<String>), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string_opt_string_opt_string();
+
+    let err = _issuer_create_credential_with_record(command_handle, wallet_handle, cred_offer_json, cred_req_json, cred_values_json, rev_reg_id, blob_storage_reader_handle, record_config_json, cb);
+
+    ResultHandler::str_optstr_optstr(command_handle, err, receiver)
+}
+
+fn _issuer_create_credential_with_record(
+    command_handle: CommandHandle,
+    wallet_handle: WalletHandle,
+    cred_offer_json: &str,
+    cred_req_json: &str,
+    cred_values_json: &str,
+    rev_reg_id: Option<&str>,
+    blob_storage_reader_handle: BlobStorageReaderHandle,
+    record_config_json: &str,
+    cb: Option<ResponseStringStringStringCB>
+) -> ErrorCode {
+    let cred_offer_json = c_str!(cred_offer_json);
+    let cred_req_json = c_str!(cred_req_json);
+    let cred_values_json = c_str!(cred_values_json);
+    let rev_reg_id_str = opt_c_str!(rev_reg_id);
+    let record_config_json = c_str!(record_config_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_create_credential_with_record(command_handle, wallet_handle, cred_offer_json.as_ptr(), cred_req_json.as_ptr(), cred_values_json.as_ptr(), opt_c_ptr!(rev_reg_id, rev_reg_id_str), blob_storage_reader_handle, record_config_json.as_ptr(), cb)
+    })
+}
+
+/// Revoke a credential identified by an issuance record (created by issuer_create_credential_with_record).
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handler (created by Wallet::open_wallet).
+/// * `blob_storage_reader_cfg_handle`: configuration of blob storage reader handle that will allow to read revocation tails
+/// * `record_id`: id of the issuance record
+///
+/// # Returns
+/// * `revoc_reg_delta_json`: Revocation registry delta json with a revoked credential
+pub fn issuer_revoke_credential_by_record(wallet_handle: WalletHandle, blob_storage_reader_cfg_handle: BlobStorageReaderCfgHandle, record_id: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _issuer_revoke_credential_by_record(command_handle, wallet_handle, blob_storage_reader_cfg_handle, record_id, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _issuer_revoke_credential_by_record(command_handle: CommandHandle,
+                                       wallet_handle: WalletHandle,
+                                       blob_storage_reader_cfg_handle: BlobStorageReaderCfgHandle,
+                                       record_id: &str,
+                                       cb: Option<ResponseStringCB>) -> ErrorCode {
+    let record_id = c_str!(record_id);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_revoke_credential_by_record(command_handle, wallet_handle, blob_storage_reader_cfg_handle, record_id.as_ptr(), cb)
+    })
+}
+
+/// Get issuance record stored in the wallet.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handler (created by Wallet::open_wallet).
+/// * `record_id`: id of the issuance record
+///
+/// # Returns
+/// * `record_json`: issuance record
+///     {
+///         "id": string,
+///         "cred_def_id": string,
+///         "rev_reg_id": Optional<string>,
+///         "cred_rev_id": Optional<string>,
+///         "issued_at": number,
+///         "revoked_at": Optional<number>,
+///         "tags": object,
+///         "values_hash": string,
+///         "values_salt": string
+///     }
+pub fn issuer_get_issuance_record(wallet_handle: WalletHandle, record_id: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _issuer_get_issuance_record(command_handle, wallet_handle, record_id, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _issuer_get_issuance_record(command_handle: CommandHandle, wallet_handle: WalletHandle, record_id: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let record_id = c_str!(record_id);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_get_issuance_record(command_handle, wallet_handle, record_id.as_ptr(), cb)
+    })
+}
+
+/// Search for issuance records stored in wallet.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handler (created by Wallet::open_wallet).
+/// * `query_json`: Wql query filter for issuance records searching based on tags.
+///     Besides caller tags "cred_def_id", "rev_reg_id", "cred_rev_id" and "revoked" ("1" or "0") can be used.
+///
+/// # Returns
+/// * `search_handle`: Search handle that can be used later to fetch records by small batches (with issuer_fetch_issuance_records)
+/// * `total_count`: Total count of records
+pub fn issuer_search_issuance_records(wallet_handle: WalletHandle, query_json: Option<&str>) -> Box<dyn Future<Item=(SearchHandle, usize), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_handle_usize();
+
+    let err = _issuer_search_issuance_records(command_handle, wallet_handle, query_json, cb);
+
+    ResultHandler::handle_usize(command_handle, err, receiver)
+}
+
+fn _issuer_search_issuance_records(command_handle: CommandHandle, wallet_handle: WalletHandle, query_json: Option<&str>, cb: Option<ResponseI32UsizeCB>) -> ErrorCode {
+    let query_json_str = opt_c_str!(query_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_search_issuance_records(command_handle, wallet_handle, opt_c_ptr!(query_json, query_json_str), cb)
+    })
+}
+
+/// Fetch next issuance records for search.
+///
+/// # Arguments
+/// * `search_handle`: Search handle (created by issuer_search_issuance_records)
+/// * `count`: Count of records to fetch
+///
+/// # Returns
+/// * `records_json`: List of issuance records (see issuer_get_issuance_record)
+pub fn issuer_fetch_issuance_records(search_handle: SearchHandle, count: usize) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _issuer_fetch_issuance_records(command_handle, search_handle, count, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _issuer_fetch_issuance_records(command_handle: CommandHandle, search_handle: SearchHandle, count: usize, cb: Option<ResponseStringCB>) -> ErrorCode {
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_fetch_issuance_records(command_handle, search_handle, count, cb)
+    })
+}
+
+/// Close issuance records search (make search handle invalid)
+///
+/// # Arguments
+/// * `search_handle`: Search handle (created by issuer_search_issuance_records)
+pub fn issuer_close_issuance_records_search(search_handle: SearchHandle) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _issuer_close_issuance_records_search(command_handle, search_handle, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _issuer_close_issuance_records_search(command_handle: CommandHandle, search_handle: SearchHandle, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_close_issuance_records_search(command_handle, search_handle, cb)
+    })
+}
+
 /// Merge two revocation registry deltas (returned by create_credential or revoke_credential) to accumulate common delta.
 /// Send common delta to ledger to reduce the load.
 ///
@@ -496,6 +779,82 @@ fn _prover_create_master_secret(command_handle: CommandHandle, wallet_handle: Wa
     })
 }
 
+/// Lists master secrets stored in the wallet. Master secret values are never returned.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open_wallet).
+///
+/// # Returns
+/// * `master_secrets_json` - [{
+///     "id": string,
+///     "default": bool,
+///     "retired": bool
+/// }]
+pub fn prover_list_master_secrets(wallet_handle: WalletHandle) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _prover_list_master_secrets(command_handle, wallet_handle, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _prover_list_master_secrets(command_handle: CommandHandle, wallet_handle: WalletHandle, cb: Option<ResponseStringCB>) -> ErrorCode {
+    ErrorCode::from(unsafe {
+        anoncreds::indy_prover_list_master_secrets(command_handle, wallet_handle, cb)
+    })
+}
+
+/// Marks a master secret stored in the wallet as the default one.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open_wallet).
+/// * `master_secret_id`: the id of the master secret stored in the wallet
+pub fn prover_set_default_master_secret(wallet_handle: WalletHandle, master_secret_id: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _prover_set_default_master_secret(command_handle, wallet_handle, master_secret_id, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _prover_set_default_master_secret(command_handle: CommandHandle, wallet_handle: WalletHandle, master_secret_id: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let master_secret_id = c_str!(master_secret_id);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_prover_set_default_master_secret(command_handle, wallet_handle, master_secret_id.as_ptr(), cb)
+    })
+}
+
+/// Creates a new master secret and retires the given one.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open_wallet).
+/// * `master_secret_id`: the id of the master secret to retire
+/// * `new_master_secret_id`: (optional, if not present random one will be generated) id of the new master secret
+///
+/// # Returns
+/// * `rotation_json` - {
+///     "master_secret_id": string,
+///     "retired_master_secret_id": string,
+///     "credentials_to_reissue": [<credential_info>]
+/// }
+pub fn prover_rotate_master_secret(wallet_handle: WalletHandle, master_secret_id: &str, new_master_secret_id: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _prover_rotate_master_secret(command_handle, wallet_handle, master_secret_id, new_master_secret_id, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _prover_rotate_master_secret(command_handle: CommandHandle, wallet_handle: WalletHandle, master_secret_id: &str, new_master_secret_id: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let master_secret_id = c_str!(master_secret_id);
+    let new_master_secret_id_str = opt_c_str!(new_master_secret_id);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_prover_rotate_master_secret(command_handle, wallet_handle, master_secret_id.as_ptr(), opt_c_ptr!(new_master_secret_id, new_master_secret_id_str), cb)
+    })
+}
+
 /// Gets human readable credential by the given id.
 ///
 /// # Arguments
@@ -527,45 +886,182 @@ fn _prover_get_credential(command_handle: CommandHandle, wallet_handle: WalletHa
     })
 }
 
-/// Deletes credential by given id.
+/// Gets status of the credential by the given id.
 ///
 /// # Arguments
 /// * `wallet_handle`: wallet handle (created by Wallet::open_wallet).
 /// * `cred_id`: Identifier by which requested credential is stored in the wallet
-pub fn prover_delete_credential(wallet_handle: WalletHandle, cred_id: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
-    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+///
+/// # Returns
+/// credential status json:
+///     {
+///         "referent": string, - id of credential in the wallet
+///         "revoked": bool, - whether credential is known to be revoked (see prover_create_credential_revocation_state)
+///         "revoked_as_of": Optional<int>, - earliest timestamp credential is known to be revoked at
+///         "expires_at": Optional<int>, - expiry timestamp (see prover_set_credential_expiry_attr)
+///         "replaced_by": Optional<string>, - id of credential with the same identity attribute value stored after this one
+///                                             (see prover_set_credential_identity_attr)
+///         "active": bool - whether credential is neither revoked, nor replaced, nor expired
+///     }
+pub fn prover_get_credential_status(wallet_handle: WalletHandle, cred_id: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
 
-    let err = _prover_delete_credential(command_handle, wallet_handle, cred_id, cb);
+    let err = _prover_get_credential_status(command_handle, wallet_handle, cred_id, cb);
 
-    ResultHandler::empty(command_handle, err, receiver)
+    ResultHandler::str(command_handle, err, receiver)
 }
 
-fn _prover_delete_credential(command_handle: CommandHandle, wallet_handle: WalletHandle, cred_id: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+fn _prover_get_credential_status(command_handle: CommandHandle, wallet_handle: WalletHandle, cred_id: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
     let cred_id = c_str!(cred_id);
 
     ErrorCode::from(unsafe {
-        anoncreds::indy_prover_delete_credential(command_handle, wallet_handle, cred_id.as_ptr(), cb)
+        anoncreds::indy_prover_get_credential_status(command_handle, wallet_handle, cred_id.as_ptr(), cb)
     })
 }
 
-/// Creates a credential request for the given credential offer.
-///
-/// The method creates a blinded master secret for a master secret identified by a provided name.
-/// The master secret identified by the name must be already stored in the secure wallet (see create_master_secret)
-/// The blinded master secret is a part of the credential request.
+/// Designate credential attribute that carries expiry of credentials of the credential definition.
+/// Expiry of subsequent and existing credentials on the credential definition is taken from this attribute.
 ///
 /// # Arguments
-/// * `wallet_handle`: wallet handle (created by open_wallet)
-/// * `prover_did`: a DID of the prover
-/// * `cred_offer_json`: credential offer as a json containing information about the issuer and a credential
-/// * `cred_def_json`: credential definition json related to <cred_def_id> in <cred_offer_json>
-/// * `master_secret_id`: the id of the master secret stored in the wallet
+/// * `wallet_handle`: wallet handle (created by Wallet::open_wallet).
+/// * `cred_def_id`: credential definition id
+/// * `attr_name`: name of the attribute with expiry as a unix timestamp or a date in YYYY-MM-DD format,
+///     None to stop tracking expiry of credentials on the credential definition
+pub fn prover_set_credential_expiry_attr(wallet_handle: WalletHandle, cred_def_id: &str, attr_name: Option<&str>) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _prover_set_credential_expiry_attr(command_handle, wallet_handle, cred_def_id, attr_name, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _prover_set_credential_expiry_attr(command_handle: CommandHandle, wallet_handle: WalletHandle, cred_def_id: &str, attr_name: Option<&str>, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let cred_def_id = c_str!(cred_def_id);
+    let attr_name_str = opt_c_str!(attr_name);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_prover_set_credential_expiry_attr(command_handle, wallet_handle, cred_def_id.as_ptr(), opt_c_ptr!(attr_name, attr_name_str), cb)
+    })
+}
+
+/// Designate credential attribute that identifies the subject of credentials of the credential definition.
+/// Subsequently stored or imported credential replaces the other credentials of the credential definition
+/// with the same value of this attribute (see prover_get_credential_status).
+/// Credentials are never replaced while no identity attribute is designated.
 ///
-/// # Returns
-/// * `cred_req_json`: Credential request json for creation of credential by Issuer
-///     {
-///      "prover_did" : string,
-///      "cred_def_id" : string,
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open_wallet).
+/// * `cred_def_id`: credential definition id
+/// * `attr_name`: name of the attribute identifying the subject of credentials,
+///     None to stop replacing credentials on the credential definition
+pub fn prover_set_credential_identity_attr(wallet_handle: WalletHandle, cred_def_id: &str, attr_name: Option<&str>) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _prover_set_credential_identity_attr(command_handle, wallet_handle, cred_def_id, attr_name, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _prover_set_credential_identity_attr(command_handle: CommandHandle, wallet_handle: WalletHandle, cred_def_id: &str, attr_name: Option<&str>, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let cred_def_id = c_str!(cred_def_id);
+    let attr_name_str = opt_c_str!(attr_name);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_prover_set_credential_identity_attr(command_handle, wallet_handle, cred_def_id.as_ptr(), opt_c_ptr!(attr_name, attr_name_str), cb)
+    })
+}
+
+/// Deletes credential by given id.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open_wallet).
+/// * `cred_id`: Identifier by which requested credential is stored in the wallet
+pub fn prover_delete_credential(wallet_handle: WalletHandle, cred_id: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _prover_delete_credential(command_handle, wallet_handle, cred_id, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _prover_delete_credential(command_handle: CommandHandle, wallet_handle: WalletHandle, cred_id: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let cred_id = c_str!(cred_id);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_prover_delete_credential(command_handle, wallet_handle, cred_id.as_ptr(), cb)
+    })
+}
+
+/// Exports a single credential stored in the wallet as an encrypted bundle.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open_wallet).
+/// * `cred_id`: Identifier by which requested credential is stored in the wallet
+/// * `export_config_json`: export configuration (see `indy_prover_export_credential` in libindy)
+///
+/// # Returns
+/// Encrypted credential bundle json
+pub fn prover_export_credential(wallet_handle: WalletHandle, cred_id: &str, export_config_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _prover_export_credential(command_handle, wallet_handle, cred_id, export_config_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _prover_export_credential(command_handle: CommandHandle, wallet_handle: WalletHandle, cred_id: &str, export_config_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let cred_id = c_str!(cred_id);
+    let export_config_json = c_str!(export_config_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_prover_export_credential(command_handle, wallet_handle, cred_id.as_ptr(), export_config_json.as_ptr(), cb)
+    })
+}
+
+/// Imports a credential from an encrypted bundle created by `prover_export_credential`.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open_wallet).
+/// * `bundle_json`: encrypted credential bundle
+/// * `import_config_json`: import configuration (see `indy_prover_import_credential` in libindy)
+///
+/// # Returns
+/// Identifier by which credential is stored in the wallet
+pub fn prover_import_credential(wallet_handle: WalletHandle, bundle_json: &str, import_config_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _prover_import_credential(command_handle, wallet_handle, bundle_json, import_config_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _prover_import_credential(command_handle: CommandHandle, wallet_handle: WalletHandle, bundle_json: &str, import_config_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let bundle_json = c_str!(bundle_json);
+    let import_config_json = c_str!(import_config_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_prover_import_credential(command_handle, wallet_handle, bundle_json.as_ptr(), import_config_json.as_ptr(), cb)
+    })
+}
+
+/// Creates a credential request for the given credential offer.
+///
+/// The method creates a blinded master secret for a master secret identified by a provided name.
+/// The master secret identified by the name must be already stored in the secure wallet (see create_master_secret)
+/// The blinded master secret is a part of the credential request.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by open_wallet)
+/// * `prover_did`: a DID of the prover
+/// * `cred_offer_json`: credential offer as a json containing information about the issuer and a credential
+/// * `cred_def_json`: credential definition json related to <cred_def_id> in <cred_offer_json>
+/// * `master_secret_id`: the id of the master secret stored in the wallet
+///
+/// # Returns
+/// * `cred_req_json`: Credential request json for creation of credential by Issuer
+///     {
+///      "prover_did" : string,
+///      "cred_def_id" : string,
 ///         // Fields below can depend on Cred Def type
 ///      "blinded_ms" : <blinded_master_secret>,
 ///                     (opaque type that contains data structures internal to Ursa.
@@ -618,11 +1114,20 @@ fn _prover_create_credential_req(command_handle: CommandHandle, wallet_handle: W
 ///         "attr::<attribute name>::value": <attribute raw value>,
 ///     }
 ///
+/// Attributes listed as "<attribute name>::<type>" where type is the one the schema declares for the attribute
+/// (integer, date or boolean, see issuer_create_typed_schema) are tagged as well and additionally get an unencrypted tag,
+/// so the wallet can compare their values in typed queries (see prover_search_credentials):
+///     {
+///         "~attr::<attribute name>::int": <encoded value in sortable form>,
+///     }
+/// Such tag is not written for values that are not valid for the type.
+///
 /// # Arguments
 /// command_handle: command handle to map callback to user context.
 /// wallet_handle: wallet handle (created by Wallet::open_wallet).
 /// cred_def_id: credential definition id
-/// tag_attrs_json: JSON array with names of attributes to tag by policy, or null for all
+/// tag_attrs_json: JSON array with names of attributes to tag by policy, or null for all.
+///     Names given as "<attribute name>::<type>" opt in for unencrypted typed tags
 /// retroactive: boolean, whether to apply policy to existing credentials on credential definition identifier
 pub fn prover_set_credential_attr_tag_policy(wallet_handle: WalletHandle, cred_def_id: &str, tag_attrs_json: Option<&str>, retroactive: bool) -> Box<dyn Future<Item=(), Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
@@ -1082,6 +1587,55 @@ fn _prover_close_credentials_search_for_proof_req(command_handle: CommandHandle,
     })
 }
 
+/// Explains why credentials stored in the wallet can or can not satisfy the given proof request.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by open_wallet)
+/// * `proof_request_json`: proof request json (same format as for prover_search_credentials_for_proof_req)
+/// * `extra_query_json`: (Optional) List of extra queries that will be applied to correspondent attribute/predicate
+///
+/// # Returns
+/// * `diagnostics_json`: json with explanation per referent:
+///     {
+///         "satisfiable": bool,
+///         "requested_attributes": {"<attr_referent>": <referent_diagnostics>},
+///         "requested_predicates": {"<predicate_referent>": <referent_diagnostics>},
+///     }
+/// `referent_diagnostics`:
+///     {
+///         "satisfiable": bool,
+///         "non_revoked": Optional<non_revoc_interval>,
+///         "credentials": [{"cred_info": <credential_info>, "satisfies": bool, "issues": [<issue>]}],
+///         "issues": [<issue>]
+///     }
+/// `issue`:
+///     {
+///         "reason": string,
+///         "message": string,
+///         "restriction": Optional<wql query>,
+///         "tag": Optional<string>,
+///         "actual": Optional<string>,
+///     }
+pub fn prover_explain_proof_req(wallet_handle: WalletHandle, proof_request_json: &str, extra_query_json: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _prover_explain_proof_req(command_handle, wallet_handle, proof_request_json, extra_query_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _prover_explain_proof_req(command_handle: CommandHandle,
+                             wallet_handle: WalletHandle,
+                             proof_request_json: &str,
+                             extra_query_json: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let proof_request_json = c_str!(proof_request_json);
+    let extra_query_json_str = opt_c_str!(extra_query_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_prover_explain_proof_req(command_handle, wallet_handle, proof_request_json.as_ptr(), opt_c_ptr!(extra_query_json, extra_query_json_str), cb)
+    })
+}
+
 /// Creates a proof according to the given proof request
 /// Either a corresponding credential with optionally revealed attributes or self-attested attribute must be provided
 /// for each requested attribute (see indy_prover_get_credentials_for_pool_req).
@@ -1235,6 +1789,42 @@ fn _prover_create_proof(command_handle: CommandHandle, wallet_handle: WalletHand
     })
 }
 
+/// Creates a proof according to the given proof request with additional proof creation options.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open_wallet).
+/// * `proof_req_json`: proof request json (see `prover_create_proof`)
+/// * `requested_credentials_json`: either a credential or self-attested attribute for each requested attribute (see `prover_create_proof`)
+/// * `master_secret_id`: (optional, default master secret is used if not set) the id of the master secret stored in the wallet
+/// * `schemas_json`: all schemas participating in the proof request
+/// * `credential_defs_json`: all credential definitions participating in the proof request
+/// * `rev_states_json`: all revocation states participating in the proof request
+/// * `options_json`: proof creation options (see `indy_prover_create_proof_with_options` in libindy)
+///
+/// # Returns
+/// Proof json (see `prover_create_proof`)
+pub fn prover_create_proof_with_options(wallet_handle: WalletHandle, proof_req_json: &str, requested_credentials_json: &str, master_secret_id: Option<&str>, schemas_json: &str, credential_defs_json: &str, rev_states_json: &str, options_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _prover_create_proof_with_options(command_handle, wallet_handle, proof_req_json, requested_credentials_json, master_secret_id, schemas_json, credential_defs_json, rev_states_json, options_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _prover_create_proof_with_options(command_handle: CommandHandle, wallet_handle: WalletHandle, proof_req_json: &str, requested_credentials_json: &str, master_secret_id: Option<&str>, schemas_json: &str, credential_defs_json: &str, rev_states_json: &str, options_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let proof_req_json = c_str!(proof_req_json);
+    let requested_credentials_json = c_str!(requested_credentials_json);
+    let master_secret_id_str = opt_c_str!(master_secret_id);
+    let schemas_json = c_str!(schemas_json);
+    let credential_defs_json = c_str!(credential_defs_json);
+    let rev_states_json = c_str!(rev_states_json);
+    let options_json = c_str!(options_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_prover_create_proof_with_options(command_handle, wallet_handle, proof_req_json.as_ptr(), requested_credentials_json.as_ptr(), opt_c_ptr!(master_secret_id, master_secret_id_str), schemas_json.as_ptr(), credential_defs_json.as_ptr(), rev_states_json.as_ptr(), options_json.as_ptr(), cb)
+    })
+}
+
 
 /// Verifies a proof (of multiple credential).
 /// All required schemas, public keys and revocation registries must be provided.
@@ -1345,6 +1935,126 @@ fn _verifier_verify_proof(command_handle: CommandHandle, proof_request_json: &st
     })
 }
 
+/// Verifies a proof (of multiple credential) with additional verification options.
+///
+/// # Arguments
+/// * `proof_request_json`: proof request json (see `verifier_verify_proof`)
+/// * `proof_json`: created for request proof json (see `verifier_verify_proof`)
+/// * `schemas_json`: all schema jsons participating in the proof
+/// * `credential_defs_json`: all credential definitions json participating in the proof
+/// * `rev_reg_defs_json`: all revocation registry definitions json participating in the proof
+/// * `rev_regs_json`: all revocation registries json participating in the proof
+/// * `options_json`: verification options
+///     {
+///         "check_raw_encoding": Optional<bool>, // (false by default) check that every revealed raw value
+///                               // re-encodes to the proven encoded value (see `encode_credential_values`)
+///     }
+///
+/// # Returns
+/// * `valid`: true - if signature is valid, false - otherwise
+pub fn verifier_verify_proof_with_options(proof_request_json: &str, proof_json: &str, schemas_json: &str, credential_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str, options_json: &str) -> Box<dyn Future<Item=bool, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_bool();
+
+    let err = _verifier_verify_proof_with_options(command_handle, proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json, options_json, cb);
+
+    ResultHandler::bool(command_handle, err, receiver)
+}
+
+fn _verifier_verify_proof_with_options(command_handle: CommandHandle, proof_request_json: &str, proof_json: &str, schemas_json: &str, credential_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str, options_json: &str, cb: Option<ResponseBoolCB>) -> ErrorCode {
+    let proof_request_json = c_str!(proof_request_json);
+    let proof_json = c_str!(proof_json);
+    let schemas_json = c_str!(schemas_json);
+    let credential_defs_json = c_str!(credential_defs_json);
+    let rev_reg_defs_json = c_str!(rev_reg_defs_json);
+    let rev_regs_json = c_str!(rev_regs_json);
+    let options_json = c_str!(options_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_verifier_verify_proof_with_options(command_handle, proof_request_json.as_ptr(), proof_json.as_ptr(), schemas_json.as_ptr(), credential_defs_json.as_ptr(), rev_reg_defs_json.as_ptr(), rev_regs_json.as_ptr(), options_json.as_ptr(), cb)
+    })
+}
+
+/// Verifies a proof wrapped into a W3C Verifiable Presentation (see `proof_to_w3c`).
+///
+/// Besides the checks of `verifier_verify_proof` the values shown in `credentialSubject`
+/// of every presented credential must match the values revealed by the proof.
+///
+/// # Arguments
+/// * `proof_request_json`: proof request json (see `verifier_verify_proof`)
+/// * `w3c_presentation_json`: W3C Verifiable Presentation json (see `proof_to_w3c`)
+/// * `schemas_json`: all schema jsons participating in the proof
+/// * `credential_defs_json`: all credential definitions json participating in the proof
+/// * `rev_reg_defs_json`: all revocation registry definitions json participating in the proof
+/// * `rev_regs_json`: all revocation registries json participating in the proof
+///
+/// # Returns
+/// * `valid`: true - if signature is valid, false - otherwise
+pub fn verifier_verify_w3c_presentation(proof_request_json: &str, w3c_presentation_json: &str, schemas_json: &str, credential_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str) -> Box<dyn Future<Item=bool, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_bool();
+
+    let err = _verifier_verify_w3c_presentation(command_handle, proof_request_json, w3c_presentation_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json, cb);
+
+    ResultHandler::bool(command_handle, err, receiver)
+}
+
+fn _verifier_verify_w3c_presentation(command_handle: CommandHandle, proof_request_json: &str, w3c_presentation_json: &str, schemas_json: &str, credential_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str, cb: Option<ResponseBoolCB>) -> ErrorCode {
+    let proof_request_json = c_str!(proof_request_json);
+    let w3c_presentation_json = c_str!(w3c_presentation_json);
+    let schemas_json = c_str!(schemas_json);
+    let credential_defs_json = c_str!(credential_defs_json);
+    let rev_reg_defs_json = c_str!(rev_reg_defs_json);
+    let rev_regs_json = c_str!(rev_regs_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_verifier_verify_w3c_presentation(command_handle, proof_request_json.as_ptr(), w3c_presentation_json.as_ptr(), schemas_json.as_ptr(), credential_defs_json.as_ptr(), rev_reg_defs_json.as_ptr(), rev_regs_json.as_ptr(), cb)
+    })
+}
+
+/// Verifies a proof (of multiple credential) and returns a detailed verification report.
+///
+/// Performs the same checks as `verifier_verify_proof` without stopping on the first failure and
+/// reports a result per requested attribute, per predicate and per sub proof.
+///
+/// # Arguments
+/// * `proof_request_json`: proof request json (see `verifier_verify_proof`)
+/// * `proof_json`: created for request proof json (see `verifier_verify_proof`)
+/// * `schemas_json`: all schema jsons participating in the proof
+/// * `credential_defs_json`: all credential definitions json participating in the proof
+/// * `rev_reg_defs_json`: all revocation registry definitions json participating in the proof
+/// * `rev_regs_json`: all revocation registries json participating in the proof
+/// * `options_json`: verification options (see `verifier_verify_proof_with_options`)
+///
+/// # Returns
+/// * `report_json`: verification report json
+///     {
+///         "valid": bool,
+///         "requested_attributes": { "<attr_referent>": <referent_report> },
+///         "requested_predicates": { "<predicate_referent>": <referent_report> },
+///         "sub_proofs": [{ "sub_proof_index", "schema_id", "cred_def_id", "rev_reg_id", "timestamp", "non_revocation_checked", "failures" }],
+///         "failures": [{ "reason": string, "message": string }]
+///     }
+pub fn verifier_verify_proof_detailed(proof_request_json: &str, proof_json: &str, schemas_json: &str, credential_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str, options_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _verifier_verify_proof_detailed(command_handle, proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json, options_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _verifier_verify_proof_detailed(command_handle: CommandHandle, proof_request_json: &str, proof_json: &str, schemas_json: &str, credential_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str, options_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let proof_request_json = c_str!(proof_request_json);
+    let proof_json = c_str!(proof_json);
+    let schemas_json = c_str!(schemas_json);
+    let credential_defs_json = c_str!(credential_defs_json);
+    let rev_reg_defs_json = c_str!(rev_reg_defs_json);
+    let rev_regs_json = c_str!(rev_regs_json);
+    let options_json = c_str!(options_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_verifier_verify_proof_detailed(command_handle, proof_request_json.as_ptr(), proof_json.as_ptr(), schemas_json.as_ptr(), credential_defs_json.as_ptr(), rev_reg_defs_json.as_ptr(), rev_regs_json.as_ptr(), options_json.as_ptr(), cb)
+    })
+}
+
 
 /// Create revocation state for a credential that corresponds to a particular time.
 ///
@@ -1388,6 +2098,38 @@ fn _create_revocation_state(command_handle: CommandHandle, blob_storage_reader_h
     })
 }
 
+/// Create revocation state for a credential stored in the wallet that corresponds to a particular time.
+/// Works as `create_revocation_state`, taking credential revocation id from the credential.
+/// If the credential is revoked according to the revocation delta, the credential is marked as revoked in the wallet.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open_wallet).
+/// * `blob_storage_reader_handle`: configuration of blob storage reader handle that will allow to read revocation tails
+/// * `rev_reg_def_json`: revocation registry definition json
+/// * `rev_reg_delta_json`: revocation registry delta which covers the whole registry existence time
+/// * `timestamp`: time represented as a total number of seconds from Unix Epoch
+/// * `cred_id`: Identifier by which the credential is stored in the wallet
+///
+/// # Returns
+/// * `revocation_state_json` (see `create_revocation_state`)
+pub fn prover_create_credential_revocation_state(wallet_handle: WalletHandle, blob_storage_reader_handle: BlobStorageReaderHandle, rev_reg_def_json: &str, rev_reg_delta_json: &str, timestamp: u64, cred_id: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _prover_create_credential_revocation_state(command_handle, wallet_handle, blob_storage_reader_handle, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_id, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _prover_create_credential_revocation_state(command_handle: CommandHandle, wallet_handle: WalletHandle, blob_storage_reader_handle: BlobStorageReaderHandle, rev_reg_def_json: &str, rev_reg_delta_json: &str, timestamp: u64, cred_id: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let rev_reg_def_json = c_str!(rev_reg_def_json);
+    let rev_reg_delta_json = c_str!(rev_reg_delta_json);
+    let cred_id = c_str!(cred_id);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_prover_create_credential_revocation_state(command_handle, wallet_handle, blob_storage_reader_handle, rev_reg_def_json.as_ptr(), rev_reg_delta_json.as_ptr(), timestamp, cred_id.as_ptr(), cb)
+    })
+}
+
 /// Create a new revocation state for a credential based on a revocation state created before.
 /// Note that provided revocation delta must cover the registry gap from based state creation until the specified time
 /// (this new delta should be received with parameters: `from`: `state_timestamp` and `to`: `needed_time`).
@@ -1457,6 +2199,32 @@ fn _generate_nonce(command_handle: CommandHandle, cb: Option<ResponseStringCB>)
     })
 }
 
+/// Builds a proof request of version 2.0 from a high level template.
+///
+/// # Arguments
+/// * `template_json`: proof request template
+///     {"attributes": [{"name": "name"}], "predicates": [{"name": "age", "p_type": ">=", "p_value": 18}],
+///      "issuer_dids": Optional<[string]>, "schema_ids": Optional<[string]>, "cred_def_ids": Optional<[string]>,
+///      "freshness": Optional<int>, "identifiers": Optional<"qualified" | "unqualified">, ...}
+///
+/// # Returns
+/// * `proof_request_json`: validated proof request with generated nonce and sorted keys
+pub fn verifier_build_proof_request(template_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _verifier_build_proof_request(command_handle, template_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _verifier_build_proof_request(command_handle: CommandHandle, template_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let template_json = c_str!(template_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_verifier_build_proof_request(command_handle, template_json.as_ptr(), cb)
+    })
+}
+
 /// Get unqualified form (short form without method) of a fully qualified entity like DID.
 ///
 /// This function should be used to the proper casting of fully qualified entity to unqualified form in the following cases:
@@ -1494,3 +2262,182 @@ fn _to_unqualified(command_handle: CommandHandle, entity: &str, cb: Option<Respo
         anoncreds::indy_to_unqualified(command_handle, entity.as_ptr(), cb)
     })
 }
+
+/// Encodes raw credential attribute values according to the de facto Indy encoding rules:
+/// 32-bit integers are encoded as themselves, any other value as decimal representation
+/// of big-endian SHA-256 hash of its UTF-8 bytes.
+///
+/// # Arguments
+/// * `values_json`: raw credential values {"attr1": "raw_value1", "attr2": "raw_value2"}
+///
+/// # Returns
+/// * `cred_values_json`: credential values that can be passed to `issuer_create_credential`
+///     {"attr1": {"raw": "raw_value1", "encoded": "encoded_value1"}, ...}
+pub fn encode_credential_values(values_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _encode_credential_values(command_handle, values_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _encode_credential_values(command_handle: CommandHandle, values_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let values_json = c_str!(values_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_encode_credential_values(command_handle, values_json.as_ptr(), cb)
+    })
+}
+
+/// Encodes raw credential attribute values according to the attribute types declared in the schema.
+///
+/// # Arguments
+/// * `values_json`: raw credential values {"attr1": "raw_value1", "attr2": "raw_value2"}
+/// * `schema_json`: schema with attribute types (see `issuer_create_typed_schema`)
+///
+/// # Returns
+/// * `cred_values_json`: credential values that can be passed to `issuer_create_credential`
+///     {"attr1": {"raw": "raw_value1", "encoded": "encoded_value1"}, ...}
+pub fn encode_typed_credential_values(values_json: &str, schema_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _encode_typed_credential_values(command_handle, values_json, schema_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _encode_typed_credential_values(command_handle: CommandHandle, values_json: &str, schema_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let values_json = c_str!(values_json);
+    let schema_json = c_str!(schema_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_encode_typed_credential_values(command_handle, values_json.as_ptr(), schema_json.as_ptr(), cb)
+    })
+}
+
+/// Builds a predicate over a date attribute for a proof request.
+///
+/// # Arguments
+/// * `date_predicate_json`: predicate with date value
+///     {"name": "dob", "p_type": "<", "date": "2006-10-17", "restrictions": Optional<filter_json>, "non_revoked": Optional<interval>}
+///
+/// # Returns
+/// * `predicate_json`: predicate info with `p_value` as number of days since 1970-01-01
+pub fn build_date_predicate(date_predicate_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_date_predicate(command_handle, date_predicate_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_date_predicate(command_handle: CommandHandle, date_predicate_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let date_predicate_json = c_str!(date_predicate_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_build_date_predicate(command_handle, date_predicate_json.as_ptr(), cb)
+    })
+}
+
+/// Wraps a credential into a W3C Verifiable Credential document.
+///
+/// Encoded values and CL signature data are carried in the `proof` section,
+/// so the credential can be restored with `credential_from_w3c`.
+///
+/// # Arguments
+/// * `cred_json`: credential json (see `issuer_create_credential`)
+/// * `issuance_date`: RFC 3339 UTC time the credential was issued, e.g. "2020-01-01T00:00:00Z"
+///
+/// # Returns
+/// * `w3c_cred_json`: W3C Verifiable Credential json
+pub fn credential_to_w3c(cred_json: &str, issuance_date: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _credential_to_w3c(command_handle, cred_json, issuance_date, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _credential_to_w3c(command_handle: CommandHandle, cred_json: &str, issuance_date: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let cred_json = c_str!(cred_json);
+    let issuance_date = c_str!(issuance_date);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_credential_to_w3c(command_handle, cred_json.as_ptr(), issuance_date.as_ptr(), cb)
+    })
+}
+
+/// Restores a credential from a W3C Verifiable Credential document created by `credential_to_w3c`.
+///
+/// # Arguments
+/// * `w3c_cred_json`: W3C Verifiable Credential json
+/// * `schema_json`: schema json the credential was issued for, its attribute types define the expected encoding
+///
+/// # Returns
+/// * `cred_json`: credential json
+pub fn credential_from_w3c(w3c_cred_json: &str, schema_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _credential_from_w3c(command_handle, w3c_cred_json, schema_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _credential_from_w3c(command_handle: CommandHandle, w3c_cred_json: &str, schema_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let w3c_cred_json = c_str!(w3c_cred_json);
+    let schema_json = c_str!(schema_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_credential_from_w3c(command_handle, w3c_cred_json.as_ptr(), schema_json.as_ptr(), cb)
+    })
+}
+
+/// Wraps a proof into a W3C Verifiable Presentation document.
+///
+/// # Arguments
+/// * `proof_request_json`: proof request json the proof was created for
+/// * `proof_json`: proof json (see `prover_create_proof`)
+/// * `issuance_dates_json`: RFC 3339 UTC times the proven credentials were issued, in the order of `identifiers` of the proof
+///
+/// # Returns
+/// * `w3c_presentation_json`: W3C Verifiable Presentation json
+pub fn proof_to_w3c(proof_request_json: &str, proof_json: &str, issuance_dates_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _proof_to_w3c(command_handle, proof_request_json, proof_json, issuance_dates_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _proof_to_w3c(command_handle: CommandHandle, proof_request_json: &str, proof_json: &str, issuance_dates_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let proof_request_json = c_str!(proof_request_json);
+    let proof_json = c_str!(proof_json);
+    let issuance_dates_json = c_str!(issuance_dates_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_proof_to_w3c(command_handle, proof_request_json.as_ptr(), proof_json.as_ptr(), issuance_dates_json.as_ptr(), cb)
+    })
+}
+
+/// Restores a proof from a W3C Verifiable Presentation document created by `proof_to_w3c`.
+///
+/// # Arguments
+/// * `w3c_presentation_json`: W3C Verifiable Presentation json
+///
+/// # Returns
+/// * `proof_json`: proof json
+pub fn proof_from_w3c(w3c_presentation_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _proof_from_w3c(command_handle, w3c_presentation_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _proof_from_w3c(command_handle: CommandHandle, w3c_presentation_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let w3c_presentation_json = c_str!(w3c_presentation_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_proof_from_w3c(command_handle, w3c_presentation_json.as_ptr(), cb)
+    })
+}
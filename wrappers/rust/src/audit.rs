@@ -0,0 +1,108 @@
+use futures::Future;
+
+use {ErrorCode, IndyError};
+
+use std::ffi::CString;
+use std::ptr::null;
+use std::sync::RwLock;
+
+use ffi::{audit, CVoid, CString as IndyCString};
+use ffi::ResponseStringCB;
+
+use utils::callbacks::{ClosureHandler, ResultHandler};
+use utils::ctypes::c_str_to_string;
+
+use {WalletHandle, CommandHandle};
+
+lazy_static! {
+    static ref HANDLER: RwLock<Option<Box<dyn Fn(&str) + Send + Sync>>> = RwLock::new(None);
+}
+
+/// Enable audit log of security relevant operations in Libindy.
+///
+/// # Arguments
+/// * `config_json` - {
+///     "wallet": (optional) bool - append events to the wallet the operation was performed with (false by default),
+///     "events": (optional) [string] - types of events to record (all by default)
+/// }
+/// * `handler` - (optional) function called with every recorded event in JSON format.
+///   It is called from Libindy command thread, so it must not block and must not call Libindy.
+pub fn set_audit_log(config_json: &str, handler: Option<Box<dyn Fn(&str) + Send + Sync>>) -> Result<(), IndyError> {
+    let config_json = c_str!(config_json);
+
+    let event_cb = if handler.is_some() { Some(IndyAuditLog::event_cb as audit::AuditEventCB) } else { None };
+
+    *HANDLER.write().unwrap() = handler;
+
+    let res = ErrorCode::from(unsafe {
+        audit::indy_set_audit_log(null(), config_json.as_ptr(), event_cb)
+    });
+
+    match res {
+        ErrorCode::Success => Ok(()),
+        err => Err(IndyError::new(err))
+    }
+}
+
+/// Disable audit log in Libindy.
+pub fn unset_audit_log() -> Result<(), IndyError> {
+    set_audit_log("{}", None)
+}
+
+/// Get audit events stored in the wallet.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by open_wallet).
+/// * `query_json` - wql query over "event", "did", "verkey" and "~timestamp" tags of events.
+///
+/// # Returns
+/// List of events ordered by "seq_no" in JSON format.
+pub fn get_audit_log(wallet_handle: WalletHandle, query_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_audit_log(command_handle, wallet_handle, query_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_audit_log(command_handle: CommandHandle, wallet_handle: WalletHandle, query_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let query_json = c_str!(query_json);
+
+    ErrorCode::from(unsafe {
+        audit::indy_get_audit_log(command_handle, wallet_handle, query_json.as_ptr(), cb)
+    })
+}
+
+/// Verify hash chain of audit events stored in the wallet.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by open_wallet).
+///
+/// # Returns
+/// Verification result in JSON format: {"valid": bool, "count": int, "broken_at": optional int, "reason": optional string}
+pub fn verify_audit_log(wallet_handle: WalletHandle) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _verify_audit_log(command_handle, wallet_handle, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _verify_audit_log(command_handle: CommandHandle, wallet_handle: WalletHandle, cb: Option<ResponseStringCB>) -> ErrorCode {
+    ErrorCode::from(unsafe {
+        audit::indy_verify_audit_log(command_handle, wallet_handle, cb)
+    })
+}
+
+pub struct IndyAuditLog;
+
+impl IndyAuditLog {
+    extern fn event_cb(_context: *const CVoid,
+                       event_json: IndyCString) {
+        if let Some(ref handler) = *HANDLER.read().unwrap() {
+            if let Ok(Some(event_json)) = c_str_to_string(event_json) {
+                handler(event_json);
+            }
+        }
+    }
+}
@@ -26,6 +26,8 @@ pub mod crypto;
 pub mod did;
 pub mod ledger;
 pub mod logger;
+pub mod tracer;
+pub mod audit;
 pub mod payments;
 pub mod pairwise;
 pub mod pool;
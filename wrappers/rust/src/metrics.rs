@@ -6,6 +6,8 @@ use ffi::metrics;
 
 use utils::callbacks::{ClosureHandler, ResultHandler};
 
+use std::ffi::CString;
+
 use ffi::ResponseStringCB;
 use CommandHandle;
 
@@ -26,3 +28,28 @@ fn _collect_metrics(command_handle: CommandHandle, cb: Option<ResponseStringCB>)
       metrics::indy_collect_metrics(command_handle, cb)
     })
 }
+
+/// Collect metrics from libindy in the requested format.
+///
+/// # Arguments
+/// * `options_json` - {
+///     format: (optional) "json" (default) or "openmetrics"
+/// }
+///
+/// # Returns
+/// String with metrics in the requested format.
+pub fn collect_metrics_with_options(options_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _collect_metrics_with_options(command_handle, options_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _collect_metrics_with_options(command_handle: CommandHandle, options_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let options_json = c_str!(options_json);
+
+    ErrorCode::from(unsafe {
+      metrics::indy_collect_metrics_with_options(command_handle, options_json.as_ptr(), cb)
+    })
+}
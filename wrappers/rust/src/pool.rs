@@ -106,6 +106,28 @@ fn _list_pools(command_handle: CommandHandle, cb: Option<ResponseStringCB>) -> E
     ErrorCode::from(unsafe { pool::indy_list_pools(command_handle, cb) })
 }
 
+/// Returns the status of the last open or refresh of the pool ledger.
+///
+/// # Arguments
+/// * `config_name` - Name of the pool ledger configuration.
+///
+/// # Returns
+/// Pool state, size and merkle root of the local pool ledger, and for every node
+/// its reply to the pool ledger status sent on catchup.
+pub fn get_pool_status(pool_name: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_pool_status(command_handle, pool_name, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_pool_status(command_handle: CommandHandle, pool_name: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let pool_name = c_str!(pool_name);
+
+    ErrorCode::from(unsafe { pool::indy_get_pool_status(command_handle, pool_name.as_ptr(), cb) })
+}
+
 /// Closes opened pool ledger, opened nodes connections and frees allocated resources.
 ///
 /// # Arguments
@@ -0,0 +1,57 @@
+use {ErrorCode, IndyError};
+
+use ffi::{tracer, CVoid, CString as IndyCString};
+
+use std::ptr::null;
+use std::sync::RwLock;
+
+use utils::ctypes::c_str_to_string;
+
+lazy_static! {
+    static ref EXPORTER: RwLock<Option<Box<dyn Fn(&str) + Send + Sync>>> = RwLock::new(None);
+}
+
+/// Set application tracing spans exporter to Libindy.
+///
+/// # Arguments
+/// * `exporter` - function called with every finished span in JSON format.
+///   It is called from Libindy threads, so it should not block.
+pub fn set_tracer(exporter: Box<dyn Fn(&str) + Send + Sync>) -> Result<(), IndyError> {
+    *EXPORTER.write().unwrap() = Some(exporter);
+
+    let res = ErrorCode::from(unsafe {
+        tracer::indy_set_tracer(null(), Some(IndyTracer::export_cb))
+    });
+
+    match res {
+        ErrorCode::Success => Ok(()),
+        err => Err(IndyError::new(err))
+    }
+}
+
+/// Disable tracing in Libindy.
+pub fn unset_tracer() -> Result<(), IndyError> {
+    let res = ErrorCode::from(unsafe {
+        tracer::indy_set_tracer(null(), None)
+    });
+
+    *EXPORTER.write().unwrap() = None;
+
+    match res {
+        ErrorCode::Success => Ok(()),
+        err => Err(IndyError::new(err))
+    }
+}
+
+pub struct IndyTracer;
+
+impl IndyTracer {
+    extern fn export_cb(_context: *const CVoid,
+                        span_json: IndyCString) {
+        if let Some(ref exporter) = *EXPORTER.read().unwrap() {
+            if let Ok(Some(span_json)) = c_str_to_string(span_json) {
+                exporter(span_json);
+            }
+        }
+    }
+}
@@ -274,6 +274,38 @@ fn _import_wallet(command_handle: CommandHandle, config: &str, credentials: &str
     })
 }
 
+/// Creates a new secure wallet and then copies all records of the opened wallet into it
+/// re-encrypting them with keys of the new wallet. It allows to move a wallet between storage types.
+/// The new wallet is deleted if migration fails.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle returned by open_wallet
+/// * `config` - configuration json of the new wallet (see create_wallet).
+/// * `credentials` - credentials json of the new wallet (see create_wallet).
+///
+/// # Returns
+/// Migrated records json
+///   {
+///     "records": {<record type>: <number of records>, ...},
+///     "total": <number of records>
+///   }
+pub fn migrate_wallet(wallet_handle: WalletHandle, config: &str, credentials: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _migrate_wallet(command_handle, wallet_handle, config, credentials, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _migrate_wallet(command_handle: CommandHandle, wallet_handle: WalletHandle, config: &str, credentials: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let config = c_str!(config);
+    let credentials = c_str!(credentials);
+
+    ErrorCode::from(unsafe {
+      wallet::indy_migrate_wallet(command_handle, wallet_handle, config.as_ptr(), credentials.as_ptr(), cb)
+    })
+}
+
 /// Deletes created wallet.
 pub fn delete_wallet(config: &str, credentials: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec();